@@ -0,0 +1,36 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(feature = "v2_30", feature = "dox"))]
+use crate::ScrollType;
+use crate::{
+    CoordType, Layer, RelationType, Role, StateType, TextAttribute, TextBoundary, TextClipType,
+    TextGranularity, ValueType,
+};
+
+// The conversions of the generated enums, see `glib::enum_conversions!`.
+glib::enum_conversions! {
+    CoordType,
+    Layer,
+    RelationType,
+    Role,
+    #[cfg(any(feature = "v2_30", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_30")))]
+    ScrollType,
+    StateType,
+    TextAttribute,
+    TextBoundary,
+    TextClipType,
+    TextGranularity,
+    ValueType,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            glib::__unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
@@ -0,0 +1,8 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::HyperlinkStateFlags;
+
+// The conversions of the generated flags, see `glib::flags_conversions!`.
+glib::flags_conversions! {
+    HyperlinkStateFlags,
+}
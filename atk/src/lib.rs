@@ -27,5 +27,7 @@ pub use crate::text_rectangle::TextRectangle;
 mod attribute;
 mod attribute_set;
 mod editable_text;
+mod enums;
+mod flags;
 mod table;
 mod text_rectangle;
@@ -23,6 +23,9 @@ use std::ffi::CString;
 #[cfg(any(feature = "script", feature = "dox"))]
 use std::path::Path;
 
+#[cfg(any(feature = "gl", feature = "dox"))]
+use libc::c_void;
+
 #[derive(Debug)]
 pub struct DeviceAcquireGuard<'a>(&'a Device);
 
@@ -134,6 +137,43 @@ impl Device {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates an OpenGL device for the `EGLContext` `context` of the `EGLDisplay` `display`.
+    ///
+    /// # Safety
+    ///
+    /// `display` and `context` have to be valid and outlive the returned device.
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    #[doc(alias = "cairo_egl_device_create")]
+    pub unsafe fn create_egl(display: *mut c_void, context: *mut c_void) -> Result<Device, Error> {
+        let device = Self::from_raw_full(ffi::cairo_egl_device_create(display, context));
+        status_to_result(ffi::cairo_device_status(device.to_raw_none()))?;
+        Ok(device)
+    }
+
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    #[doc(alias = "cairo_egl_device_get_display")]
+    pub fn get_egl_display(&self) -> *mut c_void {
+        unsafe { ffi::cairo_egl_device_get_display(self.to_raw_none()) }
+    }
+
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    #[doc(alias = "cairo_egl_device_get_context")]
+    pub fn get_egl_context(&self) -> *mut c_void {
+        unsafe { ffi::cairo_egl_device_get_context(self.to_raw_none()) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets whether cairo saves and restores the current GL context around its own rendering.
+    ///
+    /// This is enabled by default and can be disabled if the application uses the context of
+    /// the device only from a single thread and always leaves it current.
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    #[doc(alias = "cairo_gl_device_set_thread_aware")]
+    pub fn set_thread_aware(&self, thread_aware: bool) {
+        unsafe { ffi::cairo_gl_device_set_thread_aware(self.to_raw_none(), thread_aware.into()) }
+    }
+
     #[doc(alias = "cairo_device_finish")]
     pub fn finish(&self) {
         unsafe { ffi::cairo_device_finish(self.to_raw_none()) }
@@ -0,0 +1,101 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+
+use crate::device::Device;
+use crate::enums::{Content, SurfaceType};
+use crate::error::Error;
+#[cfg(feature = "use_glib")]
+use glib::translate::*;
+use libc::c_void;
+
+use crate::surface::Surface;
+
+declare_surface!(GlSurface, SurfaceType::Gl);
+
+impl GlSurface {
+    #[doc(alias = "cairo_gl_surface_create")]
+    pub fn create(
+        device: &Device,
+        content: Content,
+        width: i32,
+        height: i32,
+    ) -> Result<GlSurface, Error> {
+        unsafe {
+            Self::from_raw_full(ffi::cairo_gl_surface_create(
+                device.to_raw_none(),
+                content.into(),
+                width,
+                height,
+            ))
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a surface that renders into the OpenGL texture `texture`.
+    ///
+    /// The texture has to be created in the context of `device` with a size of at least
+    /// `width` × `height`. This allows compositing cairo content into a GL scene.
+    #[doc(alias = "cairo_gl_surface_create_for_texture")]
+    pub fn create_for_texture(
+        device: &Device,
+        content: Content,
+        texture: u32,
+        width: i32,
+        height: i32,
+    ) -> Result<GlSurface, Error> {
+        unsafe {
+            Self::from_raw_full(ffi::cairo_gl_surface_create_for_texture(
+                device.to_raw_none(),
+                content.into(),
+                texture,
+                width,
+                height,
+            ))
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a surface that renders into the `EGLSurface` `egl_surface`.
+    ///
+    /// # Safety
+    ///
+    /// `egl_surface` has to be a valid `EGLSurface` of the display of `device`, and has to
+    /// outlive the returned surface.
+    #[doc(alias = "cairo_gl_surface_create_for_egl")]
+    pub unsafe fn create_for_egl(
+        device: &Device,
+        egl_surface: *mut c_void,
+        width: i32,
+        height: i32,
+    ) -> Result<GlSurface, Error> {
+        Self::from_raw_full(ffi::cairo_gl_surface_create_for_egl(
+            device.to_raw_none(),
+            egl_surface,
+            width,
+            height,
+        ))
+    }
+
+    #[doc(alias = "cairo_gl_surface_set_size")]
+    pub fn set_size(&self, width: i32, height: i32) {
+        unsafe { ffi::cairo_gl_surface_set_size(self.to_raw_none(), width, height) }
+    }
+
+    #[doc(alias = "cairo_gl_surface_get_width")]
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::cairo_gl_surface_get_width(self.to_raw_none()) }
+    }
+
+    #[doc(alias = "cairo_gl_surface_get_height")]
+    pub fn get_height(&self) -> i32 {
+        unsafe { ffi::cairo_gl_surface_get_height(self.to_raw_none()) }
+    }
+
+    #[doc(alias = "cairo_gl_surface_swapbuffers")]
+    pub fn swap_buffers(&self) {
+        unsafe { ffi::cairo_gl_surface_swapbuffers(self.to_raw_none()) }
+    }
+}
@@ -29,6 +29,27 @@ impl ImageSurface {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates an image surface of `width` × `height` units with a device scale of `scale`.
+    ///
+    /// The surface is `scale` times as large in pixels, rounded up, so drawing on it stays
+    /// sharp on HiDPI outputs while user space coordinates stay the same.
+    #[cfg(any(feature = "v1_14", feature = "dox"))]
+    pub fn create_with_device_scale(
+        format: Format,
+        width: i32,
+        height: i32,
+        scale: f64,
+    ) -> Result<ImageSurface, Error> {
+        let surface = Self::create(
+            format,
+            (f64::from(width) * scale).ceil() as i32,
+            (f64::from(height) * scale).ceil() as i32,
+        )?;
+        surface.set_device_scale(scale, scale);
+        Ok(surface)
+    }
+
     // rustdoc-stripper-ignore-next
     /// Creates an image surface for the provided pixel data.
     /// - The pointer `data` is the beginning of the underlying slice,
@@ -228,6 +249,22 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[cfg(feature = "v1_14")]
+    #[test]
+    fn create_with_device_scale() {
+        let surface = ImageSurface::create_with_device_scale(Format::ARgb32, 10, 5, 1.5).unwrap();
+        assert_eq!(surface.get_width(), 15);
+        assert_eq!(surface.get_height(), 8);
+        assert_eq!(surface.get_device_scale(), (1.5, 1.5));
+
+        let similar = surface
+            .create_similar_image_with_device_scale(Format::ARgb32, 4, 4)
+            .unwrap();
+        let similar = ImageSurface::try_from(similar).unwrap();
+        assert_eq!(similar.get_width(), 6);
+        assert_eq!(similar.get_device_scale(), (1.5, 1.5));
+    }
+
     #[test]
     fn no_crash_after_finish() {
         let mut surf = ImageSurface::create(Format::ARgb32, 1024, 1024).unwrap();
@@ -34,6 +34,10 @@
 //! * **xcb** - X Window System rendering using the XCB library
 //! * **xlib** - X Window System rendering using XLib
 //!
+//! ### OpenGL features
+//!
+//! * **gl** - OpenGL surfaces and EGL devices, if cairo was built with `cairo-gl`
+//!
 //! ### Windows API features
 //!
 //! * **win32-surface** - Microsoft Windows surface support
@@ -116,7 +120,7 @@ pub use crate::font::{
     TextCluster, TextExtents,
 };
 
-pub use crate::matrices::Matrix;
+pub use crate::matrices::{Matrix, MatrixDecomposition};
 
 pub use crate::recording_surface::RecordingSurface;
 pub use crate::rectangle::Rectangle;
@@ -173,6 +177,11 @@ mod surface;
 #[cfg(any(feature = "xcb", feature = "dox"))]
 mod xcb;
 
+#[cfg(any(feature = "gl", feature = "dox"))]
+mod gl;
+#[cfg(any(feature = "gl", feature = "dox"))]
+pub use gl::GlSurface;
+
 #[cfg(any(feature = "pdf", feature = "svg", feature = "ps", feature = "dox"))]
 #[macro_use]
 mod stream;
@@ -125,6 +125,98 @@ impl Matrix {
         }
         (x, y)
     }
+
+    /// Transforms each of the distance vectors in place, see
+    /// [`transform_distance()`](Self::transform_distance).
+    pub fn transform_distances(&self, distances: &mut [(f64, f64)]) {
+        for distance in distances {
+            *distance = self.transform_distance(distance.0, distance.1);
+        }
+    }
+
+    /// Transforms each of the points in place, see [`transform_point()`](Self::transform_point).
+    pub fn transform_points(&self, points: &mut [(f64, f64)]) {
+        for point in points {
+            *point = self.transform_point(point.0, point.1);
+        }
+    }
+
+    /// Splits the matrix into a translation, rotation, scale and shear.
+    ///
+    /// See [`MatrixDecomposition`] for how they are combined again. The shear of matrices that
+    /// can't be inverted is lost.
+    pub fn decompose(&self) -> MatrixDecomposition {
+        // QR decomposition of the linear part into a rotation and an upper triangular matrix
+        let scale_x = self.xx.hypot(self.yx);
+        let rotation = self.yx.atan2(self.xx);
+        let (sin, cos) = rotation.sin_cos();
+        let shear_scaled = cos * self.xy + sin * self.yy;
+        let scale_y = cos * self.yy - sin * self.xy;
+        let shear = if scale_x != 0.0 {
+            shear_scaled / scale_x
+        } else {
+            0.0
+        };
+
+        MatrixDecomposition {
+            translation: (self.x0, self.y0),
+            rotation,
+            scale: (scale_x, scale_y),
+            shear,
+        }
+    }
+
+    /// Combines the components of `decomposition` into a matrix again.
+    pub fn from_decomposition(decomposition: &MatrixDecomposition) -> Matrix {
+        let mut matrix = Matrix::identity();
+        matrix.translate(decomposition.translation.0, decomposition.translation.1);
+        matrix.rotate(decomposition.rotation);
+        matrix.scale(decomposition.scale.0, decomposition.scale.1);
+        let shear = Matrix::new(1.0, 0.0, decomposition.shear, 1.0, 0.0, 0.0);
+        Matrix::multiply(&shear, &matrix)
+    }
+}
+
+/// The components of an affine transformation, as returned by [`Matrix::decompose()`].
+///
+/// Coordinates are first sheared along the x axis, i.e. `x` becomes `x + shear * y`, then
+/// scaled, rotated and finally translated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatrixDecomposition {
+    pub translation: (f64, f64),
+    /// The rotation in radians.
+    pub rotation: f64,
+    pub scale: (f64, f64),
+    pub shear: f64,
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix2x3<f64>> for Matrix {
+    fn from(matrix: mint::ColumnMatrix2x3<f64>) -> Matrix {
+        Matrix::new(
+            matrix.x.x, matrix.x.y, matrix.y.x, matrix.y.y, matrix.z.x, matrix.z.y,
+        )
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Matrix> for mint::ColumnMatrix2x3<f64> {
+    fn from(matrix: Matrix) -> mint::ColumnMatrix2x3<f64> {
+        mint::ColumnMatrix2x3 {
+            x: mint::Vector2 {
+                x: matrix.xx,
+                y: matrix.yx,
+            },
+            y: mint::Vector2 {
+                x: matrix.xy,
+                y: matrix.yy,
+            },
+            z: mint::Vector2 {
+                x: matrix.x0,
+                y: matrix.y0,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -175,4 +267,40 @@ mod tests {
         matrix.invert();
         assert!(matrix == Matrix::identity());
     }
+
+    #[test]
+    fn transform_slices() {
+        let matrix = Matrix::new(2.0, 0.0, 0.0, 3.0, 10.0, 20.0);
+
+        let mut points = [(1.0, 1.0), (-1.0, 0.0)];
+        matrix.transform_points(&mut points);
+        assert_eq!(points, [(12.0, 23.0), (8.0, 20.0)]);
+
+        let mut distances = [(1.0, 1.0), (-1.0, 0.0)];
+        matrix.transform_distances(&mut distances);
+        assert_eq!(distances, [(2.0, 3.0), (-2.0, 0.0)]);
+    }
+
+    #[test]
+    fn decompose_matrix() {
+        let mut matrix = Matrix::identity();
+        matrix.translate(5.0, -3.0);
+        matrix.rotate(0.5);
+        matrix.scale(2.0, -4.0);
+
+        let decomposition = matrix.decompose();
+        assert!((decomposition.rotation - 0.5).abs() < 1e-9);
+        assert!((decomposition.scale.0 - 2.0).abs() < 1e-9);
+        assert!((decomposition.scale.1 + 4.0).abs() < 1e-9);
+        assert!(decomposition.shear.abs() < 1e-9);
+        assert_eq!(decomposition.translation, (5.0, -3.0));
+
+        let sheared = Matrix::new(1.0, 0.5, 2.0, -1.0, 3.0, 4.0);
+        let recomposed = Matrix::from_decomposition(&sheared.decompose());
+        for point in &[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (-2.0, 3.0)] {
+            let (x, y) = sheared.transform_point(point.0, point.1);
+            let (rx, ry) = recomposed.transform_point(point.0, point.1);
+            assert!((x - rx).abs() < 1e-9 && (y - ry).abs() < 1e-9);
+        }
+    }
 }
@@ -26,6 +26,11 @@ impl QuartzSurface {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates a surface drawing to `cg_context`.
+    ///
+    /// The surface retains `cg_context` and releases it again when it is destroyed, so the
+    /// caller can release its own reference right away.
     #[doc(alias = "cairo_quartz_surface_create_for_cg_context")]
     pub fn create_for_cg_context(
         cg_context: CGContextRef,
@@ -39,6 +44,11 @@ impl QuartzSurface {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Returns the `CGContext` of the surface, or a null pointer if it has none.
+    ///
+    /// The context is not retained, it has to be retained with `CGContextRetain()` if it is
+    /// used after the surface is destroyed.
     #[doc(alias = "cairo_quartz_surface_get_cg_context")]
     pub fn get_cg_context(&self) -> CGContextRef {
         unsafe { ffi::cairo_quartz_surface_get_cg_context(self.to_raw_none()) }
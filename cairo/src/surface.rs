@@ -242,6 +242,29 @@ impl Surface {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates an image surface similar to this one of `width` × `height` units, with the same
+    /// device scale as this surface.
+    ///
+    /// Unlike [`create_similar_image`](Self::create_similar_image) the size is given in user
+    /// space units like for [`create_similar`](Self::create_similar).
+    #[cfg(any(feature = "v1_14", feature = "dox"))]
+    pub fn create_similar_image_with_device_scale(
+        &self,
+        format: Format,
+        width: i32,
+        height: i32,
+    ) -> Result<Surface, Error> {
+        let (x_scale, y_scale) = self.get_device_scale();
+        let surface = self.create_similar_image(
+            format,
+            (f64::from(width) * x_scale).ceil() as i32,
+            (f64::from(height) * y_scale).ceil() as i32,
+        )?;
+        surface.set_device_scale(x_scale, y_scale);
+        Ok(surface)
+    }
+
     #[doc(alias = "cairo_surface_map_to_image")]
     pub fn map_to_image(&self, extents: Option<RectangleInt>) -> Result<MappedImageSurface, Error> {
         unsafe {
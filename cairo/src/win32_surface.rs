@@ -2,16 +2,22 @@
 
 pub use ffi::winapi;
 
+use std::any::Any;
 use std::convert::TryFrom;
 use std::fmt;
 use std::ops::Deref;
+use std::rc::Rc;
 
 use crate::enums::{Format, SurfaceType};
 use crate::error::Error;
+use crate::image_surface::ImageSurface;
 use crate::surface::Surface;
+use crate::user_data::UserDataKey;
 #[cfg(feature = "use_glib")]
 use glib::translate::*;
 
+static HDC_OWNER_KEY: UserDataKey<Box<dyn Any>> = UserDataKey::new();
+
 declare_surface!(Win32Surface, SurfaceType::Win32);
 
 impl Win32Surface {
@@ -20,6 +26,24 @@ impl Win32Surface {
         unsafe { Self::from_raw_full(ffi::cairo_win32_surface_create(hdc)) }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates a surface drawing to `hdc` that keeps `owner` alive until the surface is
+    /// destroyed.
+    ///
+    /// cairo does not take ownership of `hdc`. `owner` can release it in its `Drop`
+    /// implementation, e.g. with `ReleaseDC()` or `DeleteDC()`, once the surface and all
+    /// references to it are gone.
+    ///
+    /// # Safety
+    ///
+    /// `hdc` has to stay valid until `owner` is dropped.
+    #[doc(alias = "cairo_win32_surface_create")]
+    pub unsafe fn from_hdc<T: 'static>(hdc: winapi::HDC, owner: T) -> Result<Win32Surface, Error> {
+        let surface = Self::from_raw_full(ffi::cairo_win32_surface_create(hdc))?;
+        surface.set_user_data(&HDC_OWNER_KEY, Rc::new(Box::new(owner) as Box<dyn Any>));
+        Ok(surface)
+    }
+
     #[cfg(any(all(windows, feature = "v1_14"), feature = "dox"))]
     #[doc(alias = "cairo_win32_surface_create_with_format")]
     pub fn create_with_format(hdc: winapi::HDC, format: Format) -> Result<Win32Surface, Error> {
@@ -63,4 +87,28 @@ impl Win32Surface {
     pub fn printing_surface_create(hdc: winapi::HDC) -> Result<Win32Surface, Error> {
         unsafe { Self::from_raw_full(ffi::cairo_win32_printing_surface_create(hdc)) }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the device context of the surface, or a null handle if it has none.
+    ///
+    /// The device context is owned by the surface and only valid as long as it.
+    #[doc(alias = "cairo_win32_surface_get_dc")]
+    pub fn get_dc(&self) -> winapi::HDC {
+        unsafe { ffi::cairo_win32_surface_get_dc(self.to_raw_none()) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the image surface sharing the pixels of this surface if it was created with
+    /// [`create_with_dib`](Self::create_with_dib).
+    #[doc(alias = "cairo_win32_surface_get_image")]
+    pub fn get_image(&self) -> Option<ImageSurface> {
+        unsafe {
+            let ptr = ffi::cairo_win32_surface_get_image(self.to_raw_none());
+            if ptr.is_null() {
+                None
+            } else {
+                ImageSurface::try_from(Surface::from_raw_none(ptr)).ok()
+            }
+        }
+    }
 }
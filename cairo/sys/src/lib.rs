@@ -1424,6 +1424,49 @@ extern "C" {
         comment: *const c_char,
         len: c_int,
     );
+
+    // CAIRO GL
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_device_set_thread_aware(
+        device: *mut cairo_device_t,
+        thread_aware: cairo_bool_t,
+    );
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_surface_create(
+        device: *mut cairo_device_t,
+        content: cairo_content_t,
+        width: c_int,
+        height: c_int,
+    ) -> *mut cairo_surface_t;
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_surface_create_for_texture(
+        device: *mut cairo_device_t,
+        content: cairo_content_t,
+        tex: c_uint,
+        width: c_int,
+        height: c_int,
+    ) -> *mut cairo_surface_t;
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_surface_set_size(surface: *mut cairo_surface_t, width: c_int, height: c_int);
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_surface_get_width(surface: *mut cairo_surface_t) -> c_int;
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_surface_get_height(surface: *mut cairo_surface_t) -> c_int;
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_surface_swapbuffers(surface: *mut cairo_surface_t);
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_egl_device_create(dpy: *mut c_void, egl: *mut c_void) -> *mut cairo_device_t;
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_gl_surface_create_for_egl(
+        device: *mut cairo_device_t,
+        egl: *mut c_void,
+        width: c_int,
+        height: c_int,
+    ) -> *mut cairo_surface_t;
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_egl_device_get_display(device: *mut cairo_device_t) -> *mut c_void;
+    #[cfg(any(feature = "gl", feature = "dox"))]
+    pub fn cairo_egl_device_get_context(device: *mut cairo_device_t) -> *mut c_void;
     pub fn cairo_device_destroy(device: *mut cairo_device_t);
     pub fn cairo_device_status(device: *mut cairo_device_t) -> cairo_status_t;
     pub fn cairo_device_finish(device: *mut cairo_device_t);
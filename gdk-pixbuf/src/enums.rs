@@ -0,0 +1,23 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Colorspace, InterpType, PixbufAlphaMode, PixbufError, PixbufRotation};
+
+// The conversions of the generated enums, see `glib::enum_conversions!`.
+glib::enum_conversions! {
+    Colorspace,
+    InterpType,
+    PixbufAlphaMode,
+    PixbufError,
+    PixbufRotation,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            glib::__unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
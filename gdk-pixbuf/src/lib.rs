@@ -10,6 +10,7 @@ pub use glib;
 #[allow(unused_imports)]
 mod auto;
 
+mod enums;
 mod pixbuf;
 mod pixbuf_animation;
 mod pixbuf_animation_iter;
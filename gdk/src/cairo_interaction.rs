@@ -2,7 +2,7 @@
 
 use crate::{Rectangle, Window, RGBA};
 use cairo::Surface;
-use cairo::{Context, Region};
+use cairo::{Context, Filter, Region};
 use gdk_pixbuf::Pixbuf;
 use glib::object::IsA;
 use glib::translate::*;
@@ -44,6 +44,44 @@ impl GdkPixbufExt for Pixbuf {
     }
 }
 
+// rustdoc-stripper-ignore-next
+/// Creates a pixbuf from the contents of `surface` in `region`.
+///
+/// `region` is in the user space of `surface`. If `surface` has a device scale the pixbuf keeps
+/// the full resolution, e.g. it is twice as large as `region` in each direction on a surface
+/// with a device scale of 2, unlike [`pixbuf_get_from_surface`](crate::pixbuf_get_from_surface).
+#[cfg(any(feature = "v3_16", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v3_16")))]
+#[doc(alias = "gdk_pixbuf_get_from_surface")]
+pub fn pixbuf_from_surface(surface: &Surface, region: &Rectangle) -> Option<Pixbuf> {
+    let (x_scale, y_scale) = surface.get_device_scale();
+    #[allow(clippy::float_cmp)]
+    if x_scale == 1.0 && y_scale == 1.0 {
+        return crate::pixbuf_get_from_surface(
+            surface,
+            region.x,
+            region.y,
+            region.width,
+            region.height,
+        );
+    }
+
+    // gdk_pixbuf_get_from_surface() works in user space and would scale the contents down,
+    // so copy them to an unscaled surface of the size in device pixels first
+    let width = (f64::from(region.width) * x_scale).ceil() as i32;
+    let height = (f64::from(region.height) * y_scale).ceil() as i32;
+    let image = cairo::ImageSurface::create(cairo::Format::ARgb32, width, height).ok()?;
+    {
+        let cr = Context::new(&image);
+        cr.scale(x_scale, y_scale);
+        cr.set_source_surface(surface, -f64::from(region.x), -f64::from(region.y));
+        cr.set_operator(cairo::Operator::Source);
+        cr.paint();
+    }
+
+    crate::pixbuf_get_from_surface(&image, 0, 0, width, height)
+}
+
 pub trait GdkContextExt {
     fn create_from_window<W: IsA<Window>>(window: &W) -> Context;
 
@@ -68,6 +106,22 @@ pub trait GdkContextExt {
 
     fn set_source_pixbuf(&self, pixbuf: &Pixbuf, x: f64, y: f64);
 
+    // rustdoc-stripper-ignore-next
+    /// Paints `pixbuf` scaled to the rectangle at `x`, `y` of size `width` × `height`, using
+    /// `filter` for resampling.
+    ///
+    /// This can be used to draw a pixbuf with the full resolution of a HiDPI surface by passing
+    /// its size divided by the scale factor.
+    fn paint_pixbuf(
+        &self,
+        pixbuf: &Pixbuf,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        filter: Filter,
+    );
+
     fn set_source_window<W: IsA<Window>>(&self, window: &W, x: f64, y: f64);
 
     fn rectangle(&self, rectangle: &Rectangle);
@@ -134,6 +188,34 @@ impl GdkContextExt for Context {
         }
     }
 
+    fn paint_pixbuf(
+        &self,
+        pixbuf: &Pixbuf,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        filter: Filter,
+    ) {
+        let (pixbuf_width, pixbuf_height) = (pixbuf.get_width(), pixbuf.get_height());
+        if pixbuf_width == 0 || pixbuf_height == 0 {
+            return;
+        }
+
+        let _ = self.save();
+        self.rectangle(x, y, width, height);
+        self.clip();
+        self.translate(x, y);
+        self.scale(
+            width / f64::from(pixbuf_width),
+            height / f64::from(pixbuf_height),
+        );
+        self.set_source_pixbuf(pixbuf, 0.0, 0.0);
+        self.get_source().set_filter(filter);
+        self.paint();
+        let _ = self.restore();
+    }
+
     fn set_source_window<W: IsA<Window>>(&self, window: &W, x: f64, y: f64) {
         unsafe {
             ffi::gdk_cairo_set_source_window(
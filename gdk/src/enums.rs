@@ -0,0 +1,74 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(feature = "v3_22", feature = "dox"))]
+use crate::DevicePadFeature;
+#[cfg(any(feature = "v3_22", feature = "dox"))]
+use crate::DeviceToolType;
+#[cfg(any(feature = "v3_20", feature = "dox"))]
+use crate::DragCancelReason;
+#[cfg(any(feature = "v3_16", feature = "dox"))]
+use crate::GLError;
+#[cfg(any(feature = "v3_22", feature = "dox"))]
+use crate::SubpixelLayout;
+use crate::{
+    AxisUse, ByteOrder, CrossingMode, CursorType, DeviceType, DragProtocol, EventType,
+    FullscreenMode, GrabOwnership, GrabStatus, Gravity, InputMode, InputSource, ModifierIntent,
+    NotifyType, OwnerChange, PropMode, PropertyState, ScrollDirection, SettingAction,
+    VisibilityState, VisualType, WindowEdge, WindowType, WindowTypeHint, WindowWindowClass,
+};
+
+// The conversions of the generated enums, see `glib::enum_conversions!`.
+glib::enum_conversions! {
+    AxisUse,
+    ByteOrder,
+    CrossingMode,
+    CursorType,
+    #[cfg(any(feature = "v3_22", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_22")))]
+    DevicePadFeature,
+    #[cfg(any(feature = "v3_22", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_22")))]
+    DeviceToolType,
+    DeviceType,
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    DragCancelReason,
+    DragProtocol,
+    EventType,
+    FullscreenMode,
+    #[cfg(any(feature = "v3_16", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_16")))]
+    GLError,
+    GrabOwnership,
+    GrabStatus,
+    Gravity,
+    InputMode,
+    InputSource,
+    ModifierIntent,
+    NotifyType,
+    OwnerChange,
+    PropMode,
+    PropertyState,
+    ScrollDirection,
+    SettingAction,
+    #[cfg(any(feature = "v3_22", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_22")))]
+    SubpixelLayout,
+    VisibilityState,
+    VisualType,
+    WindowEdge,
+    WindowType,
+    WindowTypeHint,
+    WindowWindowClass,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            glib::__unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
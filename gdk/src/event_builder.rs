@@ -0,0 +1,519 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Builders for synthetic input events.
+//!
+//! The events created here look like events coming from the windowing system and can be
+//! injected into the event queue of their window's display with `put()`, which is useful for
+//! automation and accessibility tools.
+
+use glib::translate::*;
+
+use crate::keys::Key;
+use crate::Device;
+use crate::Event;
+use crate::EventButton;
+use crate::EventKey;
+use crate::EventMotion;
+use crate::EventScroll;
+use crate::EventSequence;
+use crate::EventTouch;
+use crate::EventType;
+use crate::Keymap;
+use crate::ModifierType;
+use crate::ScrollDirection;
+use crate::Window;
+
+/// The fields shared by all synthetic events.
+#[derive(Debug, Clone)]
+struct Common {
+    window: Window,
+    device: Option<Device>,
+    time: u32,
+    state: ModifierType,
+    send_event: bool,
+}
+
+impl Common {
+    fn new(window: &Window) -> Self {
+        Self {
+            window: window.clone(),
+            device: None,
+            time: ffi::GDK_CURRENT_TIME as u32,
+            state: ModifierType::empty(),
+            send_event: false,
+        }
+    }
+
+    fn event(&self, type_: EventType, keyboard: bool) -> Event {
+        let mut event = Event::new(type_);
+        unsafe {
+            let any: *mut ffi::GdkEventAny = event.to_glib_none_mut().0;
+            (*any).window = self.window.to_glib_full();
+            (*any).send_event = self.send_event as i8;
+        }
+        let device = self
+            .device
+            .clone()
+            .or_else(|| default_device(&self.window, keyboard));
+        if let Some(ref device) = device {
+            event.set_device(Some(device));
+            event.set_source_device(Some(device));
+        }
+        event
+    }
+}
+
+#[cfg(any(feature = "v3_20", feature = "dox"))]
+fn default_device(window: &Window, keyboard: bool) -> Option<Device> {
+    let seat = window.get_display().get_default_seat()?;
+    if keyboard {
+        seat.get_keyboard()
+    } else {
+        seat.get_pointer()
+    }
+}
+
+#[cfg(not(any(feature = "v3_20", feature = "dox")))]
+fn default_device(window: &Window, keyboard: bool) -> Option<Device> {
+    let pointer = window
+        .get_display()
+        .get_device_manager()?
+        .get_client_pointer()?;
+    if keyboard {
+        pointer.get_associated_device()
+    } else {
+        Some(pointer)
+    }
+}
+
+macro_rules! common_setters {
+    () => {
+        /// Sets the device the event originates from, which defaults to the core keyboard or
+        /// pointer of the window's display.
+        pub fn device(mut self, device: &Device) -> Self {
+            self.common.device = Some(device.clone());
+            self
+        }
+
+        /// Sets the timestamp of the event, which defaults to `GDK_CURRENT_TIME`.
+        pub fn time(mut self, time: u32) -> Self {
+            self.common.time = time;
+            self
+        }
+
+        /// Sets the modifier state at the time of the event.
+        pub fn state(mut self, state: ModifierType) -> Self {
+            self.common.state = state;
+            self
+        }
+
+        /// Marks the event as explicitly sent by a client instead of the windowing system.
+        pub fn send_event(mut self, send_event: bool) -> Self {
+            self.common.send_event = send_event;
+            self
+        }
+
+        /// Builds the event and appends it to the event queue of the window's display.
+        #[doc(alias = "gdk_event_put")]
+        pub fn put(self) {
+            self.build().put();
+        }
+    };
+}
+
+macro_rules! position_setters {
+    () => {
+        /// Sets the position of the event relative to the window.
+        ///
+        /// Unless set with `root()`, the root position is derived from it.
+        pub fn position(mut self, x: f64, y: f64) -> Self {
+            self.position = (x, y);
+            self
+        }
+
+        /// Sets the position of the event relative to the root window.
+        pub fn root(mut self, x_root: f64, y_root: f64) -> Self {
+            self.root = Some((x_root, y_root));
+            self
+        }
+
+        fn get_root(&self) -> (f64, f64) {
+            self.root.unwrap_or_else(|| {
+                let (x, y) = self.position;
+                let (root_x, root_y) = self.common.window.get_root_coords(x as i32, y as i32);
+                (root_x as f64 + x.fract(), root_y as f64 + y.fract())
+            })
+        }
+    };
+}
+
+/// Builder for synthetic key press and release events.
+#[derive(Debug, Clone)]
+#[must_use = "the builder has to be built or put to create the event"]
+pub struct EventKeyBuilder {
+    common: Common,
+    type_: EventType,
+    keyval: Key,
+    hardware_keycode: Option<u16>,
+    group: Option<u8>,
+    is_modifier: bool,
+}
+
+impl EventKeyBuilder {
+    /// # Panics
+    ///
+    /// If `type_` is not `KeyPress` or `KeyRelease`.
+    pub fn new(type_: EventType, window: &Window, keyval: Key) -> Self {
+        assert!(
+            matches!(type_, EventType::KeyPress | EventType::KeyRelease),
+            "{:?} is not a key event type",
+            type_
+        );
+        Self {
+            common: Common::new(window),
+            type_,
+            keyval,
+            hardware_keycode: None,
+            group: None,
+            is_modifier: false,
+        }
+    }
+
+    common_setters!();
+
+    /// Sets the hardware keycode, which defaults to the first keycode producing the keyval in
+    /// the keymap of the window's display.
+    pub fn hardware_keycode(mut self, hardware_keycode: u16) -> Self {
+        self.hardware_keycode = Some(hardware_keycode);
+        self
+    }
+
+    /// Sets the keyboard group, which defaults to the group of the looked up keycode.
+    pub fn group(mut self, group: u8) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Marks the key as a modifier key.
+    pub fn is_modifier(mut self, is_modifier: bool) -> Self {
+        self.is_modifier = is_modifier;
+        self
+    }
+
+    pub fn build(self) -> EventKey {
+        let entry = if self.hardware_keycode.is_none() || self.group.is_none() {
+            Keymap::get_for_display(&self.common.window.get_display())
+                .and_then(|keymap| keymap.get_entries_for_keyval(*self.keyval).first().copied())
+        } else {
+            None
+        };
+
+        let mut event: EventKey = self
+            .common
+            .event(self.type_, true)
+            .downcast()
+            .expect("not a key event");
+        let string = self
+            .keyval
+            .to_unicode()
+            .map(String::from)
+            .unwrap_or_default();
+
+        let key = event.as_mut();
+        key.time = self.common.time;
+        key.state = self.common.state.to_glib();
+        key.keyval = *self.keyval;
+        key.length = string.len() as i32;
+        key.string = string.to_glib_full();
+        key.hardware_keycode = self
+            .hardware_keycode
+            .or_else(|| entry.map(|entry| entry.get_keycode() as u16))
+            .unwrap_or(0);
+        key.group = self
+            .group
+            .or_else(|| entry.map(|entry| entry.get_group() as u8))
+            .unwrap_or(0);
+        key.is_modifier = self.is_modifier as u32;
+
+        event
+    }
+}
+
+/// Builder for synthetic button press and release events.
+#[derive(Debug, Clone)]
+#[must_use = "the builder has to be built or put to create the event"]
+pub struct EventButtonBuilder {
+    common: Common,
+    type_: EventType,
+    button: u32,
+    position: (f64, f64),
+    root: Option<(f64, f64)>,
+}
+
+impl EventButtonBuilder {
+    /// # Panics
+    ///
+    /// If `type_` is not a button press or release type.
+    pub fn new(type_: EventType, window: &Window, button: u32) -> Self {
+        assert!(
+            matches!(
+                type_,
+                EventType::ButtonPress
+                    | EventType::DoubleButtonPress
+                    | EventType::TripleButtonPress
+                    | EventType::ButtonRelease
+            ),
+            "{:?} is not a button event type",
+            type_
+        );
+        Self {
+            common: Common::new(window),
+            type_,
+            button,
+            position: (0.0, 0.0),
+            root: None,
+        }
+    }
+
+    common_setters!();
+    position_setters!();
+
+    pub fn build(self) -> EventButton {
+        let (x_root, y_root) = self.get_root();
+        let mut event: EventButton = self
+            .common
+            .event(self.type_, false)
+            .downcast()
+            .expect("not a button event");
+
+        let button = event.as_mut();
+        button.time = self.common.time;
+        button.state = self.common.state.to_glib();
+        button.button = self.button;
+        button.x = self.position.0;
+        button.y = self.position.1;
+        button.x_root = x_root;
+        button.y_root = y_root;
+
+        event
+    }
+}
+
+/// Builder for synthetic motion events.
+#[derive(Debug, Clone)]
+#[must_use = "the builder has to be built or put to create the event"]
+pub struct EventMotionBuilder {
+    common: Common,
+    position: (f64, f64),
+    root: Option<(f64, f64)>,
+    is_hint: bool,
+}
+
+impl EventMotionBuilder {
+    pub fn new(window: &Window) -> Self {
+        Self {
+            common: Common::new(window),
+            position: (0.0, 0.0),
+            root: None,
+            is_hint: false,
+        }
+    }
+
+    common_setters!();
+    position_setters!();
+
+    /// Marks the event as a motion hint.
+    pub fn is_hint(mut self, is_hint: bool) -> Self {
+        self.is_hint = is_hint;
+        self
+    }
+
+    pub fn build(self) -> EventMotion {
+        let (x_root, y_root) = self.get_root();
+        let mut event: EventMotion = self
+            .common
+            .event(EventType::MotionNotify, false)
+            .downcast()
+            .expect("not a motion event");
+
+        let motion = event.as_mut();
+        motion.time = self.common.time;
+        motion.state = self.common.state.to_glib();
+        motion.x = self.position.0;
+        motion.y = self.position.1;
+        motion.x_root = x_root;
+        motion.y_root = y_root;
+        motion.is_hint = self.is_hint as i16;
+
+        event
+    }
+}
+
+/// Builder for synthetic scroll events.
+#[derive(Debug, Clone)]
+#[must_use = "the builder has to be built or put to create the event"]
+pub struct EventScrollBuilder {
+    common: Common,
+    direction: ScrollDirection,
+    position: (f64, f64),
+    root: Option<(f64, f64)>,
+    deltas: (f64, f64),
+    is_stop: bool,
+}
+
+impl EventScrollBuilder {
+    pub fn new(window: &Window, direction: ScrollDirection) -> Self {
+        Self {
+            common: Common::new(window),
+            direction,
+            position: (0.0, 0.0),
+            root: None,
+            deltas: (0.0, 0.0),
+            is_stop: false,
+        }
+    }
+
+    common_setters!();
+    position_setters!();
+
+    /// Sets the scroll deltas, which are only used with `ScrollDirection::Smooth`.
+    pub fn deltas(mut self, delta_x: f64, delta_y: f64) -> Self {
+        self.deltas = (delta_x, delta_y);
+        self
+    }
+
+    /// Marks the event as the end of a kinetic scroll sequence.
+    pub fn is_stop(mut self, is_stop: bool) -> Self {
+        self.is_stop = is_stop;
+        self
+    }
+
+    pub fn build(self) -> EventScroll {
+        let (x_root, y_root) = self.get_root();
+        let mut event: EventScroll = self
+            .common
+            .event(EventType::Scroll, false)
+            .downcast()
+            .expect("not a scroll event");
+
+        let scroll = event.as_mut();
+        scroll.time = self.common.time;
+        scroll.state = self.common.state.to_glib();
+        scroll.direction = self.direction.to_glib();
+        scroll.x = self.position.0;
+        scroll.y = self.position.1;
+        scroll.x_root = x_root;
+        scroll.y_root = y_root;
+        scroll.delta_x = self.deltas.0;
+        scroll.delta_y = self.deltas.1;
+        scroll.is_stop = self.is_stop as u32;
+
+        event
+    }
+}
+
+/// Builder for synthetic touch events.
+#[derive(Debug, Clone)]
+#[must_use = "the builder has to be built or put to create the event"]
+pub struct EventTouchBuilder {
+    common: Common,
+    type_: EventType,
+    sequence: EventSequence,
+    position: (f64, f64),
+    root: Option<(f64, f64)>,
+    emulating_pointer: bool,
+}
+
+impl EventTouchBuilder {
+    /// Events of the same touch point have to share the same `sequence`.
+    ///
+    /// # Panics
+    ///
+    /// If `type_` is not a touch event type.
+    pub fn new(type_: EventType, window: &Window, sequence: &EventSequence) -> Self {
+        assert!(
+            matches!(
+                type_,
+                EventType::TouchBegin
+                    | EventType::TouchUpdate
+                    | EventType::TouchEnd
+                    | EventType::TouchCancel
+            ),
+            "{:?} is not a touch event type",
+            type_
+        );
+        Self {
+            common: Common::new(window),
+            type_,
+            sequence: sequence.clone(),
+            position: (0.0, 0.0),
+            root: None,
+            emulating_pointer: false,
+        }
+    }
+
+    common_setters!();
+    position_setters!();
+
+    /// Marks the touch point as the one emulating pointer events.
+    pub fn emulating_pointer(mut self, emulating_pointer: bool) -> Self {
+        self.emulating_pointer = emulating_pointer;
+        self
+    }
+
+    pub fn build(self) -> EventTouch {
+        let (x_root, y_root) = self.get_root();
+        let mut event: EventTouch = self
+            .common
+            .event(self.type_, false)
+            .downcast()
+            .expect("not a touch event");
+
+        let touch = event.as_mut();
+        touch.time = self.common.time;
+        touch.state = self.common.state.to_glib();
+        // Sequences are opaque identifiers that are never freed together with the event.
+        touch.sequence = self.sequence.to_glib_none().0 as *mut _;
+        touch.x = self.position.0;
+        touch.y = self.position.1;
+        touch.x_root = x_root;
+        touch.y_root = y_root;
+        touch.emulating_pointer = self.emulating_pointer.to_glib();
+
+        event
+    }
+}
+
+impl EventKey {
+    pub fn builder(type_: EventType, window: &Window, keyval: Key) -> EventKeyBuilder {
+        EventKeyBuilder::new(type_, window, keyval)
+    }
+}
+
+impl EventButton {
+    pub fn builder(type_: EventType, window: &Window, button: u32) -> EventButtonBuilder {
+        EventButtonBuilder::new(type_, window, button)
+    }
+}
+
+impl EventMotion {
+    pub fn builder(window: &Window) -> EventMotionBuilder {
+        EventMotionBuilder::new(window)
+    }
+}
+
+impl EventScroll {
+    pub fn builder(window: &Window, direction: ScrollDirection) -> EventScrollBuilder {
+        EventScrollBuilder::new(window, direction)
+    }
+}
+
+impl EventTouch {
+    pub fn builder(
+        type_: EventType,
+        window: &Window,
+        sequence: &EventSequence,
+    ) -> EventTouchBuilder {
+        EventTouchBuilder::new(type_, window, sequence)
+    }
+}
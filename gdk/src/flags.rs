@@ -0,0 +1,33 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(feature = "v3_22", feature = "dox"))]
+use crate::AnchorHints;
+#[cfg(any(feature = "v3_22", feature = "dox"))]
+use crate::AxisFlags;
+#[cfg(any(feature = "v3_20", feature = "dox"))]
+use crate::SeatCapabilities;
+use crate::{
+    DragAction, EventMask, FrameClockPhase, ModifierType, WMDecoration, WMFunction, WindowHints,
+    WindowState,
+};
+
+// The conversions of the generated flags, see `glib::flags_conversions!`.
+glib::flags_conversions! {
+    #[cfg(any(feature = "v3_22", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_22")))]
+    AnchorHints,
+    #[cfg(any(feature = "v3_22", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_22")))]
+    AxisFlags,
+    DragAction,
+    EventMask,
+    FrameClockPhase,
+    ModifierType,
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    SeatCapabilities,
+    WMDecoration,
+    WMFunction,
+    WindowHints,
+    WindowState,
+}
@@ -10,6 +10,20 @@ pub struct KeymapKey {
     level: i32,
 }
 
+impl KeymapKey {
+    pub fn get_keycode(&self) -> u32 {
+        self.keycode
+    }
+
+    pub fn get_group(&self) -> i32 {
+        self.group
+    }
+
+    pub fn get_level(&self) -> i32 {
+        self.level
+    }
+}
+
 #[doc(hidden)]
 impl<'a> ToGlibPtr<'a, *const ffi::GdkKeymapKey> for KeymapKey {
     type Storage = &'a Self;
@@ -33,6 +33,8 @@ mod change_data;
 mod device;
 mod device_manager;
 mod drag_context;
+mod enums;
+mod event_builder;
 mod event_button;
 mod event_configure;
 mod event_crossing;
@@ -61,6 +63,7 @@ mod event_touchpad_pinch;
 mod event_touchpad_swipe;
 mod event_visibility;
 mod event_window_state;
+mod flags;
 mod frame_clock;
 mod frame_timings;
 mod functions;
@@ -68,9 +71,11 @@ mod geometry;
 mod keymap;
 mod keymap_key;
 pub mod keys;
+mod point;
 mod rectangle;
 mod rgba;
 mod screen;
+mod size;
 mod time_coord;
 mod visual;
 mod window;
@@ -97,8 +102,14 @@ pub use crate::atom::TARGET_COLORMAP;
 pub use crate::atom::TARGET_DRAWABLE;
 pub use crate::atom::TARGET_PIXMAP;
 pub use crate::atom::TARGET_STRING;
+#[cfg(any(feature = "v3_16", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v3_16")))]
+pub use crate::cairo_interaction::pixbuf_from_surface;
 pub use crate::change_data::ChangeData;
 pub use crate::event::Event;
+pub use crate::event_builder::{
+    EventButtonBuilder, EventKeyBuilder, EventMotionBuilder, EventScrollBuilder, EventTouchBuilder,
+};
 pub use crate::event_button::EventButton;
 pub use crate::event_configure::EventConfigure;
 pub use crate::event_crossing::EventCrossing;
@@ -120,8 +131,10 @@ pub use crate::event_window_state::EventWindowState;
 pub use crate::functions::*;
 pub use crate::geometry::Geometry;
 pub use crate::keymap_key::KeymapKey;
+pub use crate::point::Point;
 pub use crate::rectangle::Rectangle;
 pub use crate::rgba::{RgbaParseError, RGBA};
+pub use crate::size::Size;
 pub use crate::time_coord::TimeCoord;
 pub use crate::window::WindowAttr;
 #[cfg(any(feature = "v3_22", feature = "dox"))]
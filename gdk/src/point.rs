@@ -0,0 +1,79 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::ops;
+
+/// A position in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Point {
+        skip_assert_initialized!();
+        Point { x, y }
+    }
+
+    /// Converts a position in Pango units to pixels, rounding to the nearest pixel.
+    pub fn from_pango_units(x: i32, y: i32) -> Point {
+        skip_assert_initialized!();
+        Point::new(pango::units_to_pixels(x), pango::units_to_pixels(y))
+    }
+
+    /// Converts a position in cairo user-space coordinates to the pixel containing it.
+    pub fn from_cairo(x: f64, y: f64) -> Point {
+        skip_assert_initialized!();
+        Point::new(x.floor() as i32, y.floor() as i32)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Point {
+        skip_assert_initialized!();
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (i32, i32) {
+    fn from(point: Point) -> (i32, i32) {
+        skip_assert_initialized!();
+        (point.x, point.y)
+    }
+}
+
+impl ops::Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl ops::AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl ops::Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl ops::SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl ops::Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
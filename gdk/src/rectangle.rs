@@ -1,10 +1,12 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::{Point, Size};
 use cairo::RectangleInt;
 use glib::ffi::gconstpointer;
 use glib::translate::*;
 use std::convert::{AsRef, From};
 use std::mem;
+use std::ops;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(C)]
@@ -16,6 +18,109 @@ pub struct Rectangle {
 }
 
 impl Rectangle {
+    /// Creates a rectangle with its top left corner at `x`, `y` and the given size.
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Rectangle {
+        skip_assert_initialized!();
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a rectangle of 1 × 1 pixels at `x`, `y`, e.g. for pointing at the position of
+    /// the pointer.
+    pub fn from_point(x: i32, y: i32) -> Rectangle {
+        skip_assert_initialized!();
+        Rectangle::new(x, y, 1, 1)
+    }
+
+    /// Creates a rectangle at `0`, `0` of the given size.
+    pub fn from_size(width: i32, height: i32) -> Rectangle {
+        skip_assert_initialized!();
+        Rectangle::new(0, 0, width, height)
+    }
+
+    /// Creates a rectangle with its top left corner at `origin` and the given size.
+    pub fn from_origin_and_size(origin: Point, size: Size) -> Rectangle {
+        skip_assert_initialized!();
+        Rectangle::new(origin.x, origin.y, size.width, size.height)
+    }
+
+    /// Converts a rectangle in Pango units to the smallest rectangle in pixels containing it.
+    pub fn from_pango_units(rect: &pango::Rectangle) -> Rectangle {
+        skip_assert_initialized!();
+        let x1 = rect.x.div_euclid(pango::SCALE);
+        let y1 = rect.y.div_euclid(pango::SCALE);
+        let x2 = (rect.x + rect.width + pango::SCALE - 1).div_euclid(pango::SCALE);
+        let y2 = (rect.y + rect.height + pango::SCALE - 1).div_euclid(pango::SCALE);
+        Rectangle::new(x1, y1, x2 - x1, y2 - y1)
+    }
+
+    /// Converts the extents `x1`, `y1`, `x2`, `y2` in cairo user-space coordinates, as e.g.
+    /// returned by `cairo::Context::fill_extents()`, to the smallest rectangle in pixels
+    /// containing them.
+    pub fn from_cairo_extents(x1: f64, y1: f64, x2: f64, y2: f64) -> Rectangle {
+        skip_assert_initialized!();
+        let (x1, y1) = (x1.floor() as i32, y1.floor() as i32);
+        let (x2, y2) = (x2.ceil() as i32, y2.ceil() as i32);
+        Rectangle::new(x1, y1, x2 - x1, y2 - y1)
+    }
+
+    /// The position of the top left corner.
+    pub fn origin(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    /// The width and height of the rectangle.
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    /// Whether the rectangle has no area.
+    pub fn is_empty(&self) -> bool {
+        self.size().is_empty()
+    }
+
+    /// Whether `point` is inside the rectangle.
+    ///
+    /// The right and bottom edges are not part of the rectangle.
+    pub fn contains_point(&self, point: Point) -> bool {
+        point.x >= self.x
+            && point.y >= self.y
+            && point.x < self.x + self.width
+            && point.y < self.y + self.height
+    }
+
+    /// Whether `other` is completely inside the rectangle.
+    pub fn contains_rectangle(&self, other: &Rectangle) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.x + other.width <= self.x + self.width
+            && other.y + other.height <= self.y + self.height
+    }
+
+    /// Returns the rectangle grown by `dx` on the left and right and by `dy` on the top and
+    /// bottom.
+    pub fn inflate(&self, dx: i32, dy: i32) -> Rectangle {
+        Rectangle::new(
+            self.x - dx,
+            self.y - dy,
+            self.width + 2 * dx,
+            self.height + 2 * dy,
+        )
+    }
+
+    /// Returns the rectangle shrunk by `dx` on the left and right and by `dy` on the top and
+    /// bottom.
+    ///
+    /// The size of the result is clamped to zero.
+    pub fn deflate(&self, dx: i32, dy: i32) -> Rectangle {
+        let rect = self.inflate(-dx, -dy);
+        Rectangle::new(rect.x, rect.y, rect.width.max(0), rect.height.max(0))
+    }
+
     #[doc(alias = "gdk_rectangle_intersect")]
     pub fn intersect(&self, other: &Rectangle) -> Option<Rectangle> {
         unsafe {
@@ -122,6 +227,29 @@ impl FromGlibPtrFull<*const ffi::GdkRectangle> for Rectangle {
     }
 }
 
+/// Moves the rectangle by the given offset.
+impl ops::Add<Point> for Rectangle {
+    type Output = Rectangle;
+
+    fn add(self, offset: Point) -> Rectangle {
+        Rectangle::new(
+            self.x + offset.x,
+            self.y + offset.y,
+            self.width,
+            self.height,
+        )
+    }
+}
+
+/// Moves the rectangle back by the given offset.
+impl ops::Sub<Point> for Rectangle {
+    type Output = Rectangle;
+
+    fn sub(self, offset: Point) -> Rectangle {
+        self + -offset
+    }
+}
+
 impl AsRef<RectangleInt> for Rectangle {
     fn as_ref(&self) -> &RectangleInt {
         unsafe { &*(self as *const _ as *const _) }
@@ -0,0 +1,49 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// A size in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Size {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Size {
+    pub fn new(width: i32, height: i32) -> Size {
+        skip_assert_initialized!();
+        Size { width, height }
+    }
+
+    /// Converts a size in Pango units to pixels, rounding to the nearest pixel.
+    pub fn from_pango_units(width: i32, height: i32) -> Size {
+        skip_assert_initialized!();
+        Size::new(
+            pango::units_to_pixels(width),
+            pango::units_to_pixels(height),
+        )
+    }
+
+    /// Converts a size in cairo user-space units to pixels, rounding up to whole pixels.
+    pub fn from_cairo(width: f64, height: f64) -> Size {
+        skip_assert_initialized!();
+        Size::new(width.ceil() as i32, height.ceil() as i32)
+    }
+
+    /// Whether the size has no area.
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+}
+
+impl From<(i32, i32)> for Size {
+    fn from((width, height): (i32, i32)) -> Size {
+        skip_assert_initialized!();
+        Size::new(width, height)
+    }
+}
+
+impl From<Size> for (i32, i32) {
+    fn from(size: Size) -> (i32, i32) {
+        skip_assert_initialized!();
+        (size.width, size.height)
+    }
+}
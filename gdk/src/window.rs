@@ -194,6 +194,30 @@ pub trait WindowExtManual: 'static {
 
     #[doc(alias = "gdk_window_set_background_pattern")]
     fn set_background_pattern(&self, pattern: Option<&cairo::Pattern>);
+
+    // rustdoc-stripper-ignore-next
+    /// Exports a handle of the window that other processes can use to refer to it, e.g. as the
+    /// `parent_window` argument of the `gio::portal` helpers.
+    ///
+    /// `callback` is called with a handle of the form `x11:<XID>` or `wayland:<handle>`, or with
+    /// `None` if exporting failed or the windowing system is not supported. On X11 `callback` is
+    /// called immediately, on Wayland once the compositor exported the window. The window stays
+    /// exported until [`unexport_handle`](Self::unexport_handle) is called.
+    ///
+    /// Support for each windowing system has to be enabled with the `x11` and `wayland`
+    /// features. Exporting Wayland windows requires GTK 3.22.
+    #[cfg(any(feature = "x11", feature = "wayland", feature = "dox"))]
+    #[doc(alias = "gdk_x11_window_get_xid")]
+    #[doc(alias = "gdk_wayland_window_export_handle")]
+    fn export_handle<F: FnOnce(&Window, Option<&str>) + 'static>(&self, callback: F);
+
+    // rustdoc-stripper-ignore-next
+    /// Revokes the handle exported with [`export_handle`](Self::export_handle) on Wayland.
+    ///
+    /// This does nothing on other windowing systems.
+    #[cfg(any(feature = "x11", feature = "wayland", feature = "dox"))]
+    #[doc(alias = "gdk_wayland_window_unexport_handle")]
+    fn unexport_handle(&self);
 }
 
 impl<O: IsA<Window>> WindowExtManual for O {
@@ -281,4 +305,81 @@ impl<O: IsA<Window>> WindowExtManual for O {
             ffi::gdk_window_set_background_pattern(self.as_ref().to_glib_none().0, ptr);
         }
     }
+
+    #[cfg(any(feature = "x11", feature = "wayland", feature = "dox"))]
+    fn export_handle<F: FnOnce(&Window, Option<&str>) + 'static>(&self, callback: F) {
+        let window = self.as_ref();
+
+        #[cfg(any(feature = "x11", feature = "dox"))]
+        {
+            if is_backend_window(window, "GdkX11Window") {
+                let xid = unsafe { ffi::gdk_x11_window_get_xid(window.to_glib_none().0) };
+                callback(window, Some(&format!("x11:{:x}", xid)));
+                return;
+            }
+        }
+
+        #[cfg(any(feature = "wayland", feature = "dox"))]
+        {
+            if is_backend_window(window, "GdkWaylandWindow") {
+                unsafe extern "C" fn exported_trampoline<
+                    F: FnOnce(&Window, Option<&str>) + 'static,
+                >(
+                    window: *mut ffi::GdkWindow,
+                    handle: *const c_char,
+                    user_data: glib::ffi::gpointer,
+                ) {
+                    let callback = &mut *(user_data as *mut Option<F>);
+                    if let Some(callback) = callback.take() {
+                        let window: Borrowed<Window> = from_glib_borrow(window);
+                        let handle: Borrowed<glib::GString> = from_glib_borrow(handle);
+                        callback(&window, Some(&format!("wayland:{}", handle.as_str())));
+                    }
+                }
+
+                unsafe extern "C" fn destroy_notify<F: FnOnce(&Window, Option<&str>) + 'static>(
+                    user_data: glib::ffi::gpointer,
+                ) {
+                    let _ = Box::from_raw(user_data as *mut Option<F>);
+                }
+
+                let user_data = Box::into_raw(Box::new(Some(callback)));
+                unsafe {
+                    let exported = ffi::gdk_wayland_window_export_handle(
+                        window.to_glib_none().0,
+                        Some(exported_trampoline::<F>),
+                        user_data as *mut _,
+                        Some(destroy_notify::<F>),
+                    );
+                    // The destroy notify is only called if exporting was started
+                    if exported == glib::ffi::GFALSE {
+                        if let Some(callback) = Box::from_raw(user_data).take() {
+                            callback(window, None);
+                        }
+                    }
+                }
+                return;
+            }
+        }
+
+        callback(window, None);
+    }
+
+    #[cfg(any(feature = "x11", feature = "wayland", feature = "dox"))]
+    fn unexport_handle(&self) {
+        #[cfg(any(feature = "wayland", feature = "dox"))]
+        {
+            let window = self.as_ref();
+            if is_backend_window(window, "GdkWaylandWindow") {
+                unsafe {
+                    ffi::gdk_wayland_window_unexport_handle(window.to_glib_none().0);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "x11", feature = "wayland", feature = "dox"))]
+fn is_backend_window(window: &Window, type_name: &str) -> bool {
+    glib::Type::from_name(type_name).map_or(false, |type_| window.get_type().is_a(type_))
 }
@@ -0,0 +1,40 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// The windowing system specific API is not part of the Gdk gir, and is only available if GDK
+// was built with the corresponding backend.
+
+#[cfg(any(feature = "wayland", feature = "dox"))]
+use glib::{gboolean, gpointer, GDestroyNotify};
+#[cfg(any(feature = "wayland", feature = "dox"))]
+use libc::c_char;
+#[cfg(any(feature = "x11", feature = "dox"))]
+use libc::c_ulong;
+
+#[cfg(any(feature = "wayland", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "wayland")))]
+pub type GdkWaylandWindowExported =
+    Option<unsafe extern "C" fn(*mut crate::GdkWindow, *const c_char, gpointer)>;
+
+extern "C" {
+    //=========================================================================
+    // GdkX11Window
+    //=========================================================================
+    #[cfg(any(feature = "x11", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "x11")))]
+    pub fn gdk_x11_window_get_xid(window: *mut crate::GdkWindow) -> c_ulong;
+
+    //=========================================================================
+    // GdkWaylandWindow
+    //=========================================================================
+    #[cfg(any(feature = "wayland", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "wayland")))]
+    pub fn gdk_wayland_window_export_handle(
+        window: *mut crate::GdkWindow,
+        callback: GdkWaylandWindowExported,
+        user_data: gpointer,
+        destroy_func: GDestroyNotify,
+    ) -> gboolean;
+    #[cfg(any(feature = "wayland", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "wayland")))]
+    pub fn gdk_wayland_window_unexport_handle(window: *mut crate::GdkWindow);
+}
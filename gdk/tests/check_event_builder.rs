@@ -0,0 +1,97 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gdk::glib::translate::*;
+use gdk::{
+    EventButton, EventKey, EventMotion, EventScroll, EventSequence, EventTouch, EventType,
+    ModifierType, ScrollDirection, Window, WindowAttr, WindowType,
+};
+
+#[test]
+fn check_event_builder() {
+    // Unlike `gdk::init`, this doesn't exit the process without a display.
+    unsafe {
+        if gdk::ffi::gdk_init_check(std::ptr::null_mut(), std::ptr::null_mut()) == 0 {
+            return;
+        }
+        gdk::set_initialized();
+    }
+
+    let window = Window::new(
+        None,
+        &WindowAttr {
+            width: 100,
+            height: 100,
+            window_type: WindowType::Offscreen,
+            ..WindowAttr::default()
+        },
+    );
+
+    let key = EventKey::builder(EventType::KeyPress, &window, gdk::keys::constants::a)
+        .time(42)
+        .state(ModifierType::SHIFT_MASK)
+        .hardware_keycode(38)
+        .group(1)
+        .send_event(true)
+        .build();
+    assert_eq!(key.get_event_type(), EventType::KeyPress);
+    assert_eq!(key.get_window(), Some(window.clone()));
+    assert!(key.get_send_event());
+    assert_eq!(key.get_time(), 42);
+    assert_eq!(key.get_state(), ModifierType::SHIFT_MASK);
+    assert_eq!(key.get_keyval(), gdk::keys::constants::a);
+    assert_eq!(key.get_length(), 1);
+    assert_eq!(key.get_hardware_keycode(), 38);
+    assert_eq!(key.get_group(), 1);
+    assert!(!key.get_is_modifier());
+
+    let button = EventButton::builder(EventType::ButtonRelease, &window, 3)
+        .position(10.5, 20.25)
+        .root(110.5, 220.25)
+        .build();
+    assert_eq!(button.get_event_type(), EventType::ButtonRelease);
+    assert_eq!(button.get_window(), Some(window.clone()));
+    assert!(!button.get_send_event());
+    assert_eq!(button.get_button(), 3);
+    assert_eq!(button.get_position(), (10.5, 20.25));
+    assert_eq!(button.get_root(), (110.5, 220.25));
+
+    let motion = EventMotion::builder(&window)
+        .position(10.5, 20.25)
+        .is_hint(true)
+        .build();
+    let (root_x, root_y) = window.get_root_coords(10, 20);
+    assert_eq!(motion.get_event_type(), EventType::MotionNotify);
+    assert_eq!(motion.get_position(), (10.5, 20.25));
+    assert_eq!(
+        motion.get_root(),
+        (root_x as f64 + 0.5, root_y as f64 + 0.25)
+    );
+    assert!(motion.get_is_hint());
+
+    let scroll = EventScroll::builder(&window, ScrollDirection::Smooth)
+        .position(5.0, 6.0)
+        .deltas(1.5, -2.0)
+        .is_stop(true)
+        .build();
+    assert_eq!(scroll.get_event_type(), EventType::Scroll);
+    assert_eq!(scroll.get_direction(), ScrollDirection::Smooth);
+    assert_eq!(scroll.get_position(), (5.0, 6.0));
+    assert_eq!(scroll.get_delta(), (1.5, -2.0));
+    assert!(scroll.get_is_stop());
+
+    // Sequences are opaque identifiers, any non-NULL pointer will do.
+    let sequence: EventSequence =
+        unsafe { from_glib_none(1usize as *mut gdk::ffi::GdkEventSequence) };
+    let touch = EventTouch::builder(EventType::TouchUpdate, &window, &sequence)
+        .position(7.0, 8.0)
+        .emulating_pointer(true)
+        .build();
+    assert_eq!(touch.get_event_type(), EventType::TouchUpdate);
+    assert_eq!(touch.get_window(), Some(window.clone()));
+    assert_eq!(touch.get_position(), (7.0, 8.0));
+    assert!(touch.is_emulating_pointer());
+    assert_eq!(
+        touch.get_event_sequence().map(|s| s.to_glib_none().0),
+        Some(sequence.to_glib_none().0)
+    );
+}
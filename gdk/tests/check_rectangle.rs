@@ -0,0 +1,48 @@
+use gdk::{Point, Rectangle, Size};
+
+#[test]
+fn check_rectangle() {
+    let rect = Rectangle::new(10, 20, 30, 40);
+    assert_eq!(rect.origin(), Point::new(10, 20));
+    assert_eq!(rect.size(), Size::new(30, 40));
+    assert_eq!(
+        Rectangle::from_origin_and_size(rect.origin(), rect.size()),
+        rect
+    );
+
+    assert!(rect.contains_point(Point::new(10, 20)));
+    assert!(rect.contains_point(Point::new(39, 59)));
+    assert!(!rect.contains_point(Point::new(40, 59)));
+    assert!(rect.contains_rectangle(&Rectangle::new(15, 25, 5, 5)));
+    assert!(!rect.contains_rectangle(&Rectangle::new(15, 25, 50, 5)));
+
+    assert_eq!(rect.inflate(5, 10), Rectangle::new(5, 10, 40, 60));
+    assert_eq!(rect.deflate(5, 10), Rectangle::new(15, 30, 20, 20));
+    assert!(rect.deflate(20, 0).is_empty());
+    assert_eq!(rect + Point::new(1, 2), Rectangle::new(11, 22, 30, 40));
+    assert_eq!(rect - Point::new(1, 2), Rectangle::new(9, 18, 30, 40));
+
+    assert_eq!(rect.intersect(&Rectangle::new(100, 100, 1, 1)), None);
+    assert_eq!(
+        rect.union(&Rectangle::new(0, 0, 1, 1)),
+        Rectangle::new(0, 0, 40, 60)
+    );
+}
+
+#[test]
+fn check_rectangle_conversions() {
+    assert_eq!(Point::from_pango_units(1536, -1536), Point::new(2, -1));
+    assert_eq!(Size::from_pango_units(1024, 511), Size::new(1, 0));
+    assert_eq!(Point::from_cairo(1.5, -0.5), Point::new(1, -1));
+    assert_eq!(Size::from_cairo(1.5, 2.0), Size::new(2, 2));
+
+    let rect = gdk::pango::Rectangle::new(512, -512, 1024, 1024);
+    assert_eq!(
+        Rectangle::from_pango_units(&rect),
+        Rectangle::new(0, -1, 2, 2)
+    );
+    assert_eq!(
+        Rectangle::from_cairo_extents(0.5, 1.5, 2.5, 3.0),
+        Rectangle::new(0, 1, 3, 2)
+    );
+}
@@ -1,19 +1,43 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::prelude::*;
 use crate::Application;
 use crate::File;
+use crate::SimpleAction;
+use futures_core::stream::Stream;
 use glib::object::Cast;
 use glib::object::IsA;
 use glib::signal::{connect_raw, SignalHandlerId};
 use glib::translate::*;
-use glib::GString;
+use glib::{FromVariant, GString, ToVariant, Variant, VariantTy};
 use std::boxed::Box as Box_;
 use std::mem::transmute;
+use std::pin::Pin;
+
+const MESSAGE_ACTION: &str = "gtk-rs-message";
 
 pub trait ApplicationExtManual {
     #[doc(alias = "g_application_run")]
     fn run(&self, argv: &[String]) -> i32;
     fn connect_open<F: Fn(&Self, &[File], &str) + 'static>(&self, f: F) -> SignalHandlerId;
+
+    // rustdoc-stripper-ignore-next
+    /// Sends `message` to the primary instance of the application, where it is received by the
+    /// streams returned by [`message_stream`](Self::message_stream).
+    ///
+    /// The message is delivered by activating an application action, so the application has to
+    /// be registered. If this is the primary instance the message is delivered locally.
+    fn send_message<T: ToVariant>(&self, message: &T);
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a `Stream` of the messages sent with [`send_message`](Self::send_message) to
+    /// this instance of the application.
+    ///
+    /// Messages that can't be converted to `T` are skipped. The application action receiving
+    /// the messages is added on the first call, which has to happen before the application is
+    /// registered for the action to be exported to other instances.
+    fn message_stream<T: FromVariant + 'static>(&self)
+        -> Pin<Box_<dyn Stream<Item = T> + 'static>>;
 }
 
 impl<O: IsA<Application>> ApplicationExtManual for O {
@@ -54,4 +78,66 @@ impl<O: IsA<Application>> ApplicationExtManual for O {
             )
         }
     }
+
+    fn send_message<T: ToVariant>(&self, message: &T) {
+        let message = Variant::variant(&message.to_variant());
+        self.activate_action(MESSAGE_ACTION, Some(&message));
+    }
+
+    fn message_stream<T: FromVariant + 'static>(
+        &self,
+    ) -> Pin<Box_<dyn Stream<Item = T> + 'static>> {
+        let action = match self
+            .lookup_action(MESSAGE_ACTION)
+            .and_then(|action| action.downcast::<SimpleAction>().ok())
+        {
+            Some(action) => action,
+            None => {
+                let action = SimpleAction::new(MESSAGE_ACTION, Some(VariantTy::new("v").unwrap()));
+                self.add_action(&action);
+                action
+            }
+        };
+
+        Box_::pin(glib::signal_stream(&action, |action, sender| {
+            vec![action.connect_activate(move |_, parameter| {
+                if let Some(message) = parameter
+                    .and_then(|parameter| parameter.get_variant())
+                    .and_then(|message| message.get::<T>())
+                {
+                    let _ = sender.unbounded_send(message);
+                }
+            })]
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::run_async;
+    use crate::{ApplicationFlags, Cancellable};
+    use futures_util::StreamExt;
+
+    #[test]
+    fn message_stream() {
+        let res = run_async(|tx, l| {
+            let app = Application::new(None, ApplicationFlags::NON_UNIQUE);
+            let mut stream = app.message_stream::<String>();
+            app.register(None::<&Cancellable>).unwrap();
+
+            app.send_message(&42u32);
+            app.send_message(&"hello");
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let message = stream.next().await;
+                tx.send(message).unwrap();
+                l.quit();
+                drop(app);
+            });
+        });
+
+        assert_eq!(res.as_deref(), Some("hello"));
+    }
 }
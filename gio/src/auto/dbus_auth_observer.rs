@@ -52,12 +52,14 @@ impl DBusAuthObserver {
         }
     }
 
-    pub fn connect_allow_mechanism<F: Fn(&DBusAuthObserver, &str) -> bool + 'static>(
+    pub fn connect_allow_mechanism<
+        F: Fn(&DBusAuthObserver, &str) -> bool + Send + Sync + 'static,
+    >(
         &self,
         f: F,
     ) -> SignalHandlerId {
         unsafe extern "C" fn allow_mechanism_trampoline<
-            F: Fn(&DBusAuthObserver, &str) -> bool + 'static,
+            F: Fn(&DBusAuthObserver, &str) -> bool + Send + Sync + 'static,
         >(
             this: *mut ffi::GDBusAuthObserver,
             mechanism: *mut libc::c_char,
@@ -84,13 +86,13 @@ impl DBusAuthObserver {
     }
 
     pub fn connect_authorize_authenticated_peer<
-        F: Fn(&DBusAuthObserver, &IOStream, Option<&Credentials>) -> bool + 'static,
+        F: Fn(&DBusAuthObserver, &IOStream, Option<&Credentials>) -> bool + Send + Sync + 'static,
     >(
         &self,
         f: F,
     ) -> SignalHandlerId {
         unsafe extern "C" fn authorize_authenticated_peer_trampoline<
-            F: Fn(&DBusAuthObserver, &IOStream, Option<&Credentials>) -> bool + 'static,
+            F: Fn(&DBusAuthObserver, &IOStream, Option<&Credentials>) -> bool + Send + Sync + 'static,
         >(
             this: *mut ffi::GDBusAuthObserver,
             stream: *mut ffi::GIOStream,
@@ -127,6 +129,9 @@ impl Default for DBusAuthObserver {
     }
 }
 
+unsafe impl Send for DBusAuthObserver {}
+unsafe impl Sync for DBusAuthObserver {}
+
 impl fmt::Display for DBusAuthObserver {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str("DBusAuthObserver")
@@ -141,12 +141,6 @@ pub trait FileExt: 'static {
         progress_callback: Option<&mut dyn (FnMut(i64, i64))>,
     ) -> Result<(), glib::Error>;
 
-    //#[doc(alias = "g_file_copy_async")]
-    //fn copy_async<P: IsA<File>, Q: IsA<Cancellable>, R: FnOnce(Result<(), glib::Error>) + Send + 'static, S: FnOnce(Result<(), glib::Error>) + Send + 'static>(&self, destination: &P, flags: FileCopyFlags, io_priority: glib::Priority, cancellable: Option<&Q>, progress_callback: R, callback: S);
-
-    //
-    //fn copy_async_future<P: IsA<File> + Clone + 'static, R: FnOnce(Result<(), glib::Error>) + Send + 'static>(&self, destination: &P, flags: FileCopyFlags, io_priority: glib::Priority, progress_callback: R) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>>;
-
     #[doc(alias = "g_file_copy_attributes")]
     fn copy_attributes<P: IsA<File>, Q: IsA<Cancellable>>(
         &self,
@@ -251,6 +245,26 @@ pub trait FileExt: 'static {
         cancellable: Option<&P>,
     ) -> Result<FileEnumerator, glib::Error>;
 
+    #[doc(alias = "g_file_enumerate_children_async")]
+    fn enumerate_children_async<
+        P: IsA<Cancellable>,
+        Q: FnOnce(Result<FileEnumerator, glib::Error>) + Send + 'static,
+    >(
+        &self,
+        attributes: &str,
+        flags: FileQueryInfoFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&P>,
+        callback: Q,
+    );
+
+    fn enumerate_children_async_future(
+        &self,
+        attributes: &str,
+        flags: FileQueryInfoFlags,
+        io_priority: glib::Priority,
+    ) -> Pin<Box_<dyn std::future::Future<Output = Result<FileEnumerator, glib::Error>> + 'static>>;
+
     #[doc(alias = "g_file_equal")]
     fn equal<P: IsA<File>>(&self, file2: &P) -> bool;
 
@@ -260,6 +274,22 @@ pub trait FileExt: 'static {
         cancellable: Option<&P>,
     ) -> Result<Mount, glib::Error>;
 
+    #[doc(alias = "g_file_find_enclosing_mount_async")]
+    fn find_enclosing_mount_async<
+        P: IsA<Cancellable>,
+        Q: FnOnce(Result<Mount, glib::Error>) + Send + 'static,
+    >(
+        &self,
+        io_priority: glib::Priority,
+        cancellable: Option<&P>,
+        callback: Q,
+    );
+
+    fn find_enclosing_mount_async_future(
+        &self,
+        io_priority: glib::Priority,
+    ) -> Pin<Box_<dyn std::future::Future<Output = Result<Mount, glib::Error>> + 'static>>;
+
     #[doc(alias = "g_file_get_basename")]
     fn get_basename(&self) -> Option<std::path::PathBuf>;
 
@@ -356,12 +386,6 @@ pub trait FileExt: 'static {
         >,
     >;
 
-    //#[doc(alias = "g_file_load_partial_contents_async")]
-    //fn load_partial_contents_async<P: IsA<Cancellable>, Q: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static, R: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static>(&self, cancellable: Option<&P>, read_more_callback: Q, callback: R);
-
-    //
-    //fn load_partial_contents_async_future<Q: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static>(&self, read_more_callback: Q) -> Pin<Box_<dyn std::future::Future<Output = Result<(Vec<u8>, Option<glib::GString>), glib::Error>> + 'static>>;
-
     #[doc(alias = "g_file_make_directory")]
     fn make_directory<P: IsA<Cancellable>>(
         &self,
@@ -405,12 +429,6 @@ pub trait FileExt: 'static {
         progress_callback: Option<Box_<dyn Fn(bool, u64, u64, u64) + 'static>>,
     ) -> Result<(u64, u64, u64), glib::Error>;
 
-    //#[doc(alias = "g_file_measure_disk_usage_async")]
-    //fn measure_disk_usage_async<P: IsA<Cancellable>, Q: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static, R: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static>(&self, flags: FileMeasureFlags, io_priority: glib::Priority, cancellable: Option<&P>, progress_callback: Q, callback: R);
-
-    //
-    //fn measure_disk_usage_async_future<Q: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static>(&self, flags: FileMeasureFlags, io_priority: glib::Priority, progress_callback: Q) -> Pin<Box_<dyn std::future::Future<Output = Result<(u64, u64, u64), glib::Error>> + 'static>>;
-
     #[doc(alias = "g_file_monitor")]
     fn monitor<P: IsA<Cancellable>>(
         &self,
@@ -675,9 +693,6 @@ pub trait FileExt: 'static {
         cancellable: Option<&P>,
     ) -> Result<Option<glib::GString>, glib::Error>;
 
-    //#[doc(alias = "g_file_replace_contents_bytes_async")]
-    //fn replace_contents_bytes_async<P: IsA<Cancellable>, Q: FnOnce(Result<(), glib::Error>) + 'static>(&self, contents: &glib::Bytes, etag: Option<&str>, make_backup: bool, flags: FileCreateFlags, cancellable: Option<&P>, callback: Q);
-
     #[doc(alias = "g_file_replace_readwrite")]
     fn replace_readwrite<P: IsA<Cancellable>>(
         &self,
@@ -1024,32 +1039,6 @@ impl<O: IsA<File>> FileExt for O {
         }
     }
 
-    //fn copy_async<P: IsA<File>, Q: IsA<Cancellable>, R: FnOnce(Result<(), glib::Error>) + Send + 'static, S: FnOnce(Result<(), glib::Error>) + Send + 'static>(&self, destination: &P, flags: FileCopyFlags, io_priority: glib::Priority, cancellable: Option<&Q>, progress_callback: R, callback: S) {
-    //    unsafe { TODO: call ffi:g_file_copy_async() }
-    //}
-
-    //
-    //fn copy_async_future<P: IsA<File> + Clone + 'static, R: FnOnce(Result<(), glib::Error>) + Send + 'static>(&self, destination: &P, flags: FileCopyFlags, io_priority: glib::Priority, progress_callback: R) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
-
-    //let destination = destination.clone();
-    //let progress_callback = progress_callback.map(ToOwned::to_owned);
-    //Box_::pin(crate::GioFuture::new(self, move |obj, send| {
-    //    let cancellable = Cancellable::new();
-    //    obj.copy_async(
-    //        &destination,
-    //        flags,
-    //        io_priority,
-    //        Some(&cancellable),
-    //        progress_callback.as_ref().map(::std::borrow::Borrow::borrow),
-    //        move |res| {
-    //            send.resolve(res);
-    //        },
-    //    );
-
-    //    cancellable
-    //}))
-    //}
-
     fn copy_attributes<P: IsA<File>, Q: IsA<Cancellable>>(
         &self,
         destination: &P,
@@ -1390,6 +1379,74 @@ impl<O: IsA<File>> FileExt for O {
         }
     }
 
+    fn enumerate_children_async<
+        P: IsA<Cancellable>,
+        Q: FnOnce(Result<FileEnumerator, glib::Error>) + Send + 'static,
+    >(
+        &self,
+        attributes: &str,
+        flags: FileQueryInfoFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&P>,
+        callback: Q,
+    ) {
+        let user_data: Box_<Q> = Box_::new(callback);
+        unsafe extern "C" fn enumerate_children_async_trampoline<
+            Q: FnOnce(Result<FileEnumerator, glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut crate::ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut error = ptr::null_mut();
+            let ret =
+                ffi::g_file_enumerate_children_finish(_source_object as *mut _, res, &mut error);
+            let result = if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                Err(from_glib_full(error))
+            };
+            let callback: Box_<Q> = Box_::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        let callback = enumerate_children_async_trampoline::<Q>;
+        unsafe {
+            ffi::g_file_enumerate_children_async(
+                self.as_ref().to_glib_none().0,
+                attributes.to_glib_none().0,
+                flags.to_glib(),
+                io_priority.to_glib(),
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                Some(callback),
+                Box_::into_raw(user_data) as *mut _,
+            );
+        }
+    }
+
+    fn enumerate_children_async_future(
+        &self,
+        attributes: &str,
+        flags: FileQueryInfoFlags,
+        io_priority: glib::Priority,
+    ) -> Pin<Box_<dyn std::future::Future<Output = Result<FileEnumerator, glib::Error>> + 'static>>
+    {
+        let attributes = String::from(attributes);
+        Box_::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.enumerate_children_async(
+                &attributes,
+                flags,
+                io_priority,
+                Some(&cancellable),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+
+            cancellable
+        }))
+    }
+
     fn equal<P: IsA<File>>(&self, file2: &P) -> bool {
         unsafe {
             from_glib(ffi::g_file_equal(
@@ -1418,6 +1475,60 @@ impl<O: IsA<File>> FileExt for O {
         }
     }
 
+    fn find_enclosing_mount_async<
+        P: IsA<Cancellable>,
+        Q: FnOnce(Result<Mount, glib::Error>) + Send + 'static,
+    >(
+        &self,
+        io_priority: glib::Priority,
+        cancellable: Option<&P>,
+        callback: Q,
+    ) {
+        let user_data: Box_<Q> = Box_::new(callback);
+        unsafe extern "C" fn find_enclosing_mount_async_trampoline<
+            Q: FnOnce(Result<Mount, glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut crate::ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut error = ptr::null_mut();
+            let ret =
+                ffi::g_file_find_enclosing_mount_finish(_source_object as *mut _, res, &mut error);
+            let result = if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                Err(from_glib_full(error))
+            };
+            let callback: Box_<Q> = Box_::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        let callback = find_enclosing_mount_async_trampoline::<Q>;
+        unsafe {
+            ffi::g_file_find_enclosing_mount_async(
+                self.as_ref().to_glib_none().0,
+                io_priority.to_glib(),
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                Some(callback),
+                Box_::into_raw(user_data) as *mut _,
+            );
+        }
+    }
+
+    fn find_enclosing_mount_async_future(
+        &self,
+        io_priority: glib::Priority,
+    ) -> Pin<Box_<dyn std::future::Future<Output = Result<Mount, glib::Error>> + 'static>> {
+        Box_::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.find_enclosing_mount_async(io_priority, Some(&cancellable), move |res| {
+                send.resolve(res);
+            });
+
+            cancellable
+        }))
+    }
+
     fn get_basename(&self) -> Option<std::path::PathBuf> {
         unsafe { from_glib_full(ffi::g_file_get_basename(self.as_ref().to_glib_none().0)) }
     }
@@ -1692,27 +1803,6 @@ impl<O: IsA<File>> FileExt for O {
         }))
     }
 
-    //fn load_partial_contents_async<P: IsA<Cancellable>, Q: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static, R: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static>(&self, cancellable: Option<&P>, read_more_callback: Q, callback: R) {
-    //    unsafe { TODO: call ffi:g_file_load_partial_contents_async() }
-    //}
-
-    //
-    //fn load_partial_contents_async_future<Q: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static>(&self, read_more_callback: Q) -> Pin<Box_<dyn std::future::Future<Output = Result<(Vec<u8>, Option<glib::GString>), glib::Error>> + 'static>> {
-
-    //Box_::pin(crate::GioFuture::new(self, move |obj, send| {
-    //    let cancellable = Cancellable::new();
-    //    obj.load_partial_contents_async(
-    //        Some(&cancellable),
-    //        read_more_callback,
-    //        move |res| {
-    //            send.resolve(res);
-    //        },
-    //    );
-
-    //    cancellable
-    //}))
-    //}
-
     fn make_directory<P: IsA<Cancellable>>(
         &self,
         cancellable: Option<&P>,
@@ -1883,30 +1973,6 @@ impl<O: IsA<File>> FileExt for O {
         }
     }
 
-    //fn measure_disk_usage_async<P: IsA<Cancellable>, Q: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static, R: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static>(&self, flags: FileMeasureFlags, io_priority: glib::Priority, cancellable: Option<&P>, progress_callback: Q, callback: R) {
-    //    unsafe { TODO: call ffi:g_file_measure_disk_usage_async() }
-    //}
-
-    //
-    //fn measure_disk_usage_async_future<Q: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static>(&self, flags: FileMeasureFlags, io_priority: glib::Priority, progress_callback: Q) -> Pin<Box_<dyn std::future::Future<Output = Result<(u64, u64, u64), glib::Error>> + 'static>> {
-
-    //let progress_callback = progress_callback.map(ToOwned::to_owned);
-    //Box_::pin(crate::GioFuture::new(self, move |obj, send| {
-    //    let cancellable = Cancellable::new();
-    //    obj.measure_disk_usage_async(
-    //        flags,
-    //        io_priority,
-    //        Some(&cancellable),
-    //        progress_callback.as_ref().map(::std::borrow::Borrow::borrow),
-    //        move |res| {
-    //            send.resolve(res);
-    //        },
-    //    );
-
-    //    cancellable
-    //}))
-    //}
-
     fn monitor<P: IsA<Cancellable>>(
         &self,
         flags: FileMonitorFlags,
@@ -2753,10 +2819,6 @@ impl<O: IsA<File>> FileExt for O {
         }
     }
 
-    //fn replace_contents_bytes_async<P: IsA<Cancellable>, Q: FnOnce(Result<(), glib::Error>) + 'static>(&self, contents: &glib::Bytes, etag: Option<&str>, make_backup: bool, flags: FileCreateFlags, cancellable: Option<&P>, callback: Q) {
-    //    unsafe { TODO: call ffi:g_file_replace_contents_bytes_async() }
-    //}
-
     fn replace_readwrite<P: IsA<Cancellable>>(
         &self,
         etag: Option<&str>,
@@ -274,6 +274,9 @@ mod mount_operation;
 pub use self::mount_operation::MountOperationExt;
 pub use self::mount_operation::{MountOperation, NONE_MOUNT_OPERATION};
 
+mod native_socket_address;
+pub use self::native_socket_address::{NativeSocketAddress, NONE_NATIVE_SOCKET_ADDRESS};
+
 mod network_address;
 pub use self::network_address::NetworkAddressExt;
 pub use self::network_address::{NetworkAddress, NONE_NETWORK_ADDRESS};
@@ -305,6 +308,16 @@ mod pollable_output_stream;
 pub use self::pollable_output_stream::PollableOutputStreamExt;
 pub use self::pollable_output_stream::{PollableOutputStream, NONE_POLLABLE_OUTPUT_STREAM};
 
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+mod power_profile_monitor;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+pub use self::power_profile_monitor::PowerProfileMonitorExt;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+pub use self::power_profile_monitor::{PowerProfileMonitor, NONE_POWER_PROFILE_MONITOR};
+
 mod property_action;
 pub use self::property_action::PropertyAction;
 
@@ -540,17 +553,6 @@ pub use self::settings_schema_source::SettingsSchemaSource;
 mod srv_target;
 pub use self::srv_target::SrvTarget;
 
-#[cfg(any(unix, feature = "dox"))]
-#[cfg_attr(feature = "dox", doc(cfg(unix)))]
-#[cfg(any(feature = "v2_54", feature = "dox"))]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_54")))]
-mod unix_mount_entry;
-#[cfg(any(unix, feature = "dox"))]
-#[cfg_attr(feature = "dox", doc(cfg(unix)))]
-#[cfg(any(feature = "v2_54", feature = "dox"))]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_54")))]
-pub use self::unix_mount_entry::UnixMountEntry;
-
 #[cfg(any(unix, feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(unix)))]
 #[cfg(any(feature = "v2_54", feature = "dox"))]
@@ -826,6 +828,9 @@ pub mod traits {
     pub use super::PermissionExt;
     pub use super::PollableInputStreamExt;
     pub use super::PollableOutputStreamExt;
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    pub use super::PowerProfileMonitorExt;
     pub use super::ProxyAddressExt;
     pub use super::ProxyExt;
     pub use super::ProxyResolverExt;
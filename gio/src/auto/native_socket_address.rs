@@ -0,0 +1,26 @@
+// This file was generated by gir (https://github.com/gtk-rs/gir)
+// from gir-files (https://github.com/gtk-rs/gir-files)
+// DO NOT EDIT
+
+use crate::SocketAddress;
+use crate::SocketConnectable;
+use std::fmt;
+
+glib::wrapper! {
+    pub struct NativeSocketAddress(Object<ffi::GNativeSocketAddress, ffi::GNativeSocketAddressClass>) @extends SocketAddress, @implements SocketConnectable;
+
+    match fn {
+        get_type => || ffi::g_native_socket_address_get_type(),
+    }
+}
+
+unsafe impl Send for NativeSocketAddress {}
+unsafe impl Sync for NativeSocketAddress {}
+
+pub const NONE_NATIVE_SOCKET_ADDRESS: Option<&NativeSocketAddress> = None;
+
+impl fmt::Display for NativeSocketAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("NativeSocketAddress")
+    }
+}
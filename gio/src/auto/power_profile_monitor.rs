@@ -0,0 +1,104 @@
+// This file was generated by gir (https://github.com/gtk-rs/gir)
+// from gir-files (https://github.com/gtk-rs/gir-files)
+// DO NOT EDIT
+
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+use glib::object::Cast;
+use glib::object::IsA;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+use glib::signal::connect_raw;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+use glib::signal::SignalHandlerId;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+use glib::translate::*;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+use std::boxed::Box as Box_;
+use std::fmt;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+use std::mem::transmute;
+
+glib::wrapper! {
+    pub struct PowerProfileMonitor(Interface<ffi::GPowerProfileMonitor, ffi::GPowerProfileMonitorInterface>);
+
+    match fn {
+        get_type => || ffi::g_power_profile_monitor_get_type(),
+    }
+}
+
+impl PowerProfileMonitor {
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    #[doc(alias = "g_power_profile_monitor_dup_default")]
+    pub fn dup_default() -> PowerProfileMonitor {
+        unsafe { from_glib_full(ffi::g_power_profile_monitor_dup_default()) }
+    }
+}
+
+pub const NONE_POWER_PROFILE_MONITOR: Option<&PowerProfileMonitor> = None;
+
+pub trait PowerProfileMonitorExt: 'static {
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    #[doc(alias = "g_power_profile_monitor_get_power_saver_enabled")]
+    fn get_power_saver_enabled(&self) -> bool;
+
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    fn connect_property_power_saver_enabled_notify<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+}
+
+impl<O: IsA<PowerProfileMonitor>> PowerProfileMonitorExt for O {
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    fn get_power_saver_enabled(&self) -> bool {
+        unsafe {
+            from_glib(ffi::g_power_profile_monitor_get_power_saver_enabled(
+                self.as_ref().to_glib_none().0,
+            ))
+        }
+    }
+
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    fn connect_property_power_saver_enabled_notify<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        unsafe extern "C" fn notify_power_saver_enabled_trampoline<P, F: Fn(&P) + 'static>(
+            this: *mut ffi::GPowerProfileMonitor,
+            _param_spec: glib::ffi::gpointer,
+            f: glib::ffi::gpointer,
+        ) where
+            P: IsA<PowerProfileMonitor>,
+        {
+            let f: &F = &*(f as *const F);
+            f(&PowerProfileMonitor::from_glib_borrow(this).unsafe_cast_ref())
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(
+                self.as_ptr() as *mut _,
+                b"notify::power-saver-enabled\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(
+                    notify_power_saver_enabled_trampoline::<Self, F> as *const (),
+                )),
+                Box_::into_raw(f),
+            )
+        }
+    }
+}
+
+impl fmt::Display for PowerProfileMonitor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("PowerProfileMonitor")
+    }
+}
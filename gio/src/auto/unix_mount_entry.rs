@@ -1,14 +0,0 @@
-// This file was generated by gir (https://github.com/gtk-rs/gir)
-// from gir-files (https://github.com/gtk-rs/gir-files)
-// DO NOT EDIT
-
-glib::wrapper! {
-    #[derive(Debug)]
-    pub struct UnixMountEntry(Boxed<ffi::GUnixMountEntry>);
-
-    match fn {
-        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::g_unix_mount_entry_get_type(), ptr as *mut _) as *mut ffi::GUnixMountEntry,
-        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::g_unix_mount_entry_get_type(), ptr as *mut _),
-        get_type => || ffi::g_unix_mount_entry_get_type(),
-    }
-}
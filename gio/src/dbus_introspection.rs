@@ -0,0 +1,333 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Accessors and constructors for the DBus introspection data structures.
+//!
+//! The introspection data can either be parsed from XML with [`DBusNodeInfo::new_for_xml`] or
+//! be built programmatically, and can be turned back into XML with [`DBusNodeInfo::to_xml`].
+
+use crate::{
+    DBusArgInfo, DBusInterfaceInfo, DBusMethodInfo, DBusNodeInfo, DBusPropertyInfo,
+    DBusPropertyInfoFlags, DBusSignalInfo,
+};
+use glib::translate::*;
+use std::ffi::CStr;
+
+unsafe fn borrow_str<'a>(ptr: *const libc::c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_str().unwrap())
+    }
+}
+
+unsafe fn borrow_array<T, P: 'static>(ptr: *mut *mut P) -> Vec<T>
+where
+    T: FromGlibPtrArrayContainerAsVec<*mut P, *mut *mut P>,
+{
+    if ptr.is_null() {
+        Vec::new()
+    } else {
+        T::from_glib_none_as_vec(ptr)
+    }
+}
+
+unsafe fn lookup_annotation<'a>(
+    annotations: *mut *mut ffi::GDBusAnnotationInfo,
+    name: &str,
+) -> Option<&'a str> {
+    if annotations.is_null() {
+        return None;
+    }
+    borrow_str(ffi::g_dbus_annotation_info_lookup(
+        annotations,
+        name.to_glib_none().0,
+    ))
+}
+
+// Allocates a new, zeroed introspection struct. Once the last reference is gone, GLib frees the
+// struct and its strings with g_free() and drops the references to all contained infos.
+unsafe fn new_info<T>() -> *mut T {
+    glib::ffi::g_malloc0(std::mem::size_of::<T>()) as *mut T
+}
+
+impl DBusArgInfo {
+    pub fn new(name: Option<&str>, signature: &str) -> DBusArgInfo {
+        unsafe {
+            let ptr = new_info::<ffi::GDBusArgInfo>();
+            (*ptr).ref_count = 1;
+            (*ptr).name = name.to_glib_full();
+            (*ptr).signature = signature.to_glib_full();
+            from_glib_full(ptr)
+        }
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        unsafe { borrow_str((*self.to_glib_none().0).name) }
+    }
+
+    pub fn get_signature(&self) -> &str {
+        unsafe { borrow_str((*self.to_glib_none().0).signature).unwrap() }
+    }
+
+    #[doc(alias = "g_dbus_annotation_info_lookup")]
+    pub fn lookup_annotation(&self, name: &str) -> Option<&str> {
+        unsafe { lookup_annotation((*self.to_glib_none().0).annotations, name) }
+    }
+}
+
+impl DBusMethodInfo {
+    pub fn new(name: &str, in_args: &[DBusArgInfo], out_args: &[DBusArgInfo]) -> DBusMethodInfo {
+        unsafe {
+            let ptr = new_info::<ffi::GDBusMethodInfo>();
+            (*ptr).ref_count = 1;
+            (*ptr).name = name.to_glib_full();
+            (*ptr).in_args = ToGlibContainerFromSlice::to_glib_full_from_slice(in_args);
+            (*ptr).out_args = ToGlibContainerFromSlice::to_glib_full_from_slice(out_args);
+            from_glib_full(ptr)
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        unsafe { borrow_str((*self.to_glib_none().0).name).unwrap() }
+    }
+
+    pub fn get_in_args(&self) -> Vec<DBusArgInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).in_args) }
+    }
+
+    pub fn get_out_args(&self) -> Vec<DBusArgInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).out_args) }
+    }
+
+    #[doc(alias = "g_dbus_annotation_info_lookup")]
+    pub fn lookup_annotation(&self, name: &str) -> Option<&str> {
+        unsafe { lookup_annotation((*self.to_glib_none().0).annotations, name) }
+    }
+}
+
+impl DBusSignalInfo {
+    pub fn new(name: &str, args: &[DBusArgInfo]) -> DBusSignalInfo {
+        unsafe {
+            let ptr = new_info::<ffi::GDBusSignalInfo>();
+            (*ptr).ref_count = 1;
+            (*ptr).name = name.to_glib_full();
+            (*ptr).args = ToGlibContainerFromSlice::to_glib_full_from_slice(args);
+            from_glib_full(ptr)
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        unsafe { borrow_str((*self.to_glib_none().0).name).unwrap() }
+    }
+
+    pub fn get_args(&self) -> Vec<DBusArgInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).args) }
+    }
+
+    #[doc(alias = "g_dbus_annotation_info_lookup")]
+    pub fn lookup_annotation(&self, name: &str) -> Option<&str> {
+        unsafe { lookup_annotation((*self.to_glib_none().0).annotations, name) }
+    }
+}
+
+impl DBusPropertyInfo {
+    pub fn new(name: &str, signature: &str, flags: DBusPropertyInfoFlags) -> DBusPropertyInfo {
+        unsafe {
+            let ptr = new_info::<ffi::GDBusPropertyInfo>();
+            (*ptr).ref_count = 1;
+            (*ptr).name = name.to_glib_full();
+            (*ptr).signature = signature.to_glib_full();
+            (*ptr).flags = flags.to_glib();
+            from_glib_full(ptr)
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        unsafe { borrow_str((*self.to_glib_none().0).name).unwrap() }
+    }
+
+    pub fn get_signature(&self) -> &str {
+        unsafe { borrow_str((*self.to_glib_none().0).signature).unwrap() }
+    }
+
+    pub fn get_flags(&self) -> DBusPropertyInfoFlags {
+        unsafe { from_glib((*self.to_glib_none().0).flags) }
+    }
+
+    #[doc(alias = "g_dbus_annotation_info_lookup")]
+    pub fn lookup_annotation(&self, name: &str) -> Option<&str> {
+        unsafe { lookup_annotation((*self.to_glib_none().0).annotations, name) }
+    }
+}
+
+impl DBusInterfaceInfo {
+    pub fn new(
+        name: &str,
+        methods: &[DBusMethodInfo],
+        signals: &[DBusSignalInfo],
+        properties: &[DBusPropertyInfo],
+    ) -> DBusInterfaceInfo {
+        unsafe {
+            let ptr = new_info::<ffi::GDBusInterfaceInfo>();
+            (*ptr).ref_count = 1;
+            (*ptr).name = name.to_glib_full();
+            (*ptr).methods = ToGlibContainerFromSlice::to_glib_full_from_slice(methods);
+            (*ptr).signals = ToGlibContainerFromSlice::to_glib_full_from_slice(signals);
+            (*ptr).properties = ToGlibContainerFromSlice::to_glib_full_from_slice(properties);
+            from_glib_full(ptr)
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        unsafe { borrow_str((*self.to_glib_none().0).name).unwrap() }
+    }
+
+    pub fn get_methods(&self) -> Vec<DBusMethodInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).methods) }
+    }
+
+    pub fn get_signals(&self) -> Vec<DBusSignalInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).signals) }
+    }
+
+    pub fn get_properties(&self) -> Vec<DBusPropertyInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).properties) }
+    }
+
+    #[doc(alias = "g_dbus_annotation_info_lookup")]
+    pub fn lookup_annotation(&self, name: &str) -> Option<&str> {
+        unsafe { lookup_annotation((*self.to_glib_none().0).annotations, name) }
+    }
+
+    /// Returns the XML representation of this interface.
+    pub fn to_xml(&self) -> String {
+        let mut s = glib::String::new("");
+        self.generate_xml(0, &mut s);
+        s.to_string_lossy().into_owned()
+    }
+}
+
+impl DBusNodeInfo {
+    pub fn new(
+        path: Option<&str>,
+        interfaces: &[DBusInterfaceInfo],
+        nodes: &[DBusNodeInfo],
+    ) -> DBusNodeInfo {
+        unsafe {
+            let ptr = new_info::<ffi::GDBusNodeInfo>();
+            (*ptr).ref_count = 1;
+            (*ptr).path = path.to_glib_full();
+            (*ptr).interfaces = ToGlibContainerFromSlice::to_glib_full_from_slice(interfaces);
+            (*ptr).nodes = ToGlibContainerFromSlice::to_glib_full_from_slice(nodes);
+            from_glib_full(ptr)
+        }
+    }
+
+    pub fn get_path(&self) -> Option<&str> {
+        unsafe { borrow_str((*self.to_glib_none().0).path) }
+    }
+
+    pub fn get_interfaces(&self) -> Vec<DBusInterfaceInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).interfaces) }
+    }
+
+    pub fn get_nodes(&self) -> Vec<DBusNodeInfo> {
+        unsafe { borrow_array((*self.to_glib_none().0).nodes) }
+    }
+
+    #[doc(alias = "g_dbus_annotation_info_lookup")]
+    pub fn lookup_annotation(&self, name: &str) -> Option<&str> {
+        unsafe { lookup_annotation((*self.to_glib_none().0).annotations, name) }
+    }
+
+    /// Returns the XML representation of this node.
+    ///
+    /// The result can be parsed again with [`DBusNodeInfo::new_for_xml`].
+    pub fn to_xml(&self) -> String {
+        let mut s = glib::String::new("");
+        self.generate_xml(0, &mut s);
+        s.to_string_lossy().into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XML: &str = r#"
+<node>
+  <interface name="org.gtk_rs.Test">
+    <method name="Hello">
+      <arg name="name" type="s" direction="in"/>
+      <arg name="greeting" type="s" direction="out"/>
+      <annotation name="org.freedesktop.DBus.Deprecated" value="true"/>
+    </method>
+    <signal name="Greeted">
+      <arg name="name" type="s"/>
+    </signal>
+    <property name="Count" type="u" access="read"/>
+  </interface>
+</node>
+"#;
+
+    #[test]
+    fn parse() {
+        let node = DBusNodeInfo::new_for_xml(XML).unwrap();
+        assert_eq!(node.get_path(), None);
+        assert!(node.get_nodes().is_empty());
+
+        let interfaces = node.get_interfaces();
+        assert_eq!(interfaces.len(), 1);
+        let interface = &interfaces[0];
+        assert_eq!(interface.get_name(), "org.gtk_rs.Test");
+
+        let methods = interface.get_methods();
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0].get_name(), "Hello");
+        assert_eq!(
+            methods[0].lookup_annotation("org.freedesktop.DBus.Deprecated"),
+            Some("true")
+        );
+        let in_args = methods[0].get_in_args();
+        assert_eq!(in_args.len(), 1);
+        assert_eq!(in_args[0].get_name(), Some("name"));
+        assert_eq!(in_args[0].get_signature(), "s");
+        assert_eq!(methods[0].get_out_args()[0].get_name(), Some("greeting"));
+
+        let signals = interface.get_signals();
+        assert_eq!(signals[0].get_name(), "Greeted");
+        assert_eq!(signals[0].get_args()[0].get_signature(), "s");
+
+        let properties = interface.get_properties();
+        assert_eq!(properties[0].get_name(), "Count");
+        assert_eq!(properties[0].get_signature(), "u");
+        assert_eq!(properties[0].get_flags(), DBusPropertyInfoFlags::READABLE);
+    }
+
+    #[test]
+    fn build() {
+        let method = DBusMethodInfo::new(
+            "Hello",
+            &[DBusArgInfo::new(Some("name"), "s")],
+            &[DBusArgInfo::new(Some("greeting"), "s")],
+        );
+        let signal = DBusSignalInfo::new("Greeted", &[DBusArgInfo::new(Some("name"), "s")]);
+        let property = DBusPropertyInfo::new("Count", "u", DBusPropertyInfoFlags::READABLE);
+        let interface =
+            DBusInterfaceInfo::new("org.gtk_rs.Test", &[method], &[signal], &[property]);
+        let node = DBusNodeInfo::new(Some("/org/gtk_rs/Test"), &[interface], &[]);
+
+        let parsed = DBusNodeInfo::new_for_xml(&node.to_xml()).unwrap();
+        assert_eq!(parsed.get_path(), Some("/org/gtk_rs/Test"));
+        let interface = parsed.lookup_interface("org.gtk_rs.Test").unwrap();
+        assert_eq!(
+            interface.lookup_method("Hello").unwrap().get_in_args()[0].get_name(),
+            Some("name")
+        );
+        assert!(interface.lookup_signal("Greeted").is_some());
+        assert_eq!(
+            interface.lookup_property("Count").unwrap().get_flags(),
+            DBusPropertyInfoFlags::READABLE
+        );
+    }
+}
@@ -0,0 +1,454 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::http::{self, Client, Request, Response};
+use crate::prelude::*;
+use crate::{File, FileCopyFlags, FileCreateFlags, FileQueryInfoFlags, IOErrorEnum, OutputStream};
+use futures_channel::mpsc;
+use futures_core::stream::Stream;
+use std::future::Future;
+use std::pin::Pin;
+
+const MAX_REDIRECTS: u32 = 10;
+const READ_SIZE: usize = 64 * 1024;
+
+/// The progress of a [`Download`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    received: u64,
+    total: Option<u64>,
+}
+
+impl DownloadProgress {
+    /// Returns the number of bytes of the destination that were received so far, including
+    /// those received by a previous download that was resumed.
+    pub fn get_received(&self) -> u64 {
+        self.received
+    }
+
+    /// Returns the size of the destination if it was announced by the server.
+    pub fn get_total(&self) -> Option<u64> {
+        self.total
+    }
+}
+
+/// Downloads an `http` or `https` URI to a [`File`].
+///
+/// The data is first written to a file next to the destination, called like the destination
+/// with a `.part` suffix, which is moved to the destination once the download is complete and
+/// the checksum, if any, was verified. If a download is cancelled or fails, a later download
+/// to the same destination continues where it stopped, as long as the server supports range
+/// requests and the resource didn't change in the meantime.
+///
+/// ```no_run
+/// # async fn update() -> Result<(), glib::Error> {
+/// use futures_util::StreamExt;
+///
+/// let dest = gio::File::new_for_path("update.tar.xz");
+/// let expected = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+/// let (download, mut progress) = gio::Download::new("https://example.com/update.tar.xz", &dest)
+///     .checksum(glib::ChecksumType::Sha256, expected)
+///     .start_future();
+///
+/// glib::MainContext::default().spawn_local(async move {
+///     while let Some(progress) = progress.next().await {
+///         println!("Received {} of {:?} bytes", progress.get_received(), progress.get_total());
+///     }
+/// });
+///
+/// download.await
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Download {
+    uri: String,
+    dest: File,
+    checksum: Option<(glib::ChecksumType, String)>,
+    resume: bool,
+    client: Option<Client>,
+}
+
+impl Download {
+    /// Creates a download of `uri` to `dest`.
+    pub fn new<P: IsA<File>>(uri: &str, dest: &P) -> Self {
+        Self {
+            uri: uri.to_owned(),
+            dest: dest.as_ref().clone(),
+            checksum: None,
+            resume: true,
+            client: None,
+        }
+    }
+
+    /// Verifies that the checksum of the downloaded data is `expected`, given in hexadecimal
+    /// digits.
+    ///
+    /// If the checksum doesn't match, the download fails with [`IOErrorEnum::InvalidData`] and
+    /// the downloaded data is removed, so that the next download starts from the beginning.
+    pub fn checksum(mut self, checksum_type: glib::ChecksumType, expected: &str) -> Self {
+        self.checksum = Some((checksum_type, expected.to_owned()));
+        self
+    }
+
+    /// Whether to continue a previous download to the same destination.
+    ///
+    /// This is enabled by default.
+    pub fn resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// Sends the requests with `client` instead of a new [`Client`].
+    pub fn client(mut self, client: &Client) -> Self {
+        self.client = Some(client.clone());
+        self
+    }
+
+    /// Starts the download.
+    ///
+    /// Returns a future resolving once the destination was written, and a stream of the
+    /// progress, which is updated whenever some data was received. Dropping the future cancels
+    /// the download and keeps the data received so far for resuming it later.
+    ///
+    /// Redirects are followed, all other responses except successful ones are returned as
+    /// [`IOErrorEnum::Failed`].
+    pub fn start_future(
+        self,
+    ) -> (
+        Pin<Box<dyn Future<Output = Result<(), glib::Error>> + 'static>>,
+        Pin<Box<dyn Stream<Item = DownloadProgress> + 'static>>,
+    ) {
+        let (sender, receiver) = mpsc::unbounded();
+        (Box::pin(run(self, sender)), Box::pin(receiver))
+    }
+}
+
+/// Downloads `uri` to `dest`.
+///
+/// This is a shorthand for `Download::new(uri, dest).start_future()`, see [`Download`] for
+/// more options.
+pub fn download_future<P: IsA<File>>(
+    uri: &str,
+    dest: &P,
+) -> (
+    Pin<Box<dyn Future<Output = Result<(), glib::Error>> + 'static>>,
+    Pin<Box<dyn Stream<Item = DownloadProgress> + 'static>>,
+) {
+    Download::new(uri, dest).start_future()
+}
+
+async fn run(
+    download: Download,
+    progress: mpsc::UnboundedSender<DownloadProgress>,
+) -> Result<(), glib::Error> {
+    let (partial, validator_file) = partial_files(&download.dest)?;
+    let client = download.client.unwrap_or_else(Client::new);
+
+    // Without a validator there is no way to know if the partial data is still up to date
+    let mut validator = None;
+    let mut offset = 0;
+    if download.resume {
+        if let Some(size) = query_size(&partial).await? {
+            validator = load_validator(&validator_file).await?;
+            if validator.is_some() {
+                offset = size;
+            }
+        }
+    }
+
+    let mut uri = download.uri;
+    let mut redirects = 0;
+    let response = loop {
+        let mut request = Request::get(&uri);
+        if let Some(validator) = validator.as_deref().filter(|_| offset > 0) {
+            request = request
+                .header("Range", &format!("bytes={}-", offset))
+                .header("If-Range", validator);
+        }
+
+        let response = client.send_future(request).await?;
+        match response.get_status() {
+            200 => {
+                offset = 0;
+                break response;
+            }
+            206 if offset > 0 => {
+                let start = response
+                    .get_header("Content-Range")
+                    .and_then(parse_content_range)
+                    .map(|(start, _)| start);
+                if start != Some(offset) {
+                    return Err(glib::Error::new(
+                        IOErrorEnum::InvalidData,
+                        "Invalid Content-Range header",
+                    ));
+                }
+                break response;
+            }
+            // The partial data is longer than the resource
+            416 if offset > 0 => offset = 0,
+            301 | 302 | 303 | 307 | 308 => {
+                if redirects == MAX_REDIRECTS {
+                    return Err(glib::Error::new(IOErrorEnum::Failed, "Too many redirects"));
+                }
+                let location = response.get_header("Location").ok_or_else(|| {
+                    glib::Error::new(IOErrorEnum::InvalidData, "Redirect without Location")
+                })?;
+                uri = resolve_location(&uri, location);
+                redirects += 1;
+            }
+            status => {
+                return Err(glib::Error::new(
+                    IOErrorEnum::Failed,
+                    &format!("HTTP error {} {}", status, response.get_reason()),
+                ))
+            }
+        }
+    };
+
+    let total = if offset > 0 {
+        response
+            .get_header("Content-Range")
+            .and_then(parse_content_range)
+            .and_then(|(_, total)| total)
+    } else {
+        response.get_content_length()
+    };
+
+    let mut checksum = match download.checksum {
+        Some((checksum_type, _)) => Some(glib::Checksum::new(checksum_type).ok_or_else(|| {
+            glib::Error::new(IOErrorEnum::NotSupported, "Unsupported checksum type")
+        })?),
+        None => None,
+    };
+
+    let output = if offset > 0 {
+        if let Some(ref mut checksum) = checksum {
+            update_checksum(checksum, &partial).await?;
+        }
+        partial
+            .append_to_async_future(FileCreateFlags::NONE, glib::PRIORITY_DEFAULT)
+            .await?
+    } else {
+        store_validator(&validator_file, &response).await?;
+        partial
+            .replace_async_future(None, false, FileCreateFlags::NONE, glib::PRIORITY_DEFAULT)
+            .await?
+    };
+    let output: OutputStream = output.upcast();
+
+    let mut received = offset;
+    let _ = progress.unbounded_send(DownloadProgress { received, total });
+    while let Some(chunk) = response.read_chunk_future().await? {
+        if let Some(ref mut checksum) = checksum {
+            checksum.update(&chunk);
+        }
+        http::write_all(&output, chunk.to_vec()).await?;
+        received += chunk.len() as u64;
+        let _ = progress.unbounded_send(DownloadProgress { received, total });
+    }
+    output.close_async_future(glib::PRIORITY_DEFAULT).await?;
+
+    if let (Some(checksum), Some((_, expected))) = (checksum, download.checksum) {
+        if !checksum
+            .get_string()
+            .map_or(false, |actual| actual.eq_ignore_ascii_case(&expected))
+        {
+            delete(&partial).await?;
+            delete(&validator_file).await?;
+            return Err(glib::Error::new(
+                IOErrorEnum::InvalidData,
+                "Checksum of the download doesn't match",
+            ));
+        }
+    }
+
+    partial
+        .move_async_future(
+            &download.dest,
+            FileCopyFlags::OVERWRITE,
+            glib::PRIORITY_DEFAULT,
+        )
+        .await?;
+    delete(&validator_file).await
+}
+
+// Returns the files next to `dest` storing the partial data and its ETag or modification time
+fn partial_files(dest: &File) -> Result<(File, File), glib::Error> {
+    let invalid = || glib::Error::new(IOErrorEnum::InvalidFilename, "Invalid destination");
+
+    let parent = dest.get_parent().ok_or_else(invalid)?;
+    let name = dest.get_basename().ok_or_else(invalid)?;
+    let name = name.to_string_lossy();
+    Ok((
+        parent.get_child(&format!("{}.part", name)),
+        parent.get_child(&format!("{}.part.validator", name)),
+    ))
+}
+
+async fn query_size(file: &File) -> Result<Option<u64>, glib::Error> {
+    match file
+        .query_info_async_future(
+            "standard::size",
+            FileQueryInfoFlags::NONE,
+            glib::PRIORITY_DEFAULT,
+        )
+        .await
+    {
+        Ok(info) => Ok(Some(info.get_size() as u64)),
+        Err(err) if err.kind::<IOErrorEnum>() == Some(IOErrorEnum::NotFound) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+async fn load_validator(file: &File) -> Result<Option<String>, glib::Error> {
+    match file.load_contents_async_future().await {
+        Ok((contents, _)) => Ok(String::from_utf8(contents)
+            .ok()
+            .filter(|validator| !validator.is_empty())),
+        Err(err) if err.kind::<IOErrorEnum>() == Some(IOErrorEnum::NotFound) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// Keeps the ETag or modification time of a new download for resuming it later
+async fn store_validator(file: &File, response: &Response) -> Result<(), glib::Error> {
+    // Weak ETags can't be used for range requests
+    let validator = response
+        .get_header("ETag")
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| response.get_header("Last-Modified"));
+
+    match validator {
+        Some(validator) => file
+            .replace_contents_async_future(
+                validator.to_owned().into_bytes(),
+                None,
+                false,
+                FileCreateFlags::NONE,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|(_, err)| err),
+        None => delete(file).await,
+    }
+}
+
+async fn update_checksum(checksum: &mut glib::Checksum, file: &File) -> Result<(), glib::Error> {
+    let input = file.read_async_future(glib::PRIORITY_DEFAULT).await?;
+    loop {
+        let bytes = input
+            .read_bytes_async_future(READ_SIZE, glib::PRIORITY_DEFAULT)
+            .await?;
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        checksum.update(&bytes);
+    }
+}
+
+async fn delete(file: &File) -> Result<(), glib::Error> {
+    match file.delete_async_future(glib::PRIORITY_DEFAULT).await {
+        Err(err) if err.kind::<IOErrorEnum>() != Some(IOErrorEnum::NotFound) => Err(err),
+        _ => Ok(()),
+    }
+}
+
+// Parses the first byte position and the complete length of a `Content-Range` header
+fn parse_content_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let range = value.trim().strip_prefix("bytes ")?;
+    let (range, total) = range.split_at(range.find('/')?);
+    let start = range.split('-').next()?.trim().parse().ok()?;
+    let total = match total[1..].trim() {
+        "*" => None,
+        total => Some(total.parse().ok()?),
+    };
+    Some((start, total))
+}
+
+// Resolves the `Location` header of a redirect relative to the URI that was requested
+fn resolve_location(uri: &str, location: &str) -> String {
+    let scheme_end = match uri.find("://") {
+        Some(scheme_end) => scheme_end,
+        None => return location.to_owned(),
+    };
+    if location.contains("://") {
+        return location.to_owned();
+    }
+    if location.starts_with("//") {
+        return format!("{}:{}", &uri[..scheme_end], location);
+    }
+
+    let authority_end = uri[scheme_end + 3..]
+        .find(|c| c == '/' || c == '?' || c == '#')
+        .map_or(uri.len(), |end| scheme_end + 3 + end);
+    if location.starts_with('/') {
+        return format!("{}{}", &uri[..authority_end], location);
+    }
+
+    // Relative references replace the last segment of the path
+    let path = &uri[authority_end..];
+    let path = &path[..path
+        .find(|c| c == '?' || c == '#')
+        .unwrap_or_else(|| path.len())];
+    let directory = &path[..path.rfind('/').map_or(0, |end| end + 1)];
+    if directory.is_empty() {
+        format!("{}/{}", &uri[..authority_end], location)
+    } else {
+        format!("{}{}{}", &uri[..authority_end], directory, location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_range() {
+        assert_eq!(
+            parse_content_range("bytes 100-199/200"),
+            Some((100, Some(200)))
+        );
+        assert_eq!(parse_content_range("bytes 0-99/*"), Some((0, None)));
+        assert_eq!(parse_content_range("bytes */200"), None);
+        assert_eq!(parse_content_range("items 0-1/2"), None);
+    }
+
+    #[test]
+    fn location() {
+        let uri = "https://example.com/files/update.tar.xz?version=2";
+        assert_eq!(
+            resolve_location(uri, "http://mirror.example.com/update.tar.xz"),
+            "http://mirror.example.com/update.tar.xz"
+        );
+        assert_eq!(
+            resolve_location(uri, "//mirror.example.com/update.tar.xz"),
+            "https://mirror.example.com/update.tar.xz"
+        );
+        assert_eq!(
+            resolve_location(uri, "/mirror/update.tar.xz"),
+            "https://example.com/mirror/update.tar.xz"
+        );
+        assert_eq!(
+            resolve_location(uri, "update-2.tar.xz"),
+            "https://example.com/files/update-2.tar.xz"
+        );
+        assert_eq!(
+            resolve_location("https://example.com", "update.tar.xz"),
+            "https://example.com/update.tar.xz"
+        );
+    }
+
+    #[test]
+    fn partial() {
+        let dest = File::new_for_path("/tmp/update.tar.xz");
+        let (partial, validator) = partial_files(&dest).unwrap();
+        assert_eq!(
+            partial.get_path().unwrap(),
+            std::path::Path::new("/tmp/update.tar.xz.part")
+        );
+        assert_eq!(
+            validator.get_path().unwrap(),
+            std::path::Path::new("/tmp/update.tar.xz.part.validator")
+        );
+    }
+}
@@ -0,0 +1,167 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Drive;
+use crate::DriveExt;
+use crate::DriveStartStopType;
+use crate::IOErrorEnum;
+use crate::MountOperation;
+use crate::MountUnmountFlags;
+use futures_core::stream::Stream;
+use glib::object::IsA;
+use std::future::Future;
+use std::pin::Pin;
+
+/// The state of a [`Drive`] at the time [`DriveExtManual::get_state`] was called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriveState {
+    name: glib::GString,
+    start_stop_type: DriveStartStopType,
+    can_eject: bool,
+    can_poll_for_media: bool,
+    can_start: bool,
+    can_start_degraded: bool,
+    can_stop: bool,
+    has_media: bool,
+    has_volumes: bool,
+    is_media_check_automatic: bool,
+    is_media_removable: bool,
+    #[cfg(any(feature = "v2_50", feature = "dox"))]
+    is_removable: bool,
+}
+
+impl DriveState {
+    fn new<O: IsA<Drive>>(drive: &O) -> Self {
+        Self {
+            name: drive.get_name(),
+            start_stop_type: drive.get_start_stop_type(),
+            can_eject: drive.can_eject(),
+            can_poll_for_media: drive.can_poll_for_media(),
+            can_start: drive.can_start(),
+            can_start_degraded: drive.can_start_degraded(),
+            can_stop: drive.can_stop(),
+            has_media: drive.has_media(),
+            has_volumes: drive.has_volumes(),
+            is_media_check_automatic: drive.is_media_check_automatic(),
+            is_media_removable: drive.is_media_removable(),
+            #[cfg(any(feature = "v2_50", feature = "dox"))]
+            is_removable: drive.is_removable(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns what starting and stopping the drive does, e.g. powering it on and off.
+    pub fn get_start_stop_type(&self) -> DriveStartStopType {
+        self.start_stop_type
+    }
+
+    pub fn can_eject(&self) -> bool {
+        self.can_eject
+    }
+
+    pub fn can_poll_for_media(&self) -> bool {
+        self.can_poll_for_media
+    }
+
+    pub fn can_start(&self) -> bool {
+        self.can_start
+    }
+
+    pub fn can_start_degraded(&self) -> bool {
+        self.can_start_degraded
+    }
+
+    pub fn can_stop(&self) -> bool {
+        self.can_stop
+    }
+
+    pub fn has_media(&self) -> bool {
+        self.has_media
+    }
+
+    pub fn has_volumes(&self) -> bool {
+        self.has_volumes
+    }
+
+    /// Whether the drive detects inserted media by itself.
+    ///
+    /// Otherwise the user has to be offered to poll for media if
+    /// [`can_poll_for_media`](Self::can_poll_for_media) is `true`.
+    pub fn is_media_check_automatic(&self) -> bool {
+        self.is_media_check_automatic
+    }
+
+    pub fn is_media_removable(&self) -> bool {
+        self.is_media_removable
+    }
+
+    /// Whether the drive itself can be removed, e.g. an USB stick.
+    #[cfg(any(feature = "v2_50", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_50")))]
+    pub fn is_removable(&self) -> bool {
+        self.is_removable
+    }
+
+    /// Whether [`DriveExtManual::safely_remove_future`] can be used for the drive.
+    pub fn can_safely_remove(&self) -> bool {
+        self.can_stop || self.can_eject
+    }
+}
+
+pub trait DriveExtManual: 'static {
+    /// Returns the current capabilities and media state of the drive.
+    fn get_state(&self) -> DriveState;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a `Stream` of the state of the drive whenever it changes, e.g. because media was
+    /// inserted.
+    ///
+    /// The signal handler is disconnected once the stream is dropped.
+    fn state_stream(&self) -> Pin<Box<dyn Stream<Item = DriveState> + 'static>>;
+
+    /// Prepares the drive for being unplugged, like the "Safely Remove Drive" action of file
+    /// managers.
+    ///
+    /// The drive is stopped if it can be stopped, e.g. powering down a hard disk, and otherwise
+    /// ejected. All mounts of the drive are unmounted, `mount_operation` is used for asking the
+    /// user about applications still using them. Fails with [`IOErrorEnum::NotSupported`] if the
+    /// drive can be neither stopped nor ejected.
+    fn safely_remove_future<P: IsA<MountOperation> + Clone + 'static>(
+        &self,
+        mount_operation: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), glib::Error>> + 'static>>;
+}
+
+impl<O: IsA<Drive>> DriveExtManual for O {
+    fn get_state(&self) -> DriveState {
+        DriveState::new(self)
+    }
+
+    fn state_stream(&self) -> Pin<Box<dyn Stream<Item = DriveState> + 'static>> {
+        Box::pin(glib::signal_stream(self.as_ref(), |drive, sender| {
+            vec![drive.connect_changed(move |drive| {
+                let _ = sender.unbounded_send(DriveState::new(drive));
+            })]
+        }))
+    }
+
+    fn safely_remove_future<P: IsA<MountOperation> + Clone + 'static>(
+        &self,
+        mount_operation: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), glib::Error>> + 'static>> {
+        if self.can_stop() {
+            self.stop_future(MountUnmountFlags::NONE, mount_operation)
+        } else if self.can_eject() {
+            self.eject_with_operation_future(MountUnmountFlags::NONE, mount_operation)
+        } else {
+            Box::pin(async {
+                Err(glib::Error::new(
+                    IOErrorEnum::NotSupported,
+                    "Drive can be neither stopped nor ejected",
+                ))
+            })
+        }
+    }
+}
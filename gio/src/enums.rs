@@ -0,0 +1,77 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(feature = "v2_64", feature = "dox"))]
+use crate::MemoryMonitorWarningLevel;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+use crate::NetworkConnectivity;
+#[cfg(any(feature = "v2_46", feature = "dox"))]
+use crate::SocketListenerEvent;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+use crate::TlsChannelBindingType;
+use crate::{
+    BusType, ConverterResult, CredentialsType, DBusMessageByteOrder, DBusMessageHeaderField,
+    DBusMessageType, DataStreamByteOrder, DataStreamNewlineType, DriveStartStopType, EmblemOrigin,
+    FileAttributeStatus, FileAttributeType, FileMonitorEvent, FileType, IOErrorEnum,
+    MountOperationResult, NotificationPriority, PasswordSave, ResolverRecordType, ResourceError,
+    SocketClientEvent, SocketFamily, SocketProtocol, SocketType, TlsAuthenticationMode,
+    TlsCertificateRequestFlags, TlsDatabaseLookupFlags, TlsInteractionResult, TlsRehandshakeMode,
+    UnixSocketAddressType, ZlibCompressorFormat,
+};
+
+// The conversions of the generated enums, see `glib::enum_conversions!`.
+glib::enum_conversions! {
+    BusType,
+    ConverterResult,
+    CredentialsType,
+    DBusMessageByteOrder,
+    DBusMessageHeaderField,
+    DBusMessageType,
+    DataStreamByteOrder,
+    DataStreamNewlineType,
+    DriveStartStopType,
+    EmblemOrigin,
+    FileAttributeStatus,
+    FileAttributeType,
+    FileMonitorEvent,
+    FileType,
+    IOErrorEnum,
+    #[cfg(any(feature = "v2_64", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_64")))]
+    MemoryMonitorWarningLevel,
+    MountOperationResult,
+    #[cfg(any(feature = "v2_44", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_44")))]
+    NetworkConnectivity,
+    NotificationPriority,
+    PasswordSave,
+    ResolverRecordType,
+    ResourceError,
+    SocketClientEvent,
+    SocketFamily,
+    #[cfg(any(feature = "v2_46", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_46")))]
+    SocketListenerEvent,
+    SocketProtocol,
+    SocketType,
+    TlsAuthenticationMode,
+    TlsCertificateRequestFlags,
+    #[cfg(any(feature = "v2_66", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+    TlsChannelBindingType,
+    TlsDatabaseLookupFlags,
+    TlsInteractionResult,
+    TlsRehandshakeMode,
+    UnixSocketAddressType,
+    ZlibCompressorFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            glib::__unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
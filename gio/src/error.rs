@@ -23,9 +23,181 @@ impl From<IOErrorEnum> for io::ErrorKind {
     }
 }
 
+impl From<io::ErrorKind> for IOErrorEnum {
+    fn from(kind: io::ErrorKind) -> Self {
+        #[allow(clippy::wildcard_in_or_patterns)]
+        match kind {
+            io::ErrorKind::NotFound => IOErrorEnum::NotFound,
+            io::ErrorKind::PermissionDenied => IOErrorEnum::PermissionDenied,
+            io::ErrorKind::ConnectionRefused => IOErrorEnum::ConnectionRefused,
+            io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::NotConnected => IOErrorEnum::NotConnected,
+            io::ErrorKind::AddrInUse | io::ErrorKind::AddrNotAvailable => IOErrorEnum::AddressInUse,
+            io::ErrorKind::BrokenPipe => IOErrorEnum::BrokenPipe,
+            io::ErrorKind::AlreadyExists => IOErrorEnum::Exists,
+            io::ErrorKind::WouldBlock => IOErrorEnum::WouldBlock,
+            io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => IOErrorEnum::InvalidData,
+            io::ErrorKind::TimedOut => IOErrorEnum::TimedOut,
+            io::ErrorKind::UnexpectedEof => IOErrorEnum::Closed,
+            io::ErrorKind::WriteZero | _ => IOErrorEnum::Failed,
+        }
+    }
+}
+
+/// Helpers for classifying [`glib::Error`]s from the `G_IO_ERROR` domain.
+pub trait IOErrorExt {
+    /// Returns the [`IOErrorEnum`] of this error if it belongs to the `G_IO_ERROR` domain.
+    fn io_error_kind(&self) -> Option<IOErrorEnum>;
+
+    /// Returns the closest matching [`std::io::ErrorKind`] of this error.
+    ///
+    /// Errors from other domains are mapped to `ErrorKind::Other`.
+    fn std_io_error_kind(&self) -> io::ErrorKind {
+        self.io_error_kind()
+            .map_or(io::ErrorKind::Other, io::ErrorKind::from)
+    }
+
+    /// Returns `true` if this is a `G_IO_ERROR_WOULD_BLOCK` error.
+    fn is_would_block(&self) -> bool {
+        self.io_error_kind() == Some(IOErrorEnum::WouldBlock)
+    }
+
+    /// Returns `true` if this is a `G_IO_ERROR_CANCELLED` error.
+    fn is_cancelled(&self) -> bool {
+        self.io_error_kind() == Some(IOErrorEnum::Cancelled)
+    }
+
+    /// Returns `true` if this is a `G_IO_ERROR_NOT_FOUND` error.
+    fn is_not_found(&self) -> bool {
+        self.io_error_kind() == Some(IOErrorEnum::NotFound)
+    }
+
+    /// Returns `true` if this is a `G_IO_ERROR_TIMED_OUT` error.
+    fn is_timed_out(&self) -> bool {
+        self.io_error_kind() == Some(IOErrorEnum::TimedOut)
+    }
+
+    /// Returns `true` if this is a `G_IO_ERROR_CLOSED` error.
+    fn is_closed(&self) -> bool {
+        self.io_error_kind() == Some(IOErrorEnum::Closed)
+    }
+
+    /// Creates a `G_IO_ERROR` error from a [`std::io::Error`].
+    ///
+    /// If the `io::Error` was created by [`into_io_error`](Self::into_io_error), the original
+    /// error is returned. Otherwise the error code is derived from the OS error number if there
+    /// is one, or from the error kind.
+    fn from_io_error(err: io::Error) -> Self
+    where
+        Self: Sized;
+
+    /// Converts this error into a [`std::io::Error`] with the closest matching error kind.
+    ///
+    /// The original error is kept as the inner error of the `io::Error` and can be retrieved
+    /// again with `io::Error::get_ref()` or `io::Error::into_inner()`.
+    fn into_io_error(self) -> io::Error;
+}
+
+impl IOErrorExt for glib::Error {
+    fn io_error_kind(&self) -> Option<IOErrorEnum> {
+        self.kind::<IOErrorEnum>()
+    }
+
+    fn from_io_error(err: io::Error) -> Self {
+        if let Some(err) = err
+            .get_ref()
+            .and_then(|err| err.downcast_ref::<glib::Error>())
+        {
+            return err.clone();
+        }
+
+        #[cfg(unix)]
+        let code = err
+            .raw_os_error()
+            .map(crate::io_error_from_errno)
+            .unwrap_or_else(|| IOErrorEnum::from(err.kind()));
+        #[cfg(not(unix))]
+        let code = IOErrorEnum::from(err.kind());
+
+        glib::Error::new(code, &err.to_string())
+    }
+
+    fn into_io_error(self) -> io::Error {
+        io::Error::new(self.std_io_error_kind(), self)
+    }
+}
+
 pub(crate) fn to_std_io_result<T>(result: Result<T, glib::Error>) -> io::Result<T> {
-    result.map_err(|g_error| match g_error.kind::<IOErrorEnum>() {
-        Some(io_error_enum) => io::Error::new(io_error_enum.into(), g_error),
-        None => io::Error::new(io::ErrorKind::Other, g_error),
-    })
+    result.map_err(IOErrorExt::into_io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classification() {
+        let err = glib::Error::new(IOErrorEnum::WouldBlock, "Would Block");
+        assert!(err.is_would_block());
+        assert!(!err.is_cancelled());
+        assert_eq!(err.io_error_kind(), Some(IOErrorEnum::WouldBlock));
+        assert_eq!(err.std_io_error_kind(), io::ErrorKind::WouldBlock);
+
+        let err = glib::Error::new(glib::FileError::Noent, "No such file");
+        assert!(!err.is_not_found());
+        assert_eq!(err.io_error_kind(), None);
+        assert_eq!(err.std_io_error_kind(), io::ErrorKind::Other);
+    }
+
+    #[test]
+    fn error_kind_conversion() {
+        for kind in &[
+            io::ErrorKind::NotFound,
+            io::ErrorKind::PermissionDenied,
+            io::ErrorKind::AlreadyExists,
+            io::ErrorKind::WouldBlock,
+            io::ErrorKind::TimedOut,
+            io::ErrorKind::BrokenPipe,
+        ] {
+            assert_eq!(io::ErrorKind::from(IOErrorEnum::from(*kind)), *kind);
+        }
+    }
+
+    #[test]
+    fn io_error_round_trip() {
+        let err = glib::Error::new(IOErrorEnum::Cancelled, "Operation was cancelled");
+        let io_err = err.clone().into_io_error();
+        assert_eq!(io_err.kind(), io::ErrorKind::Other);
+        assert_eq!(
+            io_err
+                .get_ref()
+                .and_then(|err| err.downcast_ref::<glib::Error>()),
+            Some(&err)
+        );
+        assert_eq!(glib::Error::from_io_error(io_err), err);
+
+        let err = glib::Error::from_io_error(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert!(err.is_not_found());
+
+        #[cfg(unix)]
+        {
+            let err = glib::Error::from_io_error(io::Error::from_raw_os_error(libc::ENOSPC));
+            assert_eq!(err.io_error_kind(), Some(IOErrorEnum::NoSpace));
+        }
+    }
+
+    #[test]
+    fn integer_and_nick_conversion() {
+        use std::convert::TryFrom;
+
+        assert_eq!(i32::from(IOErrorEnum::NotFound), 1);
+        assert_eq!(IOErrorEnum::try_from(1), Ok(IOErrorEnum::NotFound));
+        assert_eq!(IOErrorEnum::try_from(1000), Err(1000));
+        assert_eq!(
+            "not-found".parse::<IOErrorEnum>().ok(),
+            Some(IOErrorEnum::NotFound)
+        );
+        assert!("no-such-nick".parse::<IOErrorEnum>().is_err());
+    }
 }
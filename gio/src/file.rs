@@ -2,12 +2,21 @@
 
 use crate::Cancellable;
 use crate::File;
+use crate::FileCopyFlags;
 use crate::FileCreateFlags;
+use crate::FileMeasureFlags;
+use futures_channel::mpsc;
+use futures_core::stream::Stream;
 use glib::object::IsA;
 use glib::translate::*;
+use std::cell::RefCell;
+use std::mem;
 use std::pin::Pin;
 use std::ptr;
 
+type CopyProgressCallback = Box<dyn FnMut(i64, i64) + Send + 'static>;
+type MeasureProgressCallback = Box<dyn FnMut(bool, u64, u64, u64) + Send + 'static>;
+
 pub trait FileExtManual: Sized {
     #[doc(alias = "g_file_replace_contents_async")]
     fn replace_contents_async<
@@ -36,6 +45,137 @@ pub trait FileExtManual: Sized {
                 + 'static,
         >,
     >;
+
+    #[doc(alias = "g_file_replace_contents_bytes_async")]
+    fn replace_contents_bytes_async<
+        R: FnOnce(Result<glib::GString, glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        contents: &glib::Bytes,
+        etag: Option<&str>,
+        make_backup: bool,
+        flags: FileCreateFlags,
+        cancellable: Option<&C>,
+        callback: R,
+    );
+
+    fn replace_contents_bytes_async_future(
+        &self,
+        contents: &glib::Bytes,
+        etag: Option<&str>,
+        make_backup: bool,
+        flags: FileCreateFlags,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<glib::GString, glib::Error>> + 'static>>;
+
+    #[doc(alias = "g_file_copy_async")]
+    fn copy_async<
+        P: IsA<File>,
+        R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&C>,
+        progress_callback: Option<CopyProgressCallback>,
+        callback: R,
+    );
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a future resolving once the copy is done, and a `Stream` of the progress as
+    /// `(current_num_bytes, total_num_bytes)`.
+    ///
+    /// The stream ends once the copy is done.
+    fn copy_async_future<P: IsA<File> + Clone + 'static>(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+    ) -> (
+        Pin<Box<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>>,
+        Pin<Box<dyn Stream<Item = (i64, i64)> + 'static>>,
+    );
+
+    // rustdoc-stripper-ignore-next
+    /// Moves the file to `destination` like [`move_`](crate::prelude::FileExt::move_) without
+    /// blocking the calling thread.
+    ///
+    /// `g_file_move_async()` only exists since GLib 2.72, so like its default implementation
+    /// the move is run in a `GTask` on the GIO thread pool. `callback` is called on the
+    /// thread-default main context of the calling thread.
+    fn move_async<
+        P: IsA<File>,
+        R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&C>,
+        callback: R,
+    );
+
+    fn move_async_future<P: IsA<File> + Clone + 'static>(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>>;
+
+    #[doc(alias = "g_file_measure_disk_usage_async")]
+    fn measure_disk_usage_async<
+        R: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        flags: FileMeasureFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&C>,
+        progress_callback: Option<MeasureProgressCallback>,
+        callback: R,
+    );
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a future resolving to `(disk_usage, num_dirs, num_files)`, and a `Stream` of
+    /// the progress as `(reporting, current_size, num_dirs, num_files)`.
+    ///
+    /// The stream ends once the measurement is done.
+    fn measure_disk_usage_async_future(
+        &self,
+        flags: FileMeasureFlags,
+        io_priority: glib::Priority,
+    ) -> (
+        Pin<Box<dyn std::future::Future<Output = Result<(u64, u64, u64), glib::Error>> + 'static>>,
+        Pin<Box<dyn Stream<Item = (bool, u64, u64, u64)> + 'static>>,
+    );
+
+    // rustdoc-stripper-ignore-next
+    /// Loads the contents of the file until `read_more_callback` returns `false` for the
+    /// contents read so far, e.g. to read only the header of a file.
+    #[doc(alias = "g_file_load_partial_contents_async")]
+    fn load_partial_contents_async<
+        P: FnMut(&[u8]) -> bool + Send + 'static,
+        R: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        cancellable: Option<&C>,
+        read_more_callback: P,
+        callback: R,
+    );
+
+    fn load_partial_contents_async_future<P: FnMut(&[u8]) -> bool + Send + 'static>(
+        &self,
+        read_more_callback: P,
+    ) -> Pin<
+        Box<
+            dyn std::future::Future<Output = Result<(Vec<u8>, Option<glib::GString>), glib::Error>>
+                + 'static,
+        >,
+    >;
 }
 
 impl<O: IsA<File>> FileExtManual for O {
@@ -133,4 +273,628 @@ impl<O: IsA<File>> FileExtManual for O {
             cancellable
         }))
     }
+
+    fn replace_contents_bytes_async<
+        R: FnOnce(Result<glib::GString, glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        contents: &glib::Bytes,
+        etag: Option<&str>,
+        make_backup: bool,
+        flags: FileCreateFlags,
+        cancellable: Option<&C>,
+        callback: R,
+    ) {
+        let user_data: Box<R> = Box::new(callback);
+        unsafe extern "C" fn replace_contents_bytes_async_trampoline<
+            R: FnOnce(Result<glib::GString, glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut error = ptr::null_mut();
+            let mut new_etag = ptr::null_mut();
+            let _ = ffi::g_file_replace_contents_finish(
+                _source_object as *mut _,
+                res,
+                &mut new_etag,
+                &mut error,
+            );
+            let result = if error.is_null() {
+                Ok(from_glib_full(new_etag))
+            } else {
+                Err(from_glib_full(error))
+            };
+            let callback: Box<R> = Box::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        let callback = replace_contents_bytes_async_trampoline::<R>;
+        unsafe {
+            ffi::g_file_replace_contents_bytes_async(
+                self.as_ref().to_glib_none().0,
+                contents.to_glib_none().0,
+                etag.to_glib_none().0,
+                make_backup.to_glib(),
+                flags.to_glib(),
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                Some(callback),
+                Box::into_raw(user_data) as *mut _,
+            );
+        }
+    }
+
+    fn replace_contents_bytes_async_future(
+        &self,
+        contents: &glib::Bytes,
+        etag: Option<&str>,
+        make_backup: bool,
+        flags: FileCreateFlags,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<glib::GString, glib::Error>> + 'static>>
+    {
+        let contents = contents.clone();
+        let etag = etag.map(glib::GString::from);
+        Box::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.replace_contents_bytes_async(
+                &contents,
+                etag.as_ref().map(|s| s.as_str()),
+                make_backup,
+                flags,
+                Some(&cancellable),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+
+            cancellable
+        }))
+    }
+
+    fn copy_async<
+        P: IsA<File>,
+        R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&C>,
+        progress_callback: Option<CopyProgressCallback>,
+        callback: R,
+    ) {
+        let progress_trampoline: ffi::GFileProgressCallback = if progress_callback.is_some() {
+            Some(copy_async_progress_trampoline::<R>)
+        } else {
+            None
+        };
+        // The progress callback is called with the same data until the operation finished
+        let user_data: Box<(R, RefCell<Option<CopyProgressCallback>>)> =
+            Box::new((callback, RefCell::new(progress_callback)));
+        unsafe extern "C" fn copy_async_trampoline<
+            R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut error = ptr::null_mut();
+            let _ = ffi::g_file_copy_finish(_source_object as *mut _, res, &mut error);
+            let result = if error.is_null() {
+                Ok(())
+            } else {
+                Err(from_glib_full(error))
+            };
+            let user_data: Box<(R, RefCell<Option<CopyProgressCallback>>)> =
+                Box::from_raw(user_data as *mut _);
+            (user_data.0)(result);
+        }
+        unsafe extern "C" fn copy_async_progress_trampoline<
+            R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        >(
+            current_num_bytes: i64,
+            total_num_bytes: i64,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let user_data = &*(user_data as *const (R, RefCell<Option<CopyProgressCallback>>));
+            if let Some(ref mut progress_callback) = *user_data.1.borrow_mut() {
+                progress_callback(current_num_bytes, total_num_bytes);
+            }
+        }
+        let callback = copy_async_trampoline::<R>;
+        unsafe {
+            let user_data = Box::into_raw(user_data) as glib::ffi::gpointer;
+            ffi::g_file_copy_async(
+                self.as_ref().to_glib_none().0,
+                destination.as_ref().to_glib_none().0,
+                flags.to_glib(),
+                io_priority.to_glib(),
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                progress_trampoline,
+                user_data,
+                Some(callback),
+                user_data,
+            );
+        }
+    }
+
+    fn copy_async_future<P: IsA<File> + Clone + 'static>(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+    ) -> (
+        Pin<Box<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>>,
+        Pin<Box<dyn Stream<Item = (i64, i64)> + 'static>>,
+    ) {
+        let destination = destination.clone();
+        let (sender, receiver) = mpsc::unbounded();
+
+        let fut = Box::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.copy_async(
+                &destination,
+                flags,
+                io_priority,
+                Some(&cancellable),
+                Some(Box::new(move |current_num_bytes, total_num_bytes| {
+                    let _ = sender.unbounded_send((current_num_bytes, total_num_bytes));
+                })),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+
+            cancellable
+        }));
+
+        (fut, Box::pin(receiver))
+    }
+
+    fn move_async<
+        P: IsA<File>,
+        R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&C>,
+        callback: R,
+    ) {
+        let user_data: Box<R> = Box::new(callback);
+        unsafe extern "C" fn move_async_trampoline<
+            R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut error = ptr::null_mut();
+            ffi::g_task_propagate_boolean(res as *mut ffi::GTask, &mut error);
+            let result = if error.is_null() {
+                Ok(())
+            } else {
+                Err(from_glib_full(error))
+            };
+            let callback: Box<R> = Box::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        unsafe extern "C" fn move_async_thread_func(
+            task: *mut ffi::GTask,
+            source_object: *mut glib::gobject_ffi::GObject,
+            task_data: glib::ffi::gpointer,
+            cancellable: *mut ffi::GCancellable,
+        ) {
+            let (destination, flags) = &*(task_data as *const (File, FileCopyFlags));
+            let mut error = ptr::null_mut();
+            ffi::g_file_move(
+                source_object as *mut ffi::GFile,
+                destination.to_glib_none().0,
+                flags.to_glib(),
+                cancellable,
+                None,
+                ptr::null_mut(),
+                &mut error,
+            );
+            if error.is_null() {
+                ffi::g_task_return_boolean(task, glib::ffi::GTRUE);
+            } else {
+                ffi::g_task_return_error(task, error);
+            }
+        }
+        unsafe extern "C" fn move_async_data_free(data: glib::ffi::gpointer) {
+            drop(Box::from_raw(data as *mut (File, FileCopyFlags)));
+        }
+        let task_data: Box<(File, FileCopyFlags)> = Box::new((destination.as_ref().clone(), flags));
+        unsafe {
+            let task = ffi::g_task_new(
+                self.as_ref().to_glib_none().0 as *mut glib::gobject_ffi::GObject,
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                Some(move_async_trampoline::<R>),
+                Box::into_raw(user_data) as *mut _,
+            );
+            ffi::g_task_set_priority(task, io_priority.to_glib());
+            ffi::g_task_set_task_data(
+                task,
+                Box::into_raw(task_data) as *mut _,
+                Some(move_async_data_free),
+            );
+            ffi::g_task_run_in_thread(task, Some(move_async_thread_func));
+            glib::gobject_ffi::g_object_unref(task as *mut _);
+        }
+    }
+
+    fn move_async_future<P: IsA<File> + Clone + 'static>(
+        &self,
+        destination: &P,
+        flags: FileCopyFlags,
+        io_priority: glib::Priority,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+        let destination = destination.clone();
+        Box::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.move_async(
+                &destination,
+                flags,
+                io_priority,
+                Some(&cancellable),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+
+            cancellable
+        }))
+    }
+
+    fn measure_disk_usage_async<
+        R: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        flags: FileMeasureFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&C>,
+        progress_callback: Option<MeasureProgressCallback>,
+        callback: R,
+    ) {
+        let progress_trampoline: ffi::GFileMeasureProgressCallback = if progress_callback.is_some()
+        {
+            Some(measure_disk_usage_async_progress_trampoline::<R>)
+        } else {
+            None
+        };
+        // The progress callback is called with the same data until the operation finished
+        let user_data: Box<(R, RefCell<Option<MeasureProgressCallback>>)> =
+            Box::new((callback, RefCell::new(progress_callback)));
+        unsafe extern "C" fn measure_disk_usage_async_trampoline<
+            R: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut disk_usage = 0;
+            let mut num_dirs = 0;
+            let mut num_files = 0;
+            let mut error = ptr::null_mut();
+            let _ = ffi::g_file_measure_disk_usage_finish(
+                _source_object as *mut _,
+                res,
+                &mut disk_usage,
+                &mut num_dirs,
+                &mut num_files,
+                &mut error,
+            );
+            let result = if error.is_null() {
+                Ok((disk_usage, num_dirs, num_files))
+            } else {
+                Err(from_glib_full(error))
+            };
+            let user_data: Box<(R, RefCell<Option<MeasureProgressCallback>>)> =
+                Box::from_raw(user_data as *mut _);
+            (user_data.0)(result);
+        }
+        unsafe extern "C" fn measure_disk_usage_async_progress_trampoline<
+            R: FnOnce(Result<(u64, u64, u64), glib::Error>) + Send + 'static,
+        >(
+            reporting: glib::ffi::gboolean,
+            current_size: u64,
+            num_dirs: u64,
+            num_files: u64,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let user_data = &*(user_data as *const (R, RefCell<Option<MeasureProgressCallback>>));
+            if let Some(ref mut progress_callback) = *user_data.1.borrow_mut() {
+                progress_callback(from_glib(reporting), current_size, num_dirs, num_files);
+            }
+        }
+        let callback = measure_disk_usage_async_trampoline::<R>;
+        unsafe {
+            let user_data = Box::into_raw(user_data) as glib::ffi::gpointer;
+            ffi::g_file_measure_disk_usage_async(
+                self.as_ref().to_glib_none().0,
+                flags.to_glib(),
+                io_priority.to_glib(),
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                progress_trampoline,
+                user_data,
+                Some(callback),
+                user_data,
+            );
+        }
+    }
+
+    fn measure_disk_usage_async_future(
+        &self,
+        flags: FileMeasureFlags,
+        io_priority: glib::Priority,
+    ) -> (
+        Pin<Box<dyn std::future::Future<Output = Result<(u64, u64, u64), glib::Error>> + 'static>>,
+        Pin<Box<dyn Stream<Item = (bool, u64, u64, u64)> + 'static>>,
+    ) {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let fut = Box::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.measure_disk_usage_async(
+                flags,
+                io_priority,
+                Some(&cancellable),
+                Some(Box::new(
+                    move |reporting, current_size, num_dirs, num_files| {
+                        let _ =
+                            sender.unbounded_send((reporting, current_size, num_dirs, num_files));
+                    },
+                )),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+
+            cancellable
+        }));
+
+        (fut, Box::pin(receiver))
+    }
+
+    fn load_partial_contents_async<
+        P: FnMut(&[u8]) -> bool + Send + 'static,
+        R: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        cancellable: Option<&C>,
+        read_more_callback: P,
+        callback: R,
+    ) {
+        // The read more callback is called with the same data until the operation finished
+        let user_data: Box<(R, RefCell<P>)> =
+            Box::new((callback, RefCell::new(read_more_callback)));
+        unsafe extern "C" fn load_partial_contents_async_trampoline<
+            P: FnMut(&[u8]) -> bool + Send + 'static,
+            R: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut error = ptr::null_mut();
+            let mut contents = ptr::null_mut();
+            let mut length = mem::MaybeUninit::uninit();
+            let mut etag_out = ptr::null_mut();
+            let _ = ffi::g_file_load_partial_contents_finish(
+                _source_object as *mut _,
+                res,
+                &mut contents,
+                length.as_mut_ptr(),
+                &mut etag_out,
+                &mut error,
+            );
+            let result = if error.is_null() {
+                Ok((
+                    FromGlibContainer::from_glib_full_num(contents, length.assume_init() as usize),
+                    from_glib_full(etag_out),
+                ))
+            } else {
+                Err(from_glib_full(error))
+            };
+            let user_data: Box<(R, RefCell<P>)> = Box::from_raw(user_data as *mut _);
+            (user_data.0)(result);
+        }
+        unsafe extern "C" fn load_partial_contents_async_read_more_trampoline<
+            P: FnMut(&[u8]) -> bool + Send + 'static,
+            R: FnOnce(Result<(Vec<u8>, Option<glib::GString>), glib::Error>) + Send + 'static,
+        >(
+            file_contents: *const libc::c_char,
+            file_size: i64,
+            user_data: glib::ffi::gpointer,
+        ) -> glib::ffi::gboolean {
+            let user_data = &*(user_data as *const (R, RefCell<P>));
+            let contents = if file_size > 0 {
+                std::slice::from_raw_parts(file_contents as *const u8, file_size as usize)
+            } else {
+                &[]
+            };
+            (&mut *user_data.1.borrow_mut())(contents).to_glib()
+        }
+        let callback = load_partial_contents_async_trampoline::<P, R>;
+        unsafe {
+            ffi::g_file_load_partial_contents_async(
+                self.as_ref().to_glib_none().0,
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                Some(load_partial_contents_async_read_more_trampoline::<P, R>),
+                Some(callback),
+                Box::into_raw(user_data) as *mut _,
+            );
+        }
+    }
+
+    fn load_partial_contents_async_future<P: FnMut(&[u8]) -> bool + Send + 'static>(
+        &self,
+        read_more_callback: P,
+    ) -> Pin<
+        Box<
+            dyn std::future::Future<Output = Result<(Vec<u8>, Option<glib::GString>), glib::Error>>
+                + 'static,
+        >,
+    > {
+        Box::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.load_partial_contents_async(Some(&cancellable), read_more_callback, move |res| {
+                send.resolve(res);
+            });
+
+            cancellable
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_util::{run_async, run_async_local};
+    use crate::{File, FileCopyFlags, FileCreateFlags, FileQueryInfoFlags};
+    use std::fs;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn replace_contents_bytes_async_and_copy_async() {
+        let dir = std::env::temp_dir();
+        let source_path = dir.join(format!("gio-rs-copy-async-{}-src", std::process::id()));
+        let destination_path = dir.join(format!("gio-rs-copy-async-{}-dest", std::process::id()));
+
+        let (source, destination) = (source_path.clone(), destination_path.clone());
+        let ret = run_async(move |tx, l| {
+            let source = File::new_for_path(&source);
+            let destination = File::new_for_path(&destination);
+            let bytes = glib::Bytes::from_static(b"hello world");
+            let source_clone = source.clone();
+            source.replace_contents_bytes_async(
+                &bytes,
+                None,
+                false,
+                FileCreateFlags::NONE,
+                crate::NONE_CANCELLABLE,
+                move |res| {
+                    res.unwrap();
+                    source_clone.copy_async(
+                        &destination,
+                        FileCopyFlags::OVERWRITE,
+                        glib::PRIORITY_DEFAULT,
+                        crate::NONE_CANCELLABLE,
+                        None,
+                        move |res| {
+                            tx.send(res).unwrap();
+                            l.quit();
+                        },
+                    );
+                },
+            );
+        });
+
+        ret.unwrap();
+        assert_eq!(fs::read(&destination_path).unwrap(), b"hello world");
+        let _ = fs::remove_file(&source_path);
+        let _ = fs::remove_file(&destination_path);
+    }
+
+    #[test]
+    fn move_async_future() {
+        let dir = std::env::temp_dir();
+        let source_path = dir.join(format!("gio-rs-move-async-{}-src", std::process::id()));
+        let destination_path = dir.join(format!("gio-rs-move-async-{}-dest", std::process::id()));
+        fs::write(&source_path, b"moved").unwrap();
+        fs::write(&destination_path, b"replaced").unwrap();
+
+        let source = File::new_for_path(&source_path);
+        let destination = File::new_for_path(&destination_path);
+        let ret = run_async_local(move |tx, l| {
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let res = source
+                    .move_async_future(
+                        &destination,
+                        FileCopyFlags::OVERWRITE,
+                        glib::PRIORITY_DEFAULT,
+                    )
+                    .await;
+                tx.send(res).unwrap();
+                l.quit();
+            });
+        });
+
+        ret.unwrap();
+        assert!(!source_path.exists());
+        assert_eq!(fs::read(&destination_path).unwrap(), b"moved");
+        let _ = fs::remove_file(&destination_path);
+    }
+
+    #[test]
+    fn load_partial_contents_async_future() {
+        let path = std::env::temp_dir().join(format!("gio-rs-partial-{}", std::process::id()));
+        fs::write(&path, b"header\nbody").unwrap();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let file = File::new_for_path(&path);
+        let seen_clone = seen.clone();
+        let ret = run_async_local(move |tx, l| {
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let res = file
+                    .load_partial_contents_async_future(move |contents| {
+                        seen_clone.lock().unwrap().push(contents.to_vec());
+                        !contents.contains(&b'\n')
+                    })
+                    .await;
+                tx.send(res).unwrap();
+                l.quit();
+            });
+        });
+
+        let (contents, _etag) = ret.unwrap();
+        assert!(contents.starts_with(b"header\n"));
+        assert_eq!(
+            seen.lock().unwrap().last().map(Vec::as_slice),
+            Some(&contents[..])
+        );
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn enumerate_children_async_future() {
+        let dir = std::env::temp_dir().join(format!("gio-rs-enumerate-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("child"), b"").unwrap();
+
+        let file = File::new_for_path(&dir);
+        let ret = run_async_local(move |tx, l| {
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let res = file
+                    .enumerate_children_async_future(
+                        "standard::name",
+                        FileQueryInfoFlags::NONE,
+                        glib::PRIORITY_DEFAULT,
+                    )
+                    .await
+                    .and_then(|enumerator| {
+                        let mut names = Vec::new();
+                        while let Some(info) = enumerator.next_file(crate::NONE_CANCELLABLE)? {
+                            names.push(info.get_name());
+                        }
+                        Ok(names)
+                    });
+                tx.send(res).unwrap();
+                l.quit();
+            });
+        });
+
+        assert_eq!(ret.unwrap(), [std::path::PathBuf::from("child")]);
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
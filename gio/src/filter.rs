@@ -0,0 +1,133 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::prelude::*;
+use glib::subclass::prelude::*;
+use glib::subclass::Signal;
+use glib::{Object, SignalHandlerId};
+use once_cell::sync::Lazy;
+
+pub(crate) mod iface {
+    use super::*;
+
+    #[glib::object_interface]
+    pub struct Filter {
+        pub(crate) matches: Option<fn(&super::Filter, &Object) -> bool>,
+    }
+
+    #[glib::object_interface]
+    unsafe impl ObjectInterface for Filter {
+        const NAME: &'static str = "GioRsFilter";
+        type Prerequisites = (Object,);
+
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: Lazy<Vec<Signal>> = Lazy::new(|| {
+                vec![Signal::builder("changed", &[], glib::Type::UNIT.into())
+                    .run_last()
+                    .build()]
+            });
+            SIGNALS.as_ref()
+        }
+    }
+}
+
+glib::wrapper! {
+    /// Decides which items to keep, independent of the model containing them.
+    ///
+    /// A filter can be shared by several models, e.g. a [`FilterListModel`](crate::FilterListModel)
+    /// and a `gtk::TreeModelFilter`, which filter their items again whenever the filter emits
+    /// `changed`.
+    ///
+    /// Filters are implemented with [`FilterImpl`](crate::subclass::prelude::FilterImpl), or
+    /// created from a closure with [`CustomFilter`].
+    pub struct Filter(ObjectInterface<iface::Filter>);
+}
+
+pub trait FilterExt: 'static {
+    /// Returns `true` if `item` should be kept.
+    fn matches<P: IsA<Object>>(&self, item: &P) -> bool;
+
+    /// Notifies all users of the filter that the result of `matches` might have changed, e.g.
+    /// because the search text the filter depends on was changed.
+    fn changed(&self);
+
+    fn connect_changed<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId;
+}
+
+impl<O: IsA<Filter>> FilterExt for O {
+    fn matches<P: IsA<Object>>(&self, item: &P) -> bool {
+        let filter = self.upcast_ref::<Filter>();
+        let iface = iface::Filter::from_instance(filter);
+        (iface.matches.expect("no \"matches\" implementation"))(filter, item.upcast_ref())
+    }
+
+    fn changed(&self) {
+        let _ = self
+            .emit_by_name("changed", &[])
+            .expect("Failed to emit \"changed\"");
+    }
+
+    fn connect_changed<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId {
+        self.connect_local("changed", false, move |args| {
+            let filter = args[0]
+                .get::<Filter>()
+                .expect("Wrong type for filter")
+                .expect("No filter");
+            f(unsafe { filter.unsafe_cast_ref() });
+            None
+        })
+        .expect("Failed to connect to \"changed\"")
+    }
+}
+
+type FilterFunc = dyn Fn(&Object) -> bool;
+
+mod imp {
+    use super::*;
+    use crate::subclass::prelude::FilterImpl;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    pub struct CustomFilter {
+        pub(super) filter_func: RefCell<Option<Box<FilterFunc>>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for CustomFilter {
+        const NAME: &'static str = "GioRsCustomFilter";
+        type Type = super::CustomFilter;
+        type ParentType = Object;
+        type Interfaces = (super::Filter,);
+    }
+
+    impl ObjectImpl for CustomFilter {}
+
+    impl FilterImpl for CustomFilter {
+        fn matches(&self, _filter: &Self::Type, item: &Object) -> bool {
+            self.filter_func
+                .borrow()
+                .as_ref()
+                .map_or(true, |filter_func| filter_func(item))
+        }
+    }
+}
+
+glib::wrapper! {
+    /// A [`Filter`] that keeps the items for which a Rust closure returns `true`.
+    pub struct CustomFilter(ObjectSubclass<imp::CustomFilter>) @implements Filter;
+}
+
+impl CustomFilter {
+    pub fn new<F: Fn(&Object) -> bool + 'static>(filter_func: F) -> Self {
+        let obj = glib::Object::new::<Self>(&[]).expect("Failed to create custom filter");
+        let imp = imp::CustomFilter::from_instance(&obj);
+        *imp.filter_func.borrow_mut() = Some(Box::new(filter_func));
+        obj
+    }
+
+    /// Replaces the filter function and emits `changed`.
+    pub fn set_filter_func<F: Fn(&Object) -> bool + 'static>(&self, filter_func: F) {
+        let imp = imp::CustomFilter::from_instance(self);
+        *imp.filter_func.borrow_mut() = Some(Box::new(filter_func));
+        self.changed();
+    }
+}
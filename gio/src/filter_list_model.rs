@@ -0,0 +1,305 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::*;
+use crate::subclass::prelude::*;
+use crate::{Filter, ListModel};
+use glib::{Object, SignalHandlerId};
+use std::rc::Rc;
+
+type FilterFunc = dyn Fn(&Object) -> bool;
+
+mod imp {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    pub struct FilterListModel {
+        pub(super) model: RefCell<Option<(ListModel, SignalHandlerId)>>,
+        pub(super) filter_func: RefCell<Option<Rc<FilterFunc>>>,
+        pub(super) filter: RefCell<Option<(Filter, SignalHandlerId)>>,
+        // Whether each item of the underlying model is visible
+        pub(super) visible: RefCell<Vec<bool>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for FilterListModel {
+        const NAME: &'static str = "GioRsFilterListModel";
+        type Type = super::FilterListModel;
+        type ParentType = Object;
+        type Interfaces = (ListModel,);
+    }
+
+    impl ObjectImpl for FilterListModel {
+        fn dispose(&self, _obj: &Self::Type) {
+            if let Some((model, handler_id)) = self.model.borrow_mut().take() {
+                model.disconnect(handler_id);
+            }
+            if let Some((filter, handler_id)) = self.filter.borrow_mut().take() {
+                filter.disconnect(handler_id);
+            }
+        }
+    }
+
+    impl ListModelImpl for FilterListModel {
+        fn get_item_type(&self, _list_model: &Self::Type) -> glib::Type {
+            self.model
+                .borrow()
+                .as_ref()
+                .map_or(glib::Type::OBJECT, |(model, _)| model.get_item_type())
+        }
+
+        fn get_n_items(&self, _list_model: &Self::Type) -> u32 {
+            self.visible.borrow().iter().filter(|&&v| v).count() as u32
+        }
+
+        fn get_item(&self, _list_model: &Self::Type, position: u32) -> Option<Object> {
+            let index = self
+                .visible
+                .borrow()
+                .iter()
+                .enumerate()
+                .filter(|&(_, &v)| v)
+                .nth(position as usize)
+                .map(|(index, _)| index)?;
+
+            self.model
+                .borrow()
+                .as_ref()
+                .and_then(|(model, _)| model.get_object(index as u32))
+        }
+    }
+}
+
+glib::wrapper! {
+    /// A [`ListModel`] that contains the items of another model for which a Rust closure
+    /// returns `true`.
+    ///
+    /// Changes of the underlying model are forwarded as the corresponding changes of the
+    /// visible items only. The filter function is not called again when the properties of an
+    /// item change, so [`refilter`](Self::refilter) has to be called when the result of the
+    /// filter function might have changed.
+    ///
+    /// Models using a [`Filter`] instead are filtered again automatically whenever the filter
+    /// emits `changed`.
+    pub struct FilterListModel(ObjectSubclass<imp::FilterListModel>) @implements ListModel;
+}
+
+impl FilterListModel {
+    /// Creates a new model that contains the items of `model` for which `filter_func` returns
+    /// `true`.
+    pub fn new<P: IsA<ListModel>, F: Fn(&Object) -> bool + 'static>(
+        model: &P,
+        filter_func: F,
+    ) -> Self {
+        let obj = glib::Object::new::<Self>(&[]).expect("Failed to create filter list model");
+
+        let imp = imp::FilterListModel::from_instance(&obj);
+        *imp.filter_func.borrow_mut() = Some(Rc::new(filter_func));
+
+        let model: &ListModel = model.as_ref();
+        let weak = obj.downgrade();
+        let handler_id = model.connect_items_changed(move |_, position, removed, added| {
+            if let Some(obj) = weak.upgrade() {
+                obj.source_items_changed(position, removed, added);
+            }
+        });
+        *imp.model.borrow_mut() = Some((model.clone(), handler_id));
+
+        let visible = obj.filter_items(0, model.get_n_items());
+        *imp.visible.borrow_mut() = visible;
+
+        obj
+    }
+
+    /// Creates a new model that contains the items of `model` matched by `filter`.
+    pub fn with_filter<P: IsA<ListModel>, Q: IsA<Filter>>(model: &P, filter: &Q) -> Self {
+        let filter = filter.as_ref().clone();
+        let obj = Self::new(model, {
+            let filter = filter.clone();
+            move |item| filter.matches(item)
+        });
+        obj.connect_filter(filter);
+        obj
+    }
+
+    /// Returns the underlying model.
+    pub fn get_model(&self) -> Option<ListModel> {
+        let imp = imp::FilterListModel::from_instance(self);
+        imp.model.borrow().as_ref().map(|(model, _)| model.clone())
+    }
+
+    /// Returns the filter, unless a filter function is used.
+    pub fn get_filter(&self) -> Option<Filter> {
+        let imp = imp::FilterListModel::from_instance(self);
+        imp.filter
+            .borrow()
+            .as_ref()
+            .map(|(filter, _)| filter.clone())
+    }
+
+    /// Replaces the filter function and filters all items again.
+    pub fn set_filter_func<F: Fn(&Object) -> bool + 'static>(&self, filter_func: F) {
+        let imp = imp::FilterListModel::from_instance(self);
+        self.disconnect_filter();
+        *imp.filter_func.borrow_mut() = Some(Rc::new(filter_func));
+        self.refilter();
+    }
+
+    /// Replaces the filter function with `filter` and filters all items again.
+    pub fn set_filter<P: IsA<Filter>>(&self, filter: &P) {
+        let filter = filter.as_ref().clone();
+        self.set_filter_func({
+            let filter = filter.clone();
+            move |item| filter.matches(item)
+        });
+        self.connect_filter(filter);
+    }
+
+    fn connect_filter(&self, filter: Filter) {
+        let imp = imp::FilterListModel::from_instance(self);
+        let weak = self.downgrade();
+        let handler_id = filter.connect_changed(move |_| {
+            if let Some(obj) = weak.upgrade() {
+                obj.refilter();
+            }
+        });
+        *imp.filter.borrow_mut() = Some((filter, handler_id));
+    }
+
+    fn disconnect_filter(&self) {
+        let imp = imp::FilterListModel::from_instance(self);
+        if let Some((filter, handler_id)) = imp.filter.borrow_mut().take() {
+            filter.disconnect(handler_id);
+        }
+    }
+
+    /// Calls the filter function for all items again and emits `items-changed` for the range of
+    /// visible items that changed.
+    pub fn refilter(&self) {
+        let imp = imp::FilterListModel::from_instance(self);
+        let n_items = imp.visible.borrow().len() as u32;
+        let visible = self.filter_items(0, n_items);
+        let old = imp.visible.replace(visible);
+        let new = imp.visible.borrow();
+
+        let first = match old.iter().zip(new.iter()).position(|(o, n)| o != n) {
+            Some(first) => first,
+            None => return,
+        };
+        let last = old.len()
+            - old
+                .iter()
+                .rev()
+                .zip(new.iter().rev())
+                .position(|(o, n)| o != n)
+                .unwrap();
+
+        let position = count_visible(&old[..first]);
+        let removed = count_visible(&old[first..last]);
+        let added = count_visible(&new[first..last]);
+        drop(new);
+        self.items_changed(position, removed, added);
+    }
+
+    fn filter_items(&self, position: u32, n_items: u32) -> Vec<bool> {
+        let imp = imp::FilterListModel::from_instance(self);
+        let model = match imp.model.borrow().as_ref() {
+            Some((model, _)) => model.clone(),
+            None => return vec![false; n_items as usize],
+        };
+        let filter_func = imp.filter_func.borrow().clone();
+
+        (position..position + n_items)
+            .map(|index| match (model.get_object(index), &filter_func) {
+                (Some(item), Some(filter_func)) => filter_func(&item),
+                _ => false,
+            })
+            .collect()
+    }
+
+    fn source_items_changed(&self, position: u32, removed: u32, added: u32) {
+        let imp = imp::FilterListModel::from_instance(self);
+        let new = self.filter_items(position, added);
+        let added = count_visible(&new);
+
+        let start = position as usize;
+        let end = start + removed as usize;
+        let mut visible = imp.visible.borrow_mut();
+        let position = count_visible(&visible[..start]);
+        let removed = count_visible(&visible[start..end]);
+        visible.splice(start..end, new);
+        drop(visible);
+
+        if removed > 0 || added > 0 {
+            self.items_changed(position, removed, added);
+        }
+    }
+}
+
+fn count_visible(visible: &[bool]) -> u32 {
+    visible.iter().filter(|&&v| v).count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{action_names, action_store, record_items_changed};
+    use crate::{CustomFilter, SimpleAction};
+
+    fn name_len(item: &Object) -> usize {
+        item.downcast_ref::<SimpleAction>()
+            .unwrap()
+            .get_name()
+            .len()
+    }
+
+    #[test]
+    fn filter() {
+        let store = action_store(&["a", "bb", "c", "dd"]);
+        let filter = FilterListModel::new(&store, |item| name_len(item) == 1);
+        let changes = record_items_changed(&filter);
+        assert_eq!(action_names(&filter), vec!["a", "c"]);
+
+        store.append(&SimpleAction::new("e", None));
+        store.append(&SimpleAction::new("ff", None));
+        store.remove(0);
+        assert_eq!(action_names(&filter), vec!["c", "e"]);
+        assert_eq!(*changes.borrow(), vec![(2, 0, 1), (0, 1, 0)]);
+
+        changes.borrow_mut().clear();
+        filter.set_filter_func(|item| name_len(item) == 2);
+        assert_eq!(action_names(&filter), vec!["bb", "dd", "ff"]);
+        assert_eq!(*changes.borrow(), vec![(0, 2, 3)]);
+
+        changes.borrow_mut().clear();
+        filter.refilter();
+        assert!(changes.borrow().is_empty());
+    }
+
+    #[test]
+    fn shared_filter() {
+        let store = action_store(&["a", "bb", "c"]);
+        let filter = CustomFilter::new(|item| name_len(item) == 1);
+        let first = FilterListModel::with_filter(&store, &filter);
+        let second = FilterListModel::with_filter(&store, &filter);
+        let changes = record_items_changed(&first);
+        assert_eq!(action_names(&first), vec!["a", "c"]);
+        assert_eq!(action_names(&second), vec!["a", "c"]);
+
+        filter.set_filter_func(|item| name_len(item) == 2);
+        assert_eq!(action_names(&first), vec!["bb"]);
+        assert_eq!(action_names(&second), vec!["bb"]);
+        assert_eq!(*changes.borrow(), vec![(0, 2, 1)]);
+
+        changes.borrow_mut().clear();
+        first.set_filter_func(|_| true);
+        assert!(first.get_filter().is_none());
+        assert_eq!(*changes.borrow(), vec![(0, 1, 3)]);
+
+        changes.borrow_mut().clear();
+        filter.set_filter_func(|_| false);
+        assert_eq!(first.get_n_items(), 3);
+        assert_eq!(second.get_n_items(), 0);
+        assert!(changes.borrow().is_empty());
+    }
+}
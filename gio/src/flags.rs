@@ -1,9 +1,92 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::ApplicationFlags;
+#[cfg(any(feature = "v2_60", feature = "dox"))]
+use crate::ResolverNameLookupFlags;
+use crate::{
+    AppInfoCreateFlags, ApplicationFlags, AskPasswordFlags, BusNameOwnerFlags, BusNameWatcherFlags,
+    ConverterFlags, DBusCallFlags, DBusCapabilityFlags, DBusConnectionFlags,
+    DBusInterfaceSkeletonFlags, DBusMessageFlags, DBusProxyFlags, DBusSendMessageFlags,
+    DBusServerFlags, DBusSignalFlags, DriveStartFlags, FileCopyFlags, FileCreateFlags,
+    FileMeasureFlags, FileMonitorFlags, FileQueryInfoFlags, IOStreamSpliceFlags, MountMountFlags,
+    MountUnmountFlags, OutputStreamSpliceFlags, ResourceLookupFlags, SettingsBindFlags,
+    SubprocessFlags, TlsCertificateFlags, TlsDatabaseVerifyFlags, TlsPasswordFlags,
+};
 
 impl Default for ApplicationFlags {
     fn default() -> ApplicationFlags {
         ApplicationFlags::empty()
     }
 }
+
+// The conversions of the generated flags, see `glib::flags_conversions!`.
+glib::flags_conversions! {
+    AppInfoCreateFlags,
+    ApplicationFlags,
+    AskPasswordFlags,
+    BusNameOwnerFlags,
+    BusNameWatcherFlags,
+    ConverterFlags,
+    DBusCallFlags,
+    DBusCapabilityFlags,
+    DBusConnectionFlags,
+    DBusInterfaceSkeletonFlags,
+    DBusMessageFlags,
+    DBusProxyFlags,
+    DBusSendMessageFlags,
+    DBusServerFlags,
+    DBusSignalFlags,
+    DriveStartFlags,
+    FileCopyFlags,
+    FileCreateFlags,
+    FileMeasureFlags,
+    FileMonitorFlags,
+    FileQueryInfoFlags,
+    IOStreamSpliceFlags,
+    MountMountFlags,
+    MountUnmountFlags,
+    OutputStreamSpliceFlags,
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    ResolverNameLookupFlags,
+    ResourceLookupFlags,
+    SettingsBindFlags,
+    SubprocessFlags,
+    TlsCertificateFlags,
+    TlsDatabaseVerifyFlags,
+    TlsPasswordFlags,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ApplicationFlags;
+
+    #[test]
+    fn nicks() {
+        let flags = ApplicationFlags::IS_SERVICE | ApplicationFlags::NON_UNIQUE;
+        assert_eq!(glib::flags_to_nicks(&flags), "is-service|non-unique");
+        assert_eq!(
+            glib::flags_to_nicks(&ApplicationFlags::FLAGS_NONE),
+            "flags-none"
+        );
+        assert_eq!(flags.to_string(), "IS_SERVICE | NON_UNIQUE");
+        assert_eq!(
+            "is-service | non-unique".parse::<ApplicationFlags>().ok(),
+            Some(flags)
+        );
+        assert_eq!(
+            "".parse::<ApplicationFlags>().ok(),
+            Some(ApplicationFlags::empty())
+        );
+        assert!("no-such-nick".parse::<ApplicationFlags>().is_err());
+    }
+
+    #[test]
+    fn iter() {
+        let flags = ApplicationFlags::IS_SERVICE | ApplicationFlags::NON_UNIQUE;
+        assert_eq!(
+            flags.iter().collect::<Vec<_>>(),
+            vec![ApplicationFlags::IS_SERVICE, ApplicationFlags::NON_UNIQUE]
+        );
+        assert_eq!(ApplicationFlags::empty().iter().next(), None);
+    }
+}
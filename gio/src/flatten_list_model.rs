@@ -0,0 +1,247 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::*;
+use crate::subclass::prelude::*;
+use crate::ListModel;
+use glib::{Object, SignalHandlerId};
+
+// One item of the underlying model, which contributes the items of `model` if it is a
+// `ListModel`
+struct Child {
+    id: u64,
+    model: Option<(ListModel, SignalHandlerId)>,
+    n_items: u32,
+}
+
+mod imp {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Default)]
+    pub struct FlattenListModel {
+        pub(super) model: RefCell<Option<(ListModel, SignalHandlerId)>>,
+        pub(super) children: RefCell<Vec<Child>>,
+        pub(super) next_id: Cell<u64>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for FlattenListModel {
+        const NAME: &'static str = "GioRsFlattenListModel";
+        type Type = super::FlattenListModel;
+        type ParentType = Object;
+        type Interfaces = (ListModel,);
+    }
+
+    impl ObjectImpl for FlattenListModel {
+        fn dispose(&self, _obj: &Self::Type) {
+            if let Some((model, handler_id)) = self.model.borrow_mut().take() {
+                model.disconnect(handler_id);
+            }
+            for child in self.children.borrow_mut().drain(..) {
+                child.disconnect();
+            }
+        }
+    }
+
+    impl ListModelImpl for FlattenListModel {
+        fn get_item_type(&self, _list_model: &Self::Type) -> glib::Type {
+            glib::Type::OBJECT
+        }
+
+        fn get_n_items(&self, _list_model: &Self::Type) -> u32 {
+            self.children
+                .borrow()
+                .iter()
+                .map(|child| child.n_items)
+                .sum()
+        }
+
+        fn get_item(&self, _list_model: &Self::Type, position: u32) -> Option<Object> {
+            let mut position = position;
+            for child in self.children.borrow().iter() {
+                if position < child.n_items {
+                    return child
+                        .model
+                        .as_ref()
+                        .and_then(|(model, _)| model.get_object(position));
+                }
+                position -= child.n_items;
+            }
+            None
+        }
+    }
+}
+
+glib::wrapper! {
+    /// A [`ListModel`] that contains the items of all models contained in another model, one
+    /// after the other.
+    ///
+    /// Items of the underlying model that are not [`ListModel`]s don't contribute any items.
+    /// Changes of the underlying model and of the contained models are forwarded as the
+    /// corresponding changes of the flattened items.
+    pub struct FlattenListModel(ObjectSubclass<imp::FlattenListModel>) @implements ListModel;
+}
+
+impl FlattenListModel {
+    /// Creates a new model that contains the items of all models contained in `model`.
+    pub fn new<P: IsA<ListModel>>(model: &P) -> Self {
+        let obj = glib::Object::new::<Self>(&[]).expect("Failed to create flatten list model");
+
+        let imp = imp::FlattenListModel::from_instance(&obj);
+        let model: &ListModel = model.as_ref();
+        let weak = obj.downgrade();
+        let handler_id = model.connect_items_changed(move |_, position, removed, added| {
+            if let Some(obj) = weak.upgrade() {
+                obj.source_items_changed(position, removed, added);
+            }
+        });
+        *imp.model.borrow_mut() = Some((model.clone(), handler_id));
+
+        let children = obj.create_children(model, 0, model.get_n_items());
+        *imp.children.borrow_mut() = children;
+
+        obj
+    }
+
+    /// Returns the underlying model.
+    pub fn get_model(&self) -> Option<ListModel> {
+        let imp = imp::FlattenListModel::from_instance(self);
+        imp.model.borrow().as_ref().map(|(model, _)| model.clone())
+    }
+
+    /// Returns the model containing the item at `position` and the position of the item in it.
+    pub fn get_model_for_item(&self, position: u32) -> Option<(ListModel, u32)> {
+        let imp = imp::FlattenListModel::from_instance(self);
+        let mut position = position;
+        for child in imp.children.borrow().iter() {
+            if position < child.n_items {
+                return child
+                    .model
+                    .as_ref()
+                    .map(|(model, _)| (model.clone(), position));
+            }
+            position -= child.n_items;
+        }
+        None
+    }
+
+    fn create_children(&self, model: &ListModel, position: u32, n_items: u32) -> Vec<Child> {
+        let imp = imp::FlattenListModel::from_instance(self);
+        (position..position + n_items)
+            .map(|index| {
+                let id = imp.next_id.get();
+                imp.next_id.set(id + 1);
+
+                let child_model = model
+                    .get_object(index)
+                    .and_then(|item| item.downcast::<ListModel>().ok());
+                let n_items = child_model.as_ref().map_or(0, |model| model.get_n_items());
+                let model = child_model.map(|model| {
+                    let weak = self.downgrade();
+                    let handler_id =
+                        model.connect_items_changed(move |_, position, removed, added| {
+                            if let Some(obj) = weak.upgrade() {
+                                obj.child_items_changed(id, position, removed, added);
+                            }
+                        });
+                    (model, handler_id)
+                });
+
+                Child { id, model, n_items }
+            })
+            .collect()
+    }
+
+    fn source_items_changed(&self, position: u32, removed: u32, added: u32) {
+        let imp = imp::FlattenListModel::from_instance(self);
+        let model = match self.get_model() {
+            Some(model) => model,
+            None => return,
+        };
+        let new = self.create_children(&model, position, added);
+        let n_added = new.iter().map(|child| child.n_items).sum::<u32>();
+
+        let start = position as usize;
+        let end = start + removed as usize;
+        let mut children = imp.children.borrow_mut();
+        let offset = children[..start]
+            .iter()
+            .map(|child| child.n_items)
+            .sum::<u32>();
+        let n_removed = children
+            .splice(start..end, new)
+            .map(|child| {
+                let n_items = child.n_items;
+                child.disconnect();
+                n_items
+            })
+            .sum::<u32>();
+        drop(children);
+
+        if n_removed > 0 || n_added > 0 {
+            self.items_changed(offset, n_removed, n_added);
+        }
+    }
+
+    fn child_items_changed(&self, id: u64, position: u32, removed: u32, added: u32) {
+        let imp = imp::FlattenListModel::from_instance(self);
+        let mut children = imp.children.borrow_mut();
+        let index = match children.iter().position(|child| child.id == id) {
+            Some(index) => index,
+            None => return,
+        };
+        let offset = children[..index]
+            .iter()
+            .map(|child| child.n_items)
+            .sum::<u32>();
+        let child = &mut children[index];
+        child.n_items = child.n_items + added - removed;
+        drop(children);
+
+        self.items_changed(offset + position, removed, added);
+    }
+}
+
+impl Child {
+    fn disconnect(self) {
+        if let Some((model, handler_id)) = self.model {
+            model.disconnect(handler_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{action_names, action_store, record_items_changed};
+    use crate::{ListStore, SimpleAction};
+
+    #[test]
+    fn flatten() {
+        let first = action_store(&["a", "b"]);
+        let second = action_store(&["c"]);
+        let models = ListStore::new(ListStore::static_type());
+        models.append(&first);
+        models.append(&second);
+
+        let flatten = FlattenListModel::new(&models);
+        let changes = record_items_changed(&flatten);
+        assert_eq!(action_names(&flatten), vec!["a", "b", "c"]);
+
+        first.append(&SimpleAction::new("x", None));
+        second.remove(0);
+        models.insert(1, &action_store(&["d", "e"]));
+        assert_eq!(action_names(&flatten), vec!["a", "b", "x", "d", "e"]);
+        assert_eq!(*changes.borrow(), vec![(2, 0, 1), (3, 1, 0), (3, 0, 2)]);
+        assert_eq!(
+            flatten.get_model_for_item(3),
+            Some((models.get_object(1).unwrap().downcast().unwrap(), 0))
+        );
+
+        changes.borrow_mut().clear();
+        models.remove(0);
+        first.append(&SimpleAction::new("y", None));
+        assert_eq!(action_names(&flatten), vec!["d", "e"]);
+        assert_eq!(*changes.borrow(), vec![(0, 3, 0)]);
+    }
+}
@@ -0,0 +1,10 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Checks that the GIO found at runtime is at least `major.minor.micro`.
+///
+/// GIO is released as part of GLib, so this is the same as [`glib::check_runtime_version`]. It
+/// allows using API newer than the `v2_xx` features selected at compile time after checking that
+/// the running GIO provides it.
+pub fn check_version(major: u32, minor: u32, micro: u32) -> Result<(), glib::VersionError> {
+    glib::check_runtime_version(major, minor, micro)
+}
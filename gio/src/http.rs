@@ -0,0 +1,764 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! A minimal HTTP/1.1 client built on [`SocketClient`] and [`TlsClientConnection`].
+//!
+//! This is meant for simple downloads and API calls of applications that don't want to depend
+//! on a complete HTTP library. Every request uses a new connection, which is closed after the
+//! response. Proxies are resolved by the [`ProxyResolver`](crate::ProxyResolver) of the socket
+//! client, and `https` URIs are fetched over TLS with the default validation of the server
+//! certificate.
+//!
+//! Like all futures of this crate, the futures returned here have to be spawned on a
+//! [`glib::MainContext`], and dropping them cancels the request.
+//!
+//! ```no_run
+//! # async fn fetch() -> Result<(), glib::Error> {
+//! use gio::http::{Client, Request};
+//!
+//! let client = Client::new();
+//! let response = client
+//!     .send_future(Request::get("https://gtk-rs.org/").header("Accept", "text/html"))
+//!     .await?;
+//! if response.get_status() == 200 {
+//!     while let Some(chunk) = response.read_chunk_future().await? {
+//!         println!("Received {} bytes", chunk.len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::prelude::*;
+use crate::{
+    DataInputStream, DataStreamNewlineType, IOErrorEnum, IOStream, InputStream, NetworkAddress,
+    OutputStream, SocketClient, TlsClientConnection,
+};
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+const READ_SIZE: u64 = 64 * 1024;
+
+#[derive(Debug)]
+enum Body {
+    Empty,
+    Bytes(glib::Bytes),
+    Stream(InputStream, Option<u64>),
+}
+
+/// An HTTP request.
+#[derive(Debug)]
+pub struct Request {
+    method: String,
+    uri: String,
+    headers: Vec<(String, String)>,
+    body: Body,
+}
+
+impl Request {
+    /// Creates a request with `method` for `uri`, which has to be an `http` or `https` URI.
+    ///
+    /// Sending the request fails if the method or URI contain whitespace or control characters.
+    pub fn new(method: &str, uri: &str) -> Self {
+        Self {
+            method: method.to_owned(),
+            uri: uri.to_owned(),
+            headers: Vec::new(),
+            body: Body::Empty,
+        }
+    }
+
+    /// Creates a `GET` request for `uri`.
+    pub fn get(uri: &str) -> Self {
+        Self::new("GET", uri)
+    }
+
+    /// Creates a `POST` request for `uri`.
+    pub fn post(uri: &str) -> Self {
+        Self::new("POST", uri)
+    }
+
+    /// Adds the header `name` with `value`.
+    ///
+    /// The `Host`, `Connection`, `Content-Length` and `Transfer-Encoding` headers are set by
+    /// the client. Sending the request fails if `name` is not a valid header name or `value`
+    /// contains line breaks, which would allow injecting other headers.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Sends `body` as the body of the request.
+    pub fn body(mut self, body: glib::Bytes) -> Self {
+        self.body = Body::Bytes(body);
+        self
+    }
+
+    /// Sends the contents of `stream` as the body of the request.
+    ///
+    /// If the `length` of the contents is not known, the body is sent with the chunked transfer
+    /// encoding. Otherwise exactly `length` bytes are sent, and sending fails if the stream ends
+    /// before.
+    pub fn body_stream<P: IsA<InputStream>>(mut self, stream: &P, length: Option<u64>) -> Self {
+        self.body = Body::Stream(stream.as_ref().clone(), length);
+        self
+    }
+
+    pub fn get_method(&self) -> &str {
+        &self.method
+    }
+
+    pub fn get_uri(&self) -> &str {
+        &self.uri
+    }
+}
+
+/// Sends HTTP requests.
+///
+/// Cloning the client returns a client sharing the same [`SocketClient`].
+#[derive(Debug, Clone)]
+pub struct Client {
+    socket_client: SocketClient,
+}
+
+impl Client {
+    /// Creates a client using the default proxy resolver.
+    pub fn new() -> Self {
+        Self::with_socket_client(&SocketClient::new())
+    }
+
+    /// Creates a client connecting with `socket_client`, e.g. for using a different proxy
+    /// resolver or timeout.
+    ///
+    /// TLS is handled by the client, the `tls` property of `socket_client` has to be `false`.
+    pub fn with_socket_client(socket_client: &SocketClient) -> Self {
+        Self {
+            socket_client: socket_client.clone(),
+        }
+    }
+
+    pub fn get_socket_client(&self) -> &SocketClient {
+        &self.socket_client
+    }
+
+    /// Sends `request` and returns the response once its headers were received.
+    ///
+    /// The body of the response can then be read from the [`Response`]. Responses with any
+    /// status are returned, errors are only returned if the request couldn't be sent or the
+    /// response is malformed.
+    pub fn send_future(
+        &self,
+        request: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, glib::Error>> + 'static>> {
+        let socket_client = self.socket_client.clone();
+
+        Box::pin(async move {
+            let target = Target::parse(&request.uri)?;
+            let head = request_head(&request, &target)?;
+            let connection = socket_client
+                .connect_to_uri_async_future(&request.uri, target.default_port())
+                .await?;
+            let stream: IOStream = if target.tls {
+                let identity = NetworkAddress::parse_uri(&request.uri, target.default_port())?;
+                let tls_connection = TlsClientConnection::new(&connection, Some(&identity))?;
+                tls_connection
+                    .handshake_async_future(glib::PRIORITY_DEFAULT)
+                    .await?;
+                tls_connection.upcast()
+            } else {
+                connection.upcast()
+            };
+
+            let output = stream.get_output_stream();
+            write_all(&output, head.into_bytes()).await?;
+            write_body(&output, request.body).await?;
+            output.flush_async_future(glib::PRIORITY_DEFAULT).await?;
+
+            let input = DataInputStream::new(&stream.get_input_stream());
+            input.set_newline_type(DataStreamNewlineType::Any);
+
+            // Informational responses like `100 Continue` precede the actual response
+            let (status, reason, headers) = loop {
+                let (status, reason) = parse_status_line(&read_line(&input).await?)?;
+                let headers = read_headers(&input).await?;
+                if status >= 200 || status == 101 {
+                    break (status, reason, headers);
+                }
+            };
+
+            let body = if request.method == "HEAD" || status == 204 || status == 304 {
+                BodyState::Done
+            } else if header(&headers, "Transfer-Encoding")
+                .map_or(false, |value| value.eq_ignore_ascii_case("chunked"))
+            {
+                BodyState::ChunkSize
+            } else if let Some(length) = header(&headers, "Content-Length") {
+                let length = length.trim().parse().map_err(|_| {
+                    glib::Error::new(IOErrorEnum::InvalidData, "Invalid Content-Length header")
+                })?;
+                BodyState::Length(length)
+            } else {
+                BodyState::UntilClose
+            };
+
+            Ok(Response {
+                status,
+                reason,
+                headers,
+                stream,
+                input,
+                body: Rc::new(Cell::new(body)),
+            })
+        })
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyState {
+    // The number of bytes left of a body with a Content-Length
+    Length(u64),
+    // The size of the next chunk has to be read
+    ChunkSize,
+    // The number of bytes left of the current chunk
+    Chunk(u64),
+    UntilClose,
+    Done,
+}
+
+/// The response to a [`Request`].
+///
+/// The connection is closed when the response is dropped.
+#[derive(Debug)]
+pub struct Response {
+    status: u16,
+    reason: String,
+    headers: Vec<(String, String)>,
+    stream: IOStream,
+    input: DataInputStream,
+    body: Rc<Cell<BodyState>>,
+}
+
+impl Response {
+    /// Returns the status code, e.g. `200`.
+    pub fn get_status(&self) -> u16 {
+        self.status
+    }
+
+    /// Returns the reason phrase following the status code, e.g. `OK`.
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns all headers in the order they were received.
+    pub fn get_headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the value of the first header called `name`, ignoring case.
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+
+    /// Returns the length of the body if it was announced by the server.
+    pub fn get_content_length(&self) -> Option<u64> {
+        if header(&self.headers, "Transfer-Encoding").is_some() {
+            return None;
+        }
+        self.get_header("Content-Length")
+            .and_then(|length| length.trim().parse().ok())
+    }
+
+    /// Returns the connection the response is received on.
+    pub fn get_stream(&self) -> &IOStream {
+        &self.stream
+    }
+
+    /// Reads the next part of the body, or returns `None` after the end of the body.
+    ///
+    /// The transfer encoding is removed, but content encodings like `gzip` are not.
+    pub fn read_chunk_future(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<glib::Bytes>, glib::Error>> + 'static>> {
+        let input = self.input.clone();
+        let body = self.body.clone();
+        Box::pin(async move { read_chunk(&input, &body).await })
+    }
+
+    /// Reads the remaining body.
+    pub fn read_to_end_future(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, glib::Error>> + 'static>> {
+        let input = self.input.clone();
+        let body = self.body.clone();
+
+        Box::pin(async move {
+            let mut data = Vec::new();
+            while let Some(chunk) = read_chunk(&input, &body).await? {
+                data.extend_from_slice(&chunk);
+            }
+            Ok(data)
+        })
+    }
+}
+
+async fn read_chunk(
+    input: &DataInputStream,
+    body: &Cell<BodyState>,
+) -> Result<Option<glib::Bytes>, glib::Error> {
+    loop {
+        match body.get() {
+            BodyState::Done | BodyState::Length(0) => {
+                body.set(BodyState::Done);
+                return Ok(None);
+            }
+            BodyState::Length(remaining) => {
+                let bytes = read_bytes(input, remaining).await?;
+                body.set(BodyState::Length(remaining - bytes.len() as u64));
+                return Ok(Some(bytes));
+            }
+            BodyState::ChunkSize => {
+                let size = parse_chunk_size(&read_line(input).await?)?;
+                if size > 0 {
+                    body.set(BodyState::Chunk(size));
+                    continue;
+                }
+
+                // Trailers are ignored
+                while !read_line(input).await?.is_empty() {}
+                body.set(BodyState::Done);
+                return Ok(None);
+            }
+            BodyState::Chunk(remaining) => {
+                let bytes = read_bytes(input, remaining).await?;
+                let remaining = remaining - bytes.len() as u64;
+                if remaining == 0 {
+                    // The data of each chunk is followed by a line break
+                    read_line(input).await?;
+                    body.set(BodyState::ChunkSize);
+                } else {
+                    body.set(BodyState::Chunk(remaining));
+                }
+                return Ok(Some(bytes));
+            }
+            BodyState::UntilClose => {
+                let bytes = input
+                    .read_bytes_async_future(READ_SIZE as usize, glib::PRIORITY_DEFAULT)
+                    .await?;
+                if bytes.is_empty() {
+                    body.set(BodyState::Done);
+                    return Ok(None);
+                }
+                return Ok(Some(bytes));
+            }
+        }
+    }
+}
+
+// The parts of a URI needed for sending the request
+#[derive(Debug, PartialEq, Eq)]
+struct Target {
+    tls: bool,
+    host: String,
+    path: String,
+}
+
+impl Target {
+    fn parse(uri: &str) -> Result<Target, glib::Error> {
+        let invalid = || glib::Error::new(IOErrorEnum::InvalidArgument, "Invalid HTTP URI");
+
+        if uri.contains(|c: char| c.is_whitespace() || c.is_control()) {
+            return Err(invalid());
+        }
+
+        let separator = uri.find("://").ok_or_else(invalid)?;
+        let tls = match &uri[..separator] {
+            scheme if scheme.eq_ignore_ascii_case("http") => false,
+            scheme if scheme.eq_ignore_ascii_case("https") => true,
+            _ => {
+                return Err(glib::Error::new(
+                    IOErrorEnum::NotSupported,
+                    "Only http and https URIs are supported",
+                ))
+            }
+        };
+
+        let rest = &uri[separator + 3..];
+        let authority_end = rest.find(|c| c == '/' || c == '?' || c == '#');
+        let authority = &rest[..authority_end.unwrap_or_else(|| rest.len())];
+        // User information is not sent in the Host header
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        let path = authority_end.map_or("", |end| &rest[end..]);
+        let path = path.split('#').next().unwrap_or("");
+        let path = if path.starts_with('/') {
+            path.to_owned()
+        } else {
+            format!("/{}", path)
+        };
+
+        Ok(Target {
+            tls,
+            host: host.to_owned(),
+            path,
+        })
+    }
+
+    fn default_port(&self) -> u16 {
+        if self.tls {
+            443
+        } else {
+            80
+        }
+    }
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+// Methods and header names are tokens, see RFC 7230
+fn is_token(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&c))
+}
+
+fn request_head(request: &Request, target: &Target) -> Result<String, glib::Error> {
+    if !is_token(&request.method) {
+        return Err(glib::Error::new(
+            IOErrorEnum::InvalidArgument,
+            "Invalid HTTP method",
+        ));
+    }
+
+    let mut head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        request.method, target.path, target.host
+    );
+    for (name, value) in &request.headers {
+        if !is_token(name) || value.contains(|c| c == '\r' || c == '\n' || c == '\0') {
+            return Err(glib::Error::new(
+                IOErrorEnum::InvalidArgument,
+                &format!("Invalid HTTP header '{}'", name.escape_debug()),
+            ));
+        }
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    match request.body {
+        Body::Empty => (),
+        Body::Bytes(ref bytes) => head.push_str(&format!("Content-Length: {}\r\n", bytes.len())),
+        Body::Stream(_, Some(length)) => head.push_str(&format!("Content-Length: {}\r\n", length)),
+        Body::Stream(_, None) => head.push_str("Transfer-Encoding: chunked\r\n"),
+    }
+    head.push_str("\r\n");
+    Ok(head)
+}
+
+pub(crate) async fn write_all(output: &OutputStream, mut data: Vec<u8>) -> Result<(), glib::Error> {
+    while !data.is_empty() {
+        let (mut buffer, written) = output
+            .write_async_future(data, glib::PRIORITY_DEFAULT)
+            .await
+            .map_err(|(_, err)| err)?;
+        buffer.drain(..written);
+        data = buffer;
+    }
+    Ok(())
+}
+
+async fn write_body(output: &OutputStream, body: Body) -> Result<(), glib::Error> {
+    match body {
+        Body::Empty => Ok(()),
+        Body::Bytes(bytes) => write_all(output, bytes.to_vec()).await,
+        Body::Stream(stream, Some(length)) => {
+            // Only the announced length is read, the server would take the rest for another
+            // request
+            let mut remaining = length;
+            while remaining > 0 {
+                let bytes = stream
+                    .read_bytes_async_future(
+                        remaining.min(READ_SIZE) as usize,
+                        glib::PRIORITY_DEFAULT,
+                    )
+                    .await?;
+                if bytes.is_empty() {
+                    return Err(glib::Error::new(
+                        IOErrorEnum::PartialInput,
+                        "Request body ended before its length",
+                    ));
+                }
+                remaining -= bytes.len() as u64;
+                write_all(output, bytes.to_vec()).await?;
+            }
+            Ok(())
+        }
+        Body::Stream(stream, None) => loop {
+            let bytes = stream
+                .read_bytes_async_future(READ_SIZE as usize, glib::PRIORITY_DEFAULT)
+                .await?;
+            let mut chunk = format!("{:x}\r\n", bytes.len()).into_bytes();
+            chunk.extend_from_slice(&bytes);
+            chunk.extend_from_slice(b"\r\n");
+            write_all(output, chunk).await?;
+            // The empty chunk was just written and ends the body
+            if bytes.is_empty() {
+                return Ok(());
+            }
+        },
+    }
+}
+
+async fn read_line(input: &DataInputStream) -> Result<String, glib::Error> {
+    let line = input.read_line_async_future(glib::PRIORITY_DEFAULT).await?;
+    String::from_utf8(line)
+        .map_err(|_| glib::Error::new(IOErrorEnum::InvalidData, "Invalid response header"))
+}
+
+// Reads up to `remaining` bytes of the body, which has to continue
+async fn read_bytes(input: &DataInputStream, remaining: u64) -> Result<glib::Bytes, glib::Error> {
+    let bytes = input
+        .read_bytes_async_future(remaining.min(READ_SIZE) as usize, glib::PRIORITY_DEFAULT)
+        .await?;
+    if bytes.is_empty() {
+        return Err(glib::Error::new(
+            IOErrorEnum::PartialInput,
+            "Connection closed before the end of the response",
+        ));
+    }
+    Ok(bytes)
+}
+
+async fn read_headers(input: &DataInputStream) -> Result<Vec<(String, String)>, glib::Error> {
+    let mut headers = Vec::new();
+    loop {
+        let line = read_line(input).await?;
+        if line.is_empty() {
+            return Ok(headers);
+        }
+        headers.push(parse_header(&line)?);
+    }
+}
+
+fn parse_status_line(line: &str) -> Result<(u16, String), glib::Error> {
+    let invalid = || glib::Error::new(IOErrorEnum::InvalidData, "Invalid HTTP status line");
+
+    let mut parts = line.splitn(3, ' ');
+    if !parts
+        .next()
+        .map_or(false, |version| version.starts_with("HTTP/1."))
+    {
+        return Err(invalid());
+    }
+    let status = parts
+        .next()
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(invalid)?;
+    let reason = parts.next().unwrap_or("").to_owned();
+    Ok((status, reason))
+}
+
+fn parse_header(line: &str) -> Result<(String, String), glib::Error> {
+    let separator = line
+        .find(':')
+        .ok_or_else(|| glib::Error::new(IOErrorEnum::InvalidData, "Invalid HTTP header"))?;
+    Ok((
+        line[..separator].trim().to_owned(),
+        line[separator + 1..].trim().to_owned(),
+    ))
+}
+
+fn parse_chunk_size(line: &str) -> Result<u64, glib::Error> {
+    // Chunk extensions are ignored
+    let size = line.split(';').next().unwrap_or("").trim();
+    u64::from_str_radix(size, 16)
+        .map_err(|_| glib::Error::new(IOErrorEnum::InvalidData, "Invalid chunk size"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::run_async_local;
+    use crate::{MemoryInputStream, MemoryOutputStream, SocketListener};
+
+    #[test]
+    fn target() {
+        assert_eq!(
+            Target::parse("https://user@example.com:8443/path?query#fragment").unwrap(),
+            Target {
+                tls: true,
+                host: String::from("example.com:8443"),
+                path: String::from("/path?query"),
+            }
+        );
+        assert_eq!(
+            Target::parse("HTTP://example.com?query").unwrap(),
+            Target {
+                tls: false,
+                host: String::from("example.com"),
+                path: String::from("/?query"),
+            }
+        );
+        assert!(Target::parse("ftp://example.com/").is_err());
+        assert!(Target::parse("https:///path").is_err());
+        assert!(Target::parse("example.com").is_err());
+        assert!(Target::parse("http://example.com/a b").is_err());
+        assert!(Target::parse("http://example.com/\r\nX-Injected: 1").is_err());
+    }
+
+    #[test]
+    fn head() {
+        let request = Request::post("http://example.com/upload")
+            .header("Accept", "*/*")
+            .body(glib::Bytes::from_static(b"data"));
+        let target = Target::parse(request.get_uri()).unwrap();
+        assert_eq!(
+            request_head(&request, &target).unwrap(),
+            "POST /upload HTTP/1.1\r\nHost: example.com\r\nConnection: close\r\n\
+             Accept: */*\r\nContent-Length: 4\r\n\r\n"
+        );
+
+        let invalid = [
+            Request::new("GET / HTTP/1.1\r\n", "http://example.com/"),
+            Request::new("", "http://example.com/"),
+            Request::get("http://example.com/").header("Accept\r\nX-Injected", "1"),
+            Request::get("http://example.com/").header("Accept:", "*/*"),
+            Request::get("http://example.com/").header("Accept", "*/*\r\nX-Injected: 1"),
+        ];
+        for request in &invalid {
+            assert!(request_head(request, &target).is_err());
+        }
+    }
+
+    #[test]
+    fn stream_body_length() {
+        let written = run_async_local(|tx, l| {
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let stream = MemoryInputStream::from_bytes(&glib::Bytes::from_static(b"data"));
+                let output = MemoryOutputStream::new_resizable();
+                let res =
+                    write_body(output.upcast_ref(), Body::Stream(stream.upcast(), Some(2))).await;
+                output.close(crate::NONE_CANCELLABLE).unwrap();
+                let written = (res, output.steal_as_bytes());
+
+                let stream = MemoryInputStream::from_bytes(&glib::Bytes::from_static(b"data"));
+                let output = MemoryOutputStream::new_resizable();
+                let res =
+                    write_body(output.upcast_ref(), Body::Stream(stream.upcast(), Some(6))).await;
+
+                tx.send((written, res)).unwrap();
+                l.quit();
+            });
+        });
+
+        let ((res, bytes), short_res) = written;
+        assert!(res.is_ok());
+        assert_eq!(&*bytes, b"da");
+        assert_eq!(
+            short_res.unwrap_err().kind::<IOErrorEnum>(),
+            Some(IOErrorEnum::PartialInput)
+        );
+    }
+
+    #[test]
+    fn send() {
+        let (head, body, response) = run_async_local(|tx, l| {
+            let listener = SocketListener::new();
+            let port = listener.add_any_inet_port(None::<&glib::Object>).unwrap();
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let server = async {
+                    let (connection, _) = listener.accept_async_future().await?;
+                    let input = DataInputStream::new(&connection.get_input_stream());
+                    input.set_newline_type(DataStreamNewlineType::Any);
+                    let mut head = Vec::new();
+                    loop {
+                        let line = read_line(&input).await?;
+                        if line.is_empty() {
+                            break;
+                        }
+                        head.push(line);
+                    }
+                    let mut body = Vec::new();
+                    while body.len() < 4 {
+                        body.extend_from_slice(&read_bytes(&input, 4 - body.len() as u64).await?);
+                    }
+
+                    let output = connection.get_output_stream();
+                    write_all(
+                        &output,
+                        b"HTTP/1.1 100 Continue\r\n\r\n\
+                          HTTP/1.1 201 Created\r\nTransfer-Encoding: chunked\r\n\r\n\
+                          2\r\nok\r\n0\r\n\r\n"
+                            .to_vec(),
+                    )
+                    .await?;
+                    output.flush_async_future(glib::PRIORITY_DEFAULT).await?;
+                    Ok::<_, glib::Error>((head, body, connection))
+                };
+
+                let client = async {
+                    let stream = MemoryInputStream::from_bytes(&glib::Bytes::from_static(b"data"));
+                    let request = Request::new("PUT", &format!("http://localhost:{}/item", port))
+                        .header("X-Test", "1")
+                        .body_stream(&stream, Some(4));
+                    let response = Client::new().send_future(request).await?;
+                    let body = response.read_to_end_future().await?;
+                    Ok::<_, glib::Error>((response.get_status(), body))
+                };
+
+                let (server, client) = futures_util::future::join(server, client).await;
+                let (head, body, _connection) = server.unwrap();
+                tx.send((head, body, client.unwrap())).unwrap();
+                l.quit();
+            });
+        });
+
+        assert_eq!(head[0], "PUT /item HTTP/1.1");
+        assert!(head.contains(&String::from("X-Test: 1")));
+        assert!(head.contains(&String::from("Content-Length: 4")));
+        assert_eq!(body, b"data");
+        assert_eq!(response, (201, b"ok".to_vec()));
+    }
+
+    #[test]
+    fn status_line() {
+        assert_eq!(
+            parse_status_line("HTTP/1.1 404 Not Found").unwrap(),
+            (404, String::from("Not Found"))
+        );
+        assert_eq!(
+            parse_status_line("HTTP/1.0 200").unwrap(),
+            (200, String::new())
+        );
+        assert!(parse_status_line("SSH-2.0-OpenSSH").is_err());
+    }
+
+    #[test]
+    fn header_and_chunk_size() {
+        assert_eq!(
+            parse_header("Content-Type:  text/plain ").unwrap(),
+            (String::from("Content-Type"), String::from("text/plain"))
+        );
+        assert!(parse_header("no header").is_err());
+
+        assert_eq!(parse_chunk_size("1a").unwrap(), 26);
+        assert_eq!(parse_chunk_size("FF; name=value").unwrap(), 255);
+        assert!(parse_chunk_size("").is_err());
+    }
+}
@@ -4,16 +4,66 @@ use crate::InetAddress;
 use crate::InetSocketAddress;
 use crate::InetSocketAddressExt;
 
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr, SocketAddrV4, SocketAddrV6};
 
 impl From<SocketAddr> for InetSocketAddress {
     fn from(addr: SocketAddr) -> Self {
-        InetSocketAddress::new::<InetAddress>(&addr.ip().into(), addr.port())
+        match addr {
+            SocketAddr::V4(addr) => {
+                InetSocketAddress::new(&InetAddress::from(IpAddr::V4(*addr.ip())), addr.port())
+            }
+            SocketAddr::V6(addr) => {
+                let address = InetAddress::from(IpAddr::V6(*addr.ip()));
+                glib::Object::new::<InetSocketAddress>(&[
+                    ("address", &address),
+                    ("port", &u32::from(addr.port())),
+                    ("flowinfo", &addr.flowinfo()),
+                    ("scope-id", &addr.scope_id()),
+                ])
+                .expect("Failed to create InetSocketAddress")
+            }
+        }
     }
 }
 
 impl From<InetSocketAddress> for SocketAddr {
     fn from(addr: InetSocketAddress) -> Self {
-        SocketAddr::new(addr.get_address().into(), addr.get_port())
+        SocketAddr::from(&addr)
+    }
+}
+
+impl<'a> From<&'a InetSocketAddress> for SocketAddr {
+    fn from(addr: &'a InetSocketAddress) -> Self {
+        match addr.get_address().into() {
+            IpAddr::V4(ip) => SocketAddr::V4(SocketAddrV4::new(ip, addr.get_port())),
+            IpAddr::V6(ip) => SocketAddr::V6(SocketAddrV6::new(
+                ip,
+                addr.get_port(),
+                addr.get_flowinfo(),
+                addr.get_scope_id(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn socket_addr_v4() {
+        let addr: SocketAddr = "192.168.1.1:443".parse().unwrap();
+        let inet_addr = InetSocketAddress::from(addr);
+        assert_eq!(inet_addr.get_port(), 443);
+        assert_eq!(SocketAddr::from(inet_addr), addr);
+    }
+
+    #[test]
+    fn socket_addr_v6() {
+        let addr = SocketAddr::V6(SocketAddrV6::new("fe80::1".parse().unwrap(), 8080, 12, 3));
+        let inet_addr = InetSocketAddress::from(addr);
+        assert_eq!(inet_addr.get_flowinfo(), 12);
+        assert_eq!(inet_addr.get_scope_id(), 3);
+        assert_eq!(SocketAddr::from(&inet_addr), addr);
     }
 }
@@ -2,8 +2,10 @@
 
 use crate::pollable_input_stream::PollableInputStreamExtManual;
 use crate::pollable_output_stream::PollableOutputStreamExtManual;
+use crate::Cancellable;
 use crate::IOStream;
 use crate::IOStreamExt;
+use crate::IOStreamSpliceFlags;
 use crate::InputStreamAsyncRead;
 use crate::OutputStreamAsyncWrite;
 use crate::PollableInputStream;
@@ -11,8 +13,10 @@ use crate::PollableOutputStream;
 use futures_core::task::{Context, Poll};
 use futures_io::{AsyncRead, AsyncWrite};
 use glib::object::{Cast, IsA};
+use glib::translate::*;
 use std::io;
 use std::pin::Pin;
+use std::ptr;
 
 pub trait IOStreamExtManual: Sized + IsA<IOStream> {
     fn into_async_read_write(self) -> Result<IOStreamAsyncReadWrite<Self>, Self> {
@@ -39,6 +43,131 @@ pub trait IOStreamExtManual: Sized + IsA<IOStream> {
             write,
         })
     }
+
+    /// Asynchronously splices the output stream of `self` to the input stream of `stream2`
+    /// and the output stream of `stream2` to the input stream of `self`.
+    ///
+    /// By default the operation finishes once one of the two directions has finished, use
+    /// `IOStreamSpliceFlags::WAIT_FOR_BOTH` to wait until both have finished.
+    ///
+    /// If `IOStreamSpliceFlags::CLOSE_STREAM1` or `IOStreamSpliceFlags::CLOSE_STREAM2` are
+    /// given, the corresponding streams are closed *before* `callback` is called. When the
+    /// callback is called, no further operations are pending on either stream.
+    #[doc(alias = "g_io_stream_splice_async")]
+    fn splice_async<
+        Q: IsA<IOStream>,
+        P: IsA<Cancellable>,
+        R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+    >(
+        &self,
+        stream2: &Q,
+        flags: IOStreamSpliceFlags,
+        io_priority: glib::Priority,
+        cancellable: Option<&P>,
+        callback: R,
+    ) {
+        let user_data: Box<R> = Box::new(callback);
+        unsafe extern "C" fn splice_async_trampoline<
+            R: FnOnce(Result<(), glib::Error>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut error = ptr::null_mut();
+            let _ = ffi::g_io_stream_splice_finish(res, &mut error);
+            let result = if error.is_null() {
+                Ok(())
+            } else {
+                Err(from_glib_full(error))
+            };
+            let callback: Box<R> = Box::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        let callback = splice_async_trampoline::<R>;
+        unsafe {
+            ffi::g_io_stream_splice_async(
+                self.as_ref().to_glib_none().0,
+                stream2.as_ref().to_glib_none().0,
+                flags.to_glib(),
+                io_priority.to_glib(),
+                cancellable.map(|p| p.as_ref()).to_glib_none().0,
+                Some(callback),
+                Box::into_raw(user_data) as *mut _,
+            );
+        }
+    }
+
+    /// Future version of [`splice_async`](Self::splice_async).
+    ///
+    /// `IOStreamSpliceFlags::CLOSE_STREAM1` and `IOStreamSpliceFlags::CLOSE_STREAM2` are handled
+    /// here instead of by GIO: once splicing has finished, `self` is closed first and `stream2`
+    /// second, and the future only resolves after both closes have completed. An error from
+    /// splicing takes precedence over errors from closing.
+    ///
+    /// Dropping the future cancels the splice operation, and the streams are left open.
+    ///
+    /// ```no_run
+    /// use gio::prelude::*;
+    ///
+    /// async fn forward(
+    ///     client: gio::SocketConnection,
+    ///     upstream: gio::SocketConnection,
+    /// ) -> Result<(), glib::Error> {
+    ///     client
+    ///         .splice_future(
+    ///             &upstream,
+    ///             gio::IOStreamSpliceFlags::CLOSE_STREAM1 | gio::IOStreamSpliceFlags::CLOSE_STREAM2,
+    ///             glib::PRIORITY_DEFAULT,
+    ///         )
+    ///         .await
+    /// }
+    /// ```
+    fn splice_future<Q: IsA<IOStream>>(
+        &self,
+        stream2: &Q,
+        flags: IOStreamSpliceFlags,
+        io_priority: glib::Priority,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+        let stream1 = self.as_ref().clone();
+        let stream2 = stream2.as_ref().clone();
+        let close_flags = IOStreamSpliceFlags::CLOSE_STREAM1 | IOStreamSpliceFlags::CLOSE_STREAM2;
+
+        let splice = {
+            let stream2 = stream2.clone();
+            crate::GioFuture::new(&stream1, move |obj, send| {
+                let cancellable = Cancellable::new();
+                obj.splice_async(
+                    &stream2,
+                    flags - close_flags,
+                    io_priority,
+                    Some(&cancellable),
+                    move |res| {
+                        send.resolve(res);
+                    },
+                );
+
+                cancellable
+            })
+        };
+
+        Box::pin(async move {
+            let res = splice.await;
+
+            let close1 = if flags.contains(IOStreamSpliceFlags::CLOSE_STREAM1) {
+                stream1.close_async_future(io_priority).await
+            } else {
+                Ok(())
+            };
+            let close2 = if flags.contains(IOStreamSpliceFlags::CLOSE_STREAM2) {
+                stream2.close_async_future(io_priority).await
+            } else {
+                Ok(())
+            };
+
+            res.and(close1).and(close2)
+        })
+    }
 }
 
 impl<O: IsA<IOStream>> IOStreamExtManual for O {}
@@ -95,3 +224,97 @@ impl<T: IsA<IOStream> + std::marker::Unpin> AsyncWrite for IOStreamAsyncReadWrit
         Pin::new(&mut Pin::get_mut(self).write).poll_flush(cx)
     }
 }
+
+#[cfg(all(test, any(feature = "v2_44", feature = "dox")))]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_util::run_async_local;
+    use crate::{
+        IOStreamSpliceFlags, MemoryInputStream, MemoryOutputStream, MemoryOutputStreamExt,
+        SimpleIOStream,
+    };
+
+    #[test]
+    fn splice_future() {
+        let ret = run_async_local(|tx, l| {
+            let out1 = MemoryOutputStream::new_resizable();
+            let out2 = MemoryOutputStream::new_resizable();
+            let stream1 = SimpleIOStream::new(
+                &MemoryInputStream::from_bytes(&glib::Bytes::from_static(b"ping")),
+                &out1,
+            );
+            let stream2 = SimpleIOStream::new(
+                &MemoryInputStream::from_bytes(&glib::Bytes::from_static(b"pong")),
+                &out2,
+            );
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let res = stream1
+                    .splice_future(
+                        &stream2,
+                        IOStreamSpliceFlags::WAIT_FOR_BOTH
+                            | IOStreamSpliceFlags::CLOSE_STREAM1
+                            | IOStreamSpliceFlags::CLOSE_STREAM2,
+                        glib::PRIORITY_DEFAULT,
+                    )
+                    .await;
+                assert!(stream1.is_closed());
+                assert!(stream2.is_closed());
+                tx.send((res, out1.steal_as_bytes(), out2.steal_as_bytes()))
+                    .unwrap();
+                l.quit();
+            });
+        });
+
+        let (res, out1, out2) = ret;
+        assert!(res.is_ok());
+        assert_eq!(&*out1, b"pong");
+        assert_eq!(&*out2, b"ping");
+    }
+
+    #[test]
+    fn splice_future_close_flags() {
+        let ret = run_async_local(|tx, l| {
+            let new_stream = |data: &'static [u8]| {
+                SimpleIOStream::new(
+                    &MemoryInputStream::from_bytes(&glib::Bytes::from_static(data)),
+                    &MemoryOutputStream::new_resizable(),
+                )
+            };
+            let stream1 = new_stream(b"ping");
+            let stream2 = new_stream(b"pong");
+            let stream3 = new_stream(b"pang");
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let res1 = stream1
+                    .splice_future(
+                        &stream2,
+                        IOStreamSpliceFlags::WAIT_FOR_BOTH | IOStreamSpliceFlags::CLOSE_STREAM2,
+                        glib::PRIORITY_DEFAULT,
+                    )
+                    .await;
+                assert!(!stream1.is_closed());
+                assert!(stream2.is_closed());
+
+                let res2 = stream3
+                    .splice_future(
+                        &stream1,
+                        IOStreamSpliceFlags::CLOSE_STREAM1,
+                        glib::PRIORITY_DEFAULT,
+                    )
+                    .await;
+                assert!(stream3.is_closed());
+                assert!(!stream1.is_closed());
+
+                tx.send((res1, res2)).unwrap();
+                l.quit();
+            });
+        });
+
+        let (res1, res2) = ret;
+        assert!(res1.is_ok());
+        assert!(res2.is_ok());
+    }
+}
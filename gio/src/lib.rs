@@ -22,17 +22,27 @@ pub use self::dbus_connection::{
     ActionGroupExportId, FilterId, MenuModelExportId, RegistrationId, SignalSubscriptionId,
     WatcherId,
 };
+mod dbus_introspection;
 mod dbus_message;
 mod dbus_method_invocation;
 #[cfg(any(all(not(windows), not(target_os = "macos")), feature = "dox"))]
 mod desktop_app_info;
+mod download;
+pub use crate::download::{download_future, Download, DownloadProgress};
+mod drive;
+pub use crate::drive::DriveState;
+mod enums;
 mod error;
+pub use crate::error::IOErrorExt;
 mod file;
 mod file_attribute_matcher;
 pub use crate::file_attribute_matcher::FileAttributematcherIter;
 mod file_enumerator;
 mod file_info;
 mod flags;
+mod functions;
+pub use crate::functions::*;
+pub mod http;
 mod inet_address;
 mod inet_socket_address;
 mod io_stream;
@@ -42,17 +52,23 @@ pub use crate::input_stream::{InputStreamAsyncBufRead, InputStreamRead};
 #[cfg(any(feature = "v2_44", feature = "dox"))]
 mod list_store;
 mod memory_input_stream;
+#[cfg(any(feature = "v2_64", feature = "dox"))]
+mod memory_monitor;
 mod memory_output_stream;
 mod output_stream;
-pub use crate::output_stream::OutputStreamWrite;
+pub use crate::output_stream::{OutputStreamWrite, OutputVector};
 mod pollable_input_stream;
 pub use crate::pollable_input_stream::InputStreamAsyncRead;
 mod pollable_output_stream;
 pub use crate::pollable_output_stream::OutputStreamAsyncWrite;
+pub mod portal;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+mod power_profile_monitor;
 mod resource;
 pub use crate::resource::{compile_resources, resources_register_include_impl};
 mod settings;
 mod socket;
+mod socket_address;
 mod subprocess;
 mod subprocess_launcher;
 mod threaded_socket_service;
@@ -65,6 +81,9 @@ mod unix_input_stream;
 mod unix_mount_entry;
 #[cfg(any(unix, feature = "dox"))]
 #[cfg(any(feature = "v2_54", feature = "dox"))]
+pub use crate::unix_mount_entry::UnixMountEntry;
+#[cfg(any(unix, feature = "dox"))]
+#[cfg(any(feature = "v2_54", feature = "dox"))]
 mod unix_mount_point;
 #[cfg(any(unix, feature = "dox"))]
 mod unix_output_stream;
@@ -97,6 +116,30 @@ mod read_input_stream;
 pub use crate::read_input_stream::ReadInputStream;
 mod write_output_stream;
 pub use crate::write_output_stream::WriteOutputStream;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod filter;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::filter::{CustomFilter, Filter};
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod filter_list_model;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::filter_list_model::FilterListModel;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod flatten_list_model;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::flatten_list_model::FlattenListModel;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod map_list_model;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::map_list_model::MapListModel;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod sort_list_model;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::sort_list_model::SortListModel;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod sorter;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::sorter::{CustomSorter, Sorter};
 mod tls_connection;
 pub use crate::tls_connection::TlsConnectionManualExt;
 
@@ -0,0 +1,184 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::*;
+use crate::subclass::prelude::*;
+use crate::ListModel;
+use glib::{Object, SignalHandlerId};
+use std::rc::Rc;
+
+type MapFunc = dyn Fn(&Object) -> Object;
+
+mod imp {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    pub struct MapListModel {
+        pub(super) model: RefCell<Option<(ListModel, SignalHandlerId)>>,
+        pub(super) item_type: Cell<glib::Type>,
+        pub(super) map_func: RefCell<Option<Rc<MapFunc>>>,
+        // Mapped items are created on demand and cached so that the same object is returned
+        // every time an item is retrieved
+        pub(super) items: RefCell<Vec<Option<Object>>>,
+    }
+
+    impl Default for MapListModel {
+        fn default() -> Self {
+            Self {
+                model: RefCell::new(None),
+                item_type: Cell::new(glib::Type::OBJECT),
+                map_func: RefCell::new(None),
+                items: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for MapListModel {
+        const NAME: &'static str = "GioRsMapListModel";
+        type Type = super::MapListModel;
+        type ParentType = Object;
+        type Interfaces = (ListModel,);
+    }
+
+    impl ObjectImpl for MapListModel {
+        fn dispose(&self, _obj: &Self::Type) {
+            if let Some((model, handler_id)) = self.model.borrow_mut().take() {
+                model.disconnect(handler_id);
+            }
+        }
+    }
+
+    impl ListModelImpl for MapListModel {
+        fn get_item_type(&self, _list_model: &Self::Type) -> glib::Type {
+            self.item_type.get()
+        }
+
+        fn get_n_items(&self, _list_model: &Self::Type) -> u32 {
+            self.items.borrow().len() as u32
+        }
+
+        fn get_item(&self, _list_model: &Self::Type, position: u32) -> Option<Object> {
+            match self.items.borrow().get(position as usize) {
+                None => return None,
+                Some(Some(item)) => return Some(item.clone()),
+                Some(None) => (),
+            }
+
+            let item = self
+                .model
+                .borrow()
+                .as_ref()
+                .and_then(|(model, _)| model.get_object(position))?;
+            let map_func = self.map_func.borrow().clone()?;
+            let mapped = map_func(&item);
+
+            // The map function might have changed the model
+            if let Some(cached) = self.items.borrow_mut().get_mut(position as usize) {
+                *cached = Some(mapped.clone());
+            }
+            Some(mapped)
+        }
+    }
+}
+
+glib::wrapper! {
+    /// A [`ListModel`] that contains the items of another model, mapped with a Rust closure.
+    ///
+    /// Mapped items are created when they are first retrieved and are kept until the
+    /// corresponding item is removed from the underlying model or the map function changes.
+    pub struct MapListModel(ObjectSubclass<imp::MapListModel>) @implements ListModel;
+}
+
+impl MapListModel {
+    /// Creates a new model that contains the items of `model` mapped with `map_func`.
+    ///
+    /// `item_type` is the type of the objects returned by `map_func`.
+    pub fn new<P: IsA<ListModel>, F: Fn(&Object) -> Object + 'static>(
+        model: &P,
+        item_type: glib::Type,
+        map_func: F,
+    ) -> Self {
+        let obj = glib::Object::new::<Self>(&[]).expect("Failed to create map list model");
+
+        let imp = imp::MapListModel::from_instance(&obj);
+        imp.item_type.set(item_type);
+        *imp.map_func.borrow_mut() = Some(Rc::new(map_func));
+        *imp.items.borrow_mut() = vec![None; model.get_n_items() as usize];
+
+        let model: &ListModel = model.as_ref();
+        let weak = obj.downgrade();
+        let handler_id = model.connect_items_changed(move |_, position, removed, added| {
+            if let Some(obj) = weak.upgrade() {
+                obj.source_items_changed(position, removed, added);
+            }
+        });
+        *imp.model.borrow_mut() = Some((model.clone(), handler_id));
+
+        obj
+    }
+
+    /// Returns the underlying model.
+    pub fn get_model(&self) -> Option<ListModel> {
+        let imp = imp::MapListModel::from_instance(self);
+        imp.model.borrow().as_ref().map(|(model, _)| model.clone())
+    }
+
+    /// Replaces the map function and maps all items again.
+    pub fn set_map_func<F: Fn(&Object) -> Object + 'static>(&self, map_func: F) {
+        let imp = imp::MapListModel::from_instance(self);
+        *imp.map_func.borrow_mut() = Some(Rc::new(map_func));
+
+        let n_items = {
+            let mut items = imp.items.borrow_mut();
+            items.iter_mut().for_each(|item| *item = None);
+            items.len() as u32
+        };
+        if n_items > 0 {
+            self.items_changed(0, n_items, n_items);
+        }
+    }
+
+    fn source_items_changed(&self, position: u32, removed: u32, added: u32) {
+        let imp = imp::MapListModel::from_instance(self);
+        {
+            let mut items = imp.items.borrow_mut();
+            let start = position as usize;
+            let end = start + removed as usize;
+            items.splice(start..end, std::iter::repeat(None).take(added as usize));
+        }
+        self.items_changed(position, removed, added);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{action_names, action_store, record_items_changed};
+    use crate::SimpleAction;
+
+    fn map_name<F: Fn(&str) -> String>(item: &Object, f: F) -> Object {
+        let name = item.downcast_ref::<SimpleAction>().unwrap().get_name();
+        SimpleAction::new(&f(&name), None).upcast()
+    }
+
+    #[test]
+    fn map() {
+        let store = action_store(&["a", "b"]);
+        let map = MapListModel::new(&store, SimpleAction::static_type(), |item| {
+            map_name(item, str::to_uppercase)
+        });
+        let changes = record_items_changed(&map);
+        assert_eq!(map.get_item_type(), SimpleAction::static_type());
+        assert_eq!(action_names(&map), vec!["A", "B"]);
+        assert_eq!(map.get_object(0), map.get_object(0));
+
+        store.insert(0, &SimpleAction::new("z", None));
+        assert_eq!(action_names(&map), vec!["Z", "A", "B"]);
+        assert_eq!(*changes.borrow(), vec![(0, 0, 1)]);
+
+        changes.borrow_mut().clear();
+        map.set_map_func(|item| map_name(item, |name| format!("{}{}", name, name)));
+        assert_eq!(action_names(&map), vec!["zz", "aa", "bb"]);
+        assert_eq!(*changes.borrow(), vec![(0, 3, 3)]);
+    }
+}
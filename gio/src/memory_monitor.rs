@@ -0,0 +1,75 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::MemoryMonitor;
+use crate::MemoryMonitorExt;
+use crate::MemoryMonitorWarningLevel;
+use futures_core::stream::Stream;
+use glib::object::IsA;
+use std::pin::Pin;
+
+pub trait MemoryMonitorExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns a `Stream` of the warning levels emitted by the `low-memory-warning` signal.
+    ///
+    /// Applications should drop caches and other data that can be reloaded when a warning is
+    /// received, the more the higher the level is. The signal handler is disconnected once the
+    /// stream is dropped.
+    fn low_memory_warning_stream(
+        &self,
+    ) -> Pin<Box<dyn Stream<Item = MemoryMonitorWarningLevel> + 'static>>;
+}
+
+impl<O: IsA<MemoryMonitor>> MemoryMonitorExtManual for O {
+    fn low_memory_warning_stream(
+        &self,
+    ) -> Pin<Box<dyn Stream<Item = MemoryMonitorWarningLevel> + 'static>> {
+        Box::pin(glib::signal_stream(self.as_ref(), |monitor, sender| {
+            vec![monitor.connect_low_memory_warning(move |_, level| {
+                let _ = sender.unbounded_send(level);
+            })]
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::run_async;
+    use futures_util::StreamExt;
+    use glib::ObjectExt;
+
+    #[test]
+    fn low_memory_warning_stream() {
+        let res = run_async(|tx, l| {
+            let monitor = MemoryMonitor::dup_default();
+            let mut stream = monitor.low_memory_warning_stream();
+
+            monitor
+                .emit_by_name("low-memory-warning", &[&MemoryMonitorWarningLevel::Medium])
+                .unwrap();
+            monitor
+                .emit_by_name(
+                    "low-memory-warning",
+                    &[&MemoryMonitorWarningLevel::Critical],
+                )
+                .unwrap();
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let first = stream.next().await;
+                let second = stream.next().await;
+                tx.send((first, second)).unwrap();
+                l.quit();
+                drop(monitor);
+            });
+        });
+
+        assert_eq!(
+            res,
+            (
+                Some(MemoryMonitorWarningLevel::Medium),
+                Some(MemoryMonitorWarningLevel::Critical)
+            )
+        );
+    }
+}
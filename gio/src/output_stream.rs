@@ -9,11 +9,67 @@ use crate::SeekableExt;
 use glib::object::IsA;
 use glib::translate::*;
 use glib::Priority;
+use std::fmt;
 use std::io;
+use std::marker::PhantomData;
 use std::mem;
+use std::ops::Deref;
 use std::pin::Pin;
 use std::ptr;
 
+// rustdoc-stripper-ignore-next
+/// A buffer to be written with [`OutputStreamExtManual::writev`] and
+/// [`OutputStreamExtManual::writev_all`].
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+#[doc(alias = "GOutputVector")]
+pub struct OutputVector<'v> {
+    inner: ffi::GOutputVector,
+    phantom: PhantomData<&'v [u8]>,
+}
+
+impl<'v> OutputVector<'v> {
+    pub fn new(buffer: &'v [u8]) -> Self {
+        OutputVector {
+            inner: ffi::GOutputVector {
+                buffer: buffer.as_ptr() as *const _,
+                size: buffer.len(),
+            },
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'v> Deref for OutputVector<'v> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.inner.buffer as *const u8, self.inner.size) }
+    }
+}
+
+impl<'v> fmt::Debug for OutputVector<'v> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OutputVector").field(&&**self).finish()
+    }
+}
+
+// The vectors of the buffers of an asynchronous write, which have to stay alive together with
+// the buffers until the write finished.
+#[cfg(any(feature = "v2_60", feature = "dox"))]
+fn output_vectors<B: AsRef<[u8]>>(buffers: &[B]) -> Vec<ffi::GOutputVector> {
+    buffers
+        .iter()
+        .map(|buffer| {
+            let buffer = buffer.as_ref();
+            ffi::GOutputVector {
+                buffer: buffer.as_ptr() as *const _,
+                size: buffer.len(),
+            }
+        })
+        .collect()
+}
+
 pub trait OutputStreamExtManual: Sized + OutputStreamExt {
     fn write_async<
         B: AsRef<[u8]> + Send + 'static,
@@ -67,6 +123,83 @@ pub trait OutputStreamExtManual: Sized + OutputStreamExt {
         >,
     >;
 
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    #[doc(alias = "g_output_stream_writev")]
+    fn writev<C: IsA<Cancellable>>(
+        &self,
+        vectors: &[OutputVector],
+        cancellable: Option<&C>,
+    ) -> Result<usize, glib::Error>;
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    #[doc(alias = "g_output_stream_writev_all")]
+    fn writev_all<C: IsA<Cancellable>>(
+        &self,
+        vectors: &[OutputVector],
+        cancellable: Option<&C>,
+    ) -> Result<(usize, Option<glib::Error>), glib::Error>;
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    #[doc(alias = "g_output_stream_writev_async")]
+    fn writev_async<
+        B: AsRef<[u8]> + Send + 'static,
+        Q: FnOnce(Result<(Vec<B>, usize), (Vec<B>, glib::Error)>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+        cancellable: Option<&C>,
+        callback: Q,
+    );
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    #[doc(alias = "g_output_stream_writev_all_async")]
+    fn writev_all_async<
+        B: AsRef<[u8]> + Send + 'static,
+        Q: FnOnce(Result<(Vec<B>, usize, Option<glib::Error>), (Vec<B>, glib::Error)>)
+            + Send
+            + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+        cancellable: Option<&C>,
+        callback: Q,
+    );
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev_async_future<B: AsRef<[u8]> + Send + 'static>(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+    ) -> Pin<
+        Box<
+            dyn std::future::Future<Output = Result<(Vec<B>, usize), (Vec<B>, glib::Error)>>
+                + 'static,
+        >,
+    >;
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev_all_async_future<B: AsRef<[u8]> + Send + 'static>(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+    ) -> Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<(Vec<B>, usize, Option<glib::Error>), (Vec<B>, glib::Error)>,
+                > + 'static,
+        >,
+    >;
+
     fn into_write(self) -> OutputStreamWrite<Self>
     where
         Self: IsA<OutputStream>,
@@ -264,6 +397,243 @@ impl<O: IsA<OutputStream>> OutputStreamExtManual for O {
             cancellable
         }))
     }
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev<C: IsA<Cancellable>>(
+        &self,
+        vectors: &[OutputVector],
+        cancellable: Option<&C>,
+    ) -> Result<usize, glib::Error> {
+        unsafe {
+            let mut bytes_written = mem::MaybeUninit::uninit();
+            let mut error = ptr::null_mut();
+            let _ = ffi::g_output_stream_writev(
+                self.as_ref().to_glib_none().0,
+                vectors.as_ptr() as *const ffi::GOutputVector,
+                vectors.len(),
+                bytes_written.as_mut_ptr(),
+                cancellable.map(|c| c.as_ref()).to_glib_none().0,
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(bytes_written.assume_init())
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev_all<C: IsA<Cancellable>>(
+        &self,
+        vectors: &[OutputVector],
+        cancellable: Option<&C>,
+    ) -> Result<(usize, Option<glib::Error>), glib::Error> {
+        // `g_output_stream_writev_all()` modifies the vectors while writing
+        let mut vectors = vectors.to_vec();
+        unsafe {
+            let mut bytes_written = mem::MaybeUninit::uninit();
+            let mut error = ptr::null_mut();
+            let _ = ffi::g_output_stream_writev_all(
+                self.as_ref().to_glib_none().0,
+                vectors.as_mut_ptr() as *mut ffi::GOutputVector,
+                vectors.len(),
+                bytes_written.as_mut_ptr(),
+                cancellable.map(|c| c.as_ref()).to_glib_none().0,
+                &mut error,
+            );
+
+            let bytes_written = bytes_written.assume_init();
+            if error.is_null() {
+                Ok((bytes_written, None))
+            } else if bytes_written != 0 {
+                Ok((bytes_written, Some(from_glib_full(error))))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev_async<
+        B: AsRef<[u8]> + Send + 'static,
+        Q: FnOnce(Result<(Vec<B>, usize), (Vec<B>, glib::Error)>) + Send + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+        cancellable: Option<&C>,
+        callback: Q,
+    ) {
+        let cancellable = cancellable.map(|c| c.as_ref());
+        let gcancellable = cancellable.to_glib_none();
+        let vectors = output_vectors(&buffers);
+        let user_data: Box<Option<(Q, Vec<B>, Vec<ffi::GOutputVector>)>> =
+            Box::new(Some((callback, buffers, vectors)));
+        let (vectors_ptr, n_vectors) = {
+            let vectors = &(*user_data).as_ref().unwrap().2;
+            (vectors.as_ptr(), vectors.len())
+        };
+        unsafe extern "C" fn writev_async_trampoline<
+            B: AsRef<[u8]> + Send + 'static,
+            Q: FnOnce(Result<(Vec<B>, usize), (Vec<B>, glib::Error)>) + Send + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut user_data: Box<Option<(Q, Vec<B>, Vec<ffi::GOutputVector>)>> =
+                Box::from_raw(user_data as *mut _);
+            let (callback, buffers, _) = user_data.take().unwrap();
+
+            let mut error = ptr::null_mut();
+            let mut bytes_written = mem::MaybeUninit::uninit();
+            let _ = ffi::g_output_stream_writev_finish(
+                _source_object as *mut _,
+                res,
+                bytes_written.as_mut_ptr(),
+                &mut error,
+            );
+            let result = if error.is_null() {
+                Ok((buffers, bytes_written.assume_init()))
+            } else {
+                Err((buffers, from_glib_full(error)))
+            };
+            callback(result);
+        }
+        let callback = writev_async_trampoline::<B, Q>;
+        unsafe {
+            ffi::g_output_stream_writev_async(
+                self.as_ref().to_glib_none().0,
+                vectors_ptr,
+                n_vectors,
+                io_priority.to_glib(),
+                gcancellable.0,
+                Some(callback),
+                Box::into_raw(user_data) as *mut _,
+            );
+        }
+    }
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev_all_async<
+        B: AsRef<[u8]> + Send + 'static,
+        Q: FnOnce(Result<(Vec<B>, usize, Option<glib::Error>), (Vec<B>, glib::Error)>)
+            + Send
+            + 'static,
+        C: IsA<Cancellable>,
+    >(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+        cancellable: Option<&C>,
+        callback: Q,
+    ) {
+        let cancellable = cancellable.map(|c| c.as_ref());
+        let gcancellable = cancellable.to_glib_none();
+        let vectors = output_vectors(&buffers);
+        let mut user_data: Box<Option<(Q, Vec<B>, Vec<ffi::GOutputVector>)>> =
+            Box::new(Some((callback, buffers, vectors)));
+        // The vectors are modified while writing
+        let (vectors_ptr, n_vectors) = {
+            let vectors = &mut (*user_data).as_mut().unwrap().2;
+            (vectors.as_mut_ptr(), vectors.len())
+        };
+        unsafe extern "C" fn writev_all_async_trampoline<
+            B: AsRef<[u8]> + Send + 'static,
+            Q: FnOnce(Result<(Vec<B>, usize, Option<glib::Error>), (Vec<B>, glib::Error)>)
+                + Send
+                + 'static,
+        >(
+            _source_object: *mut glib::gobject_ffi::GObject,
+            res: *mut ffi::GAsyncResult,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let mut user_data: Box<Option<(Q, Vec<B>, Vec<ffi::GOutputVector>)>> =
+                Box::from_raw(user_data as *mut _);
+            let (callback, buffers, _) = user_data.take().unwrap();
+
+            let mut error = ptr::null_mut();
+            let mut bytes_written = mem::MaybeUninit::uninit();
+            let _ = ffi::g_output_stream_writev_all_finish(
+                _source_object as *mut _,
+                res,
+                bytes_written.as_mut_ptr(),
+                &mut error,
+            );
+            let bytes_written = bytes_written.assume_init();
+            let result = if error.is_null() {
+                Ok((buffers, bytes_written, None))
+            } else if bytes_written != 0 {
+                Ok((buffers, bytes_written, from_glib_full(error)))
+            } else {
+                Err((buffers, from_glib_full(error)))
+            };
+            callback(result);
+        }
+        let callback = writev_all_async_trampoline::<B, Q>;
+        unsafe {
+            ffi::g_output_stream_writev_all_async(
+                self.as_ref().to_glib_none().0,
+                vectors_ptr,
+                n_vectors,
+                io_priority.to_glib(),
+                gcancellable.0,
+                Some(callback),
+                Box::into_raw(user_data) as *mut _,
+            );
+        }
+    }
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev_async_future<B: AsRef<[u8]> + Send + 'static>(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+    ) -> Pin<
+        Box<
+            dyn std::future::Future<Output = Result<(Vec<B>, usize), (Vec<B>, glib::Error)>>
+                + 'static,
+        >,
+    > {
+        Box::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.writev_async(buffers, io_priority, Some(&cancellable), move |res| {
+                send.resolve(res);
+            });
+
+            cancellable
+        }))
+    }
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn writev_all_async_future<B: AsRef<[u8]> + Send + 'static>(
+        &self,
+        buffers: Vec<B>,
+        io_priority: Priority,
+    ) -> Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<(Vec<B>, usize, Option<glib::Error>), (Vec<B>, glib::Error)>,
+                > + 'static,
+        >,
+    > {
+        Box::pin(crate::GioFuture::new(self, move |obj, send| {
+            let cancellable = Cancellable::new();
+            obj.writev_all_async(buffers, io_priority, Some(&cancellable), move |res| {
+                send.resolve(res);
+            });
+
+            cancellable
+        }))
+    }
 }
 
 #[derive(Debug)]
@@ -314,8 +684,12 @@ impl<T: IsA<OutputStream> + IsA<Seekable>> io::Seek for OutputStreamWrite<T> {
 mod tests {
     use crate::prelude::*;
     use crate::test_util::run_async;
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    use crate::test_util::run_async_local;
     use crate::MemoryInputStream;
     use crate::MemoryOutputStream;
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    use crate::OutputVector;
     use glib::Bytes;
     use std::io::Write;
 
@@ -388,6 +762,50 @@ mod tests {
         assert!(err.is_none());
     }
 
+    #[test]
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    fn writev() {
+        let strm = MemoryOutputStream::new_resizable();
+
+        let vectors = [OutputVector::new(b"hello "), OutputVector::new(b"world")];
+        assert_eq!(&*vectors[1], b"world");
+        let size = strm.writev(&vectors, crate::NONE_CANCELLABLE).unwrap();
+        assert_eq!(size, 11);
+        let (size, err) = strm
+            .writev_all(&[OutputVector::new(b"!")], crate::NONE_CANCELLABLE)
+            .unwrap();
+        assert_eq!(size, 1);
+        assert!(err.is_none());
+
+        strm.close(crate::NONE_CANCELLABLE).unwrap();
+        assert_eq!(strm.steal_as_bytes(), b"hello world!".as_ref());
+    }
+
+    #[test]
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    fn writev_all_async_future() {
+        let ret = run_async_local(|tx, l| {
+            let strm = MemoryOutputStream::new_resizable();
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let res = strm
+                    .writev_all_async_future(vec![vec![1, 2], vec![3]], glib::PRIORITY_DEFAULT)
+                    .await;
+                strm.close(crate::NONE_CANCELLABLE).unwrap();
+                tx.send((res, strm.steal_as_bytes())).unwrap();
+                l.quit();
+            });
+        });
+
+        let (res, bytes) = ret;
+        let (buffers, size, err) = res.unwrap();
+        assert_eq!(buffers, vec![vec![1, 2], vec![3]]);
+        assert_eq!(size, 3);
+        assert!(err.is_none());
+        assert_eq!(bytes, [1, 2, 3].as_ref());
+    }
+
     #[test]
     fn write_bytes_async() {
         let ret = run_async(|tx, l| {
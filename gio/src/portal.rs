@@ -0,0 +1,490 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Helpers for calling [XDG desktop portals](https://flatpak.github.io/xdg-desktop-portal/).
+//!
+//! Portals are D-Bus interfaces of the `org.freedesktop.portal.Desktop` service that allow
+//! sandboxed applications to access resources outside of the sandbox. Most portal methods
+//! return a request object that emits a `Response` signal once the user interacted with the
+//! dialog shown by the portal, which is handled by [`request_future`].
+//!
+//! The `parent_window` arguments are window handles as described in the portal documentation,
+//! e.g. the string returned by `gdk::WindowExtManual::export_handle`, or an empty string if the
+//! application has no window.
+//!
+//! ```no_run
+//! # async fn open(connection: &gio::DBusConnection) -> Result<(), glib::Error> {
+//! gio::portal::open_uri_future(connection, "", "https://gtk-rs.org", false).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{DBusCallFlags, DBusConnection, DBusSignalFlags, IOErrorEnum, SignalSubscriptionId};
+use futures_channel::oneshot;
+use glib::{ToVariant, Variant, VariantDict, VariantTy};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const PORTAL_BUS_NAME: &str = "org.freedesktop.portal.Desktop";
+const PORTAL_OBJECT_PATH: &str = "/org/freedesktop/portal/desktop";
+const REQUEST_INTERFACE: &str = "org.freedesktop.portal.Request";
+
+static HANDLE_TOKEN_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+type ResponseSender = Rc<RefCell<Option<oneshot::Sender<(u32, VariantDict)>>>>;
+
+/// Calls `method` of the portal `interface` and waits for the `Response` signal of the
+/// returned request.
+///
+/// `args` are the arguments of the method before the trailing options vardict, `options` is
+/// passed as that vardict with a `handle_token` added to it. On success the results of the
+/// response are returned, a response cancelled by the user results in an
+/// [`IOErrorEnum::Cancelled`] error and any other unsuccessful response in an
+/// [`IOErrorEnum::Failed`] error.
+pub fn request_future(
+    connection: &DBusConnection,
+    interface: &str,
+    method: &str,
+    args: &[Variant],
+    options: VariantDict,
+) -> Pin<Box<dyn Future<Output = Result<VariantDict, glib::Error>> + 'static>> {
+    let connection = connection.clone();
+    let interface = String::from(interface);
+    let method = String::from(method);
+    let mut args = args.to_vec();
+
+    Box::pin(async move {
+        let unique_name = connection.get_unique_name().ok_or_else(|| {
+            glib::Error::new(IOErrorEnum::NotConnected, "Connection has no unique name")
+        })?;
+        let token = format!(
+            "gtk_rs_{}_{}",
+            std::process::id(),
+            HANDLE_TOKEN_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = format!(
+            "{}/request/{}/{}",
+            PORTAL_OBJECT_PATH,
+            unique_name.trim_start_matches(':').replace('.', "_"),
+            token
+        );
+
+        // Subscribe before calling the method so that no response can be missed
+        let (sender, receiver) = oneshot::channel();
+        let sender: ResponseSender = Rc::new(RefCell::new(Some(sender)));
+        let mut subscription = RequestSubscription::new(&connection, &path, &sender);
+
+        options.insert("handle_token", &token);
+        args.push(options.end());
+        let reply = connection
+            .call_future(
+                Some(PORTAL_BUS_NAME),
+                PORTAL_OBJECT_PATH,
+                &interface,
+                &method,
+                Some(&Variant::tuple(&args)),
+                Some(VariantTy::new("(o)").unwrap()),
+                DBusCallFlags::NONE,
+                -1,
+            )
+            .await?;
+
+        // Portals before version 0.9 ignore the handle token
+        let handle = reply.get_child_value(0);
+        if let Some(handle) = handle.get_str() {
+            if handle != path {
+                subscription = RequestSubscription::new(&connection, handle, &sender);
+            }
+        }
+
+        let (response, results) = receiver.await.map_err(|_| {
+            glib::Error::new(IOErrorEnum::Failed, "Request was closed without response")
+        })?;
+        drop(subscription);
+
+        match response {
+            0 => Ok(results),
+            1 => Err(glib::Error::new(
+                IOErrorEnum::Cancelled,
+                "Request was cancelled by the user",
+            )),
+            _ => Err(glib::Error::new(IOErrorEnum::Failed, "Request failed")),
+        }
+    })
+}
+
+/// Asks the `org.freedesktop.portal.OpenURI` portal to open `uri` with the default
+/// application, or with an application chosen by the user if `ask` is `true`.
+pub fn open_uri_future(
+    connection: &DBusConnection,
+    parent_window: &str,
+    uri: &str,
+    ask: bool,
+) -> Pin<Box<dyn Future<Output = Result<(), glib::Error>> + 'static>> {
+    let options = VariantDict::new(None);
+    options.insert("ask", &ask);
+
+    let request = request_future(
+        connection,
+        "org.freedesktop.portal.OpenURI",
+        "OpenURI",
+        &[parent_window.to_variant(), uri.to_variant()],
+        options,
+    );
+    Box::pin(async move { request.await.map(|_| ()) })
+}
+
+/// The permissions granted by the `org.freedesktop.portal.Background` portal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackgroundResponse {
+    background: bool,
+    autostart: bool,
+}
+
+impl BackgroundResponse {
+    /// Returns `true` if the application is allowed to run in the background.
+    pub fn get_background(&self) -> bool {
+        self.background
+    }
+
+    /// Returns `true` if the application is started automatically at login.
+    pub fn get_autostart(&self) -> bool {
+        self.autostart
+    }
+}
+
+/// Asks the `org.freedesktop.portal.Background` portal for permission to keep running in the
+/// background after the last window was closed, giving `reason` to the user.
+///
+/// If `autostart` is `true` the application is also started at login with `commandline`, or
+/// with the command line from its desktop file if `commandline` is `None`.
+pub fn request_background_future(
+    connection: &DBusConnection,
+    parent_window: &str,
+    reason: &str,
+    autostart: bool,
+    commandline: Option<&[&str]>,
+) -> Pin<Box<dyn Future<Output = Result<BackgroundResponse, glib::Error>> + 'static>> {
+    let options = VariantDict::new(None);
+    options.insert("reason", &reason);
+    options.insert("autostart", &autostart);
+    if let Some(commandline) = commandline {
+        let commandline = commandline
+            .iter()
+            .map(|arg| String::from(*arg))
+            .collect::<Vec<_>>();
+        options.insert("commandline", &commandline);
+    }
+
+    let request = request_future(
+        connection,
+        "org.freedesktop.portal.Background",
+        "RequestBackground",
+        &[parent_window.to_variant()],
+        options,
+    );
+    Box::pin(async move {
+        let results = request.await?;
+        Ok(BackgroundResponse {
+            background: lookup(&results, "background").unwrap_or(false),
+            autostart: lookup(&results, "autostart").unwrap_or(false),
+        })
+    })
+}
+
+/// The user information returned by the `org.freedesktop.portal.Account` portal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserInformation {
+    id: String,
+    name: String,
+    image: Option<String>,
+}
+
+impl UserInformation {
+    /// Returns the user ID.
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the full name of the user.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the URI of the image representing the user, if any.
+    pub fn get_image(&self) -> Option<&str> {
+        self.image.as_deref()
+    }
+}
+
+/// Asks the `org.freedesktop.portal.Account` portal for information about the user, giving
+/// `reason` to the user.
+pub fn get_user_information_future(
+    connection: &DBusConnection,
+    parent_window: &str,
+    reason: &str,
+) -> Pin<Box<dyn Future<Output = Result<UserInformation, glib::Error>> + 'static>> {
+    let options = VariantDict::new(None);
+    options.insert("reason", &reason);
+
+    let request = request_future(
+        connection,
+        "org.freedesktop.portal.Account",
+        "GetUserInformation",
+        &[parent_window.to_variant()],
+        options,
+    );
+    Box::pin(async move {
+        let results = request.await?;
+        Ok(UserInformation {
+            id: lookup(&results, "id").unwrap_or_default(),
+            name: lookup(&results, "name").unwrap_or_default(),
+            image: lookup::<String>(&results, "image").filter(|image| !image.is_empty()),
+        })
+    })
+}
+
+/// Asks the `org.freedesktop.portal.Screenshot` portal to take a screenshot and returns the
+/// URI of the resulting image.
+///
+/// If `interactive` is `true` the user can choose the area of the screenshot.
+pub fn screenshot_future(
+    connection: &DBusConnection,
+    parent_window: &str,
+    interactive: bool,
+) -> Pin<Box<dyn Future<Output = Result<String, glib::Error>> + 'static>> {
+    let options = VariantDict::new(None);
+    options.insert("interactive", &interactive);
+
+    let request = request_future(
+        connection,
+        "org.freedesktop.portal.Screenshot",
+        "Screenshot",
+        &[parent_window.to_variant()],
+        options,
+    );
+    Box::pin(async move {
+        let results = request.await?;
+        lookup(&results, "uri")
+            .ok_or_else(|| glib::Error::new(IOErrorEnum::InvalidData, "Response contains no URI"))
+    })
+}
+
+fn lookup<T: glib::FromVariant>(results: &VariantDict, key: &str) -> Option<T> {
+    results
+        .lookup_value(key, Some(&T::static_variant_type()))
+        .and_then(|value| value.get())
+}
+
+// Unsubscribes from the `Response` signal of a request once dropped.
+struct RequestSubscription {
+    connection: DBusConnection,
+    id: Option<SignalSubscriptionId>,
+}
+
+impl RequestSubscription {
+    fn new(connection: &DBusConnection, path: &str, sender: &ResponseSender) -> Self {
+        let sender = sender.clone();
+        let id = connection.signal_subscribe(
+            Some(PORTAL_BUS_NAME),
+            Some(REQUEST_INTERFACE),
+            Some("Response"),
+            Some(path),
+            None,
+            DBusSignalFlags::NONE,
+            move |_, _, _, _, _, parameters| {
+                if let Some(response) = parameters.get::<(u32, VariantDict)>() {
+                    if let Some(sender) = sender.borrow_mut().take() {
+                        let _ = sender.send(response);
+                    }
+                }
+            },
+        );
+
+        Self {
+            connection: connection.clone(),
+            id: Some(id),
+        }
+    }
+}
+
+impl Drop for RequestSubscription {
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            self.connection.signal_unsubscribe(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_checks_type() {
+        let results = VariantDict::new(None);
+        results.insert("uri", &"file:///tmp/screenshot.png");
+        results.insert("background", &true);
+
+        assert_eq!(
+            lookup::<String>(&results, "uri").as_deref(),
+            Some("file:///tmp/screenshot.png")
+        );
+        assert_eq!(lookup::<bool>(&results, "background"), Some(true));
+        assert_eq!(lookup::<bool>(&results, "uri"), None);
+        assert_eq!(lookup::<String>(&results, "image"), None);
+    }
+
+    #[cfg(any(feature = "v2_46", feature = "dox"))]
+    fn object_path(path: &str) -> Variant {
+        use glib::translate::*;
+
+        unsafe { from_glib_none(glib::ffi::g_variant_new_object_path(path.to_glib_none().0)) }
+    }
+
+    // Registers a fake portal on the session bus that answers every request right away. An
+    // OpenURI request for the URI `cancel` is cancelled.
+    #[cfg(any(feature = "v2_46", feature = "dox"))]
+    fn register_fake_portal() -> Option<DBusConnection> {
+        use crate::{BusType, Cancellable, DBusConnectionFlags, DBusNodeInfo};
+
+        const XML: &str = r#"
+            <node>
+              <interface name="org.freedesktop.portal.OpenURI">
+                <method name="OpenURI">
+                  <arg type="s" name="parent_window" direction="in"/>
+                  <arg type="s" name="uri" direction="in"/>
+                  <arg type="a{sv}" name="options" direction="in"/>
+                  <arg type="o" name="handle" direction="out"/>
+                </method>
+              </interface>
+              <interface name="org.freedesktop.portal.Screenshot">
+                <method name="Screenshot">
+                  <arg type="s" name="parent_window" direction="in"/>
+                  <arg type="a{sv}" name="options" direction="in"/>
+                  <arg type="o" name="handle" direction="out"/>
+                </method>
+              </interface>
+            </node>
+        "#;
+
+        let address =
+            crate::dbus_address_get_for_bus_sync(BusType::Session, None::<&Cancellable>).ok()?;
+        let connection = DBusConnection::new_for_address_sync(
+            &address,
+            DBusConnectionFlags::AUTHENTICATION_CLIENT
+                | DBusConnectionFlags::MESSAGE_BUS_CONNECTION,
+            None,
+            None::<&Cancellable>,
+        )
+        .ok()?;
+
+        let node = DBusNodeInfo::new_for_xml(XML).unwrap();
+        for interface in &[
+            "org.freedesktop.portal.OpenURI",
+            "org.freedesktop.portal.Screenshot",
+        ] {
+            connection
+                .register_object(
+                    PORTAL_OBJECT_PATH,
+                    &node.lookup_interface(interface).unwrap(),
+                    |connection, sender, _, _, method, parameters, invocation| {
+                        let options = VariantDict::new(Some(
+                            &parameters.get_child_value(parameters.n_children() - 1),
+                        ));
+                        let token = options.lookup::<String>("handle_token").unwrap();
+                        let path = format!(
+                            "{}/request/{}/{}",
+                            PORTAL_OBJECT_PATH,
+                            sender.trim_start_matches(':').replace('.', "_"),
+                            token
+                        );
+                        invocation.return_value(Some(&Variant::tuple(&[object_path(&path)])));
+
+                        let results = VariantDict::new(None);
+                        if method == "Screenshot" {
+                            results.insert("uri", &"file:///tmp/screenshot.png");
+                        }
+                        let cancel = method == "OpenURI"
+                            && parameters.get_child_value(1).get_str() == Some("cancel");
+                        let response = if cancel { 1u32 } else { 0u32 };
+                        connection
+                            .emit_signal(
+                                Some(sender),
+                                &path,
+                                REQUEST_INTERFACE,
+                                "Response",
+                                Some(&Variant::tuple(&[response.to_variant(), results.end()])),
+                            )
+                            .unwrap();
+                    },
+                    |_, _, _, _, _| unreachable!(),
+                    |_, _, _, _, _, _| unreachable!(),
+                )
+                .unwrap();
+        }
+
+        // Don't replace a real portal
+        let reply = connection
+            .call_sync(
+                Some("org.freedesktop.DBus"),
+                "/org/freedesktop/DBus",
+                "org.freedesktop.DBus",
+                "RequestName",
+                Some(&(PORTAL_BUS_NAME, 4u32).to_variant()),
+                Some(VariantTy::new("(u)").unwrap()),
+                DBusCallFlags::NONE,
+                -1,
+                None::<&Cancellable>,
+            )
+            .ok()?;
+        if reply.get::<(u32,)>() != Some((1,)) {
+            return None;
+        }
+
+        Some(connection)
+    }
+
+    #[test]
+    #[cfg(any(feature = "v2_46", feature = "dox"))]
+    fn requests() {
+        use crate::test_util::run_async_local;
+        use crate::{BusType, Cancellable};
+
+        let res = run_async_local(|tx, l| {
+            let portal = match register_fake_portal() {
+                Some(portal) => portal,
+                None => {
+                    // No session bus, or a real portal is running
+                    tx.send(None).unwrap();
+                    l.quit();
+                    return;
+                }
+            };
+            let connection = crate::bus_get_sync(BusType::Session, None::<&Cancellable>).unwrap();
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let opened = open_uri_future(&connection, "", "https://gtk-rs.org", false).await;
+                let cancelled = open_uri_future(&connection, "", "cancel", true).await;
+                let screenshot = screenshot_future(&connection, "", false).await;
+                tx.send(Some((
+                    opened.is_ok(),
+                    cancelled.err().and_then(|err| err.kind::<IOErrorEnum>()),
+                    screenshot.ok(),
+                )))
+                .unwrap();
+                portal.close_sync(None::<&Cancellable>).unwrap();
+                l.quit();
+            });
+        });
+
+        if let Some((opened, cancelled, screenshot)) = res {
+            assert!(opened);
+            assert_eq!(cancelled, Some(IOErrorEnum::Cancelled));
+            assert_eq!(screenshot.as_deref(), Some("file:///tmp/screenshot.png"));
+        }
+    }
+}
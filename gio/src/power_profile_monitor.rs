@@ -0,0 +1,58 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::PowerProfileMonitor;
+use crate::PowerProfileMonitorExt;
+use futures_core::stream::Stream;
+use glib::object::IsA;
+use std::pin::Pin;
+
+pub trait PowerProfileMonitorExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns a `Stream` of the new value of the `power-saver-enabled` property whenever it
+    /// changes.
+    ///
+    /// Applications should reduce animations, polling and other background work while power
+    /// saving is enabled. The signal handler is disconnected once the stream is dropped.
+    fn power_saver_enabled_stream(&self) -> Pin<Box<dyn Stream<Item = bool> + 'static>>;
+}
+
+impl<O: IsA<PowerProfileMonitor>> PowerProfileMonitorExtManual for O {
+    fn power_saver_enabled_stream(&self) -> Pin<Box<dyn Stream<Item = bool> + 'static>> {
+        Box::pin(glib::signal_stream(self.as_ref(), |monitor, sender| {
+            vec![
+                monitor.connect_property_power_saver_enabled_notify(move |monitor| {
+                    let _ = sender.unbounded_send(monitor.get_power_saver_enabled());
+                }),
+            ]
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::run_async;
+    use futures_util::StreamExt;
+    use glib::ObjectExt;
+
+    #[test]
+    fn power_saver_enabled_stream() {
+        let res = run_async(|tx, l| {
+            let monitor = PowerProfileMonitor::dup_default();
+            let mut stream = monitor.power_saver_enabled_stream();
+
+            monitor.notify("power-saver-enabled");
+
+            let c = glib::MainContext::ref_thread_default();
+            c.spawn_local(async move {
+                let enabled = stream.next().await;
+                tx.send((enabled, monitor.get_power_saver_enabled()))
+                    .unwrap();
+                l.quit();
+            });
+        });
+
+        let (enabled, current) = res;
+        assert_eq!(enabled, Some(current));
+    }
+}
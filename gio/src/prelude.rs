@@ -15,17 +15,28 @@ pub use crate::data_input_stream::DataInputStreamExtManual;
 #[cfg(any(feature = "v2_58", feature = "dox"))]
 #[cfg(any(all(not(windows), not(target_os = "macos")), feature = "dox"))]
 pub use crate::desktop_app_info::DesktopAppInfoExtManual;
+pub use crate::drive::DriveExtManual;
+pub use crate::error::IOErrorExt;
 pub use crate::file::FileExtManual;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::filter::FilterExt;
 pub use crate::inet_address::InetAddressExtManual;
 pub use crate::input_stream::InputStreamExtManual;
 pub use crate::io_stream::IOStreamExtManual;
 #[cfg(any(feature = "v2_44", feature = "dox"))]
 pub use crate::list_store::ListStoreExtManual;
+#[cfg(any(feature = "v2_64", feature = "dox"))]
+pub use crate::memory_monitor::MemoryMonitorExtManual;
 pub use crate::output_stream::OutputStreamExtManual;
 pub use crate::pollable_input_stream::PollableInputStreamExtManual;
 pub use crate::pollable_output_stream::PollableOutputStreamExtManual;
+#[cfg(any(feature = "v2_70", feature = "dox"))]
+pub use crate::power_profile_monitor::PowerProfileMonitorExtManual;
 pub use crate::settings::SettingsExtManual;
 pub use crate::socket::*;
+pub use crate::socket_address::SocketAddressExtManual;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+pub use crate::sorter::SorterExt;
 #[cfg(any(unix, feature = "dox"))]
 pub use crate::unix_input_stream::UnixInputStreamExtManual;
 #[cfg(any(unix, feature = "dox"))]
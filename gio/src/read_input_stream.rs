@@ -271,64 +271,8 @@ pub(crate) fn std_error_to_gio_error<T>(
 ) -> Option<Result<T, glib::Error>> {
     match res {
         Ok(res) => Some(Ok(res)),
-        Err(err) => {
-            use std::io::ErrorKind;
-
-            #[allow(clippy::wildcard_in_or_patterns)]
-            match err.kind() {
-                ErrorKind::NotFound => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::NotFound,
-                    "Not Found",
-                ))),
-                ErrorKind::PermissionDenied => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::PermissionDenied,
-                    "Permission Denied",
-                ))),
-                ErrorKind::ConnectionRefused => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::ConnectionRefused,
-                    "Connection Refused",
-                ))),
-                ErrorKind::ConnectionReset
-                | ErrorKind::ConnectionAborted
-                | ErrorKind::NotConnected => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::NotConnected,
-                    "Connection Reset",
-                ))),
-                ErrorKind::AddrInUse | ErrorKind::AddrNotAvailable => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::AddressInUse,
-                    "Address In Use",
-                ))),
-                ErrorKind::BrokenPipe => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::BrokenPipe,
-                    "Broken Pipe",
-                ))),
-                ErrorKind::AlreadyExists => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::Exists,
-                    "Already Exists",
-                ))),
-                ErrorKind::WouldBlock => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::WouldBlock,
-                    "Would Block",
-                ))),
-                ErrorKind::InvalidInput | ErrorKind::InvalidData => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::InvalidData,
-                    "Invalid Input",
-                ))),
-                ErrorKind::TimedOut => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::TimedOut,
-                    "Timed Out",
-                ))),
-                ErrorKind::Interrupted => None,
-                ErrorKind::UnexpectedEof => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::Closed,
-                    "Unexpected Eof",
-                ))),
-                ErrorKind::WriteZero | _ => Some(Err(glib::Error::new(
-                    crate::IOErrorEnum::Failed,
-                    format!("Unknown error: {:?}", err).as_str(),
-                ))),
-            }
-        }
+        Err(err) if err.kind() == std::io::ErrorKind::Interrupted => None,
+        Err(err) => Some(Err(glib::Error::from_io_error(err))),
     }
 }
 
@@ -0,0 +1,98 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::NativeSocketAddress;
+use crate::SocketAddress;
+use crate::SocketAddressExt;
+use glib::object::{Cast, IsA};
+use glib::translate::*;
+use std::net::SocketAddr;
+use std::ptr;
+
+impl SocketAddress {
+    /// Creates a `SocketAddress` from the raw bytes of a native `struct sockaddr`.
+    ///
+    /// Returns `None` if the address family is not supported.
+    #[doc(alias = "g_socket_address_new_from_native")]
+    pub fn from_native(native: &[u8]) -> Option<SocketAddress> {
+        unsafe {
+            from_glib_full(ffi::g_socket_address_new_from_native(
+                mut_override(native.as_ptr()) as glib::ffi::gpointer,
+                native.len(),
+            ))
+        }
+    }
+}
+
+impl From<SocketAddr> for SocketAddress {
+    fn from(addr: SocketAddr) -> Self {
+        crate::InetSocketAddress::from(addr).upcast()
+    }
+}
+
+impl NativeSocketAddress {
+    /// Creates a `NativeSocketAddress` from the raw bytes of a native `struct sockaddr`.
+    ///
+    /// This can be used for address families that are not otherwise supported by GIO.
+    #[doc(alias = "g_native_socket_address_new")]
+    pub fn new(native: &[u8]) -> NativeSocketAddress {
+        unsafe {
+            SocketAddress::from_glib_full(ffi::g_native_socket_address_new(
+                mut_override(native.as_ptr()) as glib::ffi::gpointer,
+                native.len(),
+            ))
+            .unsafe_cast()
+        }
+    }
+}
+
+pub trait SocketAddressExtManual {
+    /// Returns the raw bytes of the native `struct sockaddr` for this address.
+    #[doc(alias = "g_socket_address_to_native")]
+    fn to_native(&self) -> Result<Vec<u8>, glib::Error>;
+}
+
+impl<O: IsA<SocketAddress>> SocketAddressExtManual for O {
+    fn to_native(&self) -> Result<Vec<u8>, glib::Error> {
+        let len = self.get_native_size();
+        if len < 0 {
+            return Err(glib::Error::new(
+                crate::IOErrorEnum::InvalidArgument,
+                "Socket address has no native representation",
+            ));
+        }
+
+        let mut native = vec![0u8; len as usize];
+        unsafe {
+            let mut error = ptr::null_mut();
+            let _ = ffi::g_socket_address_to_native(
+                self.as_ref().to_glib_none().0,
+                native.as_mut_ptr() as glib::ffi::gpointer,
+                native.len(),
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(native)
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InetSocketAddress;
+
+    #[test]
+    fn native_round_trip() {
+        let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        let native = SocketAddress::from(addr).to_native().unwrap();
+
+        let addr2 = SocketAddress::from_native(&native)
+            .unwrap()
+            .downcast::<InetSocketAddress>()
+            .unwrap();
+        assert_eq!(SocketAddr::from(addr2), addr);
+    }
+}
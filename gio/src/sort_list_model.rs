@@ -0,0 +1,328 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::*;
+use crate::subclass::prelude::*;
+use crate::{ListModel, Sorter};
+use glib::{Object, SignalHandlerId};
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+type SortFunc = dyn Fn(&Object, &Object) -> Ordering;
+
+mod imp {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    pub struct SortListModel {
+        pub(super) model: RefCell<Option<(ListModel, SignalHandlerId)>>,
+        pub(super) sort_func: RefCell<Option<Rc<SortFunc>>>,
+        pub(super) sorter: RefCell<Option<(Sorter, SignalHandlerId)>>,
+        // The items in the order of the underlying model, to know which items were removed
+        pub(super) items: RefCell<Vec<Object>>,
+        pub(super) sorted: RefCell<Vec<Object>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SortListModel {
+        const NAME: &'static str = "GioRsSortListModel";
+        type Type = super::SortListModel;
+        type ParentType = Object;
+        type Interfaces = (ListModel,);
+    }
+
+    impl ObjectImpl for SortListModel {
+        fn dispose(&self, _obj: &Self::Type) {
+            if let Some((model, handler_id)) = self.model.borrow_mut().take() {
+                model.disconnect(handler_id);
+            }
+            if let Some((sorter, handler_id)) = self.sorter.borrow_mut().take() {
+                sorter.disconnect(handler_id);
+            }
+        }
+    }
+
+    impl ListModelImpl for SortListModel {
+        fn get_item_type(&self, _list_model: &Self::Type) -> glib::Type {
+            self.model
+                .borrow()
+                .as_ref()
+                .map_or(glib::Type::OBJECT, |(model, _)| model.get_item_type())
+        }
+
+        fn get_n_items(&self, _list_model: &Self::Type) -> u32 {
+            self.sorted.borrow().len() as u32
+        }
+
+        fn get_item(&self, _list_model: &Self::Type, position: u32) -> Option<Object> {
+            self.sorted.borrow().get(position as usize).cloned()
+        }
+    }
+}
+
+glib::wrapper! {
+    /// A [`ListModel`] that contains the items of another model, sorted with a Rust closure.
+    ///
+    /// Items added to the underlying model are inserted after all items comparing equal to
+    /// them, and each removed or added item is forwarded as a separate `items-changed` emission
+    /// at its sorted position. The items are not sorted again when their properties change, so
+    /// [`resort`](Self::resort) has to be called when the result of the sort function might
+    /// have changed.
+    ///
+    /// Models using a [`Sorter`] instead are sorted again automatically whenever the sorter
+    /// emits `changed`.
+    pub struct SortListModel(ObjectSubclass<imp::SortListModel>) @implements ListModel;
+}
+
+impl SortListModel {
+    /// Creates a new model that contains the items of `model` sorted with `sort_func`.
+    pub fn new<P: IsA<ListModel>, F: Fn(&Object, &Object) -> Ordering + 'static>(
+        model: &P,
+        sort_func: F,
+    ) -> Self {
+        let obj = glib::Object::new::<Self>(&[]).expect("Failed to create sort list model");
+
+        let imp = imp::SortListModel::from_instance(&obj);
+        *imp.sort_func.borrow_mut() = Some(Rc::new(sort_func));
+
+        let model: &ListModel = model.as_ref();
+        let weak = obj.downgrade();
+        let handler_id = model.connect_items_changed(move |_, position, removed, added| {
+            if let Some(obj) = weak.upgrade() {
+                obj.source_items_changed(position, removed, added);
+            }
+        });
+        *imp.model.borrow_mut() = Some((model.clone(), handler_id));
+
+        let items = get_objects(model, 0, model.get_n_items());
+        let sorted = obj.sort_items(&items);
+        *imp.items.borrow_mut() = items;
+        *imp.sorted.borrow_mut() = sorted;
+
+        obj
+    }
+
+    /// Creates a new model that contains the items of `model` sorted with `sorter`.
+    pub fn with_sorter<P: IsA<ListModel>, Q: IsA<Sorter>>(model: &P, sorter: &Q) -> Self {
+        let sorter = sorter.as_ref().clone();
+        let obj = Self::new(model, {
+            let sorter = sorter.clone();
+            move |a, b| sorter.compare(a, b)
+        });
+        obj.connect_sorter(sorter);
+        obj
+    }
+
+    /// Returns the underlying model.
+    pub fn get_model(&self) -> Option<ListModel> {
+        let imp = imp::SortListModel::from_instance(self);
+        imp.model.borrow().as_ref().map(|(model, _)| model.clone())
+    }
+
+    /// Returns the sorter, unless a sort function is used.
+    pub fn get_sorter(&self) -> Option<Sorter> {
+        let imp = imp::SortListModel::from_instance(self);
+        imp.sorter
+            .borrow()
+            .as_ref()
+            .map(|(sorter, _)| sorter.clone())
+    }
+
+    /// Replaces the sort function and sorts all items again.
+    pub fn set_sort_func<F: Fn(&Object, &Object) -> Ordering + 'static>(&self, sort_func: F) {
+        let imp = imp::SortListModel::from_instance(self);
+        self.disconnect_sorter();
+        *imp.sort_func.borrow_mut() = Some(Rc::new(sort_func));
+        self.resort();
+    }
+
+    /// Replaces the sort function with `sorter` and sorts all items again.
+    pub fn set_sorter<P: IsA<Sorter>>(&self, sorter: &P) {
+        let sorter = sorter.as_ref().clone();
+        self.set_sort_func({
+            let sorter = sorter.clone();
+            move |a, b| sorter.compare(a, b)
+        });
+        self.connect_sorter(sorter);
+    }
+
+    fn connect_sorter(&self, sorter: Sorter) {
+        let imp = imp::SortListModel::from_instance(self);
+        let weak = self.downgrade();
+        let handler_id = sorter.connect_changed(move |_| {
+            if let Some(obj) = weak.upgrade() {
+                obj.resort();
+            }
+        });
+        *imp.sorter.borrow_mut() = Some((sorter, handler_id));
+    }
+
+    fn disconnect_sorter(&self) {
+        let imp = imp::SortListModel::from_instance(self);
+        if let Some((sorter, handler_id)) = imp.sorter.borrow_mut().take() {
+            sorter.disconnect(handler_id);
+        }
+    }
+
+    /// Sorts all items again and emits `items-changed` for the range of items whose position
+    /// changed.
+    pub fn resort(&self) {
+        let imp = imp::SortListModel::from_instance(self);
+        let items = imp.items.borrow().clone();
+        let sorted = self.sort_items(&items);
+        let old = imp.sorted.replace(sorted);
+        let new = imp.sorted.borrow();
+
+        let first = match old.iter().zip(new.iter()).position(|(o, n)| o != n) {
+            Some(first) => first,
+            None => return,
+        };
+        let last = old.len()
+            - old
+                .iter()
+                .rev()
+                .zip(new.iter().rev())
+                .position(|(o, n)| o != n)
+                .unwrap();
+        drop(new);
+
+        let n_changed = (last - first) as u32;
+        self.items_changed(first as u32, n_changed, n_changed);
+    }
+
+    fn sort_items(&self, items: &[Object]) -> Vec<Object> {
+        let imp = imp::SortListModel::from_instance(self);
+        let mut sorted = items.to_vec();
+        if let Some(sort_func) = imp.sort_func.borrow().clone() {
+            sorted.sort_by(|a, b| sort_func(a, b));
+        }
+        sorted
+    }
+
+    // Returns the position after all sorted items that compare less than or equal to `item`
+    fn insert_position(&self, item: &Object) -> usize {
+        let imp = imp::SortListModel::from_instance(self);
+        let sort_func = match imp.sort_func.borrow().clone() {
+            Some(sort_func) => sort_func,
+            None => return imp.sorted.borrow().len(),
+        };
+        imp.sorted
+            .borrow()
+            .binary_search_by(|probe| match sort_func(probe, item) {
+                Ordering::Greater => Ordering::Greater,
+                _ => Ordering::Less,
+            })
+            .unwrap_err()
+    }
+
+    fn source_items_changed(&self, position: u32, removed: u32, added: u32) {
+        let imp = imp::SortListModel::from_instance(self);
+        let model = match self.get_model() {
+            Some(model) => model,
+            None => return,
+        };
+        let new = get_objects(&model, position, added);
+
+        let start = position as usize;
+        let end = start + removed as usize;
+        let removed_items = imp
+            .items
+            .borrow_mut()
+            .splice(start..end, new.iter().cloned())
+            .collect::<Vec<_>>();
+
+        // Replacing all items is forwarded as a single change
+        if removed_items.len() == imp.sorted.borrow().len() {
+            let n_removed = removed_items.len() as u32;
+            let sorted = self.sort_items(&new);
+            *imp.sorted.borrow_mut() = sorted;
+            if n_removed > 0 || added > 0 {
+                self.items_changed(0, n_removed, added);
+            }
+            return;
+        }
+
+        for item in removed_items {
+            let index = imp.sorted.borrow().iter().position(|i| *i == item);
+            if let Some(index) = index {
+                imp.sorted.borrow_mut().remove(index);
+                self.items_changed(index as u32, 1, 0);
+            }
+        }
+
+        for item in new {
+            let index = self.insert_position(&item);
+            imp.sorted.borrow_mut().insert(index, item);
+            self.items_changed(index as u32, 0, 1);
+        }
+    }
+}
+
+fn get_objects(model: &ListModel, position: u32, n_items: u32) -> Vec<Object> {
+    (position..position + n_items)
+        .filter_map(|index| model.get_object(index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{action_names, action_store, record_items_changed};
+    use crate::{CustomSorter, SimpleAction};
+
+    fn compare_names(a: &Object, b: &Object) -> Ordering {
+        let a = a.downcast_ref::<SimpleAction>().unwrap().get_name();
+        let b = b.downcast_ref::<SimpleAction>().unwrap().get_name();
+        a.cmp(&b)
+    }
+
+    #[test]
+    fn sort() {
+        let store = action_store(&["c", "a", "b"]);
+        let sort = SortListModel::new(&store, compare_names);
+        let changes = record_items_changed(&sort);
+        assert_eq!(action_names(&sort), vec!["a", "b", "c"]);
+
+        store.append(&SimpleAction::new("ab", None));
+        store.remove(0);
+        assert_eq!(action_names(&sort), vec!["a", "ab", "b"]);
+        assert_eq!(*changes.borrow(), vec![(1, 0, 1), (3, 1, 0)]);
+
+        changes.borrow_mut().clear();
+        sort.set_sort_func(|a, b| compare_names(b, a));
+        assert_eq!(action_names(&sort), vec!["b", "ab", "a"]);
+        assert_eq!(*changes.borrow(), vec![(0, 3, 3)]);
+
+        changes.borrow_mut().clear();
+        store.remove_all();
+        assert_eq!(sort.get_n_items(), 0);
+        assert_eq!(*changes.borrow(), vec![(0, 3, 0)]);
+    }
+
+    #[test]
+    fn shared_sorter() {
+        let store = action_store(&["b", "c", "a"]);
+        let sorter = CustomSorter::new(compare_names);
+        let first = SortListModel::with_sorter(&store, &sorter);
+        let second = SortListModel::with_sorter(&store, &sorter);
+        let changes = record_items_changed(&first);
+        assert_eq!(action_names(&first), vec!["a", "b", "c"]);
+        assert_eq!(action_names(&second), vec!["a", "b", "c"]);
+
+        sorter.set_sort_func(|a, b| compare_names(b, a));
+        assert_eq!(action_names(&first), vec!["c", "b", "a"]);
+        assert_eq!(action_names(&second), vec!["c", "b", "a"]);
+        assert_eq!(*changes.borrow(), vec![(0, 3, 3)]);
+
+        changes.borrow_mut().clear();
+        first.set_sort_func(compare_names);
+        assert!(first.get_sorter().is_none());
+        assert_eq!(*changes.borrow(), vec![(0, 3, 3)]);
+
+        changes.borrow_mut().clear();
+        sorter.set_sort_func(|_, _| Ordering::Equal);
+        assert_eq!(action_names(&first), vec!["a", "b", "c"]);
+        assert_eq!(action_names(&second), vec!["b", "c", "a"]);
+        assert!(changes.borrow().is_empty());
+    }
+}
@@ -0,0 +1,138 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::prelude::*;
+use glib::subclass::prelude::*;
+use glib::subclass::Signal;
+use glib::{Object, SignalHandlerId};
+use once_cell::sync::Lazy;
+use std::cmp::Ordering;
+
+pub(crate) mod iface {
+    use super::*;
+
+    #[glib::object_interface]
+    pub struct Sorter {
+        pub(crate) compare: Option<fn(&super::Sorter, &Object, &Object) -> Ordering>,
+    }
+
+    #[glib::object_interface]
+    unsafe impl ObjectInterface for Sorter {
+        const NAME: &'static str = "GioRsSorter";
+        type Prerequisites = (Object,);
+
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: Lazy<Vec<Signal>> = Lazy::new(|| {
+                vec![Signal::builder("changed", &[], glib::Type::UNIT.into())
+                    .run_last()
+                    .build()]
+            });
+            SIGNALS.as_ref()
+        }
+    }
+}
+
+glib::wrapper! {
+    /// Decides the order of items, independent of the model containing them.
+    ///
+    /// A sorter can be shared by several models, e.g. several [`SortListModel`](crate::SortListModel)s
+    /// showing different subsets of the same items, which sort their items again whenever the
+    /// sorter emits `changed`.
+    ///
+    /// Sorters are implemented with [`SorterImpl`](crate::subclass::prelude::SorterImpl), or
+    /// created from a closure with [`CustomSorter`].
+    pub struct Sorter(ObjectInterface<iface::Sorter>);
+}
+
+pub trait SorterExt: 'static {
+    /// Compares `a` and `b`.
+    fn compare<P: IsA<Object>, Q: IsA<Object>>(&self, a: &P, b: &Q) -> Ordering;
+
+    /// Notifies all users of the sorter that the result of `compare` might have changed, e.g.
+    /// because the sort column was changed.
+    fn changed(&self);
+
+    fn connect_changed<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId;
+}
+
+impl<O: IsA<Sorter>> SorterExt for O {
+    fn compare<P: IsA<Object>, Q: IsA<Object>>(&self, a: &P, b: &Q) -> Ordering {
+        let sorter = self.upcast_ref::<Sorter>();
+        let iface = iface::Sorter::from_instance(sorter);
+        (iface.compare.expect("no \"compare\" implementation"))(
+            sorter,
+            a.upcast_ref(),
+            b.upcast_ref(),
+        )
+    }
+
+    fn changed(&self) {
+        let _ = self
+            .emit_by_name("changed", &[])
+            .expect("Failed to emit \"changed\"");
+    }
+
+    fn connect_changed<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId {
+        self.connect_local("changed", false, move |args| {
+            let sorter = args[0]
+                .get::<Sorter>()
+                .expect("Wrong type for sorter")
+                .expect("No sorter");
+            f(unsafe { sorter.unsafe_cast_ref() });
+            None
+        })
+        .expect("Failed to connect to \"changed\"")
+    }
+}
+
+type SortFunc = dyn Fn(&Object, &Object) -> Ordering;
+
+mod imp {
+    use super::*;
+    use crate::subclass::prelude::SorterImpl;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    pub struct CustomSorter {
+        pub(super) sort_func: RefCell<Option<Box<SortFunc>>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for CustomSorter {
+        const NAME: &'static str = "GioRsCustomSorter";
+        type Type = super::CustomSorter;
+        type ParentType = Object;
+        type Interfaces = (super::Sorter,);
+    }
+
+    impl ObjectImpl for CustomSorter {}
+
+    impl SorterImpl for CustomSorter {
+        fn compare(&self, _sorter: &Self::Type, a: &Object, b: &Object) -> Ordering {
+            self.sort_func
+                .borrow()
+                .as_ref()
+                .map_or(Ordering::Equal, |sort_func| sort_func(a, b))
+        }
+    }
+}
+
+glib::wrapper! {
+    /// A [`Sorter`] that compares items with a Rust closure.
+    pub struct CustomSorter(ObjectSubclass<imp::CustomSorter>) @implements Sorter;
+}
+
+impl CustomSorter {
+    pub fn new<F: Fn(&Object, &Object) -> Ordering + 'static>(sort_func: F) -> Self {
+        let obj = glib::Object::new::<Self>(&[]).expect("Failed to create custom sorter");
+        let imp = imp::CustomSorter::from_instance(&obj);
+        *imp.sort_func.borrow_mut() = Some(Box::new(sort_func));
+        obj
+    }
+
+    /// Replaces the sort function and emits `changed`.
+    pub fn set_sort_func<F: Fn(&Object, &Object) -> Ordering + 'static>(&self, sort_func: F) {
+        let imp = imp::CustomSorter::from_instance(self);
+        *imp.sort_func.borrow_mut() = Some(Box::new(sort_func));
+        self.changed();
+    }
+}
@@ -11,6 +11,7 @@ use crate::Application;
 use libc::{c_char, c_int, c_void};
 use std::ffi::OsString;
 use std::fmt;
+use std::mem;
 use std::ops::Deref;
 use std::ptr;
 
@@ -70,6 +71,31 @@ impl From<ArgumentList> for Vec<OsString> {
     }
 }
 
+/// Builder for the `a{sv}` platform data that is sent along with remote invocations of the
+/// primary instance.
+pub struct PlatformDataBuilder {
+    ptr: *mut glib::ffi::GVariantBuilder,
+}
+
+impl PlatformDataBuilder {
+    /// Adds `value` under `key` to the platform data.
+    pub fn add(&mut self, key: &str, value: &glib::Variant) {
+        unsafe {
+            let entry = glib::ffi::g_variant_new_dict_entry(
+                glib::ffi::g_variant_new_string(key.to_glib_none().0),
+                glib::ffi::g_variant_new_variant(value.to_glib_none().0),
+            );
+            glib::ffi::g_variant_builder_add_value(self.ptr, entry);
+        }
+    }
+}
+
+impl fmt::Debug for PlatformDataBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PlatformDataBuilder").finish()
+    }
+}
+
 pub trait ApplicationImpl: ObjectImpl + ApplicationImplExt {
     fn activate(&self, application: &Self::Type) {
         self.parent_activate(application)
@@ -83,6 +109,10 @@ pub trait ApplicationImpl: ObjectImpl + ApplicationImplExt {
         self.parent_before_emit(application, platform_data)
     }
 
+    fn add_platform_data(&self, application: &Self::Type, builder: &mut PlatformDataBuilder) {
+        self.parent_add_platform_data(application, builder)
+    }
+
     fn command_line(
         &self,
         application: &Self::Type,
@@ -122,12 +152,48 @@ pub trait ApplicationImpl: ObjectImpl + ApplicationImplExt {
     fn handle_local_options(&self, application: &Self::Type, options: &VariantDict) -> i32 {
         self.parent_handle_local_options(application, options)
     }
+
+    /// Called when the application registers itself on `connection` at `object_path`, before
+    /// `startup` is emitted.
+    ///
+    /// Additional objects exported here stay alive for as long as the application is
+    /// registered. Returning an error makes the registration of the application fail.
+    fn dbus_register(
+        &self,
+        application: &Self::Type,
+        connection: &crate::DBusConnection,
+        object_path: &str,
+    ) -> Result<(), glib::Error> {
+        self.parent_dbus_register(application, connection, object_path)
+    }
+
+    /// Called when the application unregisters itself from `connection`, after `shutdown`.
+    ///
+    /// Everything exported from `dbus_register` should be unexported here.
+    fn dbus_unregister(
+        &self,
+        application: &Self::Type,
+        connection: &crate::DBusConnection,
+        object_path: &str,
+    ) {
+        self.parent_dbus_unregister(application, connection, object_path)
+    }
+
+    /// Called when another instance takes over the application's bus name.
+    ///
+    /// Returns `true` if the loss was handled. The default implementation quits the application.
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_60")))]
+    fn name_lost(&self, application: &Self::Type) -> bool {
+        self.parent_name_lost(application)
+    }
 }
 
 pub trait ApplicationImplExt: ObjectSubclass {
     fn parent_activate(&self, application: &Self::Type);
     fn parent_after_emit(&self, application: &Self::Type, platform_data: &glib::Variant);
     fn parent_before_emit(&self, application: &Self::Type, platform_data: &glib::Variant);
+    fn parent_add_platform_data(&self, application: &Self::Type, builder: &mut PlatformDataBuilder);
     fn parent_command_line(
         &self,
         application: &Self::Type,
@@ -144,6 +210,20 @@ pub trait ApplicationImplExt: ObjectSubclass {
     fn parent_shutdown(&self, application: &Self::Type);
     fn parent_startup(&self, application: &Self::Type);
     fn parent_handle_local_options(&self, application: &Self::Type, options: &VariantDict) -> i32;
+    fn parent_dbus_register(
+        &self,
+        application: &Self::Type,
+        connection: &crate::DBusConnection,
+        object_path: &str,
+    ) -> Result<(), glib::Error>;
+    fn parent_dbus_unregister(
+        &self,
+        application: &Self::Type,
+        connection: &crate::DBusConnection,
+        object_path: &str,
+    );
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    fn parent_name_lost(&self, application: &Self::Type) -> bool;
 }
 
 impl<T: ApplicationImpl> ApplicationImplExt for T {
@@ -195,6 +275,27 @@ impl<T: ApplicationImpl> ApplicationImplExt for T {
         }
     }
 
+    fn parent_add_platform_data(
+        &self,
+        application: &Self::Type,
+        builder: &mut PlatformDataBuilder,
+    ) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GApplicationClass;
+            let f = (*parent_class)
+                .add_platform_data
+                .expect("No parent class implementation for \"add_platform_data\"");
+            f(
+                application
+                    .unsafe_cast_ref::<Application>()
+                    .to_glib_none()
+                    .0,
+                builder.ptr,
+            )
+        }
+    }
+
     fn parent_command_line(
         &self,
         application: &Self::Type,
@@ -339,6 +440,76 @@ impl<T: ApplicationImpl> ApplicationImplExt for T {
             }
         }
     }
+
+    fn parent_dbus_register(
+        &self,
+        application: &Self::Type,
+        connection: &crate::DBusConnection,
+        object_path: &str,
+    ) -> Result<(), glib::Error> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GApplicationClass;
+            if let Some(f) = (*parent_class).dbus_register {
+                let mut err = ptr::null_mut();
+                let res = f(
+                    application
+                        .unsafe_cast_ref::<Application>()
+                        .to_glib_none()
+                        .0,
+                    connection.to_glib_none().0,
+                    object_path.to_glib_none().0,
+                    &mut err,
+                );
+                if res == glib::ffi::GFALSE {
+                    Err(from_glib_full(err))
+                } else {
+                    debug_assert!(err.is_null());
+                    Ok(())
+                }
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn parent_dbus_unregister(
+        &self,
+        application: &Self::Type,
+        connection: &crate::DBusConnection,
+        object_path: &str,
+    ) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GApplicationClass;
+            if let Some(f) = (*parent_class).dbus_unregister {
+                f(
+                    application
+                        .unsafe_cast_ref::<Application>()
+                        .to_glib_none()
+                        .0,
+                    connection.to_glib_none().0,
+                    object_path.to_glib_none().0,
+                )
+            }
+        }
+    }
+
+    #[cfg(any(feature = "v2_60", feature = "dox"))]
+    fn parent_name_lost(&self, application: &Self::Type) -> bool {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GApplicationClass;
+            if let Some(f) = (*parent_class).name_lost {
+                from_glib(f(application
+                    .unsafe_cast_ref::<Application>()
+                    .to_glib_none()
+                    .0))
+            } else {
+                false
+            }
+        }
+    }
 }
 
 unsafe impl<T: ApplicationImpl> IsSubclassable<T> for Application {
@@ -349,6 +520,7 @@ unsafe impl<T: ApplicationImpl> IsSubclassable<T> for Application {
         klass.activate = Some(application_activate::<T>);
         klass.after_emit = Some(application_after_emit::<T>);
         klass.before_emit = Some(application_before_emit::<T>);
+        klass.add_platform_data = Some(application_add_platform_data::<T>);
         klass.command_line = Some(application_command_line::<T>);
         klass.local_command_line = Some(application_local_command_line::<T>);
         klass.open = Some(application_open::<T>);
@@ -357,6 +529,12 @@ unsafe impl<T: ApplicationImpl> IsSubclassable<T> for Application {
         klass.shutdown = Some(application_shutdown::<T>);
         klass.startup = Some(application_startup::<T>);
         klass.handle_local_options = Some(application_handle_local_options::<T>);
+        klass.dbus_register = Some(application_dbus_register::<T>);
+        klass.dbus_unregister = Some(application_dbus_unregister::<T>);
+        #[cfg(any(feature = "v2_60", feature = "dox"))]
+        {
+            klass.name_lost = Some(application_name_lost::<T>);
+        }
     }
 
     fn instance_init(instance: &mut glib::subclass::InitializingObject<T>) {
@@ -392,6 +570,20 @@ unsafe extern "C" fn application_before_emit<T: ApplicationImpl>(
 
     imp.before_emit(wrap.unsafe_cast_ref(), &from_glib_borrow(platform_data))
 }
+unsafe extern "C" fn application_add_platform_data<T: ApplicationImpl>(
+    ptr: *mut ffi::GApplication,
+    builder: *mut glib::ffi::GVariantBuilder,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Application> = from_glib_borrow(ptr);
+
+    imp.add_platform_data(
+        wrap.unsafe_cast_ref(),
+        &mut PlatformDataBuilder { ptr: builder },
+    )
+}
+
 unsafe extern "C" fn application_command_line<T: ApplicationImpl>(
     ptr: *mut ffi::GApplication,
     command_line: *mut ffi::GApplicationCommandLine,
@@ -480,6 +672,59 @@ unsafe extern "C" fn application_handle_local_options<T: ApplicationImpl>(
     imp.handle_local_options(wrap.unsafe_cast_ref(), &from_glib_borrow(options))
 }
 
+unsafe extern "C" fn application_dbus_register<T: ApplicationImpl>(
+    ptr: *mut ffi::GApplication,
+    connection: *mut ffi::GDBusConnection,
+    object_path: *const c_char,
+    err: *mut *mut glib::ffi::GError,
+) -> glib::ffi::gboolean {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Application> = from_glib_borrow(ptr);
+
+    match imp.dbus_register(
+        wrap.unsafe_cast_ref(),
+        &from_glib_borrow(connection),
+        &glib::GString::from_glib_borrow(object_path),
+    ) {
+        Ok(()) => glib::ffi::GTRUE,
+        Err(e) => {
+            if !err.is_null() {
+                let mut e = mem::ManuallyDrop::new(e);
+                *err = e.to_glib_none_mut().0;
+            }
+            glib::ffi::GFALSE
+        }
+    }
+}
+
+unsafe extern "C" fn application_dbus_unregister<T: ApplicationImpl>(
+    ptr: *mut ffi::GApplication,
+    connection: *mut ffi::GDBusConnection,
+    object_path: *const c_char,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Application> = from_glib_borrow(ptr);
+
+    imp.dbus_unregister(
+        wrap.unsafe_cast_ref(),
+        &from_glib_borrow(connection),
+        &glib::GString::from_glib_borrow(object_path),
+    )
+}
+
+#[cfg(any(feature = "v2_60", feature = "dox"))]
+unsafe extern "C" fn application_name_lost<T: ApplicationImpl>(
+    ptr: *mut ffi::GApplication,
+) -> glib::ffi::gboolean {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Application> = from_glib_borrow(ptr);
+
+    imp.name_lost(wrap.unsafe_cast_ref()).to_glib()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -545,11 +790,129 @@ mod tests {
         }
     }
 
+    mod imp_dbus {
+        use super::*;
+        use std::cell::RefCell;
+
+        #[derive(Default)]
+        pub struct DBusApplication {
+            pub events: RefCell<Vec<String>>,
+            pub fail_register: std::cell::Cell<bool>,
+        }
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for DBusApplication {
+            const NAME: &'static str = "DBusApplication";
+            type Type = super::DBusApplication;
+            type ParentType = Application;
+        }
+
+        impl ObjectImpl for DBusApplication {}
+
+        impl ApplicationImpl for DBusApplication {
+            fn activate(&self, _application: &Self::Type) {}
+
+            fn startup(&self, application: &Self::Type) {
+                self.parent_startup(application);
+                self.events.borrow_mut().push("startup".into());
+            }
+
+            fn shutdown(&self, application: &Self::Type) {
+                self.events.borrow_mut().push("shutdown".into());
+                self.parent_shutdown(application);
+            }
+
+            fn dbus_register(
+                &self,
+                application: &Self::Type,
+                connection: &crate::DBusConnection,
+                object_path: &str,
+            ) -> Result<(), glib::Error> {
+                self.parent_dbus_register(application, connection, object_path)?;
+                if self.fail_register.get() {
+                    return Err(glib::Error::new(
+                        crate::IOErrorEnum::Failed,
+                        "Registration refused",
+                    ));
+                }
+                self.events
+                    .borrow_mut()
+                    .push(format!("dbus_register {}", object_path));
+                Ok(())
+            }
+
+            fn dbus_unregister(
+                &self,
+                application: &Self::Type,
+                connection: &crate::DBusConnection,
+                object_path: &str,
+            ) {
+                self.events
+                    .borrow_mut()
+                    .push(format!("dbus_unregister {}", object_path));
+                self.parent_dbus_unregister(application, connection, object_path);
+            }
+
+            #[cfg(any(feature = "v2_60", feature = "dox"))]
+            fn name_lost(&self, _application: &Self::Type) -> bool {
+                self.events.borrow_mut().push("name_lost".into());
+                true
+            }
+
+            fn add_platform_data(
+                &self,
+                application: &Self::Type,
+                builder: &mut PlatformDataBuilder,
+            ) {
+                self.parent_add_platform_data(application, builder);
+                builder.add("answer", &42i32.to_variant());
+            }
+
+            fn command_line(
+                &self,
+                _application: &Self::Type,
+                cmd_line: &crate::ApplicationCommandLine,
+            ) -> i32 {
+                let platform_data = VariantDict::new(cmd_line.get_platform_data().as_ref());
+                let answer = platform_data
+                    .lookup_value("answer", None)
+                    .and_then(|v| v.get::<i32>());
+                self.events
+                    .borrow_mut()
+                    .push(format!("command_line {:?}", answer));
+                0
+            }
+        }
+    }
+
     glib::wrapper! {
         pub struct SimpleApplication(ObjectSubclass<imp::SimpleApplication>)
         @implements crate::Application;
     }
 
+    glib::wrapper! {
+        pub struct DBusApplication(ObjectSubclass<imp_dbus::DBusApplication>)
+        @implements crate::Application;
+    }
+
+    impl DBusApplication {
+        fn new(application_id: &str, flags: crate::ApplicationFlags) -> Self {
+            glib::Object::new(&[("application-id", &application_id), ("flags", &flags)]).unwrap()
+        }
+
+        fn events(&self) -> Vec<String> {
+            imp_dbus::DBusApplication::from_instance(self)
+                .events
+                .borrow()
+                .clone()
+        }
+    }
+
+    // The D-Bus vfuncs are only called if the application can connect to the session bus.
+    fn has_session_bus() -> bool {
+        crate::bus_get_sync(crate::BusType::Session, None::<&crate::Cancellable>).is_ok()
+    }
+
     #[test]
     fn test_simple_application() {
         let app = glib::Object::new::<SimpleApplication>(&[
@@ -563,4 +926,106 @@ mod tests {
 
         assert!(app.run(&["--local".to_string()]) == EXIT_STATUS);
     }
+
+    #[test]
+    fn test_dbus_register() {
+        if !has_session_bus() {
+            return;
+        }
+
+        let app = DBusApplication::new(
+            "org.gtk-rs.DBusApplication",
+            crate::ApplicationFlags::NON_UNIQUE,
+        );
+        assert_eq!(app.run(&["dbus-application".to_string()]), 0);
+        assert_eq!(
+            app.events(),
+            [
+                "dbus_register /org/gtk_rs/DBusApplication",
+                "startup",
+                "shutdown",
+                "dbus_unregister /org/gtk_rs/DBusApplication",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dbus_register_error() {
+        if !has_session_bus() {
+            return;
+        }
+
+        let app = DBusApplication::new(
+            "org.gtk-rs.DBusApplicationError",
+            crate::ApplicationFlags::NON_UNIQUE,
+        );
+        imp_dbus::DBusApplication::from_instance(&app)
+            .fail_register
+            .set(true);
+
+        let err = app.register(None::<&crate::Cancellable>).unwrap_err();
+        assert!(err.matches(crate::IOErrorEnum::Failed));
+        assert!(!app.get_is_registered());
+        assert!(app.events().is_empty());
+    }
+
+    #[test]
+    fn test_add_platform_data() {
+        let app = DBusApplication::new(
+            "org.gtk-rs.PlatformDataApplication",
+            crate::ApplicationFlags::NON_UNIQUE | crate::ApplicationFlags::HANDLES_COMMAND_LINE,
+        );
+        assert_eq!(app.run(&["platform-data".to_string()]), 0);
+        assert!(app.events().contains(&"command_line Some(42)".to_string()));
+    }
+
+    #[cfg(feature = "v2_60")]
+    #[test]
+    fn test_name_lost() {
+        if !has_session_bus() {
+            return;
+        }
+
+        let name = "org.gtk-rs.NameLostApplication";
+        let context = glib::MainContext::new();
+        context.push_thread_default();
+
+        let app = DBusApplication::new(name, crate::ApplicationFlags::ALLOW_REPLACEMENT);
+        app.register(None::<&crate::Cancellable>).unwrap();
+
+        // Take over the name from a second connection to the session bus.
+        let address = crate::dbus_address_get_for_bus_sync(
+            crate::BusType::Session,
+            None::<&crate::Cancellable>,
+        )
+        .unwrap();
+        let connection = crate::DBusConnection::new_for_address_sync(
+            &address,
+            crate::DBusConnectionFlags::AUTHENTICATION_CLIENT
+                | crate::DBusConnectionFlags::MESSAGE_BUS_CONNECTION,
+            None,
+            None::<&crate::Cancellable>,
+        )
+        .unwrap();
+        let owner = crate::bus_own_name_on_connection(
+            &connection,
+            name,
+            crate::BusNameOwnerFlags::REPLACE,
+            |_, _| {},
+            |_, _| {},
+        );
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while !app.events().contains(&"name_lost".to_string())
+            && std::time::Instant::now() < deadline
+        {
+            context.iteration(false);
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        crate::bus_unown_name(owner);
+        context.pop_thread_default();
+
+        assert!(app.events().contains(&"name_lost".to_string()));
+    }
 }
@@ -0,0 +1,30 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Filter;
+use glib::subclass::prelude::*;
+use glib::{Cast, IsA, Object};
+
+pub trait FilterImpl: ObjectImpl {
+    fn matches(&self, filter: &Self::Type, item: &Object) -> bool;
+}
+
+unsafe impl<T: FilterImpl> IsImplementable<T> for Filter
+where
+    <T as ObjectSubclass>::Type: IsA<glib::Object>,
+{
+    fn interface_init(iface: &mut glib::Interface<Self>) {
+        iface.as_mut().matches = Some(filter_matches::<T>);
+    }
+
+    fn instance_init(_instance: &mut glib::subclass::InitializingObject<T>) {}
+}
+
+fn filter_matches<T: FilterImpl>(filter: &Filter, item: &Object) -> bool
+where
+    <T as ObjectSubclass>::Type: IsA<glib::Object>,
+{
+    let instance = filter
+        .dynamic_cast_ref::<T::Type>()
+        .expect("Wrong type for filter");
+    T::from_instance(instance).matches(instance, item)
+}
@@ -3,14 +3,19 @@
 mod action_group;
 mod action_map;
 mod application;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod filter;
 mod input_stream;
 mod io_stream;
 #[cfg(any(feature = "v2_44", feature = "dox"))]
 mod list_model;
 mod output_stream;
 mod seekable;
+#[cfg(any(feature = "v2_44", feature = "dox"))]
+mod sorter;
+mod tls_database;
 
-pub use self::application::ArgumentList;
+pub use self::application::{ArgumentList, PlatformDataBuilder};
 
 pub mod prelude {
     #[doc(hidden)]
@@ -19,10 +24,15 @@ pub mod prelude {
     pub use super::action_group::{ActionGroupImpl, ActionGroupImplExt};
     pub use super::action_map::{ActionMapImpl, ActionMapImplExt};
     pub use super::application::{ApplicationImpl, ApplicationImplExt};
+    #[cfg(any(feature = "v2_44", feature = "dox"))]
+    pub use super::filter::FilterImpl;
     pub use super::input_stream::{InputStreamImpl, InputStreamImplExt};
     pub use super::io_stream::{IOStreamImpl, IOStreamImplExt};
     #[cfg(any(feature = "v2_44", feature = "dox"))]
     pub use super::list_model::{ListModelImpl, ListModelImplExt};
     pub use super::output_stream::{OutputStreamImpl, OutputStreamImplExt};
     pub use super::seekable::{SeekableImpl, SeekableImplExt};
+    #[cfg(any(feature = "v2_44", feature = "dox"))]
+    pub use super::sorter::SorterImpl;
+    pub use super::tls_database::{TlsDatabaseImpl, TlsDatabaseImplExt};
 }
@@ -0,0 +1,31 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Sorter;
+use glib::subclass::prelude::*;
+use glib::{Cast, IsA, Object};
+use std::cmp::Ordering;
+
+pub trait SorterImpl: ObjectImpl {
+    fn compare(&self, sorter: &Self::Type, a: &Object, b: &Object) -> Ordering;
+}
+
+unsafe impl<T: SorterImpl> IsImplementable<T> for Sorter
+where
+    <T as ObjectSubclass>::Type: IsA<glib::Object>,
+{
+    fn interface_init(iface: &mut glib::Interface<Self>) {
+        iface.as_mut().compare = Some(sorter_compare::<T>);
+    }
+
+    fn instance_init(_instance: &mut glib::subclass::InitializingObject<T>) {}
+}
+
+fn sorter_compare<T: SorterImpl>(sorter: &Sorter, a: &Object, b: &Object) -> Ordering
+where
+    <T as ObjectSubclass>::Type: IsA<glib::Object>,
+{
+    let instance = sorter
+        .dynamic_cast_ref::<T::Type>()
+        .expect("Wrong type for sorter");
+    T::from_instance(instance).compare(instance, a, b)
+}
@@ -0,0 +1,702 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::subclass::prelude::*;
+use glib::translate::*;
+
+use glib::{ByteArray, Cast, Error, GString};
+
+use crate::{
+    Cancellable, IOErrorEnum, SocketConnectable, TlsCertificate, TlsCertificateFlags, TlsDatabase,
+    TlsDatabaseLookupFlags, TlsDatabaseVerifyFlags, TlsInteraction,
+};
+
+use std::mem;
+use std::ptr;
+
+// The default implementations of the asynchronous virtual methods of `GTlsDatabase` run the
+// synchronous ones in a thread pool, which is why implementations have to be `Send + Sync`.
+/// Overriding `verify_chain` allows implementing certificate pinning:
+///
+/// ```
+/// use gio::prelude::*;
+/// use gio::subclass::prelude::*;
+/// use gio::{
+///     Cancellable, SocketConnectable, TlsCertificate, TlsCertificateFlags, TlsDatabase,
+///     TlsDatabaseVerifyFlags, TlsInteraction,
+/// };
+/// use std::sync::Mutex;
+///
+/// mod imp {
+///     use super::*;
+///
+///     #[derive(Default)]
+///     pub struct PinnedDatabase {
+///         // `TlsCertificate` is not `Send`, so the pin is stored as PEM.
+///         pub pin: Mutex<Option<String>>,
+///     }
+///
+///     #[glib::object_subclass]
+///     impl ObjectSubclass for PinnedDatabase {
+///         const NAME: &'static str = "ExamplePinnedDatabase";
+///         type Type = super::PinnedDatabase;
+///         type ParentType = TlsDatabase;
+///     }
+///
+///     impl ObjectImpl for PinnedDatabase {}
+///
+///     impl TlsDatabaseImpl for PinnedDatabase {
+///         fn verify_chain(
+///             &self,
+///             _database: &Self::Type,
+///             chain: &TlsCertificate,
+///             _purpose: &str,
+///             _identity: Option<&SocketConnectable>,
+///             _interaction: Option<&TlsInteraction>,
+///             _flags: TlsDatabaseVerifyFlags,
+///             _cancellable: Option<&Cancellable>,
+///         ) -> Result<TlsCertificateFlags, glib::Error> {
+///             let pinned = match *self.pin.lock().unwrap() {
+///                 Some(ref pem) => TlsCertificate::from_pem(pem)?,
+///                 None => return Ok(TlsCertificateFlags::UNKNOWN_CA),
+///             };
+///
+///             if chain.is_same(&pinned) {
+///                 Ok(TlsCertificateFlags::empty())
+///             } else {
+///                 Ok(TlsCertificateFlags::UNKNOWN_CA)
+///             }
+///         }
+///     }
+/// }
+///
+/// glib::wrapper! {
+///     pub struct PinnedDatabase(ObjectSubclass<imp::PinnedDatabase>)
+///         @extends TlsDatabase;
+/// }
+///
+/// impl PinnedDatabase {
+///     pub fn new(pem: &str) -> Self {
+///         let database = glib::Object::new::<Self>(&[]).unwrap();
+///         *imp::PinnedDatabase::from_instance(&database).pin.lock().unwrap() = Some(pem.into());
+///         database
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// The database can then be set on a connection with `TlsConnectionExt::set_database`.
+pub trait TlsDatabaseImpl: ObjectImpl + TlsDatabaseImplExt + Send + Sync {
+    #[allow(clippy::too_many_arguments)]
+    fn verify_chain(
+        &self,
+        database: &Self::Type,
+        chain: &TlsCertificate,
+        purpose: &str,
+        identity: Option<&SocketConnectable>,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseVerifyFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<TlsCertificateFlags, Error> {
+        self.parent_verify_chain(
+            database,
+            chain,
+            purpose,
+            identity,
+            interaction,
+            flags,
+            cancellable,
+        )
+    }
+
+    fn create_certificate_handle(
+        &self,
+        database: &Self::Type,
+        certificate: &TlsCertificate,
+    ) -> Option<GString> {
+        self.parent_create_certificate_handle(database, certificate)
+    }
+
+    fn lookup_certificate_for_handle(
+        &self,
+        database: &Self::Type,
+        handle: &str,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Option<TlsCertificate>, Error> {
+        self.parent_lookup_certificate_for_handle(database, handle, interaction, flags, cancellable)
+    }
+
+    fn lookup_certificate_issuer(
+        &self,
+        database: &Self::Type,
+        certificate: &TlsCertificate,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Option<TlsCertificate>, Error> {
+        self.parent_lookup_certificate_issuer(
+            database,
+            certificate,
+            interaction,
+            flags,
+            cancellable,
+        )
+    }
+
+    fn lookup_certificates_issued_by(
+        &self,
+        database: &Self::Type,
+        issuer_raw_dn: &ByteArray,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Vec<TlsCertificate>, Error> {
+        self.parent_lookup_certificates_issued_by(
+            database,
+            issuer_raw_dn,
+            interaction,
+            flags,
+            cancellable,
+        )
+    }
+}
+
+pub trait TlsDatabaseImplExt: ObjectSubclass {
+    #[allow(clippy::too_many_arguments)]
+    fn parent_verify_chain(
+        &self,
+        database: &Self::Type,
+        chain: &TlsCertificate,
+        purpose: &str,
+        identity: Option<&SocketConnectable>,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseVerifyFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<TlsCertificateFlags, Error>;
+
+    fn parent_create_certificate_handle(
+        &self,
+        database: &Self::Type,
+        certificate: &TlsCertificate,
+    ) -> Option<GString>;
+
+    fn parent_lookup_certificate_for_handle(
+        &self,
+        database: &Self::Type,
+        handle: &str,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Option<TlsCertificate>, Error>;
+
+    fn parent_lookup_certificate_issuer(
+        &self,
+        database: &Self::Type,
+        certificate: &TlsCertificate,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Option<TlsCertificate>, Error>;
+
+    fn parent_lookup_certificates_issued_by(
+        &self,
+        database: &Self::Type,
+        issuer_raw_dn: &ByteArray,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Vec<TlsCertificate>, Error>;
+}
+
+impl<T: TlsDatabaseImpl> TlsDatabaseImplExt for T {
+    fn parent_verify_chain(
+        &self,
+        database: &Self::Type,
+        chain: &TlsCertificate,
+        purpose: &str,
+        identity: Option<&SocketConnectable>,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseVerifyFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<TlsCertificateFlags, Error> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GTlsDatabaseClass;
+            if let Some(f) = (*parent_class).verify_chain {
+                let mut err = ptr::null_mut();
+                let res = f(
+                    database.unsafe_cast_ref::<TlsDatabase>().to_glib_none().0,
+                    chain.to_glib_none().0,
+                    purpose.to_glib_none().0,
+                    identity.to_glib_none().0,
+                    interaction.to_glib_none().0,
+                    flags.to_glib(),
+                    cancellable.to_glib_none().0,
+                    &mut err,
+                );
+                if err.is_null() {
+                    Ok(from_glib(res))
+                } else {
+                    Err(from_glib_full(err))
+                }
+            } else {
+                Err(Error::new(
+                    IOErrorEnum::NotSupported,
+                    "Verifying certificate chains is not supported",
+                ))
+            }
+        }
+    }
+
+    fn parent_create_certificate_handle(
+        &self,
+        database: &Self::Type,
+        certificate: &TlsCertificate,
+    ) -> Option<GString> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GTlsDatabaseClass;
+            (*parent_class).create_certificate_handle.and_then(|f| {
+                from_glib_full(f(
+                    database.unsafe_cast_ref::<TlsDatabase>().to_glib_none().0,
+                    certificate.to_glib_none().0,
+                ))
+            })
+        }
+    }
+
+    fn parent_lookup_certificate_for_handle(
+        &self,
+        database: &Self::Type,
+        handle: &str,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Option<TlsCertificate>, Error> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GTlsDatabaseClass;
+            if let Some(f) = (*parent_class).lookup_certificate_for_handle {
+                let mut err = ptr::null_mut();
+                let res = f(
+                    database.unsafe_cast_ref::<TlsDatabase>().to_glib_none().0,
+                    handle.to_glib_none().0,
+                    interaction.to_glib_none().0,
+                    flags.to_glib(),
+                    cancellable.to_glib_none().0,
+                    &mut err,
+                );
+                if err.is_null() {
+                    Ok(from_glib_full(res))
+                } else {
+                    Err(from_glib_full(err))
+                }
+            } else {
+                Ok(None)
+            }
+        }
+    }
+
+    fn parent_lookup_certificate_issuer(
+        &self,
+        database: &Self::Type,
+        certificate: &TlsCertificate,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Option<TlsCertificate>, Error> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GTlsDatabaseClass;
+            if let Some(f) = (*parent_class).lookup_certificate_issuer {
+                let mut err = ptr::null_mut();
+                let res = f(
+                    database.unsafe_cast_ref::<TlsDatabase>().to_glib_none().0,
+                    certificate.to_glib_none().0,
+                    interaction.to_glib_none().0,
+                    flags.to_glib(),
+                    cancellable.to_glib_none().0,
+                    &mut err,
+                );
+                if err.is_null() {
+                    Ok(from_glib_full(res))
+                } else {
+                    Err(from_glib_full(err))
+                }
+            } else {
+                Ok(None)
+            }
+        }
+    }
+
+    fn parent_lookup_certificates_issued_by(
+        &self,
+        database: &Self::Type,
+        issuer_raw_dn: &ByteArray,
+        interaction: Option<&TlsInteraction>,
+        flags: TlsDatabaseLookupFlags,
+        cancellable: Option<&Cancellable>,
+    ) -> Result<Vec<TlsCertificate>, Error> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GTlsDatabaseClass;
+            if let Some(f) = (*parent_class).lookup_certificates_issued_by {
+                let mut err = ptr::null_mut();
+                let res = f(
+                    database.unsafe_cast_ref::<TlsDatabase>().to_glib_none().0,
+                    issuer_raw_dn.to_glib_none().0,
+                    interaction.to_glib_none().0,
+                    flags.to_glib(),
+                    cancellable.to_glib_none().0,
+                    &mut err,
+                );
+                if err.is_null() {
+                    Ok(FromGlibPtrContainer::from_glib_full(res))
+                } else {
+                    Err(from_glib_full(err))
+                }
+            } else {
+                Ok(Vec::new())
+            }
+        }
+    }
+}
+
+unsafe impl<T: TlsDatabaseImpl> IsSubclassable<T> for TlsDatabase {
+    fn class_init(class: &mut ::glib::Class<Self>) {
+        <glib::Object as IsSubclassable<T>>::class_init(class);
+
+        let klass = class.as_mut();
+        klass.verify_chain = Some(database_verify_chain::<T>);
+        klass.create_certificate_handle = Some(database_create_certificate_handle::<T>);
+        klass.lookup_certificate_for_handle = Some(database_lookup_certificate_for_handle::<T>);
+        klass.lookup_certificate_issuer = Some(database_lookup_certificate_issuer::<T>);
+        klass.lookup_certificates_issued_by = Some(database_lookup_certificates_issued_by::<T>);
+    }
+
+    fn instance_init(instance: &mut glib::subclass::InitializingObject<T>) {
+        <glib::Object as IsSubclassable<T>>::instance_init(instance);
+    }
+}
+
+unsafe extern "C" fn database_verify_chain<T: TlsDatabaseImpl>(
+    ptr: *mut ffi::GTlsDatabase,
+    chain: *mut ffi::GTlsCertificate,
+    purpose: *const libc::c_char,
+    identity: *mut ffi::GSocketConnectable,
+    interaction: *mut ffi::GTlsInteraction,
+    flags: ffi::GTlsDatabaseVerifyFlags,
+    cancellable: *mut ffi::GCancellable,
+    err: *mut *mut glib::ffi::GError,
+) -> ffi::GTlsCertificateFlags {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TlsDatabase> = from_glib_borrow(ptr);
+
+    match imp.verify_chain(
+        wrap.unsafe_cast_ref(),
+        &from_glib_borrow(chain),
+        &GString::from_glib_borrow(purpose),
+        Option::<SocketConnectable>::from_glib_borrow(identity)
+            .as_ref()
+            .as_ref(),
+        Option::<TlsInteraction>::from_glib_borrow(interaction)
+            .as_ref()
+            .as_ref(),
+        from_glib(flags),
+        Option::<Cancellable>::from_glib_borrow(cancellable)
+            .as_ref()
+            .as_ref(),
+    ) {
+        Ok(res) => res.to_glib(),
+        Err(e) => {
+            if !err.is_null() {
+                let mut e = mem::ManuallyDrop::new(e);
+                *err = e.to_glib_none_mut().0;
+            }
+            TlsCertificateFlags::GENERIC_ERROR.to_glib()
+        }
+    }
+}
+
+unsafe extern "C" fn database_create_certificate_handle<T: TlsDatabaseImpl>(
+    ptr: *mut ffi::GTlsDatabase,
+    certificate: *mut ffi::GTlsCertificate,
+) -> *mut libc::c_char {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TlsDatabase> = from_glib_borrow(ptr);
+
+    imp.create_certificate_handle(wrap.unsafe_cast_ref(), &from_glib_borrow(certificate))
+        .to_glib_full()
+}
+
+unsafe extern "C" fn database_lookup_certificate_for_handle<T: TlsDatabaseImpl>(
+    ptr: *mut ffi::GTlsDatabase,
+    handle: *const libc::c_char,
+    interaction: *mut ffi::GTlsInteraction,
+    flags: ffi::GTlsDatabaseLookupFlags,
+    cancellable: *mut ffi::GCancellable,
+    err: *mut *mut glib::ffi::GError,
+) -> *mut ffi::GTlsCertificate {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TlsDatabase> = from_glib_borrow(ptr);
+
+    match imp.lookup_certificate_for_handle(
+        wrap.unsafe_cast_ref(),
+        &GString::from_glib_borrow(handle),
+        Option::<TlsInteraction>::from_glib_borrow(interaction)
+            .as_ref()
+            .as_ref(),
+        from_glib(flags),
+        Option::<Cancellable>::from_glib_borrow(cancellable)
+            .as_ref()
+            .as_ref(),
+    ) {
+        Ok(res) => res.to_glib_full(),
+        Err(e) => {
+            if !err.is_null() {
+                let mut e = mem::ManuallyDrop::new(e);
+                *err = e.to_glib_none_mut().0;
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+unsafe extern "C" fn database_lookup_certificate_issuer<T: TlsDatabaseImpl>(
+    ptr: *mut ffi::GTlsDatabase,
+    certificate: *mut ffi::GTlsCertificate,
+    interaction: *mut ffi::GTlsInteraction,
+    flags: ffi::GTlsDatabaseLookupFlags,
+    cancellable: *mut ffi::GCancellable,
+    err: *mut *mut glib::ffi::GError,
+) -> *mut ffi::GTlsCertificate {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TlsDatabase> = from_glib_borrow(ptr);
+
+    match imp.lookup_certificate_issuer(
+        wrap.unsafe_cast_ref(),
+        &from_glib_borrow(certificate),
+        Option::<TlsInteraction>::from_glib_borrow(interaction)
+            .as_ref()
+            .as_ref(),
+        from_glib(flags),
+        Option::<Cancellable>::from_glib_borrow(cancellable)
+            .as_ref()
+            .as_ref(),
+    ) {
+        Ok(res) => res.to_glib_full(),
+        Err(e) => {
+            if !err.is_null() {
+                let mut e = mem::ManuallyDrop::new(e);
+                *err = e.to_glib_none_mut().0;
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+unsafe extern "C" fn database_lookup_certificates_issued_by<T: TlsDatabaseImpl>(
+    ptr: *mut ffi::GTlsDatabase,
+    issuer_raw_dn: *mut glib::ffi::GByteArray,
+    interaction: *mut ffi::GTlsInteraction,
+    flags: ffi::GTlsDatabaseLookupFlags,
+    cancellable: *mut ffi::GCancellable,
+    err: *mut *mut glib::ffi::GError,
+) -> *mut glib::ffi::GList {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TlsDatabase> = from_glib_borrow(ptr);
+
+    match imp.lookup_certificates_issued_by(
+        wrap.unsafe_cast_ref(),
+        &from_glib_borrow(issuer_raw_dn),
+        Option::<TlsInteraction>::from_glib_borrow(interaction)
+            .as_ref()
+            .as_ref(),
+        from_glib(flags),
+        Option::<Cancellable>::from_glib_borrow(cancellable)
+            .as_ref()
+            .as_ref(),
+    ) {
+        Ok(res) => ToGlibContainerFromSlice::to_glib_full_from_slice(&res),
+        Err(e) => {
+            if !err.is_null() {
+                let mut e = mem::ManuallyDrop::new(e);
+                *err = e.to_glib_none_mut().0;
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::sync::Mutex;
+
+    const PINNED_PEM: &str = "-----BEGIN CERTIFICATE-----\n\
+MIIBkTCCATegAwIBAgIUYHWWvREc7bR6AB/pGiyzqzRq4BcwCgYIKoZIzj0EAwIw\n\
+HTEbMBkGA1UEAwwScGlubmVkLmV4YW1wbGUuY29tMCAXDTI2MTAxNDE3MzM0MVoY\n\
+DzIxMjYwOTIwMTczMzQxWjAdMRswGQYDVQQDDBJwaW5uZWQuZXhhbXBsZS5jb20w\n\
+WTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAATjHnkttgIRVdjPsY8tuBnMz5TVKG1N\n\
+oMu9+sS8fnrvnfGuX12hckVOXvLLZAwHcN6oVe8rCKuaI68r7LEj/THDo1MwUTAd\n\
+BgNVHQ4EFgQUkuBWj4U/i9yjMcgVLwuAjX+vkyYwHwYDVR0jBBgwFoAUkuBWj4U/\n\
+i9yjMcgVLwuAjX+vkyYwDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQDAgNIADBF\n\
+AiEAzKAVlKrynQEts/VcpQmMGICi4sT/SxTqubQayM3SD90CIHcyrQxgt4XnjJFQ\n\
+cHgD/VqplxqKbbcYXjnTV14RN8ki\n\
+-----END CERTIFICATE-----";
+
+    const OTHER_PEM: &str = "-----BEGIN CERTIFICATE-----\n\
+MIIBjjCCATWgAwIBAgIUagRh1F8H22kCzt1bwYsxMP9VRvkwCgYIKoZIzj0EAwIw\n\
+HDEaMBgGA1UEAwwRb3RoZXIuZXhhbXBsZS5jb20wIBcNMjYxMDE0MTczMzQxWhgP\n\
+MjEyNjA5MjAxNzMzNDFaMBwxGjAYBgNVBAMMEW90aGVyLmV4YW1wbGUuY29tMFkw\n\
+EwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEs9otsemM/N2l6UMZwPZha1SbqpnNAFxL\n\
+IinvrEOehrSRwBwQELJ4dkQy2pozGm1EF2b5AJHus9mD2Hrlw8NRKaNTMFEwHQYD\n\
+VR0OBBYEFGGiv4bosKNCJcuGEt/bx1bTGealMB8GA1UdIwQYMBaAFGGiv4bosKNC\n\
+JcuGEt/bx1bTGealMA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwIDRwAwRAIg\n\
+U58dXXQZRE/ZYzzPVoBgCFT+N3NdkrWEvgEMfubKQAoCIFTvebertXvrEdlhELcQ\n\
+iDtqtBfzXsSTvOEH4UxD8bQU\n\
+-----END CERTIFICATE-----";
+
+    mod imp {
+        use super::*;
+
+        #[derive(Default)]
+        pub struct PinnedDatabase {
+            pub pin: Mutex<Option<String>>,
+            pub handles: Mutex<Vec<String>>,
+        }
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for PinnedDatabase {
+            const NAME: &'static str = "PinnedDatabase";
+            type Type = super::PinnedDatabase;
+            type ParentType = TlsDatabase;
+        }
+
+        impl ObjectImpl for PinnedDatabase {}
+
+        impl TlsDatabaseImpl for PinnedDatabase {
+            fn verify_chain(
+                &self,
+                _database: &Self::Type,
+                chain: &TlsCertificate,
+                _purpose: &str,
+                _identity: Option<&SocketConnectable>,
+                _interaction: Option<&TlsInteraction>,
+                _flags: TlsDatabaseVerifyFlags,
+                _cancellable: Option<&Cancellable>,
+            ) -> Result<TlsCertificateFlags, Error> {
+                let pin = self.pin.lock().unwrap();
+                let pinned = match *pin {
+                    Some(ref pem) => TlsCertificate::from_pem(pem)?,
+                    None => {
+                        return Err(Error::new(
+                            IOErrorEnum::NotInitialized,
+                            "No certificate pinned",
+                        ))
+                    }
+                };
+
+                if chain.is_same(&pinned) {
+                    Ok(TlsCertificateFlags::empty())
+                } else {
+                    Ok(TlsCertificateFlags::UNKNOWN_CA)
+                }
+            }
+
+            fn lookup_certificate_for_handle(
+                &self,
+                _database: &Self::Type,
+                handle: &str,
+                _interaction: Option<&TlsInteraction>,
+                _flags: TlsDatabaseLookupFlags,
+                _cancellable: Option<&Cancellable>,
+            ) -> Result<Option<TlsCertificate>, Error> {
+                self.handles.lock().unwrap().push(handle.to_owned());
+                Ok(None)
+            }
+        }
+    }
+
+    glib::wrapper! {
+        pub struct PinnedDatabase(ObjectSubclass<imp::PinnedDatabase>)
+            @extends TlsDatabase;
+    }
+
+    impl PinnedDatabase {
+        fn new(pin: Option<&str>) -> Self {
+            let database = glib::Object::new::<Self>(&[]).unwrap();
+            *imp::PinnedDatabase::from_instance(&database)
+                .pin
+                .lock()
+                .unwrap() = pin.map(String::from);
+            database
+        }
+    }
+
+    #[test]
+    fn test_lookup_certificate_for_handle() {
+        let database = PinnedDatabase::new(None);
+
+        let res = database.lookup_certificate_for_handle(
+            "pkcs11:id=1",
+            None::<&TlsInteraction>,
+            TlsDatabaseLookupFlags::None,
+            None::<&Cancellable>,
+        );
+        assert!(matches!(res, Ok(None)));
+        assert_eq!(
+            *imp::PinnedDatabase::from_instance(&database)
+                .handles
+                .lock()
+                .unwrap(),
+            ["pkcs11:id=1"]
+        );
+    }
+
+    #[test]
+    fn test_verify_chain() {
+        // Parsing certificates needs a TLS backend, which might not be installed.
+        if !crate::TlsBackend::get_default().supports_tls() {
+            eprintln!("No TLS backend available, skipping test_verify_chain");
+            return;
+        }
+        let pinned = TlsCertificate::from_pem(PINNED_PEM).unwrap();
+        let other = TlsCertificate::from_pem(OTHER_PEM).unwrap();
+
+        let database = PinnedDatabase::new(Some(PINNED_PEM));
+        let verify = |cert: &TlsCertificate| {
+            database.verify_chain(
+                cert,
+                &crate::TLS_DATABASE_PURPOSE_AUTHENTICATE_SERVER,
+                None::<&SocketConnectable>,
+                None::<&TlsInteraction>,
+                TlsDatabaseVerifyFlags::NONE,
+                None::<&Cancellable>,
+            )
+        };
+
+        assert_eq!(verify(&pinned).unwrap(), TlsCertificateFlags::empty());
+        assert_eq!(verify(&other).unwrap(), TlsCertificateFlags::UNKNOWN_CA);
+
+        let database = PinnedDatabase::new(None);
+        let err = database
+            .verify_chain(
+                &pinned,
+                &crate::TLS_DATABASE_PURPOSE_AUTHENTICATE_SERVER,
+                None::<&SocketConnectable>,
+                None::<&TlsInteraction>,
+                TlsDatabaseVerifyFlags::NONE,
+                None::<&Cancellable>,
+            )
+            .unwrap_err();
+        assert!(err.matches(IOErrorEnum::NotInitialized));
+    }
+}
@@ -42,3 +42,48 @@ pub fn run_async_local<T: 'static, Q: FnOnce(Sender<T>, MainLoop) + Send + 'stat
 
     rx.recv().unwrap()
 }
+
+// Creates a `ListStore` containing `SimpleAction`s with the given names
+#[cfg(feature = "v2_44")]
+#[allow(dead_code)]
+pub fn action_store(names: &[&str]) -> crate::ListStore {
+    let store = crate::ListStore::new(crate::SimpleAction::static_type());
+    for name in names {
+        store.append(&crate::SimpleAction::new(name, None));
+    }
+    store
+}
+
+// Returns the names of the `SimpleAction`s in `model`
+#[cfg(feature = "v2_44")]
+#[allow(dead_code)]
+pub fn action_names<P: IsA<crate::ListModel>>(model: &P) -> Vec<String> {
+    use crate::prelude::*;
+
+    (0..model.get_n_items())
+        .map(|position| {
+            let action = model.get_object(position).unwrap();
+            action
+                .downcast::<crate::SimpleAction>()
+                .unwrap()
+                .get_name()
+                .to_string()
+        })
+        .collect()
+}
+
+// Records the emissions of `items-changed` of `model`
+#[cfg(feature = "v2_44")]
+#[allow(dead_code)]
+pub fn record_items_changed<P: IsA<crate::ListModel>>(
+    model: &P,
+) -> std::rc::Rc<std::cell::RefCell<Vec<(u32, u32, u32)>>> {
+    use crate::prelude::*;
+
+    let changes = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let changes_clone = changes.clone();
+    model.connect_items_changed(move |_, position, removed, added| {
+        changes_clone.borrow_mut().push((position, removed, added));
+    });
+    changes
+}
@@ -1,12 +1,25 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::Icon;
-use crate::UnixMountEntry;
 use glib::translate::*;
 use glib::GString;
-use std::cmp;
 use std::mem;
 
+glib::wrapper! {
+    #[derive(Debug)]
+    pub struct UnixMountEntry(Boxed<ffi::GUnixMountEntry>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::g_unix_mount_entry_get_type(), ptr as *mut _) as *mut ffi::GUnixMountEntry,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::g_unix_mount_entry_get_type(), ptr as *mut _),
+        get_type => || ffi::g_unix_mount_entry_get_type(),
+    }
+
+    impl traits {
+        compare => |a, b| ffi::g_unix_mount_compare(mut_override(a), mut_override(b)),
+    }
+}
+
 impl UnixMountEntry {
     #[doc(alias = "g_unix_mount_at")]
     pub fn new_at<P: AsRef<std::path::Path>>(mount_path: P) -> (UnixMountEntry, u64) {
@@ -175,26 +188,3 @@ impl UnixMountEntry {
         unsafe { from_glib(ffi::g_unix_mounts_changed_since(time)) }
     }
 }
-
-impl PartialEq for UnixMountEntry {
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        self.compare(other) == 0
-    }
-}
-
-impl Eq for UnixMountEntry {}
-
-impl PartialOrd for UnixMountEntry {
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.compare(other).partial_cmp(&0)
-    }
-}
-
-impl Ord for UnixMountEntry {
-    #[inline]
-    fn cmp(&self, other: &Self) -> cmp::Ordering {
-        self.compare(other).cmp(&0)
-    }
-}
@@ -34,6 +34,26 @@ impl<'a> UnixSocketAddressPath<'a> {
             AbstractPadded(_) => UnixSocketAddressType::AbstractPadded,
         }
     }
+
+    /// Returns an owned copy of the path if this is a filesystem path address.
+    pub fn to_path_buf(&self) -> Option<path::PathBuf> {
+        match *self {
+            UnixSocketAddressPath::Path(path) => Some(path.to_path_buf()),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the socket if this is an abstract address.
+    ///
+    /// For `AbstractPadded` addresses this includes the trailing NUL padding.
+    pub fn as_abstract_bytes(&self) -> Option<&'a [u8]> {
+        match *self {
+            UnixSocketAddressPath::Abstract(name) | UnixSocketAddressPath::AbstractPadded(name) => {
+                Some(name)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl UnixSocketAddress {
@@ -50,12 +70,17 @@ impl UnixSocketAddress {
         use self::UnixSocketAddressPath::*;
 
         let type_ = address_type.to_type();
+        // The stash has to stay alive until the address is created
+        let path_stash: Stash<*const libc::c_char, path::Path>;
         let (path, len) = match address_type {
-            Path(path) => (path.to_glib_none().0, path.as_os_str().len()),
+            Path(path) => {
+                path_stash = path.to_glib_none();
+                (path_stash.0, path.as_os_str().len())
+            }
             Abstract(path) | AbstractPadded(path) => {
-                (path.to_glib_none().0 as *mut libc::c_char, path.len())
+                (path.as_ptr() as *const libc::c_char, path.len())
             }
-            Anonymous => (ptr::null_mut(), 0),
+            Anonymous => (ptr::null(), 0),
         };
         unsafe {
             SocketAddress::from_glib_full(ffi::g_unix_socket_address_new_with_type(
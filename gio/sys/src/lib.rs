@@ -553,6 +553,8 @@ pub const G_NATIVE_VOLUME_MONITOR_EXTENSION_POINT_NAME: *const c_char =
     b"gio-native-volume-monitor\0" as *const u8 as *const c_char;
 pub const G_NETWORK_MONITOR_EXTENSION_POINT_NAME: *const c_char =
     b"gio-network-monitor\0" as *const u8 as *const c_char;
+pub const G_POWER_PROFILE_MONITOR_EXTENSION_POINT_NAME: *const c_char =
+    b"gio-power-profile-monitor\0" as *const u8 as *const c_char;
 pub const G_PROXY_EXTENSION_POINT_NAME: *const c_char =
     b"gio-proxy\0" as *const u8 as *const c_char;
 pub const G_PROXY_RESOLVER_EXTENSION_POINT_NAME: *const c_char =
@@ -5043,6 +5045,23 @@ impl ::std::fmt::Debug for GPollableOutputStreamInterface {
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct GPowerProfileMonitorInterface {
+    pub g_iface: gobject::GTypeInterface,
+}
+
+impl ::std::fmt::Debug for GPowerProfileMonitorInterface {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct(&format!(
+            "GPowerProfileMonitorInterface @ {:?}",
+            self as *const _
+        ))
+        .field("g_iface", &self.g_iface)
+        .finish()
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct GProxyAddressClass {
@@ -8669,6 +8688,15 @@ impl ::std::fmt::Debug for GPollableOutputStream {
     }
 }
 
+#[repr(C)]
+pub struct GPowerProfileMonitor(c_void);
+
+impl ::std::fmt::Debug for GPowerProfileMonitor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "GPowerProfileMonitor @ {:?}", self as *const _)
+    }
+}
+
 #[repr(C)]
 pub struct GProxy(c_void);
 
@@ -15973,6 +16001,21 @@ extern "C" {
         error: *mut *mut glib::GError,
     ) -> GPollableReturn;
 
+    //=========================================================================
+    // GPowerProfileMonitor
+    //=========================================================================
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    pub fn g_power_profile_monitor_get_type() -> GType;
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    pub fn g_power_profile_monitor_dup_default() -> *mut GPowerProfileMonitor;
+    #[cfg(any(feature = "v2_70", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_70")))]
+    pub fn g_power_profile_monitor_get_power_saver_enabled(
+        monitor: *mut GPowerProfileMonitor,
+    ) -> gboolean;
+
     //=========================================================================
     // GProxy
     //=========================================================================
@@ -1491,6 +1491,13 @@ const RUST_LAYOUTS: &[(&str, Layout)] = &[
             alignment: align_of::<GPollableReturn>(),
         },
     ),
+    (
+        "GPowerProfileMonitorInterface",
+        Layout {
+            size: size_of::<GPowerProfileMonitorInterface>(),
+            alignment: align_of::<GPowerProfileMonitorInterface>(),
+        },
+    ),
     (
         "GProxyAddress",
         Layout {
@@ -2744,6 +2751,10 @@ const RUST_CONSTANTS: &[(&str, &str)] = &[
     ("(gint) G_POLLABLE_RETURN_FAILED", "0"),
     ("(gint) G_POLLABLE_RETURN_OK", "1"),
     ("(gint) G_POLLABLE_RETURN_WOULD_BLOCK", "-27"),
+    (
+        "G_POWER_PROFILE_MONITOR_EXTENSION_POINT_NAME",
+        "gio-power-profile-monitor",
+    ),
     ("G_PROXY_EXTENSION_POINT_NAME", "gio-proxy"),
     (
         "G_PROXY_RESOLVER_EXTENSION_POINT_NAME",
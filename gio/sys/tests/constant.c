@@ -422,6 +422,7 @@ int main() {
     PRINT_CONSTANT((gint) G_POLLABLE_RETURN_FAILED);
     PRINT_CONSTANT((gint) G_POLLABLE_RETURN_OK);
     PRINT_CONSTANT((gint) G_POLLABLE_RETURN_WOULD_BLOCK);
+    PRINT_CONSTANT(G_POWER_PROFILE_MONITOR_EXTENSION_POINT_NAME);
     PRINT_CONSTANT(G_PROXY_EXTENSION_POINT_NAME);
     PRINT_CONSTANT(G_PROXY_RESOLVER_EXTENSION_POINT_NAME);
     PRINT_CONSTANT((gint) G_RESOLVER_ERROR_INTERNAL);
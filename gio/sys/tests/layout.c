@@ -190,6 +190,7 @@ int main() {
     printf("%s;%zu;%zu\n", "GPollableInputStreamInterface", sizeof(GPollableInputStreamInterface), alignof(GPollableInputStreamInterface));
     printf("%s;%zu;%zu\n", "GPollableOutputStreamInterface", sizeof(GPollableOutputStreamInterface), alignof(GPollableOutputStreamInterface));
     printf("%s;%zu;%zu\n", "GPollableReturn", sizeof(GPollableReturn), alignof(GPollableReturn));
+    printf("%s;%zu;%zu\n", "GPowerProfileMonitorInterface", sizeof(GPowerProfileMonitorInterface), alignof(GPowerProfileMonitorInterface));
     printf("%s;%zu;%zu\n", "GProxyAddress", sizeof(GProxyAddress), alignof(GProxyAddress));
     printf("%s;%zu;%zu\n", "GProxyAddressClass", sizeof(GProxyAddressClass), alignof(GProxyAddressClass));
     printf("%s;%zu;%zu\n", "GProxyAddressEnumerator", sizeof(GProxyAddressEnumerator), alignof(GProxyAddressEnumerator));
@@ -0,0 +1,69 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(unix)]
+#[test]
+fn peer_to_peer_connection() {
+    use gio::prelude::*;
+    use gio::{
+        Cancellable, DBusAuthObserver, DBusConnection, DBusConnectionFlags, DBusServer,
+        DBusServerFlags,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    let c = glib::MainContext::new();
+    let l = glib::MainLoop::new(Some(&c), false);
+    c.push_thread_default();
+
+    let observer = DBusAuthObserver::new();
+    observer.connect_allow_mechanism(|_, mechanism| mechanism == "EXTERNAL");
+    let authorized = Arc::new(AtomicBool::new(false));
+    let authorized_clone = authorized.clone();
+    observer.connect_authorize_authenticated_peer(move |_, _, credentials| {
+        authorized_clone.store(true, Ordering::SeqCst);
+        credentials.is_some()
+    });
+
+    let guid = gio::dbus_generate_guid();
+    let server = DBusServer::new_sync(
+        "unix:tmpdir=/tmp",
+        DBusServerFlags::NONE,
+        &guid,
+        Some(&observer),
+        None::<&Cancellable>,
+    )
+    .expect("Failed to create server");
+
+    // The server has to keep a reference to connections it accepts
+    let server_connection = Rc::new(RefCell::new(None));
+    let server_connection_clone = server_connection.clone();
+    server.connect_new_connection(move |_, connection| {
+        server_connection_clone.replace(Some(connection.clone()));
+        true
+    });
+    server.start();
+
+    let address = server.get_client_address();
+    let l_clone = l.clone();
+    c.spawn_local(async move {
+        let connection = DBusConnection::new_for_address_future(
+            &address,
+            DBusConnectionFlags::AUTHENTICATION_CLIENT,
+            None,
+        )
+        .await
+        .expect("Failed to connect to server");
+        assert_eq!(connection.get_guid(), guid);
+        assert!(!connection.is_closed());
+        l_clone.quit();
+    });
+
+    l.run();
+    server.stop();
+    c.pop_thread_default();
+
+    assert!(authorized.load(Ordering::SeqCst));
+    assert!(server_connection.borrow().is_some());
+}
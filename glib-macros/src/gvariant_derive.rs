@@ -0,0 +1,467 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use heck::KebabCase;
+use proc_macro2::{Ident, TokenStream};
+use proc_macro_error::{abort, abort_call_site};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Data, DataEnum, DeriveInput, Field, Fields, Generics,
+    Lit, Meta, NestedMeta,
+};
+
+use crate::utils::{crate_ident_new, find_attribute_meta};
+
+#[derive(Default)]
+struct Attributes {
+    name: Option<String>,
+    dict: bool,
+    string: bool,
+    default: bool,
+    type_: Option<String>,
+}
+
+// Parse the attributes of the container, a field or an enum variant such as:
+// #[gvariant(dict)]
+// #[gvariant(name = "key", default)]
+// #[gvariant(type = "a{sv}")]
+fn parse_attributes(attrs: &[Attribute]) -> Attributes {
+    let mut ret = Attributes::default();
+
+    let meta = match find_attribute_meta(attrs, "gvariant") {
+        Ok(Some(meta)) => meta,
+        Ok(None) => return ret,
+        Err(e) => abort_call_site!("{}: expected #[gvariant(...)]", e),
+    };
+
+    for nested in &meta.nested {
+        match nested {
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("dict") => ret.dict = true,
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("string") => ret.string = true,
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("default") => ret.default = true,
+            NestedMeta::Meta(Meta::NameValue(n)) if n.path.is_ident("name") => match &n.lit {
+                Lit::Str(s) => ret.name = Some(s.value()),
+                lit => abort!(lit, "expected a string"),
+            },
+            NestedMeta::Meta(Meta::NameValue(n)) if n.path.is_ident("type") => match &n.lit {
+                Lit::Str(s) => ret.type_ = Some(s.value()),
+                lit => abort!(lit, "expected a string"),
+            },
+            nested => abort!(
+                nested,
+                "GVariant supports only the following attributes: dict, string, name = \"...\", default and type = \"...\""
+            ),
+        }
+    }
+
+    ret
+}
+
+// Parse the attributes of a field, rejecting those that don't apply to it
+fn parse_field_attributes(field: &Field, dict: bool) -> Attributes {
+    let attrs = parse_attributes(&field.attrs);
+    if attrs.dict || attrs.string {
+        abort!(
+            field,
+            "#[gvariant(dict)] and #[gvariant(string)] are only supported on structs and enums"
+        );
+    }
+    if !dict && (attrs.name.is_some() || attrs.default) {
+        abort!(
+            field,
+            "#[gvariant(name = \"...\")] and #[gvariant(default)] are only supported on fields of #[gvariant(dict)] structs"
+        );
+    }
+    attrs
+}
+
+// Adds `bound` to all type parameters
+fn add_bounds(generics: &Generics, bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+// Generate the type string of a tuple of `fields`, such as "(si)"
+fn gen_tuple_signature(fields: &Fields) -> TokenStream {
+    let crate_ident = crate_ident_new();
+    let types = fields.iter().map(|f| {
+        let ty = &f.ty;
+        match parse_field_attributes(f, false).type_ {
+            Some(type_) => quote! { #type_ },
+            None => quote_spanned! { f.span() =>
+                <#ty as #crate_ident::StaticVariantType>::static_variant_type().to_str()
+            },
+        }
+    });
+
+    quote! {
+        {
+            let mut signature = String::from("(");
+            #(signature.push_str(#types);)*
+            signature.push(')');
+            signature
+        }
+    }
+}
+
+// Generate the conversion of the field `f` bound to `name` to a variant.
+//
+// Fields with `#[gvariant(type = "...")]` are `Variant`s of that type, which are stored as
+// they are instead of being boxed.
+fn gen_to_variant(f: &Field, name: TokenStream, type_: Option<&str>) -> TokenStream {
+    let crate_ident = crate_ident_new();
+    match type_ {
+        Some(type_) => quote_spanned! { f.span() =>
+            {
+                let value: &#crate_ident::Variant = #name;
+                assert_eq!(
+                    value.type_().to_str(),
+                    #type_,
+                    "variant of the wrong type"
+                );
+                value.clone()
+            }
+        },
+        None => quote_spanned! { f.span() => #crate_ident::ToVariant::to_variant(#name) },
+    }
+}
+
+// Generate the conversion of the fields bound to `names` to the children of a tuple
+fn gen_tuple_children(fields: &Fields, names: &[Ident]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .zip(names)
+        .map(|(f, name)| {
+            let type_ = parse_field_attributes(f, false).type_;
+            gen_to_variant(f, quote! { #name }, type_.as_deref())
+        })
+        .collect()
+}
+
+// Generate the bindings of the fields when matching `Self` or `Self::Variant` such as:
+// { ref a, ref b }
+// (ref field_0, ref field_1)
+fn gen_field_pattern(fields: &Fields) -> (TokenStream, Vec<Ident>) {
+    match fields {
+        Fields::Named(named) => {
+            let names = named
+                .named
+                .iter()
+                .map(|f| f.ident.clone().unwrap())
+                .collect::<Vec<_>>();
+            (quote! { { #(ref #names),* } }, names)
+        }
+        Fields::Unnamed(unnamed) => {
+            let names = (0..unnamed.unnamed.len())
+                .map(|i| format_ident!("field_{}", i))
+                .collect::<Vec<_>>();
+            (quote! { ( #(ref #names),* ) }, names)
+        }
+        Fields::Unit => (quote! {}, Vec::new()),
+    }
+}
+
+// Generate the construction of `Self` or `Self::Variant` from the children of the tuple
+// `variant`, returning `None` if a child has the wrong type
+fn gen_from_tuple(path: TokenStream, fields: &Fields, variant: &Ident) -> TokenStream {
+    let crate_ident = crate_ident_new();
+    // The type of the children was already checked together with the type of `variant`
+    let values = fields.iter().enumerate().map(|(i, f)| {
+        let ty = &f.ty;
+        match parse_field_attributes(f, false).type_ {
+            Some(_) => quote_spanned! { f.span() =>
+                {
+                    let value: #crate_ident::Variant = #variant.get_child_value(#i);
+                    value
+                }
+            },
+            None => quote_spanned! { f.span() =>
+                <#ty as #crate_ident::FromVariant>::from_variant(&#variant.get_child_value(#i))?
+            },
+        }
+    });
+
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote! { #path { #(#names: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#values),* ) },
+        Fields::Unit => path,
+    }
+}
+
+struct Impls {
+    static_variant_type: TokenStream,
+    to_variant: TokenStream,
+    from_variant: TokenStream,
+}
+
+fn gen_tuple_struct(fields: &Fields) -> Impls {
+    let crate_ident = crate_ident_new();
+    let signature = gen_tuple_signature(fields);
+    let (pattern, names) = gen_field_pattern(fields);
+    let children = gen_tuple_children(fields, &names);
+    let variant = format_ident!("variant");
+    let from_tuple = gen_from_tuple(quote! { Self }, fields, &variant);
+
+    Impls {
+        static_variant_type: quote! {
+            #crate_ident::VariantType::new(&#signature)
+                .expect("incorrect signature")
+                .into()
+        },
+        to_variant: quote! {
+            let Self #pattern = *self;
+            #crate_ident::Variant::tuple(&[#(#children),*])
+        },
+        from_variant: quote! {
+            if !variant.is::<Self>() {
+                return None;
+            }
+            Some(#from_tuple)
+        },
+    }
+}
+
+fn gen_dict_struct(fields: &Fields) -> Impls {
+    let crate_ident = crate_ident_new();
+    let fields = match fields {
+        Fields::Named(named) => &named.named,
+        _ => abort_call_site!("#[gvariant(dict)] is only supported for structs with named fields"),
+    };
+
+    let attrs = fields
+        .iter()
+        .map(|f| parse_field_attributes(f, true))
+        .collect::<Vec<_>>();
+    let names = fields
+        .iter()
+        .map(|f| f.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let keys = fields
+        .iter()
+        .zip(attrs.iter())
+        .map(|(f, attrs)| {
+            attrs
+                .name
+                .clone()
+                .unwrap_or_else(|| f.ident.as_ref().unwrap().to_string())
+        })
+        .collect::<Vec<_>>();
+    let entries = fields
+        .iter()
+        .zip(names.iter())
+        .zip(attrs.iter())
+        .map(|((f, name), attrs)| {
+            gen_to_variant(f, quote! { &self.#name }, attrs.type_.as_deref())
+        });
+    let values = fields
+        .iter()
+        .zip(keys.iter())
+        .zip(attrs.iter())
+        .map(|((f, key), attrs)| {
+            let ty = &f.ty;
+            let missing = if attrs.default {
+                quote! { Default::default() }
+            } else {
+                quote! { return None }
+            };
+            match attrs.type_ {
+                // Values of the wrong type are treated like missing ones by `lookup_value()`
+                Some(ref type_) => quote_spanned! { f.span() =>
+                    match dict.lookup_value(
+                        #key,
+                        Some(#crate_ident::VariantTy::new(#type_).expect("incorrect signature")),
+                    ) {
+                        Some(value) => value,
+                        None => #missing,
+                    }
+                },
+                None => quote_spanned! { f.span() =>
+                    match dict.lookup_value(#key, None) {
+                        Some(value) => value.get::<#ty>()?,
+                        None => #missing,
+                    }
+                },
+            }
+        });
+
+    Impls {
+        static_variant_type: quote! {
+            #crate_ident::VariantTy::new("a{sv}").unwrap().into()
+        },
+        to_variant: quote! {
+            let dict = #crate_ident::VariantDict::new(None);
+            #(
+                dict.insert_value(#keys, &#entries);
+            )*
+            dict.end()
+        },
+        from_variant: quote! {
+            if !variant.is::<Self>() {
+                return None;
+            }
+            let dict = #crate_ident::VariantDict::new(Some(variant));
+            Some(Self {
+                #(#names: #values),*
+            })
+        },
+    }
+}
+
+fn variant_tag(variant: &syn::Variant) -> String {
+    let attrs = parse_attributes(&variant.attrs);
+    if attrs.dict || attrs.string || attrs.default || attrs.type_.is_some() {
+        abort!(
+            variant,
+            "Enum variants support only the #[gvariant(name = \"...\")] attribute"
+        );
+    }
+    attrs
+        .name
+        .unwrap_or_else(|| variant.ident.to_string().to_kebab_case())
+}
+
+fn gen_string_enum(data: &DataEnum) -> Impls {
+    let crate_ident = crate_ident_new();
+    if let Some(v) = data
+        .variants
+        .iter()
+        .find(|v| !matches!(v.fields, Fields::Unit))
+    {
+        abort!(
+            v,
+            "#[gvariant(string)] is only supported for enums without fields"
+        );
+    }
+
+    let idents = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let tags = data.variants.iter().map(variant_tag).collect::<Vec<_>>();
+
+    Impls {
+        static_variant_type: quote! {
+            #crate_ident::VariantTy::new("s").unwrap().into()
+        },
+        to_variant: quote! {
+            let tag = match *self {
+                #(Self::#idents => #tags,)*
+            };
+            #crate_ident::ToVariant::to_variant(tag)
+        },
+        from_variant: quote! {
+            if !variant.is::<Self>() {
+                return None;
+            }
+            match variant.get_str()? {
+                #(#tags => Some(Self::#idents),)*
+                _ => None,
+            }
+        },
+    }
+}
+
+fn gen_tagged_enum(data: &DataEnum) -> Impls {
+    let crate_ident = crate_ident_new();
+    let payload = format_ident!("payload");
+
+    let to_arms = data.variants.iter().map(|v| {
+        let ident = &v.ident;
+        let tag = variant_tag(v);
+        let (pattern, names) = gen_field_pattern(&v.fields);
+        let children = gen_tuple_children(&v.fields, &names);
+        quote_spanned! { v.span() =>
+            Self::#ident #pattern => (
+                #tag,
+                #crate_ident::Variant::tuple(&[#(#children),*]),
+            ),
+        }
+    });
+    let from_arms = data.variants.iter().map(|v| {
+        let ident = &v.ident;
+        let tag = variant_tag(v);
+        let signature = gen_tuple_signature(&v.fields);
+        let from_tuple = gen_from_tuple(quote! { Self::#ident }, &v.fields, &payload);
+        quote_spanned! { v.span() =>
+            #tag if payload.type_().to_str() == #signature => Some(#from_tuple),
+        }
+    });
+
+    Impls {
+        static_variant_type: quote! {
+            #crate_ident::VariantTy::new("(sv)").unwrap().into()
+        },
+        to_variant: quote! {
+            let (tag, payload) = match *self {
+                #(#to_arms)*
+            };
+            #crate_ident::Variant::tuple(&[
+                #crate_ident::ToVariant::to_variant(tag),
+                #crate_ident::Variant::variant(&payload),
+            ])
+        },
+        from_variant: quote! {
+            if !variant.is::<Self>() {
+                return None;
+            }
+            let payload = variant.get_child_value(1).get_variant()?;
+            match variant.get_child_value(0).get_str()? {
+                #(#from_arms)*
+                _ => None,
+            }
+        },
+    }
+}
+
+pub fn impl_gvariant(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let crate_ident = crate_ident_new();
+    let attrs = parse_attributes(&input.attrs);
+    if attrs.name.is_some() || attrs.default || attrs.type_.is_some() {
+        abort_call_site!(
+            "Structs and enums support only the #[gvariant(dict)] and #[gvariant(string)] attributes"
+        );
+    }
+
+    let impls = match &input.data {
+        Data::Struct(s) if attrs.dict => gen_dict_struct(&s.fields),
+        Data::Struct(s) => gen_tuple_struct(&s.fields),
+        Data::Enum(e) if attrs.string => gen_string_enum(e),
+        Data::Enum(e) => gen_tagged_enum(e),
+        Data::Union(_) => abort_call_site!("GVariant does not support unions"),
+    };
+    let Impls {
+        static_variant_type,
+        to_variant,
+        from_variant,
+    } = impls;
+
+    let static_generics = add_bounds(&input.generics, quote! { #crate_ident::StaticVariantType });
+    let (static_impl_generics, ty_generics, static_where_clause) = static_generics.split_for_impl();
+    let to_generics = add_bounds(&input.generics, quote! { #crate_ident::ToVariant });
+    let (to_impl_generics, _, to_where_clause) = to_generics.split_for_impl();
+    let from_generics = add_bounds(&input.generics, quote! { #crate_ident::FromVariant });
+    let (from_impl_generics, _, from_where_clause) = from_generics.split_for_impl();
+
+    quote! {
+        impl #static_impl_generics #crate_ident::StaticVariantType for #name #ty_generics #static_where_clause {
+            fn static_variant_type() -> std::borrow::Cow<'static, #crate_ident::VariantTy> {
+                #static_variant_type
+            }
+        }
+
+        impl #to_impl_generics #crate_ident::ToVariant for #name #ty_generics #to_where_clause {
+            fn to_variant(&self) -> #crate_ident::Variant {
+                #to_variant
+            }
+        }
+
+        impl #from_impl_generics #crate_ident::FromVariant for #name #ty_generics #from_where_clause {
+            fn from_variant(variant: &#crate_ident::Variant) -> Option<Self> {
+                #from_variant
+            }
+        }
+    }
+}
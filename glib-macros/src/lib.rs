@@ -6,6 +6,7 @@ mod gboxed_derive;
 mod genum_derive;
 mod gerror_domain_derive;
 mod gflags_attribute;
+mod gvariant_derive;
 mod object_interface_attribute;
 mod object_subclass_attribute;
 mod utils;
@@ -300,6 +301,77 @@ pub fn gboxed_derive(input: TokenStream) -> TokenStream {
     gen.into()
 }
 
+/// Derive macro for the [`StaticVariantType`], [`ToVariant`] and [`FromVariant`] traits.
+///
+/// Structs are converted to a tuple of their fields, e.g. `(si)` for a struct with a `String`
+/// and an `i32` field. With `#[gvariant(dict)]`, structs with named fields are converted to a
+/// dictionary `a{sv}` instead, with the field names as keys. Keys can be renamed with
+/// `#[gvariant(name = "key")]`, and fields marked with `#[gvariant(default)]` are set to their
+/// default value if they are missing when converting back.
+///
+/// Enums are converted to a tuple `(sv)` of the kebab-case name of the variant and a tuple of
+/// its fields. Enums without fields can be converted to just the name `s` with
+/// `#[gvariant(string)]`, e.g. for enum keys in `GSettings`. Names can be changed with
+/// `#[gvariant(name = "name")]`.
+///
+/// Fields of type [`Variant`] are boxed as `v` by default. With `#[gvariant(type = "...")]` they
+/// are stored as they are instead and have to be of the given type, e.g. `a{sv}` for the
+/// options of D-Bus methods. Converting a value with a variant of another type panics.
+///
+/// Attributes that don't apply where they are used, such as `#[gvariant(default)]` on a field
+/// of a tuple struct, are rejected.
+///
+/// # Example
+///
+/// ```
+/// use glib::prelude::*;
+///
+/// #[derive(Debug, PartialEq, glib::GVariant)]
+/// #[gvariant(dict)]
+/// struct WindowState {
+///     width: i32,
+///     height: i32,
+///     #[gvariant(name = "is-maximized", default)]
+///     maximized: bool,
+/// }
+///
+/// #[derive(Debug, PartialEq, glib::GVariant)]
+/// enum Shape {
+///     Circle(f64),
+///     Rectangle { width: f64, height: f64 },
+///     Empty,
+/// }
+///
+/// #[derive(Debug, PartialEq, glib::GVariant)]
+/// #[gvariant(string)]
+/// enum Theme {
+///     Light,
+///     Dark,
+///     #[gvariant(name = "system")]
+///     FollowSystem,
+/// }
+///
+/// let state = WindowState { width: 800, height: 600, maximized: false };
+/// assert_eq!(state.to_variant().get::<WindowState>(), Some(state));
+///
+/// let shape = Shape::Circle(1.0);
+/// assert_eq!(shape.to_variant().to_string(), "('circle', <(1.0,)>)");
+///
+/// assert_eq!(Theme::FollowSystem.to_variant().get_str(), Some("system"));
+/// ```
+///
+/// [`StaticVariantType`]: variant/trait.StaticVariantType.html
+/// [`ToVariant`]: variant/trait.ToVariant.html
+/// [`FromVariant`]: variant/trait.FromVariant.html
+/// [`Variant`]: variant/struct.Variant.html
+#[proc_macro_derive(GVariant, attributes(gvariant))]
+#[proc_macro_error]
+pub fn gvariant_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let gen = gvariant_derive::impl_gvariant(&input);
+    gen.into()
+}
+
 /// Attribute macro for defining flags using the `bitflags` crate.
 /// This macro will also define a `GFlags::get_type` function and
 /// the [`glib::Value`] traits.
@@ -367,13 +439,100 @@ pub fn gflags(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// The type can be marked as abstract or final by passing `abstract` or `final` to the
+/// attribute. Abstract types can't be instantiated and final types can't be subclassed.
+///
+/// ```ignore
+/// #[glib::object_subclass(abstract)]
+/// impl ObjectSubclass for MyBaseClass {
+///     // ...
+/// }
+/// ```
+///
+/// Types declaring new virtual methods need a custom `#[repr(C)]` class struct with the parent
+/// class struct as first field. Passing `class_struct` generates the `ClassStruct` and
+/// `Deref<Target = glib::Class<Self::Type>>` implementations for the struct given as `Class`.
+/// Default implementations of the virtual methods are then set in `class_init()`.
+///
+/// ```ignore
+/// #[repr(C)]
+/// pub struct MyBaseClassClass {
+///     parent_class: glib::gobject_ffi::GObjectClass,
+///     frobnicate: Option<fn(&super::MyBaseClass) -> bool>,
+/// }
+///
+/// #[glib::object_subclass(abstract, class_struct)]
+/// impl ObjectSubclass for MyBaseClass {
+///     type Class = MyBaseClassClass;
+///     // ...
+///
+///     fn class_init(klass: &mut Self::Class) {
+///         klass.frobnicate = Some(|_obj| false);
+///     }
+/// }
+/// ```
+///
+/// Types registered by a [`TypeModule`] when it is loaded are marked with `dynamic`. Their
+/// `get_type()` then returns the type registered with
+/// [`register_dynamic_type`] instead of registering the type itself.
+///
+/// ```ignore
+/// #[glib::object_subclass(dynamic)]
+/// impl ObjectSubclass for MyPluginObject {
+///     // ...
+/// }
+/// ```
+///
+/// Types that are part of the public C API of a library, e.g. for subclassing them from C, need
+/// instance and class structs matching the declarations of the C header. Passing
+/// `instance_struct` generates the `InstanceStruct` implementation for the struct given as
+/// `Instance`, like `class_struct` does for the class struct, and
+/// `export_get_type = <symbol>` exports the `get_type()` function of the header under the
+/// given name. The structs should reserve some padding for adding fields without breaking the
+/// ABI of the C subclasses, and virtual methods must use `extern "C"` functions.
+///
+/// ```ignore
+/// // typedef struct {
+/// //     GObject parent_instance;
+/// //     int counter;
+/// // } MyWidget;
+/// #[repr(C)]
+/// pub struct MyWidgetInstance {
+///     parent_instance: glib::gobject_ffi::GObject,
+///     pub counter: std::os::raw::c_int,
+/// }
+///
+/// // typedef struct {
+/// //     GObjectClass parent_class;
+/// //     void (*frobnicate) (MyWidget *widget);
+/// //     gpointer padding[8];
+/// // } MyWidgetClass;
+/// #[repr(C)]
+/// pub struct MyWidgetClass {
+///     parent_class: glib::gobject_ffi::GObjectClass,
+///     pub frobnicate: Option<unsafe extern "C" fn(*mut MyWidgetInstance)>,
+///     padding: [glib::ffi::gpointer; 8],
+/// }
+///
+/// // GType my_widget_get_type (void);
+/// #[glib::object_subclass(class_struct, instance_struct, export_get_type = my_widget_get_type)]
+/// impl ObjectSubclass for MyWidget {
+///     type Instance = MyWidgetInstance;
+///     type Class = MyWidgetClass;
+///     // ...
+/// }
+/// ```
+///
 /// [`ObjectSubclass`]: subclass/types/trait.ObjectSubclass.html
+/// [`TypeModule`]: struct.TypeModule.html
+/// [`register_dynamic_type`]: subclass/types/fn.register_dynamic_type.html
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn object_subclass(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn object_subclass(attr: TokenStream, item: TokenStream) -> TokenStream {
     use proc_macro_error::abort_call_site;
+    let args = parse_macro_input!(attr as object_subclass_attribute::Args);
     match syn::parse::<syn::ItemImpl>(item) {
-        Ok(input) => object_subclass_attribute::impl_object_subclass(&input).into(),
+        Ok(input) => object_subclass_attribute::impl_object_subclass(&args, &input).into(),
         Err(_) => abort_call_site!(object_subclass_attribute::WRONG_PLACE_MSG),
     }
 }
@@ -389,17 +548,58 @@ pub fn object_subclass(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// `Prerequisites` is interfaces for types that require a specific base class or interfaces.
 ///
 /// ```ignore
-/// type Prerequisites = ();
+/// type Prerequisites = (glib::Object,);
+/// ```
+///
+/// Placed on the interface struct, the macro makes it `#[repr(C)]`, `Clone` and `Copy` and
+/// inserts the `GTypeInterface` field that has to come first, so only the virtual methods of the
+/// interface have to be declared. `#[repr(packed)]` and `#[repr(align)]` are rejected, as GLib
+/// allocates the interface struct with the alignment of its C layout.
+///
+/// ```ignore
+/// #[glib::object_interface]
+/// pub struct MyInterface {
+///     frobnicate: Option<fn(&super::MyInterface) -> bool>,
+/// }
+///
+/// #[glib::object_interface]
+/// unsafe impl ObjectInterface for MyInterface {
+///     const NAME: &'static str = "MyInterface";
+///     type Prerequisites = (glib::Object,);
+///
+///     fn interface_init(&mut self) {
+///         self.frobnicate = Some(|_obj| false);
+///     }
+/// }
+/// ```
+///
+/// Interfaces registered by a [`TypeModule`] when it is loaded are marked with `dynamic`, like
+/// dynamic types with `object_subclass`, and are registered with
+/// [`register_dynamic_interface`].
+///
+/// ```ignore
+/// #[glib::object_interface(dynamic)]
+/// unsafe impl ObjectInterface for MyPluginInterface {
+///     // ...
+/// }
 /// ```
 ///
 /// [`ObjectInterface`]: interface/types/trait.ObjectInterface.html
+/// [`TypeModule`]: struct.TypeModule.html
+/// [`register_dynamic_interface`]: subclass/interface/fn.register_dynamic_interface.html
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn object_interface(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn object_interface(attr: TokenStream, item: TokenStream) -> TokenStream {
     use proc_macro_error::abort_call_site;
-    match syn::parse::<syn::ItemImpl>(item) {
-        Ok(input) => object_interface_attribute::impl_object_interface(&input).into(),
-        Err(_) => abort_call_site!(object_interface_attribute::WRONG_PLACE_MSG),
+    let args = parse_macro_input!(attr as object_interface_attribute::Args);
+    match syn::parse::<syn::Item>(item) {
+        Ok(syn::Item::Impl(input)) => {
+            object_interface_attribute::impl_object_interface(&args, &input).into()
+        }
+        Ok(syn::Item::Struct(input)) => {
+            object_interface_attribute::impl_interface_struct(&args, &input).into()
+        }
+        _ => abort_call_site!(object_interface_attribute::WRONG_PLACE_MSG),
     }
 }
 
@@ -1,13 +1,37 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use proc_macro2::TokenStream;
-use proc_macro_error::abort_call_site;
+use proc_macro_error::{abort, abort_call_site};
 use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::Token;
 
 pub const WRONG_PLACE_MSG: &str =
-    "This macro should be used on `impl` block for `glib::ObjectInterface` trait";
+    "This macro should be used on the interface struct or on `impl` block for `glib::ObjectInterface` trait";
 
-pub fn impl_object_interface(input: &syn::ItemImpl) -> TokenStream {
+/// Arguments passed to `#[object_interface(...)]`.
+#[derive(Default)]
+pub struct Args {
+    dynamic: bool,
+}
+
+impl syn::parse::Parse for Args {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Args::default();
+
+        let idents = Punctuated::<syn::Ident, Token![,]>::parse_terminated(input)?;
+        for ident in idents {
+            match ident.to_string().as_str() {
+                "dynamic" => args.dynamic = true,
+                _ => abort!(ident, "Unknown argument, expected `dynamic`"),
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+pub fn impl_object_interface(args: &Args, input: &syn::ItemImpl) -> TokenStream {
     let mut has_prerequisites = false;
     for item in &input.items {
         if let syn::ImplItem::Type(type_) = item {
@@ -43,14 +67,23 @@ pub fn impl_object_interface(input: &syn::ItemImpl) -> TokenStream {
         None => abort_call_site!(WRONG_PLACE_MSG),
     };
 
-    quote! {
-        #(#attrs)*
-        #unsafety impl#generics #trait_path for #self_ty {
-            #prerequisites_opt
-            #(#items)*
+    // Dynamic interfaces are registered by their type module whenever it is loaded, so the
+    // type only has to be looked up.
+    let get_type = if args.dynamic {
+        quote! {
+            fn get_type() -> #crate_ident::Type {
+                let name = <Self as #crate_ident::subclass::interface::ObjectInterface>::NAME;
+                match #crate_ident::Type::from_name(name) {
+                    Some(type_) => type_,
+                    None => panic!(
+                        "Dynamic interface {} was not registered by a type module yet",
+                        name,
+                    ),
+                }
+            }
         }
-
-        unsafe impl #crate_ident::subclass::interface::ObjectInterfaceType for #self_ty {
+    } else {
+        quote! {
             fn get_type() -> #crate_ident::Type {
                 static ONCE: std::sync::Once = std::sync::Once::new();
                 static mut TYPE: #crate_ident::Type = #crate_ident::Type::INVALID;
@@ -68,5 +101,106 @@ pub fn impl_object_interface(input: &syn::ItemImpl) -> TokenStream {
                 }
             }
         }
+    };
+
+    quote! {
+        #(#attrs)*
+        #unsafety impl#generics #trait_path for #self_ty {
+            #prerequisites_opt
+            #(#items)*
+        }
+
+        unsafe impl #crate_ident::subclass::interface::ObjectInterfaceType for #self_ty {
+            #get_type
+        }
+    }
+}
+
+pub fn impl_interface_struct(args: &Args, input: &syn::ItemStruct) -> TokenStream {
+    if args.dynamic {
+        abort_call_site!("`dynamic` has to be passed on the `impl` block");
+    }
+
+    let syn::ItemStruct {
+        attrs,
+        vis,
+        struct_token,
+        ident,
+        generics,
+        fields,
+        ..
+    } = &input;
+
+    let fields = match fields {
+        syn::Fields::Named(fields) => fields.named.iter().collect(),
+        syn::Fields::Unit => Vec::new(),
+        syn::Fields::Unnamed(fields) => abort!(fields, "Interface structs need named fields"),
+    };
+
+    // Interface structs are copied when an implementor is initialized, see `IsImplementable`
+    let mut derived = Vec::new();
+    let mut has_repr_c = false;
+    for attr in attrs {
+        if !attr.path.is_ident("derive") && !attr.path.is_ident("repr") {
+            continue;
+        }
+
+        let nested = match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => list.nested,
+            _ => abort!(attr, "Malformed attribute"),
+        };
+        for meta in nested {
+            let path = match meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => path.clone(),
+                syn::NestedMeta::Meta(syn::Meta::List(ref list)) => list.path.clone(),
+                _ => abort!(meta, "Malformed attribute"),
+            };
+            if attr.path.is_ident("derive") {
+                if let Some(segment) = path.segments.last() {
+                    derived.push(segment.ident.to_string());
+                }
+            } else if path.is_ident("C") {
+                has_repr_c = true;
+            } else if path.is_ident("packed") || path.is_ident("align") {
+                // GLib allocates and copies the struct with the layout of its C declaration
+                abort!(
+                    path,
+                    "Interface structs can't change their alignment with `{}`",
+                    path.get_ident().unwrap()
+                );
+            } else {
+                abort!(path, "Interface structs need the `C` representation");
+            }
+        }
+    }
+
+    let missing = ["Clone", "Copy"]
+        .iter()
+        .filter(|name| !derived.iter().any(|derived| derived == *name))
+        .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()))
+        .collect::<Vec<_>>();
+    let derive_opt = if missing.is_empty() {
+        None
+    } else {
+        Some(quote!(#[derive(#(#missing),*)]))
+    };
+
+    let repr_opt = if has_repr_c {
+        None
+    } else {
+        Some(quote!(#[repr(C)]))
+    };
+
+    let crate_ident = crate::utils::crate_ident_new();
+    let where_clause = &generics.where_clause;
+
+    quote! {
+        #(#attrs)*
+        #derive_opt
+        #repr_opt
+        #vis #struct_token #ident #generics #where_clause {
+            parent: #crate_ident::gobject_ffi::GTypeInterface,
+            #(#fields),*
+        }
     }
 }
@@ -1,17 +1,67 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use proc_macro2::TokenStream;
-use proc_macro_error::abort_call_site;
+use proc_macro_error::{abort, abort_call_site};
 use quote::quote;
+use syn::ext::IdentExt;
+use syn::Token;
 
 pub const WRONG_PLACE_MSG: &str =
     "This macro should be used on `impl` block for `glib::ObjectSubclass` trait";
 
-pub fn impl_object_subclass(input: &syn::ItemImpl) -> TokenStream {
+/// Arguments passed to `#[object_subclass(...)]`.
+#[derive(Default)]
+pub struct Args {
+    abstract_: bool,
+    final_: bool,
+    class_struct: bool,
+    instance_struct: bool,
+    dynamic: bool,
+    export_get_type: Option<syn::Ident>,
+}
+
+impl syn::parse::Parse for Args {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Args::default();
+
+        while !input.is_empty() {
+            // `abstract` and `final` are reserved keywords, hence `parse_any`.
+            let ident = input.call(syn::Ident::parse_any)?;
+            match ident.to_string().as_str() {
+                "abstract" => args.abstract_ = true,
+                "final" => args.final_ = true,
+                "class_struct" => args.class_struct = true,
+                "instance_struct" => args.instance_struct = true,
+                "dynamic" => args.dynamic = true,
+                "export_get_type" => {
+                    input.parse::<Token![=]>()?;
+                    args.export_get_type = Some(input.parse()?);
+                }
+                _ => abort!(
+                    ident,
+                    "Unknown argument, expected `abstract`, `final`, `class_struct`, \
+                     `instance_struct`, `dynamic` or `export_get_type`"
+                ),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        if args.abstract_ && args.final_ {
+            abort_call_site!("A type can't be both `abstract` and `final`");
+        }
+
+        Ok(args)
+    }
+}
+
+pub fn impl_object_subclass(args: &Args, input: &syn::ItemImpl) -> TokenStream {
     let mut has_new = false;
     let mut has_interfaces = false;
-    let mut has_instance = false;
-    let mut has_class = false;
+    let mut instance_ty = None;
+    let mut class_ty = None;
     for item in &input.items {
         match item {
             syn::ImplItem::Method(method) => {
@@ -25,9 +75,9 @@ pub fn impl_object_subclass(input: &syn::ItemImpl) -> TokenStream {
                 if name == "Interfaces" {
                     has_interfaces = true;
                 } else if name == "Instance" {
-                    has_instance = true;
+                    instance_ty = Some(&type_.ty);
                 } else if name == "Class" {
-                    has_class = true;
+                    class_ty = Some(&type_.ty);
                 }
             }
             _ => {}
@@ -63,13 +113,29 @@ pub fn impl_object_subclass(input: &syn::ItemImpl) -> TokenStream {
 
     let crate_ident = crate::utils::crate_ident_new();
 
-    let class_opt = if has_class {
+    let class_opt = if class_ty.is_some() {
         None
     } else {
         Some(quote!(type Class = #crate_ident::subclass::basic::ClassStruct<Self>;))
     };
 
-    let instance_opt = if has_instance {
+    let abstract_opt = if args.abstract_ {
+        Some(quote!(
+            const ABSTRACT: bool = true;
+        ))
+    } else {
+        None
+    };
+
+    let final_opt = if args.final_ {
+        Some(quote!(
+            const FINAL: bool = true;
+        ))
+    } else {
+        None
+    };
+
+    let instance_opt = if instance_ty.is_some() {
         None
     } else {
         Some(quote!(type Instance = #crate_ident::subclass::basic::InstanceStruct<Self>;))
@@ -80,9 +146,108 @@ pub fn impl_object_subclass(input: &syn::ItemImpl) -> TokenStream {
         None => abort_call_site!(WRONG_PLACE_MSG),
     };
 
+    // A custom class struct only has to declare its fields, the trait impls linking it to the
+    // subclass and giving access to the parent class are always the same.
+    let class_struct_opt = if args.class_struct {
+        let class_ty = match class_ty {
+            Some(class_ty) => class_ty,
+            None => abort_call_site!("`class_struct` requires a `type Class` definition"),
+        };
+
+        Some(quote! {
+            unsafe impl #crate_ident::subclass::types::ClassStruct for #class_ty {
+                type Type = #self_ty;
+            }
+
+            impl std::ops::Deref for #class_ty {
+                type Target = #crate_ident::Class<<#self_ty as #crate_ident::subclass::types::ObjectSubclass>::Type>;
+
+                fn deref(&self) -> &Self::Target {
+                    unsafe { &*(self as *const _ as *const Self::Target) }
+                }
+            }
+
+            impl std::ops::DerefMut for #class_ty {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    unsafe { &mut *(self as *mut _ as *mut Self::Target) }
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let instance_struct_opt = if args.instance_struct {
+        let instance_ty = match instance_ty {
+            Some(instance_ty) => instance_ty,
+            None => abort_call_site!("`instance_struct` requires a `type Instance` definition"),
+        };
+
+        Some(quote! {
+            unsafe impl #crate_ident::subclass::types::InstanceStruct for #instance_ty {
+                type Type = #self_ty;
+            }
+        })
+    } else {
+        None
+    };
+
+    // The `*_get_type()` function declared in the C header of the type, e.g. for subclassing it
+    // from C.
+    let export_get_type_opt = args.export_get_type.as_ref().map(|symbol| {
+        quote! {
+            #[no_mangle]
+            pub extern "C" fn #symbol() -> #crate_ident::ffi::GType {
+                #crate_ident::translate::ToGlib::to_glib(
+                    &<#self_ty as #crate_ident::subclass::types::ObjectSubclassType>::get_type(),
+                )
+            }
+        }
+    });
+
+    // Dynamic types are registered by their type module whenever it is loaded, so the type only
+    // has to be looked up.
+    let get_type = if args.dynamic {
+        quote! {
+            fn get_type() -> #crate_ident::Type {
+                unsafe {
+                    let data = Self::type_data();
+                    let type_ = data.as_ref().get_type();
+                    assert!(
+                        type_.is_valid(),
+                        "Dynamic type {} was not registered by a type module yet",
+                        <Self as #crate_ident::subclass::types::ObjectSubclass>::NAME,
+                    );
+
+                    type_
+                }
+            }
+        }
+    } else {
+        quote! {
+            fn get_type() -> #crate_ident::Type {
+                static ONCE: std::sync::Once = std::sync::Once::new();
+
+                ONCE.call_once(|| {
+                    #crate_ident::subclass::register_type::<Self>();
+                });
+
+                unsafe {
+                    let data = Self::type_data();
+                    let type_ = data.as_ref().get_type();
+                    assert!(type_.is_valid());
+
+                    type_
+                }
+            }
+        }
+    };
+
     quote! {
         #(#attrs)*
         impl#generics #trait_path for #self_ty {
+            #abstract_opt
+            #final_opt
             #interfaces_opt
             #class_opt
             #instance_opt
@@ -104,21 +269,11 @@ pub fn impl_object_subclass(input: &syn::ItemImpl) -> TokenStream {
                 unsafe { std::ptr::NonNull::new_unchecked(&mut DATA) }
             }
 
-            fn get_type() -> #crate_ident::Type {
-                static ONCE: std::sync::Once = std::sync::Once::new();
-
-                ONCE.call_once(|| {
-                    #crate_ident::subclass::register_type::<Self>();
-                });
-
-                unsafe {
-                    let data = Self::type_data();
-                    let type_ = data.as_ref().get_type();
-                    assert!(type_.is_valid());
-
-                    type_
-                }
-            }
+            #get_type
         }
+
+        #class_struct_opt
+        #instance_struct_opt
+        #export_get_type_opt
     }
 }
@@ -5,7 +5,7 @@
 use glib::prelude::*;
 use glib::subclass::prelude::*;
 use glib::translate::{FromGlib, ToGlib};
-use glib::{gflags, GBoxed, GEnum, GErrorDomain};
+use glib::{gflags, GBoxed, GEnum, GErrorDomain, GVariant};
 
 #[test]
 fn derive_gerror_domain() {
@@ -110,6 +110,143 @@ fn derive_gboxed_nullable() {
     assert_eq!(None, v.get::<&MyNullableBoxed>().unwrap());
 }
 
+#[test]
+fn derive_gvariant() {
+    #[derive(Debug, PartialEq, GVariant)]
+    struct Point(i32, i32);
+
+    #[derive(Debug, PartialEq, GVariant)]
+    struct Item {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq, GVariant)]
+    #[gvariant(dict)]
+    struct Settings {
+        #[gvariant(name = "font-size")]
+        font_size: u32,
+        #[gvariant(default)]
+        monospace: bool,
+    }
+
+    #[derive(Debug, PartialEq, GVariant)]
+    enum Message {
+        Quit,
+        Move { x: i32, y: i32 },
+        Write(String),
+    }
+
+    #[derive(Debug, PartialEq, GVariant)]
+    struct Call {
+        method: String,
+        #[gvariant(type = "a{sv}")]
+        options: glib::Variant,
+    }
+
+    #[derive(Debug, PartialEq, GVariant)]
+    #[gvariant(dict)]
+    struct Entry {
+        #[gvariant(type = "as")]
+        tags: glib::Variant,
+    }
+
+    #[derive(Debug, PartialEq, GVariant)]
+    #[gvariant(string)]
+    enum Direction {
+        Up,
+        #[gvariant(name = "DOWN")]
+        Down,
+    }
+
+    assert_eq!(Point::static_variant_type().to_str(), "(ii)");
+    let v = Point(1, -2).to_variant();
+    assert_eq!(v.to_string(), "(1, -2)");
+    assert_eq!(v.get::<Point>(), Some(Point(1, -2)));
+    assert_eq!(v.get::<(i32, i32)>(), Some((1, -2)));
+
+    assert_eq!(Item::static_variant_type().to_str(), "(sas)");
+    let item = Item {
+        name: String::from("gtk"),
+        tags: vec![String::from("gui")],
+    };
+    assert_eq!(item.to_variant().get::<Item>(), Some(item));
+
+    assert_eq!(Settings::static_variant_type().to_str(), "a{sv}");
+    let settings = Settings {
+        font_size: 12,
+        monospace: true,
+    };
+    let v = settings.to_variant();
+    let dict = glib::VariantDict::new(Some(&v));
+    assert_eq!(
+        dict.lookup_value("font-size", None)
+            .and_then(|v| v.get::<u32>()),
+        Some(12)
+    );
+    assert_eq!(v.get::<Settings>(), Some(settings));
+    dict.remove("monospace");
+    assert_eq!(
+        dict.end().get::<Settings>(),
+        Some(Settings {
+            font_size: 12,
+            monospace: false
+        })
+    );
+    assert_eq!(glib::VariantDict::new(None).end().get::<Settings>(), None);
+
+    assert_eq!(Message::static_variant_type().to_str(), "(sv)");
+    assert_eq!(Message::Quit.to_variant().to_string(), "('quit', <()>)");
+    for message in vec![
+        Message::Quit,
+        Message::Move { x: 1, y: 2 },
+        Message::Write(String::from("hello")),
+    ] {
+        assert_eq!(message.to_variant().get::<Message>(), Some(message));
+    }
+    assert_eq!(
+        ("move", (1i32,).to_variant()).to_variant().get::<Message>(),
+        None
+    );
+    assert_eq!(
+        ("jump", glib::Variant::tuple(&[]))
+            .to_variant()
+            .get::<Message>(),
+        None
+    );
+
+    assert_eq!(Call::static_variant_type().to_str(), "(sa{sv})");
+    let call = Call {
+        method: String::from("open"),
+        options: glib::VariantDict::new(None).end(),
+    };
+    let v = call.to_variant();
+    assert_eq!(v.get_child_value(1).type_().to_str(), "a{sv}");
+    assert_eq!(v.get::<Call>(), Some(call));
+
+    let entry = Entry {
+        tags: vec!["a", "b"].to_variant(),
+    };
+    let v = entry.to_variant();
+    let dict = glib::VariantDict::new(Some(&v));
+    assert_eq!(
+        dict.lookup_value("tags", None)
+            .map(|v| v.type_().to_str().to_owned()),
+        Some(String::from("as"))
+    );
+    assert_eq!(v.get::<Entry>(), Some(entry));
+    dict.insert_value("tags", &"a".to_variant());
+    assert_eq!(dict.end().get::<Entry>(), None);
+
+    assert_eq!(Direction::static_variant_type().to_str(), "s");
+    assert_eq!(Direction::Up.to_variant().get_str(), Some("up"));
+    assert_eq!(
+        "DOWN".to_variant().get::<Direction>(),
+        Some(Direction::Down)
+    );
+    assert_eq!("down".to_variant().get::<Direction>(), None);
+}
+
 #[test]
 fn attr_gflags() {
     #[gflags("MyFlags")]
@@ -173,3 +310,206 @@ fn attr_gflags() {
     assert!(e.get_value_by_nick("ab").is_none());
     assert!(e.get_value_by_nick("c").is_some());
 }
+
+mod abstract_final_imp {
+    use glib::subclass::prelude::*;
+
+    #[repr(C)]
+    pub struct AbstractBaseClass {
+        pub parent_class: glib::gobject_ffi::GObjectClass,
+        pub value: Option<fn(&super::AbstractBase) -> i32>,
+    }
+
+    #[derive(Default)]
+    pub struct AbstractBase;
+
+    #[glib::object_subclass(abstract, class_struct)]
+    impl ObjectSubclass for AbstractBase {
+        const NAME: &'static str = "MacroTestAbstractBase";
+        type Type = super::AbstractBase;
+        type ParentType = glib::Object;
+        type Class = AbstractBaseClass;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.value = Some(|_| 42);
+        }
+    }
+
+    impl ObjectImpl for AbstractBase {}
+
+    #[derive(Default)]
+    pub struct FinalDerived;
+
+    #[glib::object_subclass(final)]
+    impl ObjectSubclass for FinalDerived {
+        const NAME: &'static str = "MacroTestFinalDerived";
+        type Type = super::FinalDerived;
+        type ParentType = super::AbstractBase;
+    }
+
+    impl ObjectImpl for FinalDerived {}
+}
+
+glib::wrapper! {
+    pub struct AbstractBase(ObjectSubclass<abstract_final_imp::AbstractBase>);
+}
+
+unsafe impl<T: ObjectImpl> IsSubclassable<T> for AbstractBase {
+    fn class_init(class: &mut glib::Class<Self>) {
+        <glib::Object as IsSubclassable<T>>::class_init(class);
+    }
+
+    fn instance_init(instance: &mut glib::subclass::InitializingObject<T>) {
+        <glib::Object as IsSubclassable<T>>::instance_init(instance);
+    }
+}
+
+glib::wrapper! {
+    pub struct FinalDerived(ObjectSubclass<abstract_final_imp::FinalDerived>) @extends AbstractBase;
+}
+
+#[test]
+fn attr_object_subclass_abstract_final() {
+    assert!(glib::Object::with_type(AbstractBase::static_type(), &[]).is_err());
+    assert!(!abstract_final_imp::FinalDerived::ABSTRACT);
+    assert!(abstract_final_imp::FinalDerived::FINAL);
+
+    let obj = glib::Object::new::<FinalDerived>(&[]).expect("Object::new failed");
+    let base = obj.upcast_ref::<AbstractBase>();
+    let klass = base.get_class();
+    assert_eq!((klass.as_ref().value.unwrap())(base), 42);
+}
+
+mod interface_imp {
+    use glib::subclass::prelude::*;
+
+    #[glib::object_interface]
+    pub struct Frobnicator {
+        pub frobnicate: Option<fn(&super::Frobnicator) -> i32>,
+    }
+
+    #[glib::object_interface]
+    unsafe impl ObjectInterface for Frobnicator {
+        const NAME: &'static str = "MacroTestFrobnicator";
+        type Prerequisites = (glib::Object,);
+
+        fn interface_init(&mut self) {
+            self.frobnicate = Some(|_| 42);
+        }
+    }
+
+    // Only the missing derives are added
+    #[glib::object_interface]
+    #[derive(Clone, Debug)]
+    #[repr(C)]
+    pub struct PartiallyDerived {
+        pub value: u32,
+    }
+
+    #[derive(Default)]
+    pub struct Frobnicated;
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Frobnicated {
+        const NAME: &'static str = "MacroTestFrobnicated";
+        type Type = super::Frobnicated;
+        type ParentType = glib::Object;
+        type Interfaces = (super::Frobnicator,);
+    }
+
+    impl ObjectImpl for Frobnicated {}
+}
+
+glib::wrapper! {
+    pub struct Frobnicator(ObjectInterface<interface_imp::Frobnicator>);
+}
+
+unsafe impl<T: ObjectSubclass> IsImplementable<T> for Frobnicator {
+    fn interface_init(_iface: &mut glib::Interface<Self>) {}
+}
+
+glib::wrapper! {
+    pub struct Frobnicated(ObjectSubclass<interface_imp::Frobnicated>) @implements Frobnicator;
+}
+
+#[test]
+fn attr_object_interface() {
+    assert!(Frobnicator::static_type().is_a(glib::Type::INTERFACE));
+
+    let obj = glib::Object::new::<Frobnicated>(&[]).expect("Object::new failed");
+    let iface = interface_imp::Frobnicator::from_instance(&obj);
+    assert_eq!((iface.frobnicate.unwrap())(obj.upcast_ref()), 42);
+
+    fn assert_copy<T: Copy + std::fmt::Debug>() {}
+    assert_copy::<interface_imp::PartiallyDerived>();
+    assert_eq!(
+        std::mem::align_of::<interface_imp::PartiallyDerived>(),
+        std::mem::align_of::<glib::gobject_ffi::GTypeInterface>()
+    );
+}
+
+mod c_layout_imp {
+    use glib::subclass::prelude::*;
+    use std::os::raw::c_int;
+
+    #[repr(C)]
+    pub struct CLayoutInstance {
+        parent_instance: glib::gobject_ffi::GObject,
+        pub counter: c_int,
+    }
+
+    #[repr(C)]
+    pub struct CLayoutClass {
+        parent_class: glib::gobject_ffi::GObjectClass,
+        pub get_counter: Option<unsafe extern "C" fn(*mut CLayoutInstance) -> c_int>,
+        padding: [glib::ffi::gpointer; 8],
+    }
+
+    unsafe extern "C" fn get_counter(instance: *mut CLayoutInstance) -> c_int {
+        (*instance).counter
+    }
+
+    #[derive(Default)]
+    pub struct CLayout;
+
+    #[glib::object_subclass(
+        class_struct,
+        instance_struct,
+        export_get_type = macro_test_c_layout_get_type
+    )]
+    impl ObjectSubclass for CLayout {
+        const NAME: &'static str = "MacroTestCLayout";
+        type Type = super::CLayout;
+        type ParentType = glib::Object;
+        type Instance = CLayoutInstance;
+        type Class = CLayoutClass;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.get_counter = Some(get_counter);
+        }
+    }
+
+    impl ObjectImpl for CLayout {}
+}
+
+glib::wrapper! {
+    pub struct CLayout(ObjectSubclass<c_layout_imp::CLayout>);
+}
+
+#[test]
+fn attr_object_subclass_c_layout() {
+    assert_eq!(
+        c_layout_imp::macro_test_c_layout_get_type(),
+        CLayout::static_type().to_glib()
+    );
+
+    let obj = glib::Object::new::<CLayout>(&[]).expect("Object::new failed");
+    unsafe {
+        let instance = obj.as_ptr();
+        assert_eq!((*instance).counter, 0);
+        (*instance).counter = 42;
+
+        let klass = obj.get_class();
+        assert_eq!((klass.as_ref().get_counter.unwrap())(instance), 42);
+    }
+}
@@ -354,6 +354,104 @@ impl ErrorDomain for KeyFileError {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[non_exhaustive]
+#[doc(alias = "GMarkupError")]
+pub enum MarkupError {
+    #[doc(alias = "G_MARKUP_ERROR_BAD_UTF8")]
+    BadUtf8,
+    #[doc(alias = "G_MARKUP_ERROR_EMPTY")]
+    Empty,
+    #[doc(alias = "G_MARKUP_ERROR_PARSE")]
+    Parse,
+    #[doc(alias = "G_MARKUP_ERROR_UNKNOWN_ELEMENT")]
+    UnknownElement,
+    #[doc(alias = "G_MARKUP_ERROR_UNKNOWN_ATTRIBUTE")]
+    UnknownAttribute,
+    #[doc(alias = "G_MARKUP_ERROR_INVALID_CONTENT")]
+    InvalidContent,
+    #[doc(alias = "G_MARKUP_ERROR_MISSING_ATTRIBUTE")]
+    MissingAttribute,
+    #[doc(hidden)]
+    __Unknown(i32),
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MarkupError::{}",
+            match *self {
+                MarkupError::BadUtf8 => "BadUtf8",
+                MarkupError::Empty => "Empty",
+                MarkupError::Parse => "Parse",
+                MarkupError::UnknownElement => "UnknownElement",
+                MarkupError::UnknownAttribute => "UnknownAttribute",
+                MarkupError::InvalidContent => "InvalidContent",
+                MarkupError::MissingAttribute => "MissingAttribute",
+                _ => "Unknown",
+            }
+        )
+    }
+}
+
+#[doc(hidden)]
+impl ToGlib for MarkupError {
+    type GlibType = ffi::GMarkupError;
+
+    fn to_glib(&self) -> ffi::GMarkupError {
+        match *self {
+            MarkupError::BadUtf8 => ffi::G_MARKUP_ERROR_BAD_UTF8,
+            MarkupError::Empty => ffi::G_MARKUP_ERROR_EMPTY,
+            MarkupError::Parse => ffi::G_MARKUP_ERROR_PARSE,
+            MarkupError::UnknownElement => ffi::G_MARKUP_ERROR_UNKNOWN_ELEMENT,
+            MarkupError::UnknownAttribute => ffi::G_MARKUP_ERROR_UNKNOWN_ATTRIBUTE,
+            MarkupError::InvalidContent => ffi::G_MARKUP_ERROR_INVALID_CONTENT,
+            MarkupError::MissingAttribute => ffi::G_MARKUP_ERROR_MISSING_ATTRIBUTE,
+            MarkupError::__Unknown(value) => value,
+        }
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::GMarkupError> for MarkupError {
+    unsafe fn from_glib(value: ffi::GMarkupError) -> Self {
+        match value {
+            0 => MarkupError::BadUtf8,
+            1 => MarkupError::Empty,
+            2 => MarkupError::Parse,
+            3 => MarkupError::UnknownElement,
+            4 => MarkupError::UnknownAttribute,
+            5 => MarkupError::InvalidContent,
+            6 => MarkupError::MissingAttribute,
+            value => MarkupError::__Unknown(value),
+        }
+    }
+}
+
+impl ErrorDomain for MarkupError {
+    fn domain() -> Quark {
+        unsafe { from_glib(ffi::g_markup_error_quark()) }
+    }
+
+    fn code(self) -> i32 {
+        self.to_glib()
+    }
+
+    fn from(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(MarkupError::BadUtf8),
+            1 => Some(MarkupError::Empty),
+            2 => Some(MarkupError::Parse),
+            3 => Some(MarkupError::UnknownElement),
+            4 => Some(MarkupError::UnknownAttribute),
+            5 => Some(MarkupError::InvalidContent),
+            6 => Some(MarkupError::MissingAttribute),
+            value => Some(MarkupError::__Unknown(value)),
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
 #[non_exhaustive]
 #[doc(alias = "GOptionArg")]
@@ -439,6 +537,431 @@ impl FromGlib<ffi::GOptionArg> for OptionArg {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[non_exhaustive]
+#[doc(alias = "GRegexError")]
+pub enum RegexError {
+    #[doc(alias = "G_REGEX_ERROR_COMPILE")]
+    Compile,
+    #[doc(alias = "G_REGEX_ERROR_OPTIMIZE")]
+    Optimize,
+    #[doc(alias = "G_REGEX_ERROR_REPLACE")]
+    Replace,
+    #[doc(alias = "G_REGEX_ERROR_MATCH")]
+    Match,
+    #[doc(alias = "G_REGEX_ERROR_INTERNAL")]
+    Internal,
+    #[doc(alias = "G_REGEX_ERROR_STRAY_BACKSLASH")]
+    StrayBackslash,
+    #[doc(alias = "G_REGEX_ERROR_MISSING_CONTROL_CHAR")]
+    MissingControlChar,
+    #[doc(alias = "G_REGEX_ERROR_UNRECOGNIZED_ESCAPE")]
+    UnrecognizedEscape,
+    #[doc(alias = "G_REGEX_ERROR_QUANTIFIERS_OUT_OF_ORDER")]
+    QuantifiersOutOfOrder,
+    #[doc(alias = "G_REGEX_ERROR_QUANTIFIER_TOO_BIG")]
+    QuantifierTooBig,
+    #[doc(alias = "G_REGEX_ERROR_UNTERMINATED_CHARACTER_CLASS")]
+    UnterminatedCharacterClass,
+    #[doc(alias = "G_REGEX_ERROR_INVALID_ESCAPE_IN_CHARACTER_CLASS")]
+    InvalidEscapeInCharacterClass,
+    #[doc(alias = "G_REGEX_ERROR_RANGE_OUT_OF_ORDER")]
+    RangeOutOfOrder,
+    #[doc(alias = "G_REGEX_ERROR_NOTHING_TO_REPEAT")]
+    NothingToRepeat,
+    #[doc(alias = "G_REGEX_ERROR_UNRECOGNIZED_CHARACTER")]
+    UnrecognizedCharacter,
+    #[doc(alias = "G_REGEX_ERROR_POSIX_NAMED_CLASS_OUTSIDE_CLASS")]
+    PosixNamedClassOutsideClass,
+    #[doc(alias = "G_REGEX_ERROR_UNMATCHED_PARENTHESIS")]
+    UnmatchedParenthesis,
+    #[doc(alias = "G_REGEX_ERROR_INEXISTENT_SUBPATTERN_REFERENCE")]
+    InexistentSubpatternReference,
+    #[doc(alias = "G_REGEX_ERROR_UNTERMINATED_COMMENT")]
+    UnterminatedComment,
+    #[doc(alias = "G_REGEX_ERROR_EXPRESSION_TOO_LARGE")]
+    ExpressionTooLarge,
+    #[doc(alias = "G_REGEX_ERROR_MEMORY_ERROR")]
+    MemoryError,
+    #[doc(alias = "G_REGEX_ERROR_VARIABLE_LENGTH_LOOKBEHIND")]
+    VariableLengthLookbehind,
+    #[doc(alias = "G_REGEX_ERROR_MALFORMED_CONDITION")]
+    MalformedCondition,
+    #[doc(alias = "G_REGEX_ERROR_TOO_MANY_CONDITIONAL_BRANCHES")]
+    TooManyConditionalBranches,
+    #[doc(alias = "G_REGEX_ERROR_ASSERTION_EXPECTED")]
+    AssertionExpected,
+    #[doc(alias = "G_REGEX_ERROR_UNKNOWN_POSIX_CLASS_NAME")]
+    UnknownPosixClassName,
+    #[doc(alias = "G_REGEX_ERROR_POSIX_COLLATING_ELEMENTS_NOT_SUPPORTED")]
+    PosixCollatingElementsNotSupported,
+    #[doc(alias = "G_REGEX_ERROR_HEX_CODE_TOO_LARGE")]
+    HexCodeTooLarge,
+    #[doc(alias = "G_REGEX_ERROR_INVALID_CONDITION")]
+    InvalidCondition,
+    #[doc(alias = "G_REGEX_ERROR_SINGLE_BYTE_MATCH_IN_LOOKBEHIND")]
+    SingleByteMatchInLookbehind,
+    #[doc(alias = "G_REGEX_ERROR_INFINITE_LOOP")]
+    InfiniteLoop,
+    #[doc(alias = "G_REGEX_ERROR_MISSING_SUBPATTERN_NAME_TERMINATOR")]
+    MissingSubpatternNameTerminator,
+    #[doc(alias = "G_REGEX_ERROR_DUPLICATE_SUBPATTERN_NAME")]
+    DuplicateSubpatternName,
+    #[doc(alias = "G_REGEX_ERROR_MALFORMED_PROPERTY")]
+    MalformedProperty,
+    #[doc(alias = "G_REGEX_ERROR_UNKNOWN_PROPERTY")]
+    UnknownProperty,
+    #[doc(alias = "G_REGEX_ERROR_SUBPATTERN_NAME_TOO_LONG")]
+    SubpatternNameTooLong,
+    #[doc(alias = "G_REGEX_ERROR_TOO_MANY_SUBPATTERNS")]
+    TooManySubpatterns,
+    #[doc(alias = "G_REGEX_ERROR_INVALID_OCTAL_VALUE")]
+    InvalidOctalValue,
+    #[doc(alias = "G_REGEX_ERROR_TOO_MANY_BRANCHES_IN_DEFINE")]
+    TooManyBranchesInDefine,
+    #[doc(alias = "G_REGEX_ERROR_DEFINE_REPETION")]
+    DefineRepetion,
+    #[doc(alias = "G_REGEX_ERROR_INCONSISTENT_NEWLINE_OPTIONS")]
+    InconsistentNewlineOptions,
+    #[doc(alias = "G_REGEX_ERROR_MISSING_BACK_REFERENCE")]
+    MissingBackReference,
+    #[doc(alias = "G_REGEX_ERROR_INVALID_RELATIVE_REFERENCE")]
+    InvalidRelativeReference,
+    #[doc(alias = "G_REGEX_ERROR_BACKTRACKING_CONTROL_VERB_ARGUMENT_FORBIDDEN")]
+    BacktrackingControlVerbArgumentForbidden,
+    #[doc(alias = "G_REGEX_ERROR_UNKNOWN_BACKTRACKING_CONTROL_VERB")]
+    UnknownBacktrackingControlVerb,
+    #[doc(alias = "G_REGEX_ERROR_NUMBER_TOO_BIG")]
+    NumberTooBig,
+    #[doc(alias = "G_REGEX_ERROR_MISSING_SUBPATTERN_NAME")]
+    MissingSubpatternName,
+    #[doc(alias = "G_REGEX_ERROR_MISSING_DIGIT")]
+    MissingDigit,
+    #[doc(alias = "G_REGEX_ERROR_INVALID_DATA_CHARACTER")]
+    InvalidDataCharacter,
+    #[doc(alias = "G_REGEX_ERROR_EXTRA_SUBPATTERN_NAME")]
+    ExtraSubpatternName,
+    #[doc(alias = "G_REGEX_ERROR_BACKTRACKING_CONTROL_VERB_ARGUMENT_REQUIRED")]
+    BacktrackingControlVerbArgumentRequired,
+    #[doc(alias = "G_REGEX_ERROR_INVALID_CONTROL_CHAR")]
+    InvalidControlChar,
+    #[doc(alias = "G_REGEX_ERROR_MISSING_NAME")]
+    MissingName,
+    #[doc(alias = "G_REGEX_ERROR_NOT_SUPPORTED_IN_CLASS")]
+    NotSupportedInClass,
+    #[doc(alias = "G_REGEX_ERROR_TOO_MANY_FORWARD_REFERENCES")]
+    TooManyForwardReferences,
+    #[doc(alias = "G_REGEX_ERROR_NAME_TOO_LONG")]
+    NameTooLong,
+    #[doc(alias = "G_REGEX_ERROR_CHARACTER_VALUE_TOO_LARGE")]
+    CharacterValueTooLarge,
+    #[doc(hidden)]
+    __Unknown(i32),
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RegexError::{}",
+            match *self {
+                RegexError::Compile => "Compile",
+                RegexError::Optimize => "Optimize",
+                RegexError::Replace => "Replace",
+                RegexError::Match => "Match",
+                RegexError::Internal => "Internal",
+                RegexError::StrayBackslash => "StrayBackslash",
+                RegexError::MissingControlChar => "MissingControlChar",
+                RegexError::UnrecognizedEscape => "UnrecognizedEscape",
+                RegexError::QuantifiersOutOfOrder => "QuantifiersOutOfOrder",
+                RegexError::QuantifierTooBig => "QuantifierTooBig",
+                RegexError::UnterminatedCharacterClass => "UnterminatedCharacterClass",
+                RegexError::InvalidEscapeInCharacterClass => "InvalidEscapeInCharacterClass",
+                RegexError::RangeOutOfOrder => "RangeOutOfOrder",
+                RegexError::NothingToRepeat => "NothingToRepeat",
+                RegexError::UnrecognizedCharacter => "UnrecognizedCharacter",
+                RegexError::PosixNamedClassOutsideClass => "PosixNamedClassOutsideClass",
+                RegexError::UnmatchedParenthesis => "UnmatchedParenthesis",
+                RegexError::InexistentSubpatternReference => "InexistentSubpatternReference",
+                RegexError::UnterminatedComment => "UnterminatedComment",
+                RegexError::ExpressionTooLarge => "ExpressionTooLarge",
+                RegexError::MemoryError => "MemoryError",
+                RegexError::VariableLengthLookbehind => "VariableLengthLookbehind",
+                RegexError::MalformedCondition => "MalformedCondition",
+                RegexError::TooManyConditionalBranches => "TooManyConditionalBranches",
+                RegexError::AssertionExpected => "AssertionExpected",
+                RegexError::UnknownPosixClassName => "UnknownPosixClassName",
+                RegexError::PosixCollatingElementsNotSupported =>
+                    "PosixCollatingElementsNotSupported",
+                RegexError::HexCodeTooLarge => "HexCodeTooLarge",
+                RegexError::InvalidCondition => "InvalidCondition",
+                RegexError::SingleByteMatchInLookbehind => "SingleByteMatchInLookbehind",
+                RegexError::InfiniteLoop => "InfiniteLoop",
+                RegexError::MissingSubpatternNameTerminator => "MissingSubpatternNameTerminator",
+                RegexError::DuplicateSubpatternName => "DuplicateSubpatternName",
+                RegexError::MalformedProperty => "MalformedProperty",
+                RegexError::UnknownProperty => "UnknownProperty",
+                RegexError::SubpatternNameTooLong => "SubpatternNameTooLong",
+                RegexError::TooManySubpatterns => "TooManySubpatterns",
+                RegexError::InvalidOctalValue => "InvalidOctalValue",
+                RegexError::TooManyBranchesInDefine => "TooManyBranchesInDefine",
+                RegexError::DefineRepetion => "DefineRepetion",
+                RegexError::InconsistentNewlineOptions => "InconsistentNewlineOptions",
+                RegexError::MissingBackReference => "MissingBackReference",
+                RegexError::InvalidRelativeReference => "InvalidRelativeReference",
+                RegexError::BacktrackingControlVerbArgumentForbidden =>
+                    "BacktrackingControlVerbArgumentForbidden",
+                RegexError::UnknownBacktrackingControlVerb => "UnknownBacktrackingControlVerb",
+                RegexError::NumberTooBig => "NumberTooBig",
+                RegexError::MissingSubpatternName => "MissingSubpatternName",
+                RegexError::MissingDigit => "MissingDigit",
+                RegexError::InvalidDataCharacter => "InvalidDataCharacter",
+                RegexError::ExtraSubpatternName => "ExtraSubpatternName",
+                RegexError::BacktrackingControlVerbArgumentRequired =>
+                    "BacktrackingControlVerbArgumentRequired",
+                RegexError::InvalidControlChar => "InvalidControlChar",
+                RegexError::MissingName => "MissingName",
+                RegexError::NotSupportedInClass => "NotSupportedInClass",
+                RegexError::TooManyForwardReferences => "TooManyForwardReferences",
+                RegexError::NameTooLong => "NameTooLong",
+                RegexError::CharacterValueTooLarge => "CharacterValueTooLarge",
+                _ => "Unknown",
+            }
+        )
+    }
+}
+
+#[doc(hidden)]
+impl ToGlib for RegexError {
+    type GlibType = ffi::GRegexError;
+
+    fn to_glib(&self) -> ffi::GRegexError {
+        match *self {
+            RegexError::Compile => ffi::G_REGEX_ERROR_COMPILE,
+            RegexError::Optimize => ffi::G_REGEX_ERROR_OPTIMIZE,
+            RegexError::Replace => ffi::G_REGEX_ERROR_REPLACE,
+            RegexError::Match => ffi::G_REGEX_ERROR_MATCH,
+            RegexError::Internal => ffi::G_REGEX_ERROR_INTERNAL,
+            RegexError::StrayBackslash => ffi::G_REGEX_ERROR_STRAY_BACKSLASH,
+            RegexError::MissingControlChar => ffi::G_REGEX_ERROR_MISSING_CONTROL_CHAR,
+            RegexError::UnrecognizedEscape => ffi::G_REGEX_ERROR_UNRECOGNIZED_ESCAPE,
+            RegexError::QuantifiersOutOfOrder => ffi::G_REGEX_ERROR_QUANTIFIERS_OUT_OF_ORDER,
+            RegexError::QuantifierTooBig => ffi::G_REGEX_ERROR_QUANTIFIER_TOO_BIG,
+            RegexError::UnterminatedCharacterClass => {
+                ffi::G_REGEX_ERROR_UNTERMINATED_CHARACTER_CLASS
+            }
+            RegexError::InvalidEscapeInCharacterClass => {
+                ffi::G_REGEX_ERROR_INVALID_ESCAPE_IN_CHARACTER_CLASS
+            }
+            RegexError::RangeOutOfOrder => ffi::G_REGEX_ERROR_RANGE_OUT_OF_ORDER,
+            RegexError::NothingToRepeat => ffi::G_REGEX_ERROR_NOTHING_TO_REPEAT,
+            RegexError::UnrecognizedCharacter => ffi::G_REGEX_ERROR_UNRECOGNIZED_CHARACTER,
+            RegexError::PosixNamedClassOutsideClass => {
+                ffi::G_REGEX_ERROR_POSIX_NAMED_CLASS_OUTSIDE_CLASS
+            }
+            RegexError::UnmatchedParenthesis => ffi::G_REGEX_ERROR_UNMATCHED_PARENTHESIS,
+            RegexError::InexistentSubpatternReference => {
+                ffi::G_REGEX_ERROR_INEXISTENT_SUBPATTERN_REFERENCE
+            }
+            RegexError::UnterminatedComment => ffi::G_REGEX_ERROR_UNTERMINATED_COMMENT,
+            RegexError::ExpressionTooLarge => ffi::G_REGEX_ERROR_EXPRESSION_TOO_LARGE,
+            RegexError::MemoryError => ffi::G_REGEX_ERROR_MEMORY_ERROR,
+            RegexError::VariableLengthLookbehind => ffi::G_REGEX_ERROR_VARIABLE_LENGTH_LOOKBEHIND,
+            RegexError::MalformedCondition => ffi::G_REGEX_ERROR_MALFORMED_CONDITION,
+            RegexError::TooManyConditionalBranches => {
+                ffi::G_REGEX_ERROR_TOO_MANY_CONDITIONAL_BRANCHES
+            }
+            RegexError::AssertionExpected => ffi::G_REGEX_ERROR_ASSERTION_EXPECTED,
+            RegexError::UnknownPosixClassName => ffi::G_REGEX_ERROR_UNKNOWN_POSIX_CLASS_NAME,
+            RegexError::PosixCollatingElementsNotSupported => {
+                ffi::G_REGEX_ERROR_POSIX_COLLATING_ELEMENTS_NOT_SUPPORTED
+            }
+            RegexError::HexCodeTooLarge => ffi::G_REGEX_ERROR_HEX_CODE_TOO_LARGE,
+            RegexError::InvalidCondition => ffi::G_REGEX_ERROR_INVALID_CONDITION,
+            RegexError::SingleByteMatchInLookbehind => {
+                ffi::G_REGEX_ERROR_SINGLE_BYTE_MATCH_IN_LOOKBEHIND
+            }
+            RegexError::InfiniteLoop => ffi::G_REGEX_ERROR_INFINITE_LOOP,
+            RegexError::MissingSubpatternNameTerminator => {
+                ffi::G_REGEX_ERROR_MISSING_SUBPATTERN_NAME_TERMINATOR
+            }
+            RegexError::DuplicateSubpatternName => ffi::G_REGEX_ERROR_DUPLICATE_SUBPATTERN_NAME,
+            RegexError::MalformedProperty => ffi::G_REGEX_ERROR_MALFORMED_PROPERTY,
+            RegexError::UnknownProperty => ffi::G_REGEX_ERROR_UNKNOWN_PROPERTY,
+            RegexError::SubpatternNameTooLong => ffi::G_REGEX_ERROR_SUBPATTERN_NAME_TOO_LONG,
+            RegexError::TooManySubpatterns => ffi::G_REGEX_ERROR_TOO_MANY_SUBPATTERNS,
+            RegexError::InvalidOctalValue => ffi::G_REGEX_ERROR_INVALID_OCTAL_VALUE,
+            RegexError::TooManyBranchesInDefine => ffi::G_REGEX_ERROR_TOO_MANY_BRANCHES_IN_DEFINE,
+            RegexError::DefineRepetion => ffi::G_REGEX_ERROR_DEFINE_REPETION,
+            RegexError::InconsistentNewlineOptions => {
+                ffi::G_REGEX_ERROR_INCONSISTENT_NEWLINE_OPTIONS
+            }
+            RegexError::MissingBackReference => ffi::G_REGEX_ERROR_MISSING_BACK_REFERENCE,
+            RegexError::InvalidRelativeReference => ffi::G_REGEX_ERROR_INVALID_RELATIVE_REFERENCE,
+            RegexError::BacktrackingControlVerbArgumentForbidden => {
+                ffi::G_REGEX_ERROR_BACKTRACKING_CONTROL_VERB_ARGUMENT_FORBIDDEN
+            }
+            RegexError::UnknownBacktrackingControlVerb => {
+                ffi::G_REGEX_ERROR_UNKNOWN_BACKTRACKING_CONTROL_VERB
+            }
+            RegexError::NumberTooBig => ffi::G_REGEX_ERROR_NUMBER_TOO_BIG,
+            RegexError::MissingSubpatternName => ffi::G_REGEX_ERROR_MISSING_SUBPATTERN_NAME,
+            RegexError::MissingDigit => ffi::G_REGEX_ERROR_MISSING_DIGIT,
+            RegexError::InvalidDataCharacter => ffi::G_REGEX_ERROR_INVALID_DATA_CHARACTER,
+            RegexError::ExtraSubpatternName => ffi::G_REGEX_ERROR_EXTRA_SUBPATTERN_NAME,
+            RegexError::BacktrackingControlVerbArgumentRequired => {
+                ffi::G_REGEX_ERROR_BACKTRACKING_CONTROL_VERB_ARGUMENT_REQUIRED
+            }
+            RegexError::InvalidControlChar => ffi::G_REGEX_ERROR_INVALID_CONTROL_CHAR,
+            RegexError::MissingName => ffi::G_REGEX_ERROR_MISSING_NAME,
+            RegexError::NotSupportedInClass => ffi::G_REGEX_ERROR_NOT_SUPPORTED_IN_CLASS,
+            RegexError::TooManyForwardReferences => ffi::G_REGEX_ERROR_TOO_MANY_FORWARD_REFERENCES,
+            RegexError::NameTooLong => ffi::G_REGEX_ERROR_NAME_TOO_LONG,
+            RegexError::CharacterValueTooLarge => ffi::G_REGEX_ERROR_CHARACTER_VALUE_TOO_LARGE,
+            RegexError::__Unknown(value) => value,
+        }
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::GRegexError> for RegexError {
+    unsafe fn from_glib(value: ffi::GRegexError) -> Self {
+        match value {
+            0 => RegexError::Compile,
+            1 => RegexError::Optimize,
+            2 => RegexError::Replace,
+            3 => RegexError::Match,
+            4 => RegexError::Internal,
+            101 => RegexError::StrayBackslash,
+            102 => RegexError::MissingControlChar,
+            103 => RegexError::UnrecognizedEscape,
+            104 => RegexError::QuantifiersOutOfOrder,
+            105 => RegexError::QuantifierTooBig,
+            106 => RegexError::UnterminatedCharacterClass,
+            107 => RegexError::InvalidEscapeInCharacterClass,
+            108 => RegexError::RangeOutOfOrder,
+            109 => RegexError::NothingToRepeat,
+            112 => RegexError::UnrecognizedCharacter,
+            113 => RegexError::PosixNamedClassOutsideClass,
+            114 => RegexError::UnmatchedParenthesis,
+            115 => RegexError::InexistentSubpatternReference,
+            118 => RegexError::UnterminatedComment,
+            120 => RegexError::ExpressionTooLarge,
+            121 => RegexError::MemoryError,
+            125 => RegexError::VariableLengthLookbehind,
+            126 => RegexError::MalformedCondition,
+            127 => RegexError::TooManyConditionalBranches,
+            128 => RegexError::AssertionExpected,
+            130 => RegexError::UnknownPosixClassName,
+            131 => RegexError::PosixCollatingElementsNotSupported,
+            134 => RegexError::HexCodeTooLarge,
+            135 => RegexError::InvalidCondition,
+            136 => RegexError::SingleByteMatchInLookbehind,
+            140 => RegexError::InfiniteLoop,
+            142 => RegexError::MissingSubpatternNameTerminator,
+            143 => RegexError::DuplicateSubpatternName,
+            146 => RegexError::MalformedProperty,
+            147 => RegexError::UnknownProperty,
+            148 => RegexError::SubpatternNameTooLong,
+            149 => RegexError::TooManySubpatterns,
+            151 => RegexError::InvalidOctalValue,
+            154 => RegexError::TooManyBranchesInDefine,
+            155 => RegexError::DefineRepetion,
+            156 => RegexError::InconsistentNewlineOptions,
+            157 => RegexError::MissingBackReference,
+            158 => RegexError::InvalidRelativeReference,
+            159 => RegexError::BacktrackingControlVerbArgumentForbidden,
+            160 => RegexError::UnknownBacktrackingControlVerb,
+            161 => RegexError::NumberTooBig,
+            162 => RegexError::MissingSubpatternName,
+            163 => RegexError::MissingDigit,
+            164 => RegexError::InvalidDataCharacter,
+            165 => RegexError::ExtraSubpatternName,
+            166 => RegexError::BacktrackingControlVerbArgumentRequired,
+            168 => RegexError::InvalidControlChar,
+            169 => RegexError::MissingName,
+            171 => RegexError::NotSupportedInClass,
+            172 => RegexError::TooManyForwardReferences,
+            175 => RegexError::NameTooLong,
+            176 => RegexError::CharacterValueTooLarge,
+            value => RegexError::__Unknown(value),
+        }
+    }
+}
+
+impl ErrorDomain for RegexError {
+    fn domain() -> Quark {
+        unsafe { from_glib(ffi::g_regex_error_quark()) }
+    }
+
+    fn code(self) -> i32 {
+        self.to_glib()
+    }
+
+    fn from(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(RegexError::Compile),
+            1 => Some(RegexError::Optimize),
+            2 => Some(RegexError::Replace),
+            3 => Some(RegexError::Match),
+            4 => Some(RegexError::Internal),
+            101 => Some(RegexError::StrayBackslash),
+            102 => Some(RegexError::MissingControlChar),
+            103 => Some(RegexError::UnrecognizedEscape),
+            104 => Some(RegexError::QuantifiersOutOfOrder),
+            105 => Some(RegexError::QuantifierTooBig),
+            106 => Some(RegexError::UnterminatedCharacterClass),
+            107 => Some(RegexError::InvalidEscapeInCharacterClass),
+            108 => Some(RegexError::RangeOutOfOrder),
+            109 => Some(RegexError::NothingToRepeat),
+            112 => Some(RegexError::UnrecognizedCharacter),
+            113 => Some(RegexError::PosixNamedClassOutsideClass),
+            114 => Some(RegexError::UnmatchedParenthesis),
+            115 => Some(RegexError::InexistentSubpatternReference),
+            118 => Some(RegexError::UnterminatedComment),
+            120 => Some(RegexError::ExpressionTooLarge),
+            121 => Some(RegexError::MemoryError),
+            125 => Some(RegexError::VariableLengthLookbehind),
+            126 => Some(RegexError::MalformedCondition),
+            127 => Some(RegexError::TooManyConditionalBranches),
+            128 => Some(RegexError::AssertionExpected),
+            130 => Some(RegexError::UnknownPosixClassName),
+            131 => Some(RegexError::PosixCollatingElementsNotSupported),
+            134 => Some(RegexError::HexCodeTooLarge),
+            135 => Some(RegexError::InvalidCondition),
+            136 => Some(RegexError::SingleByteMatchInLookbehind),
+            140 => Some(RegexError::InfiniteLoop),
+            142 => Some(RegexError::MissingSubpatternNameTerminator),
+            143 => Some(RegexError::DuplicateSubpatternName),
+            146 => Some(RegexError::MalformedProperty),
+            147 => Some(RegexError::UnknownProperty),
+            148 => Some(RegexError::SubpatternNameTooLong),
+            149 => Some(RegexError::TooManySubpatterns),
+            151 => Some(RegexError::InvalidOctalValue),
+            154 => Some(RegexError::TooManyBranchesInDefine),
+            155 => Some(RegexError::DefineRepetion),
+            156 => Some(RegexError::InconsistentNewlineOptions),
+            157 => Some(RegexError::MissingBackReference),
+            158 => Some(RegexError::InvalidRelativeReference),
+            159 => Some(RegexError::BacktrackingControlVerbArgumentForbidden),
+            160 => Some(RegexError::UnknownBacktrackingControlVerb),
+            161 => Some(RegexError::NumberTooBig),
+            162 => Some(RegexError::MissingSubpatternName),
+            163 => Some(RegexError::MissingDigit),
+            164 => Some(RegexError::InvalidDataCharacter),
+            165 => Some(RegexError::ExtraSubpatternName),
+            166 => Some(RegexError::BacktrackingControlVerbArgumentRequired),
+            168 => Some(RegexError::InvalidControlChar),
+            169 => Some(RegexError::MissingName),
+            171 => Some(RegexError::NotSupportedInClass),
+            172 => Some(RegexError::TooManyForwardReferences),
+            175 => Some(RegexError::NameTooLong),
+            176 => Some(RegexError::CharacterValueTooLarge),
+            value => Some(RegexError::__Unknown(value)),
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
 #[non_exhaustive]
 #[doc(alias = "GSeekType")]
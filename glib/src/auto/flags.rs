@@ -238,6 +238,37 @@ impl FromGlib<ffi::GLogLevelFlags> for LogLevelFlags {
     }
 }
 
+bitflags! {
+    pub struct MarkupParseFlags: u32 {
+        const DO_NOT_USE_THIS_UNSUPPORTED_FLAG = 1;
+        const TREAT_CDATA_AS_TEXT = 2;
+        const PREFIX_ERROR_POSITION = 4;
+        const IGNORE_QUALIFIED = 8;
+    }
+}
+
+impl fmt::Display for MarkupParseFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[doc(hidden)]
+impl ToGlib for MarkupParseFlags {
+    type GlibType = ffi::GMarkupParseFlags;
+
+    fn to_glib(&self) -> ffi::GMarkupParseFlags {
+        self.bits()
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::GMarkupParseFlags> for MarkupParseFlags {
+    unsafe fn from_glib(value: ffi::GMarkupParseFlags) -> MarkupParseFlags {
+        MarkupParseFlags::from_bits_truncate(value)
+    }
+}
+
 bitflags! {
     pub struct OptionFlags: u32 {
         const NONE = 0;
@@ -273,6 +304,93 @@ impl FromGlib<ffi::GOptionFlags> for OptionFlags {
     }
 }
 
+bitflags! {
+    pub struct RegexCompileFlags: u32 {
+        const CASELESS = 1;
+        const MULTILINE = 2;
+        const DOTALL = 4;
+        const EXTENDED = 8;
+        const ANCHORED = 16;
+        const DOLLAR_ENDONLY = 32;
+        const UNGREEDY = 512;
+        const RAW = 2048;
+        const NO_AUTO_CAPTURE = 4096;
+        const OPTIMIZE = 8192;
+        const FIRSTLINE = 262144;
+        const DUPNAMES = 524288;
+        const NEWLINE_CR = 1048576;
+        const NEWLINE_LF = 2097152;
+        const NEWLINE_CRLF = 3145728;
+        const NEWLINE_ANYCRLF = 5242880;
+        const BSR_ANYCRLF = 8388608;
+        const JAVASCRIPT_COMPAT = 33554432;
+    }
+}
+
+impl fmt::Display for RegexCompileFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[doc(hidden)]
+impl ToGlib for RegexCompileFlags {
+    type GlibType = ffi::GRegexCompileFlags;
+
+    fn to_glib(&self) -> ffi::GRegexCompileFlags {
+        self.bits()
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::GRegexCompileFlags> for RegexCompileFlags {
+    unsafe fn from_glib(value: ffi::GRegexCompileFlags) -> RegexCompileFlags {
+        RegexCompileFlags::from_bits_truncate(value)
+    }
+}
+
+bitflags! {
+    pub struct RegexMatchFlags: u32 {
+        const ANCHORED = 16;
+        const NOTBOL = 128;
+        const NOTEOL = 256;
+        const NOTEMPTY = 1024;
+        const PARTIAL = 32768;
+        const NEWLINE_CR = 1048576;
+        const NEWLINE_LF = 2097152;
+        const NEWLINE_CRLF = 3145728;
+        const NEWLINE_ANY = 4194304;
+        const NEWLINE_ANYCRLF = 5242880;
+        const BSR_ANYCRLF = 8388608;
+        const BSR_ANY = 16777216;
+        const PARTIAL_SOFT = 32768;
+        const PARTIAL_HARD = 134217728;
+        const NOTEMPTY_ATSTART = 268435456;
+    }
+}
+
+impl fmt::Display for RegexMatchFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[doc(hidden)]
+impl ToGlib for RegexMatchFlags {
+    type GlibType = ffi::GRegexMatchFlags;
+
+    fn to_glib(&self) -> ffi::GRegexMatchFlags {
+        self.bits()
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::GRegexMatchFlags> for RegexMatchFlags {
+    unsafe fn from_glib(value: ffi::GRegexMatchFlags) -> RegexMatchFlags {
+        RegexMatchFlags::from_bits_truncate(value)
+    }
+}
+
 bitflags! {
     pub struct SpawnFlags: u32 {
         const DEFAULT = 0;
@@ -384,11 +384,6 @@ impl KeyFile {
         }
     }
 
-    //#[doc(alias = "g_key_file_set_boolean_list")]
-    //pub fn set_boolean_list(&self, group_name: &str, key: &str, list: /*Unimplemented*/&CArray TypeId { ns_id: 0, id: 1 }) {
-    //    unsafe { TODO: call ffi:g_key_file_set_boolean_list() }
-    //}
-
     #[doc(alias = "g_key_file_set_comment")]
     pub fn set_comment(
         &self,
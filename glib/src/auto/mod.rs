@@ -35,7 +35,9 @@ pub use self::enums::ChecksumType;
 pub use self::enums::DateMonth;
 pub use self::enums::DateWeekday;
 pub use self::enums::KeyFileError;
+pub use self::enums::MarkupError;
 pub use self::enums::OptionArg;
+pub use self::enums::RegexError;
 pub use self::enums::SeekType;
 pub use self::enums::TimeType;
 #[cfg(any(feature = "v2_66", feature = "dox"))]
@@ -51,7 +53,10 @@ pub use self::flags::FormatSizeFlags;
 pub use self::flags::IOCondition;
 pub use self::flags::KeyFileFlags;
 pub use self::flags::LogLevelFlags;
+pub use self::flags::MarkupParseFlags;
 pub use self::flags::OptionFlags;
+pub use self::flags::RegexCompileFlags;
+pub use self::flags::RegexMatchFlags;
 pub use self::flags::SpawnFlags;
 #[cfg(any(feature = "v2_66", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
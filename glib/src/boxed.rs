@@ -272,6 +272,73 @@ macro_rules! glib_boxed_wrapper {
         }
     };
 
+    (@trait_impls $name:ident, $ffi_name:ty, ) => {};
+
+    (@trait_impls $name:ident, $ffi_name:ty, compare => |$a_arg:ident, $b_arg:ident| $compare_expr:expr $(, $($rest:tt)*)?) => {
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                Ord::cmp(self, other) == std::cmp::Ordering::Equal
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(Ord::cmp(self, other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                let $a_arg: *const $ffi_name = $crate::translate::ToGlibPtr::<*const $ffi_name>::to_glib_none(self).0;
+                let $b_arg: *const $ffi_name = $crate::translate::ToGlibPtr::<*const $ffi_name>::to_glib_none(other).0;
+                #[allow(unused_unsafe)]
+                let res = unsafe { $compare_expr };
+                res.cmp(&0)
+            }
+        }
+
+        $crate::glib_boxed_wrapper!(@trait_impls $name, $ffi_name, $($($rest)*)?);
+    };
+
+    (@trait_impls $name:ident, $ffi_name:ty, hash => |$hash_arg:ident| $hash_expr:expr $(, $($rest:tt)*)?) => {
+        impl std::hash::Hash for $name {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                let $hash_arg: *const $ffi_name = $crate::translate::ToGlibPtr::<*const $ffi_name>::to_glib_none(self).0;
+                #[allow(unused_unsafe)]
+                let hash = unsafe { $hash_expr };
+                std::hash::Hash::hash(&hash, state)
+            }
+        }
+
+        $crate::glib_boxed_wrapper!(@trait_impls $name, $ffi_name, $($($rest)*)?);
+    };
+
+    (@trait_impls $name:ident, $ffi_name:ty, serde $(, $($rest:tt)*)?) => {
+        $crate::__glib_if_serde! {
+            impl $crate::__serde::Serialize for $name {
+                fn serialize<S: $crate::__serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> $crate::__serde::Deserialize<'de> for $name {
+                fn deserialize<D: $crate::__serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    <std::string::String as $crate::__serde::Deserialize>::deserialize(deserializer)?
+                        .parse()
+                        .map_err($crate::__serde::de::Error::custom)
+                }
+            }
+        }
+
+        $crate::glib_boxed_wrapper!(@trait_impls $name, $ffi_name, $($($rest)*)?);
+    };
+
     (@memory_manager_impl $name:ident, $ffi_name:ty, @copy $copy_arg:ident $copy_expr:expr, @free $free_arg:ident $free_expr:expr) => {
         #[doc(hidden)]
         impl $crate::boxed::BoxedMemoryManager<$ffi_name> for $name {
@@ -547,3 +614,64 @@ impl<T: 'static, MM: BoxedMemoryManager<T>> DerefMut for Boxed<T, MM> {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::translate::*;
+    use crate::variant_serde::{from_variant, to_variant};
+    use crate::ToVariant;
+    use std::fmt;
+    use std::str::FromStr;
+
+    crate::wrapper! {
+        #[derive(Debug)]
+        pub struct JulianDay(Boxed<ffi::GDate>);
+
+        match fn {
+            copy => |ptr| gobject_ffi::g_boxed_copy(ffi::g_date_get_type(), ptr as *const _) as *mut _,
+            free => |ptr| ffi::g_date_free(ptr),
+        }
+
+        impl traits {
+            compare => |a, b| ffi::g_date_compare(a, b),
+            serde,
+        }
+    }
+
+    impl JulianDay {
+        fn new(day: u32) -> Self {
+            unsafe { from_glib_full(ffi::g_date_new_julian(day)) }
+        }
+    }
+
+    impl fmt::Display for JulianDay {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", unsafe {
+                ffi::g_date_get_julian(self.to_glib_none().0)
+            })
+        }
+    }
+
+    impl FromStr for JulianDay {
+        type Err = std::num::ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(JulianDay::new)
+        }
+    }
+
+    #[test]
+    fn serde() {
+        let day = JulianDay::new(730_000);
+        let variant = to_variant(&day).unwrap();
+        assert_eq!(variant.get_str(), Some("730000"));
+        assert_eq!(from_variant::<JulianDay>(&variant).unwrap(), day);
+        assert!(from_variant::<JulianDay>(&"monday".to_variant()).is_err());
+    }
+
+    #[test]
+    fn compare() {
+        assert!(JulianDay::new(1) < JulianDay::new(2));
+        assert_eq!(JulianDay::new(2), JulianDay::new(2));
+    }
+}
@@ -6,7 +6,6 @@ use crate::DateDay;
 use crate::DateMonth;
 use crate::DateWeekday;
 use crate::DateYear;
-use std::cmp;
 use std::fmt;
 use std::hash;
 
@@ -20,6 +19,10 @@ wrapper! {
         clear => |ptr| ffi::g_date_clear(ptr, 1),
         get_type => || ffi::g_date_get_type(),
     }
+
+    impl traits {
+        compare => |a, b| ffi::g_date_compare(a, b),
+    }
 }
 
 unsafe impl Send for Date {}
@@ -100,11 +103,6 @@ impl Date {
         }
     }
 
-    #[doc(alias = "g_date_compare")]
-    fn compare(&self, rhs: &Date) -> i32 {
-        unsafe { ffi::g_date_compare(self.to_glib_none().0, rhs.to_glib_none().0) }
-    }
-
     #[doc(alias = "g_date_days_between")]
     pub fn days_between(&self, date2: &Date) -> i32 {
         unsafe { ffi::g_date_days_between(self.to_glib_none().0, date2.to_glib_none().0) }
@@ -378,29 +376,6 @@ impl Date {
     }
 }
 
-impl PartialEq for Date {
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        self.compare(other) == 0
-    }
-}
-
-impl Eq for Date {}
-
-impl PartialOrd for Date {
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.compare(other).partial_cmp(&0)
-    }
-}
-
-impl Ord for Date {
-    #[inline]
-    fn cmp(&self, other: &Self) -> cmp::Ordering {
-        self.compare(other).cmp(&0)
-    }
-}
-
 impl fmt::Debug for Date {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Date")
@@ -421,3 +396,19 @@ impl hash::Hash for Date {
         self.get_day().hash(state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare() {
+        let date = Date::new_dmy(14, DateMonth::October, 2020).unwrap();
+        let later = Date::new_dmy(1, DateMonth::November, 2020).unwrap();
+
+        assert_eq!(date, date.clone());
+        assert_ne!(date, later);
+        assert!(date < later);
+        assert_eq!(later.clone().max(date), later);
+    }
+}
@@ -0,0 +1,553 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::translate::*;
+use crate::{BoolError, DateTime, GString, TimeType, TimeZone};
+#[cfg(feature = "chrono")]
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+
+impl DateTime {
+    /// Formats the date and time in the preferred representation of the current locale.
+    ///
+    /// Like all formatting functions this follows the `LC_TIME` locale of the process.
+    pub fn format_locale(&self) -> Result<GString, BoolError> {
+        self.format("%c")
+    }
+
+    /// Formats the date in the preferred representation of the current locale.
+    pub fn format_locale_date(&self) -> Result<GString, BoolError> {
+        self.format("%x")
+    }
+
+    /// Formats the time in the preferred representation of the current locale.
+    pub fn format_locale_time(&self) -> Result<GString, BoolError> {
+        self.format("%X")
+    }
+
+    /// Returns the name of the month in the current locale.
+    pub fn get_month_name(&self) -> GString {
+        self.format("%B").expect("Failed to format month name")
+    }
+
+    /// Returns the abbreviated name of the month in the current locale.
+    pub fn get_month_abbreviation(&self) -> GString {
+        self.format("%b")
+            .expect("Failed to format month abbreviation")
+    }
+
+    /// Returns the name of the day of the week in the current locale.
+    pub fn get_day_name(&self) -> GString {
+        self.format("%A").expect("Failed to format day name")
+    }
+
+    /// Returns the abbreviated name of the day of the week in the current locale.
+    pub fn get_day_abbreviation(&self) -> GString {
+        self.format("%a")
+            .expect("Failed to format day abbreviation")
+    }
+
+    /// Parses a timestamp as specified by RFC 3339, e.g. `2003-07-01T10:52:37.5+02:00`.
+    ///
+    /// Unlike [`from_iso8601`](Self::from_iso8601) only the strict RFC 3339 syntax is accepted
+    /// and the error describes what is wrong with `text`.
+    pub fn from_rfc3339(text: &str) -> Result<DateTime, DateTimeParseError> {
+        let mut parser = Parser::new(text);
+
+        let year = parser.number(4, 4, "year")?;
+        parser.expect(|c| c == '-', "'-' after the year")?;
+        let month = parser.number(2, 2, "month")?;
+        parser.expect(|c| c == '-', "'-' after the month")?;
+        let day = parser.number(2, 2, "day")?;
+        parser.expect(|c| c == 'T' || c == 't' || c == ' ', "'T' after the date")?;
+        let hour = parser.number(2, 2, "hour")?;
+        parser.expect(|c| c == ':', "':' after the hour")?;
+        let minute = parser.number(2, 2, "minute")?;
+        parser.expect(|c| c == ':', "':' after the minute")?;
+        let mut seconds = f64::from(parser.number(2, 2, "second")?);
+        if parser.eat(|c| c == '.') {
+            let start = parser.pos;
+            parser.number(1, usize::MAX, "fraction of second")?;
+            seconds += format!("0.{}", &text[start..parser.pos])
+                .parse::<f64>()
+                .unwrap_or(0.0);
+        }
+
+        let tz = if parser.eat(|c| c == 'Z' || c == 'z') {
+            TimeZone::new_utc()
+        } else {
+            parser.utc_offset(true)?
+        };
+        parser.end()?;
+
+        DateTime::new(&tz, year, month, day, hour, minute, seconds)
+            .map_err(|_| DateTimeParseError::new("Invalid date or time", 0))
+    }
+
+    /// Parses a timestamp as specified by RFC 2822, e.g. `Tue, 1 Jul 2003 10:52:37 +0200`.
+    ///
+    /// The obsolete time zone names like `GMT` or `PST` are accepted as well.
+    pub fn from_rfc2822(text: &str) -> Result<DateTime, DateTimeParseError> {
+        let mut parser = Parser::new(text);
+
+        parser.whitespace();
+        if parser.peek().map_or(false, |c| c.is_ascii_alphabetic()) {
+            let day_pos = parser.pos;
+            let name = parser.word();
+            if !DAY_NAMES.iter().any(|d| d.eq_ignore_ascii_case(name)) {
+                return Err(DateTimeParseError::new("Invalid day of the week", day_pos));
+            }
+            parser.whitespace();
+            parser.expect(|c| c == ',', "',' after the day of the week")?;
+            parser.whitespace();
+        }
+
+        let day = parser.number(1, 2, "day")?;
+        parser.required_whitespace()?;
+        let month_pos = parser.pos;
+        let month = parser.word();
+        let month = MONTH_NAMES
+            .iter()
+            .position(|m| m.eq_ignore_ascii_case(month))
+            .ok_or_else(|| DateTimeParseError::new("Invalid month", month_pos))?
+            as i32
+            + 1;
+        parser.required_whitespace()?;
+        let year_pos = parser.pos;
+        let mut year = parser.number(2, 4, "year")?;
+        // Obsolete two and three digit years as specified in section 4.3
+        match parser.pos - year_pos {
+            2 if year < 50 => year += 2000,
+            2 | 3 => year += 1900,
+            _ => (),
+        }
+        parser.required_whitespace()?;
+
+        let hour = parser.number(2, 2, "hour")?;
+        parser.expect(|c| c == ':', "':' after the hour")?;
+        let minute = parser.number(2, 2, "minute")?;
+        let second = if parser.eat(|c| c == ':') {
+            parser.number(2, 2, "second")?
+        } else {
+            0
+        };
+        parser.required_whitespace()?;
+
+        let zone_pos = parser.pos;
+        let tz = if parser.peek().map_or(false, |c| c == '+' || c == '-') {
+            parser.utc_offset(false)?
+        } else {
+            let name = parser.word();
+            let hours = match name.to_ascii_uppercase().as_str() {
+                "UT" | "GMT" | "Z" => 0,
+                "EDT" => -4,
+                "EST" | "CDT" => -5,
+                "CST" | "MDT" => -6,
+                "MST" | "PDT" => -7,
+                "PST" => -8,
+                // Military zones are treated as "-0000", i.e. as unknown local offset.
+                _ if name.len() == 1 && name.chars().all(|c| c.is_ascii_alphabetic()) => 0,
+                _ => return Err(DateTimeParseError::new("Invalid time zone", zone_pos)),
+            };
+            offset_time_zone(hours.signum(), hours.abs(), 0)
+        };
+        parser.whitespace();
+        parser.end()?;
+
+        DateTime::new(&tz, year, month, day, hour, minute, f64::from(second))
+            .map_err(|_| DateTimeParseError::new("Invalid date or time", 0))
+    }
+}
+
+impl TimeZone {
+    /// Finds the interval of the time zone containing `time`, a number of seconds since
+    /// January 1, 1970 interpreted as `type_`.
+    ///
+    /// Unlike [`find_interval`](Self::find_interval) this also succeeds for local times that
+    /// don't exist, like the hour skipped when daylight saving time starts. `time` is then moved
+    /// forward into the following interval, and the adjusted time is returned together with the
+    /// interval.
+    #[doc(alias = "g_time_zone_adjust_time")]
+    pub fn adjust_time(&self, type_: TimeType, time: i64) -> (i32, i64) {
+        let mut time = time;
+        let interval = unsafe {
+            ffi::g_time_zone_adjust_time(self.to_glib_none().0, type_.to_glib(), &mut time)
+        };
+        (interval, time)
+    }
+}
+
+const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// `g_time_zone_new_identifier()` is only available since 2.68 and `g_time_zone_new_offset()`
+// since 2.58, while the deprecated constructor works everywhere.
+#[allow(deprecated)]
+fn offset_time_zone(sign: i32, hours: i32, minutes: i32) -> TimeZone {
+    if hours == 0 && minutes == 0 {
+        TimeZone::new_utc()
+    } else {
+        let sign = if sign < 0 { '-' } else { '+' };
+        TimeZone::new(Some(&format!("{}{:02}:{:02}", sign, hours, minutes)))
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Parser { text, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn eat<F: Fn(char) -> bool>(&mut self, f: F) -> bool {
+        match self.peek() {
+            Some(c) if f(c) => {
+                self.pos += c.len_utf8();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect<F: Fn(char) -> bool>(
+        &mut self,
+        f: F,
+        expected: &'static str,
+    ) -> Result<(), DateTimeParseError> {
+        if self.eat(f) {
+            Ok(())
+        } else {
+            Err(DateTimeParseError::expected(expected, self.pos))
+        }
+    }
+
+    fn number(
+        &mut self,
+        min_digits: usize,
+        max_digits: usize,
+        expected: &'static str,
+    ) -> Result<i32, DateTimeParseError> {
+        let start = self.pos;
+        while self.pos - start < max_digits && self.eat(|c| c.is_ascii_digit()) {}
+
+        let digits = &self.text[start..self.pos];
+        if digits.len() < min_digits {
+            return Err(DateTimeParseError::expected(expected, start));
+        }
+        // Only the first digits are significant for arbitrarily long fractions.
+        Ok(digits[..digits.len().min(9)].parse().unwrap())
+    }
+
+    fn sign(&mut self, expected: &'static str) -> Result<i32, DateTimeParseError> {
+        if self.eat(|c| c == '+') {
+            Ok(1)
+        } else if self.eat(|c| c == '-') {
+            Ok(-1)
+        } else {
+            Err(DateTimeParseError::expected(expected, self.pos))
+        }
+    }
+
+    // Parses a `+hh:mm` or, without `colon`, `+hhmm` offset from UTC. GLib silently falls back
+    // to UTC for time zone identifiers it does not understand, so the offset is validated here.
+    fn utc_offset(&mut self, colon: bool) -> Result<TimeZone, DateTimeParseError> {
+        let sign = self.sign("time zone offset")?;
+        let hours_pos = self.pos;
+        let hours = self.number(2, 2, "time zone hours")?;
+        if hours >= 24 {
+            return Err(DateTimeParseError::new(
+                "Invalid time zone hours",
+                hours_pos,
+            ));
+        }
+        if colon {
+            self.expect(|c| c == ':', "':' in the time zone offset")?;
+        }
+        let minutes_pos = self.pos;
+        let minutes = self.number(2, 2, "time zone minutes")?;
+        if minutes >= 60 {
+            return Err(DateTimeParseError::new(
+                "Invalid time zone minutes",
+                minutes_pos,
+            ));
+        }
+        Ok(offset_time_zone(sign, hours, minutes))
+    }
+
+    fn word(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.eat(|c| c.is_ascii_alphabetic()) {}
+        &self.text[start..self.pos]
+    }
+
+    fn whitespace(&mut self) {
+        while self.eat(|c| c == ' ' || c == '\t') {}
+    }
+
+    fn required_whitespace(&mut self) -> Result<(), DateTimeParseError> {
+        let start = self.pos;
+        self.whitespace();
+        if self.pos == start {
+            Err(DateTimeParseError::expected("whitespace", start))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn end(&self) -> Result<(), DateTimeParseError> {
+        if self.pos == self.text.len() {
+            Ok(())
+        } else {
+            Err(DateTimeParseError::new(
+                "Unexpected trailing characters",
+                self.pos,
+            ))
+        }
+    }
+}
+
+/// An error returned when parsing a [`DateTime`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateTimeParseError {
+    message: String,
+    position: usize,
+}
+
+impl DateTimeParseError {
+    fn new(message: &str, position: usize) -> Self {
+        DateTimeParseError {
+            message: String::from(message),
+            position,
+        }
+    }
+
+    fn expected(expected: &str, position: usize) -> Self {
+        DateTimeParseError {
+            message: format!("Expected {}", expected),
+            position,
+        }
+    }
+
+    /// The description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The byte offset in the parsed text at which the problem was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for DateTimeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl error::Error for DateTimeParseError {}
+
+/// Converts chrono date and times.
+///
+/// The conversions from and to chrono are `TryFrom` instead of `From` implementations, as
+/// `GDateTime` only covers the years 1 to 9999 with microsecond precision and UTC offsets of
+/// whole minutes, so they are fallible in both directions.
+#[cfg(feature = "chrono")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "chrono")))]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for DateTime {
+    type Error = BoolError;
+
+    /// Converts `date_time` to a `DateTime` with a time zone of the same, fixed UTC offset.
+    ///
+    /// Nanoseconds are truncated to microseconds. Fails for years outside of 1 to 9999 and for
+    /// UTC offsets that are not a whole number of minutes.
+    fn try_from(date_time: chrono::DateTime<Tz>) -> Result<Self, BoolError> {
+        use chrono::{Datelike, Offset, Timelike};
+
+        let offset = date_time.offset().fix().local_minus_utc();
+        if offset % 60 != 0 {
+            return Err(crate::bool_error!(
+                "UTC offset of {} seconds is not a whole number of minutes",
+                offset
+            ));
+        }
+        let tz = offset_time_zone(
+            offset.signum(),
+            offset.abs() / 3600,
+            offset.abs() % 3600 / 60,
+        );
+
+        let local = date_time.naive_local();
+        DateTime::new(
+            &tz,
+            local.year(),
+            local.month() as i32,
+            local.day() as i32,
+            local.hour() as i32,
+            local.minute() as i32,
+            f64::from(local.second()),
+        )?
+        // Adding the microseconds separately avoids rounding errors of the floating point seconds
+        .add(i64::from(local.nanosecond() / 1000))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "chrono")))]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = BoolError;
+
+    /// Converts `date_time` to a chrono date and time with its UTC offset.
+    fn try_from(date_time: DateTime) -> Result<Self, BoolError> {
+        use chrono::TimeZone as _;
+
+        let offset = (date_time.get_utc_offset() / 1_000_000) as i32;
+        let offset = chrono::FixedOffset::east_opt(offset)
+            .ok_or_else(|| crate::bool_error!("Invalid UTC offset of {} seconds", offset))?;
+        let (year, month, day) = date_time.get_ymd();
+        chrono::NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+            .and_then(|date| {
+                date.and_hms_micro_opt(
+                    date_time.get_hour() as u32,
+                    date_time.get_minute() as u32,
+                    date_time.get_second() as u32,
+                    date_time.get_microsecond() as u32,
+                )
+            })
+            .and_then(|naive| offset.from_local_datetime(&naive).single())
+            .ok_or_else(|| crate::bool_error!("Can't convert date and time to chrono"))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "chrono")))]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::Utc> {
+    type Error = BoolError;
+
+    fn try_from(date_time: DateTime) -> Result<Self, BoolError> {
+        chrono::DateTime::<chrono::FixedOffset>::try_from(date_time)
+            .map(|date_time| date_time.with_timezone(&chrono::Utc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc3339() {
+        let date_time = DateTime::from_rfc3339("2003-07-01T10:52:37.25+02:00").unwrap();
+        assert_eq!(date_time.get_ymd(), (2003, 7, 1));
+        assert_eq!(date_time.get_hour(), 10);
+        assert_eq!(date_time.get_minute(), 52);
+        assert_eq!(date_time.get_second(), 37);
+        assert_eq!(date_time.get_microsecond(), 250_000);
+        assert_eq!(date_time.get_utc_offset(), 2 * 60 * 60 * 1_000_000);
+
+        let date_time = DateTime::from_rfc3339("1985-04-12t23:20:50z").unwrap();
+        assert_eq!(date_time.get_utc_offset(), 0);
+        assert_eq!(date_time.to_unix(), 482_196_050);
+
+        let err = DateTime::from_rfc3339("2003-07-01 10:52").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected ':' after the minute at position 16"
+        );
+        let err = DateTime::from_rfc3339("2003-7-01T10:52:37Z").unwrap_err();
+        assert_eq!(err.position(), 5);
+        assert_eq!(
+            DateTime::from_rfc3339("2003-02-30T10:52:37Z")
+                .unwrap_err()
+                .message(),
+            "Invalid date or time"
+        );
+        assert_eq!(
+            DateTime::from_rfc3339("2003-07-01T10:52:37Zabc")
+                .unwrap_err()
+                .position(),
+            20
+        );
+
+        let err = DateTime::from_rfc3339("2003-07-01T10:52:37+25:00").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid time zone hours at position 20");
+        let err = DateTime::from_rfc3339("2003-07-01T10:52:37-02:60").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid time zone minutes at position 23");
+    }
+
+    #[test]
+    fn rfc2822() {
+        let date_time = DateTime::from_rfc2822("Tue, 1 Jul 2003 10:52:37 +0200").unwrap();
+        assert_eq!(date_time.get_ymd(), (2003, 7, 1));
+        assert_eq!(date_time.get_hour(), 10);
+        assert_eq!(date_time.get_utc_offset(), 2 * 60 * 60 * 1_000_000);
+
+        let date_time = DateTime::from_rfc2822("21 Nov 97 09:55 PST").unwrap();
+        assert_eq!(date_time.get_ymd(), (1997, 11, 21));
+        assert_eq!(date_time.get_second(), 0);
+        assert_eq!(date_time.get_utc_offset(), -8 * 60 * 60 * 1_000_000);
+
+        let err = DateTime::from_rfc2822("Tue, 1 Jux 2003 10:52:37 +0200").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid month at position 7");
+        let err = DateTime::from_rfc2822("Tue, 1 Jul 2003 10:52:37 XYZ").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid time zone at position 25");
+        let err = DateTime::from_rfc2822("Tue, 1 Jul 2003 10:52:37 +2400").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid time zone hours at position 26");
+    }
+
+    #[test]
+    fn names() {
+        let date_time = DateTime::new_utc(2003, 7, 1, 10, 52, 37.0).unwrap();
+        // The test environment uses the C locale as `setlocale()` is never called.
+        assert_eq!(date_time.get_month_name(), "July");
+        assert_eq!(date_time.get_month_abbreviation(), "Jul");
+        assert_eq!(date_time.get_day_name(), "Tuesday");
+        assert_eq!(date_time.get_day_abbreviation(), "Tue");
+        assert_eq!(date_time.format_locale_date().unwrap(), "07/01/03");
+    }
+
+    #[test]
+    fn adjust_time() {
+        let tz = TimeZone::new_utc();
+        assert_eq!(
+            tz.adjust_time(TimeType::Standard, 1_000_000),
+            (0, 1_000_000)
+        );
+
+        let date_time = DateTime::new_utc(2003, 7, 1, 10, 52, 37.0).unwrap();
+        let (interval, time) = tz.adjust_time(TimeType::Universal, date_time.to_unix());
+        assert_eq!(time, date_time.to_unix());
+        assert_eq!(tz.get_offset(interval), 0);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use chrono::TimeZone as _;
+
+        let date_time = DateTime::from_rfc3339("2003-07-01T10:52:37.25+02:00").unwrap();
+        let fixed = chrono::DateTime::<chrono::FixedOffset>::try_from(date_time.clone()).unwrap();
+        assert_eq!(fixed.to_rfc3339(), "2003-07-01T10:52:37.250+02:00");
+        let utc = chrono::DateTime::<chrono::Utc>::try_from(date_time.clone()).unwrap();
+        assert_eq!(utc.to_rfc3339(), "2003-07-01T08:52:37.250+00:00");
+
+        let back = DateTime::try_from(fixed).unwrap();
+        assert_eq!(back, date_time);
+        assert_eq!(back.get_utc_offset(), date_time.get_utc_offset());
+        assert_eq!(DateTime::try_from(utc).unwrap(), date_time);
+
+        let odd_offset = chrono::FixedOffset::east_opt(30)
+            .unwrap()
+            .with_ymd_and_hms(2003, 7, 1, 0, 0, 0)
+            .unwrap();
+        assert!(DateTime::try_from(odd_offset).is_err());
+    }
+}
@@ -1,10 +1,31 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::translate::*;
+use crate::types::StaticType;
 use crate::value::Value;
+use crate::BoolError;
 use crate::CStr;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+use crate::FileSetContentsFlags;
 use crate::Type;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+use crate::UriError;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+use crate::UriFlags;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+use crate::UriHideFlags;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+use crate::UriParamsFlags;
+use crate::{
+    ChecksumType, DateMonth, DateWeekday, KeyFileError, MarkupError, OptionArg, RegexError,
+    SeekType, TimeType,
+};
+use crate::{
+    FileTest, FormatSizeFlags, IOCondition, KeyFileFlags, LogLevelFlags, MarkupParseFlags,
+    OptionFlags, RegexCompileFlags, RegexMatchFlags, SpawnFlags,
+};
 use std::cmp;
+use std::marker::PhantomData;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum UserDirectory {
@@ -148,6 +169,117 @@ impl EnumClass {
     }
 }
 
+/// Parses a value of the enum type `T` from its nick.
+///
+/// This implements `FromStr` for the generated enum types, e.g. `"not-found"` is parsed as
+/// `gio::IOErrorEnum::NotFound`.
+pub fn enum_from_nick<T: StaticType + FromGlib<i32>>(nick: &str) -> Result<T, BoolError> {
+    EnumClass::new(T::static_type())
+        .and_then(|class| class.get_value_by_nick(nick))
+        .map(|value| unsafe { from_glib(value.get_value()) })
+        .ok_or_else(|| bool_error!("Invalid nick '{}' for {}", nick, T::static_type()))
+}
+
+/// Implements the integer and nick conversions of enums generated from C enums.
+///
+/// For each listed enum `TryFrom<i32>` is implemented, which fails for values that are unknown
+/// to the bindings instead of returning the hidden `__Unknown` variant, and `From<T> for i32`,
+/// which returns the C value. `FromStr` parses values from their nicks with [`enum_from_nick`].
+///
+/// Enums that aren't registered with the GLib type system have no nicks and are listed after
+/// `integer:`, which only implements the integer conversions. Enums with manual integer
+/// conversions are listed after `nick:`, which only implements `FromStr`.
+///
+/// The conversions are not part of the code generated by gir, so each crate lists its enums in
+/// its `enums.rs`.
+///
+/// ```ignore
+/// glib::enum_conversions! {
+///     Align,
+///     #[cfg(any(feature = "v3_16", feature = "dox"))]
+///     ButtonRole,
+/// }
+/// ```
+#[macro_export]
+macro_rules! enum_conversions {
+    (integer: $($(#[$attr:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl ::std::convert::TryFrom<i32> for $name {
+                type Error = i32;
+
+                fn try_from(value: i32) -> ::std::result::Result<Self, i32> {
+                    match unsafe { $crate::translate::from_glib(value) } {
+                        $name::__Unknown(value) => ::std::result::Result::Err(value),
+                        value => ::std::result::Result::Ok(value),
+                    }
+                }
+            }
+
+            $(#[$attr])*
+            impl ::std::convert::From<$name> for i32 {
+                fn from(value: $name) -> i32 {
+                    $crate::translate::ToGlib::to_glib(&value)
+                }
+            }
+        )*
+    };
+    (nick: $($(#[$attr:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl ::std::str::FromStr for $name {
+                type Err = $crate::BoolError;
+
+                fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                    $crate::enum_from_nick(s)
+                }
+            }
+        )*
+    };
+    ($($(#[$attr:meta])* $name:ident),* $(,)?) => {
+        $crate::enum_conversions!(integer: $($(#[$attr])* $name),*);
+        $crate::enum_conversions!(nick: $($(#[$attr])* $name),*);
+    };
+}
+
+/// Returns the enums declared in `generated` that are not listed in any [`enum_conversions!`]
+/// invocation of `listed`.
+///
+/// The crates use this in a test of their `enums.rs` with the contents of `auto/enums.rs`, so that
+/// enums added by gir are not forgotten.
+#[doc(hidden)]
+pub fn __unlisted_enums<'a>(generated: &'a str, listed: &str) -> Vec<&'a str> {
+    let listed = listed
+        .split("enum_conversions! {")
+        .skip(1)
+        .flat_map(|invocation| invocation.split("\n}").next().unwrap_or_default().lines())
+        .map(|line| line.trim().trim_end_matches(','))
+        .collect::<Vec<_>>();
+
+    generated
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub enum "))
+        .map(|line| line.trim_end_matches('{').trim())
+        .filter(|name| !listed.contains(name))
+        .collect()
+}
+
+crate::enum_conversions! {
+    integer:
+    ChecksumType,
+    DateMonth,
+    DateWeekday,
+    KeyFileError,
+    MarkupError,
+    OptionArg,
+    RegexError,
+    SeekType,
+    TimeType,
+    #[cfg(any(feature = "v2_66", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+    UriError,
+}
+
 impl Drop for EnumClass {
     fn drop(&mut self) {
         unsafe {
@@ -689,3 +821,229 @@ impl<'a> FlagsBuilder<'a> {
         self.1
     }
 }
+
+/// An iterator over the single flags set in a value of the flags type `T`.
+///
+/// Created by the `iter()` method of the generated flags types.
+#[derive(Debug, Clone)]
+pub struct FlagsIter<T> {
+    bits: u32,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToGlib<GlibType = u32> + FromGlib<u32>> FlagsIter<T> {
+    /// Creates an iterator over the flags set in `value`.
+    pub fn new(value: &T) -> Self {
+        Self {
+            bits: value.to_glib(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: ToGlib<GlibType = u32> + FromGlib<u32>> Iterator for FlagsIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.bits != 0 {
+            let bit = self.bits & self.bits.wrapping_neg();
+            self.bits &= !bit;
+
+            // Skip bits unknown to the bindings, which are truncated
+            let flag: T = unsafe { from_glib(bit) };
+            if flag.to_glib() != 0 {
+                return Some(flag);
+            }
+        }
+        None
+    }
+}
+
+/// Formats `value` of the flags type `T` as its nicks separated by `|`.
+///
+/// This serializes the generated flags types with glib's `serde` feature, e.g.
+/// `gio::FileQueryInfoFlags::NOFOLLOW_SYMLINKS` is formatted as `"nofollow-symlinks"`. Bits
+/// without a nick are formatted as a hexadecimal number.
+pub fn flags_to_nicks<T: StaticType + ToGlib<GlibType = u32>>(value: &T) -> String {
+    let mut bits = value.to_glib();
+    let class = match FlagsClass::new(T::static_type()) {
+        Some(class) => class,
+        None => return format!("0x{:x}", bits),
+    };
+
+    if bits == 0 {
+        return class
+            .get_value(0)
+            .map(|value| String::from(value.get_nick()))
+            .unwrap_or_default();
+    }
+
+    let mut nicks = Vec::new();
+    while bits != 0 {
+        match class.get_value(bits) {
+            Some(ref value) if value.get_value() != 0 => {
+                nicks.push(String::from(value.get_nick()));
+                bits &= !value.get_value();
+            }
+            _ => {
+                nicks.push(format!("0x{:x}", bits));
+                break;
+            }
+        }
+    }
+    nicks.join("|")
+}
+
+/// Parses a value of the flags type `T` from nicks separated by `|`.
+///
+/// This implements `FromStr` for the generated flags types and accepts the strings produced by
+/// [`flags_to_nicks`].
+pub fn flags_from_nicks<T: StaticType + FromGlib<u32>>(s: &str) -> Result<T, BoolError> {
+    let class = FlagsClass::new(T::static_type())
+        .ok_or_else(|| bool_error!("{} is not a flags type", T::static_type()))?;
+
+    let mut bits = 0;
+    for nick in s.split('|').map(str::trim).filter(|nick| !nick.is_empty()) {
+        let value = match nick.strip_prefix("0x") {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => class.get_value_by_nick(nick).map(|value| value.get_value()),
+        };
+        bits |=
+            value.ok_or_else(|| bool_error!("Invalid nick '{}' for {}", nick, T::static_type()))?;
+    }
+    Ok(unsafe { from_glib(bits) })
+}
+
+/// Implements iteration, nick parsing and serde support for flags generated from C flags.
+///
+/// For each listed flags type an `iter()` method is implemented, which returns a [`FlagsIter`]
+/// over the single flags that are set. `FromStr` parses values from their nicks with
+/// [`flags_from_nicks`] and if glib is built with the `serde` feature the values are serialized
+/// as their nicks formatted by [`flags_to_nicks`]. The crates using the macro don't need to
+/// depend on serde themselves.
+///
+/// The generated `Display` implementations are not affected and keep formatting values like
+/// `Debug`, e.g. `NOFOLLOW_SYMLINKS`, so use [`flags_to_nicks`] for strings that can be parsed
+/// back.
+///
+/// Flags that aren't registered with the GLib type system have no nicks and are listed after
+/// `iter:`, which only implements `iter()`.
+///
+/// These implementations are not part of the code generated by gir, so each crate lists its
+/// flags in its `flags.rs`.
+///
+/// ```ignore
+/// glib::flags_conversions! {
+///     AccelFlags,
+///     #[cfg(any(feature = "v3_24", feature = "dox"))]
+///     EventControllerScrollFlags,
+/// }
+/// ```
+#[macro_export]
+macro_rules! flags_conversions {
+    (iter: $($(#[$attr:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl $name {
+                pub fn iter(&self) -> $crate::FlagsIter<Self> {
+                    $crate::FlagsIter::new(self)
+                }
+            }
+        )*
+    };
+    (nick: $($(#[$attr:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl ::std::str::FromStr for $name {
+                type Err = $crate::BoolError;
+
+                fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                    $crate::flags_from_nicks(s)
+                }
+            }
+
+            $crate::__glib_if_serde! {
+                $(#[$attr])*
+                impl $crate::__serde::Serialize for $name {
+                    fn serialize<S: $crate::__serde::Serializer>(
+                        &self,
+                        serializer: S,
+                    ) -> ::std::result::Result<S::Ok, S::Error> {
+                        serializer.serialize_str(&$crate::flags_to_nicks(self))
+                    }
+                }
+
+                $(#[$attr])*
+                impl<'de> $crate::__serde::Deserialize<'de> for $name {
+                    fn deserialize<D: $crate::__serde::Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> ::std::result::Result<Self, D::Error> {
+                        <::std::string::String as $crate::__serde::Deserialize>::deserialize(deserializer)?
+                            .parse()
+                            .map_err($crate::__serde::de::Error::custom)
+                    }
+                }
+            }
+        )*
+    };
+    ($($(#[$attr:meta])* $name:ident),* $(,)?) => {
+        $crate::flags_conversions!(iter: $($(#[$attr])* $name),*);
+        $crate::flags_conversions!(nick: $($(#[$attr])* $name),*);
+    };
+}
+
+crate::flags_conversions! {
+    IOCondition,
+}
+
+crate::flags_conversions! {
+    iter:
+    #[cfg(any(feature = "v2_66", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+    FileSetContentsFlags,
+    FileTest,
+    FormatSizeFlags,
+    KeyFileFlags,
+    LogLevelFlags,
+    MarkupParseFlags,
+    OptionFlags,
+    RegexCompileFlags,
+    RegexMatchFlags,
+    SpawnFlags,
+    #[cfg(any(feature = "v2_66", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+    UriFlags,
+    #[cfg(any(feature = "v2_66", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+    UriHideFlags,
+    #[cfg(any(feature = "v2_66", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+    UriParamsFlags,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlisted_enums() {
+        let generated = "pub enum Align {\n}\n\npub enum ButtonRole {\n}\n";
+        assert_eq!(
+            __unlisted_enums(generated, "enum_conversions! {\n    Align,\n}\n"),
+            ["ButtonRole"]
+        );
+        assert!(__unlisted_enums(
+            generated,
+            "enum_conversions! {\n    integer:\n    Align,\n    ButtonRole,\n}\n"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            __unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
@@ -0,0 +1,292 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Continue, IOCondition, MainContext, Priority, Source};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll, Waker};
+use std::collections::HashMap;
+use std::fmt;
+use std::os::unix::io::RawFd;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+type Watches = Arc<Mutex<HashMap<RawFd, Arc<Mutex<WatchState>>>>>;
+
+/// Watches the file descriptors of external libraries, e.g. an inotify or timerfd file
+/// descriptor or the ones of libusb, with a main context.
+///
+/// Every watched file descriptor provides its IO conditions through an [`FdWatch`] stream.
+/// Unlike [`unix_fd_source_stream`](crate::unix_fd_source_stream) the file descriptors are
+/// watched from the moment [`watch`](Self::watch) is called, on the main context of the
+/// watcher instead of the one of the executor, and all watches end once the watcher is dropped.
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+///
+/// # async fn run(fd: std::os::unix::io::RawFd) {
+/// let watcher = glib::FdWatcher::new(None);
+/// let mut events = watcher.watch(fd, glib::IOCondition::IN);
+/// while let Some(condition) = events.next().await {
+///     // Read from `fd` before the next condition is requested
+/// }
+/// # }
+/// ```
+pub struct FdWatcher {
+    context: MainContext,
+    priority: Priority,
+    watches: Watches,
+}
+
+impl FdWatcher {
+    /// Creates a watcher for the main context `context` or the default main context.
+    pub fn new(context: Option<&MainContext>) -> Self {
+        Self::with_priority(context, crate::PRIORITY_DEFAULT)
+    }
+
+    /// Creates a watcher whose sources have the priority `priority`.
+    pub fn with_priority(context: Option<&MainContext>, priority: Priority) -> Self {
+        Self {
+            context: context.cloned().unwrap_or_else(MainContext::default),
+            priority,
+            watches: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the main context the file descriptors are watched with.
+    pub fn get_context(&self) -> &MainContext {
+        &self.context
+    }
+
+    /// Starts watching `fd` for `condition`, replacing a previous watch of `fd`.
+    ///
+    /// The conditions have to be handled, e.g. by reading from the file descriptor, before the
+    /// next value is requested from the stream, otherwise they are provided again. Until then
+    /// the file descriptor is not watched. The stream ends once the watch is removed, or after
+    /// providing `IOCondition::NVAL` if the file descriptor is invalid.
+    pub fn watch(&self, fd: RawFd, condition: IOCondition) -> FdWatch {
+        let watch = FdWatch {
+            fd,
+            condition,
+            priority: self.priority,
+            context: self.context.clone(),
+            state: Arc::new(Mutex::new(WatchState::default())),
+            watches: self.watches.clone(),
+        };
+        watch.start(&mut watch.lock());
+
+        let previous = self
+            .watches
+            .lock()
+            .expect("Failed to lock fd watches")
+            .insert(fd, watch.state.clone());
+        if let Some(previous) = previous {
+            end(&previous);
+        }
+
+        watch
+    }
+
+    /// Stops watching `fd`, which ends its stream.
+    ///
+    /// Returns `false` if `fd` was not watched.
+    pub fn unwatch(&self, fd: RawFd) -> bool {
+        let state = self
+            .watches
+            .lock()
+            .expect("Failed to lock fd watches")
+            .remove(&fd);
+        match state {
+            Some(state) => {
+                end(&state);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if `fd` is watched.
+    pub fn is_watched(&self, fd: RawFd) -> bool {
+        self.watches
+            .lock()
+            .expect("Failed to lock fd watches")
+            .contains_key(&fd)
+    }
+}
+
+impl Drop for FdWatcher {
+    fn drop(&mut self) {
+        let watches = std::mem::take(&mut *self.watches.lock().expect("Failed to lock fd watches"));
+        for (_, state) in watches {
+            end(&state);
+        }
+    }
+}
+
+impl fmt::Debug for FdWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut fds = self
+            .watches
+            .lock()
+            .expect("Failed to lock fd watches")
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        fds.sort_unstable();
+        f.debug_struct("FdWatcher")
+            .field("context", &self.context)
+            .field("fds", &fds)
+            .finish()
+    }
+}
+
+// The fd source is level-triggered and would fire on every main loop iteration until the
+// condition is handled, so it only provides one condition and a new source is created when the
+// next one is requested
+#[derive(Default)]
+struct WatchState {
+    source: Option<Source>,
+    condition: Option<IOCondition>,
+    waker: Option<Waker>,
+    ended: bool,
+}
+
+fn end(state: &Mutex<WatchState>) {
+    let mut state = state.lock().expect("Failed to lock fd watch");
+    state.ended = true;
+    if let Some(source) = state.source.take() {
+        source.destroy();
+    }
+    if let Some(waker) = state.waker.take() {
+        waker.wake();
+    }
+}
+
+/// A `Stream` of the IO conditions of a file descriptor watched by an [`FdWatcher`].
+///
+/// The file descriptor is not watched anymore once the stream is dropped.
+pub struct FdWatch {
+    fd: RawFd,
+    condition: IOCondition,
+    priority: Priority,
+    context: MainContext,
+    state: Arc<Mutex<WatchState>>,
+    watches: Watches,
+}
+
+impl FdWatch {
+    /// Returns the watched file descriptor.
+    pub fn get_fd(&self) -> RawFd {
+        self.fd
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<WatchState> {
+        self.state.lock().expect("Failed to lock fd watch")
+    }
+
+    fn start(&self, state: &mut WatchState) {
+        let weak_state = Arc::downgrade(&self.state);
+        let source = crate::unix_fd_source_new(
+            self.fd,
+            self.condition,
+            Some("glib-rs fd watch"),
+            self.priority,
+            move |_, condition| {
+                if let Some(state) = weak_state.upgrade() {
+                    let mut state = state.lock().expect("Failed to lock fd watch");
+                    state.source = None;
+                    state.condition = Some(condition);
+                    if let Some(waker) = state.waker.take() {
+                        waker.wake();
+                    }
+                }
+                Continue(false)
+            },
+        );
+        source.attach(Some(&self.context));
+        state.source = Some(source);
+    }
+}
+
+impl Stream for FdWatch {
+    type Item = IOCondition;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        let mut state = self.lock();
+        if let Some(condition) = state.condition.take() {
+            if condition.contains(IOCondition::NVAL) {
+                state.ended = true;
+            }
+            return Poll::Ready(Some(condition));
+        }
+        if state.ended {
+            return Poll::Ready(None);
+        }
+        if state.source.is_none() {
+            self.start(&mut state);
+        }
+        state.waker = Some(ctx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for FdWatch {
+    fn drop(&mut self) {
+        end(&self.state);
+        let mut watches = self.watches.lock().expect("Failed to lock fd watches");
+        // The watch might have been replaced by a newer one of the same file descriptor
+        if watches
+            .get(&self.fd)
+            .map_or(false, |state| Arc::ptr_eq(state, &self.state))
+        {
+            watches.remove(&self.fd);
+        }
+    }
+}
+
+impl fmt::Debug for FdWatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FdWatch").field("fd", &self.fd).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    fn pipe() -> (RawFd, RawFd) {
+        let mut fds = [0; 2];
+        assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+        (fds[0], fds[1])
+    }
+
+    #[test]
+    fn watch() {
+        let c = MainContext::new();
+        let watcher = FdWatcher::new(Some(&c));
+        let (read_fd, write_fd) = pipe();
+
+        let mut watch = watcher.watch(read_fd, IOCondition::IN);
+        assert!(watcher.is_watched(read_fd));
+        assert_eq!(
+            unsafe { libc::write(write_fd, b"x".as_ptr() as *const _, 1) },
+            1
+        );
+        assert_eq!(c.block_on(watch.next()), Some(IOCondition::IN));
+
+        // The condition is not provided again until the next one is requested
+        assert!(!c.pending());
+
+        assert!(watcher.unwatch(read_fd));
+        assert!(!watcher.unwatch(read_fd));
+        assert_eq!(c.block_on(watch.next()), None);
+
+        let watch = watcher.watch(read_fd, IOCondition::IN);
+        drop(watch);
+        assert!(!watcher.is_watched(read_fd));
+
+        unsafe {
+            libc::close(read_fd);
+            libc::close(write_fd);
+        }
+    }
+}
@@ -17,6 +17,9 @@ use crate::translate::*;
 use crate::Error;
 use crate::GString;
 #[cfg(not(windows))]
+#[cfg(any(feature = "v2_58", feature = "dox"))]
+use crate::IntoStrV;
+#[cfg(not(windows))]
 use crate::Pid;
 #[cfg(not(windows))]
 use crate::SpawnFlags;
@@ -36,6 +39,42 @@ pub fn spawn_async_with_fds<P: AsRef<std::path::Path>, T: AsRawFd, U: AsRawFd, V
     stdin_fd: T,
     stdout_fd: U,
     stderr_fd: V,
+) -> Result<Pid, Error> {
+    spawn_async_with_fds_strv(
+        working_directory,
+        argv,
+        envp,
+        flags,
+        child_setup,
+        stdin_fd,
+        stdout_fd,
+        stderr_fd,
+    )
+}
+
+/// Like [`spawn_async_with_fds`] but takes the arguments and environment as any list of
+/// strings, e.g. a [`StrV`](crate::StrV) that is passed without copying it.
+#[cfg(any(feature = "v2_58", feature = "dox"))]
+#[cfg(not(windows))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "v2_58", not(windows)))))]
+#[allow(clippy::too_many_arguments)]
+#[doc(alias = "g_spawn_async_with_fds")]
+pub fn spawn_async_with_fds_strv<
+    P: AsRef<std::path::Path>,
+    A: IntoStrV,
+    E: IntoStrV,
+    T: AsRawFd,
+    U: AsRawFd,
+    V: AsRawFd,
+>(
+    working_directory: P,
+    argv: A,
+    envp: E,
+    flags: SpawnFlags,
+    child_setup: Option<Box_<dyn FnOnce() + 'static>>,
+    stdin_fd: T,
+    stdout_fd: U,
+    stderr_fd: V,
 ) -> Result<Pid, Error> {
     let child_setup_data: Box_<Option<Box_<dyn FnOnce() + 'static>>> = Box_::new(child_setup);
     unsafe extern "C" fn child_setup_func<P: AsRef<std::path::Path>>(user_data: ffi::gpointer) {
@@ -50,29 +89,31 @@ pub fn spawn_async_with_fds<P: AsRef<std::path::Path>, T: AsRawFd, U: AsRawFd, V
         None
     };
     let super_callback0: Box_<Option<Box_<dyn FnOnce() + 'static>>> = child_setup_data;
-    unsafe {
-        let mut child_pid = mem::MaybeUninit::uninit();
-        let mut error = ptr::null_mut();
-        let _ = ffi::g_spawn_async_with_fds(
-            working_directory.as_ref().to_glib_none().0,
-            argv.to_glib_none().0,
-            envp.to_glib_none().0,
-            flags.to_glib(),
-            child_setup,
-            Box_::into_raw(super_callback0) as *mut _,
-            child_pid.as_mut_ptr(),
-            stdin_fd.as_raw_fd(),
-            stdout_fd.as_raw_fd(),
-            stderr_fd.as_raw_fd(),
-            &mut error,
-        );
-        let child_pid = from_glib(child_pid.assume_init());
-        if error.is_null() {
-            Ok(child_pid)
-        } else {
-            Err(from_glib_full(error))
-        }
-    }
+    argv.run_with_strv(|argv| {
+        envp.run_with_strv(|envp| unsafe {
+            let mut child_pid = mem::MaybeUninit::uninit();
+            let mut error = ptr::null_mut();
+            let _ = ffi::g_spawn_async_with_fds(
+                working_directory.as_ref().to_glib_none().0,
+                argv.to_glib_none().0,
+                envp.to_glib_none().0,
+                flags.to_glib(),
+                child_setup,
+                Box_::into_raw(super_callback0) as *mut _,
+                child_pid.as_mut_ptr(),
+                stdin_fd.as_raw_fd(),
+                stdout_fd.as_raw_fd(),
+                stderr_fd.as_raw_fd(),
+                &mut error,
+            );
+            let child_pid = from_glib(child_pid.assume_init());
+            if error.is_null() {
+                Ok(child_pid)
+            } else {
+                Err(from_glib_full(error))
+            }
+        })
+    })
 }
 
 // #[cfg(any(feature = "v2_58", feature = "dox"))]
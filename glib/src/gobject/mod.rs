@@ -5,7 +5,9 @@
 mod auto;
 mod binding;
 mod flags;
+mod type_module;
 
 pub use self::auto::*;
 pub use self::flags::*;
+pub use self::type_module::{TypeModule, TypeModuleExt};
 //pub use self::auto::functions::*;
@@ -0,0 +1,64 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::translate::*;
+use crate::IsA;
+
+crate::wrapper! {
+    /// Base class for modules that register types dynamically, e.g. from plugins that can be
+    /// loaded and unloaded at runtime.
+    ///
+    /// Subclasses implement [`TypeModuleImpl`](crate::subclass::type_module::TypeModuleImpl)
+    /// and register their types with
+    /// [`register_dynamic_type`](crate::subclass::register_dynamic_type) and
+    /// [`register_dynamic_interface`](crate::subclass::register_dynamic_interface) when they
+    /// are loaded.
+    ///
+    /// A type module that registered types must never be freed, as the type system keeps
+    /// using it to load the types again.
+    pub struct TypeModule(Object<gobject_ffi::GTypeModule, gobject_ffi::GTypeModuleClass>);
+
+    match fn {
+        get_type => || gobject_ffi::g_type_module_get_type(),
+    }
+}
+
+pub trait TypeModuleExt: 'static {
+    /// Increases the use count of the module, loading it if it was not in use before.
+    ///
+    /// Returns `false` if loading the module failed.
+    #[doc(alias = "g_type_module_use")]
+    fn use_(&self) -> bool;
+
+    /// Decreases the use count of the module, unloading it once it is not in use anymore.
+    #[doc(alias = "g_type_module_unuse")]
+    fn unuse(&self);
+
+    /// Sets the name of the module, which is used in error messages.
+    #[doc(alias = "g_type_module_set_name")]
+    fn set_name(&self, name: &str);
+}
+
+impl<O: IsA<TypeModule>> TypeModuleExt for O {
+    fn use_(&self) -> bool {
+        unsafe {
+            from_glib(gobject_ffi::g_type_module_use(
+                self.as_ref().to_glib_none().0,
+            ))
+        }
+    }
+
+    fn unuse(&self) {
+        unsafe {
+            gobject_ffi::g_type_module_unuse(self.as_ref().to_glib_none().0);
+        }
+    }
+
+    fn set_name(&self, name: &str) {
+        unsafe {
+            gobject_ffi::g_type_module_set_name(
+                self.as_ref().to_glib_none().0,
+                name.to_glib_none().0,
+            );
+        }
+    }
+}
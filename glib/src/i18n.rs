@@ -0,0 +1,241 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Helpers for translating user visible strings with gettext.
+//!
+//! The translations are looked up in the text domain configured with [`init`] or
+//! [`set_text_domain`], which is the same domain C libraries like GTK use through GLib.
+//!
+//! Translated strings can't be used as `format!` strings as they are only known at runtime.
+//! The [`gettext!`](crate::gettext!), [`ngettext!`](crate::ngettext!) and
+//! [`pgettext!`](crate::pgettext!) macros instead substitute their arguments into the
+//! translated string with [`format`], which keeps working when translators reorder the
+//! placeholders with `{0}`, `{1}`, ...
+//!
+//! ```no_run
+//! glib::i18n::init("my-app", "/usr/share/locale").expect("Failed to set up translations");
+//!
+//! let name = "World";
+//! println!("{}", glib::gettext!("Hello {}!", name));
+//!
+//! let n = 3;
+//! println!("{}", glib::ngettext!("{} file", "{} files", n, n));
+//! ```
+
+pub use crate::{dcgettext, dgettext, dngettext, dpgettext, dpgettext2};
+
+use crate::translate::*;
+use crate::GString;
+use std::fmt::{self, Write};
+use std::path::Path;
+use std::ptr;
+
+#[cfg_attr(not(target_env = "gnu"), link(name = "intl"))]
+extern "C" {
+    fn bindtextdomain(
+        domainname: *const libc::c_char,
+        dirname: *const libc::c_char,
+    ) -> *mut libc::c_char;
+    fn bind_textdomain_codeset(
+        domainname: *const libc::c_char,
+        codeset: *const libc::c_char,
+    ) -> *mut libc::c_char;
+    fn textdomain(domainname: *const libc::c_char) -> *mut libc::c_char;
+}
+
+/// Translates `msgid` in the current text domain.
+#[doc(alias = "g_dgettext")]
+pub fn gettext(msgid: &str) -> GString {
+    dgettext(None, msgid)
+}
+
+/// Translates `msgid` or `msgid_plural` in the current text domain, depending on `n`.
+#[doc(alias = "g_dngettext")]
+pub fn ngettext(msgid: &str, msgid_plural: &str, n: u64) -> GString {
+    dngettext(None, msgid, msgid_plural, n as libc::c_ulong)
+}
+
+/// Translates `msgid` with the disambiguating `context` in the current text domain.
+#[doc(alias = "g_dpgettext2")]
+pub fn pgettext(context: &str, msgid: &str) -> GString {
+    dpgettext2(None, context, msgid)
+}
+
+/// Sets the locale of the process from the environment, i.e. `LANG` and the `LC_*` variables.
+///
+/// This has to be called before any other thread is started.
+pub fn init_locale() {
+    unsafe {
+        libc::setlocale(libc::LC_ALL, b"\0".as_ptr() as *const _);
+    }
+}
+
+/// Sets the directory containing the translations of `domain`.
+pub fn bind_text_domain<P: AsRef<Path>>(domain: &str, dir: P) -> Result<(), crate::BoolError> {
+    unsafe {
+        if bindtextdomain(domain.to_glib_none().0, dir.as_ref().to_glib_none().0).is_null() {
+            Err(bool_error!("Failed to bind text domain '{}'", domain))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Sets the encoding of the translations of `domain` returned by gettext.
+pub fn bind_text_domain_codeset(domain: &str, codeset: &str) -> Result<(), crate::BoolError> {
+    unsafe {
+        if bind_textdomain_codeset(domain.to_glib_none().0, codeset.to_glib_none().0).is_null() {
+            Err(bool_error!(
+                "Failed to set codeset '{}' of text domain '{}'",
+                codeset,
+                domain
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Sets the default text domain used for translations.
+pub fn set_text_domain(domain: &str) -> Result<(), crate::BoolError> {
+    unsafe {
+        if textdomain(domain.to_glib_none().0).is_null() {
+            Err(bool_error!("Failed to set text domain '{}'", domain))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Returns the current default text domain.
+pub fn text_domain() -> Option<String> {
+    unsafe {
+        let domain = textdomain(ptr::null());
+        if domain.is_null() {
+            None
+        } else {
+            Some(from_glib_none(domain as *const libc::c_char))
+        }
+    }
+}
+
+/// Sets up translations for an application.
+///
+/// This initializes the locale from the environment, binds `domain` to the translations in
+/// `locale_dir` with UTF-8 encoding and makes it the default text domain.
+pub fn init<P: AsRef<Path>>(domain: &str, locale_dir: P) -> Result<(), crate::BoolError> {
+    init_locale();
+    bind_text_domain(domain, locale_dir)?;
+    bind_text_domain_codeset(domain, "UTF-8")?;
+    set_text_domain(domain)
+}
+
+/// Substitutes `args` into the placeholders of `template`.
+///
+/// `{}` is replaced by the next argument and `{N}` by the argument at index `N`, while `{{` and
+/// `}}` are replaced by literal braces. Placeholders without a corresponding argument are kept
+/// as they are.
+pub fn format(template: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut res = String::with_capacity(template.len());
+    let mut next = 0;
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((pos, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                chars.next();
+                res.push('{');
+            }
+            '}' if chars.peek().map(|&(_, c)| c) == Some('}') => {
+                chars.next();
+                res.push('}');
+            }
+            '{' => {
+                let end = match template[pos..].find('}') {
+                    Some(len) => pos + len,
+                    None => {
+                        res.push_str(&template[pos..]);
+                        break;
+                    }
+                };
+                let placeholder = &template[pos + 1..end];
+                let idx = if placeholder.is_empty() {
+                    next += 1;
+                    Some(next - 1)
+                } else {
+                    placeholder.parse::<usize>().ok()
+                };
+
+                match idx.and_then(|idx| args.get(idx)) {
+                    Some(arg) => {
+                        let _ = write!(res, "{}", arg);
+                    }
+                    None => res.push_str(&template[pos..=end]),
+                }
+                while chars.peek().map_or(false, |&(pos, _)| pos <= end) {
+                    chars.next();
+                }
+            }
+            c => res.push(c),
+        }
+    }
+
+    res
+}
+
+/// Translates a message and substitutes the arguments into the translation.
+///
+/// See [`i18n::format`](crate::i18n::format) for the supported placeholders.
+#[macro_export]
+macro_rules! gettext {
+    ($msgid:expr $(, $arg:expr)* $(,)?) => {
+        $crate::i18n::format(&$crate::i18n::gettext($msgid), &[$(&$arg),*])
+    };
+}
+
+/// Translates a message with plural forms and substitutes the arguments into the translation.
+///
+/// See [`i18n::format`](crate::i18n::format) for the supported placeholders.
+#[macro_export]
+macro_rules! ngettext {
+    ($msgid:expr, $msgid_plural:expr, $n:expr $(, $arg:expr)* $(,)?) => {
+        $crate::i18n::format(
+            &$crate::i18n::ngettext($msgid, $msgid_plural, $n as u64),
+            &[$(&$arg),*],
+        )
+    };
+}
+
+/// Translates a message with context and substitutes the arguments into the translation.
+///
+/// See [`i18n::format`](crate::i18n::format) for the supported placeholders.
+#[macro_export]
+macro_rules! pgettext {
+    ($context:expr, $msgid:expr $(, $arg:expr)* $(,)?) => {
+        $crate::i18n::format(&$crate::i18n::pgettext($context, $msgid), &[$(&$arg),*])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_placeholders() {
+        assert_eq!(format("{} and {}", &[&1, &"two"]), "1 and two");
+        assert_eq!(format("{1} before {0}", &[&"a", &"b"]), "b before a");
+        assert_eq!(format("{{}} {}", &[&3]), "{} 3");
+        assert_eq!(format("{} {} {2}", &[&1]), "1 {} {2}");
+        assert_eq!(format("{x} {", &[&1]), "{x} {");
+        assert_eq!(format("ünïcödé {}", &[&'✓']), "ünïcödé ✓");
+    }
+
+    #[test]
+    fn untranslated() {
+        assert_eq!(gettext("Hello"), "Hello");
+        assert_eq!(ngettext("file", "files", 1), "file");
+        assert_eq!(ngettext("file", "files", 2), "files");
+        assert_eq!(pgettext("menu", "Open"), "Open");
+        assert_eq!(crate::gettext!("Hello {}!", "World"), "Hello World!");
+        assert_eq!(crate::ngettext!("{} file", "{} files", 3, 3), "3 files");
+    }
+}
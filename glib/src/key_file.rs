@@ -4,6 +4,7 @@ use crate::error::Error;
 use crate::gstring::GString;
 use crate::translate::*;
 use crate::KeyFileFlags;
+use crate::{IntoStrV, StrV};
 use std::mem;
 use std::path;
 use std::ptr;
@@ -254,4 +255,258 @@ impl KeyFile {
             }
         }
     }
+
+    #[doc(alias = "g_key_file_set_boolean_list")]
+    pub fn set_boolean_list(&self, group_name: &str, key: &str, list: &[bool]) {
+        let mut list = list.iter().map(|v| v.to_glib()).collect::<Vec<_>>();
+        unsafe {
+            ffi::g_key_file_set_boolean_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                list.as_mut_ptr(),
+                list.len(),
+            );
+        }
+    }
+
+    #[doc(alias = "g_key_file_set_integer_list")]
+    pub fn set_integer_list(&self, group_name: &str, key: &str, list: &[i32]) {
+        unsafe {
+            ffi::g_key_file_set_integer_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                mut_override(list.as_ptr()),
+                list.len(),
+            );
+        }
+    }
+
+    #[doc(alias = "g_key_file_set_double_list")]
+    pub fn set_double_list(&self, group_name: &str, key: &str, list: &[f64]) {
+        unsafe {
+            ffi::g_key_file_set_double_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                mut_override(list.as_ptr()),
+                list.len(),
+            );
+        }
+    }
+
+    #[doc(alias = "g_key_file_set_string_list")]
+    pub fn set_string_list(&self, group_name: &str, key: &str, list: &[&str]) {
+        unsafe {
+            ffi::g_key_file_set_string_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                list.to_glib_none().0,
+                list.len(),
+            );
+        }
+    }
+
+    #[doc(alias = "g_key_file_set_locale_string_list")]
+    pub fn set_locale_string_list(&self, group_name: &str, key: &str, locale: &str, list: &[&str]) {
+        unsafe {
+            ffi::g_key_file_set_locale_string_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                locale.to_glib_none().0,
+                list.to_glib_none().0,
+                list.len(),
+            );
+        }
+    }
+
+    /// Like [`get_string_list`](Self::get_string_list) but returns the strings as they were
+    /// allocated by GLib, without copying them.
+    #[doc(alias = "g_key_file_get_string_list")]
+    pub fn get_string_list_strv(&self, group_name: &str, key: &str) -> Result<StrV, Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            let ret = ffi::g_key_file_get_string_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                ptr::null_mut(),
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                if !ret.is_null() {
+                    ffi::g_strfreev(ret);
+                }
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Like [`get_locale_string_list`](Self::get_locale_string_list) but returns the strings as
+    /// they were allocated by GLib, without copying them.
+    #[doc(alias = "g_key_file_get_locale_string_list")]
+    pub fn get_locale_string_list_strv(
+        &self,
+        group_name: &str,
+        key: &str,
+        locale: Option<&str>,
+    ) -> Result<StrV, Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            let ret = ffi::g_key_file_get_locale_string_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                locale.to_glib_none().0,
+                ptr::null_mut(),
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                if !ret.is_null() {
+                    ffi::g_strfreev(ret);
+                }
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Like [`set_string_list`](Self::set_string_list) but passes a [`StrV`] without copying it.
+    #[doc(alias = "g_key_file_set_string_list")]
+    pub fn set_string_list_strv<L: IntoStrV>(&self, group_name: &str, key: &str, list: L) {
+        list.run_with_strv(|list| unsafe {
+            ffi::g_key_file_set_string_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                list.as_ptr(),
+                list.len(),
+            );
+        })
+    }
+
+    /// Like [`set_locale_string_list`](Self::set_locale_string_list) but passes a [`StrV`]
+    /// without copying it.
+    #[doc(alias = "g_key_file_set_locale_string_list")]
+    pub fn set_locale_string_list_strv<L: IntoStrV>(
+        &self,
+        group_name: &str,
+        key: &str,
+        locale: &str,
+        list: L,
+    ) {
+        list.run_with_strv(|list| unsafe {
+            ffi::g_key_file_set_locale_string_list(
+                self.to_glib_none().0,
+                group_name.to_glib_none().0,
+                key.to_glib_none().0,
+                locale.to_glib_none().0,
+                list.as_ptr(),
+                list.len(),
+            );
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists() {
+        let key_file = KeyFile::new();
+        key_file.set_boolean_list("Lists", "Booleans", &[true, false]);
+        key_file.set_integer_list("Lists", "Integers", &[1, -2]);
+        key_file.set_double_list("Lists", "Doubles", &[0.5]);
+        key_file.set_string_list("Lists", "Strings", &["a", "b;c"]);
+        key_file.set_locale_string_list("Lists", "Strings", "de", &["ä"]);
+
+        assert_eq!(
+            key_file.get_boolean_list("Lists", "Booleans").unwrap(),
+            vec![true, false]
+        );
+        assert_eq!(
+            key_file.get_integer_list("Lists", "Integers").unwrap(),
+            vec![1, -2]
+        );
+        assert_eq!(
+            key_file.get_double_list("Lists", "Doubles").unwrap(),
+            vec![0.5]
+        );
+        assert_eq!(
+            key_file.get_string_list("Lists", "Strings").unwrap(),
+            vec!["a", "b;c"]
+        );
+        assert_eq!(
+            key_file
+                .get_locale_string_list("Lists", "Strings", Some("de"))
+                .unwrap(),
+            vec!["ä"]
+        );
+        assert!(key_file.get_integer_list("Lists", "Strings").is_err());
+    }
+
+    #[test]
+    fn strv_lists() {
+        let key_file = KeyFile::new();
+        let strings = StrV::from(&["a", "b;c"][..]);
+        key_file.set_string_list_strv("Lists", "Strings", &strings);
+        key_file.set_locale_string_list_strv("Lists", "Strings", "de", vec!["ä"]);
+
+        assert_eq!(
+            key_file.get_string_list_strv("Lists", "Strings").unwrap(),
+            strings
+        );
+        assert_eq!(
+            key_file
+                .get_locale_string_list_strv("Lists", "Strings", Some("de"))
+                .unwrap(),
+            ["ä"][..]
+        );
+        assert!(key_file.get_string_list_strv("Lists", "Missing").is_err());
+    }
+
+    #[test]
+    fn comments_groups_save() {
+        let key_file = KeyFile::new();
+        key_file.set_string("Desktop Entry", "Name", "Demo");
+        key_file.set_locale_string("Desktop Entry", "Name", "de", "Beispiel");
+        key_file.set_boolean("Other", "Hidden", true);
+        key_file
+            .set_comment(Some("Desktop Entry"), Some("Name"), " The name")
+            .unwrap();
+
+        let (groups, len) = key_file.get_groups();
+        assert_eq!(len, 2);
+        assert_eq!(groups, vec!["Desktop Entry", "Other"]);
+        let comment = key_file
+            .get_comment(Some("Desktop Entry"), Some("Name"))
+            .unwrap();
+        assert_eq!(comment.trim_end(), " The name");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("demo.desktop");
+        key_file.save_to_file(&path).unwrap();
+
+        let loaded = KeyFile::new();
+        loaded
+            .load_from_file(
+                &path,
+                KeyFileFlags::KEEP_COMMENTS | KeyFileFlags::KEEP_TRANSLATIONS,
+            )
+            .unwrap();
+        assert_eq!(
+            loaded
+                .get_locale_string("Desktop Entry", "Name", Some("de"))
+                .unwrap(),
+            "Beispiel"
+        );
+        assert_eq!(loaded.get_boolean("Other", "Hidden"), Ok(true));
+    }
 }
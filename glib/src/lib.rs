@@ -29,6 +29,26 @@
 //!
 //! [`Variant`](variant/index.html) can carry values of `T: StaticVariantType`.
 //!
+//! # Enums
+//!
+//! Enums generated from C enums are marked `#[non_exhaustive]`, as newer
+//! versions of the C libraries can add values to them. Values unknown to the
+//! bindings are not rejected when coming from C but represented by the hidden
+//! `__Unknown(i32)` variant, which is passed back to C unchanged, so code
+//! matching on them always needs a catch-all arm. Use `TryFrom<i32>`, which
+//! fails for unknown values, to validate integers of other origins, and
+//! `FromStr` to parse values from their nicks (see
+//! [`enum_from_nick`](fn.enum_from_nick.html)). These conversions are not
+//! generated by gir but implemented with
+//! [`enum_conversions!`](macro.enum_conversions.html).
+//!
+//! Flags implement `FromStr` in the same way, parsing nicks separated by `|`
+//! with [`flags_from_nicks`](fn.flags_from_nicks.html), and `iter()` over the
+//! single flags that are set, implemented with
+//! [`flags_conversions!`](macro.flags_conversions.html). Their `Display`
+//! implementations format values like `Debug`, use
+//! [`flags_to_nicks`](fn.flags_to_nicks.html) for strings that can be parsed.
+//!
 //! # Errors
 //!
 //! Errors are represented by [`Error`](error/struct.Error.html), which can
@@ -86,8 +106,31 @@ pub use bitflags;
 #[doc(hidden)]
 pub use once_cell;
 
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+
+// Expands the serde implementations of the conversion macros only if glib itself is built with
+// the `serde` feature, so that the crates using the macros don't need their own serde feature
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __glib_if_serde {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __glib_if_serde {
+    ($($item:item)*) => {};
+}
+
 pub use glib_macros::{
     clone, gflags, object_interface, object_subclass, Downgrade, GBoxed, GEnum, GErrorDomain,
+    GVariant,
 };
 
 pub use self::byte_array::ByteArray;
@@ -100,19 +143,25 @@ pub use self::object::{
     WeakRef,
 };
 pub use self::signal::{
-    signal_handler_block, signal_handler_disconnect, signal_handler_unblock,
-    signal_stop_emission_by_name, SignalHandlerId,
+    signal_add_emission_hook, signal_handler_block, signal_handler_disconnect,
+    signal_handler_unblock, signal_stop_emission_by_name, EmissionHook, SignalHandlerId,
 };
 pub use self::string::String;
 use std::ffi::CStr;
 
-pub use self::enums::{EnumClass, EnumValue, FlagsBuilder, FlagsClass, FlagsValue, UserDirectory};
+#[doc(hidden)]
+pub use self::enums::__unlisted_enums;
+pub use self::enums::{
+    enum_from_nick, flags_from_nicks, flags_to_nicks, EnumClass, EnumValue, FlagsBuilder,
+    FlagsClass, FlagsIter, FlagsValue, UserDirectory,
+};
 pub use self::types::{ILong, StaticType, Type, ULong};
 pub use self::value::{SendValue, ToSendValue, ToValue, TypedValue, Value};
 pub use self::variant::{FromVariant, StaticVariantType, ToVariant, Variant};
+pub use self::variant_builder::VariantBuilder;
 pub use self::variant_dict::VariantDict;
-pub use self::variant_iter::VariantIter;
-pub use self::variant_type::{VariantTy, VariantType};
+pub use self::variant_iter::{VariantIter, VariantTypedIter};
+pub use self::variant_type::{VariantTy, VariantTyIterator, VariantType};
 
 pub mod clone;
 #[macro_use]
@@ -126,6 +175,9 @@ pub mod error;
 #[macro_use]
 pub mod object;
 
+mod object_list;
+pub use self::object_list::{IterOf, ObjectList};
+
 pub use self::auto::functions::*;
 pub use self::auto::*;
 #[allow(clippy::let_and_return)]
@@ -152,6 +204,8 @@ mod file_error;
 mod functions;
 pub use self::functions::*;
 mod key_file;
+mod markup;
+pub use self::markup::{MarkupParseContext, MarkupParseState, MarkupParser};
 pub mod prelude;
 pub mod signal;
 pub mod source;
@@ -160,6 +214,9 @@ pub use self::source::*;
 pub mod translate;
 mod gstring;
 pub use self::gstring::GString;
+mod strv;
+pub use self::strv::{IntoStrV, StrV, StrVIter};
+pub mod i18n;
 pub mod types;
 mod utils;
 pub use self::utils::*;
@@ -167,18 +224,43 @@ mod main_context;
 mod main_context_channel;
 pub mod value;
 pub mod variant;
+mod variant_builder;
 mod variant_dict;
 mod variant_iter;
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+pub mod variant_serde;
 mod variant_type;
 pub use self::main_context_channel::{Receiver, Sender, SyncSender};
 mod date;
 pub use self::date::Date;
+mod date_time;
+pub use self::date_time::DateTimeParseError;
 mod value_array;
 pub use self::value_array::ValueArray;
 mod param_spec;
 pub use self::param_spec::*;
+mod property_undo;
+pub use self::property_undo::{PropertyChange, PropertyUndoStack};
+mod property_watch;
+pub use self::property_watch::{watch_property_chain, PropertyChainWatch};
+pub mod profiling;
 mod quark;
 pub use self::quark::Quark;
+mod regex;
+pub use self::regex::{Match, Matches, Regex};
+mod signal_stream;
+pub use self::signal_stream::{signal_stream, SignalStream};
+mod state_machine;
+pub use self::state_machine::StateMachine;
+mod timer;
+pub use self::timer::Timer;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+mod uri;
+#[cfg(any(feature = "v2_66", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_66")))]
+pub use self::uri::UriBuilder;
 #[macro_use]
 mod log;
 #[cfg(any(feature = "v2_46", feature = "dox"))]
@@ -213,9 +295,27 @@ mod main_context_futures;
 mod source_futures;
 pub use self::source_futures::*;
 
+#[cfg(any(unix, feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(unix)))]
+mod fd_watcher;
+#[cfg(any(unix, feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(unix)))]
+pub use self::fd_watcher::{FdWatch, FdWatcher};
+
 mod thread_pool;
 pub use self::thread_pool::ThreadPool;
 
+mod slice_box;
+pub use self::slice_box::{SliceBox, SlicePool, SlicePoolBox};
+
+mod string_chunk;
+pub use self::string_chunk::StringChunk;
+
+mod version;
+#[cfg(unix)]
+pub use self::version::lookup_versioned_fn;
+pub use self::version::{check_runtime_version, runtime_version, Version, VersionError};
+
 /// This is the log domain used by the [`clone!`][crate::clone!] macro. If you want to use a custom
 /// logger (it prints to stdout by default), you can set your own logger using the corresponding
 /// `log` functions.
@@ -249,6 +349,10 @@ impl<T> ThreadGuard<T> {
         }
     }
 
+    pub(crate) fn is_owner(&self) -> bool {
+        self.thread_id == get_thread_id()
+    }
+
     pub(crate) fn get_ref(&self) -> &T {
         if self.thread_id != get_thread_id() {
             panic!("Value accessed from different thread than where it was created");
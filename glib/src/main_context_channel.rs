@@ -234,19 +234,21 @@ unsafe extern "C" fn dispatch<T, F: FnMut(T) -> Continue + 'static>(
         .channel
         .as_ref()
         .expect("ChannelSource without Channel");
-    loop {
-        match channel.try_recv() {
-            Err(mpsc::TryRecvError::Empty) => break,
-            Err(mpsc::TryRecvError::Disconnected) => return ffi::G_SOURCE_REMOVE,
-            Ok(item) => {
-                if callback(item) == Continue(false) {
-                    return ffi::G_SOURCE_REMOVE;
+    crate::profiling::dispatch(&mut source.source, || {
+        loop {
+            match channel.try_recv() {
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => return ffi::G_SOURCE_REMOVE,
+                Ok(item) => {
+                    if callback(item) == Continue(false) {
+                        return ffi::G_SOURCE_REMOVE;
+                    }
                 }
             }
         }
-    }
 
-    ffi::G_SOURCE_CONTINUE
+        ffi::G_SOURCE_CONTINUE
+    })
 }
 
 unsafe extern "C" fn finalize<T, F: FnMut(T) -> Continue + 'static>(source: *mut ffi::GSource) {
@@ -58,12 +58,12 @@ impl TaskSource {
         callback: ffi::GSourceFunc,
         _user_data: ffi::gpointer,
     ) -> ffi::gboolean {
-        let source = &mut *(source as *mut TaskSource);
+        let task = &mut *(source as *mut TaskSource);
         assert!(callback.is_none());
 
         // Poll the TaskSource and ensure we're never called again if the
         // contained Future resolved now.
-        if let Poll::Ready(()) = source.poll() {
+        if let Poll::Ready(()) = crate::profiling::dispatch(source, || task.poll()) {
             ffi::G_SOURCE_REMOVE
         } else {
             ffi::G_SOURCE_CONTINUE
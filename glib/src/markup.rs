@@ -0,0 +1,486 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::translate::*;
+use crate::{Error, MarkupParseFlags};
+use libc::{c_char, size_t};
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+use std::{fmt, slice, str};
+
+/// The callbacks of a [`MarkupParseContext`].
+///
+/// All callbacks have a default implementation that ignores the event. Returning an error stops
+/// the parsing, and the error is passed to [`error`](Self::error) and returned from
+/// [`MarkupParseContext::parse`]. Errors of the parser itself use the
+/// [`MarkupError`](crate::MarkupError) domain.
+pub trait MarkupParser {
+    /// Called for the opening tag of an element, and for empty elements like `<br/>`.
+    fn start_element(
+        &mut self,
+        state: &MarkupParseState,
+        element_name: &str,
+        attributes: &[(&str, &str)],
+    ) -> Result<(), Error> {
+        let _ = (state, element_name, attributes);
+        Ok(())
+    }
+
+    /// Called for the closing tag of an element, and for empty elements like `<br/>`.
+    fn end_element(&mut self, state: &MarkupParseState, element_name: &str) -> Result<(), Error> {
+        let _ = (state, element_name);
+        Ok(())
+    }
+
+    /// Called for the unescaped text between elements, which might be split into several calls.
+    fn text(&mut self, state: &MarkupParseState, text: &str) -> Result<(), Error> {
+        let _ = (state, text);
+        Ok(())
+    }
+
+    /// Called for comments, processing instructions and CDATA sections, unless
+    /// [`MarkupParseFlags::TREAT_CDATA_AS_TEXT`] is set.
+    fn passthrough(
+        &mut self,
+        state: &MarkupParseState,
+        passthrough_text: &str,
+    ) -> Result<(), Error> {
+        let _ = (state, passthrough_text);
+        Ok(())
+    }
+
+    /// Called once when the parsing fails, either because of invalid markup or because one of
+    /// the callbacks returned an error.
+    fn error(&mut self, state: &MarkupParseState, error: &Error) {
+        let _ = (state, error);
+    }
+}
+
+/// The current state of a [`MarkupParseContext`], passed to the [`MarkupParser`] callbacks.
+pub struct MarkupParseState<'a> {
+    ptr: *mut ffi::GMarkupParseContext,
+    phantom: PhantomData<&'a ffi::GMarkupParseContext>,
+}
+
+impl<'a> MarkupParseState<'a> {
+    /// Returns the name of the element that is currently being parsed.
+    #[doc(alias = "g_markup_parse_context_get_element")]
+    pub fn get_element(&self) -> Option<&'a str> {
+        unsafe { str_from_ptr(ffi::g_markup_parse_context_get_element(self.ptr)) }
+    }
+
+    /// Returns the names of all open elements, starting with the innermost one.
+    #[doc(alias = "g_markup_parse_context_get_element_stack")]
+    pub fn get_element_stack(&self) -> Vec<&'a str> {
+        let mut stack = Vec::new();
+        unsafe {
+            let mut list = ffi::g_markup_parse_context_get_element_stack(self.ptr);
+            while !list.is_null() {
+                if let Some(element) = str_from_ptr((*list).data as *const c_char) {
+                    stack.push(element);
+                }
+                list = (*list).next;
+            }
+        }
+        stack
+    }
+
+    /// Returns the line and character number of the current position, both starting at 1.
+    #[doc(alias = "g_markup_parse_context_get_position")]
+    pub fn get_position(&self) -> (i32, i32) {
+        get_position(self.ptr)
+    }
+}
+
+impl<'a> fmt::Debug for MarkupParseState<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MarkupParseState")
+            .field("element_stack", &self.get_element_stack())
+            .field("position", &self.get_position())
+            .finish()
+    }
+}
+
+/// An incremental parser for a subset of XML, like Pango markup or `GtkBuilder` files.
+///
+/// The text can be passed in arbitrary chunks, e.g. while it is read from a file.
+///
+/// ```
+/// use glib::{MarkupParseContext, MarkupParseFlags, MarkupParseState, MarkupParser};
+///
+/// #[derive(Default)]
+/// struct Links(Vec<String>);
+///
+/// impl MarkupParser for Links {
+///     fn start_element(
+///         &mut self,
+///         _state: &MarkupParseState,
+///         element_name: &str,
+///         attributes: &[(&str, &str)],
+///     ) -> Result<(), glib::Error> {
+///         if element_name == "a" {
+///             self.0.extend(
+///                 attributes
+///                     .iter()
+///                     .filter(|(name, _)| *name == "href")
+///                     .map(|(_, value)| value.to_string()),
+///             );
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut context = MarkupParseContext::new(Links::default(), MarkupParseFlags::empty());
+/// context.parse("<p>See <a href='https://gtk-rs.org'>gt").unwrap();
+/// context.parse("k-rs</a></p>").unwrap();
+/// context.end_parse().unwrap();
+/// assert_eq!(context.into_parser().0, ["https://gtk-rs.org"]);
+/// ```
+#[doc(alias = "GMarkupParseContext")]
+pub struct MarkupParseContext<P: MarkupParser> {
+    ptr: NonNull<ffi::GMarkupParseContext>,
+    // GLib keeps pointers to both, so they are boxed to have a stable address and only kept as
+    // raw pointers, as references to the boxes would conflict with the pointers used by GLib
+    vtable: NonNull<ffi::GMarkupParser>,
+    parser: NonNull<P>,
+}
+
+impl<P: MarkupParser> MarkupParseContext<P> {
+    #[doc(alias = "g_markup_parse_context_new")]
+    pub fn new(parser: P, flags: MarkupParseFlags) -> Self {
+        let vtable = Box::into_raw(Box::new(ffi::GMarkupParser {
+            start_element: Some(start_element_trampoline::<P>),
+            end_element: Some(end_element_trampoline::<P>),
+            text: Some(text_trampoline::<P>),
+            passthrough: Some(passthrough_trampoline::<P>),
+            error: Some(error_trampoline::<P>),
+        }));
+        let parser = Box::into_raw(Box::new(parser));
+
+        unsafe {
+            let ptr = ffi::g_markup_parse_context_new(
+                vtable,
+                flags.to_glib(),
+                parser as ffi::gpointer,
+                None,
+            );
+            Self {
+                ptr: NonNull::new(ptr).expect("Failed to create markup parse context"),
+                vtable: NonNull::new_unchecked(vtable),
+                parser: NonNull::new_unchecked(parser),
+            }
+        }
+    }
+
+    /// Parses the next chunk `text` of the document.
+    ///
+    /// Once this failed, the context can't be used for parsing anymore.
+    #[doc(alias = "g_markup_parse_context_parse")]
+    pub fn parse(&mut self, text: &str) -> Result<(), Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            ffi::g_markup_parse_context_parse(
+                self.ptr.as_ptr(),
+                text.as_ptr() as *const _,
+                text.len() as isize,
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(())
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Signals the end of the document, which fails if it is incomplete, e.g. if elements are
+    /// still open.
+    #[doc(alias = "g_markup_parse_context_end_parse")]
+    pub fn end_parse(&mut self) -> Result<(), Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            ffi::g_markup_parse_context_end_parse(self.ptr.as_ptr(), &mut error);
+            if error.is_null() {
+                Ok(())
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Returns the line and character number of the current position, both starting at 1.
+    #[doc(alias = "g_markup_parse_context_get_position")]
+    pub fn get_position(&self) -> (i32, i32) {
+        get_position(self.ptr.as_ptr())
+    }
+
+    pub fn get_parser(&self) -> &P {
+        unsafe { self.parser.as_ref() }
+    }
+
+    pub fn get_parser_mut(&mut self) -> &mut P {
+        unsafe { self.parser.as_mut() }
+    }
+
+    /// Frees the context and returns the parser, e.g. to get the parsed data.
+    pub fn into_parser(self) -> P {
+        let this = mem::ManuallyDrop::new(self);
+        unsafe {
+            ffi::g_markup_parse_context_free(this.ptr.as_ptr());
+            drop(Box::from_raw(this.vtable.as_ptr()));
+            *Box::from_raw(this.parser.as_ptr())
+        }
+    }
+}
+
+impl<P: MarkupParser> Drop for MarkupParseContext<P> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::g_markup_parse_context_free(self.ptr.as_ptr());
+            drop(Box::from_raw(self.vtable.as_ptr()));
+            drop(Box::from_raw(self.parser.as_ptr()));
+        }
+    }
+}
+
+impl<P: MarkupParser + fmt::Debug> fmt::Debug for MarkupParseContext<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MarkupParseContext")
+            .field("parser", self.get_parser())
+            .field("position", &self.get_position())
+            .finish()
+    }
+}
+
+fn get_position(ptr: *mut ffi::GMarkupParseContext) -> (i32, i32) {
+    let mut line_number = 0;
+    let mut char_number = 0;
+    unsafe { ffi::g_markup_parse_context_get_position(ptr, &mut line_number, &mut char_number) };
+    (line_number, char_number)
+}
+
+// GMarkup validates that the parsed text is UTF-8, and the text passed to `parse()` is a `str`
+unsafe fn str_from_ptr<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(str::from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes()))
+    }
+}
+
+unsafe fn str_from_ptr_len<'a>(ptr: *const c_char, len: size_t) -> &'a str {
+    if len == 0 {
+        ""
+    } else {
+        str::from_utf8_unchecked(slice::from_raw_parts(ptr as *const u8, len))
+    }
+}
+
+unsafe fn set_error(result: Result<(), Error>, error: *mut *mut ffi::GError) {
+    if let Err(err) = result {
+        *error = err.to_glib_full() as *mut _;
+    }
+}
+
+unsafe extern "C" fn start_element_trampoline<P: MarkupParser>(
+    context: *mut ffi::GMarkupParseContext,
+    element_name: *const c_char,
+    attribute_names: *mut *const c_char,
+    attribute_values: *mut *const c_char,
+    user_data: ffi::gpointer,
+    error: *mut *mut ffi::GError,
+) {
+    let parser = &mut *(user_data as *mut P);
+    let state = MarkupParseState {
+        ptr: context,
+        phantom: PhantomData,
+    };
+
+    let mut attributes = Vec::new();
+    let mut i = 0;
+    while !(*attribute_names.add(i)).is_null() {
+        attributes.push((
+            str_from_ptr(*attribute_names.add(i)).unwrap(),
+            str_from_ptr(*attribute_values.add(i)).unwrap_or(""),
+        ));
+        i += 1;
+    }
+
+    let element_name = str_from_ptr(element_name).unwrap();
+    set_error(
+        parser.start_element(&state, element_name, &attributes),
+        error,
+    );
+}
+
+unsafe extern "C" fn end_element_trampoline<P: MarkupParser>(
+    context: *mut ffi::GMarkupParseContext,
+    element_name: *const c_char,
+    user_data: ffi::gpointer,
+    error: *mut *mut ffi::GError,
+) {
+    let parser = &mut *(user_data as *mut P);
+    let state = MarkupParseState {
+        ptr: context,
+        phantom: PhantomData,
+    };
+    let element_name = str_from_ptr(element_name).unwrap();
+    set_error(parser.end_element(&state, element_name), error);
+}
+
+unsafe extern "C" fn text_trampoline<P: MarkupParser>(
+    context: *mut ffi::GMarkupParseContext,
+    text: *const c_char,
+    text_len: size_t,
+    user_data: ffi::gpointer,
+    error: *mut *mut ffi::GError,
+) {
+    let parser = &mut *(user_data as *mut P);
+    let state = MarkupParseState {
+        ptr: context,
+        phantom: PhantomData,
+    };
+    set_error(parser.text(&state, str_from_ptr_len(text, text_len)), error);
+}
+
+unsafe extern "C" fn passthrough_trampoline<P: MarkupParser>(
+    context: *mut ffi::GMarkupParseContext,
+    passthrough_text: *const c_char,
+    text_len: size_t,
+    user_data: ffi::gpointer,
+    error: *mut *mut ffi::GError,
+) {
+    let parser = &mut *(user_data as *mut P);
+    let state = MarkupParseState {
+        ptr: context,
+        phantom: PhantomData,
+    };
+    set_error(
+        parser.passthrough(&state, str_from_ptr_len(passthrough_text, text_len)),
+        error,
+    );
+}
+
+unsafe extern "C" fn error_trampoline<P: MarkupParser>(
+    context: *mut ffi::GMarkupParseContext,
+    error: *mut ffi::GError,
+    user_data: ffi::gpointer,
+) {
+    let parser = &mut *(user_data as *mut P);
+    let state = MarkupParseState {
+        ptr: context,
+        phantom: PhantomData,
+    };
+    parser.error(&state, &from_glib_borrow(error));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FileError, MarkupError};
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        events: Vec<String>,
+        error: Option<String>,
+    }
+
+    impl MarkupParser for Recorder {
+        fn start_element(
+            &mut self,
+            state: &MarkupParseState,
+            element_name: &str,
+            attributes: &[(&str, &str)],
+        ) -> Result<(), Error> {
+            if element_name == "fail" {
+                return Err(Error::new(FileError::Failed, "Failing element"));
+            }
+            let attributes = attributes
+                .iter()
+                .map(|(name, value)| format!(" {}={}", name, value))
+                .collect::<String>();
+            self.events.push(format!(
+                "start {}{} in {:?}",
+                element_name,
+                attributes,
+                state.get_element_stack()
+            ));
+            Ok(())
+        }
+
+        fn end_element(
+            &mut self,
+            state: &MarkupParseState,
+            element_name: &str,
+        ) -> Result<(), Error> {
+            assert_eq!(state.get_element(), Some(element_name));
+            self.events.push(format!("end {}", element_name));
+            Ok(())
+        }
+
+        fn text(&mut self, _state: &MarkupParseState, text: &str) -> Result<(), Error> {
+            if !text.is_empty() {
+                self.events.push(format!("text {}", text));
+            }
+            Ok(())
+        }
+
+        fn passthrough(&mut self, _state: &MarkupParseState, text: &str) -> Result<(), Error> {
+            self.events.push(format!("passthrough {}", text));
+            Ok(())
+        }
+
+        fn error(&mut self, state: &MarkupParseState, error: &Error) {
+            self.error = Some(format!("{:?}: {}", state.get_position(), error));
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let mut context = MarkupParseContext::new(Recorder::default(), MarkupParseFlags::empty());
+        context
+            .parse("<span weight='bold' lang=\"en\">f&amp;ö<!-- c -->")
+            .unwrap();
+        context.parse("o<br/></span>").unwrap();
+        context.end_parse().unwrap();
+
+        let recorder = context.into_parser();
+        assert_eq!(
+            recorder.events,
+            vec![
+                "start span weight=bold lang=en in [\"span\"]",
+                "text f&ö",
+                "passthrough <!-- c -->",
+                "text o",
+                "start br in [\"br\", \"span\"]",
+                "end br",
+                "end span",
+            ]
+        );
+        assert!(recorder.error.is_none());
+    }
+
+    #[test]
+    fn errors() {
+        let mut context = MarkupParseContext::new(Recorder::default(), MarkupParseFlags::empty());
+        let err = context.parse("<a><fail/></a>").unwrap_err();
+        assert!(err.matches(FileError::Failed));
+        assert!(context
+            .get_parser()
+            .error
+            .as_ref()
+            .unwrap()
+            .ends_with(": Failing element"));
+
+        let mut context = MarkupParseContext::new(Recorder::default(), MarkupParseFlags::empty());
+        let err = context.parse("<a></b>").unwrap_err();
+        assert!(err.matches(MarkupError::Parse));
+        assert!(context.get_parser().error.is_some());
+
+        let mut context = MarkupParseContext::new(Recorder::default(), MarkupParseFlags::empty());
+        context.parse("<a>").unwrap();
+        let err = context.end_parse().unwrap_err();
+        assert!(err.matches(MarkupError::Parse));
+    }
+}
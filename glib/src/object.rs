@@ -5,14 +5,19 @@
 use crate::translate::*;
 use crate::types::StaticType;
 use crate::{quark::Quark, subclass::signal::SignalQuery};
+use std::cell::{Cell, RefCell};
 use std::cmp;
+use std::collections::VecDeque;
 use std::fmt;
+use std::future::Future;
 use std::hash;
 use std::marker::PhantomData;
 use std::mem;
 use std::ops;
 use std::pin::Pin;
 use std::ptr;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 use crate::subclass::{prelude::ObjectSubclass, SignalId};
 use crate::value::ToValue;
@@ -608,6 +613,23 @@ macro_rules! glib_weak_impl {
 /// ObjectType implementations for Object types. See `wrapper!`.
 #[macro_export]
 macro_rules! glib_object_wrapper {
+    (@class_trait $name:ident, $trait_name:ident,
+        $($(#[$fn_attr:meta])* fn $fn_name:ident($($fn_args:tt)*) $(-> $fn_ret:ty)? $fn_body:block)*
+    ) => {
+        pub trait $trait_name {
+            $(
+                $(#[$fn_attr])*
+                fn $fn_name($($fn_args)*) $(-> $fn_ret)?;
+            )*
+        }
+
+        impl $trait_name for $crate::object::Class<$name> {
+            $(
+                fn $fn_name($($fn_args)*) $(-> $fn_ret)? $fn_body
+            )*
+        }
+    };
+
     (@generic_impl [$($attr:meta)*] $name:ident, $ffi_name:ty, $ffi_class_name:ty, @get_type $get_type_expr:expr) => {
         $(#[$attr])*
         // Always derive Hash/Ord (and below impl Debug, PartialEq, Eq, PartialOrd) for object
@@ -1194,6 +1216,27 @@ pub trait ObjectExt: ObjectType {
     fn set_properties_from_value(&self, property_values: &[(&str, Value)])
         -> Result<(), BoolError>;
     fn get_property<'a, N: Into<&'a str>>(&self, property_name: N) -> Result<Value, BoolError>;
+
+    /// Same as `set_property` but takes a `ParamSpec` instead of a property name.
+    ///
+    /// The `ParamSpec` can be looked up once with `find_property()` and then be stored to avoid
+    /// looking up the property by name every time it is set.
+    fn set_property_from_pspec<V: ToValue>(
+        &self,
+        pspec: &crate::ParamSpec,
+        value: &V,
+    ) -> Result<(), BoolError>;
+    /// Same as `set_property_from_value` but takes a `ParamSpec` instead of a property name.
+    fn set_property_value_from_pspec(
+        &self,
+        pspec: &crate::ParamSpec,
+        value: &Value,
+    ) -> Result<(), BoolError>;
+    /// Same as `get_property` but takes a `ParamSpec` instead of a property name.
+    ///
+    /// The `ParamSpec` can be looked up once with `find_property()` and then be stored to avoid
+    /// looking up the property by name every time it is read.
+    fn get_property_from_pspec(&self, pspec: &crate::ParamSpec) -> Result<Value, BoolError>;
     fn has_property<'a, N: Into<&'a str>>(&self, property_name: N, type_: Option<Type>) -> bool;
     fn get_property_type<'a, N: Into<&'a str>>(&self, property_name: N) -> Option<Type>;
     fn find_property<'a, N: Into<&'a str>>(&self, property_name: N) -> Option<crate::ParamSpec>;
@@ -1279,6 +1322,41 @@ pub trait ObjectExt: ObjectType {
     where
         F: Fn(&[Value]) -> Option<Value> + 'static;
 
+    /// Like `connect_local`, but `callback` returns a future that is spawned for every
+    /// emission.
+    ///
+    /// The futures are spawned on the thread default main context at the time of connecting,
+    /// which has to be owned by the current thread, and get a copy of the signal arguments,
+    /// starting with the object. Signals with a return value are not supported.
+    ///
+    /// Disconnecting the returned handler doesn't cancel futures that are already running.
+    fn connect_local_async<'a, N, F, Fut>(
+        &self,
+        signal_name: N,
+        after: bool,
+        callback: F,
+    ) -> Result<SignalHandlerId, BoolError>
+    where
+        N: Into<&'a str>,
+        F: Fn(Vec<Value>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static;
+    /// Same as `connect_local_async` but runs at most `max_concurrent` futures at the same time.
+    ///
+    /// Emissions while `max_concurrent` futures are running are queued, and their futures are
+    /// run in order once the running ones finished, e.g. to save a document only once at a
+    /// time.
+    fn connect_local_async_limited<'a, N, F, Fut>(
+        &self,
+        signal_name: N,
+        after: bool,
+        max_concurrent: usize,
+        callback: F,
+    ) -> Result<SignalHandlerId, BoolError>
+    where
+        N: Into<&'a str>,
+        F: Fn(Vec<Value>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static;
+
     unsafe fn connect_unsafe<'a, N, F>(
         &self,
         signal_name: N,
@@ -1349,9 +1427,84 @@ pub trait ObjectExt: ObjectType {
         name: Option<&str>,
         f: F,
     ) -> SignalHandlerId;
+    /// Connects `f` to the `notify` signal, coalescing bursts of notifications.
+    ///
+    /// `f` is called once no further notification was emitted for `delay`. The timeout is
+    /// handled by the thread default main context at the time of connecting, which has to be
+    /// owned by the current thread.
+    ///
+    /// Disconnecting the returned handler also cancels any pending call of `f`.
+    fn connect_notify_debounced<F: Fn(&Self) + 'static>(
+        &self,
+        name: Option<&str>,
+        delay: std::time::Duration,
+        f: F,
+    ) -> SignalHandlerId;
     fn notify<'a, N: Into<&'a str>>(&self, property_name: N);
+    /// Same as `notify` but takes a `ParamSpec` instead of a property name.
+    ///
+    /// This avoids looking up the property by name, e.g. when a subclass notifies about changes
+    /// of its own properties with the `ParamSpec`s returned by `ObjectImpl::properties()`.
     fn notify_by_pspec(&self, pspec: &crate::ParamSpec);
 
+    /// Freezes property change notifications of the object until the returned guard is dropped.
+    ///
+    /// Notifications emitted in the meantime are queued and emitted once per property when the
+    /// guard is dropped.
+    fn freeze_notify(&self) -> PropertyNotificationFreezeGuard;
+
+    /// Adds a toggle reference to the object, which is removed again when the returned guard is
+    /// dropped.
+    ///
+    /// A toggle reference is a strong reference that calls `callback` with `true` whenever it
+    /// becomes the last reference to the object, and with `false` once another reference is
+    /// added again. This allows caches to keep objects alive while they are used elsewhere and
+    /// to release them once the cache is the only user left.
+    ///
+    /// `callback` is called on the thread adding or removing the other reference and must not
+    /// drop the guard itself, e.g. it could schedule an idle callback doing that instead.
+    #[doc(alias = "g_object_add_toggle_ref")]
+    fn add_toggle_ref<F: Fn(&Self, bool) + Send + Sync + 'static>(
+        &self,
+        callback: F,
+    ) -> ToggleRefGuard<Self>;
+
+    /// Like [`add_toggle_ref`](ObjectExt::add_toggle_ref) but `callback` doesn't have to be
+    /// `Send` or `Sync`, e.g. for caches based on `Rc`.
+    ///
+    /// The returned guard has to stay on the thread that added the toggle reference. References
+    /// added or removed on other threads don't call `callback` but print a critical warning.
+    #[doc(alias = "g_object_add_toggle_ref")]
+    fn add_toggle_ref_local<F: Fn(&Self, bool) + 'static>(
+        &self,
+        callback: F,
+    ) -> LocalToggleRefGuard<Self>;
+
+    /// Calls `callback` once the object is disposed, which usually happens right before it is
+    /// finalized.
+    ///
+    /// The notification can be cancelled with the returned handle as long as the object is
+    /// alive. Dropping the handle keeps the notification.
+    #[doc(alias = "g_object_weak_ref")]
+    fn add_weak_ref_notify<F: FnOnce() + Send + 'static>(&self, callback: F) -> WeakRefNotify;
+
+    /// Like [`add_weak_ref_notify`](ObjectExt::add_weak_ref_notify) but `callback` doesn't
+    /// have to be `Send`.
+    ///
+    /// The object has to be disposed on the thread that added the notification. Otherwise
+    /// `callback` is neither called nor dropped and a critical warning is printed.
+    #[doc(alias = "g_object_weak_ref")]
+    fn add_weak_ref_notify_local<F: FnOnce() + 'static>(&self, callback: F) -> LocalWeakRefNotify;
+
+    /// Disposes the object while it is still referenced, making it release all references it
+    /// holds to other objects.
+    ///
+    /// This can be used to break reference cycles explicitly. The object stays valid until its
+    /// last reference is dropped, but afterwards it might not be usable anymore. All signal
+    /// handlers are disconnected and the weak reference notifications are called.
+    #[doc(alias = "g_object_run_dispose")]
+    fn run_dispose(&self);
+
     fn downgrade(&self) -> WeakRef<Self>;
 
     fn bind_property<'a, O: ObjectType, N: Into<&'a str>, M: Into<&'a str>>(
@@ -1492,30 +1645,12 @@ impl<T: ObjectType> ObjectExt for T {
         property_name: N,
         value: &V,
     ) -> Result<(), BoolError> {
-        let property_name = property_name.into();
-
-        let pspec = match self.find_property(property_name) {
-            Some(pspec) => pspec,
-            None => {
-                return Err(bool_error!(
-                    "property '{}' of type '{}' not found",
-                    property_name,
-                    self.get_type()
-                ));
-            }
-        };
-
-        let mut property_value = value.to_value();
-        validate_property_type(self.get_type(), false, &pspec, &mut property_value)?;
-        unsafe {
-            gobject_ffi::g_object_set_property(
-                self.as_object_ref().to_glib_none().0,
-                property_name.to_glib_none().0,
-                property_value.to_glib_none().0,
-            );
-        }
-
-        Ok(())
+        let pspec = find_property_checked(
+            self.get_object_class(),
+            self.get_type(),
+            property_name.into(),
+        )?;
+        self.set_property_from_pspec(&pspec, value)
     }
 
     fn set_property_from_value<'a, N: Into<&'a str>>(
@@ -1523,25 +1658,44 @@ impl<T: ObjectType> ObjectExt for T {
         property_name: N,
         value: &Value,
     ) -> Result<(), BoolError> {
-        let property_name = property_name.into();
+        let pspec = find_property_checked(
+            self.get_object_class(),
+            self.get_type(),
+            property_name.into(),
+        )?;
+        self.set_property_value_from_pspec(&pspec, value)
+    }
 
-        let pspec = match self.find_property(property_name) {
-            Some(pspec) => pspec,
-            None => {
-                return Err(bool_error!(
-                    "property '{}' of type '{}' not found",
-                    property_name,
-                    self.get_type()
-                ));
-            }
-        };
+    fn get_property<'a, N: Into<&'a str>>(&self, property_name: N) -> Result<Value, BoolError> {
+        let pspec = find_property_checked(
+            self.get_object_class(),
+            self.get_type(),
+            property_name.into(),
+        )?;
+        self.get_property_from_pspec(&pspec)
+    }
+
+    fn set_property_from_pspec<V: ToValue>(
+        &self,
+        pspec: &crate::ParamSpec,
+        value: &V,
+    ) -> Result<(), BoolError> {
+        self.set_property_value_from_pspec(pspec, &value.to_value())
+    }
+
+    fn set_property_value_from_pspec(
+        &self,
+        pspec: &crate::ParamSpec,
+        value: &Value,
+    ) -> Result<(), BoolError> {
+        validate_property_owner(self.get_type(), pspec)?;
 
         let mut property_value = value.clone();
-        validate_property_type(self.get_type(), false, &pspec, &mut property_value)?;
+        validate_property_type(self.get_type(), false, pspec, &mut property_value)?;
         unsafe {
             gobject_ffi::g_object_set_property(
                 self.as_object_ref().to_glib_none().0,
-                property_name.to_glib_none().0,
+                gobject_ffi::g_param_spec_get_name(pspec.to_glib_none().0),
                 property_value.to_glib_none().0,
             );
         }
@@ -1549,24 +1703,13 @@ impl<T: ObjectType> ObjectExt for T {
         Ok(())
     }
 
-    fn get_property<'a, N: Into<&'a str>>(&self, property_name: N) -> Result<Value, BoolError> {
-        let property_name = property_name.into();
-
-        let pspec = match self.find_property(property_name) {
-            Some(pspec) => pspec,
-            None => {
-                return Err(bool_error!(
-                    "property '{}' of type '{}' not found",
-                    property_name,
-                    self.get_type()
-                ));
-            }
-        };
+    fn get_property_from_pspec(&self, pspec: &crate::ParamSpec) -> Result<Value, BoolError> {
+        validate_property_owner(self.get_type(), pspec)?;
 
         if !pspec.get_flags().contains(crate::ParamFlags::READABLE) {
             return Err(bool_error!(
                 "property '{}' of type '{}' is not readable",
-                property_name,
+                pspec.get_name(),
                 self.get_type()
             ));
         }
@@ -1575,7 +1718,7 @@ impl<T: ObjectType> ObjectExt for T {
             let mut value = Value::from_type(pspec.get_value_type());
             gobject_ffi::g_object_get_property(
                 self.as_object_ref().to_glib_none().0,
-                property_name.to_glib_none().0,
+                gobject_ffi::g_param_spec_get_name(pspec.to_glib_none().0),
                 value.to_glib_none_mut().0,
             );
 
@@ -1583,7 +1726,7 @@ impl<T: ObjectType> ObjectExt for T {
             Some(value).filter(|v| v.type_().is_valid()).ok_or_else(|| {
                 bool_error!(
                     "Failed to get property value for property '{}' of type '{}'",
-                    property_name,
+                    pspec.get_name(),
                     self.get_type()
                 )
             })
@@ -1730,15 +1873,65 @@ impl<T: ObjectType> ObjectExt for T {
         )
     }
 
+    fn connect_notify_debounced<F: Fn(&Self) + 'static>(
+        &self,
+        name: Option<&str>,
+        delay: std::time::Duration,
+        f: F,
+    ) -> SignalHandlerId {
+        struct Debounce<F> {
+            func: F,
+            pending: RefCell<Option<crate::Source>>,
+        }
+
+        impl<F> Drop for Debounce<F> {
+            fn drop(&mut self) {
+                if let Some(source) = self.pending.borrow_mut().take() {
+                    source.destroy();
+                }
+            }
+        }
+
+        let context = crate::MainContext::ref_thread_default();
+        assert!(
+            context.is_owner(),
+            "Current thread does not own the thread default main context"
+        );
+
+        let debounce = Rc::new(Debounce {
+            func: f,
+            pending: RefCell::new(None),
+        });
+
+        self.connect_notify_local(name, move |obj, _| {
+            if let Some(source) = debounce.pending.borrow_mut().take() {
+                source.destroy();
+            }
+
+            let state = crate::ThreadGuard::new((Rc::downgrade(&debounce), obj.downgrade()));
+            let source =
+                crate::timeout_source_new(delay, None, crate::PRIORITY_DEFAULT, move || {
+                    let (debounce, obj) = state.get_ref();
+                    if let (Some(debounce), Some(obj)) = (debounce.upgrade(), obj.upgrade()) {
+                        debounce.pending.borrow_mut().take();
+                        (debounce.func)(&obj);
+                    }
+                    crate::Continue(false)
+                });
+            source.attach(Some(&context));
+            *debounce.pending.borrow_mut() = Some(source);
+        })
+    }
+
     fn notify<'a, N: Into<&'a str>>(&self, property_name: N) {
         let property_name = property_name.into();
 
-        unsafe {
+        run_with_c_str(property_name, |property_name| unsafe {
             gobject_ffi::g_object_notify(
                 self.as_object_ref().to_glib_none().0,
-                property_name.to_glib_none().0,
+                property_name.as_ptr(),
             );
-        }
+        })
     }
 
     fn notify_by_pspec(&self, pspec: &crate::ParamSpec) {
@@ -1750,6 +1943,90 @@ impl<T: ObjectType> ObjectExt for T {
         }
     }
 
+    fn freeze_notify(&self) -> PropertyNotificationFreezeGuard {
+        let object = self.as_object_ref().clone();
+        unsafe {
+            gobject_ffi::g_object_freeze_notify(object.inner.as_ptr());
+        }
+        PropertyNotificationFreezeGuard(object)
+    }
+
+    fn add_toggle_ref<F: Fn(&Self, bool) + Send + Sync + 'static>(
+        &self,
+        callback: F,
+    ) -> ToggleRefGuard<Self> {
+        unsafe {
+            ToggleRefGuard::new(
+                self,
+                Box::new(move |obj: &Object, is_last_ref| {
+                    callback(obj.unsafe_cast_ref(), is_last_ref)
+                }),
+            )
+        }
+    }
+
+    fn add_toggle_ref_local<F: Fn(&Self, bool) + 'static>(
+        &self,
+        callback: F,
+    ) -> LocalToggleRefGuard<Self> {
+        let callback = crate::ThreadGuard::new(callback);
+        let guard = unsafe {
+            ToggleRefGuard::new(
+                self,
+                Box::new(move |obj: &Object, is_last_ref| {
+                    if !callback.is_owner() {
+                        crate::g_critical!(
+                            "GLib-GObject",
+                            "Toggle reference of {} notified on a different thread than where it was added",
+                            obj.get_type()
+                        );
+                        return;
+                    }
+                    (callback.get_ref())(obj.unsafe_cast_ref(), is_last_ref)
+                }),
+            )
+        };
+        LocalToggleRefGuard(guard, PhantomData)
+    }
+
+    fn add_weak_ref_notify<F: FnOnce() + Send + 'static>(&self, callback: F) -> WeakRefNotify {
+        let callback: Box<dyn FnOnce() + Send + 'static> = Box::new(callback);
+        let callback = Arc::new(Mutex::new(Some(callback)));
+
+        unsafe {
+            gobject_ffi::g_object_weak_ref(
+                self.as_object_ref().to_glib_none().0,
+                Some(weak_ref_notify_trampoline),
+                Arc::into_raw(callback.clone()) as ffi::gpointer,
+            );
+        }
+
+        WeakRefNotify {
+            object: self.as_object_ref().downgrade(),
+            callback,
+        }
+    }
+
+    fn add_weak_ref_notify_local<F: FnOnce() + 'static>(&self, callback: F) -> LocalWeakRefNotify {
+        let callback = crate::ThreadGuard::new(Some(callback));
+
+        let notify = self.add_weak_ref_notify(move || {
+            let mut callback = callback;
+            // Panicking here would unwind into the weak reference trampoline, and dropping the
+            // callback on this thread would panic too
+            if !callback.is_owner() {
+                crate::g_critical!(
+                    "GLib-GObject",
+                    "Object with a local weak reference notification disposed on a different thread than where it was added"
+                );
+                mem::forget(callback);
+                return;
+            }
+            (callback.get_mut().take().unwrap())()
+        });
+        LocalWeakRefNotify(notify, PhantomData)
+    }
+
     fn has_property<'a, N: Into<&'a str>>(&self, property_name: N, type_: Option<Type>) -> bool {
         self.get_object_class().has_property(property_name, type_)
     }
@@ -1830,6 +2107,95 @@ impl<T: ObjectType> ObjectExt for T {
         }
     }
 
+    fn connect_local_async<'a, N, F, Fut>(
+        &self,
+        signal_name: N,
+        after: bool,
+        callback: F,
+    ) -> Result<SignalHandlerId, BoolError>
+    where
+        N: Into<&'a str>,
+        F: Fn(Vec<Value>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.connect_local_async_limited(signal_name, after, usize::MAX, callback)
+    }
+
+    fn connect_local_async_limited<'a, N, F, Fut>(
+        &self,
+        signal_name: N,
+        after: bool,
+        max_concurrent: usize,
+        callback: F,
+    ) -> Result<SignalHandlerId, BoolError>
+    where
+        N: Into<&'a str>,
+        F: Fn(Vec<Value>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        struct AsyncHandler<F> {
+            callback: F,
+            running: Cell<usize>,
+            queue: RefCell<VecDeque<Vec<Value>>>,
+        }
+
+        // Decrements the number of running futures also if a future is dropped before it
+        // completed, e.g. together with its main context
+        struct Running<F>(Rc<AsyncHandler<F>>);
+
+        impl<F> Drop for Running<F> {
+            fn drop(&mut self) {
+                self.0.running.set(self.0.running.get() - 1);
+            }
+        }
+
+        assert!(max_concurrent > 0, "max_concurrent must be at least 1");
+
+        let signal_name: &str = signal_name.into();
+        let type_ = self.get_type();
+        let (signal_id, details) = SignalId::parse_name(signal_name, type_, true)
+            .ok_or_else(|| bool_error!("Signal '{}' of type '{}' not found", signal_name, type_))?;
+        let return_type: Type = signal_id.query().return_type().into();
+        if return_type != Type::UNIT {
+            return Err(bool_error!(
+                "Signal '{}' of type '{}' has a return value and can't be handled asynchronously",
+                signal_name,
+                type_
+            ));
+        }
+
+        let context = crate::MainContext::ref_thread_default();
+        assert!(
+            context.is_owner(),
+            "Current thread does not own the thread default main context"
+        );
+
+        let handler = Rc::new(AsyncHandler {
+            callback,
+            running: Cell::new(0),
+            queue: RefCell::new(VecDeque::new()),
+        });
+
+        self.connect_local_id(signal_id, Some(details), after, move |values| {
+            if handler.running.get() >= max_concurrent {
+                handler.queue.borrow_mut().push_back(values.to_vec());
+                return None;
+            }
+
+            handler.running.set(handler.running.get() + 1);
+            let running = Running(handler.clone());
+            let mut args = Some(values.to_vec());
+            context.spawn_local(async move {
+                let handler = &running.0;
+                while let Some(values) = args.take() {
+                    (handler.callback)(values).await;
+                    args = handler.queue.borrow_mut().pop_front();
+                }
+            });
+            None
+        })
+    }
+
     unsafe fn connect_unsafe<'a, N, F>(
         &self,
         signal_name: N,
@@ -1952,45 +2318,13 @@ impl<T: ObjectType> ObjectExt for T {
     }
 
     fn emit(&self, signal_id: SignalId, args: &[&dyn ToValue]) -> Result<Option<Value>, BoolError> {
-        let signal_query = signal_id.query();
-        unsafe {
-            let type_ = self.get_type();
-
-            let self_v = {
-                let mut v = Value::uninitialized();
-                gobject_ffi::g_value_init(v.to_glib_none_mut().0, self.get_type().to_glib());
-                gobject_ffi::g_value_set_object(
-                    v.to_glib_none_mut().0,
-                    self.as_object_ref().to_glib_none().0,
-                );
-                v
-            };
-
-            let mut args = Iterator::chain(
-                std::iter::once(self_v),
-                args.iter().copied().map(ToValue::to_value),
-            )
-            .collect::<smallvec::SmallVec<[_; 10]>>();
-
-            let signal_detail = validate_signal_arguments(type_, &signal_query, &mut args[1..])?;
-
-            let mut return_value = Value::uninitialized();
-            if signal_query.return_type() != Type::UNIT {
-                gobject_ffi::g_value_init(
-                    return_value.to_glib_none_mut().0,
-                    signal_query.return_type().to_glib(),
-                );
-            }
-
-            gobject_ffi::g_signal_emitv(
-                mut_override(args.as_ptr()) as *mut gobject_ffi::GValue,
-                signal_id.to_glib(),
-                signal_detail.to_glib(),
-                return_value.to_glib_none_mut().0,
-            );
-
-            Ok(Some(return_value).filter(|r| r.type_().is_valid() && r.type_() != Type::UNIT))
-        }
+        emit_signal(
+            self.as_object_ref(),
+            self.get_type(),
+            signal_id,
+            None,
+            args.iter().copied().map(ToValue::to_value),
+        )
     }
 
     fn emit_with_details(
@@ -1999,47 +2333,13 @@ impl<T: ObjectType> ObjectExt for T {
         details: Quark,
         args: &[&dyn ToValue],
     ) -> Result<Option<Value>, BoolError> {
-        let signal_query = signal_id.query();
-        assert!(signal_query.flags().contains(crate::SignalFlags::DETAILED));
-
-        unsafe {
-            let type_ = self.get_type();
-
-            let self_v = {
-                let mut v = Value::uninitialized();
-                gobject_ffi::g_value_init(v.to_glib_none_mut().0, self.get_type().to_glib());
-                gobject_ffi::g_value_set_object(
-                    v.to_glib_none_mut().0,
-                    self.as_object_ref().to_glib_none().0,
-                );
-                v
-            };
-
-            let mut args = Iterator::chain(
-                std::iter::once(self_v),
-                args.iter().copied().map(ToValue::to_value),
-            )
-            .collect::<smallvec::SmallVec<[_; 10]>>();
-
-            validate_signal_arguments(type_, &signal_query, &mut args)?;
-
-            let mut return_value = Value::uninitialized();
-            if signal_query.return_type() != Type::UNIT {
-                gobject_ffi::g_value_init(
-                    return_value.to_glib_none_mut().0,
-                    signal_query.return_type().to_glib(),
-                );
-            }
-
-            gobject_ffi::g_signal_emitv(
-                mut_override(args.as_ptr()) as *mut gobject_ffi::GValue,
-                signal_id.to_glib(),
-                details.to_glib(),
-                return_value.to_glib_none_mut().0,
-            );
-
-            Ok(Some(return_value).filter(|r| r.type_().is_valid() && r.type_() != Type::UNIT))
-        }
+        emit_signal(
+            self.as_object_ref(),
+            self.get_type(),
+            signal_id,
+            Some(details),
+            args.iter().copied().map(ToValue::to_value),
+        )
     }
 
     fn emit_by_name<'a, N: Into<&'a str>>(
@@ -2054,6 +2354,12 @@ impl<T: ObjectType> ObjectExt for T {
         self.emit(signal_id, args)
     }
 
+    fn run_dispose(&self) {
+        unsafe {
+            gobject_ffi::g_object_run_dispose(self.as_object_ref().to_glib_none().0);
+        }
+    }
+
     fn downgrade(&self) -> WeakRef<T> {
         unsafe {
             let w = WeakRef(Box::pin(mem::zeroed()), PhantomData);
@@ -2089,43 +2395,13 @@ impl<T: ObjectType> ObjectExt for T {
         signal_id: SignalId,
         args: &[Value],
     ) -> Result<Option<Value>, BoolError> {
-        unsafe {
-            let type_ = self.get_type();
-
-            let signal_query = signal_id.query();
-
-            let self_v = {
-                let mut v = Value::uninitialized();
-                gobject_ffi::g_value_init(v.to_glib_none_mut().0, self.get_type().to_glib());
-                gobject_ffi::g_value_set_object(
-                    v.to_glib_none_mut().0,
-                    self.as_object_ref().to_glib_none().0,
-                );
-                v
-            };
-
-            let mut args = Iterator::chain(std::iter::once(self_v), args.iter().cloned())
-                .collect::<smallvec::SmallVec<[_; 10]>>();
-
-            let signal_detail = validate_signal_arguments(type_, &signal_query, &mut args[1..])?;
-
-            let mut return_value = Value::uninitialized();
-            if signal_query.return_type() != Type::UNIT {
-                gobject_ffi::g_value_init(
-                    return_value.to_glib_none_mut().0,
-                    signal_query.return_type().to_glib(),
-                );
-            }
-
-            gobject_ffi::g_signal_emitv(
-                mut_override(args.as_ptr()) as *mut gobject_ffi::GValue,
-                signal_id.to_glib(),
-                signal_detail.to_glib(),
-                return_value.to_glib_none_mut().0,
-            );
-
-            Ok(Some(return_value).filter(|r| r.type_().is_valid() && r.type_() != Type::UNIT))
-        }
+        emit_signal(
+            self.as_object_ref(),
+            self.get_type(),
+            signal_id,
+            None,
+            args.iter().cloned(),
+        )
     }
 
     fn emit_by_name_with_values<'a, N: Into<&'a str>>(
@@ -2146,44 +2422,89 @@ impl<T: ObjectType> ObjectExt for T {
         details: Quark,
         args: &[Value],
     ) -> Result<Option<Value>, BoolError> {
-        let signal_query = signal_id.query();
-        assert!(signal_query.flags().contains(crate::SignalFlags::DETAILED));
+        emit_signal(
+            self.as_object_ref(),
+            self.get_type(),
+            signal_id,
+            Some(details),
+            args.iter().cloned(),
+        )
+    }
+}
 
-        unsafe {
-            let type_ = self.get_type();
+// Number of signal arguments (including the instance) that are marshalled without a heap
+// allocation
+const MAX_STACK_SIGNAL_ARGS: usize = 10;
 
-            let self_v = {
-                let mut v = Value::uninitialized();
-                gobject_ffi::g_value_init(v.to_glib_none_mut().0, self.get_type().to_glib());
-                gobject_ffi::g_value_set_object(
-                    v.to_glib_none_mut().0,
-                    self.as_object_ref().to_glib_none().0,
-                );
-                v
-            };
+// Emit the signal with the given arguments. The argument values are collected together with the
+// instance into a stack-allocated array if there are not too many of them, and validated against
+// the already known signal query instead of looking up the signal by name again
+fn emit_signal(
+    obj: &ObjectRef,
+    type_: Type,
+    signal_id: SignalId,
+    details: Option<Quark>,
+    args: impl Iterator<Item = Value>,
+) -> Result<Option<Value>, BoolError> {
+    let signal_query = signal_id.query();
+    if details.is_some() {
+        assert!(signal_query.flags().contains(crate::SignalFlags::DETAILED));
+    }
 
-            let mut args = Iterator::chain(std::iter::once(self_v), args.iter().cloned())
-                .collect::<smallvec::SmallVec<[_; 10]>>();
+    unsafe {
+        let self_v = {
+            let mut v = Value::uninitialized();
+            gobject_ffi::g_value_init(v.to_glib_none_mut().0, type_.to_glib());
+            gobject_ffi::g_value_set_object(v.to_glib_none_mut().0, obj.to_glib_none().0);
+            v
+        };
 
-            validate_signal_arguments(type_, &signal_query, &mut args)?;
+        let mut args = Iterator::chain(std::iter::once(self_v), args)
+            .collect::<smallvec::SmallVec<[_; MAX_STACK_SIGNAL_ARGS]>>();
 
-            let mut return_value = Value::uninitialized();
-            if signal_query.return_type() != Type::UNIT {
-                gobject_ffi::g_value_init(
-                    return_value.to_glib_none_mut().0,
-                    signal_query.return_type().to_glib(),
-                );
-            }
+        validate_signal_arguments(type_, &signal_query, &mut args[1..])?;
 
-            gobject_ffi::g_signal_emitv(
-                mut_override(args.as_ptr()) as *mut gobject_ffi::GValue,
-                signal_id.to_glib(),
-                details.to_glib(),
+        let mut return_value = Value::uninitialized();
+        if signal_query.return_type() != Type::UNIT {
+            gobject_ffi::g_value_init(
                 return_value.to_glib_none_mut().0,
+                signal_query.return_type().to_glib(),
             );
-
-            Ok(Some(return_value).filter(|r| r.type_().is_valid() && r.type_() != Type::UNIT))
         }
+
+        gobject_ffi::g_signal_emitv(
+            mut_override(args.as_ptr()) as *mut gobject_ffi::GValue,
+            signal_id.to_glib(),
+            details.map_or(0, |d| d.to_glib()),
+            return_value.to_glib_none_mut().0,
+        );
+
+        Ok(Some(return_value).filter(|r| r.type_().is_valid() && r.type_() != Type::UNIT))
+    }
+}
+
+// Look up the property with the given name and return an error if it doesn't exist
+fn find_property_checked(
+    klass: &ObjectClass,
+    type_: Type,
+    property_name: &str,
+) -> Result<crate::ParamSpec, BoolError> {
+    klass
+        .find_property(property_name)
+        .ok_or_else(|| bool_error!("property '{}' of type '{}' not found", property_name, type_))
+}
+
+// Validate that the given pspec is a property of the given type or one of its interfaces
+fn validate_property_owner(type_: Type, pspec: &crate::ParamSpec) -> Result<(), BoolError> {
+    if type_.is_a(pspec.get_owner_type()) {
+        Ok(())
+    } else {
+        Err(bool_error!(
+            "property '{}' of type '{}' is not a property of type '{}'",
+            pspec.get_name(),
+            pspec.get_owner_type(),
+            type_
+        ))
     }
 }
 
@@ -2278,12 +2599,8 @@ fn validate_signal_arguments(
     type_: Type,
     signal_query: &SignalQuery,
     args: &mut [Value],
-) -> Result<Quark, BoolError> {
+) -> Result<(), BoolError> {
     let signal_name = signal_query.signal_name();
-    let (signal_id, signal_detail) = SignalId::parse_name(signal_name, type_, false)
-        .ok_or_else(|| bool_error!("Signal '{}' of type '{}' not found", signal_name, type_))?;
-
-    let signal_query = signal_id.query();
 
     if signal_query.n_params() != args.len() as u32 {
         return Err(bool_error!(
@@ -2337,7 +2654,7 @@ fn validate_signal_arguments(
         }
     }
 
-    Ok(signal_detail)
+    Ok(())
 }
 
 impl ObjectClass {
@@ -2366,14 +2683,14 @@ impl ObjectClass {
         property_name: N,
     ) -> Option<crate::ParamSpec> {
         let property_name = property_name.into();
-        unsafe {
+        run_with_c_str(property_name, |property_name| unsafe {
             let klass = self as *const _ as *const gobject_ffi::GObjectClass;
 
             from_glib_none(gobject_ffi::g_object_class_find_property(
                 klass as *mut _,
-                property_name.to_glib_none().0,
+                property_name.as_ptr(),
             ))
-        }
+        })
     }
 
     pub fn list_properties(&self) -> Vec<crate::ParamSpec> {
@@ -2387,6 +2704,26 @@ impl ObjectClass {
             FromGlibContainer::from_glib_container_num(props, n_properties as usize)
         }
     }
+
+    /// Lists the signals of this class, including the ones of its ancestors and of the
+    /// interfaces it implements.
+    #[doc(alias = "g_signal_list_ids")]
+    pub fn list_signals(&self) -> Vec<SignalQuery> {
+        let type_ = self.get_type();
+        let mut types = Vec::new();
+        let mut ancestor = Some(type_);
+        while let Some(t) = ancestor {
+            types.push(t);
+            ancestor = t.parent();
+        }
+        types.extend(type_.interfaces());
+
+        types
+            .into_iter()
+            .flat_map(SignalId::list)
+            .map(|signal_id| signal_id.query())
+            .collect()
+    }
 }
 
 wrapper! {
@@ -2515,6 +2852,194 @@ impl<T: ObjectType> From<WeakRef<T>> for SendWeakRef<T> {
 unsafe impl<T: ObjectType> Sync for SendWeakRef<T> {}
 unsafe impl<T: ObjectType> Send for SendWeakRef<T> {}
 
+/// Guard returned by [`ObjectExt::freeze_notify`] that thaws the property change notifications
+/// of the object when dropped.
+#[derive(Debug)]
+#[must_use = "if unused the property notifications will immediately be thawed"]
+pub struct PropertyNotificationFreezeGuard(ObjectRef);
+
+impl Drop for PropertyNotificationFreezeGuard {
+    fn drop(&mut self) {
+        unsafe {
+            gobject_ffi::g_object_thaw_notify(self.0.inner.as_ptr());
+        }
+    }
+}
+
+// Only `add_toggle_ref_local` passes callbacks that aren't `Send` and `Sync`, behind a
+// `ThreadGuard` and with a guard that can't be sent to other threads
+type ToggleNotifyCallback = dyn Fn(&Object, bool) + 'static;
+
+unsafe extern "C" fn toggle_notify_trampoline(
+    data: ffi::gpointer,
+    object: *mut gobject_ffi::GObject,
+    is_last_ref: ffi::gboolean,
+) {
+    let callback = &*(data as *const Box<ToggleNotifyCallback>);
+    let object: Borrowed<Object> = from_glib_borrow(object);
+    callback(&object, from_glib(is_last_ref));
+}
+
+type WeakRefNotifyCallback = Mutex<Option<Box<dyn FnOnce() + Send + 'static>>>;
+
+unsafe extern "C" fn weak_ref_notify_trampoline(
+    data: ffi::gpointer,
+    _object: *mut gobject_ffi::GObject,
+) {
+    let callback = Arc::from_raw(data as *const WeakRefNotifyCallback);
+    // Don't hold the lock while calling the callback
+    let f = callback.lock().unwrap().take();
+    if let Some(f) = f {
+        f();
+    }
+}
+
+/// An object borrowed from a [`Value`] without taking a new reference to it.
+///
+/// Returned by [`Value::get_object_borrow`](crate::Value::get_object_borrow).
+#[derive(Debug)]
+pub struct BorrowedObject<'a, T> {
+    object: Borrowed<T>,
+    phantom: PhantomData<&'a Value>,
+}
+
+impl<'a, T: ObjectType> BorrowedObject<'a, T> {
+    // `ptr` has to be kept alive by a value that is borrowed for `'a`
+    pub(crate) unsafe fn new(ptr: *mut GObject) -> Self {
+        let object = from_glib_borrow::<_, ObjectRef>(ptr).into_inner();
+        BorrowedObject {
+            object: Borrowed::new(T::unsafe_from(object)),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> ops::Deref for BorrowedObject<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.object
+    }
+}
+
+/// Handle returned by [`ObjectExt::add_weak_ref_notify`] for cancelling the notification.
+pub struct WeakRefNotify {
+    object: WeakRef<Object>,
+    callback: Arc<WeakRefNotifyCallback>,
+}
+
+impl WeakRefNotify {
+    /// Cancels the notification unless the object was already disposed.
+    #[doc(alias = "g_object_weak_unref")]
+    pub fn disconnect(self) {
+        let object = match self.object.upgrade() {
+            Some(object) => object,
+            None => return,
+        };
+
+        let mut pending = self.callback.lock().unwrap();
+        if pending.take().is_some() {
+            unsafe {
+                let data = Arc::as_ptr(&self.callback);
+                gobject_ffi::g_object_weak_unref(
+                    object.as_ptr(),
+                    Some(weak_ref_notify_trampoline),
+                    data as ffi::gpointer,
+                );
+                // The reference owned by the removed notification
+                drop(Arc::from_raw(data));
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WeakRefNotify {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WeakRefNotify")
+            .field("object", &self.object)
+            .finish()
+    }
+}
+
+/// Handle returned by [`ObjectExt::add_weak_ref_notify_local`] for cancelling the notification.
+///
+/// Unlike [`WeakRefNotify`] it can't be sent to other threads, as cancelling the notification
+/// drops the callback.
+#[derive(Debug)]
+pub struct LocalWeakRefNotify(WeakRefNotify, PhantomData<*const ()>);
+
+impl LocalWeakRefNotify {
+    /// Cancels the notification unless the object was already disposed.
+    #[doc(alias = "g_object_weak_unref")]
+    pub fn disconnect(self) {
+        self.0.disconnect();
+    }
+}
+
+/// Guard returned by [`ObjectExt::add_toggle_ref`] that removes the toggle reference when
+/// dropped.
+///
+/// The guard holds a strong reference to the object, which is released together with the
+/// toggle reference.
+#[must_use = "if unused the toggle reference will immediately be removed"]
+pub struct ToggleRefGuard<T: ObjectType> {
+    object: ptr::NonNull<gobject_ffi::GObject>,
+    callback: *mut Box<ToggleNotifyCallback>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ObjectType> ToggleRefGuard<T> {
+    // The callback has to be `Send` and `Sync` unless the guard is wrapped in a
+    // `LocalToggleRefGuard`
+    unsafe fn new(object: &T, callback: Box<ToggleNotifyCallback>) -> Self {
+        let callback = Box::into_raw(Box::new(callback));
+        let object: *mut gobject_ffi::GObject = object.as_object_ref().to_glib_none().0;
+        gobject_ffi::g_object_add_toggle_ref(
+            object,
+            Some(toggle_notify_trampoline),
+            callback as ffi::gpointer,
+        );
+
+        ToggleRefGuard {
+            object: ptr::NonNull::new_unchecked(object),
+            callback,
+            phantom: PhantomData,
+        }
+    }
+}
+
+unsafe impl<T: ObjectType + Send> Send for ToggleRefGuard<T> {}
+unsafe impl<T: ObjectType + Sync> Sync for ToggleRefGuard<T> {}
+
+impl<T: ObjectType> fmt::Debug for ToggleRefGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ToggleRefGuard")
+            .field("object", &self.object)
+            .finish()
+    }
+}
+
+impl<T: ObjectType> Drop for ToggleRefGuard<T> {
+    fn drop(&mut self) {
+        unsafe {
+            gobject_ffi::g_object_remove_toggle_ref(
+                self.object.as_ptr(),
+                Some(toggle_notify_trampoline),
+                self.callback as ffi::gpointer,
+            );
+            drop(Box::from_raw(self.callback));
+        }
+    }
+}
+
+/// Guard returned by [`ObjectExt::add_toggle_ref_local`] that removes the toggle reference when
+/// dropped.
+///
+/// Unlike [`ToggleRefGuard`] it can't be sent to other threads.
+#[derive(Debug)]
+#[must_use = "if unused the toggle reference will immediately be removed"]
+pub struct LocalToggleRefGuard<T: ObjectType>(ToggleRefGuard<T>, PhantomData<*const ()>);
+
 #[derive(Debug)]
 #[must_use]
 pub struct BindingBuilder<'a> {
@@ -2720,6 +3245,64 @@ impl<T: IsClass> Class<T> {
         }
     }
 
+    /// Sets type-specific data for the type of this class.
+    ///
+    /// This allows storing additional data for types that are not defined in Rust, e.g. for
+    /// bindings that have to keep state next to the class' virtual methods. The data stays
+    /// around for the remaining lifetime of the process.
+    ///
+    /// # Panics
+    ///
+    /// If data was already set for `key` on this type.
+    #[doc(alias = "g_type_set_qdata")]
+    pub fn set_type_data<D: std::any::Any + Send + Sync + 'static>(&self, key: Quark, data: D) {
+        // Checking and setting the data has to happen atomically, otherwise the data of one of
+        // two threads setting it at the same time would be overwritten and leaked
+        static LOCK: once_cell::sync::Lazy<std::sync::Mutex<()>> =
+            once_cell::sync::Lazy::new(|| std::sync::Mutex::new(()));
+        let type_ = self.get_type();
+        let is_set = {
+            // Panicking while holding the lock would poison it
+            let _lock = LOCK.lock().expect("Failed to lock type data");
+            unsafe {
+                let is_set =
+                    !gobject_ffi::g_type_get_qdata(type_.to_glib(), key.to_glib()).is_null();
+                if !is_set {
+                    let data: Box<Box<dyn std::any::Any + Send + Sync>> = Box::new(Box::new(data));
+                    gobject_ffi::g_type_set_qdata(
+                        type_.to_glib(),
+                        key.to_glib(),
+                        Box::into_raw(data) as ffi::gpointer,
+                    );
+                }
+                is_set
+            }
+        };
+        if is_set {
+            panic!("Type data for {:?} is already set on {}", key, type_);
+        }
+    }
+
+    /// Gets type-specific data previously set with [`set_type_data`](#method.set_type_data).
+    ///
+    /// Returns `None` if no data is set for `key` on the type of this class or if it is not of
+    /// type `D`.
+    #[doc(alias = "g_type_get_qdata")]
+    pub fn get_type_data<D: std::any::Any + Send + Sync + 'static>(
+        &self,
+        key: Quark,
+    ) -> Option<&D> {
+        unsafe {
+            let ptr = gobject_ffi::g_type_get_qdata(self.get_type().to_glib(), key.to_glib())
+                as *const Box<dyn std::any::Any + Send + Sync>;
+            if ptr.is_null() {
+                None
+            } else {
+                (*ptr).downcast_ref()
+            }
+        }
+    }
+
     /// Gets the parent class struct, if any.
     pub fn parent(&self) -> Option<ClassRef<T>> {
         unsafe {
@@ -2877,6 +3460,29 @@ impl<T: IsInterface> Interface<T> {
         }
     }
 
+    /// Lists the properties declared by this interface.
+    #[doc(alias = "g_object_interface_list_properties")]
+    pub fn list_properties(&self) -> Vec<crate::ParamSpec> {
+        unsafe {
+            let mut n_properties = 0;
+
+            let props = gobject_ffi::g_object_interface_list_properties(
+                &self.0 as *const _ as *mut _,
+                &mut n_properties,
+            );
+            FromGlibContainer::from_glib_container_num(props, n_properties as usize)
+        }
+    }
+
+    /// Lists the signals declared by this interface.
+    #[doc(alias = "g_signal_list_ids")]
+    pub fn list_signals(&self) -> Vec<SignalQuery> {
+        SignalId::list(self.get_type())
+            .into_iter()
+            .map(|signal_id| signal_id.query())
+            .collect()
+    }
+
     /// Gets the parent interface struct, if any.
     ///
     /// This returns the parent interface if a parent type of the instance type also implements the
@@ -2961,4 +3567,178 @@ mod tests {
             assert_eq!(data2, "hello");
         }
     }
+
+    wrapper! {
+        pub struct ClassTraitObject(Object<gobject_ffi::GObject, gobject_ffi::GObjectClass>);
+
+        match fn {
+            get_type => || gobject_ffi::g_object_get_type(),
+        }
+
+        class trait ClassTraitObjectClassExt {
+            fn type_name(&self) -> &'static str {
+                self.get_type().name()
+            }
+
+            fn is_object(&self) -> bool {
+                self.get_type().is_a(Type::OBJECT)
+            }
+        }
+    }
+
+    #[test]
+    fn class_trait() {
+        let klass = Class::<ClassTraitObject>::from_type(ClassTraitObject::static_type()).unwrap();
+        assert_eq!(klass.type_name(), "GObject");
+        assert!(klass.is_object());
+    }
+
+    #[test]
+    fn type_data_race() {
+        use std::sync::{Arc, Barrier};
+
+        let key = Quark::from_string("glib-rs-test-type-data-race");
+        let barrier = Arc::new(Barrier::new(4));
+        let threads = (0..4)
+            .map(|i| {
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    let klass = Class::<Object>::from_type(Object::static_type()).unwrap();
+                    barrier.wait();
+                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        klass.set_type_data::<i32>(key, i)
+                    }))
+                    .is_ok()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let succeeded = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .filter(|succeeded| *succeeded)
+            .count();
+        assert_eq!(succeeded, 1);
+    }
+
+    #[test]
+    fn type_data() {
+        let key = Quark::from_string("glib-rs-test-type-data");
+        let klass = Class::<Object>::from_type(Object::static_type()).unwrap();
+        assert!(klass.get_type_data::<String>(key).is_none());
+
+        klass.set_type_data::<String>(key, "hello".into());
+        assert_eq!(
+            klass.get_type_data::<String>(key).map(String::as_str),
+            Some("hello")
+        );
+        assert!(klass.get_type_data::<i32>(key).is_none());
+    }
+
+    #[test]
+    fn introspection() {
+        let klass = Class::<Object>::from_type(crate::Binding::static_type()).unwrap();
+
+        let properties = klass.list_properties();
+        assert!(properties
+            .iter()
+            .any(|pspec| pspec.get_name() == "source-property"));
+
+        let signals = klass.list_signals();
+        let notify = signals
+            .iter()
+            .find(|query| query.signal_name() == "notify")
+            .expect("no notify signal");
+        assert_eq!(notify.type_(), Object::static_type());
+        assert_eq!(notify.n_params(), 1);
+        assert!(SignalId::list(crate::Binding::static_type()).is_empty());
+    }
+
+    #[test]
+    fn toggle_ref() {
+        let obj: Object = Object::new(&[]).unwrap();
+        let weak = obj.downgrade();
+        let notifications = Arc::new(Mutex::new(Vec::new()));
+        let notifications_clone = notifications.clone();
+        let guard = obj.add_toggle_ref(move |_, is_last_ref| {
+            notifications_clone.lock().unwrap().push(is_last_ref);
+        });
+        assert!(notifications.lock().unwrap().is_empty());
+
+        drop(obj);
+        assert_eq!(*notifications.lock().unwrap(), [true]);
+
+        let obj = weak.upgrade().unwrap();
+        assert_eq!(*notifications.lock().unwrap(), [true, false]);
+        drop(obj);
+        assert_eq!(*notifications.lock().unwrap(), [true, false, true]);
+
+        drop(guard);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn toggle_ref_local() {
+        let obj: Object = Object::new(&[]).unwrap();
+        let weak = obj.downgrade();
+        let notifications = Rc::new(RefCell::new(Vec::new()));
+        let notifications_clone = notifications.clone();
+        let guard = obj.add_toggle_ref_local(move |_, is_last_ref| {
+            notifications_clone.borrow_mut().push(is_last_ref);
+        });
+
+        drop(obj);
+        assert_eq!(*notifications.borrow(), [true]);
+
+        let obj = weak.upgrade().unwrap();
+        drop(obj);
+        assert_eq!(*notifications.borrow(), [true, false, true]);
+
+        drop(guard);
+        assert!(weak.upgrade().is_none());
+        assert_eq!(Rc::strong_count(&notifications), 1);
+    }
+
+    #[test]
+    fn run_dispose() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let disposed = Arc::new(AtomicBool::new(false));
+        let obj: Object = Object::new(&[]).unwrap();
+        let disposed_clone = disposed.clone();
+        obj.add_weak_ref_notify(move || disposed_clone.store(true, Ordering::SeqCst));
+
+        obj.run_dispose();
+        assert!(disposed.load(Ordering::SeqCst));
+        assert_eq!(obj.ref_count(), 1);
+    }
+
+    #[test]
+    fn weak_ref_notify() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let notified = Arc::new(AtomicUsize::new(0));
+
+        let obj: Object = Object::new(&[]).unwrap();
+        let notified_clone = notified.clone();
+        obj.add_weak_ref_notify(move || {
+            notified_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let notified_clone = notified.clone();
+        let handle = obj.add_weak_ref_notify(move || {
+            notified_clone.fetch_add(10, Ordering::SeqCst);
+        });
+        handle.disconnect();
+        drop(obj);
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+        let obj: Object = Object::new(&[]).unwrap();
+        let notified_clone = notified.clone();
+        let handle = obj.add_weak_ref_notify_local(move || {
+            notified_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        drop(obj);
+        assert_eq!(notified.load(Ordering::SeqCst), 2);
+        handle.disconnect();
+    }
 }
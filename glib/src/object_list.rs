@@ -0,0 +1,285 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::object::{Cast, IsA, ObjectExt, ObjectType};
+use crate::Object;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::{fmt, slice, vec};
+
+/// A list of objects of different types that share the common base type `T`.
+///
+/// Objects are upcast to `T` when they are added, and can be retrieved again as any of their
+/// actual types with [`get_as`](Self::get_as) or [`iter_of`](Self::iter_of), e.g. to collect
+/// all entries of a form as `ObjectList<gtk::Widget>` and later iterate over them with
+/// `iter_of::<gtk::Entry>()`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ObjectList<T: ObjectType = Object> {
+    objects: Vec<T>,
+}
+
+impl<T: ObjectType> ObjectList<T> {
+    /// Creates a new, empty list.
+    pub fn new() -> Self {
+        Self {
+            objects: Vec::new(),
+        }
+    }
+
+    /// Creates a new, empty list with space for at least `capacity` objects.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            objects: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends `obj` to the end of the list.
+    pub fn push<U: IsA<T>>(&mut self, obj: &U) {
+        self.objects.push(obj.clone().upcast());
+    }
+
+    /// Inserts `obj` at position `index`, shifting all objects after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert<U: IsA<T>>(&mut self, index: usize, obj: &U) {
+        self.objects.insert(index, obj.clone().upcast());
+    }
+
+    /// Removes and returns the object at position `index`, shifting all objects after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.objects.remove(index)
+    }
+
+    /// Removes all objects of type `U` from the list.
+    pub fn remove_of<U: ObjectType>(&mut self) {
+        self.objects.retain(|obj| !obj.is::<U>());
+    }
+
+    /// Removes all objects from the list.
+    pub fn clear(&mut self) {
+        self.objects.clear();
+    }
+
+    /// Returns the number of objects in the list.
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Returns `true` if the list contains no objects.
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// Returns `true` if the list contains `obj`.
+    pub fn contains<U: IsA<T>>(&self, obj: &U) -> bool {
+        self.objects.iter().any(|o| o == obj.as_ref())
+    }
+
+    /// Returns the object at position `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.objects.get(index)
+    }
+
+    /// Returns the object at position `index` if it is of type `U`.
+    pub fn get_as<U: ObjectType>(&self, index: usize) -> Option<&U> {
+        self.objects
+            .get(index)
+            .and_then(|obj| obj.dynamic_cast_ref::<U>())
+    }
+
+    /// Returns the first object of type `U`.
+    pub fn find_of<U: ObjectType>(&self) -> Option<&U> {
+        self.iter_of::<U>().next()
+    }
+
+    /// Returns an iterator over all objects.
+    pub fn iter(&self) -> slice::Iter<T> {
+        self.objects.iter()
+    }
+
+    /// Returns an iterator over all objects of type `U`, skipping the ones of other types.
+    pub fn iter_of<U: ObjectType>(&self) -> IterOf<T, U> {
+        IterOf {
+            iter: self.objects.iter(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the objects as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.objects
+    }
+}
+
+impl<T: ObjectType> Default for ObjectList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ObjectType> fmt::Debug for ObjectList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.objects.iter()).finish()
+    }
+}
+
+impl<T: ObjectType> AsRef<[T]> for ObjectList<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.objects
+    }
+}
+
+impl<T: ObjectType> From<ObjectList<T>> for Vec<T> {
+    fn from(list: ObjectList<T>) -> Self {
+        list.objects
+    }
+}
+
+impl<T: ObjectType, U: IsA<T>> FromIterator<U> for ObjectList<T> {
+    fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
+        Self {
+            objects: iter.into_iter().map(|obj| obj.upcast()).collect(),
+        }
+    }
+}
+
+impl<T: ObjectType, U: IsA<T>> Extend<U> for ObjectList<T> {
+    fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        self.objects
+            .extend(iter.into_iter().map(|obj| obj.upcast()));
+    }
+}
+
+impl<T: ObjectType> IntoIterator for ObjectList<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.objects.into_iter()
+    }
+}
+
+impl<'a, T: ObjectType> IntoIterator for &'a ObjectList<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.objects.iter()
+    }
+}
+
+/// An iterator over the objects of type `U` in an [`ObjectList`].
+///
+/// Created with [`ObjectList::iter_of`].
+pub struct IterOf<'a, T: ObjectType, U: ObjectType> {
+    iter: slice::Iter<'a, T>,
+    phantom: PhantomData<&'a U>,
+}
+
+impl<'a, T: ObjectType, U: ObjectType> Iterator for IterOf<'a, T, U> {
+    type Item = &'a U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for obj in &mut self.iter {
+            if let Some(obj) = obj.dynamic_cast_ref::<U>() {
+                return Some(obj);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, T: ObjectType, U: ObjectType> DoubleEndedIterator for IterOf<'a, T, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(obj) = self.iter.next_back() {
+            if let Some(obj) = obj.dynamic_cast_ref::<U>() {
+                return Some(obj);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, T: ObjectType, U: ObjectType> fmt::Debug for IterOf<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IterOf")
+            .field("type", &U::static_type())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as glib;
+    use crate::subclass::prelude::*;
+
+    mod imp {
+        use super::*;
+
+        #[derive(Default)]
+        pub struct Sub;
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for Sub {
+            const NAME: &'static str = "ObjectListSub";
+            type Type = super::Sub;
+            type ParentType = Object;
+        }
+
+        impl ObjectImpl for Sub {}
+    }
+
+    wrapper! {
+        pub struct Sub(ObjectSubclass<imp::Sub>);
+    }
+
+    #[test]
+    fn typed_access() {
+        let obj = Object::new::<Object>(&[]).unwrap();
+        let sub = Object::new::<Sub>(&[]).unwrap();
+
+        let mut list = ObjectList::<Object>::new();
+        list.push(&obj);
+        list.push(&sub);
+        list.push(&Object::new::<Object>(&[]).unwrap());
+
+        assert_eq!(list.len(), 3);
+        assert!(list.contains(&sub));
+        assert_eq!(list.get(0), Some(&obj));
+        assert_eq!(list.get_as::<Sub>(1), Some(&sub));
+        assert_eq!(list.get_as::<Sub>(0), None);
+        assert_eq!(list.find_of::<Sub>(), Some(&sub));
+        assert_eq!(list.iter_of::<Object>().count(), 3);
+        assert_eq!(list.iter_of::<Sub>().collect::<Vec<_>>(), vec![&sub]);
+
+        list.remove_of::<Sub>();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter_of::<Sub>().next(), None);
+    }
+
+    #[test]
+    fn collect() {
+        let objs = vec![
+            Object::new::<Sub>(&[]).unwrap(),
+            Object::new::<Sub>(&[]).unwrap(),
+        ];
+
+        let mut list: ObjectList = objs.iter().cloned().collect();
+        list.extend(vec![Object::new::<Object>(&[]).unwrap()]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.iter_of::<Sub>().rev().collect::<Vec<_>>(),
+            vec![&objs[1], &objs[0]]
+        );
+    }
+}
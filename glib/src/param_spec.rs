@@ -1,7 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::object::{Class, Interface, IsA, IsClass, IsInterface};
 use crate::translate::*;
 use crate::value;
+use crate::Object;
 use crate::ParamFlags;
 use crate::StaticType;
 use crate::Type;
@@ -430,6 +432,22 @@ impl ParamSpec {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates a `ParamSpec` overriding the property `name` of the interface `T`.
+    ///
+    /// See [`ParamSpecOverride::for_interface`].
+    pub fn override_interface<T: IsInterface>(name: &str) -> ParamSpec {
+        ParamSpecOverride::for_interface::<T>(name)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a `ParamSpec` overriding the property `name` of the class `T`.
+    ///
+    /// See [`ParamSpecOverride::for_class`].
+    pub fn override_class<T: IsClass + IsA<Object>>(name: &str) -> ParamSpec {
+        ParamSpecOverride::for_class::<T>(name)
+    }
+
     #[doc(alias = "g_param_spec_param")]
     pub fn param(
         name: &str,
@@ -992,6 +1010,61 @@ define_param_spec!(
 );
 
 impl ParamSpecOverride {
+    // rustdoc-stripper-ignore-next
+    /// Creates a `ParamSpec` overriding the property `name` of the interface `T`.
+    ///
+    /// This has to be used by subclasses implementing `T` to install the interface's properties,
+    /// instead of redeclaring them with a full `ParamSpec` that has to match the interface's
+    /// declaration exactly.
+    ///
+    /// # Panics
+    ///
+    /// If `T` has no property named `name`.
+    #[doc(alias = "g_object_interface_find_property")]
+    pub fn for_interface<T: IsInterface>(name: &str) -> ParamSpec {
+        let iface = Interface::<T>::default();
+        let pspec: Option<ParamSpec> = unsafe {
+            from_glib_none(gobject_ffi::g_object_interface_find_property(
+                &*iface as *const Interface<T> as *mut _,
+                name.to_glib_none().0,
+            ))
+        };
+        let pspec = pspec.unwrap_or_else(|| {
+            panic!(
+                "Interface '{}' has no property named '{}'",
+                T::static_type(),
+                name
+            )
+        });
+
+        ParamSpec::override_(name, &pspec)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a `ParamSpec` overriding the property `name` of the class `T`.
+    ///
+    /// This allows subclasses to override the implementation of a property of a parent class.
+    ///
+    /// # Panics
+    ///
+    /// If `T` has no property named `name`.
+    pub fn for_class<T: IsClass + IsA<Object>>(name: &str) -> ParamSpec {
+        let klass = Class::<T>::from_type(T::static_type())
+            .unwrap_or_else(|| panic!("Failed to get class of '{}'", T::static_type()));
+        let pspec = klass
+            .upcast_ref::<Object>()
+            .find_property(name)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Class '{}' has no property named '{}'",
+                    T::static_type(),
+                    name
+                )
+            });
+
+        ParamSpec::override_(name, &pspec)
+    }
+
     pub fn get_overridden(&self) -> ParamSpec {
         unsafe {
             let ptr = self.to_glib_none().0;
@@ -1035,6 +1108,512 @@ impl ParamSpecVariant {
     }
 }
 
+macro_rules! define_param_spec_builder {
+    (
+        $rust_type:ident, $builder_type:ident,
+        new($($arg:ident: $arg_ty:ty),*),
+        { $($field:ident: $field_ty:ty = $field_default:expr),* $(,)? }
+    ) => {
+        #[derive(Debug, Clone)]
+        #[must_use = "the builder has to be built to create the param spec"]
+        pub struct $builder_type<'a> {
+            name: &'a str,
+            nick: Option<&'a str>,
+            blurb: Option<&'a str>,
+            flags: ParamFlags,
+            $($arg: $arg_ty,)*
+            $($field: $field_ty,)*
+        }
+
+        impl<'a> $builder_type<'a> {
+            pub fn new(name: &'a str, $($arg: $arg_ty),*) -> Self {
+                Self {
+                    name,
+                    nick: None,
+                    blurb: None,
+                    flags: ParamFlags::READWRITE,
+                    $($arg,)*
+                    $($field: $field_default,)*
+                }
+            }
+
+            /// Sets the human readable name of the property, which defaults to its name.
+            pub fn nick(mut self, nick: &'a str) -> Self {
+                self.nick = Some(nick);
+                self
+            }
+
+            /// Sets the description of the property, which defaults to an empty string.
+            pub fn blurb(mut self, blurb: &'a str) -> Self {
+                self.blurb = Some(blurb);
+                self
+            }
+
+            /// Sets the flags of the property, which default to [`ParamFlags::READWRITE`].
+            pub fn flags(mut self, flags: ParamFlags) -> Self {
+                self.flags = flags;
+                self
+            }
+
+            fn get_nick(&self) -> &'a str {
+                self.nick.unwrap_or(self.name)
+            }
+
+            fn get_blurb(&self) -> &'a str {
+                self.blurb.unwrap_or("")
+            }
+        }
+
+        impl $rust_type {
+            pub fn builder<'a>(name: &'a str, $($arg: $arg_ty),*) -> $builder_type<'a> {
+                $builder_type::new(name, $($arg),*)
+            }
+        }
+    };
+}
+
+macro_rules! define_param_spec_numeric_builder {
+    ($rust_type:ident, $builder_type:ident, $value_type:ty, $constructor:ident, $alias:literal) => {
+        define_param_spec_builder!(
+            $rust_type,
+            $builder_type,
+            new(),
+            {
+                minimum: $value_type = <$value_type>::MIN,
+                maximum: $value_type = <$value_type>::MAX,
+                default_value: Option<$value_type> = None,
+            }
+        );
+
+        impl<'a> $builder_type<'a> {
+            /// Sets the minimum value, which defaults to the smallest value of the type.
+            pub fn minimum(mut self, minimum: $value_type) -> Self {
+                self.minimum = minimum;
+                self
+            }
+
+            /// Sets the maximum value, which defaults to the largest value of the type.
+            pub fn maximum(mut self, maximum: $value_type) -> Self {
+                self.maximum = maximum;
+                self
+            }
+
+            /// Sets the default value, which defaults to zero, or the value closest to zero
+            /// between the minimum and maximum.
+            pub fn default_value(mut self, default_value: $value_type) -> Self {
+                self.default_value = Some(default_value);
+                self
+            }
+
+            #[doc(alias = $alias)]
+            pub fn build(self) -> ParamSpec {
+                let default_value = self.default_value.unwrap_or_else(|| {
+                    clamp_default(<$value_type>::default(), self.minimum, self.maximum)
+                });
+                ParamSpec::$constructor(
+                    self.name,
+                    self.get_nick(),
+                    self.get_blurb(),
+                    self.minimum,
+                    self.maximum,
+                    default_value,
+                    self.flags,
+                )
+            }
+        }
+    };
+}
+
+fn clamp_default<T: PartialOrd>(value: T, minimum: T, maximum: T) -> T {
+    if value < minimum {
+        minimum
+    } else if value > maximum {
+        maximum
+    } else {
+        value
+    }
+}
+
+define_param_spec_numeric_builder!(
+    ParamSpecChar,
+    ParamSpecCharBuilder,
+    i8,
+    char,
+    "g_param_spec_char"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecUChar,
+    ParamSpecUCharBuilder,
+    u8,
+    uchar,
+    "g_param_spec_uchar"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecInt,
+    ParamSpecIntBuilder,
+    i32,
+    int,
+    "g_param_spec_int"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecUInt,
+    ParamSpecUIntBuilder,
+    u32,
+    uint,
+    "g_param_spec_uint"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecLong,
+    ParamSpecLongBuilder,
+    libc::c_long,
+    long,
+    "g_param_spec_long"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecULong,
+    ParamSpecULongBuilder,
+    libc::c_ulong,
+    ulong,
+    "g_param_spec_ulong"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecInt64,
+    ParamSpecInt64Builder,
+    i64,
+    int64,
+    "g_param_spec_int64"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecUInt64,
+    ParamSpecUInt64Builder,
+    u64,
+    uint64,
+    "g_param_spec_uint64"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecFloat,
+    ParamSpecFloatBuilder,
+    f32,
+    float,
+    "g_param_spec_float"
+);
+define_param_spec_numeric_builder!(
+    ParamSpecDouble,
+    ParamSpecDoubleBuilder,
+    f64,
+    double,
+    "g_param_spec_double"
+);
+
+define_param_spec_builder!(
+    ParamSpecBoolean,
+    ParamSpecBooleanBuilder,
+    new(),
+    { default_value: bool = false }
+);
+
+impl<'a> ParamSpecBooleanBuilder<'a> {
+    /// Sets the default value, which defaults to `false`.
+    pub fn default_value(mut self, default_value: bool) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    #[doc(alias = "g_param_spec_boolean")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::boolean(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.default_value,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecUnichar,
+    ParamSpecUnicharBuilder,
+    new(),
+    { default_value: char = '\0' }
+);
+
+impl<'a> ParamSpecUnicharBuilder<'a> {
+    /// Sets the default value, which defaults to `'\0'`.
+    pub fn default_value(mut self, default_value: char) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    #[doc(alias = "g_param_spec_unichar")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::unichar(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.default_value,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecString,
+    ParamSpecStringBuilder,
+    new(),
+    { default_value: Option<&'a str> = None }
+);
+
+impl<'a> ParamSpecStringBuilder<'a> {
+    /// Sets the default value, which defaults to `None`.
+    pub fn default_value(mut self, default_value: Option<&'a str>) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    #[doc(alias = "g_param_spec_string")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::string(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.default_value,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecEnum,
+    ParamSpecEnumBuilder,
+    new(enum_type: Type),
+    { default_value: Option<i32> = None }
+);
+
+impl<'a> ParamSpecEnumBuilder<'a> {
+    /// Sets the default value, which defaults to the first value of the enum.
+    pub fn default_value(mut self, default_value: i32) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+
+    #[doc(alias = "g_param_spec_enum")]
+    pub fn build(self) -> ParamSpec {
+        let enum_type = self.enum_type;
+        let default_value = self.default_value.unwrap_or_else(|| {
+            crate::EnumClass::new(enum_type)
+                .and_then(|class| class.get_values().first().map(|value| value.get_value()))
+                .unwrap_or(0)
+        });
+        ParamSpec::enum_(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            enum_type,
+            default_value,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecFlags,
+    ParamSpecFlagsBuilder,
+    new(flags_type: Type),
+    { default_value: u32 = 0 }
+);
+
+impl<'a> ParamSpecFlagsBuilder<'a> {
+    /// Sets the default value, which defaults to no flags.
+    pub fn default_value(mut self, default_value: u32) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    #[doc(alias = "g_param_spec_flags")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::flags(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.flags_type,
+            self.default_value,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(ParamSpecBoxed, ParamSpecBoxedBuilder, new(boxed_type: Type), {});
+
+impl<'a> ParamSpecBoxedBuilder<'a> {
+    #[doc(alias = "g_param_spec_boxed")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::boxed(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.boxed_type,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecObject,
+    ParamSpecObjectBuilder,
+    new(object_type: Type),
+    {}
+);
+
+impl<'a> ParamSpecObjectBuilder<'a> {
+    #[doc(alias = "g_param_spec_object")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::object(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.object_type,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecGType,
+    ParamSpecGTypeBuilder,
+    new(),
+    { is_a_type: Type = Type::UNIT }
+);
+
+impl<'a> ParamSpecGTypeBuilder<'a> {
+    /// Only allows types deriving from `is_a_type`, by default all types are allowed.
+    pub fn is_a_type(mut self, is_a_type: Type) -> Self {
+        self.is_a_type = is_a_type;
+        self
+    }
+
+    #[doc(alias = "g_param_spec_gtype")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::gtype(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.is_a_type,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecParam,
+    ParamSpecParamBuilder,
+    new(param_type: Type),
+    {}
+);
+
+impl<'a> ParamSpecParamBuilder<'a> {
+    #[doc(alias = "g_param_spec_param")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::param(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.param_type,
+            self.flags,
+        )
+    }
+}
+
+define_param_spec_builder!(ParamSpecPointer, ParamSpecPointerBuilder, new(), {});
+
+impl<'a> ParamSpecPointerBuilder<'a> {
+    #[doc(alias = "g_param_spec_pointer")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::pointer(self.name, self.get_nick(), self.get_blurb(), self.flags)
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecValueArray,
+    ParamSpecValueArrayBuilder,
+    new(),
+    { element_spec: Option<&'a ParamSpec> = None }
+);
+
+impl<'a> ParamSpecValueArrayBuilder<'a> {
+    /// Sets the param spec all elements of the array have to conform to.
+    pub fn element_spec(mut self, element_spec: &'a ParamSpec) -> Self {
+        self.element_spec = Some(element_spec);
+        self
+    }
+
+    #[doc(alias = "g_param_spec_value_array")]
+    pub fn build(self) -> ParamSpec {
+        unsafe {
+            from_glib_none(gobject_ffi::g_param_spec_value_array(
+                self.name.to_glib_none().0,
+                self.get_nick().to_glib_none().0,
+                self.get_blurb().to_glib_none().0,
+                self.element_spec.to_glib_none().0,
+                self.flags.to_glib(),
+            ))
+        }
+    }
+}
+
+define_param_spec_builder!(
+    ParamSpecVariant,
+    ParamSpecVariantBuilder,
+    new(type_: &'a crate::VariantTy),
+    { default_value: Option<&'a crate::Variant> = None }
+);
+
+impl<'a> ParamSpecVariantBuilder<'a> {
+    /// Sets the default value, which defaults to `None`.
+    pub fn default_value(mut self, default_value: Option<&'a crate::Variant>) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    #[doc(alias = "g_param_spec_variant")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::variant(
+            self.name,
+            self.get_nick(),
+            self.get_blurb(),
+            self.type_,
+            self.default_value,
+            self.flags,
+        )
+    }
+}
+
+/// Builder for a [`ParamSpecOverride`], which redirects a property to a property of a parent
+/// class or an implemented interface.
+#[derive(Debug, Clone)]
+#[must_use = "the builder has to be built to create the param spec"]
+pub struct ParamSpecOverrideBuilder<'a> {
+    name: &'a str,
+    overridden: &'a ParamSpec,
+}
+
+impl<'a> ParamSpecOverrideBuilder<'a> {
+    pub fn new(name: &'a str, overridden: &'a ParamSpec) -> Self {
+        Self { name, overridden }
+    }
+
+    #[doc(alias = "g_param_spec_override")]
+    pub fn build(self) -> ParamSpec {
+        ParamSpec::override_(self.name, self.overridden)
+    }
+}
+
+impl ParamSpecOverride {
+    pub fn builder<'a>(name: &'a str, overridden: &'a ParamSpec) -> ParamSpecOverrideBuilder<'a> {
+        ParamSpecOverrideBuilder::new(name, overridden)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1068,4 +1647,71 @@ mod tests {
             .expect("Not a string param spec");
         assert_eq!(pspec.get_default_value(), Some("default"));
     }
+
+    #[test]
+    fn test_param_spec_override_for_class() {
+        let pspec = ParamSpecOverride::for_class::<crate::Binding>("source");
+        assert_eq!(pspec.get_name(), "source");
+        assert_eq!(pspec.get_type(), ParamSpecOverride::static_type());
+
+        let overridden = pspec
+            .downcast::<ParamSpecOverride>()
+            .expect("Not an override param spec")
+            .get_overridden();
+        assert_eq!(overridden.get_name(), "source");
+        assert_eq!(overridden.get_value_type(), Object::static_type());
+    }
+
+    #[test]
+    fn test_param_spec_int_builder() {
+        let pspec = ParamSpecInt::builder("name")
+            .minimum(1)
+            .maximum(10)
+            .flags(ParamFlags::READABLE)
+            .build();
+
+        assert_eq!(pspec.get_name(), "name");
+        assert_eq!(pspec.get_nick(), "name");
+        assert_eq!(pspec.get_blurb(), "");
+        assert_eq!(pspec.get_flags(), ParamFlags::READABLE);
+
+        let pspec = pspec
+            .downcast::<ParamSpecInt>()
+            .expect("Not an int param spec");
+        assert_eq!(pspec.get_minimum(), 1);
+        assert_eq!(pspec.get_maximum(), 10);
+        assert_eq!(pspec.get_default_value(), 1);
+
+        let pspec = ParamSpecInt::builder("name")
+            .maximum(10)
+            .default_value(5)
+            .build()
+            .downcast::<ParamSpecInt>()
+            .expect("Not an int param spec");
+        assert_eq!(pspec.get_minimum(), i32::MIN);
+        assert_eq!(pspec.get_default_value(), 5);
+    }
+
+    #[test]
+    fn test_param_spec_string_builder() {
+        let pspec = ParamSpecString::builder("name")
+            .nick("nick")
+            .blurb("blurb")
+            .default_value(Some("default"))
+            .build();
+
+        assert_eq!(pspec.get_nick(), "nick");
+        assert_eq!(pspec.get_blurb(), "blurb");
+        assert_eq!(pspec.get_flags(), ParamFlags::READWRITE);
+        let pspec = pspec
+            .downcast::<ParamSpecString>()
+            .expect("Not a string param spec");
+        assert_eq!(pspec.get_default_value(), Some("default"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_param_spec_override_for_class_missing() {
+        ParamSpecOverride::for_class::<crate::Binding>("does-not-exist");
+    }
 }
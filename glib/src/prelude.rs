@@ -4,5 +4,5 @@
 
 pub use crate::{
     Cast, Continue, IsA, ObjectExt, ObjectType, ParamSpecType, StaticType, StaticVariantType,
-    ToSendValue, ToValue, ToVariant,
+    ToSendValue, ToValue, ToVariant, TypeModuleExt,
 };
@@ -0,0 +1,300 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Instrumentation for finding main loop callbacks that take too long.
+//!
+//! Everything running on the main loop of a UI delays the next frame, so a callback taking
+//! longer than the time between two frames causes visible stutter. The functions and macros of
+//! this module measure how long callbacks and other code take, log a message whenever the
+//! frame budget is exceeded, and pass all measurements to an optional hook, e.g. to collect
+//! statistics.
+//!
+//! ```
+//! use glib::profiling;
+//!
+//! profiling::set_measurement_hook(|name, duration| {
+//!     println!("{} took {:?}", name, duration);
+//! });
+//!
+//! // Measures every dispatch of the source
+//! glib::idle_add(profiling::instrument("update-list", || {
+//!     // ...
+//!     glib::Continue(false)
+//! }));
+//!
+//! fn load() {
+//!     // Measures the rest of the function
+//!     glib::time_scope!("load");
+//!     // ...
+//! }
+//!
+//! let sum = glib::timed!("sum", (0..1000).sum::<u32>());
+//! ```
+//!
+//! Instead of instrumenting callbacks one by one, [`set_dispatch_profiling`] measures the
+//! dispatches of all sources created by this crate.
+//!
+//! Over-budget messages are logged with the domain [`LOG_DOMAIN`] at the `Message` level.
+
+use once_cell::sync::Lazy;
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The log domain of the messages about callbacks exceeding the frame budget.
+pub const LOG_DOMAIN: &str = "glib-rs-profiling";
+
+/// The default frame budget, the time between two frames at 60 frames per second.
+pub const DEFAULT_FRAME_BUDGET: Duration = Duration::from_micros(16_667);
+
+static FRAME_BUDGET: AtomicU64 = AtomicU64::new(16_667);
+
+/// Sets the time after which measurements are logged as exceeding the frame budget.
+pub fn set_frame_budget(budget: Duration) {
+    FRAME_BUDGET.store(budget.as_micros() as u64, Ordering::Relaxed);
+}
+
+/// Returns the frame budget, [`DEFAULT_FRAME_BUDGET`] unless set with [`set_frame_budget`].
+pub fn get_frame_budget() -> Duration {
+    Duration::from_micros(FRAME_BUDGET.load(Ordering::Relaxed))
+}
+
+type MeasurementHook = dyn Fn(&str, Duration) + Send + Sync + 'static;
+
+static MEASUREMENT_HOOK: Lazy<Mutex<Option<Arc<MeasurementHook>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Sets a function that is called with the name and duration of every measurement, also if it
+/// didn't exceed the frame budget.
+///
+/// To remove the hook again, use the [`unset_measurement_hook`] function.
+pub fn set_measurement_hook<F: Fn(&str, Duration) + Send + Sync + 'static>(hook: F) {
+    *MEASUREMENT_HOOK
+        .lock()
+        .expect("Failed to lock MEASUREMENT_HOOK to change hook") = Some(Arc::new(hook));
+}
+
+/// Removes the hook set with the [`set_measurement_hook`] function.
+pub fn unset_measurement_hook() {
+    *MEASUREMENT_HOOK
+        .lock()
+        .expect("Failed to lock MEASUREMENT_HOOK to remove hook") = None;
+}
+
+/// Records that `name` took `duration`, passing it to the measurement hook and logging a
+/// message if it exceeds the frame budget.
+pub fn record(name: &str, duration: Duration) {
+    let hook = MEASUREMENT_HOOK
+        .lock()
+        .expect("Failed to lock MEASUREMENT_HOOK")
+        .clone();
+    if let Some(hook) = hook {
+        hook(name, duration);
+    }
+
+    let budget = get_frame_budget();
+    if duration > budget {
+        crate::g_message!(
+            LOG_DOMAIN,
+            "{} took {:.1} ms, exceeding the frame budget of {:.1} ms",
+            name,
+            duration.as_secs_f64() * 1000.0,
+            budget.as_secs_f64() * 1000.0,
+        );
+    }
+}
+
+static DISPATCH_PROFILING: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether every dispatch of the sources created by this crate is [`record`]ed.
+///
+/// This covers the callbacks of e.g. [`timeout_add_local`](crate::timeout_add_local) and
+/// [`idle_source_new`](crate::idle_source_new), the futures spawned on a
+/// [`MainContext`](crate::MainContext) and the receivers of main context channels. The
+/// measurements are named after the source, or `unnamed source` if it has no name.
+pub fn set_dispatch_profiling(enabled: bool) {
+    DISPATCH_PROFILING.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether the dispatches of sources are recorded, see [`set_dispatch_profiling`].
+pub fn get_dispatch_profiling() -> bool {
+    DISPATCH_PROFILING.load(Ordering::Relaxed)
+}
+
+// Called by the sources of this crate with themselves and their dispatch
+pub(crate) unsafe fn dispatch<R, F: FnOnce() -> R>(source: *mut ffi::GSource, func: F) -> R {
+    if !get_dispatch_profiling() {
+        return func();
+    }
+
+    let start = Instant::now();
+    let ret = func();
+    let duration = start.elapsed();
+
+    // The name is only looked up afterwards as the callback could change it, while the source
+    // itself is kept alive until the dispatch returned
+    let name = if source.is_null() {
+        std::ptr::null()
+    } else {
+        ffi::g_source_get_name(source)
+    };
+    if name.is_null() {
+        record("unnamed source", duration);
+    } else {
+        record(&CStr::from_ptr(name).to_string_lossy(), duration);
+    }
+    ret
+}
+
+/// Wraps `func`, e.g. the callback of a timeout or idle source, to [`record`] the duration of
+/// every call as `name`.
+pub fn instrument<R, F: FnMut() -> R>(
+    name: impl Into<Cow<'static, str>>,
+    mut func: F,
+) -> impl FnMut() -> R {
+    let name = name.into();
+    move || {
+        let start = Instant::now();
+        let ret = func();
+        record(&name, start.elapsed());
+        ret
+    }
+}
+
+/// Guard that [`record`]s the time from its creation until it is dropped.
+///
+/// Usually created with the [`time_scope!`](crate::time_scope) or [`timed!`](crate::timed)
+/// macros.
+#[must_use = "if unused the measurement immediately ends again"]
+pub struct ScopeTimer {
+    name: Cow<'static, str>,
+    start: Instant,
+}
+
+impl ScopeTimer {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            start: Instant::now(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the time since the guard was created.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Drop for ScopeTimer {
+    fn drop(&mut self) {
+        record(&self.name, self.start.elapsed());
+    }
+}
+
+impl fmt::Debug for ScopeTimer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScopeTimer")
+            .field("name", &self.name)
+            .field("elapsed", &self.elapsed())
+            .finish()
+    }
+}
+
+/// Records the time until the end of the current scope as `$name`.
+///
+/// See the [`profiling`](crate::profiling) module.
+#[macro_export]
+macro_rules! time_scope {
+    ($name:expr) => {
+        let _time_scope = $crate::profiling::ScopeTimer::new($name);
+    };
+}
+
+/// Evaluates `$body`, recording the time it took as `$name`.
+///
+/// See the [`profiling`](crate::profiling) module.
+#[macro_export]
+macro_rules! timed {
+    ($name:expr, $body:expr) => {{
+        let _time_scope = $crate::profiling::ScopeTimer::new($name);
+        $body
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The hook and budget are global, so everything is tested in a single test
+    #[test]
+    fn measurements() {
+        let measurements = Arc::new(Mutex::new(Vec::new()));
+        let measurements_clone = measurements.clone();
+        // Sources dispatched by other tests are recorded as well while dispatch profiling is on
+        let thread = std::thread::current().id();
+        set_measurement_hook(move |name, duration| {
+            if std::thread::current().id() != thread {
+                return;
+            }
+            measurements_clone
+                .lock()
+                .unwrap()
+                .push((name.to_owned(), duration));
+        });
+        assert_eq!(get_frame_budget(), DEFAULT_FRAME_BUDGET);
+        set_frame_budget(Duration::from_millis(1));
+
+        {
+            crate::time_scope!("scope");
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        assert_eq!(crate::timed!(String::from("timed"), 1 + 1), 2);
+        let mut count = 0;
+        let mut callback = instrument("callback", || {
+            count += 1;
+            count
+        });
+        assert_eq!(callback(), 1);
+        assert_eq!(callback(), 2);
+
+        let context = crate::MainContext::new();
+        let attach = |name| {
+            crate::idle_source_new(name, crate::PRIORITY_DEFAULT, || crate::Continue(false))
+                .attach(Some(&context));
+            while context.iteration(false) {}
+        };
+        attach(Some("unprofiled"));
+        assert!(!get_dispatch_profiling());
+        set_dispatch_profiling(true);
+        attach(Some("idle"));
+        attach(None);
+        set_dispatch_profiling(false);
+
+        unset_measurement_hook();
+        record("unhooked", Duration::from_millis(1));
+        set_frame_budget(DEFAULT_FRAME_BUDGET);
+
+        let measurements = measurements.lock().unwrap();
+        let names = measurements
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "scope",
+                "timed",
+                "callback",
+                "callback",
+                "idle",
+                "unnamed source"
+            ]
+        );
+        assert!(measurements[0].1 >= Duration::from_millis(2));
+    }
+}
@@ -0,0 +1,510 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::object::{Cast, IsA, LocalWeakRefNotify, ObjectExt, ObjectType, WeakRef};
+use crate::translate::*;
+use crate::{Object, ParamFlags, ParamSpec, SignalHandlerId, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+/// A recorded change of a property.
+#[derive(Clone, Debug)]
+pub struct PropertyChange {
+    object: Object,
+    pspec: ParamSpec,
+    old_value: Value,
+    new_value: Value,
+}
+
+impl PropertyChange {
+    /// Returns the object whose property changed.
+    pub fn get_object(&self) -> &Object {
+        &self.object
+    }
+
+    /// Returns the property that changed.
+    pub fn get_pspec(&self) -> &ParamSpec {
+        &self.pspec
+    }
+
+    /// Returns the value of the property before the change.
+    pub fn get_old_value(&self) -> &Value {
+        &self.old_value
+    }
+
+    /// Returns the value of the property after the change.
+    pub fn get_new_value(&self) -> &Value {
+        &self.new_value
+    }
+}
+
+/// Records property changes of objects to undo and redo them again.
+///
+/// Objects have to be added with [`track`](Self::track). Every change of one of their properties
+/// is then recorded with its old and new value as a separate undo step, unless it happens
+/// between [`begin_group`](Self::begin_group) and [`end_group`](Self::end_group), in which case
+/// all changes of the group are undone and redone together.
+///
+/// Changes are detected with the `notify` signal, so properties that are not notified on change
+/// are not recorded. Changes made while undoing or redoing are not recorded either.
+///
+/// All signal handlers are disconnected once the last clone of the stack is dropped.
+#[derive(Clone)]
+pub struct PropertyUndoStack(Rc<Inner>);
+
+struct Inner {
+    tracked: RefCell<Vec<Tracked>>,
+    undo: RefCell<Vec<Vec<PropertyChange>>>,
+    redo: RefCell<Vec<Vec<PropertyChange>>>,
+    group: RefCell<Vec<PropertyChange>>,
+    group_depth: Cell<usize>,
+    replaying: Cell<bool>,
+}
+
+struct Tracked {
+    object: WeakRef<Object>,
+    // The address of the object, which identifies the entry once the object is finalized
+    ptr: usize,
+    handler: SignalHandlerId,
+    // Removes the entry once the object is finalized
+    finalize_notify: LocalWeakRefNotify,
+    // Last known values, as the notify signal doesn't provide the old value
+    values: HashMap<String, Value>,
+}
+
+impl PropertyUndoStack {
+    /// Creates a new, empty undo stack.
+    pub fn new() -> Self {
+        PropertyUndoStack(Rc::new(Inner {
+            tracked: RefCell::new(Vec::new()),
+            undo: RefCell::new(Vec::new()),
+            redo: RefCell::new(Vec::new()),
+            group: RefCell::new(Vec::new()),
+            group_depth: Cell::new(0),
+            replaying: Cell::new(false),
+        }))
+    }
+
+    /// Starts recording changes of the `properties` of `obj`, or of all its readable and
+    /// writable properties if `properties` is `None`.
+    ///
+    /// Tracking an object again replaces the previously tracked properties. The stack only keeps
+    /// a weak reference to `obj`, which is untracked once it is finalized. Recorded changes keep
+    /// their objects alive though.
+    pub fn track<O: IsA<Object>>(&self, obj: &O, properties: Option<&[&str]>) {
+        let obj = obj.upcast_ref::<Object>();
+        self.untrack(obj);
+
+        let pspecs = match properties {
+            Some(properties) => properties
+                .iter()
+                .filter_map(|name| obj.find_property(*name))
+                .collect(),
+            None => obj.list_properties(),
+        };
+        let values = pspecs
+            .iter()
+            .filter(|pspec| is_recordable(pspec))
+            .filter_map(|pspec| {
+                let value = obj.get_property_from_pspec(pspec).ok()?;
+                Some((pspec.get_name().to_owned(), value))
+            })
+            .collect();
+
+        let inner = Rc::downgrade(&self.0);
+        let handler = obj.connect_notify_local(None, move |obj, pspec| {
+            if let Some(inner) = inner.upgrade() {
+                inner.notify(obj, pspec);
+            }
+        });
+
+        let ptr = obj.as_ptr() as usize;
+        let inner = Rc::downgrade(&self.0);
+        let finalize_notify = obj.add_weak_ref_notify_local(move || {
+            if let Some(inner) = inner.upgrade() {
+                inner.tracked.borrow_mut().retain(|t| t.ptr != ptr);
+            }
+        });
+
+        self.0.tracked.borrow_mut().push(Tracked {
+            object: obj.downgrade(),
+            ptr,
+            handler,
+            finalize_notify,
+            values,
+        });
+    }
+
+    /// Stops recording changes of `obj`.
+    ///
+    /// Already recorded changes are kept.
+    pub fn untrack<O: IsA<Object>>(&self, obj: &O) {
+        let obj = obj.upcast_ref::<Object>();
+        let mut tracked = self.0.tracked.borrow_mut();
+        if let Some(pos) = tracked
+            .iter()
+            .position(|t| t.object.upgrade().as_ref() == Some(obj))
+        {
+            let t = tracked.remove(pos);
+            obj.disconnect(t.handler);
+            t.finalize_notify.disconnect();
+        }
+    }
+
+    /// Starts a group of changes that are undone and redone together.
+    ///
+    /// Groups can be nested, the changes are only added to the stack once the outermost group
+    /// is ended.
+    pub fn begin_group(&self) {
+        self.0.group_depth.set(self.0.group_depth.get() + 1);
+    }
+
+    /// Ends a group of changes started with [`begin_group`](Self::begin_group).
+    ///
+    /// # Panics
+    ///
+    /// Panics if no group was started.
+    pub fn end_group(&self) {
+        let depth = self.0.group_depth.get();
+        assert!(depth > 0, "No group was started");
+        self.0.group_depth.set(depth - 1);
+        if depth == 1 {
+            let group = self.0.group.replace(Vec::new());
+            self.0.push(group);
+        }
+    }
+
+    /// Calls `f` inside a group of changes.
+    pub fn group<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        self.begin_group();
+        let res = f();
+        self.end_group();
+        res
+    }
+
+    /// Reverts all changes of the current group and ends it, including all outer groups.
+    ///
+    /// Does nothing if no group was started.
+    pub fn rollback(&self) {
+        if self.0.group_depth.replace(0) == 0 {
+            return;
+        }
+        let group = self.0.group.replace(Vec::new());
+        self.0.replay(&group, true);
+    }
+
+    /// Returns `true` if there are changes that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.0.undo.borrow().is_empty()
+    }
+
+    /// Returns `true` if there are changes that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.0.redo.borrow().is_empty()
+    }
+
+    /// Undoes the last recorded change or group of changes.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&self) -> bool {
+        let changes = match self.0.undo.borrow_mut().pop() {
+            Some(changes) => changes,
+            None => return false,
+        };
+        self.0.replay(&changes, true);
+        self.0.redo.borrow_mut().push(changes);
+        true
+    }
+
+    /// Redoes the last undone change or group of changes.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&self) -> bool {
+        let changes = match self.0.redo.borrow_mut().pop() {
+            Some(changes) => changes,
+            None => return false,
+        };
+        self.0.replay(&changes, false);
+        self.0.undo.borrow_mut().push(changes);
+        true
+    }
+
+    /// Drops all recorded changes.
+    pub fn clear(&self) {
+        self.0.undo.borrow_mut().clear();
+        self.0.redo.borrow_mut().clear();
+        self.0.group.borrow_mut().clear();
+    }
+}
+
+impl Default for PropertyUndoStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PropertyUndoStack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PropertyUndoStack")
+            .field("tracked", &self.0.tracked.borrow().len())
+            .field("undo", &self.0.undo.borrow().len())
+            .field("redo", &self.0.redo.borrow().len())
+            .field("group_depth", &self.0.group_depth.get())
+            .finish()
+    }
+}
+
+impl Inner {
+    fn notify(&self, obj: &Object, pspec: &ParamSpec) {
+        let change = {
+            let mut tracked = self.tracked.borrow_mut();
+            let t = match tracked
+                .iter_mut()
+                .find(|t| t.object.upgrade().as_ref() == Some(obj))
+            {
+                Some(t) => t,
+                None => return,
+            };
+            let old_value = match t.values.get_mut(pspec.get_name()) {
+                Some(value) => value,
+                None => return,
+            };
+            let new_value = match obj.get_property_from_pspec(pspec) {
+                Ok(value) => value,
+                Err(_) => return,
+            };
+            if values_equal(pspec, old_value, &new_value) {
+                return;
+            }
+
+            PropertyChange {
+                object: obj.clone(),
+                pspec: pspec.clone(),
+                old_value: std::mem::replace(old_value, new_value.clone()),
+                new_value,
+            }
+        };
+
+        if self.replaying.get() {
+            return;
+        }
+        if self.group_depth.get() > 0 {
+            self.group.borrow_mut().push(change);
+        } else {
+            self.push(vec![change]);
+        }
+    }
+
+    fn push(&self, changes: Vec<PropertyChange>) {
+        if changes.is_empty() {
+            return;
+        }
+        self.undo.borrow_mut().push(changes);
+        self.redo.borrow_mut().clear();
+    }
+
+    fn replay(&self, changes: &[PropertyChange], undo: bool) {
+        self.replaying.set(true);
+        {
+            // Emit the notifications only once all properties are set, and only once per
+            // property
+            let mut guards = Vec::new();
+            for change in changes {
+                if !guards.iter().any(|(obj, _)| obj == &change.object) {
+                    guards.push((change.object.clone(), change.object.freeze_notify()));
+                }
+            }
+
+            if undo {
+                for change in changes.iter().rev() {
+                    let _ = change
+                        .object
+                        .set_property_value_from_pspec(&change.pspec, &change.old_value);
+                }
+            } else {
+                for change in changes {
+                    let _ = change
+                        .object
+                        .set_property_value_from_pspec(&change.pspec, &change.new_value);
+                }
+            }
+        }
+        self.replaying.set(false);
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        for t in self.tracked.get_mut().drain(..) {
+            if let Some(obj) = t.object.upgrade() {
+                obj.disconnect(t.handler);
+                t.finalize_notify.disconnect();
+            }
+        }
+    }
+}
+
+fn is_recordable(pspec: &ParamSpec) -> bool {
+    let flags = pspec.get_flags();
+    flags.contains(ParamFlags::READWRITE) && !flags.contains(ParamFlags::CONSTRUCT_ONLY)
+}
+
+fn values_equal(pspec: &ParamSpec, a: &Value, b: &Value) -> bool {
+    unsafe {
+        gobject_ffi::g_param_values_cmp(
+            pspec.to_glib_none().0,
+            a.to_glib_none().0,
+            b.to_glib_none().0,
+        ) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as glib;
+    use crate::subclass::prelude::*;
+    use crate::ToValue;
+
+    mod imp {
+        use super::*;
+
+        #[derive(Default)]
+        pub struct Counter {
+            value: Cell<i32>,
+            label: RefCell<Option<String>>,
+        }
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for Counter {
+            const NAME: &'static str = "PropertyUndoCounter";
+            type Type = super::Counter;
+            type ParentType = Object;
+        }
+
+        impl ObjectImpl for Counter {
+            fn properties() -> &'static [ParamSpec] {
+                use once_cell::sync::Lazy;
+                static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
+                    vec![
+                        ParamSpec::int(
+                            "value",
+                            "Value",
+                            "Value",
+                            i32::MIN,
+                            i32::MAX,
+                            0,
+                            ParamFlags::READWRITE,
+                        ),
+                        ParamSpec::string("label", "Label", "Label", None, ParamFlags::READWRITE),
+                    ]
+                });
+
+                PROPERTIES.as_ref()
+            }
+
+            fn set_property(
+                &self,
+                _obj: &Self::Type,
+                _id: usize,
+                value: &Value,
+                pspec: &ParamSpec,
+            ) {
+                match pspec.get_name() {
+                    "value" => self.value.set(value.get_some().unwrap()),
+                    "label" => *self.label.borrow_mut() = value.get().unwrap(),
+                    _ => unimplemented!(),
+                }
+            }
+
+            fn get_property(&self, _obj: &Self::Type, _id: usize, pspec: &ParamSpec) -> Value {
+                match pspec.get_name() {
+                    "value" => self.value.get().to_value(),
+                    "label" => self.label.borrow().to_value(),
+                    _ => unimplemented!(),
+                }
+            }
+        }
+    }
+
+    wrapper! {
+        pub struct Counter(ObjectSubclass<imp::Counter>);
+    }
+
+    fn value(obj: &Counter) -> i32 {
+        obj.get_property("value").unwrap().get_some().unwrap()
+    }
+
+    #[test]
+    fn undo_redo() {
+        let obj = Object::new::<Counter>(&[]).unwrap();
+        let stack = PropertyUndoStack::new();
+        stack.track(&obj, None);
+
+        obj.set_property("value", &1).unwrap();
+        obj.set_property("value", &2).unwrap();
+        // Setting the same value again is not a change
+        obj.set_property("value", &2).unwrap();
+        assert!(stack.can_undo());
+        assert!(!stack.can_redo());
+
+        assert!(stack.undo());
+        assert_eq!(value(&obj), 1);
+        assert!(stack.undo());
+        assert_eq!(value(&obj), 0);
+        assert!(!stack.undo());
+
+        assert!(stack.redo());
+        assert_eq!(value(&obj), 1);
+
+        // A new change drops the redo stack
+        obj.set_property("value", &5).unwrap();
+        assert!(!stack.redo());
+        assert!(stack.undo());
+        assert_eq!(value(&obj), 1);
+    }
+
+    #[test]
+    fn groups_and_rollback() {
+        let obj = Object::new::<Counter>(&[]).unwrap();
+        let stack = PropertyUndoStack::new();
+        stack.track(&obj, Some(&["value"]));
+
+        stack.group(|| {
+            obj.set_property("value", &1).unwrap();
+            obj.set_property("value", &2).unwrap();
+            // Not tracked
+            obj.set_property("label", &"label").unwrap();
+        });
+        assert!(stack.undo());
+        assert_eq!(value(&obj), 0);
+        assert!(!stack.can_undo());
+
+        stack.begin_group();
+        obj.set_property("value", &3).unwrap();
+        stack.rollback();
+        assert_eq!(value(&obj), 0);
+        assert!(!stack.can_undo());
+        assert!(stack.can_redo());
+    }
+
+    #[test]
+    fn finalized_objects_are_untracked() {
+        let stack = PropertyUndoStack::new();
+        let obj = Object::new::<Counter>(&[]).unwrap();
+        stack.track(&obj, None);
+        let other = Object::new::<Counter>(&[]).unwrap();
+        stack.track(&other, None);
+        assert_eq!(stack.0.tracked.borrow().len(), 2);
+
+        drop(obj);
+        assert_eq!(stack.0.tracked.borrow().len(), 1);
+
+        stack.untrack(&other);
+        assert!(stack.0.tracked.borrow().is_empty());
+        // The notification was removed together with the entry
+        drop(other);
+        assert!(stack.0.tracked.borrow().is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::object::{IsA, ObjectExt, WeakRef};
+use crate::{Object, SignalHandlerId, Value};
+use futures_channel::mpsc;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+
+/// A `Stream` of the value at the end of a chain of object properties.
+///
+/// Created with [`watch_property_chain`] or the [`watch!`](crate::watch!) macro. Whenever
+/// one of the properties along the chain changes, the handlers further down are reconnected to
+/// the new objects and the current value of the last property is yielded. `None` is yielded
+/// while the chain is broken, i.e. one of the intermediate properties is unset or does not
+/// exist on the object it currently points to.
+///
+/// All signal handlers are disconnected once the stream is dropped.
+pub struct PropertyChainWatch {
+    inner: Rc<RefCell<Inner>>,
+    receiver: mpsc::UnboundedReceiver<Option<Value>>,
+}
+
+struct Inner {
+    path: Vec<String>,
+    links: Vec<(WeakRef<Object>, SignalHandlerId)>,
+    sender: mpsc::UnboundedSender<Option<Value>>,
+}
+
+impl Inner {
+    fn disconnect_from(&mut self, idx: usize) {
+        for (obj, handler) in self.links.drain(idx..) {
+            if let Some(obj) = obj.upgrade() {
+                obj.disconnect(handler);
+            }
+        }
+    }
+
+    fn send(&self, value: Option<Value>) {
+        let _ = self.sender.unbounded_send(value);
+    }
+
+    // Connects to the properties of the chain starting at `start`, with `obj` being the value of
+    // the property before it.
+    fn connect_from(&mut self, this: &Weak<RefCell<Inner>>, start: usize, obj: Option<Object>) {
+        self.disconnect_from(start);
+
+        let mut obj = obj;
+        for idx in start..self.path.len() {
+            let (current, pspec) = match obj.and_then(|obj| {
+                obj.find_property(self.path[idx].as_str())
+                    .map(|pspec| (obj, pspec))
+            }) {
+                Some(link) => link,
+                None => return self.send(None),
+            };
+
+            let this = this.clone();
+            let handler = current.connect_notify_local(Some(pspec.get_name()), move |obj, _| {
+                if let Some(inner) = this.upgrade() {
+                    inner.borrow_mut().changed(&this, obj, idx);
+                }
+            });
+            self.links.push((current.downgrade(), handler));
+
+            let value = current.get_property_from_pspec(&pspec).ok();
+            if idx + 1 == self.path.len() {
+                return self.send(value);
+            }
+            obj = value.and_then(|value| value.get::<Object>().ok().flatten());
+        }
+    }
+
+    fn changed(&mut self, this: &Weak<RefCell<Inner>>, obj: &Object, idx: usize) {
+        let value = obj.get_property(self.path[idx].as_str()).ok();
+        if idx + 1 == self.path.len() {
+            self.send(value);
+        } else {
+            let next = value.and_then(|value| value.get::<Object>().ok().flatten());
+            self.connect_from(this, idx + 1, next);
+        }
+    }
+}
+
+/// Watches the property at the end of the chain `path`, starting at `obj`.
+///
+/// With `path` being `["child", "settings", "theme"]` this yields the value of
+/// `obj.child.settings.theme` initially and then every time any of the three properties
+/// changes. See [`PropertyChainWatch`] for details.
+///
+/// # Panics
+///
+/// Panics if `path` is empty.
+pub fn watch_property_chain<O: IsA<Object>>(obj: &O, path: &[&str]) -> PropertyChainWatch {
+    assert!(!path.is_empty(), "Property chain can't be empty");
+
+    let (sender, receiver) = mpsc::unbounded();
+    let inner = Rc::new(RefCell::new(Inner {
+        path: path.iter().map(|name| String::from(*name)).collect(),
+        links: Vec::with_capacity(path.len()),
+        sender,
+    }));
+    inner
+        .borrow_mut()
+        .connect_from(&Rc::downgrade(&inner), 0, Some(obj.as_ref().clone()));
+
+    PropertyChainWatch { inner, receiver }
+}
+
+impl Stream for PropertyChainWatch {
+    type Item = Option<Value>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(ctx)
+    }
+}
+
+impl Drop for PropertyChainWatch {
+    fn drop(&mut self) {
+        self.inner.borrow_mut().disconnect_from(0);
+    }
+}
+
+/// Watches a chain of properties, see [`watch_property_chain`].
+///
+/// `-` in property names can be written as `_`.
+///
+/// ```no_run
+/// # fn watch(window: glib::Object) {
+/// let theme = glib::watch!(window.settings.theme_name);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! watch {
+    ($obj:ident $(. $property:ident)+) => {
+        $crate::watch_property_chain(&$obj, &[$(stringify!($property)),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as glib;
+    use crate::subclass::prelude::*;
+    use crate::{ParamSpec, StaticType, ToValue};
+    use futures_util::{FutureExt, StreamExt};
+
+    mod imp {
+        use super::*;
+
+        #[derive(Default)]
+        pub struct Node {
+            pub name: RefCell<Option<String>>,
+            pub next: RefCell<Option<Object>>,
+        }
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for Node {
+            const NAME: &'static str = "PropertyWatchNode";
+            type Type = super::Node;
+            type ParentType = Object;
+        }
+
+        impl ObjectImpl for Node {
+            fn properties() -> &'static [ParamSpec] {
+                use once_cell::sync::Lazy;
+                static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
+                    vec![
+                        ParamSpec::string(
+                            "name",
+                            "Name",
+                            "Name",
+                            None,
+                            crate::ParamFlags::READWRITE,
+                        ),
+                        ParamSpec::object(
+                            "next",
+                            "Next",
+                            "Next node",
+                            Object::static_type(),
+                            crate::ParamFlags::READWRITE,
+                        ),
+                    ]
+                });
+
+                PROPERTIES.as_ref()
+            }
+
+            fn set_property(
+                &self,
+                _obj: &Self::Type,
+                _id: usize,
+                value: &Value,
+                pspec: &ParamSpec,
+            ) {
+                match pspec.get_name() {
+                    "name" => *self.name.borrow_mut() = value.get().unwrap(),
+                    "next" => *self.next.borrow_mut() = value.get().unwrap(),
+                    _ => unimplemented!(),
+                }
+            }
+
+            fn get_property(&self, _obj: &Self::Type, _id: usize, pspec: &ParamSpec) -> Value {
+                match pspec.get_name() {
+                    "name" => self.name.borrow().to_value(),
+                    "next" => self.next.borrow().to_value(),
+                    _ => unimplemented!(),
+                }
+            }
+        }
+    }
+
+    glib::wrapper! {
+        pub struct Node(ObjectSubclass<imp::Node>);
+    }
+
+    fn node(name: &str) -> Node {
+        Object::new(&[("name", &name)]).unwrap()
+    }
+
+    fn next_name(watch: &mut PropertyChainWatch) -> Option<Option<String>> {
+        watch
+            .next()
+            .now_or_never()
+            .flatten()
+            .map(|value| value.and_then(|value| value.get::<String>().unwrap()))
+    }
+
+    #[test]
+    fn watch_chain() {
+        let root = node("root");
+        let first = node("first");
+
+        let mut watch = crate::watch!(root.next.name);
+        assert_eq!(next_name(&mut watch), Some(None));
+        assert_eq!(next_name(&mut watch), None);
+
+        root.set_property("next", &first).unwrap();
+        assert_eq!(next_name(&mut watch), Some(Some("first".into())));
+
+        first.set_property("name", &"renamed").unwrap();
+        assert_eq!(next_name(&mut watch), Some(Some("renamed".into())));
+
+        let second = node("second");
+        root.set_property("next", &second).unwrap();
+        assert_eq!(next_name(&mut watch), Some(Some("second".into())));
+
+        // The previous intermediate object is not watched anymore
+        first.set_property("name", &"ignored").unwrap();
+        assert_eq!(next_name(&mut watch), None);
+
+        root.set_property("next", &None::<Object>).unwrap();
+        assert_eq!(next_name(&mut watch), Some(None));
+
+        drop(watch);
+        root.set_property("next", &first).unwrap();
+    }
+}
@@ -11,7 +11,9 @@ pub struct Quark(ffi::GQuark);
 impl Quark {
     #[doc(alias = "g_quark_from_string")]
     pub fn from_string(s: &str) -> Quark {
-        unsafe { from_glib(ffi::g_quark_from_string(s.to_glib_none().0)) }
+        run_with_c_str(s, |s| unsafe {
+            from_glib(ffi::g_quark_from_string(s.as_ptr()))
+        })
     }
 
     #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -26,12 +28,12 @@ impl Quark {
 
     #[doc(alias = "g_quark_try_string")]
     pub fn try_string(s: &str) -> Option<Quark> {
-        unsafe {
-            match ffi::g_quark_try_string(s.to_glib_none().0) {
+        run_with_c_str(s, |s| unsafe {
+            match ffi::g_quark_try_string(s.as_ptr()) {
                 0 => None,
                 x => Some(from_glib(x)),
             }
-        }
+        })
     }
 }
 
@@ -0,0 +1,652 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::translate::*;
+use crate::{Error, GString, RegexCompileFlags, RegexError, RegexMatchFlags};
+use std::fmt;
+use std::ops::Range;
+use std::ptr;
+
+wrapper! {
+    /// A compiled Perl-compatible regular expression.
+    ///
+    /// This uses the same regular expression dialect as e.g. `GtkSourceView` or the search of
+    /// other GNOME components. All positions are byte offsets into the searched string.
+    ///
+    /// ```
+    /// use glib::{Regex, RegexCompileFlags, RegexMatchFlags};
+    ///
+    /// let regex = Regex::new(
+    ///     r"(?<key>\w+)=(?<value>\w+)",
+    ///     RegexCompileFlags::empty(),
+    ///     RegexMatchFlags::empty(),
+    /// )
+    /// .unwrap();
+    /// let keys = regex
+    ///     .find_iter("a=1, b=2", RegexMatchFlags::empty())
+    ///     .map(|m| m.unwrap().name("key").unwrap())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(keys, ["a", "b"]);
+    /// ```
+    #[doc(alias = "GRegex")]
+    pub struct Regex(Shared<ffi::GRegex>);
+
+    match fn {
+        ref => |ptr| ffi::g_regex_ref(ptr),
+        unref => |ptr| ffi::g_regex_unref(ptr),
+        get_type => || ffi::g_regex_get_type(),
+    }
+}
+
+impl Regex {
+    /// Compiles the regular expression `pattern`.
+    ///
+    /// `match_flags` are used in addition to the flags passed when matching.
+    /// [`RegexCompileFlags::RAW`] is not supported, as matches could split UTF-8 characters.
+    #[doc(alias = "g_regex_new")]
+    pub fn new(
+        pattern: &str,
+        compile_flags: RegexCompileFlags,
+        match_flags: RegexMatchFlags,
+    ) -> Result<Regex, Error> {
+        if compile_flags.contains(RegexCompileFlags::RAW) {
+            return Err(Error::new(
+                RegexError::Compile,
+                "Raw regular expressions can't match UTF-8 strings",
+            ));
+        }
+
+        unsafe {
+            let mut error = ptr::null_mut();
+            let ret = ffi::g_regex_new(
+                pattern.to_glib_none().0,
+                compile_flags.to_glib(),
+                match_flags.to_glib(),
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Returns `true` if `pattern` matches `string`, without keeping the compiled expression.
+    #[doc(alias = "g_regex_match_simple")]
+    pub fn match_simple(
+        pattern: &str,
+        string: &str,
+        compile_flags: RegexCompileFlags,
+        match_flags: RegexMatchFlags,
+    ) -> bool {
+        unsafe {
+            from_glib(ffi::g_regex_match_simple(
+                pattern.to_glib_none().0,
+                string.to_glib_none().0,
+                compile_flags.to_glib(),
+                match_flags.to_glib(),
+            ))
+        }
+    }
+
+    /// Escapes all characters of `string` that have a special meaning in a pattern.
+    #[doc(alias = "g_regex_escape_string")]
+    pub fn escape_string(string: &str) -> GString {
+        unsafe {
+            from_glib_full(ffi::g_regex_escape_string(
+                string.as_ptr() as *const _,
+                string.len() as i32,
+            ))
+        }
+    }
+
+    /// Checks the syntax of the replacement `replacement` of [`replace`](Self::replace).
+    ///
+    /// Returns `true` if it contains references to capture groups like `\1` or `\g<name>`.
+    #[doc(alias = "g_regex_check_replacement")]
+    pub fn check_replacement(replacement: &str) -> Result<bool, Error> {
+        unsafe {
+            let mut has_references = ffi::GFALSE;
+            let mut error = ptr::null_mut();
+            ffi::g_regex_check_replacement(
+                replacement.to_glib_none().0,
+                &mut has_references,
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib(has_references))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    #[doc(alias = "g_regex_get_pattern")]
+    pub fn get_pattern(&self) -> GString {
+        unsafe { from_glib_none(ffi::g_regex_get_pattern(self.to_glib_none().0)) }
+    }
+
+    #[doc(alias = "g_regex_get_compile_flags")]
+    pub fn get_compile_flags(&self) -> RegexCompileFlags {
+        unsafe { from_glib(ffi::g_regex_get_compile_flags(self.to_glib_none().0)) }
+    }
+
+    #[doc(alias = "g_regex_get_match_flags")]
+    pub fn get_match_flags(&self) -> RegexMatchFlags {
+        unsafe { from_glib(ffi::g_regex_get_match_flags(self.to_glib_none().0)) }
+    }
+
+    /// Returns the number of capture groups, not counting the whole match.
+    #[doc(alias = "g_regex_get_capture_count")]
+    pub fn get_capture_count(&self) -> usize {
+        unsafe { ffi::g_regex_get_capture_count(self.to_glib_none().0) as usize }
+    }
+
+    /// Returns the index of the capture group named `name`.
+    #[doc(alias = "g_regex_get_string_number")]
+    pub fn get_string_number(&self, name: &str) -> Option<usize> {
+        unsafe {
+            let ret = ffi::g_regex_get_string_number(self.to_glib_none().0, name.to_glib_none().0);
+            if ret < 0 {
+                None
+            } else {
+                Some(ret as usize)
+            }
+        }
+    }
+
+    /// Returns `true` if the expression matches anywhere in `string`.
+    #[doc(alias = "g_regex_match")]
+    pub fn is_match(&self, string: &str, match_flags: RegexMatchFlags) -> bool {
+        matches!(self.find(string, match_flags), Ok(Some(_)))
+    }
+
+    /// Returns the first match in `string`.
+    #[doc(alias = "g_regex_match_full")]
+    pub fn find<'s>(
+        &self,
+        string: &'s str,
+        match_flags: RegexMatchFlags,
+    ) -> Result<Option<Match<'s>>, Error> {
+        self.find_iter(string, match_flags).next().transpose()
+    }
+
+    /// Returns an iterator over all non-overlapping matches in `string`.
+    ///
+    /// The iterator ends after the first error.
+    #[doc(alias = "g_regex_match_full")]
+    #[doc(alias = "g_match_info_next")]
+    pub fn find_iter<'s>(&self, string: &'s str, match_flags: RegexMatchFlags) -> Matches<'s> {
+        unsafe {
+            let mut match_info = ptr::null_mut();
+            let mut error = ptr::null_mut();
+            ffi::g_regex_match_full(
+                self.to_glib_none().0,
+                string.as_ptr() as *const _,
+                string.len() as isize,
+                0,
+                match_flags.to_glib(),
+                &mut match_info,
+                &mut error,
+            );
+            Matches {
+                regex: self.clone(),
+                string,
+                match_info,
+                error: if error.is_null() {
+                    None
+                } else {
+                    Some(from_glib_full(error))
+                },
+                started: false,
+                done: false,
+            }
+        }
+    }
+
+    /// Splits `string` at all matches, into at most `max_tokens` parts if it's not 0.
+    ///
+    /// The contents of capture groups are included between the parts.
+    #[doc(alias = "g_regex_split_full")]
+    pub fn split(
+        &self,
+        string: &str,
+        match_flags: RegexMatchFlags,
+        max_tokens: u32,
+    ) -> Result<Vec<GString>, Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            let ret = ffi::g_regex_split_full(
+                self.to_glib_none().0,
+                string.as_ptr() as *const _,
+                string.len() as isize,
+                0,
+                match_flags.to_glib(),
+                max_tokens as i32,
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(FromGlibPtrContainer::from_glib_full(ret))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Replaces all matches in `string` by `replacement`, which can contain references to
+    /// capture groups like `\1` or `\g<name>`.
+    #[doc(alias = "g_regex_replace")]
+    pub fn replace(
+        &self,
+        string: &str,
+        replacement: &str,
+        match_flags: RegexMatchFlags,
+    ) -> Result<GString, Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            let ret = ffi::g_regex_replace(
+                self.to_glib_none().0,
+                string.as_ptr() as *const _,
+                string.len() as isize,
+                0,
+                replacement.to_glib_none().0,
+                match_flags.to_glib(),
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Replaces all matches in `string` by `replacement`, without expanding references.
+    #[doc(alias = "g_regex_replace_literal")]
+    pub fn replace_literal(
+        &self,
+        string: &str,
+        replacement: &str,
+        match_flags: RegexMatchFlags,
+    ) -> Result<GString, Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            let ret = ffi::g_regex_replace_literal(
+                self.to_glib_none().0,
+                string.as_ptr() as *const _,
+                string.len() as isize,
+                0,
+                replacement.to_glib_none().0,
+                match_flags.to_glib(),
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    /// Replaces all matches in `string` by the return value of `func`.
+    ///
+    /// ```
+    /// use glib::{Regex, RegexCompileFlags, RegexMatchFlags};
+    ///
+    /// let regex = Regex::new(r"\d+", RegexCompileFlags::empty(), RegexMatchFlags::empty()).unwrap();
+    /// let doubled = regex
+    ///     .replace_with("1 and 21", RegexMatchFlags::empty(), |m| {
+    ///         (m.as_str().parse::<u32>().unwrap() * 2).to_string()
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(doubled, "2 and 42");
+    /// ```
+    #[doc(alias = "g_regex_replace_eval")]
+    pub fn replace_with<F, R>(
+        &self,
+        string: &str,
+        match_flags: RegexMatchFlags,
+        func: F,
+    ) -> Result<GString, Error>
+    where
+        F: FnMut(&Match) -> R,
+        R: AsRef<str>,
+    {
+        struct EvalData<'s, F> {
+            regex: &'s Regex,
+            string: &'s str,
+            func: F,
+        }
+
+        unsafe extern "C" fn eval_trampoline<F, R>(
+            match_info: *const ffi::GMatchInfo,
+            result: *mut ffi::GString,
+            user_data: ffi::gpointer,
+        ) -> ffi::gboolean
+        where
+            F: FnMut(&Match) -> R,
+            R: AsRef<str>,
+        {
+            let data = &mut *(user_data as *mut EvalData<F>);
+            let m = Match::from_match_info(data.regex, data.string, match_info);
+            let replacement = (data.func)(&m);
+            let replacement = replacement.as_ref();
+            ffi::g_string_append_len(
+                result,
+                replacement.as_ptr() as *const _,
+                replacement.len() as isize,
+            );
+            ffi::GFALSE
+        }
+
+        unsafe {
+            let mut data = EvalData {
+                regex: self,
+                string,
+                func,
+            };
+            let mut error = ptr::null_mut();
+            let ret = ffi::g_regex_replace_eval(
+                self.to_glib_none().0,
+                string.as_ptr() as *const _,
+                string.len() as isize,
+                0,
+                match_flags.to_glib(),
+                Some(eval_trampoline::<F, R>),
+                &mut data as *mut EvalData<F> as ffi::gpointer,
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib_full(ret))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+}
+
+unsafe impl Send for Regex {}
+unsafe impl Sync for Regex {}
+
+/// A match of a [`Regex`] and its capture groups.
+///
+/// The positions of all groups are copied out of the `GMatchInfo`, so a match stays valid while
+/// the iterator advances.
+#[derive(Clone)]
+pub struct Match<'s> {
+    regex: Regex,
+    string: &'s str,
+    groups: Vec<Option<(usize, usize)>>,
+}
+
+impl<'s> Match<'s> {
+    unsafe fn from_match_info(
+        regex: &Regex,
+        string: &'s str,
+        match_info: *const ffi::GMatchInfo,
+    ) -> Self {
+        let groups = (0..=regex.get_capture_count())
+            .map(|group| {
+                let mut start = -1;
+                let mut end = -1;
+                let found =
+                    ffi::g_match_info_fetch_pos(match_info, group as i32, &mut start, &mut end);
+                // Groups that didn't participate in the match have the position -1
+                if found == ffi::GFALSE || start < 0 || end < 0 {
+                    None
+                } else {
+                    Some((start as usize, end as usize))
+                }
+            })
+            .collect();
+
+        Self {
+            regex: regex.clone(),
+            string,
+            groups,
+        }
+    }
+
+    /// Returns the byte offset of the start of the match.
+    pub fn start(&self) -> usize {
+        self.range().start
+    }
+
+    /// Returns the byte offset after the end of the match.
+    pub fn end(&self) -> usize {
+        self.range().end
+    }
+
+    /// Returns the byte range of the match.
+    pub fn range(&self) -> Range<usize> {
+        self.group_range(0)
+            .expect("Match without whole match group")
+    }
+
+    /// Returns the matched part of the string.
+    pub fn as_str(&self) -> &'s str {
+        self.group(0).expect("Match without whole match group")
+    }
+
+    /// Returns the number of groups, including the whole match at index 0.
+    pub fn group_count(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns the byte range of the capture group `index`, or `None` if it didn't participate
+    /// in the match.
+    #[doc(alias = "g_match_info_fetch_pos")]
+    pub fn group_range(&self, index: usize) -> Option<Range<usize>> {
+        self.groups
+            .get(index)
+            .copied()
+            .flatten()
+            .map(|(start, end)| start..end)
+    }
+
+    /// Returns the text of the capture group `index`, or `None` if it didn't participate in the
+    /// match.
+    #[doc(alias = "g_match_info_fetch")]
+    pub fn group(&self, index: usize) -> Option<&'s str> {
+        self.group_range(index).map(|range| &self.string[range])
+    }
+
+    /// Returns the text of the capture group named `name`.
+    ///
+    /// If the expression was compiled with [`RegexCompileFlags::DUPNAMES`], this returns the
+    /// first group with this name.
+    #[doc(alias = "g_match_info_fetch_named")]
+    pub fn name(&self, name: &str) -> Option<&'s str> {
+        self.regex
+            .get_string_number(name)
+            .and_then(|index| self.group(index))
+    }
+
+    /// Returns an iterator over the texts of all groups, including the whole match.
+    #[doc(alias = "g_match_info_fetch_all")]
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'s str>> + '_ {
+        (0..self.group_count()).map(move |index| self.group(index))
+    }
+}
+
+impl<'s> fmt::Debug for Match<'s> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Match")
+            .field("range", &self.range())
+            .field("groups", &self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// An iterator over the matches of a [`Regex`], created with [`Regex::find_iter`].
+pub struct Matches<'s> {
+    regex: Regex,
+    string: &'s str,
+    match_info: *mut ffi::GMatchInfo,
+    error: Option<Error>,
+    started: bool,
+    done: bool,
+}
+
+impl<'s> Iterator for Matches<'s> {
+    type Item = Result<Match<'s>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(error) = self.error.take() {
+            self.done = true;
+            return Some(Err(error));
+        }
+
+        unsafe {
+            if self.started {
+                let mut error = ptr::null_mut();
+                ffi::g_match_info_next(self.match_info, &mut error);
+                if !error.is_null() {
+                    self.done = true;
+                    return Some(Err(from_glib_full(error)));
+                }
+            }
+            self.started = true;
+
+            if self.match_info.is_null()
+                || ffi::g_match_info_matches(self.match_info) == ffi::GFALSE
+            {
+                self.done = true;
+                return None;
+            }
+
+            Some(Ok(Match::from_match_info(
+                &self.regex,
+                self.string,
+                self.match_info,
+            )))
+        }
+    }
+}
+
+impl<'s> Drop for Matches<'s> {
+    fn drop(&mut self) {
+        if !self.match_info.is_null() {
+            unsafe { ffi::g_match_info_free(self.match_info) };
+        }
+    }
+}
+
+impl<'s> fmt::Debug for Matches<'s> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Matches")
+            .field("regex", &self.regex.get_pattern())
+            .field("string", &self.string)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regex(pattern: &str) -> Regex {
+        Regex::new(
+            pattern,
+            RegexCompileFlags::empty(),
+            RegexMatchFlags::empty(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let r = regex(r"(a)(b)?");
+        assert_eq!(r.get_pattern(), r"(a)(b)?");
+        assert_eq!(r.get_capture_count(), 2);
+
+        let err =
+            Regex::new("(", RegexCompileFlags::empty(), RegexMatchFlags::empty()).unwrap_err();
+        assert!(err.matches(RegexError::UnmatchedParenthesis));
+        assert!(Regex::new("a", RegexCompileFlags::RAW, RegexMatchFlags::empty()).is_err());
+
+        assert!(Regex::match_simple(
+            "^A",
+            "abc",
+            RegexCompileFlags::CASELESS,
+            RegexMatchFlags::empty()
+        ));
+        assert_eq!(Regex::escape_string("a.b*"), r"a\.b\*");
+    }
+
+    #[test]
+    fn find_iter() {
+        let r = regex(r"(?<word>\w+)(?:=(?<value>\d+))?");
+        let matches = r
+            .find_iter("ä=1 b é=3", RegexMatchFlags::empty())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(matches.len(), 3);
+
+        assert_eq!(matches[0].as_str(), "ä=1");
+        assert_eq!(matches[0].range(), 0..4);
+        assert_eq!(matches[0].name("word"), Some("ä"));
+        assert_eq!(matches[0].name("value"), Some("1"));
+        assert_eq!(matches[0].name("missing"), None);
+
+        assert_eq!(matches[1].as_str(), "b");
+        assert_eq!(matches[1].group_count(), 3);
+        assert_eq!(matches[1].group(2), None);
+        assert_eq!(
+            matches[1].iter().collect::<Vec<_>>(),
+            vec![Some("b"), Some("b"), None]
+        );
+
+        assert_eq!(matches[2].start(), 7);
+        assert_eq!(matches[2].group(1), Some("é"));
+
+        assert!(r.is_match("x", RegexMatchFlags::empty()));
+        assert!(!r.is_match("-", RegexMatchFlags::empty()));
+        assert!(r.find(" ", RegexMatchFlags::empty()).unwrap().is_none());
+    }
+
+    #[test]
+    fn replace() {
+        let r = regex(r"(\w+)@(\w+)");
+        assert_eq!(
+            r.replace("a@b c@d", r"\2@\1", RegexMatchFlags::empty())
+                .unwrap(),
+            "b@a d@c"
+        );
+        assert_eq!(
+            r.replace_literal("a@b", r"\1", RegexMatchFlags::empty())
+                .unwrap(),
+            r"\1"
+        );
+        assert!(r.replace("a@b", r"\g<", RegexMatchFlags::empty()).is_err());
+
+        let mut count = 0;
+        let replaced = r
+            .replace_with("a@b, c@d", RegexMatchFlags::empty(), |m| {
+                count += 1;
+                format!("{} at {}", m.group(1).unwrap(), m.group(2).unwrap())
+            })
+            .unwrap();
+        assert_eq!(replaced, "a at b, c at d");
+        assert_eq!(count, 2);
+
+        assert!(Regex::check_replacement(r"\1").unwrap());
+        assert!(!Regex::check_replacement("x").unwrap());
+    }
+
+    #[test]
+    fn split() {
+        let r = regex(r"\s*,\s*");
+        assert_eq!(
+            r.split("a , b,c", RegexMatchFlags::empty(), 0).unwrap(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            r.split("a , b,c", RegexMatchFlags::empty(), 2).unwrap(),
+            vec!["a", "b,c"]
+        );
+    }
+}
@@ -3,12 +3,15 @@
 //! `IMPL` Low level signal support.
 
 use crate::object::ObjectType;
+use crate::subclass::{SignalId, SignalInvocationHint};
 use crate::translate::{from_glib, FromGlib, ToGlib, ToGlibPtr};
+use crate::{Quark, SignalFlags, Value};
 use ffi::{gboolean, gpointer};
 use gobject_ffi::{self, GCallback};
-use libc::{c_char, c_ulong, c_void};
+use libc::{c_char, c_uint, c_ulong, c_void};
 use std::mem;
 use std::num::NonZeroU64;
+use std::slice;
 
 /// The id of a signal that is returned by `connect`.
 ///
@@ -141,3 +144,94 @@ pub fn signal_stop_emission_by_name<T: ObjectType>(instance: &T, signal_name: &s
         );
     }
 }
+
+/// An emission hook added with [`signal_add_emission_hook`].
+///
+/// The hook is removed again when this is dropped.
+#[must_use = "the emission hook is removed again when this is dropped"]
+#[derive(Debug)]
+pub struct EmissionHook {
+    signal_id: SignalId,
+    hook_id: NonZeroU64,
+}
+
+impl Drop for EmissionHook {
+    fn drop(&mut self) {
+        unsafe {
+            gobject_ffi::g_signal_remove_emission_hook(
+                self.signal_id.to_glib(),
+                self.hook_id.get() as c_ulong,
+            );
+        }
+    }
+}
+
+/// Adds a hook that is called whenever the signal `signal_id` is emitted on any instance, for
+/// the given `detail` only if it is set.
+///
+/// The hook gets the arguments of the signal including the instance it is emitted on, and is
+/// called from whatever thread the signal is emitted on. It is called before any handler of
+/// the signal.
+///
+/// # Panics
+///
+/// Panics if the signal was registered with [`SignalFlags::NO_HOOKS`].
+#[doc(alias = "g_signal_add_emission_hook")]
+pub fn signal_add_emission_hook<F>(
+    signal_id: SignalId,
+    detail: Option<Quark>,
+    hook: F,
+) -> EmissionHook
+where
+    F: Fn(&SignalInvocationHint, &[Value]) + Send + Sync + 'static,
+{
+    unsafe extern "C" fn emission_hook_trampoline<F>(
+        ihint: *mut gobject_ffi::GSignalInvocationHint,
+        n_param_values: c_uint,
+        param_values: *const gobject_ffi::GValue,
+        data: gpointer,
+    ) -> gboolean
+    where
+        F: Fn(&SignalInvocationHint, &[Value]) + Send + Sync + 'static,
+    {
+        let hook: &F = &*(data as *const F);
+        let values = slice::from_raw_parts(param_values as *const Value, n_param_values as usize);
+        hook(&*(ihint as *const SignalInvocationHint), values);
+        // Hooks are only removed by dropping the `EmissionHook`
+        true.to_glib()
+    }
+
+    unsafe extern "C" fn destroy_hook<F>(data: gpointer) {
+        Box::<F>::from_raw(data as *mut _);
+    }
+
+    let query = signal_id.query();
+    assert!(
+        !query.flags().contains(SignalFlags::NO_HOOKS),
+        "Signal '{}' doesn't support emission hooks",
+        query.signal_name()
+    );
+
+    unsafe {
+        let hook: *mut F = Box::into_raw(Box::new(hook));
+        let hook_id = gobject_ffi::g_signal_add_emission_hook(
+            signal_id.to_glib(),
+            detail.map_or(0, |detail| detail.to_glib()),
+            Some(emission_hook_trampoline::<F>),
+            hook as gpointer,
+            Some(destroy_hook::<F>),
+        );
+        // GLib doesn't call the destroy notify if it fails to add the hook
+        if hook_id == 0 {
+            drop(Box::from_raw(hook));
+            panic!(
+                "Failed to add emission hook to signal '{}'",
+                query.signal_name()
+            );
+        }
+        EmissionHook {
+            signal_id,
+            hook_id: NonZeroU64::new_unchecked(hook_id as u64),
+        }
+    }
+}
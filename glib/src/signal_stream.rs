@@ -0,0 +1,118 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::object::{IsA, ObjectExt, WeakRef};
+use crate::{Object, SignalHandlerId};
+use futures_channel::mpsc;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use std::fmt;
+use std::pin::Pin;
+
+/// A `Stream` of the values sent by signal handlers, created with [`signal_stream`].
+///
+/// The signal handlers are disconnected once the stream is dropped.
+pub struct SignalStream<T> {
+    object: WeakRef<Object>,
+    handlers: Vec<SignalHandlerId>,
+    receiver: mpsc::UnboundedReceiver<T>,
+}
+
+/// Returns a `Stream` of the values sent by the signal handlers that `connect` connects to
+/// `obj`.
+///
+/// `connect` is called with `obj` and the sender of the stream and returns the ids of the
+/// connected handlers, which are disconnected once the stream is dropped. The stream doesn't keep
+/// `obj` alive and ends once the handlers and with them the sender are dropped.
+///
+/// ```
+/// use futures_util::StreamExt;
+/// use glib::prelude::*;
+///
+/// # fn run(obj: &glib::Object) {
+/// let mut names = glib::signal_stream(obj, |obj, sender| {
+///     vec![obj.connect_notify(None, move |_, pspec| {
+///         let _ = sender.unbounded_send(pspec.get_name().to_owned());
+///     })]
+/// });
+/// glib::MainContext::default().spawn_local(async move {
+///     while let Some(name) = names.next().await {
+///         println!("{} changed", name);
+///     }
+/// });
+/// # }
+/// ```
+pub fn signal_stream<O, T, F>(obj: &O, connect: F) -> SignalStream<T>
+where
+    O: IsA<Object>,
+    F: FnOnce(&O, mpsc::UnboundedSender<T>) -> Vec<SignalHandlerId>,
+{
+    let (sender, receiver) = mpsc::unbounded();
+    let handlers = connect(obj, sender);
+
+    SignalStream {
+        object: obj.upcast_ref().downgrade(),
+        handlers,
+        receiver,
+    }
+}
+
+impl<T> Stream for SignalStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(ctx)
+    }
+}
+
+impl<T> Drop for SignalStream<T> {
+    fn drop(&mut self) {
+        if let Some(object) = self.object.upgrade() {
+            for handler in self.handlers.drain(..) {
+                object.disconnect(handler);
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for SignalStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SignalStream")
+            .field("object", &self.object)
+            .field("handlers", &self.handlers)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParamFlags, ParamSpec};
+    use futures_util::{FutureExt, StreamExt};
+    use std::sync::Arc;
+
+    #[test]
+    fn signal_stream() {
+        let obj = Object::new(&[]).unwrap();
+        let pspec = ParamSpec::boolean("flag", "Flag", "Flag", false, ParamFlags::READWRITE);
+        let handler_data = Arc::new(());
+
+        let handler_data_clone = handler_data.clone();
+        let mut stream = super::signal_stream(&obj, move |obj, sender| {
+            vec![obj.connect_notify(None, move |_, pspec| {
+                let _ = &handler_data_clone;
+                let _ = sender.unbounded_send(pspec.get_name().to_owned());
+            })]
+        });
+        assert_eq!(stream.next().now_or_never(), None);
+
+        obj.emit_by_name("notify", &[&pspec]).unwrap();
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some(String::from("flag")))
+        );
+
+        // The handler is disconnected and dropped together with the stream
+        drop(stream);
+        assert_eq!(Arc::strong_count(&handler_data), 1);
+    }
+}
@@ -0,0 +1,366 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops;
+use std::ptr;
+
+/// A box allocated with the GLib slice allocator.
+///
+/// This is needed for values whose ownership is passed to C code that frees them with
+/// `g_slice_free()`, or that are received from C code that allocated them with
+/// `g_slice_new()`.
+///
+/// Use a [`SlicePool`] to reuse the allocations of values that are created and dropped often.
+#[doc(alias = "g_slice_new")]
+pub struct SliceBox<T> {
+    ptr: ptr::NonNull<T>,
+    phantom: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for SliceBox<T> {}
+unsafe impl<T: Sync> Sync for SliceBox<T> {}
+
+impl<T> SliceBox<T> {
+    /// Moves `value` into a new slice allocation.
+    #[doc(alias = "g_slice_alloc")]
+    pub fn new(value: T) -> Self {
+        unsafe {
+            let ptr = alloc::<T>();
+            ptr::write(ptr.as_ptr(), value);
+
+            SliceBox {
+                ptr,
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Takes ownership of a value allocated with the slice allocator.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated with `g_slice_alloc()` or `g_slice_new()` for the size
+    /// of `T`, e.g. by [`SliceBox::into_raw`], and point to a valid value that is not owned by
+    /// anything else.
+    pub unsafe fn from_raw(ptr: *mut T) -> Self {
+        assert!(!ptr.is_null());
+
+        SliceBox {
+            ptr: ptr::NonNull::new_unchecked(ptr),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gives up ownership of the value, which has to be freed again with `g_slice_free()` or
+    /// [`SliceBox::from_raw`].
+    pub fn into_raw(this: Self) -> *mut T {
+        let ptr = this.ptr.as_ptr();
+        mem::forget(this);
+        ptr
+    }
+
+    /// Moves the value out of the slice allocation.
+    pub fn into_inner(this: Self) -> T {
+        unsafe {
+            let value = ptr::read(this.ptr.as_ptr());
+            free::<T>(this.ptr.as_ptr());
+            mem::forget(this);
+            value
+        }
+    }
+}
+
+fn alloc<T>() -> ptr::NonNull<T> {
+    assert!(
+        mem::align_of::<T>() <= 2 * mem::size_of::<usize>(),
+        "Alignment of {} is too big for the slice allocator",
+        std::any::type_name::<T>(),
+    );
+
+    if mem::size_of::<T>() == 0 {
+        ptr::NonNull::dangling()
+    } else {
+        unsafe {
+            ptr::NonNull::new(ffi::g_slice_alloc(mem::size_of::<T>()) as *mut T)
+                .expect("Slice allocation failed")
+        }
+    }
+}
+
+unsafe fn free<T>(ptr: *mut T) {
+    if mem::size_of::<T>() != 0 {
+        ffi::g_slice_free1(mem::size_of::<T>(), ptr as ffi::gpointer);
+    }
+}
+
+impl<T> Drop for SliceBox<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.ptr.as_ptr());
+            free::<T>(self.ptr.as_ptr());
+        }
+    }
+}
+
+impl<T> ops::Deref for SliceBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.ptr.as_ptr() }
+    }
+}
+
+impl<T> ops::DerefMut for SliceBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.ptr.as_ptr() }
+    }
+}
+
+impl<T> AsRef<T> for SliceBox<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> AsMut<T> for SliceBox<T> {
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: Clone> Clone for SliceBox<T> {
+    fn clone(&self) -> Self {
+        SliceBox::new((**self).clone())
+    }
+}
+
+impl<T: Default> Default for SliceBox<T> {
+    fn default() -> Self {
+        SliceBox::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SliceBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for SliceBox<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for SliceBox<T> {}
+
+/// A pool of slice allocations for values of type `T`.
+///
+/// Values allocated from the pool are [`SlicePoolBox`]es borrowing it. When they are dropped,
+/// their allocation is kept by the pool and reused for the next value instead of being returned
+/// to the slice allocator. All kept allocations are freed together once the pool is dropped.
+///
+/// ```
+/// let pool = glib::SlicePool::new();
+/// let first = pool.alloc([0u8; 64]);
+/// drop(first);
+/// assert_eq!(pool.get_available(), 1);
+///
+/// // Reuses the allocation of `first`
+/// let second = pool.alloc([1u8; 64]);
+/// assert_eq!(pool.get_available(), 0);
+/// # drop(second);
+/// ```
+pub struct SlicePool<T> {
+    free: RefCell<Vec<ptr::NonNull<T>>>,
+}
+
+impl<T> SlicePool<T> {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        SlicePool {
+            free: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Creates a pool with `capacity` allocations already available.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SlicePool {
+            free: RefCell::new((0..capacity).map(|_| alloc::<T>()).collect()),
+        }
+    }
+
+    /// Moves `value` into an allocation of the pool, or a new slice allocation if none is
+    /// available.
+    pub fn alloc(&self, value: T) -> SlicePoolBox<T> {
+        let ptr = self.free.borrow_mut().pop().unwrap_or_else(alloc::<T>);
+        unsafe {
+            ptr::write(ptr.as_ptr(), value);
+        }
+
+        SlicePoolBox { ptr, pool: self }
+    }
+
+    /// Returns the number of allocations that are available for reuse.
+    pub fn get_available(&self) -> usize {
+        self.free.borrow().len()
+    }
+
+    /// Returns the allocations available for reuse to the slice allocator.
+    pub fn trim(&self) {
+        for ptr in self.free.borrow_mut().drain(..) {
+            unsafe {
+                free::<T>(ptr.as_ptr());
+            }
+        }
+    }
+}
+
+impl<T> Default for SlicePool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for SlicePool<T> {
+    fn drop(&mut self) {
+        self.trim();
+    }
+}
+
+impl<T> fmt::Debug for SlicePool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SlicePool")
+            .field("available", &self.get_available())
+            .finish()
+    }
+}
+
+/// A value allocated from a [`SlicePool`], whose allocation is returned to the pool once it is
+/// dropped.
+pub struct SlicePoolBox<'a, T> {
+    ptr: ptr::NonNull<T>,
+    pool: &'a SlicePool<T>,
+}
+
+impl<'a, T> SlicePoolBox<'a, T> {
+    /// Moves the value out of the pool, e.g. to pass its ownership to C code.
+    ///
+    /// The allocation is not returned to the pool, but stays a slice allocation.
+    pub fn detach(this: Self) -> SliceBox<T> {
+        let ptr = this.ptr;
+        mem::forget(this);
+        SliceBox {
+            ptr,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Moves the value out of the allocation, which is returned to the pool.
+    pub fn into_inner(this: Self) -> T {
+        let value = unsafe { ptr::read(this.ptr.as_ptr()) };
+        this.pool.free.borrow_mut().push(this.ptr);
+        mem::forget(this);
+        value
+    }
+}
+
+impl<'a, T> Drop for SlicePoolBox<'a, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.ptr.as_ptr());
+        }
+        self.pool.free.borrow_mut().push(self.ptr);
+    }
+}
+
+impl<'a, T> ops::Deref for SlicePoolBox<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.ptr.as_ptr() }
+    }
+}
+
+impl<'a, T> ops::DerefMut for SlicePoolBox<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.ptr.as_ptr() }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for SlicePoolBox<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn new_and_drop() {
+        let value = Rc::new(());
+        let mut slice_box = SliceBox::new((1u64, value.clone()));
+        assert_eq!(Rc::strong_count(&value), 2);
+        slice_box.0 += 1;
+        assert_eq!(slice_box.0, 2);
+
+        drop(slice_box.clone());
+        drop(slice_box);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        // Zero-sized values are not allocated
+        let unit = SliceBox::new(());
+        SliceBox::into_inner(unit);
+    }
+
+    #[test]
+    fn raw() {
+        let ptr = SliceBox::into_raw(SliceBox::new([1u8, 2, 3]));
+        unsafe {
+            (*ptr)[0] = 4;
+            let slice_box = SliceBox::from_raw(ptr);
+            assert_eq!(SliceBox::into_inner(slice_box), [4, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn pool() {
+        let value = Rc::new(());
+        let pool = SlicePool::new();
+        let first = pool.alloc((1u64, value.clone()));
+        let first_ptr = &*first as *const _;
+        assert_eq!(pool.get_available(), 0);
+        drop(first);
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert_eq!(pool.get_available(), 1);
+
+        let mut second = pool.alloc((2u64, value.clone()));
+        assert_eq!(&*second as *const _, first_ptr);
+        assert_eq!(pool.get_available(), 0);
+        second.0 += 1;
+        let (number, _) = SlicePoolBox::into_inner(second);
+        assert_eq!(number, 3);
+        assert_eq!(pool.get_available(), 1);
+
+        // Detached values don't return to the pool
+        let third = SlicePoolBox::detach(pool.alloc((4u64, value.clone())));
+        assert_eq!(pool.get_available(), 0);
+        assert_eq!(third.0, 4);
+        drop(third);
+        assert_eq!(pool.get_available(), 0);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let pool = SlicePool::<u64>::with_capacity(3);
+        assert_eq!(pool.get_available(), 3);
+        let _fourth = pool.alloc(4);
+        assert_eq!(pool.get_available(), 2);
+        pool.trim();
+        assert_eq!(pool.get_available(), 0);
+    }
+}
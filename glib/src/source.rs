@@ -86,7 +86,8 @@ impl ToGlib for Continue {
 
 unsafe extern "C" fn trampoline<F: FnMut() -> Continue + 'static>(func: gpointer) -> gboolean {
     let func: &RefCell<F> = &*(func as *const RefCell<F>);
-    (&mut *func.borrow_mut())().to_glib()
+    crate::profiling::dispatch(ffi::g_main_current_source(), || (&mut *func.borrow_mut())())
+        .to_glib()
 }
 
 unsafe extern "C" fn destroy_closure<F: FnMut() -> Continue + 'static>(ptr: gpointer) {
@@ -104,7 +105,9 @@ unsafe extern "C" fn trampoline_child_watch<F: FnMut(Pid, i32) + 'static>(
     func: gpointer,
 ) {
     let func: &RefCell<F> = &*(func as *const RefCell<F>);
-    (&mut *func.borrow_mut())(Pid(pid), status)
+    crate::profiling::dispatch(ffi::g_main_current_source(), || {
+        (&mut *func.borrow_mut())(Pid(pid), status)
+    })
 }
 
 unsafe extern "C" fn destroy_closure_child_watch<F: FnMut(Pid, i32) + 'static>(ptr: gpointer) {
@@ -124,7 +127,10 @@ unsafe extern "C" fn trampoline_unix_fd<F: FnMut(RawFd, IOCondition) -> Continue
     func: gpointer,
 ) -> gboolean {
     let func: &RefCell<F> = &*(func as *const RefCell<F>);
-    (&mut *func.borrow_mut())(fd, from_glib(condition)).to_glib()
+    crate::profiling::dispatch(ffi::g_main_current_source(), || {
+        (&mut *func.borrow_mut())(fd, from_glib(condition))
+    })
+    .to_glib()
 }
 
 #[cfg(any(unix, feature = "dox"))]
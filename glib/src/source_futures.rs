@@ -6,11 +6,15 @@ use futures_core::stream::Stream;
 use futures_core::task;
 use futures_core::task::Poll;
 use std::marker::Unpin;
+#[cfg(unix)]
+use std::os::unix::io::RawFd;
 use std::pin;
 use std::pin::Pin;
 use std::time::Duration;
 
 use crate::Continue;
+#[cfg(any(unix, feature = "dox"))]
+use crate::IOCondition;
 use crate::MainContext;
 use crate::Priority;
 use crate::Source;
@@ -203,6 +207,42 @@ pub fn unix_signal_future_with_priority(
     }))
 }
 
+#[cfg(any(unix, feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(unix)))]
+/// Create a `Future` that will resolve once the given UNIX file descriptor reaches one of the
+/// given IO conditions
+///
+/// The `Future` will resolve to the conditions the file descriptor reached, which can also
+/// include `IOCondition::HUP`, `IOCondition::ERR` and `IOCondition::NVAL`.
+///
+/// The `Future` must be spawned on an `Executor` backed by a `glib::MainContext`.
+pub fn unix_fd_source_future(
+    fd: RawFd,
+    condition: IOCondition,
+) -> Pin<Box<dyn Future<Output = IOCondition> + Send + 'static>> {
+    unix_fd_source_future_with_priority(crate::PRIORITY_DEFAULT, fd, condition)
+}
+
+#[cfg(any(unix, feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(unix)))]
+/// Create a `Future` that will resolve once the given UNIX file descriptor reaches one of the
+/// given IO conditions
+///
+/// The `Future` must be spawned on an `Executor` backed by a `glib::MainContext`.
+pub fn unix_fd_source_future_with_priority(
+    priority: Priority,
+    fd: RawFd,
+    condition: IOCondition,
+) -> Pin<Box<dyn Future<Output = IOCondition> + Send + 'static>> {
+    Box::pin(SourceFuture::new(move |send| {
+        let mut send = Some(send);
+        crate::unix_fd_source_new(fd, condition, None, priority, move |_, condition| {
+            let _ = send.take().unwrap().send(condition);
+            Continue(false)
+        })
+    }))
+}
+
 /// Represents a `Stream` around a `glib::Source`. The stream will
 /// be provide all values that are provided by the source
 pub struct SourceStream<F, T> {
@@ -370,6 +410,82 @@ pub fn unix_signal_stream_with_priority(
     }))
 }
 
+#[cfg(any(unix, feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(unix)))]
+/// Create a `Stream` that will provide the IO conditions whenever the given UNIX file
+/// descriptor reaches one of the given IO conditions
+///
+/// The conditions have to be handled, e.g. by reading from the file descriptor, before the next
+/// value is requested from the `Stream`, otherwise they are provided again. The file descriptor
+/// is only watched while a value is requested. The `Stream` ends once the file descriptor is
+/// invalid, i.e. after providing `IOCondition::NVAL`.
+///
+/// The `Stream` must be spawned on an `Executor` backed by a `glib::MainContext`.
+pub fn unix_fd_source_stream(
+    fd: RawFd,
+    condition: IOCondition,
+) -> Pin<Box<dyn Stream<Item = IOCondition> + Send + 'static>> {
+    unix_fd_source_stream_with_priority(crate::PRIORITY_DEFAULT, fd, condition)
+}
+
+#[cfg(any(unix, feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(unix)))]
+/// Create a `Stream` that will provide the IO conditions whenever the given UNIX file
+/// descriptor reaches one of the given IO conditions
+///
+/// The `Stream` must be spawned on an `Executor` backed by a `glib::MainContext`.
+pub fn unix_fd_source_stream_with_priority(
+    priority: Priority,
+    fd: RawFd,
+    condition: IOCondition,
+) -> Pin<Box<dyn Stream<Item = IOCondition> + Send + 'static>> {
+    Box::pin(UnixFdSourceStream {
+        priority,
+        fd,
+        condition,
+        future: None,
+        done: false,
+    })
+}
+
+// The source is level-triggered and would fire on every main loop iteration until the condition
+// is handled, so a new one is only created when the next value is requested
+#[cfg(any(unix, feature = "dox"))]
+struct UnixFdSourceStream {
+    priority: Priority,
+    fd: RawFd,
+    condition: IOCondition,
+    future: Option<Pin<Box<dyn Future<Output = IOCondition> + Send + 'static>>>,
+    done: bool,
+}
+
+#[cfg(any(unix, feature = "dox"))]
+impl Stream for UnixFdSourceStream {
+    type Item = IOCondition;
+
+    fn poll_next(
+        mut self: pin::Pin<&mut Self>,
+        ctx: &mut task::Context,
+    ) -> Poll<Option<IOCondition>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        let (priority, fd, condition) = (self.priority, self.fd, self.condition);
+        let future = self
+            .future
+            .get_or_insert_with(|| unix_fd_source_future_with_priority(priority, fd, condition));
+        match future.as_mut().poll(ctx) {
+            Poll::Ready(condition) => {
+                self.future = None;
+                self.done = condition.contains(IOCondition::NVAL);
+                Poll::Ready(Some(condition))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -438,4 +554,48 @@ mod tests {
 
         assert_eq!(res, 1);
     }
+
+    #[cfg(unix)]
+    fn pipe() -> (RawFd, RawFd) {
+        let mut fds = [0; 2];
+        assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+        (fds[0], fds[1])
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_fd() {
+        let c = MainContext::new();
+        let (read_fd, write_fd) = pipe();
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            assert_eq!(
+                unsafe { libc::write(write_fd, b"x".as_ptr() as *const _, 1) },
+                1
+            );
+        });
+        let condition = c.block_on(unix_fd_source_future(read_fd, IOCondition::IN));
+        assert!(condition.contains(IOCondition::IN));
+
+        let mut stream = unix_fd_source_stream(read_fd, IOCondition::IN);
+        let condition = c.block_on(stream.next());
+        assert_eq!(condition, Some(IOCondition::IN));
+        // The fd is only watched again once the next condition is requested
+        assert!(!c.pending());
+
+        let mut buf = [0u8; 1];
+        assert_eq!(
+            unsafe { libc::read(read_fd, buf.as_mut_ptr() as *mut _, 1) },
+            1
+        );
+        unsafe {
+            libc::close(write_fd);
+        }
+        let condition = c.block_on(stream.next()).unwrap();
+        assert!(condition.contains(IOCondition::HUP));
+        unsafe {
+            libc::close(read_fd);
+        }
+    }
 }
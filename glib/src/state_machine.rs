@@ -0,0 +1,480 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate as glib;
+use crate::object::ObjectExt;
+use crate::subclass::prelude::*;
+use crate::subclass::{Signal, SignalId};
+use crate::value::FromValue;
+use crate::{
+    EnumClass, EnumValue, Object, ParamFlags, ParamSpec, Quark, SignalHandlerId, StaticType,
+    ToValue, Type, Value,
+};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+type GuardFunc = dyn Fn(&StateMachine) -> bool;
+
+struct Transition {
+    from: i32,
+    to: i32,
+    guard: Option<Rc<GuardFunc>>,
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct StateMachine {
+        pub(super) class: RefCell<Option<EnumClass>>,
+        pub(super) state: Cell<i32>,
+        pub(super) transitions: RefCell<Vec<Transition>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for StateMachine {
+        const NAME: &'static str = "GlibRsStateMachine";
+        type Type = super::StateMachine;
+        type ParentType = Object;
+    }
+
+    impl ObjectImpl for StateMachine {
+        fn properties() -> &'static [ParamSpec] {
+            use once_cell::sync::Lazy;
+            static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
+                vec![
+                    ParamSpec::gtype(
+                        "state-type",
+                        "State type",
+                        "Enum type of the states",
+                        Type::ENUM,
+                        ParamFlags::READWRITE | ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    ParamSpec::int(
+                        "state",
+                        "State",
+                        "Value of the current state",
+                        i32::min_value(),
+                        i32::max_value(),
+                        0,
+                        ParamFlags::READWRITE | ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    ParamSpec::string(
+                        "state-nick",
+                        "State nick",
+                        "Nick of the current state",
+                        None,
+                        ParamFlags::READABLE,
+                    ),
+                ]
+            });
+
+            PROPERTIES.as_ref()
+        }
+
+        fn signals() -> &'static [Signal] {
+            use once_cell::sync::Lazy;
+            static SIGNALS: Lazy<Vec<Signal>> = Lazy::new(|| {
+                vec![Signal::builder(
+                    "transitioned",
+                    &[i32::static_type().into(), i32::static_type().into()],
+                    Type::UNIT.into(),
+                )
+                .detailed()
+                .run_last()
+                .build()]
+            });
+
+            SIGNALS.as_ref()
+        }
+
+        fn set_property(&self, _obj: &Self::Type, _id: usize, value: &Value, pspec: &ParamSpec) {
+            match pspec.get_name() {
+                "state-type" => {
+                    let type_ = value.get_some::<Type>().unwrap();
+                    *self.class.borrow_mut() = EnumClass::new(type_);
+                }
+                "state" => self.state.set(value.get_some().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn get_property(&self, obj: &Self::Type, _id: usize, pspec: &ParamSpec) -> Value {
+            match pspec.get_name() {
+                "state-type" => obj.get_state_type().to_value(),
+                "state" => self.state.get().to_value(),
+                "state-nick" => obj.get_state_nick().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            // Construct properties are set in no particular order, so only check them now
+            let class = self.class.borrow();
+            let class = match *class {
+                Some(ref class) => class,
+                None => return,
+            };
+            if class.get_value(self.state.get()).is_none() {
+                crate::g_critical!(
+                    "GLib-rs",
+                    "{} is not a value of the state type {}",
+                    self.state.get(),
+                    class.type_()
+                );
+                if let Some(first) = class.get_values().first() {
+                    self.state.set(first.get_value());
+                }
+            }
+        }
+    }
+}
+
+wrapper! {
+    /// A state machine whose states are the values of an enum type, e.g. the pages of an
+    /// assistant.
+    ///
+    /// Only the transitions added with [`add_transition`](Self::add_transition) or
+    /// [`add_guarded_transition`](Self::add_guarded_transition) are allowed. The current state is
+    /// available as the `state` and `state-nick` properties, so that it can be bound to e.g. the
+    /// visible child name of a stack, and every transition emits the detailed `transitioned`
+    /// signal with the nick of the new state as detail.
+    ///
+    /// ```
+    /// use glib::prelude::*;
+    /// use glib::StateMachine;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, glib::GEnum)]
+    /// #[genum(type_name = "WizardPage")]
+    /// enum Page {
+    ///     Welcome,
+    ///     Account,
+    ///     Done,
+    /// }
+    ///
+    /// let accepted = std::rc::Rc::new(std::cell::Cell::new(false));
+    /// let machine = StateMachine::new(Page::Welcome);
+    /// machine.add_transition(Page::Welcome, Page::Account);
+    /// machine.add_transition(Page::Account, Page::Welcome);
+    /// let accepted_clone = accepted.clone();
+    /// machine.add_guarded_transition(Page::Account, Page::Done, move |_| accepted_clone.get());
+    ///
+    /// machine.transition_to(Page::Account).unwrap();
+    /// assert!(machine.transition_to(Page::Done).is_err());
+    /// accepted.set(true);
+    /// machine.transition_to(Page::Done).unwrap();
+    /// assert_eq!(machine.get_state::<Page>(), Page::Done);
+    /// assert_eq!(machine.get_state_nick(), "done");
+    /// ```
+    pub struct StateMachine(ObjectSubclass<imp::StateMachine>);
+}
+
+impl StateMachine {
+    /// Creates a state machine for the states of the enum type `T`, starting in `initial`.
+    pub fn new<T: StaticType + ToValue>(initial: T) -> Self {
+        let initial = EnumValue::from_value(&initial.to_value())
+            .expect("State type is not an enum type")
+            .get_value();
+        Self::with_type(T::static_type(), initial)
+    }
+
+    /// Creates a state machine for the states of the enum type `state_type`, starting in the
+    /// state with the value `initial`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state_type` is not an enum type or `initial` is not one of its values.
+    pub fn with_type(state_type: Type, initial: i32) -> Self {
+        let class = EnumClass::new(state_type)
+            .unwrap_or_else(|| panic!("{} is not an enum type", state_type));
+        assert!(
+            class.get_value(initial).is_some(),
+            "{} is not a value of the state type {}",
+            initial,
+            state_type
+        );
+        Object::new(&[("state-type", &state_type), ("state", &initial)])
+            .expect("Failed to create state machine")
+    }
+
+    /// Returns the enum type of the states.
+    pub fn get_state_type(&self) -> Type {
+        self.class()
+            .as_ref()
+            .map_or(Type::INVALID, |class| class.type_())
+    }
+
+    /// Returns the current state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not the state type.
+    pub fn get_state<T: StaticType + for<'a> FromValue<'a>>(&self) -> T {
+        self.check_type::<T>();
+        self.class()
+            .as_ref()
+            .and_then(|class| class.to_value(self.get_state_value()))
+            .expect("Invalid state")
+            .get_some::<T>()
+            .expect("Invalid state")
+    }
+
+    /// Returns the value of the current state.
+    pub fn get_state_value(&self) -> i32 {
+        imp::StateMachine::from_instance(self).state.get()
+    }
+
+    /// Returns the nick of the current state.
+    pub fn get_state_nick(&self) -> String {
+        self.nick(self.get_state_value())
+    }
+
+    /// Allows the transition from the state `from` to the state `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not the state type.
+    pub fn add_transition<T: StaticType + ToValue>(&self, from: T, to: T) {
+        let (from, to) = (self.enum_value(&from), self.enum_value(&to));
+        self.insert_transition(from, to, None);
+    }
+
+    /// Allows the transition from the state `from` to the state `to` while `guard` returns
+    /// `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not the state type.
+    pub fn add_guarded_transition<T, F>(&self, from: T, to: T, guard: F)
+    where
+        T: StaticType + ToValue,
+        F: Fn(&Self) -> bool + 'static,
+    {
+        let (from, to) = (self.enum_value(&from), self.enum_value(&to));
+        self.insert_transition(from, to, Some(Rc::new(guard)));
+    }
+
+    /// Removes the transition from the state `from` to the state `to`.
+    pub fn remove_transition<T: StaticType + ToValue>(&self, from: T, to: T) {
+        let (from, to) = (self.enum_value(&from), self.enum_value(&to));
+        imp::StateMachine::from_instance(self)
+            .transitions
+            .borrow_mut()
+            .retain(|transition| transition.from != from || transition.to != to);
+    }
+
+    /// Returns `true` if the transition from the current state to `to` is allowed and its
+    /// guard, if any, returns `true`.
+    pub fn can_transition_to<T: StaticType + ToValue>(&self, to: T) -> bool {
+        self.check_transition(self.enum_value(&to)).is_ok()
+    }
+
+    /// Changes the current state to `to`.
+    ///
+    /// Fails without changing the state if the transition from the current state to `to` is
+    /// not allowed or its guard returns `false`.
+    pub fn transition_to<T: StaticType + ToValue>(&self, to: T) -> Result<(), crate::BoolError> {
+        let to = self.enum_value(&to);
+        self.check_transition(to)?;
+
+        let from = self.get_state_value();
+        imp::StateMachine::from_instance(self).state.set(to);
+        self.notify("state");
+        self.notify("state-nick");
+
+        let signal_id = SignalId::lookup("transitioned", Self::static_type()).unwrap();
+        self.emit_with_details(signal_id, Quark::from_string(&self.nick(to)), &[&from, &to])
+            .map(|_| ())
+    }
+
+    /// Connects to the `transitioned` signal, which is emitted after every transition with the
+    /// previous and the new state.
+    ///
+    /// With `state_nick` set `f` is only called for transitions to the state with that nick.
+    pub fn connect_transitioned<F: Fn(&Self, &EnumValue, &EnumValue) + 'static>(
+        &self,
+        state_nick: Option<&str>,
+        f: F,
+    ) -> SignalHandlerId {
+        let signal_name = match state_nick {
+            Some(nick) => format!("transitioned::{}", nick),
+            None => String::from("transitioned"),
+        };
+        self.connect_local(signal_name.as_str(), false, move |values| {
+            let obj = values[0].get_some::<StateMachine>().unwrap();
+            let class = obj.class().clone().unwrap();
+            let from = class.get_value(values[1].get_some().unwrap()).unwrap();
+            let to = class.get_value(values[2].get_some().unwrap()).unwrap();
+            f(&obj, &from, &to);
+            None
+        })
+        .unwrap()
+    }
+
+    fn class(&self) -> std::cell::Ref<Option<EnumClass>> {
+        imp::StateMachine::from_instance(self).class.borrow()
+    }
+
+    fn nick(&self, value: i32) -> String {
+        self.class()
+            .as_ref()
+            .and_then(|class| class.get_value(value))
+            .map(|value| value.get_nick().to_owned())
+            .unwrap_or_default()
+    }
+
+    fn check_type<T: StaticType>(&self) {
+        assert_eq!(
+            T::static_type(),
+            self.get_state_type(),
+            "Wrong state type {}",
+            T::static_type()
+        );
+    }
+
+    fn enum_value<T: StaticType + ToValue>(&self, state: &T) -> i32 {
+        self.check_type::<T>();
+        EnumValue::from_value(&state.to_value())
+            .expect("Invalid state")
+            .get_value()
+    }
+
+    fn insert_transition(&self, from: i32, to: i32, guard: Option<Rc<GuardFunc>>) {
+        let mut transitions = imp::StateMachine::from_instance(self)
+            .transitions
+            .borrow_mut();
+        match transitions
+            .iter_mut()
+            .find(|transition| transition.from == from && transition.to == to)
+        {
+            Some(transition) => transition.guard = guard,
+            None => transitions.push(Transition { from, to, guard }),
+        }
+    }
+
+    fn check_transition(&self, to: i32) -> Result<(), crate::BoolError> {
+        let from = self.get_state_value();
+        let guard = imp::StateMachine::from_instance(self)
+            .transitions
+            .borrow()
+            .iter()
+            .find(|transition| transition.from == from && transition.to == to)
+            .map(|transition| transition.guard.clone())
+            .ok_or_else(|| {
+                crate::bool_error!(
+                    "No transition from {} to {}",
+                    self.nick(from),
+                    self.nick(to)
+                )
+            })?;
+
+        // The guard is called without borrowing the transitions, so that it can add some
+        match guard {
+            Some(guard) if !guard(self) => Err(crate::bool_error!(
+                "Transition from {} to {} is not allowed by its guard",
+                self.nick(from),
+                self.nick(to)
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as glib;
+
+    #[derive(Clone, Copy, Debug, PartialEq, glib::GEnum)]
+    #[genum(type_name = "StateMachineTestState")]
+    enum State {
+        Idle,
+        Running,
+        Finished,
+    }
+
+    #[test]
+    fn transitions() {
+        let machine = StateMachine::new(State::Idle);
+        assert_eq!(machine.get_state_type(), State::static_type());
+        assert_eq!(machine.get_state::<State>(), State::Idle);
+        assert_eq!(machine.get_state_nick(), "idle");
+
+        machine.add_transition(State::Idle, State::Running);
+        machine.add_transition(State::Running, State::Finished);
+        assert!(machine.can_transition_to(State::Running));
+        assert!(!machine.can_transition_to(State::Finished));
+        let err = machine.transition_to(State::Finished).unwrap_err();
+        assert_eq!(err.to_string(), "No transition from idle to finished");
+
+        machine.transition_to(State::Running).unwrap();
+        assert_eq!(machine.get_state::<State>(), State::Running);
+        assert_eq!(
+            machine
+                .get_property("state")
+                .unwrap()
+                .get_some::<i32>()
+                .unwrap(),
+            State::Running as i32
+        );
+
+        machine.remove_transition(State::Running, State::Finished);
+        assert!(!machine.can_transition_to(State::Finished));
+    }
+
+    #[test]
+    fn guards() {
+        let allowed = Rc::new(Cell::new(false));
+        let machine = StateMachine::new(State::Idle);
+        let allowed_clone = allowed.clone();
+        machine.add_guarded_transition(State::Idle, State::Running, move |_| allowed_clone.get());
+
+        assert!(!machine.can_transition_to(State::Running));
+        assert!(machine.transition_to(State::Running).is_err());
+        assert_eq!(machine.get_state::<State>(), State::Idle);
+
+        allowed.set(true);
+        machine.transition_to(State::Running).unwrap();
+        assert_eq!(machine.get_state::<State>(), State::Running);
+    }
+
+    #[test]
+    fn signals_and_bindings() {
+        let machine = StateMachine::new(State::Idle);
+        machine.add_transition(State::Idle, State::Running);
+        machine.add_transition(State::Running, State::Finished);
+
+        let transitions = Rc::new(RefCell::new(Vec::new()));
+        let transitions_clone = transitions.clone();
+        machine.connect_transitioned(None, move |_, from, to| {
+            transitions_clone
+                .borrow_mut()
+                .push((from.get_nick().to_owned(), to.get_nick().to_owned()));
+        });
+        let finished = Rc::new(Cell::new(0));
+        let finished_clone = finished.clone();
+        machine.connect_transitioned(Some("finished"), move |_, _, _| {
+            finished_clone.set(finished_clone.get() + 1);
+        });
+
+        let notified = Rc::new(RefCell::new(Vec::new()));
+        let notified_clone = notified.clone();
+        machine.connect_notify_local(Some("state-nick"), move |machine, _| {
+            notified_clone.borrow_mut().push(machine.get_state_nick());
+        });
+
+        machine.transition_to(State::Running).unwrap();
+        machine.transition_to(State::Finished).unwrap();
+
+        assert_eq!(
+            *transitions.borrow(),
+            vec![
+                ("idle".to_owned(), "running".to_owned()),
+                ("running".to_owned(), "finished".to_owned())
+            ]
+        );
+        assert_eq!(finished.get(), 1);
+        assert_eq!(*notified.borrow(), vec!["running", "finished"]);
+    }
+}
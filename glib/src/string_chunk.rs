@@ -0,0 +1,124 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::translate::*;
+use std::ptr;
+use std::slice;
+use std::str;
+
+/// A memory arena for strings.
+///
+/// The strings inserted into the chunk are copied into large blocks of memory, which are only
+/// freed together when the chunk is cleared or dropped. This avoids allocating every string
+/// separately, e.g. for the names and values of a parsed document.
+///
+/// The inserted strings are nul-terminated, so they can also be passed to C code that expects
+/// strings from a `GStringChunk`.
+#[derive(Debug)]
+#[doc(alias = "GStringChunk")]
+pub struct StringChunk(ptr::NonNull<ffi::GStringChunk>);
+
+unsafe impl Send for StringChunk {}
+
+impl StringChunk {
+    /// Creates a chunk allocating blocks of at least `size` bytes.
+    #[doc(alias = "g_string_chunk_new")]
+    pub fn new(size: usize) -> Self {
+        unsafe { StringChunk(ptr::NonNull::new_unchecked(ffi::g_string_chunk_new(size))) }
+    }
+
+    /// Copies `string` into the chunk.
+    #[doc(alias = "g_string_chunk_insert_len")]
+    pub fn insert(&self, string: &str) -> &str {
+        unsafe {
+            let ptr = ffi::g_string_chunk_insert_len(
+                self.0.as_ptr(),
+                string.as_ptr() as *const _,
+                string.len() as isize,
+            );
+            str::from_utf8_unchecked(slice::from_raw_parts(ptr as *const u8, string.len()))
+        }
+    }
+
+    /// Copies `string` into the chunk unless an equal string was already inserted with this
+    /// function, which is then returned instead.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `string` contains a nul byte.
+    #[doc(alias = "g_string_chunk_insert_const")]
+    pub fn insert_const(&self, string: &str) -> &str {
+        unsafe {
+            let ptr = ffi::g_string_chunk_insert_const(self.0.as_ptr(), string.to_glib_none().0);
+            str::from_utf8_unchecked(slice::from_raw_parts(ptr as *const u8, string.len()))
+        }
+    }
+
+    /// Frees all strings of the chunk.
+    #[doc(alias = "g_string_chunk_clear")]
+    pub fn clear(&mut self) {
+        unsafe {
+            ffi::g_string_chunk_clear(self.0.as_ptr());
+        }
+    }
+}
+
+impl Default for StringChunk {
+    fn default() -> Self {
+        // The default size of the blocks, all strings longer than that get their own block
+        Self::new(4096)
+    }
+}
+
+impl Drop for StringChunk {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::g_string_chunk_free(self.0.as_ptr());
+        }
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *mut ffi::GStringChunk> for StringChunk {
+    type Storage = &'a Self;
+
+    #[inline]
+    fn to_glib_none(&'a self) -> Stash<'a, *mut ffi::GStringChunk, Self> {
+        Stash(self.0.as_ptr(), self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn insert() {
+        let mut chunk = StringChunk::new(8);
+        let a = chunk.insert("first");
+        let b = chunk.insert("a string longer than a block");
+        let c = chunk.insert("first");
+        assert_eq!(a, "first");
+        assert_eq!(b, "a string longer than a block");
+        assert_eq!(c, "first");
+        assert_ne!(a.as_ptr(), c.as_ptr());
+        assert_eq!(
+            unsafe { CStr::from_ptr(a.as_ptr() as *const _) }.to_str(),
+            Ok("first")
+        );
+
+        chunk.clear();
+        assert_eq!(chunk.insert("after clear"), "after clear");
+    }
+
+    #[test]
+    fn insert_const() {
+        let chunk = StringChunk::default();
+        let a = chunk.insert_const("name");
+        let b = chunk.insert_const("value");
+        let c = chunk.insert_const("name");
+        assert_eq!(a, "name");
+        assert_eq!(b, "value");
+        assert_eq!(a.as_ptr(), c.as_ptr());
+    }
+}
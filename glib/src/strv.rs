@@ -0,0 +1,524 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::translate::*;
+use crate::types::{StaticType, Type};
+use crate::value::{FromValue, FromValueOptional, SetValue, SetValueOptional, Value};
+use libc::{c_char, c_void, size_t};
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ops::Index;
+use std::ptr::{self, NonNull};
+use std::{fmt, slice, str};
+
+/// An owned `NULL`-terminated array of strings, as used by GLib for `GStrv`.
+///
+/// Creating a `StrV` from strings containing a NUL character panics, like passing them to C as
+/// a single string does.
+///
+/// The strings are stored in memory allocated by GLib, so a `StrV` can be passed to functions
+/// taking a `*const *const c_char` or `*mut *mut c_char` without copying it, unlike a
+/// `Vec<String>` or `&[&str]` that are converted to a temporary array of `CString`s first.
+///
+/// All strings of a `StrV` are valid UTF-8. Arrays taken from C with `from_glib_none()` and
+/// `from_glib_full()` replace invalid strings with a lossy copy, use
+/// [`try_from_glib_none`](Self::try_from_glib_none) and
+/// [`try_from_glib_full`](Self::try_from_glib_full) to reject them instead.
+pub struct StrV {
+    ptr: NonNull<*mut c_char>,
+    len: usize,
+}
+
+unsafe impl Send for StrV {}
+unsafe impl Sync for StrV {}
+
+impl StrV {
+    /// Creates a new, empty array.
+    pub fn new() -> Self {
+        unsafe {
+            let ptr = ffi::g_malloc0(std::mem::size_of::<*mut c_char>()) as *mut *mut c_char;
+            Self::from_raw_full(ptr, 0)
+        }
+    }
+
+    // Takes ownership of `ptr`, which has to be non-`NULL` and contain `len` valid UTF-8
+    // strings.
+    unsafe fn from_raw_full(ptr: *mut *mut c_char, len: usize) -> Self {
+        Self {
+            ptr: NonNull::new_unchecked(ptr),
+            len,
+        }
+    }
+
+    // Takes ownership of `ptr` like `from_raw_full()`, replacing the strings that aren't valid
+    // UTF-8 with a lossy copy.
+    unsafe fn from_raw_full_lossy(ptr: *mut *mut c_char, len: usize) -> Self {
+        for i in 0..len {
+            let item = ptr.add(i);
+            if let Cow::Owned(s) = String::from_utf8_lossy(CStr::from_ptr(*item).to_bytes()) {
+                ffi::g_free(*item as *mut c_void);
+                *item = ffi::g_strndup(s.as_ptr() as *const c_char, s.len() as size_t);
+            }
+        }
+        Self::from_raw_full(ptr, len)
+    }
+
+    /// Takes ownership of the `NULL`-terminated array `ptr`, or returns an error and frees it if
+    /// one of its strings is not valid UTF-8.
+    ///
+    /// A `NULL` `ptr` results in an empty array.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` has to be `NULL` or a `NULL`-terminated array that can be freed with `g_strfreev`.
+    pub unsafe fn try_from_glib_full(ptr: *mut *mut c_char) -> Result<Self, str::Utf8Error> {
+        if ptr.is_null() {
+            return Ok(Self::new());
+        }
+
+        let len = c_ptr_array_len(ptr as *const *const c_char);
+        for i in 0..len {
+            if let Err(err) = str::from_utf8(CStr::from_ptr(*ptr.add(i)).to_bytes()) {
+                ffi::g_strfreev(ptr);
+                return Err(err);
+            }
+        }
+        Ok(Self::from_raw_full(ptr, len))
+    }
+
+    /// Copies the `NULL`-terminated array `ptr`, or returns an error if one of its strings is not
+    /// valid UTF-8.
+    ///
+    /// A `NULL` `ptr` results in an empty array.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` has to be `NULL` or a valid `NULL`-terminated array of strings.
+    pub unsafe fn try_from_glib_none(ptr: *const *const c_char) -> Result<Self, str::Utf8Error> {
+        if ptr.is_null() {
+            return Ok(Self::new());
+        }
+
+        Self::try_from_glib_full(ffi::g_strdupv(ptr as *mut *mut c_char))
+    }
+
+    /// Returns the number of strings in the array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the array contains no strings.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the string at position `index`.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.as_ptr_slice()
+            .get(index)
+            .map(|&ptr| unsafe { ptr_to_str(ptr) })
+    }
+
+    /// Returns an iterator over the strings of the array.
+    pub fn iter(&self) -> StrVIter {
+        StrVIter {
+            iter: self.as_ptr_slice().iter(),
+        }
+    }
+
+    /// Returns the pointers to the strings of the array, without the trailing `NULL`.
+    pub fn as_ptr_slice(&self) -> &[*mut c_char] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns the `NULL`-terminated array, which stays valid as long as `self` is alive.
+    pub fn as_ptr(&self) -> *const *const c_char {
+        self.ptr.as_ptr() as *const *const c_char
+    }
+
+    /// Consumes the array and returns the `NULL`-terminated array, which has to be freed with
+    /// `g_strfreev`.
+    pub fn into_raw(self) -> *mut *mut c_char {
+        let ptr = self.ptr.as_ptr();
+        std::mem::forget(self);
+        ptr
+    }
+
+    /// Returns the strings of the array as a `Vec` of owned strings.
+    pub fn to_vec(&self) -> Vec<String> {
+        self.iter().map(String::from).collect()
+    }
+}
+
+// All strings of a `StrV` were validated when it was created.
+unsafe fn ptr_to_str<'a>(ptr: *const c_char) -> &'a str {
+    str::from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes())
+}
+
+impl Drop for StrV {
+    fn drop(&mut self) {
+        unsafe { ffi::g_strfreev(self.ptr.as_ptr()) }
+    }
+}
+
+impl Clone for StrV {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_raw_full(ffi::g_strdupv(self.ptr.as_ptr()), self.len) }
+    }
+}
+
+impl Default for StrV {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for StrV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for StrV {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for StrV {}
+
+impl<S: AsRef<str>> PartialEq<[S]> for StrV {
+    fn eq(&self, other: &[S]) -> bool {
+        self.iter().eq(other.iter().map(AsRef::as_ref))
+    }
+}
+
+impl Index<usize> for StrV {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &str {
+        unsafe { ptr_to_str(self.as_ptr_slice()[index]) }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for StrV {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut ptrs = Vec::new();
+        for s in iter {
+            let s = s.as_ref();
+            // `g_strndup()` would silently truncate the string
+            if s.as_bytes().contains(&0) {
+                for ptr in ptrs {
+                    unsafe { ffi::g_free(ptr as *mut c_void) };
+                }
+                panic!("StrV: unexpected NUL character");
+            }
+            ptrs.push(unsafe { ffi::g_strndup(s.as_ptr() as *const c_char, s.len() as size_t) });
+        }
+        let len = ptrs.len();
+        ptrs.push(ptr::null_mut());
+
+        unsafe {
+            let ptr =
+                ffi::g_malloc(std::mem::size_of::<*mut c_char>() * (len + 1)) as *mut *mut c_char;
+            ptr::copy_nonoverlapping(ptrs.as_ptr(), ptr, len + 1);
+            Self::from_raw_full(ptr, len)
+        }
+    }
+}
+
+impl From<Vec<String>> for StrV {
+    fn from(strings: Vec<String>) -> Self {
+        strings.iter().collect()
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for StrV {
+    fn from(strings: &'a [&'a str]) -> Self {
+        strings.iter().collect()
+    }
+}
+
+impl From<StrV> for Vec<String> {
+    fn from(strv: StrV) -> Self {
+        strv.to_vec()
+    }
+}
+
+/// A list of strings that can be passed to C as a `NULL`-terminated array.
+///
+/// A [`StrV`] is passed as is, other lists are copied into a temporary `StrV` first.
+///
+/// ```
+/// use glib::IntoStrV;
+///
+/// let len = ["a", "bc"][..].run_with_strv(|strv| strv.len());
+/// assert_eq!(len, 2);
+/// ```
+pub trait IntoStrV {
+    /// Calls `f` with the strings as a [`StrV`].
+    fn run_with_strv<T, F: FnOnce(&StrV) -> T>(self, f: F) -> T;
+}
+
+impl<'a> IntoStrV for &'a StrV {
+    #[inline]
+    fn run_with_strv<T, F: FnOnce(&StrV) -> T>(self, f: F) -> T {
+        f(self)
+    }
+}
+
+impl IntoStrV for StrV {
+    #[inline]
+    fn run_with_strv<T, F: FnOnce(&StrV) -> T>(self, f: F) -> T {
+        f(&self)
+    }
+}
+
+impl<'a, S: AsRef<str>> IntoStrV for &'a [S] {
+    #[inline]
+    fn run_with_strv<T, F: FnOnce(&StrV) -> T>(self, f: F) -> T {
+        f(&self.iter().collect())
+    }
+}
+
+impl<'a, S: AsRef<str>> IntoStrV for &'a Vec<S> {
+    #[inline]
+    fn run_with_strv<T, F: FnOnce(&StrV) -> T>(self, f: F) -> T {
+        self.as_slice().run_with_strv(f)
+    }
+}
+
+impl<S: AsRef<str>> IntoStrV for Vec<S> {
+    #[inline]
+    fn run_with_strv<T, F: FnOnce(&StrV) -> T>(self, f: F) -> T {
+        self.as_slice().run_with_strv(f)
+    }
+}
+
+impl<'a> IntoIterator for &'a StrV {
+    type Item = &'a str;
+    type IntoIter = StrVIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the strings of a [`StrV`].
+#[derive(Debug, Clone)]
+pub struct StrVIter<'a> {
+    iter: slice::Iter<'a, *mut c_char>,
+}
+
+impl<'a> Iterator for StrVIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|&ptr| unsafe { ptr_to_str(ptr) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrVIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|&ptr| unsafe { ptr_to_str(ptr) })
+    }
+}
+
+impl<'a> ExactSizeIterator for StrVIter<'a> {}
+
+impl GlibPtrDefault for StrV {
+    type GlibType = *mut *mut c_char;
+}
+
+impl<'a> ToGlibPtr<'a, *const *const c_char> for StrV {
+    type Storage = PhantomData<&'a Self>;
+
+    #[inline]
+    fn to_glib_none(&'a self) -> Stash<'a, *const *const c_char, Self> {
+        Stash(self.as_ptr(), PhantomData)
+    }
+
+    #[inline]
+    fn to_glib_full(&self) -> *const *const c_char {
+        self.clone().into_raw() as *const *const c_char
+    }
+}
+
+impl<'a> ToGlibPtr<'a, *mut *mut c_char> for StrV {
+    type Storage = PhantomData<&'a Self>;
+
+    #[inline]
+    fn to_glib_none(&'a self) -> Stash<'a, *mut *mut c_char, Self> {
+        Stash(self.ptr.as_ptr(), PhantomData)
+    }
+
+    #[inline]
+    fn to_glib_full(&self) -> *mut *mut c_char {
+        self.clone().into_raw()
+    }
+}
+
+impl FromGlibPtrNone<*const *const c_char> for StrV {
+    #[inline]
+    unsafe fn from_glib_none(ptr: *const *const c_char) -> Self {
+        if ptr.is_null() {
+            Self::new()
+        } else {
+            Self::from_raw_full_lossy(
+                ffi::g_strdupv(ptr as *mut *mut c_char),
+                c_ptr_array_len(ptr),
+            )
+        }
+    }
+}
+
+impl FromGlibPtrNone<*mut *mut c_char> for StrV {
+    #[inline]
+    unsafe fn from_glib_none(ptr: *mut *mut c_char) -> Self {
+        from_glib_none(ptr as *const *const c_char)
+    }
+}
+
+impl FromGlibPtrFull<*mut *mut c_char> for StrV {
+    #[inline]
+    unsafe fn from_glib_full(ptr: *mut *mut c_char) -> Self {
+        if ptr.is_null() {
+            Self::new()
+        } else {
+            Self::from_raw_full_lossy(ptr, c_ptr_array_len(ptr as *const *const c_char))
+        }
+    }
+}
+
+impl FromGlibPtrFull<*const *const c_char> for StrV {
+    #[inline]
+    unsafe fn from_glib_full(ptr: *const *const c_char) -> Self {
+        from_glib_full(ptr as *mut *mut c_char)
+    }
+}
+
+impl StaticType for StrV {
+    fn static_type() -> Type {
+        unsafe { from_glib(ffi::g_strv_get_type()) }
+    }
+}
+
+impl<'a> FromValueOptional<'a> for StrV {
+    unsafe fn from_value_optional(value: &'a Value) -> Option<Self> {
+        Some(<StrV as FromValue>::from_value(value))
+    }
+}
+
+impl<'a> FromValue<'a> for StrV {
+    unsafe fn from_value(value: &'a Value) -> Self {
+        let ptr = gobject_ffi::g_value_get_boxed(value.to_glib_none().0) as *const *const c_char;
+        from_glib_none(ptr)
+    }
+}
+
+impl SetValue for StrV {
+    unsafe fn set_value(value: &mut Value, this: &Self) {
+        let ptr: *mut *mut c_char = this.to_glib_full();
+        gobject_ffi::g_value_take_boxed(value.to_glib_none_mut().0, ptr as *const c_void)
+    }
+}
+
+impl SetValueOptional for StrV {
+    unsafe fn set_value_optional(value: &mut Value, this: Option<&Self>) {
+        let ptr: *mut *mut c_char = this.map_or(ptr::null_mut(), |this| this.to_glib_full());
+        gobject_ffi::g_value_take_boxed(value.to_glib_none_mut().0, ptr as *const c_void)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToValue;
+
+    #[test]
+    fn from_vec() {
+        let strv = StrV::from(vec![String::from("a"), String::from("bc")]);
+        assert_eq!(strv.len(), 2);
+        assert_eq!(&strv[1], "bc");
+        assert_eq!(strv.get(2), None);
+        assert_eq!(strv.iter().rev().collect::<Vec<_>>(), vec!["bc", "a"]);
+        assert_eq!(strv, ["a", "bc"][..]);
+        assert_eq!(Vec::<String>::from(strv), vec!["a", "bc"]);
+
+        let empty = StrV::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[test]
+    fn ffi() {
+        let strv = StrV::from(&["a", "bc"][..]);
+        unsafe {
+            let ptr: *const *const c_char = strv.to_glib_none().0;
+            assert_eq!(ptr, strv.as_ptr());
+            assert_eq!(ffi::g_strv_length(ptr as *mut *mut c_char), 2);
+
+            let copy: StrV = from_glib_none(ptr);
+            assert_eq!(copy, strv);
+
+            let full: StrV = from_glib_full(strv.to_glib_full() as *mut *mut c_char);
+            assert_eq!(full, strv);
+        }
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        unsafe {
+            let ptr = ffi::g_malloc0(std::mem::size_of::<*mut c_char>() * 3) as *mut *mut c_char;
+            *ptr = ffi::g_strdup(b"a\0".as_ptr() as *const c_char);
+            *ptr.add(1) = ffi::g_strdup(b"b\xff\0".as_ptr() as *const c_char);
+
+            assert!(StrV::try_from_glib_none(ptr as *const *const c_char).is_err());
+
+            let lossy: StrV = from_glib_none(ptr as *const *const c_char);
+            assert_eq!(lossy, ["a", "b\u{fffd}"][..]);
+
+            assert!(StrV::try_from_glib_full(ptr).is_err());
+        }
+
+        let strv = unsafe { StrV::try_from_glib_full(StrV::from(&["a"][..]).into_raw()) };
+        assert_eq!(strv.unwrap(), ["a"][..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected NUL character")]
+    fn interior_nul() {
+        let _ = StrV::from(&["a", "b\0c"][..]);
+    }
+
+    #[test]
+    fn into_strv() {
+        let strv = StrV::from(&["a", "bc"][..]);
+        let ptr = strv.as_ptr();
+        assert_eq!((&strv).run_with_strv(|strv| strv.as_ptr()), ptr);
+        assert_eq!(strv.run_with_strv(|strv| strv.as_ptr()), ptr);
+
+        assert_eq!(
+            vec![String::from("a")].run_with_strv(|strv| strv.to_vec()),
+            vec!["a"]
+        );
+        assert_eq!(["a", "bc"][..].run_with_strv(|strv| strv.len()), 2);
+    }
+
+    #[test]
+    fn value() {
+        let strv: StrV = vec!["a", "bc"].into_iter().collect();
+        let value = strv.to_value();
+        assert_eq!(value.type_(), StrV::static_type());
+        assert_eq!(value.get::<StrV>().unwrap(), Some(strv));
+        assert_eq!(
+            value.get::<Vec<String>>().unwrap(),
+            Some(vec![String::from("a"), String::from("bc")])
+        );
+    }
+}
@@ -5,6 +5,7 @@ use crate::translate::*;
 use crate::{IsA, Object, ObjectExt, ParamSpec, Type};
 use std::marker;
 use std::mem;
+use std::ptr;
 
 /// Trait for a type list of prerequisite object types.
 pub trait PrerequisiteList {
@@ -94,11 +95,16 @@ pub unsafe trait ObjectInterfaceType {
 /// into various steps of the type registration and initialization.
 ///
 /// This must only be implemented on `#[repr(C)]` structs and have `gobject_ffi::GTypeInterface` as
-/// the first field.
+/// the first field. The [`#[object_interface]`](crate::object_interface) attribute macro generates
+/// both when placed on the struct.
 ///
 /// See [`register_interface`] for registering an implementation of this trait
 /// with the type system.
 ///
+/// Virtual methods can be added as function pointer fields of the struct, see the
+/// [module documentation](super#example-for-defining-an-interface-with-virtual-methods) for an
+/// example.
+///
 /// [`register_interface`]: fn.register_interface.html
 pub unsafe trait ObjectInterface: ObjectInterfaceType + Sized + 'static {
     /// `GObject` type name.
@@ -225,3 +231,65 @@ pub fn register_interface<T: ObjectInterface>() -> Type {
         type_
     }
 }
+
+/// Register a `glib::Type` ID for `T` that belongs to `type_module`.
+///
+/// This must be called from [`TypeModuleImpl::load()`] every time the module is loaded. The
+/// prerequisites are only added and `type_init()` is only called when the interface is registered
+/// for the first time.
+///
+/// The [`object_interface!`] macro creates a `get_type()` function for interfaces declared as
+/// `dynamic` that returns the type registered by this function, instead of registering it
+/// statically.
+///
+/// [`TypeModuleImpl::load()`]: super::type_module::TypeModuleImpl::load
+/// [`object_interface!`]: ../../macro.object_interface.html
+pub fn register_dynamic_interface<T: ObjectInterface>(type_module: &crate::TypeModule) -> Type {
+    unsafe {
+        use std::ffi::CString;
+
+        let type_name = CString::new(T::NAME).unwrap();
+        let registered = gobject_ffi::g_type_from_name(type_name.as_ptr());
+
+        let type_info = gobject_ffi::GTypeInfo {
+            class_size: mem::size_of::<T>() as u16,
+            base_init: None,
+            base_finalize: None,
+            class_init: Some(interface_init::<T>),
+            class_finalize: None,
+            class_data: ptr::null(),
+            instance_size: 0,
+            n_preallocs: 0,
+            instance_init: None,
+            value_table: ptr::null(),
+        };
+
+        let type_ = gobject_ffi::g_type_module_register_type(
+            type_module.to_glib_none().0,
+            Type::INTERFACE.to_glib(),
+            type_name.as_ptr(),
+            &type_info,
+            0,
+        );
+        if type_ == gobject_ffi::G_TYPE_INVALID {
+            panic!(
+                "Interface {} has already been registered by another module",
+                type_name.to_str().unwrap()
+            );
+        }
+
+        if registered == gobject_ffi::G_TYPE_INVALID {
+            let prerequisites = T::Prerequisites::types();
+            for prerequisite in prerequisites {
+                gobject_ffi::g_type_interface_add_prerequisite(type_, prerequisite);
+            }
+
+            T::type_init(&mut InitializingType::<T>(
+                from_glib(type_),
+                marker::PhantomData,
+            ));
+        }
+
+        from_glib(type_)
+    }
+}
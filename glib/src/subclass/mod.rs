@@ -231,6 +231,297 @@
 //!     assert_eq!(&b, b2);
 //! }
 //! ```
+//!
+//! # Example for defining an interface with virtual methods
+//!
+//! Virtual methods of an interface are function pointers in its interface struct. The default
+//! implementations are set in
+//! [`ObjectInterface::interface_init`](interface::ObjectInterface::interface_init), and an
+//! [`IsImplementable`](types::IsImplementable) implementation replaces them with the
+//! implementation of each implementor, which is provided by an `Impl` trait like for classes.
+//! The implementation can chain up to the implementation of the parent class, or to the default
+//! implementation, with
+//! [`ObjectSubclassExt::parent_interface`](types::ObjectSubclassExt::parent_interface).
+//!
+//! ```rust
+//! use glib::prelude::*;
+//! use glib::subclass::prelude::*;
+//!
+//! mod iface {
+//!     use super::*;
+//!
+//!     // The attribute adds the `GTypeInterface` field that has to come first
+//!     #[glib::object_interface]
+//!     pub struct Greeter {
+//!         pub(super) greet: Option<fn(&super::Greeter) -> String>,
+//!     }
+//!
+//!     #[glib::object_interface]
+//!     unsafe impl ObjectInterface for Greeter {
+//!         const NAME: &'static str = "Greeter";
+//!
+//!         fn interface_init(&mut self) {
+//!             self.greet = Some(|_| String::from("Hello"));
+//!         }
+//!     }
+//! }
+//!
+//! glib::wrapper! {
+//!     pub struct Greeter(ObjectInterface<iface::Greeter>);
+//! }
+//!
+//! // Calls the implementation of `greet` of the object
+//! pub trait GreeterExt: IsA<Greeter> {
+//!     fn greet(&self) -> String {
+//!         let iface = iface::Greeter::from_instance(self.upcast_ref::<Greeter>());
+//!         (iface.greet.unwrap())(self.upcast_ref())
+//!     }
+//! }
+//!
+//! impl<O: IsA<Greeter>> GreeterExt for O {}
+//!
+//! // Implemented by subclasses implementing `Greeter`
+//! pub trait GreeterImpl: ObjectImpl {
+//!     fn greet(&self, greeter: &Self::Type) -> String {
+//!         self.parent_greet(greeter)
+//!     }
+//! }
+//!
+//! pub trait GreeterImplExt: ObjectSubclass {
+//!     fn parent_greet(&self, greeter: &Self::Type) -> String;
+//! }
+//!
+//! impl<T: GreeterImpl> GreeterImplExt for T {
+//!     fn parent_greet(&self, greeter: &Self::Type) -> String {
+//!         let parent_iface = Self::parent_interface::<Greeter>();
+//!         (parent_iface.as_ref().greet.unwrap())(greeter.dynamic_cast_ref().unwrap())
+//!     }
+//! }
+//!
+//! unsafe impl<T: GreeterImpl> IsImplementable<T> for Greeter {
+//!     fn interface_init(iface: &mut glib::Interface<Self>) {
+//!         iface.as_mut().greet = Some(greet_trampoline::<T>);
+//!     }
+//! }
+//!
+//! fn greet_trampoline<T: GreeterImpl>(greeter: &Greeter) -> String {
+//!     let instance = greeter.dynamic_cast_ref::<T::Type>().unwrap();
+//!     T::from_instance(instance).greet(instance)
+//! }
+//!
+//! mod imp {
+//!     use super::*;
+//!
+//!     #[derive(Default)]
+//!     pub struct Polite;
+//!
+//!     #[glib::object_subclass]
+//!     impl ObjectSubclass for Polite {
+//!         const NAME: &'static str = "Polite";
+//!         type Type = super::Polite;
+//!         type ParentType = glib::Object;
+//!         type Interfaces = (Greeter,);
+//!     }
+//!
+//!     impl ObjectImpl for Polite {}
+//!
+//!     impl GreeterImpl for Polite {
+//!         fn greet(&self, greeter: &Self::Type) -> String {
+//!             format!("{}, nice to meet you", self.parent_greet(greeter))
+//!         }
+//!     }
+//!
+//!     #[derive(Default)]
+//!     pub struct Plain;
+//!
+//!     #[glib::object_subclass]
+//!     impl ObjectSubclass for Plain {
+//!         const NAME: &'static str = "Plain";
+//!         type Type = super::Plain;
+//!         type ParentType = glib::Object;
+//!         type Interfaces = (Greeter,);
+//!     }
+//!
+//!     impl ObjectImpl for Plain {}
+//!
+//!     // Uses the default implementation
+//!     impl GreeterImpl for Plain {}
+//! }
+//!
+//! glib::wrapper! {
+//!     pub struct Polite(ObjectSubclass<imp::Polite>) @implements Greeter;
+//! }
+//!
+//! glib::wrapper! {
+//!     pub struct Plain(ObjectSubclass<imp::Plain>) @implements Greeter;
+//! }
+//!
+//! pub fn main() {
+//!     let polite = glib::Object::new::<Polite>(&[]).unwrap();
+//!     assert_eq!(polite.greet(), "Hello, nice to meet you");
+//!
+//!     let plain = glib::Object::new::<Plain>(&[]).unwrap();
+//!     assert_eq!(plain.greet(), "Hello");
+//! }
+//! ```
+//!
+//! # Example for chaining up across several Rust subclasses
+//!
+//! Virtual methods of a class are function pointers in its class struct, which is declared with
+//! the `class_struct` argument of [`#[object_subclass]`](crate::object_subclass). A Rust
+//! subclass of such a class can be subclassed again, and every subclass chains up to the
+//! closest ancestor implementing the virtual method with
+//! [`ObjectSubclassExt::parent_class`](types::ObjectSubclassExt::parent_class), or the
+//! [`parent_vfunc!`](crate::parent_vfunc) shorthand. Both look up the class struct of the type
+//! declaring the virtual method in the parent class, so the chain-up code doesn't depend on the
+//! depth of the hierarchy.
+//!
+//! ```rust
+//! use glib::prelude::*;
+//! use glib::subclass::prelude::*;
+//!
+//! // Calls the implementation of `speak` of the object
+//! pub trait AnimalExt: IsA<Animal> {
+//!     fn speak(&self) -> String {
+//!         let klass = self.upcast_ref::<Animal>().get_class();
+//!         (klass.as_ref().speak.unwrap())(self.upcast_ref())
+//!     }
+//! }
+//!
+//! impl<O: IsA<Animal>> AnimalExt for O {}
+//!
+//! // Implemented by subclasses of `Animal`
+//! pub trait AnimalImpl: ObjectImpl {
+//!     fn speak(&self, animal: &Self::Type) -> String {
+//!         self.parent_speak(animal)
+//!     }
+//! }
+//!
+//! pub trait AnimalImplExt: ObjectSubclass {
+//!     fn parent_speak(&self, animal: &Self::Type) -> String;
+//! }
+//!
+//! impl<T: AnimalImpl> AnimalImplExt for T {
+//!     fn parent_speak(&self, animal: &Self::Type) -> String {
+//!         glib::parent_vfunc!(T, Animal, speak, animal.dynamic_cast_ref().unwrap())
+//!             .expect("No parent implementation of speak")
+//!     }
+//! }
+//!
+//! unsafe impl<T: AnimalImpl> IsSubclassable<T> for Animal {
+//!     fn class_init(class: &mut glib::Class<Self>) {
+//!         <glib::Object as IsSubclassable<T>>::class_init(class);
+//!         class.as_mut().speak = Some(speak_trampoline::<T>);
+//!     }
+//!
+//!     fn instance_init(instance: &mut glib::subclass::InitializingObject<T>) {
+//!         <glib::Object as IsSubclassable<T>>::instance_init(instance);
+//!     }
+//! }
+//!
+//! // `Dog` is subclassable as well and only forwards to `Animal`
+//! unsafe impl<T: AnimalImpl> IsSubclassable<T> for Dog {
+//!     fn class_init(class: &mut glib::Class<Self>) {
+//!         <Animal as IsSubclassable<T>>::class_init(class);
+//!     }
+//!
+//!     fn instance_init(instance: &mut glib::subclass::InitializingObject<T>) {
+//!         <Animal as IsSubclassable<T>>::instance_init(instance);
+//!     }
+//! }
+//!
+//! fn speak_trampoline<T: AnimalImpl>(animal: &Animal) -> String {
+//!     let instance = animal.dynamic_cast_ref::<T::Type>().unwrap();
+//!     T::from_instance(instance).speak(instance)
+//! }
+//!
+//! mod imp {
+//!     use super::*;
+//!
+//!     #[repr(C)]
+//!     pub struct AnimalClass {
+//!         parent_class: glib::gobject_ffi::GObjectClass,
+//!         pub(super) speak: Option<fn(&super::Animal) -> String>,
+//!     }
+//!
+//!     #[derive(Default)]
+//!     pub struct Animal;
+//!
+//!     #[glib::object_subclass(class_struct)]
+//!     impl ObjectSubclass for Animal {
+//!         const NAME: &'static str = "Animal";
+//!         type Type = super::Animal;
+//!         type ParentType = glib::Object;
+//!         type Class = AnimalClass;
+//!
+//!         fn class_init(klass: &mut Self::Class) {
+//!             klass.speak = Some(|_| String::from("..."));
+//!         }
+//!     }
+//!
+//!     impl ObjectImpl for Animal {}
+//!
+//!     #[derive(Default)]
+//!     pub struct Dog;
+//!
+//!     #[glib::object_subclass]
+//!     impl ObjectSubclass for Dog {
+//!         const NAME: &'static str = "Dog";
+//!         type Type = super::Dog;
+//!         type ParentType = super::Animal;
+//!     }
+//!
+//!     impl ObjectImpl for Dog {}
+//!
+//!     impl AnimalImpl for Dog {
+//!         fn speak(&self, animal: &Self::Type) -> String {
+//!             format!("Woof instead of {}", self.parent_speak(animal))
+//!         }
+//!     }
+//!
+//!     #[derive(Default)]
+//!     pub struct Puppy;
+//!
+//!     #[glib::object_subclass]
+//!     impl ObjectSubclass for Puppy {
+//!         const NAME: &'static str = "Puppy";
+//!         type Type = super::Puppy;
+//!         type ParentType = super::Dog;
+//!     }
+//!
+//!     impl ObjectImpl for Puppy {}
+//!
+//!     // Chains up to the implementation of `Dog`, not the one of `Animal`
+//!     impl AnimalImpl for Puppy {
+//!         fn speak(&self, animal: &Self::Type) -> String {
+//!             format!("{}, but quietly", self.parent_speak(animal))
+//!         }
+//!     }
+//! }
+//!
+//! glib::wrapper! {
+//!     pub struct Animal(ObjectSubclass<imp::Animal>);
+//! }
+//!
+//! glib::wrapper! {
+//!     pub struct Dog(ObjectSubclass<imp::Dog>) @extends Animal;
+//! }
+//!
+//! glib::wrapper! {
+//!     pub struct Puppy(ObjectSubclass<imp::Puppy>) @extends Dog, Animal;
+//! }
+//!
+//! pub fn main() {
+//!     let animal = glib::Object::new::<Animal>(&[]).unwrap();
+//!     assert_eq!(animal.speak(), "...");
+//!
+//!     let dog = glib::Object::new::<Dog>(&[]).unwrap();
+//!     assert_eq!(dog.speak(), "Woof instead of ...");
+//!
+//!     let puppy = glib::Object::new::<Puppy>(&[]).unwrap();
+//!     assert_eq!(puppy.speak(), "Woof instead of ..., but quietly");
+//! }
+//! ```
 
 pub mod basic;
 #[macro_use]
@@ -247,11 +538,14 @@ pub mod boxed;
 
 pub mod signal;
 
+pub mod type_module;
+
 pub mod prelude {
     //! Prelude that re-exports all important traits from this crate.
     pub use super::boxed::BoxedType;
     pub use super::interface::{ObjectInterface, ObjectInterfaceExt, ObjectInterfaceType};
     pub use super::object::{ObjectClassSubclassExt, ObjectImpl, ObjectImplExt};
+    pub use super::type_module::TypeModuleImpl;
     pub use super::types::{
         ClassStruct, InstanceStruct, IsImplementable, IsSubclassable, ObjectSubclass,
         ObjectSubclassExt, ObjectSubclassType,
@@ -259,8 +553,10 @@ pub mod prelude {
 }
 
 pub use self::boxed::register_boxed_type;
-pub use self::interface::register_interface;
+pub use self::interface::{register_dynamic_interface, register_interface};
 pub use self::signal::{
     Signal, SignalClassHandlerToken, SignalId, SignalInvocationHint, SignalQuery, SignalType,
 };
-pub use self::types::{register_type, InitializingObject, InitializingType, TypeData};
+pub use self::types::{
+    register_dynamic_type, register_type, InitializingObject, InitializingType, TypeData,
+};
@@ -6,7 +6,8 @@
 use super::prelude::*;
 use super::Signal;
 use crate::translate::*;
-use crate::{Cast, Object, ObjectType, ParamSpec, Value};
+use crate::value::FromValueOptional;
+use crate::{Cast, Object, ObjectType, ParamFlags, ParamSpec, Value};
 use std::mem;
 use std::ptr;
 
@@ -54,7 +55,10 @@ pub trait ObjectImpl: ObjectSubclass + ObjectImplExt {
     /// When `dispose()` ends, the object should not hold any reference to any other member object.
     /// The object is also expected to be able to answer client method invocations (with possibly an
     /// error code but no memory violation) until it is dropped. `dispose()` can be executed more
-    /// than once.
+    /// than once, e.g. when [`ObjectExt::run_dispose()`] was called before the last reference
+    /// was dropped.
+    ///
+    /// [`ObjectExt::run_dispose()`]: ../../object/trait.ObjectExt.html#tymethod.run_dispose
     fn dispose(&self, _obj: &Self::Type) {}
 }
 
@@ -94,11 +98,23 @@ unsafe extern "C" fn set_property<T: ObjectImpl>(
 ) {
     let instance = &*(obj as *mut T::Instance);
     let imp = instance.get_impl();
+    let value = &*(value as *mut Value);
+    let pspec: Borrowed<ParamSpec> = from_glib_borrow(pspec);
+
+    if pspec
+        .get_flags()
+        .intersects(ParamFlags::CONSTRUCT | ParamFlags::CONSTRUCT_ONLY)
+    {
+        if let Some(ref mut properties) = *super::types::construct_properties(imp).borrow_mut() {
+            properties.push((pspec.get_name().to_owned(), value.clone()));
+        }
+    }
+
     imp.set_property(
         &from_glib_borrow::<_, Object>(obj).unsafe_cast_ref(),
         id as usize,
-        &*(value as *mut Value),
-        &from_glib_borrow(pspec),
+        value,
+        &pspec,
     );
 }
 
@@ -107,6 +123,9 @@ unsafe extern "C" fn constructed<T: ObjectImpl>(obj: *mut gobject_ffi::GObject)
     let imp = instance.get_impl();
 
     imp.constructed(&from_glib_borrow::<_, Object>(obj).unsafe_cast_ref());
+
+    // The values of the construct properties are only available until here
+    super::types::construct_properties(imp).replace(None);
 }
 
 unsafe extern "C" fn dispose<T: ObjectImpl>(obj: *mut gobject_ffi::GObject) {
@@ -127,6 +146,11 @@ unsafe extern "C" fn dispose<T: ObjectImpl>(obj: *mut gobject_ffi::GObject) {
 ///
 /// This contains various class methods and allows subclasses to override signal class handlers.
 pub unsafe trait ObjectClassSubclassExt: Sized + 'static {
+    /// Overrides the class handler of the signal `name`, which has to be registered by a parent
+    /// class.
+    ///
+    /// The parent class' handler can be called with
+    /// [`ObjectImplExt::signal_chain_from_overridden`].
     fn override_signal_class_handler<F>(&mut self, name: &str, class_handler: F)
     where
         F: Fn(&super::SignalClassHandlerToken, &[Value]) -> Option<Value> + Send + Sync + 'static,
@@ -139,6 +163,23 @@ pub unsafe trait ObjectClassSubclassExt: Sized + 'static {
             );
         }
     }
+
+    /// Overrides the class handler of the signal `name` with one that gets the object the
+    /// signal is emitted on as `T`.
+    ///
+    /// See [`SignalBuilder::object_class_handler`](super::SignalBuilder::object_class_handler).
+    fn override_signal_object_class_handler<T, F>(&mut self, name: &str, class_handler: F)
+    where
+        T: ObjectType + for<'v> FromValueOptional<'v>,
+        F: Fn(&super::SignalClassHandlerToken, &T, &[Value]) -> Option<Value>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.override_signal_class_handler(name, move |token, values| {
+            class_handler(token, &super::signal::signal_object(values), values)
+        });
+    }
 }
 
 unsafe impl ObjectClassSubclassExt for crate::Class<Object> {}
@@ -190,6 +231,20 @@ pub trait ObjectImplExt: ObjectSubclass {
         token: &super::SignalClassHandlerToken,
         values: &[Value],
     ) -> Option<Value>;
+
+    /// Returns the value the construct property `name` of this type was set to when the object
+    /// was constructed.
+    ///
+    /// This is only available until `constructed()` returns and `None` afterwards, or if this
+    /// type has no construct property `name`.
+    ///
+    /// # Panics
+    ///
+    /// If the value of the property is not of type `V`.
+    fn construct_property<V: for<'v> FromValueOptional<'v> + 'static>(
+        &self,
+        name: &str,
+    ) -> Option<V>;
 }
 
 impl<T: ObjectImpl> ObjectImplExt for T {
@@ -217,6 +272,21 @@ impl<T: ObjectImpl> ObjectImplExt for T {
             )
         }
     }
+
+    fn construct_property<V: for<'v> FromValueOptional<'v> + 'static>(
+        &self,
+        name: &str,
+    ) -> Option<V> {
+        let properties = super::types::construct_properties(self).borrow();
+        let (_, value) = properties
+            .as_ref()?
+            .iter()
+            .find(|(property_name, _)| property_name == name)?;
+
+        value
+            .get::<V>()
+            .unwrap_or_else(|err| panic!("Wrong type for construct property '{}': {}", name, err))
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +320,8 @@ mod test {
             name: RefCell<Option<String>>,
             construct_name: RefCell<Option<String>>,
             constructed: RefCell<bool>,
+            pub(super) constructed_with_name: RefCell<Option<String>>,
+            pub(super) instance_initialized: RefCell<bool>,
         }
 
         #[glib::object_subclass]
@@ -258,6 +330,12 @@ mod test {
             type Type = super::SimpleObject;
             type ParentType = Object;
             type Interfaces = (super::Dummy,);
+
+            fn instance_init(obj: &crate::subclass::InitializingObject<Self>) {
+                // The implementation struct returned by `new()` is already available here
+                let imp = Self::from_instance(unsafe { obj.as_ref() });
+                *imp.instance_initialized.borrow_mut() = true;
+            }
         }
 
         impl ObjectImpl for SimpleObject {
@@ -297,6 +375,13 @@ mod test {
                             Some(old_name.to_value())
                         })
                         .build(),
+                        super::Signal::builder("get-name", &[], String::static_type().into())
+                            .action()
+                            .object_class_handler(|_, obj: &super::SimpleObject, _| {
+                                let imp = SimpleObject::from_instance(obj);
+                                Some(imp.name.borrow().to_value())
+                            })
+                            .build(),
                         super::Signal::builder("create-string", &[], String::static_type().into())
                             .build(),
                         super::Signal::builder(
@@ -343,6 +428,7 @@ mod test {
                             super::ChildObject::static_type(),
                             crate::ParamFlags::READWRITE,
                         ),
+                        crate::ParamSpec::override_interface::<super::Dummy>("dummy"),
                     ]
                 });
 
@@ -388,6 +474,7 @@ mod test {
                     "name" => self.name.borrow().to_value(),
                     "construct-name" => self.construct_name.borrow().to_value(),
                     "constructed" => self.constructed.borrow().to_value(),
+                    "dummy" => true.to_value(),
                     _ => unimplemented!(),
                 }
             }
@@ -399,9 +486,38 @@ mod test {
                 assert_eq!(self as *const _, Self::from_instance(obj) as *const _);
 
                 *self.constructed.borrow_mut() = true;
+                *self.constructed_with_name.borrow_mut() =
+                    self.construct_property::<String>("construct-name");
             }
         }
 
+        #[derive(Default)]
+        pub struct DerivedObject;
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for DerivedObject {
+            const NAME: &'static str = "DerivedObject";
+            type Type = super::DerivedObject;
+            type ParentType = super::SimpleObject;
+
+            fn class_init(klass: &mut Self::Class) {
+                klass.override_signal_object_class_handler(
+                    "change-name",
+                    |token, obj: &super::DerivedObject, args| {
+                        let new_name = args[1]
+                            .get::<&str>()
+                            .expect("Failed to get args[1]")
+                            .expect("Failed to get str from args[1]");
+                        let args = [args[0].clone(), new_name.to_uppercase().to_value()];
+
+                        DerivedObject::from_instance(obj).signal_chain_from_overridden(token, &args)
+                    },
+                );
+            }
+        }
+
+        impl ObjectImpl for DerivedObject {}
+
         #[derive(Clone, Copy)]
         #[repr(C)]
         pub struct DummyInterface {
@@ -411,6 +527,21 @@ mod test {
         #[glib::object_interface]
         unsafe impl ObjectInterface for DummyInterface {
             const NAME: &'static str = "Dummy";
+
+            fn properties() -> &'static [ParamSpec] {
+                use once_cell::sync::Lazy;
+                static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
+                    vec![crate::ParamSpec::boolean(
+                        "dummy",
+                        "Dummy",
+                        "Dummy property of the interface",
+                        false,
+                        crate::ParamFlags::READABLE,
+                    )]
+                });
+
+                PROPERTIES.as_ref()
+            }
         }
     }
 
@@ -422,13 +553,27 @@ mod test {
         pub struct SimpleObject(ObjectSubclass<imp::SimpleObject>);
     }
 
+    unsafe impl<T: ObjectImpl> IsSubclassable<T> for SimpleObject {
+        fn class_init(class: &mut crate::Class<Self>) {
+            <Object as IsSubclassable<T>>::class_init(class);
+        }
+
+        fn instance_init(instance: &mut crate::subclass::InitializingObject<T>) {
+            <Object as IsSubclassable<T>>::instance_init(instance);
+        }
+    }
+
+    wrapper! {
+        pub struct DerivedObject(ObjectSubclass<imp::DerivedObject>) @extends SimpleObject;
+    }
+
     wrapper! {
         pub struct Dummy(ObjectInterface<imp::DummyInterface>);
     }
 
     unsafe impl<T: ObjectSubclass> IsImplementable<T> for Dummy {
         fn interface_init(_iface: &mut crate::Interface<Dummy>) {}
-        fn instance_init(_instance: &mut super::super::InitializingObject<T>) {}
+        fn instance_init(_instance: &mut crate::subclass::InitializingObject<T>) {}
     }
 
     #[test]
@@ -451,6 +596,43 @@ mod test {
         assert!(weak.upgrade().is_none());
     }
 
+    #[test]
+    fn test_interface_property() {
+        let obj = Object::with_type(SimpleObject::static_type(), &[]).expect("Object::new failed");
+
+        let pspec = obj
+            .find_property("dummy")
+            .expect("Failed to find 'dummy' property");
+        // Looking up an overridden property returns the interface's declaration
+        assert_eq!(pspec.get_owner_type(), Dummy::static_type());
+        assert_eq!(pspec.get_value_type(), crate::Type::BOOL);
+        assert_eq!(
+            obj.get_property("dummy")
+                .expect("Failed to get 'dummy' property")
+                .get_some::<bool>()
+                .expect("Failed to get bool from 'dummy' property"),
+            true
+        );
+    }
+
+    #[test]
+    fn test_construct_property() {
+        let obj: SimpleObject =
+            Object::new(&[("construct-name", &"meh")]).expect("Object::new failed");
+
+        let imp = imp::SimpleObject::from_instance(&obj);
+        assert_eq!(imp.constructed_with_name.borrow().as_deref(), Some("meh"));
+        assert_eq!(imp.construct_property::<String>("construct-name"), None);
+    }
+
+    #[test]
+    fn test_instance_init() {
+        let obj: SimpleObject = Object::new(&[]).expect("Object::new failed");
+
+        let imp = imp::SimpleObject::from_instance(&obj);
+        assert!(*imp.instance_initialized.borrow());
+    }
+
     #[test]
     fn test_create_child_object() {
         let obj: ChildObject = Object::new(&[]).expect("Object::new failed");
@@ -543,6 +725,150 @@ mod test {
         assert!(obj.set_property("child", &child).is_ok());
     }
 
+    #[test]
+    fn test_pspec_properties() {
+        let obj = Object::with_type(SimpleObject::static_type(), &[("name", &"initial")])
+            .expect("Object::new failed");
+
+        let pspec = obj.find_property("name").expect("Failed to find 'name'");
+        assert_eq!(
+            obj.get_property_from_pspec(&pspec)
+                .expect("Failed to get 'name' property")
+                .get::<&str>()
+                .expect("Failed to get str from 'name' property"),
+            Some("initial")
+        );
+        assert!(obj.set_property_from_pspec(&pspec, &"test").is_ok());
+        assert_eq!(
+            obj.get_property_from_pspec(&pspec)
+                .expect("Failed to get 'name' property")
+                .get::<&str>()
+                .expect("Failed to get str from 'name' property"),
+            Some("test")
+        );
+        assert!(obj.set_property_from_pspec(&pspec, &false).is_err());
+
+        let child = Object::with_type(ChildObject::static_type(), &[]).expect("Object::new failed");
+        assert_eq!(
+            child
+                .get_property_from_pspec(&pspec)
+                .err()
+                .expect("Got property of another type")
+                .to_string(),
+            "property 'name' of type 'SimpleObject' is not a property of type 'ChildObject'",
+        );
+    }
+
+    #[test]
+    fn test_notify_debounced() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use std::time::Duration;
+
+        let context = crate::MainContext::new();
+        context.with_thread_default(|| {
+            let obj =
+                Object::with_type(SimpleObject::static_type(), &[]).expect("Object::new failed");
+
+            let calls = Rc::new(Cell::new(0));
+            let calls_clone = calls.clone();
+            obj.connect_notify_debounced(Some("name"), Duration::from_millis(10), move |obj| {
+                assert_eq!(
+                    obj.get_property("name")
+                        .expect("Failed to get 'name' property")
+                        .get::<&str>()
+                        .expect("Failed to get str from 'name' property"),
+                    Some("3")
+                );
+                calls_clone.set(calls_clone.get() + 1);
+            });
+
+            for name in &["1", "2", "3"] {
+                assert!(obj.set_property("name", name).is_ok());
+            }
+            assert_eq!(calls.get(), 0);
+
+            while calls.get() == 0 {
+                context.iteration(true);
+            }
+            assert_eq!(calls.get(), 1);
+            assert!(!context.pending());
+        });
+    }
+
+    #[test]
+    fn test_connect_local_async() {
+        use futures_channel::oneshot;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let context = crate::MainContext::new();
+        context.with_thread_default(|| {
+            let obj =
+                Object::with_type(SimpleObject::static_type(), &[]).expect("Object::new failed");
+
+            let started = Rc::new(RefCell::new(Vec::new()));
+            let senders = Rc::new(RefCell::new(Vec::new()));
+            let started_clone = started.clone();
+            let senders_clone = senders.clone();
+            obj.connect_local_async_limited("name-changed", false, 1, move |args| {
+                let name = args[1]
+                    .get::<String>()
+                    .expect("Failed to get args[1]")
+                    .expect("Failed to get str from args[1]");
+                started_clone.borrow_mut().push(name);
+                let (sender, receiver) = oneshot::channel::<()>();
+                senders_clone.borrow_mut().push(sender);
+                async move {
+                    let _ = receiver.await;
+                }
+            })
+            .expect("Failed to connect on 'name-changed'");
+
+            for name in &["1", "2"] {
+                obj.emit_by_name("name-changed", &[name])
+                    .expect("Failed to emit");
+            }
+            while context.iteration(false) {}
+            assert_eq!(*started.borrow(), vec!["1"]);
+
+            let sender = senders.borrow_mut().remove(0);
+            sender.send(()).expect("Failed to send");
+            while context.iteration(false) {}
+            assert_eq!(*started.borrow(), vec!["1", "2"]);
+
+            assert!(obj
+                .connect_local_async("change-name", false, |_| async {})
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn test_freeze_notify() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let obj = Object::with_type(SimpleObject::static_type(), &[]).expect("Object::new failed");
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        obj.connect_notify(Some("name"), move |_, _| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let guard = obj.freeze_notify();
+        for name in &["1", "2", "3"] {
+            assert!(obj.set_property("name", name).is_ok());
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        drop(guard);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let pspec = obj.find_property("name").expect("Failed to find 'name'");
+        obj.notify_by_pspec(&pspec);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
     #[test]
     fn test_signals() {
         use std::sync::atomic::{AtomicBool, Ordering};
@@ -589,6 +915,76 @@ mod test {
         assert!(name_changed_triggered.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn test_signal_object_class_handler() {
+        let obj = Object::with_type(SimpleObject::static_type(), &[("name", &"name")])
+            .expect("Object::new failed");
+
+        let name = obj
+            .emit_by_name("get-name", &[])
+            .expect("Failed to emit")
+            .expect("Failed to get value from emit")
+            .get::<String>()
+            .expect("Failed to get str from emit");
+        assert_eq!(name, Some("name".to_string()));
+    }
+
+    #[test]
+    fn test_signal_override_class_handler() {
+        let obj = Object::with_type(DerivedObject::static_type(), &[("name", &"old-name")])
+            .expect("Object::new failed");
+
+        let old_name = obj
+            .emit_by_name("change-name", &[&"new-name"])
+            .expect("Failed to emit")
+            .expect("Failed to get value from emit")
+            .get::<String>()
+            .expect("Failed to get str from emit");
+        assert_eq!(old_name, Some("old-name".to_string()));
+        assert_eq!(
+            obj.get_property("name")
+                .expect("Failed to get 'name' property")
+                .get::<&str>()
+                .expect("Failed to get str from 'name' property"),
+            Some("NEW-NAME")
+        );
+    }
+
+    #[test]
+    fn test_emission_hook() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let obj = Object::with_type(SimpleObject::static_type(), &[]).expect("Object::new failed");
+        // Hooks are called for all instances, including the ones of other tests
+        let obj_ptr = obj.as_ptr() as usize;
+        let signal_id =
+            crate::subclass::SignalId::lookup("name-changed", SimpleObject::static_type())
+                .expect("Failed to look up 'name-changed'");
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let hook = crate::signal_add_emission_hook(signal_id, None, move |hint, args| {
+            assert_eq!(hint.signal_id(), signal_id);
+            let instance = args[0]
+                .get::<Object>()
+                .expect("Failed to get args[0]")
+                .expect("Failed to get Object from args[0]");
+            if instance.as_ptr() as usize == obj_ptr {
+                calls_clone.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        obj.set_property("name", &"new-name")
+            .expect("Failed to set 'name' property");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        drop(hook);
+        obj.set_property("name", &"other-name")
+            .expect("Failed to set 'name' property");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_signal_return_expected_type() {
         let obj = Object::with_type(SimpleObject::static_type(), &[]).expect("Object::new failed");
@@ -607,6 +1003,26 @@ mod test {
         assert_eq!(value.get::<String>(), Ok(Some("return value".to_string())));
     }
 
+    #[test]
+    fn test_signal_emit_by_id() {
+        let obj = Object::with_type(SimpleObject::static_type(), &[("name", &"old-name")])
+            .expect("Object::new failed");
+
+        let signal_id = imp::SimpleObject::signals()[1].signal_id();
+
+        let old_name = obj
+            .emit_with_values(signal_id, &["new-name".to_value()])
+            .expect("Failed to emit")
+            .expect("Failed to get value from emit")
+            .get::<String>()
+            .expect("Failed to get str from emit");
+        assert_eq!(old_name, Some("old-name".to_string()));
+
+        assert!(obj.emit(signal_id, &[]).is_err());
+        assert!(obj.emit(signal_id, &[&1i32]).is_err());
+        assert!(obj.emit(signal_id, &[&"a", &"b"]).is_err());
+    }
+
     #[test]
     fn test_callback_validity() {
         use std::sync::atomic::{AtomicBool, Ordering};
@@ -3,13 +3,15 @@
 use smallvec::SmallVec;
 
 use crate::translate::*;
+use crate::value::FromValueOptional;
 use crate::Closure;
+use crate::ObjectType;
 use crate::SignalFlags;
 use crate::Type;
 use crate::Value;
 
 use std::ptr;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::{fmt, num::NonZeroU32};
 
 /// Builder for signals.
@@ -33,9 +35,16 @@ pub struct Signal {
     flags: SignalFlags,
     param_types: Vec<SignalType>,
     return_type: SignalType,
+    class_handler: Option<Arc<SignalClassHandler>>,
+    accumulator: Option<Arc<SignalAccumulator>>,
     registration: Mutex<SignalRegistration>,
 }
 
+type SignalClassHandler =
+    dyn Fn(&SignalClassHandlerToken, &[Value]) -> Option<Value> + Send + Sync + 'static;
+type SignalAccumulator =
+    dyn Fn(&SignalInvocationHint, &mut Value, &Value) -> bool + Send + Sync + 'static;
+
 /// Token passed to signal class handlers.
 pub struct SignalClassHandlerToken(pub(super) *mut gobject_ffi::GTypeInstance);
 
@@ -52,6 +61,11 @@ impl fmt::Debug for SignalClassHandlerToken {
 pub struct SignalInvocationHint(gobject_ffi::GSignalInvocationHint);
 
 impl SignalInvocationHint {
+    /// The ID of the signal being emitted.
+    pub fn signal_id(&self) -> SignalId {
+        unsafe { from_glib(self.0.signal_id) }
+    }
+
     pub fn detail(&self) -> crate::Quark {
         unsafe { from_glib(self.0.detail) }
     }
@@ -64,6 +78,7 @@ impl SignalInvocationHint {
 impl fmt::Debug for SignalInvocationHint {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         f.debug_struct("SignalInvocationHint")
+            .field("signal_id", &self.signal_id())
             .field("detail", &self.detail())
             .field("run_type", &self.run_type())
             .finish()
@@ -147,9 +162,9 @@ impl SignalId {
     pub fn parse_name(name: &str, type_: Type, force_detail: bool) -> Option<(Self, crate::Quark)> {
         let mut signal_id = std::mem::MaybeUninit::uninit();
         let mut detail_quark = std::mem::MaybeUninit::uninit();
-        unsafe {
+        run_with_c_str(name, |name| unsafe {
             let found: bool = from_glib(gobject_ffi::g_signal_parse_name(
-                name.to_glib_none().0,
+                name.as_ptr(),
                 type_.to_glib(),
                 signal_id.as_mut_ptr(),
                 detail_quark.as_mut_ptr(),
@@ -164,19 +179,40 @@ impl SignalId {
             } else {
                 None
             }
-        }
+        })
     }
 
     /// Find a SignalId by it's `name` and the `type` it connects to.
     #[doc(alias = "g_signal_lookup")]
     pub fn lookup(name: &str, type_: Type) -> Option<Self> {
-        unsafe {
-            let signal_id = gobject_ffi::g_signal_lookup(name.to_glib_none().0, type_.to_glib());
+        run_with_c_str(name, |name| unsafe {
+            let signal_id = gobject_ffi::g_signal_lookup(name.as_ptr(), type_.to_glib());
             if signal_id == 0 {
                 None
             } else {
                 Some(Self::new(NonZeroU32::new_unchecked(signal_id)))
             }
+        })
+    }
+
+    /// Lists the signals registered by `type_` itself, not including the ones of its ancestors.
+    ///
+    /// The class or default interface of `type_` must have been initialized for its signals to
+    /// be registered.
+    #[doc(alias = "g_signal_list_ids")]
+    pub fn list(type_: Type) -> Vec<Self> {
+        unsafe {
+            let mut n_ids = 0;
+            let ids = gobject_ffi::g_signal_list_ids(type_.to_glib(), &mut n_ids);
+            if ids.is_null() {
+                return Vec::new();
+            }
+            let res = std::slice::from_raw_parts(ids, n_ids as usize)
+                .iter()
+                .map(|id| from_glib(*id))
+                .collect();
+            ffi::g_free(ids as ffi::gpointer);
+            res
         }
     }
 
@@ -317,22 +353,9 @@ impl FromGlibContainerAsVec<Type, *const ffi::GType> for SignalType {
     }
 }
 
-#[allow(clippy::type_complexity)]
 enum SignalRegistration {
-    Unregistered {
-        class_handler: Option<
-            Box<
-                dyn Fn(&SignalClassHandlerToken, &[Value]) -> Option<Value> + Send + Sync + 'static,
-            >,
-        >,
-        accumulator: Option<
-            Box<dyn Fn(&SignalInvocationHint, &mut Value, &Value) -> bool + Send + Sync + 'static>,
-        >,
-    },
-    Registered {
-        type_: Type,
-        signal_id: SignalId,
-    },
+    Unregistered,
+    Registered { type_: Type, signal_id: SignalId },
 }
 
 impl<'a> SignalBuilder<'a> {
@@ -412,6 +435,18 @@ impl<'a> SignalBuilder<'a> {
         self
     }
 
+    /// Class handler for this signal that gets the object the signal is emitted on as `T`.
+    ///
+    /// `values` still contains all arguments of the signal including the object, so that they
+    /// can be passed on when chaining up from an overridden class handler.
+    pub fn object_class_handler<T, F>(self, func: F) -> Self
+    where
+        T: ObjectType + for<'v> FromValueOptional<'v>,
+        F: Fn(&SignalClassHandlerToken, &T, &[Value]) -> Option<Value> + Send + Sync + 'static,
+    {
+        self.class_handler(move |token, values| func(token, &signal_object(values), values))
+    }
+
     /// Accumulator for the return values of the signal.
     ///
     /// This is called if multiple signal handlers are connected to the signal for accumulating the
@@ -445,14 +480,21 @@ impl<'a> SignalBuilder<'a> {
             flags,
             param_types: self.param_types.to_vec(),
             return_type: self.return_type,
-            registration: Mutex::new(SignalRegistration::Unregistered {
-                class_handler: self.class_handler,
-                accumulator: self.accumulator,
-            }),
+            class_handler: self.class_handler.map(Arc::from),
+            accumulator: self.accumulator.map(Arc::from),
+            registration: Mutex::new(SignalRegistration::Unregistered),
         }
     }
 }
 
+// Returns the object a signal is emitted on from the arguments of the signal
+pub(super) fn signal_object<T: ObjectType + for<'v> FromValueOptional<'v>>(values: &[Value]) -> T {
+    values[0]
+        .get::<T>()
+        .expect("Signal emitted on object of unexpected type")
+        .expect("Signal emitted without object")
+}
+
 impl Signal {
     /// Create a new builder for a signal.
     pub fn builder<'a>(
@@ -495,7 +537,7 @@ impl Signal {
     /// This will panic if called before the signal was registered.
     pub fn signal_id(&self) -> SignalId {
         match &*self.registration.lock().unwrap() {
-            SignalRegistration::Unregistered { .. } => panic!("Signal not registered yet"),
+            SignalRegistration::Unregistered => panic!("Signal not registered yet"),
             SignalRegistration::Registered { signal_id, .. } => *signal_id,
         }
     }
@@ -505,7 +547,7 @@ impl Signal {
     /// This will panic if called before the signal was registered.
     pub fn type_(&self) -> Type {
         match &*self.registration.lock().unwrap() {
-            SignalRegistration::Unregistered { .. } => panic!("Signal not registered yet"),
+            SignalRegistration::Unregistered => panic!("Signal not registered yet"),
             SignalRegistration::Registered { type_, .. } => *type_,
         }
     }
@@ -513,13 +555,13 @@ impl Signal {
     pub(super) fn register(&self, type_: Type) {
         let mut registration = self.registration.lock().unwrap();
 
-        let (class_handler, accumulator) = match &mut *registration {
-            SignalRegistration::Unregistered {
-                class_handler,
-                accumulator,
-            } => (class_handler.take(), accumulator.take()),
-            SignalRegistration::Registered { .. } => unreachable!(),
-        };
+        // Classes of dynamic types are initialized again after their type module was reloaded,
+        // and the signal has to be registered again if it was destroyed with the previous class.
+        if let SignalRegistration::Registered { signal_id, .. } = *registration {
+            if SignalId::lookup(&self.name, type_) == Some(signal_id) {
+                return;
+            }
+        }
 
         let param_types = self
             .param_types
@@ -527,7 +569,7 @@ impl Signal {
             .map(ToGlib::to_glib)
             .collect::<Vec<_>>();
 
-        let class_handler = class_handler.map(|class_handler| {
+        let class_handler = self.class_handler.clone().map(|class_handler| {
             Closure::new(move |values| unsafe {
                 let instance = gobject_ffi::g_value_get_object(values[0].to_glib_none().0);
                 class_handler(&SignalClassHandlerToken(instance as *mut _), values)
@@ -540,11 +582,7 @@ impl Signal {
             handler_return: *const gobject_ffi::GValue,
             data: ffi::gpointer,
         ) -> ffi::gboolean {
-            let accumulator = &**(data
-                as *const *const (dyn Fn(&SignalInvocationHint, &mut Value, &Value) -> bool
-                     + Send
-                     + Sync
-                     + 'static));
+            let accumulator = &**(data as *const Arc<SignalAccumulator>);
             accumulator(
                 &SignalInvocationHint(*ihint),
                 &mut *(return_accu as *mut Value),
@@ -553,9 +591,10 @@ impl Signal {
             .to_glib()
         }
 
-        let (accumulator, accumulator_trampoline) = if let Some(accumulator) = accumulator {
+        let (accumulator, accumulator_trampoline) = if let Some(ref accumulator) = self.accumulator
+        {
             (
-                Box::into_raw(Box::new(accumulator)),
+                Box::into_raw(Box::new(accumulator.clone())),
                 Some::<unsafe extern "C" fn(_, _, _, _) -> _>(accumulator_trampoline),
             )
         } else {
@@ -0,0 +1,137 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::prelude::*;
+use crate::translate::*;
+use crate::{Cast, TypeModule};
+
+pub trait TypeModuleImpl: ObjectImpl {
+    /// Loads the module and registers its types with
+    /// [`register_dynamic_type`](super::register_dynamic_type) and
+    /// [`register_dynamic_interface`](super::register_dynamic_interface).
+    ///
+    /// This is called whenever the module starts being used again after it was unloaded, so
+    /// the types have to be registered again every time.
+    ///
+    /// Returns `false` if loading failed.
+    fn load(&self, type_module: &Self::Type) -> bool;
+
+    /// Unloads the module once none of its types is in use anymore.
+    fn unload(&self, type_module: &Self::Type);
+}
+
+unsafe impl<T: TypeModuleImpl> IsSubclassable<T> for TypeModule {
+    fn class_init(class: &mut crate::Class<Self>) {
+        <crate::Object as IsSubclassable<T>>::class_init(class);
+
+        let klass = class.as_mut();
+        klass.load = Some(type_module_load::<T>);
+        klass.unload = Some(type_module_unload::<T>);
+    }
+
+    fn instance_init(instance: &mut super::InitializingObject<T>) {
+        <crate::Object as IsSubclassable<T>>::instance_init(instance);
+    }
+}
+
+unsafe extern "C" fn type_module_load<T: TypeModuleImpl>(
+    ptr: *mut gobject_ffi::GTypeModule,
+) -> ffi::gboolean {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TypeModule> = from_glib_borrow(ptr);
+
+    imp.load(wrap.unsafe_cast_ref()).to_glib()
+}
+
+unsafe extern "C" fn type_module_unload<T: TypeModuleImpl>(ptr: *mut gobject_ffi::GTypeModule) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TypeModule> = from_glib_borrow(ptr);
+
+    imp.unload(wrap.unsafe_cast_ref());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as glib;
+    use crate::prelude::*;
+    use crate::subclass::register_dynamic_type;
+    use std::cell::Cell;
+
+    mod imp {
+        use super::*;
+
+        #[derive(Default)]
+        pub struct Plugin {
+            pub(super) loaded: Cell<u32>,
+            pub(super) unloaded: Cell<u32>,
+        }
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for Plugin {
+            const NAME: &'static str = "TypeModuleTestPlugin";
+            type Type = super::Plugin;
+            type ParentType = TypeModule;
+        }
+
+        impl ObjectImpl for Plugin {}
+
+        impl TypeModuleImpl for Plugin {
+            fn load(&self, type_module: &Self::Type) -> bool {
+                self.loaded.set(self.loaded.get() + 1);
+                register_dynamic_type::<PluginObject>(type_module.upcast_ref());
+                true
+            }
+
+            fn unload(&self, _type_module: &Self::Type) {
+                self.unloaded.set(self.unloaded.get() + 1);
+            }
+        }
+
+        #[derive(Default)]
+        pub struct PluginObject;
+
+        #[glib::object_subclass(dynamic)]
+        impl ObjectSubclass for PluginObject {
+            const NAME: &'static str = "TypeModuleTestPluginObject";
+            type Type = super::PluginObject;
+            type ParentType = crate::Object;
+        }
+
+        impl ObjectImpl for PluginObject {}
+    }
+
+    glib::wrapper! {
+        pub struct Plugin(ObjectSubclass<imp::Plugin>) @extends TypeModule;
+    }
+
+    glib::wrapper! {
+        pub struct PluginObject(ObjectSubclass<imp::PluginObject>);
+    }
+
+    #[test]
+    fn load_unload() {
+        let plugin = crate::Object::new::<Plugin>(&[]).unwrap();
+        let imp = imp::Plugin::from_instance(&plugin);
+        plugin.set_name("test-plugin");
+
+        assert!(plugin.use_());
+        let type_ = PluginObject::static_type();
+        assert_eq!(type_.name(), "TypeModuleTestPluginObject");
+        plugin.unuse();
+        assert_eq!((imp.loaded.get(), imp.unloaded.get()), (1, 1));
+
+        // The type stays registered while the module is unloaded and is registered again by
+        // the next load
+        assert!(plugin.use_());
+        assert_eq!(PluginObject::static_type(), type_);
+        let obj = crate::Object::new::<PluginObject>(&[]).unwrap();
+        assert!(obj.get_type().is_a(crate::Object::static_type()));
+        drop(obj);
+        assert_eq!(imp.loaded.get(), 2);
+
+        // Type modules can't be freed once they registered types
+        std::mem::forget(plugin);
+    }
+}
@@ -4,7 +4,8 @@
 
 use crate::object::{Cast, ObjectSubclassIs, ObjectType};
 use crate::translate::*;
-use crate::{Closure, Object, StaticType, Type, Value};
+use crate::{Closure, Object, Quark, StaticType, Type, Value};
+use std::cell::RefCell;
 use std::marker;
 use std::mem;
 use std::ptr;
@@ -30,6 +31,8 @@ impl<T> ToGlib for InitializingType<T> {
 struct PrivateStruct<T: ObjectSubclass> {
     imp: T,
     instance_data: Option<HashMap<Type, Box<dyn Any + Send + Sync>>>,
+    // Values of the construct properties, `None` once the object is constructed
+    construct_properties: RefCell<Option<Vec<(String, Value)>>>,
 }
 
 /// Trait implemented by structs that implement a `GObject` C instance struct.
@@ -142,7 +145,7 @@ where
     /// Instance specific initialization.
     ///
     /// This is automatically called during instance initialization.
-    fn instance_init(_instance: &mut InitializingObject<T>);
+    fn instance_init(_instance: &mut InitializingObject<T>) {}
 }
 
 unsafe extern "C" fn interface_init<T: ObjectSubclass, A: IsImplementable<T>>(
@@ -451,6 +454,17 @@ pub trait ObjectSubclass: ObjectSubclassType + Sized + 'static {
     /// Optional.
     const ABSTRACT: bool = false;
 
+    /// If this subclass is a final class or not.
+    ///
+    /// By default all subclasses can be subclassed further but setting this to `true` will make
+    /// registering any subclass of this type panic.
+    ///
+    /// With GLib 2.70 or newer the type is also registered as final with the type system, so
+    /// that it can't be subclassed from C either.
+    ///
+    /// Optional.
+    const FINAL: bool = false;
+
     /// Wrapper around this subclass defined with `wrapper!`
     type Type: ObjectType
         + ObjectSubclassIs<Subclass = Self>
@@ -550,6 +564,29 @@ pub trait ObjectSubclassExt: ObjectSubclass {
     ///
     /// This is used for the subclassing infrastructure to store additional instance data.
     fn get_instance_data<U: Any + Send + Sync + 'static>(&self, type_: Type) -> Option<&U>;
+
+    /// Returns the interface struct of `I` as it was before this type implemented `I`.
+    ///
+    /// This contains the implementation of the parent class, or the default implementation of
+    /// the interface if no parent class implements it, and is used to chain up from virtual
+    /// methods of interfaces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this type doesn't implement `I`.
+    fn parent_interface<I: crate::object::IsInterface>() -> &'static crate::Interface<I>;
+
+    /// Returns the class struct of the parent class as the class struct of its ancestor `P`.
+    ///
+    /// `P` is usually the type declaring a virtual method. The class struct contains the
+    /// implementation of the closest ancestor overriding the method, which can be a Rust type
+    /// itself, and is used to chain up from virtual methods of classes declared in Rust. See
+    /// [`parent_vfunc!`](crate::parent_vfunc) for reading a virtual method from it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent class is not a subclass of `P`.
+    fn parent_class<P: crate::object::IsClass>() -> &'static crate::Class<P>;
 }
 
 impl<T: ObjectSubclass> ObjectSubclassExt for T {
@@ -606,6 +643,57 @@ impl<T: ObjectSubclass> ObjectSubclassExt for T {
             }
         }
     }
+
+    fn parent_interface<I: crate::object::IsInterface>() -> &'static crate::Interface<I> {
+        unsafe {
+            let type_data = Self::type_data();
+            let parent_iface = type_data.as_ref().get_parent_interface::<I>();
+            &*(parent_iface as *const crate::Interface<I>)
+        }
+    }
+
+    fn parent_class<P: crate::object::IsClass>() -> &'static crate::Class<P> {
+        unsafe {
+            let type_data = Self::type_data();
+            let parent_class = &*(type_data.as_ref().get_parent_class() as *const crate::Class<P>);
+            assert!(
+                parent_class.get_type().is_a(P::static_type()),
+                "Parent class of {} is not a subclass of {}",
+                type_data.as_ref().get_type(),
+                P::static_type(),
+            );
+            parent_class
+        }
+    }
+}
+
+/// Returns the virtual method `$vfunc` from the class struct of `$class` of the parent class
+/// of the subclass `$subclass`, for chaining up to the parent implementation.
+///
+/// If arguments are passed after `$vfunc`, the parent implementation is called with them and
+/// its return value is returned, or `None` if `$vfunc` is not set.
+///
+/// The virtual method has to be a field containing an `Option` of a function pointer. See
+/// [`ObjectSubclassExt::parent_class`] for details.
+///
+/// ```ignore
+/// impl<T: AnimalImpl> AnimalImplExt for T {
+///     fn parent_speak(&self, animal: &Self::Type) -> String {
+///         glib::parent_vfunc!(T, Animal, speak, animal.dynamic_cast_ref().unwrap())
+///             .expect("No parent implementation of speak")
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! parent_vfunc {
+    ($subclass:ty, $class:ty, $vfunc:ident) => {
+        <$subclass as $crate::subclass::types::ObjectSubclassExt>::parent_class::<$class>()
+            .as_ref()
+            .$vfunc
+    };
+    ($subclass:ty, $class:ty, $vfunc:ident, $($arg:expr),+ $(,)?) => {
+        $crate::parent_vfunc!($subclass, $class, $vfunc).map(|vfunc| vfunc($($arg),+))
+    };
 }
 
 /// An object that is currently being initialized.
@@ -725,6 +813,7 @@ unsafe extern "C" fn instance_init<T: ObjectSubclass>(
         PrivateStruct {
             imp,
             instance_data: None,
+            construct_properties: RefCell::new(Some(Vec::new())),
         },
     );
 
@@ -750,6 +839,7 @@ unsafe extern "C" fn finalize<T: ObjectSubclass>(obj: *mut gobject_ffi::GObject)
     if let Some(instance_data) = priv_storage.instance_data.take() {
         drop(instance_data);
     }
+    ptr::drop_in_place(&mut priv_storage.construct_properties);
 
     // Chain up to the parent class' finalize implementation, if any.
     let parent_class = &*(data.as_ref().get_parent_class() as *const gobject_ffi::GObjectClass);
@@ -767,15 +857,7 @@ unsafe extern "C" fn finalize<T: ObjectSubclass>(obj: *mut gobject_ffi::GObject)
 ///
 /// [`object_subclass!`]: ../../macro.object_subclass.html
 pub fn register_type<T: ObjectSubclass>() -> Type {
-    // GLib aligns the type private data to two gsizes so we can't safely store any type there that
-    // requires a bigger alignment.
-    if mem::align_of::<T>() > 2 * mem::size_of::<usize>() {
-        panic!(
-            "Alignment {} of type not supported, bigger than {}",
-            mem::align_of::<T>(),
-            2 * mem::size_of::<usize>(),
-        );
-    }
+    check_private_alignment::<T>();
 
     unsafe {
         use std::ffi::CString;
@@ -788,6 +870,15 @@ pub fn register_type<T: ObjectSubclass>() -> Type {
             );
         }
 
+        let parent_type = <T::ParentType as StaticType>::static_type();
+        if is_final_type(parent_type) {
+            panic!(
+                "Type {} can't be registered as {} is final",
+                type_name.to_str().unwrap(),
+                parent_type
+            );
+        }
+
         let type_ = from_glib(gobject_ffi::g_type_register_static_simple(
             <T::ParentType as StaticType>::static_type().to_glib(),
             type_name.as_ptr(),
@@ -795,33 +886,26 @@ pub fn register_type<T: ObjectSubclass>() -> Type {
             Some(class_init::<T>),
             mem::size_of::<T::Instance>() as u32,
             Some(instance_init::<T>),
-            if T::ABSTRACT {
-                gobject_ffi::G_TYPE_FLAG_ABSTRACT
-            } else {
-                0
-            },
+            type_flags::<T>(),
         ));
 
         let mut data = T::type_data();
         (*data.as_mut()).type_ = type_;
 
+        if T::FINAL {
+            gobject_ffi::g_type_set_qdata(
+                type_.to_glib(),
+                final_type_quark().to_glib(),
+                1 as ffi::gpointer,
+            );
+        }
+
         let private_offset = gobject_ffi::g_type_add_instance_private(
             type_.to_glib(),
             mem::size_of::<PrivateStruct<T>>(),
         );
         (*data.as_mut()).private_offset = private_offset as isize;
-
-        // Get the offset from PrivateStruct<T> to the imp field in it. This has to go through
-        // some hoops because Rust doesn't have an offsetof operator yet.
-        (*data.as_mut()).private_imp_offset = {
-            // Must not be a dangling pointer so let's create some uninitialized memory
-            let priv_ = std::mem::MaybeUninit::<PrivateStruct<T>>::uninit();
-            let ptr = priv_.as_ptr();
-            // FIXME: Technically UB but we'd need std::ptr::raw_const for this
-            let imp_ptr = &(*ptr).imp as *const _ as *const u8;
-            let ptr = ptr as *const u8;
-            imp_ptr as isize - ptr as isize
-        };
+        (*data.as_mut()).private_imp_offset = private_imp_offset::<T>();
 
         let iface_types = T::Interfaces::iface_infos();
         for (iface_type, iface_info) in iface_types {
@@ -834,6 +918,178 @@ pub fn register_type<T: ObjectSubclass>() -> Type {
     }
 }
 
+/// Register a `glib::Type` ID for `T` that belongs to `type_module`.
+///
+/// This must be called from [`TypeModuleImpl::load()`] every time the module is loaded. The type
+/// keeps its ID when the module is unloaded and loaded again, but its class is finalized in
+/// between and initialized again once it is used next. `type_init()` is only called when the type
+/// is registered for the first time.
+///
+/// The [`object_subclass!`] macro creates a `get_type()` function for types declared as `dynamic`
+/// that returns the type registered by this function, instead of registering it statically.
+///
+/// [`TypeModuleImpl::load()`]: super::type_module::TypeModuleImpl::load
+/// [`object_subclass!`]: ../../macro.object_subclass.html
+pub fn register_dynamic_type<T: ObjectSubclass>(type_module: &crate::TypeModule) -> Type {
+    check_private_alignment::<T>();
+
+    unsafe {
+        use std::ffi::CString;
+
+        let type_name = CString::new(T::NAME).unwrap();
+
+        let parent_type = <T::ParentType as StaticType>::static_type();
+        if is_final_type(parent_type) {
+            panic!(
+                "Type {} can't be registered as {} is final",
+                type_name.to_str().unwrap(),
+                parent_type
+            );
+        }
+
+        let type_info = gobject_ffi::GTypeInfo {
+            class_size: mem::size_of::<T::Class>() as u16,
+            base_init: None,
+            base_finalize: None,
+            class_init: Some(class_init::<T>),
+            class_finalize: None,
+            class_data: ptr::null(),
+            instance_size: mem::size_of::<T::Instance>() as u16,
+            n_preallocs: 0,
+            instance_init: Some(instance_init::<T>),
+            value_table: ptr::null(),
+        };
+
+        let mut data = T::type_data();
+        let registered = data.as_ref().get_type().is_valid();
+
+        let type_: Type = from_glib(gobject_ffi::g_type_module_register_type(
+            type_module.to_glib_none().0,
+            parent_type.to_glib(),
+            type_name.as_ptr(),
+            &type_info,
+            type_flags::<T>(),
+        ));
+        if !type_.is_valid() {
+            panic!(
+                "Type {} has already been registered by another module",
+                type_name.to_str().unwrap()
+            );
+        }
+
+        (*data.as_mut()).type_ = type_;
+
+        if T::FINAL {
+            gobject_ffi::g_type_set_qdata(
+                type_.to_glib(),
+                final_type_quark().to_glib(),
+                1 as ffi::gpointer,
+            );
+        }
+
+        // Private data can't be added to dynamic types during registration. Storing the size
+        // instead of an offset makes `class_init()` add it when the class is initialized again.
+        (*data.as_mut()).private_offset = mem::size_of::<PrivateStruct<T>>() as isize;
+        (*data.as_mut()).private_imp_offset = private_imp_offset::<T>();
+
+        let iface_types = T::Interfaces::iface_infos();
+        for (iface_type, iface_info) in iface_types {
+            gobject_ffi::g_type_module_add_interface(
+                type_module.to_glib_none().0,
+                type_.to_glib(),
+                iface_type,
+                &iface_info,
+            );
+        }
+
+        if !registered {
+            T::type_init(&mut InitializingType::<T>(type_, marker::PhantomData));
+        }
+
+        type_
+    }
+}
+
+fn check_private_alignment<T: ObjectSubclass>() {
+    // GLib aligns the type private data to two gsizes so we can't safely store any type there that
+    // requires a bigger alignment.
+    if mem::align_of::<T>() > 2 * mem::size_of::<usize>() {
+        panic!(
+            "Alignment {} of type not supported, bigger than {}",
+            mem::align_of::<T>(),
+            2 * mem::size_of::<usize>(),
+        );
+    }
+}
+
+// Get the values of the construct properties of the object of `imp` that were set so far, or
+// `None` once the object is constructed.
+pub(super) fn construct_properties<T: ObjectSubclass>(
+    imp: &T,
+) -> &RefCell<Option<Vec<(String, Value)>>> {
+    unsafe {
+        let type_data = T::type_data();
+        assert!(type_data.as_ref().get_type().is_valid());
+
+        let offset = -type_data.as_ref().private_imp_offset;
+
+        let ptr = imp as *const T as *const u8;
+        let ptr = ptr.offset(offset);
+        let priv_ = &*(ptr as *const PrivateStruct<T>);
+        &priv_.construct_properties
+    }
+}
+
+// Get the offset from PrivateStruct<T> to the imp field in it. This has to go through
+// some hoops because Rust doesn't have an offsetof operator yet.
+unsafe fn private_imp_offset<T: ObjectSubclass>() -> isize {
+    // Must not be a dangling pointer so let's create some uninitialized memory
+    let priv_ = std::mem::MaybeUninit::<PrivateStruct<T>>::uninit();
+    let ptr = priv_.as_ptr();
+    // FIXME: Technically UB but we'd need std::ptr::raw_const for this
+    let imp_ptr = &(*ptr).imp as *const _ as *const u8;
+    let ptr = ptr as *const u8;
+    imp_ptr as isize - ptr as isize
+}
+
+// `G_TYPE_FLAG_FINAL`, which is only known to GLib 2.70 and newer
+const TYPE_FLAG_FINAL: gobject_ffi::GTypeFlags = 1 << 6;
+
+fn supports_final_types() -> bool {
+    crate::check_runtime_version(2, 70, 0).is_ok()
+}
+
+fn type_flags<T: ObjectSubclass>() -> gobject_ffi::GTypeFlags {
+    let mut flags = 0;
+    if T::ABSTRACT {
+        flags |= gobject_ffi::G_TYPE_FLAG_ABSTRACT;
+    }
+    if T::FINAL && supports_final_types() {
+        flags |= TYPE_FLAG_FINAL;
+    }
+    flags
+}
+
+// GLib only supports final types since 2.70 so this is tracked on the Rust side too.
+fn final_type_quark() -> Quark {
+    Quark::from_string("gtk-rs-subclass-final")
+}
+
+fn is_final_type(type_: Type) -> bool {
+    unsafe {
+        if supports_final_types()
+            && from_glib(gobject_ffi::g_type_test_flags(
+                type_.to_glib(),
+                TYPE_FLAG_FINAL,
+            ))
+        {
+            return true;
+        }
+
+        !gobject_ffi::g_type_get_qdata(type_.to_glib(), final_type_quark().to_glib()).is_null()
+    }
+}
+
 pub(crate) unsafe fn signal_override_class_handler<F>(
     name: &str,
     type_: ffi::GType,
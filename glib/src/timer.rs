@@ -0,0 +1,101 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::ptr;
+use std::time::Duration;
+
+/// A stopwatch measuring the elapsed time with the monotonic clock of GLib.
+///
+/// The timer is started when it is created.
+#[derive(Debug)]
+#[doc(alias = "GTimer")]
+pub struct Timer(ptr::NonNull<ffi::GTimer>);
+
+unsafe impl Send for Timer {}
+unsafe impl Sync for Timer {}
+
+impl Timer {
+    /// Creates a new timer and starts it.
+    #[doc(alias = "g_timer_new")]
+    pub fn new() -> Self {
+        unsafe { Timer(ptr::NonNull::new_unchecked(ffi::g_timer_new())) }
+    }
+
+    /// Starts the timer again from zero.
+    #[doc(alias = "g_timer_start")]
+    pub fn start(&mut self) {
+        unsafe { ffi::g_timer_start(self.0.as_ptr()) }
+    }
+
+    /// Stops the timer, so that [`elapsed`](Self::elapsed) returns the time between the start
+    /// and now.
+    #[doc(alias = "g_timer_stop")]
+    pub fn stop(&mut self) {
+        unsafe { ffi::g_timer_stop(self.0.as_ptr()) }
+    }
+
+    /// Continues a stopped timer, without counting the time it was stopped.
+    #[doc(alias = "g_timer_continue")]
+    pub fn resume(&mut self) {
+        unsafe { ffi::g_timer_continue(self.0.as_ptr()) }
+    }
+
+    /// Sets the elapsed time of a running timer to zero.
+    #[doc(alias = "g_timer_reset")]
+    pub fn reset(&mut self) {
+        unsafe { ffi::g_timer_reset(self.0.as_ptr()) }
+    }
+
+    /// Returns the time the timer was running.
+    #[doc(alias = "g_timer_elapsed")]
+    pub fn elapsed(&self) -> Duration {
+        let seconds = unsafe { ffi::g_timer_elapsed(self.0.as_ptr(), ptr::null_mut()) };
+        // Negative after resetting a stopped timer
+        Duration::from_secs_f64(seconds.max(0.0))
+    }
+
+    /// Returns `true` if the timer is running.
+    #[cfg(any(feature = "v2_62", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_62")))]
+    #[doc(alias = "g_timer_is_active")]
+    pub fn is_active(&self) -> bool {
+        unsafe { ffi::g_timer_is_active(self.0.as_ptr()) != ffi::GFALSE }
+    }
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::g_timer_destroy(self.0.as_ptr());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_resume() {
+        let mut timer = Timer::new();
+        std::thread::sleep(Duration::from_millis(10));
+        timer.stop();
+        let elapsed = timer.elapsed();
+        assert!(elapsed >= Duration::from_millis(10));
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(timer.elapsed(), elapsed);
+
+        timer.resume();
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(timer.elapsed() >= elapsed + Duration::from_millis(10));
+
+        timer.reset();
+        assert!(timer.elapsed() < elapsed + Duration::from_millis(10));
+    }
+}
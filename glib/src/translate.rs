@@ -451,6 +451,65 @@ impl<'a, P: Ptr, T: ?Sized + ToGlibPtr<'a, P>> ToGlibPtr<'a, P> for &'a T {
     }
 }
 
+/// Size of the stack buffer used by [`run_with_c_str`] and [`run_with_c_path`].
+const MAX_STACK_C_STR_LEN: usize = 384;
+
+fn run_with_c_bytes<T, F: FnOnce(&CStr) -> T>(bytes: &[u8], f: F) -> T {
+    if bytes.len() < MAX_STACK_C_STR_LEN {
+        let mut buf = mem::MaybeUninit::<[u8; MAX_STACK_C_STR_LEN]>::uninit();
+        unsafe {
+            let ptr = buf.as_mut_ptr() as *mut u8;
+            ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+            *ptr.add(bytes.len()) = 0;
+
+            let c_str = CStr::from_bytes_with_nul(std::slice::from_raw_parts(ptr, bytes.len() + 1))
+                .expect("run_with_c_str: unexpected '\0' character");
+            f(c_str)
+        }
+    } else {
+        let c_string = CString::new(bytes).expect("run_with_c_str: unexpected '\0' character");
+        f(&c_string)
+    }
+}
+
+/// Calls `f` with a NUL-terminated copy of `s`.
+///
+/// Unlike `to_glib_none()`, which always allocates a `CString`, short strings are copied into a
+/// buffer on the stack. This is preferable for strings that are passed very often, e.g. property
+/// and signal names.
+///
+/// ```
+/// use glib::translate::run_with_c_str;
+///
+/// let len = run_with_c_str("label", |s| s.to_bytes().len());
+/// assert_eq!(len, 5);
+/// ```
+///
+/// # Panics
+///
+/// If `s` contains a NUL byte.
+pub fn run_with_c_str<T, F: FnOnce(&CStr) -> T>(s: &str, f: F) -> T {
+    run_with_c_bytes(s.as_bytes(), f)
+}
+
+/// Calls `f` with a NUL-terminated copy of `path` in the encoding expected by GLib.
+///
+/// See [`run_with_c_str`] for details.
+///
+/// # Panics
+///
+/// If `path` contains a NUL byte or, on Windows, can't be represented as UTF-8.
+pub fn run_with_c_path<T, F: FnOnce(&CStr) -> T>(path: &Path, f: F) -> T {
+    #[cfg(not(windows))]
+    {
+        run_with_c_bytes(path.as_os_str().as_bytes(), f)
+    }
+    #[cfg(windows)]
+    {
+        f(&path_to_c(path))
+    }
+}
+
 impl<'a> ToGlibPtr<'a, *const c_char> for str {
     type Storage = CString;
 
@@ -2252,6 +2311,30 @@ mod tests {
     use crate::GString;
     use std::collections::HashMap;
 
+    #[test]
+    fn c_str_on_stack_and_heap() {
+        run_with_c_str("", |s| assert_eq!(s.to_bytes(), b""));
+        run_with_c_str("property-name", |s| {
+            assert_eq!(s.to_bytes(), b"property-name")
+        });
+
+        let long = "a".repeat(MAX_STACK_C_STR_LEN * 2);
+        run_with_c_str(&long, |s| assert_eq!(s.to_bytes(), long.as_bytes()));
+
+        let exact = "b".repeat(MAX_STACK_C_STR_LEN);
+        run_with_c_str(&exact, |s| assert_eq!(s.to_bytes(), exact.as_bytes()));
+
+        run_with_c_path(Path::new("/tmp/foo"), |s| {
+            assert_eq!(s.to_bytes(), b"/tmp/foo")
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn c_str_with_nul() {
+        run_with_c_str("foo\0bar", |_| ());
+    }
+
     #[test]
     fn string_hash_map() {
         let mut map = HashMap::new();
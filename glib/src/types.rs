@@ -3,8 +3,8 @@
 //! Runtime type information.
 
 use crate::translate::{
-    from_glib, FromGlib, FromGlibContainerAsVec, ToGlib, ToGlibContainerFromSlice, ToGlibPtr,
-    ToGlibPtrMut,
+    from_glib, run_with_c_str, FromGlib, FromGlibContainerAsVec, ToGlib, ToGlibContainerFromSlice,
+    ToGlibPtr, ToGlibPtrMut,
 };
 use crate::value::{FromValue, FromValueOptional, SetValue, Value};
 
@@ -151,11 +151,10 @@ impl Type {
 
     #[doc(alias = "g_type_from_name")]
     pub fn from_name<'a, P: Into<&'a str>>(name: P) -> Option<Self> {
-        unsafe {
-            let type_: Self =
-                from_glib(gobject_ffi::g_type_from_name(name.into().to_glib_none().0));
+        run_with_c_str(name.into(), |name| unsafe {
+            let type_: Self = from_glib(gobject_ffi::g_type_from_name(name.as_ptr()));
             Some(type_).filter(|t| t.is_valid())
-        }
+        })
     }
 
     /// Checks that the type is not [`INVALID`](Self::INVALID)
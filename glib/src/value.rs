@@ -88,6 +88,7 @@ use std::ops::Deref;
 use std::ptr;
 
 use crate::gstring::GString;
+use crate::object::{BorrowedObject, ObjectType};
 use crate::translate::*;
 use crate::types::{StaticType, Type};
 
@@ -221,6 +222,33 @@ impl Value {
         }
     }
 
+    /// Tries to borrow the object of type `T` stored in the value.
+    ///
+    /// Unlike [`get`](Self::get) this doesn't take a new reference to the object, which is
+    /// cheaper for values that are only inspected, e.g. signal arguments.
+    ///
+    /// Returns `Ok` if the type is correct.
+    pub fn get_object_borrow<T: ObjectType>(
+        &self,
+    ) -> Result<Option<BorrowedObject<'_, T>>, GetError> {
+        unsafe {
+            let ok = from_glib(gobject_ffi::g_type_check_value_holds(
+                mut_override(self.to_glib_none().0),
+                T::static_type().to_glib(),
+            ));
+            if !ok {
+                return Err(GetError::new_type_mismatch(self.type_(), T::static_type()));
+            }
+
+            let obj = gobject_ffi::g_value_get_object(self.to_glib_none().0);
+            if obj.is_null() {
+                Ok(None)
+            } else {
+                Ok(Some(BorrowedObject::new(obj)))
+            }
+        }
+    }
+
     /// Returns `true` if the type of the value corresponds to `T`
     /// or is a sub-type of `T`.
     #[inline]
@@ -1162,6 +1190,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_object_borrow() {
+        use crate::{Object, ObjectExt};
+
+        let obj = Object::new(&[]).unwrap();
+        let v = obj.to_value();
+        let ref_count = obj.ref_count();
+        let borrowed = v.get_object_borrow::<Object>().unwrap().unwrap();
+        assert_eq!(*borrowed, obj);
+        assert_eq!(obj.ref_count(), ref_count);
+        drop(borrowed);
+        assert_eq!(obj.ref_count(), ref_count);
+
+        assert!(v.get_object_borrow::<crate::Binding>().is_err());
+        assert!(123.to_value().get_object_borrow::<Object>().is_err());
+        let none_v = Value::from_type(Object::static_type());
+        assert!(none_v.get_object_borrow::<Object>().unwrap().is_none());
+    }
+
     #[test]
     fn test_transform() {
         let v = 123.to_value();
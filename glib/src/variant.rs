@@ -90,9 +90,11 @@ use crate::value;
 use crate::StaticType;
 use crate::Type;
 use crate::Value;
+use crate::VariantBuilder;
 use crate::VariantIter;
 use crate::VariantTy;
 use crate::VariantType;
+use crate::VariantTypedIter;
 use std::borrow::Cow;
 use std::cmp::{Eq, Ordering, PartialEq, PartialOrd};
 use std::collections::HashMap;
@@ -234,6 +236,19 @@ impl Variant {
         }
     }
 
+    /// Creates a new GVariant array from the items of `iter`.
+    ///
+    /// Unlike [`array`](Self::array), this doesn't require collecting the children first.
+    pub fn array_from_iter<T: StaticVariantType + ToVariant>(
+        iter: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let mut builder = VariantBuilder::new(&<Vec<T>>::static_variant_type());
+        for item in iter {
+            builder.add(&item);
+        }
+        builder.end()
+    }
+
     /// Creates a new GVariant tuple from children.
     pub fn tuple(children: &[Variant]) -> Self {
         unsafe {
@@ -313,6 +328,17 @@ impl Variant {
         VariantIter::new(self.clone())
     }
 
+    /// Create an iterator over the elements of an array variant, converted to `T`.
+    ///
+    /// Returns `None` if the variant is not an array of `T`.
+    pub fn iter_as<T: FromVariant>(&self) -> Option<VariantTypedIter<T>> {
+        if self.is::<Vec<T>>() {
+            Some(VariantTypedIter::new(self.clone()))
+        } else {
+            None
+        }
+    }
+
     /// Variant has a container type.
     pub fn is_container(&self) -> bool {
         unsafe { ffi::g_variant_is_container(self.to_glib_none().0) != ffi::GFALSE }
@@ -912,4 +938,14 @@ mod tests {
             "a(syu)"
         );
     }
+
+    #[test]
+    fn test_array_from_iter() {
+        let v = Variant::array_from_iter((0..3u32).map(|i| (i.to_string(), i)));
+        assert_eq!(v.type_().to_str(), "a(su)");
+        assert_eq!(
+            v.iter_as::<(String, u32)>().unwrap().collect::<Vec<_>>(),
+            vec![("0".into(), 0), ("1".into(), 1), ("2".into(), 2)]
+        );
+    }
 }
@@ -0,0 +1,147 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::translate::*;
+use crate::variant::{ToVariant, Variant};
+use crate::VariantTy;
+use std::ptr;
+
+/// Incrementally constructs a container [`Variant`] such as an array, tuple or dictionary.
+///
+/// Compared to calling `to_variant()` on a collection, the children are added to the builder one
+/// by one without first collecting them into an intermediate collection of variants.
+///
+/// ```
+/// use glib::{VariantBuilder, VariantTy};
+///
+/// let mut builder = VariantBuilder::new(VariantTy::new("a(su)").unwrap());
+/// for i in 0..3u32 {
+///     builder.add(&("item", i));
+/// }
+/// let variant = builder.end();
+/// assert_eq!(variant.n_children(), 3);
+/// ```
+#[derive(Debug)]
+#[doc(alias = "GVariantBuilder")]
+pub struct VariantBuilder(ptr::NonNull<ffi::GVariantBuilder>);
+
+unsafe impl Send for VariantBuilder {}
+
+impl VariantBuilder {
+    /// Creates a builder for a container of type `type_`, e.g. `as` or `(su)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `type_` is not a container type.
+    #[doc(alias = "g_variant_builder_new")]
+    pub fn new(type_: &VariantTy) -> Self {
+        unsafe {
+            assert!(
+                ffi::g_variant_type_is_container(type_.as_ptr()) != ffi::GFALSE,
+                "{} is not a container type",
+                type_
+            );
+            VariantBuilder(ptr::NonNull::new_unchecked(ffi::g_variant_builder_new(
+                type_.as_ptr(),
+            )))
+        }
+    }
+
+    /// Adds `value` to the currently open container.
+    #[doc(alias = "g_variant_builder_add_value")]
+    pub fn add_value(&mut self, value: &Variant) {
+        unsafe {
+            ffi::g_variant_builder_add_value(self.0.as_ptr(), value.to_glib_none().0);
+        }
+    }
+
+    /// Converts `value` to a variant and adds it to the currently open container.
+    pub fn add<T: ToVariant + ?Sized>(&mut self, value: &T) {
+        self.add_value(&value.to_variant());
+    }
+
+    /// Opens a child container of type `type_`, to which all following values are added until
+    /// it is closed again with [`close`](Self::close).
+    #[doc(alias = "g_variant_builder_open")]
+    pub fn open(&mut self, type_: &VariantTy) {
+        unsafe {
+            ffi::g_variant_builder_open(self.0.as_ptr(), type_.as_ptr());
+        }
+    }
+
+    /// Closes the child container opened last with [`open`](Self::open).
+    #[doc(alias = "g_variant_builder_close")]
+    pub fn close(&mut self) {
+        unsafe {
+            ffi::g_variant_builder_close(self.0.as_ptr());
+        }
+    }
+
+    /// Returns the constructed variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a child container is still open, or if the values don't make up a complete
+    /// value of the container type, e.g. a tuple with children missing.
+    #[doc(alias = "g_variant_builder_end")]
+    pub fn end(self) -> Variant {
+        unsafe {
+            let ptr = ffi::g_variant_builder_end(self.0.as_ptr());
+            assert!(!ptr.is_null(), "Incomplete variant builder");
+            from_glib_none(ptr)
+        }
+    }
+}
+
+impl Drop for VariantBuilder {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::g_variant_builder_unref(self.0.as_ptr());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn array() {
+        let mut builder = VariantBuilder::new(VariantTy::new("a(su)").unwrap());
+        builder.add(&("foo", 1u32));
+        builder.add_value(&("bar", 2u32).to_variant());
+        let variant = builder.end();
+
+        assert_eq!(variant.type_().to_str(), "a(su)");
+        assert_eq!(
+            variant.get::<Vec<(String, u32)>>(),
+            Some(vec![("foo".into(), 1), ("bar".into(), 2)])
+        );
+    }
+
+    #[test]
+    fn empty_array() {
+        let builder = VariantBuilder::new(VariantTy::new("as").unwrap());
+        assert_eq!(builder.end().get::<Vec<String>>(), Some(Vec::new()));
+    }
+
+    #[test]
+    fn nested() {
+        let mut builder = VariantBuilder::new(VariantTy::new("(sa{su})").unwrap());
+        builder.add("map");
+        builder.open(VariantTy::new("a{su}").unwrap());
+        builder.open(VariantTy::new("{su}").unwrap());
+        builder.add("foo");
+        builder.add(&1u32);
+        builder.close();
+        builder.close();
+        let variant = builder.end();
+
+        assert_eq!(variant.get_child_value(0).get_str(), Some("map"));
+        let map = variant
+            .get_child_value(1)
+            .get::<HashMap<String, u32>>()
+            .unwrap();
+        assert_eq!(map.get("foo"), Some(&1));
+    }
+}
@@ -75,6 +75,19 @@ impl VariantDict {
         }
     }
 
+    /// Look up a value from this `VariantDict` and convert it to `T`.
+    ///
+    /// This is a convenience method which automatically checks the type of the
+    /// value and calls [`get()`](variant/struct.Variant.html#method.get) for you.
+    ///
+    /// This will return `None` if the `key` is not present in the dictionary
+    /// or if the type of its value is not the variant type of `T`.  Use
+    /// [`contains()`](#method.contains) to tell both cases apart.
+    pub fn lookup<T: FromVariant>(&self, key: &str) -> Option<T> {
+        self.lookup_value(key, Some(&*T::static_variant_type()))
+            .and_then(|value| value.get())
+    }
+
     /// Insert a variant into the dictionary.
     ///
     /// The given `key`/`value` pair is inserted into `self`.  If a value
@@ -233,6 +246,22 @@ mod test {
         assert_eq!(dict.lookup_value("one", None), Some(1u8.to_variant()));
     }
 
+    #[test]
+    fn create_populate_lookup() {
+        let dict = VariantDict::default();
+        dict.insert("one", &1u8);
+        dict.insert("name", &"foo");
+        dict.insert("list", &vec![String::from("a"), String::from("b")]);
+        assert_eq!(dict.lookup::<u8>("one"), Some(1));
+        assert_eq!(dict.lookup::<String>("name"), Some(String::from("foo")));
+        assert_eq!(
+            dict.lookup::<Vec<String>>("list"),
+            Some(vec![String::from("a"), String::from("b")])
+        );
+        assert_eq!(dict.lookup::<u32>("one"), None);
+        assert_eq!(dict.lookup::<u8>("two"), None);
+    }
+
     #[test]
     fn create_populate_remove() {
         let dict = VariantDict::default();
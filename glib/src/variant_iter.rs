@@ -5,8 +5,9 @@
 // many items are left in the iterator.
 
 use std::iter::{DoubleEndedIterator, ExactSizeIterator, Iterator};
+use std::marker::PhantomData;
 
-use crate::variant::Variant;
+use crate::variant::{FromVariant, Variant};
 
 /// Iterator over items in a variant.
 #[derive(Debug)]
@@ -59,6 +60,51 @@ impl DoubleEndedIterator for VariantIter {
 
 impl ExactSizeIterator for VariantIter {}
 
+/// Iterator over the elements of an array variant, converted to `T`.
+///
+/// Created with [`Variant::iter_as`].
+#[derive(Debug)]
+pub struct VariantTypedIter<T> {
+    inner: VariantIter,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: FromVariant> VariantTypedIter<T> {
+    // The caller has to ensure that `variant` is an array of `T`
+    pub(crate) fn new(variant: Variant) -> Self {
+        Self {
+            inner: VariantIter::new(variant),
+            phantom: PhantomData,
+        }
+    }
+
+    fn convert(value: Variant) -> T {
+        value
+            .get()
+            .expect("Array element doesn't have the element type of the array")
+    }
+}
+
+impl<T: FromVariant> Iterator for VariantTypedIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next().map(Self::convert)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: FromVariant> DoubleEndedIterator for VariantTypedIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back().map(Self::convert)
+    }
+}
+
+impl<T: FromVariant> ExactSizeIterator for VariantTypedIter<T> {}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -107,4 +153,17 @@ mod tests {
         let v = map.to_variant();
         assert_eq!(v.iter().count(), 2);
     }
+
+    #[test]
+    fn test_variant_typed_iter() {
+        let v = vec![("foo".to_string(), 1u32), ("bar".to_string(), 2u32)].to_variant();
+        let mut iter = v.iter_as::<(String, u32)>().unwrap();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(("bar".to_string(), 2)));
+        assert_eq!(iter.next(), Some(("foo".to_string(), 1)));
+        assert_eq!(iter.next(), None);
+
+        assert!(v.iter_as::<(String, i32)>().is_none());
+        assert!(1u32.to_variant().iter_as::<u32>().is_none());
+    }
 }
@@ -0,0 +1,1104 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Conversion of any type implementing [`serde`]'s `Serialize` and `Deserialize` to and from
+//! [`Variant`]s.
+//!
+//! This allows storing such types in `GSettings`, sending them over D-Bus or using them as
+//! the state of a `GAction` without writing [`ToVariant`] and
+//! [`FromVariant`](crate::FromVariant) implementations.
+//!
+//! ```
+//! use glib::{variant_serde, VariantTy};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Window {
+//!     title: String,
+//!     size: (i32, i32),
+//!     maximized: bool,
+//! }
+//!
+//! let window = Window {
+//!     title: "Files".into(),
+//!     size: (800, 600),
+//!     maximized: false,
+//! };
+//!
+//! let variant = variant_serde::to_variant(&window).unwrap();
+//! assert_eq!(variant.type_().to_str(), "(s(ii)b)");
+//! assert_eq!(variant_serde::from_variant::<Window>(&variant).unwrap(), window);
+//!
+//! // Structs can also be stored as dictionaries, e.g. if a GSettings key has this type
+//! let type_ = VariantTy::new("a{sv}").unwrap();
+//! let variant = variant_serde::to_variant_with_type(&window, type_).unwrap();
+//! assert_eq!(variant.type_(), type_);
+//! assert_eq!(variant_serde::from_variant::<Window>(&variant).unwrap(), window);
+//! ```
+//!
+//! # Data model
+//!
+//! | serde | `Variant` |
+//! |-------|-----------|
+//! | `bool` | `b` |
+//! | `i8`, `i16`, `i32`, `i64` | `n`, `n`, `i`, `x` |
+//! | `u8`, `u16`, `u32`, `u64` | `y`, `q`, `u`, `t` |
+//! | `f32`, `f64` | `d` |
+//! | `char`, string | `s` |
+//! | bytes | `ay` |
+//! | option | maybe |
+//! | unit, unit struct | `()` |
+//! | newtype struct | the type of the wrapped value |
+//! | sequence | array |
+//! | tuple, tuple struct, struct | tuple |
+//! | map | array of dictionary entries |
+//! | unit variant | `s` with the name of the variant |
+//! | other enum variants | `(sv)` with the name of the variant and a tuple of its fields |
+//!
+//! Enums are represented like by the [`GVariant`](crate::GVariant) derive macro.
+//!
+//! [`to_variant`] has to infer the types from the values, which isn't possible in all cases:
+//! arrays and dictionaries whose values have different types, or no values at all, contain
+//! their values boxed in variants (`av` and `a{sv}`), and `None` becomes a `mv`.
+//! [`to_variant_with_type`] instead serializes to a known type such as the type of a GSettings
+//! key or D-Bus argument. It converts numbers to the expected type, allows serializing structs
+//! to `a{sv}` or other dictionaries with string keys, strings to object paths (`o`) and
+//! signatures (`g`), and boxes values wherever a variant (`v`) is expected.
+//!
+//! Deserializing accepts all these representations. The contents of variants are deserialized
+//! as if they weren't boxed.
+
+use crate::translate::*;
+use crate::{ToVariant, Variant, VariantTy, VariantTyIterator, VariantType};
+use serde::de::value::StringDeserializer;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::fmt;
+use std::ptr;
+
+/// The error returned when a value can't be converted to or from a [`Variant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+/// Serializes `value` to a [`Variant`] whose type is inferred from the value.
+pub fn to_variant<T: Serialize + ?Sized>(value: &T) -> Result<Variant, Error> {
+    value.serialize(Serializer::new(None))
+}
+
+/// Serializes `value` to a [`Variant`] of type `type_`.
+///
+/// Fails if the value doesn't fit the type, e.g. if a number is out of range.
+pub fn to_variant_with_type<T: Serialize + ?Sized>(
+    value: &T,
+    type_: &VariantTy,
+) -> Result<Variant, Error> {
+    let variant = value.serialize(Serializer::new(Some(type_)))?;
+    // Indefinite types are not passed on by the serializer, so they have to be checked here
+    let matches: bool = unsafe {
+        from_glib(ffi::g_variant_is_of_type(
+            variant.to_glib_none().0,
+            type_.as_ptr(),
+        ))
+    };
+    if matches {
+        Ok(variant)
+    } else {
+        Err(type_mismatch(type_, variant.type_()))
+    }
+}
+
+/// Deserializes a `T` from `variant`.
+pub fn from_variant<T: DeserializeOwned>(variant: &Variant) -> Result<T, Error> {
+    T::deserialize(Deserializer::new(variant.clone()))
+}
+
+fn type_mismatch(expected: &VariantTy, actual: &VariantTy) -> Error {
+    Error(format!(
+        "Expected a variant of type {}, got {}",
+        expected, actual
+    ))
+}
+
+fn new_array(element: &VariantTy, children: &[Variant]) -> Variant {
+    unsafe {
+        from_glib_none(ffi::g_variant_new_array(
+            element.as_ptr(),
+            children.to_glib_none().0,
+            children.len(),
+        ))
+    }
+}
+
+fn new_maybe(element: &VariantTy, child: Option<&Variant>) -> Variant {
+    unsafe {
+        from_glib_none(ffi::g_variant_new_maybe(
+            element.as_ptr(),
+            child.map_or(ptr::null_mut(), |child| child.to_glib_none().0),
+        ))
+    }
+}
+
+fn new_dict_entry(key: &Variant, value: &Variant) -> Variant {
+    unsafe {
+        from_glib_none(ffi::g_variant_new_dict_entry(
+            key.to_glib_none().0,
+            value.to_glib_none().0,
+        ))
+    }
+}
+
+fn new_tagged(tag: &str, payload: &Variant) -> Variant {
+    Variant::tuple(&[tag.to_variant(), Variant::variant(payload)])
+}
+
+// Returns the type shared by `children`, boxing them into variants if they have different
+// types
+fn common_type(children: &mut [Variant]) -> VariantType {
+    let first = match children.first() {
+        Some(child) => child.type_().to_owned(),
+        None => return VariantType::new("v").unwrap(),
+    };
+    if children.iter().all(|child| child.type_() == &*first) {
+        first
+    } else {
+        for child in children.iter_mut() {
+            *child = Variant::variant(child);
+        }
+        VariantType::new("v").unwrap()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Serializer<'t> {
+    expected: Option<&'t VariantTy>,
+}
+
+impl<'t> Serializer<'t> {
+    fn new(expected: Option<&'t VariantTy>) -> Self {
+        Self {
+            expected: expected.filter(|type_| type_.is_definite()),
+        }
+    }
+
+    // The type the value itself has to be serialized to, `None` if it is inferred
+    fn value_type(&self) -> Option<&'t VariantTy> {
+        self.expected.filter(|type_| !type_.is_variant())
+    }
+
+    // Boxes `variant` if a variant is expected, or checks that it has the expected type
+    fn finish(self, variant: Variant) -> Result<Variant, Error> {
+        match self.expected {
+            Some(type_) if type_.is_variant() => Ok(Variant::variant(&variant)),
+            Some(type_) if type_ != variant.type_() => Err(type_mismatch(type_, variant.type_())),
+            _ => Ok(variant),
+        }
+    }
+
+    fn integer(self, value: i128, natural: &str) -> Result<Variant, Error> {
+        let type_ = self.value_type().map_or(natural, VariantTy::to_str);
+        let variant = match type_ {
+            "y" => u8::try_from(value).ok().map(|v| v.to_variant()),
+            "n" => i16::try_from(value).ok().map(|v| v.to_variant()),
+            "q" => u16::try_from(value).ok().map(|v| v.to_variant()),
+            "i" => i32::try_from(value).ok().map(|v| v.to_variant()),
+            "u" => u32::try_from(value).ok().map(|v| v.to_variant()),
+            "x" => i64::try_from(value).ok().map(|v| v.to_variant()),
+            "t" => u64::try_from(value).ok().map(|v| v.to_variant()),
+            "d" => Some((value as f64).to_variant()),
+            _ => {
+                return Err(Error(format!(
+                    "Expected a variant of type {}, got an integer",
+                    type_
+                )))
+            }
+        };
+        let variant = variant
+            .ok_or_else(|| Error(format!("{} is out of range for type {}", value, type_)))?;
+        self.finish(variant)
+    }
+}
+
+impl<'t> ser::Serializer for Serializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer<'t>;
+    type SerializeTuple = TupleSerializer<'t>;
+    type SerializeTupleStruct = TupleSerializer<'t>;
+    type SerializeTupleVariant = TupleSerializer<'t>;
+    type SerializeMap = MapSerializer<'t>;
+    type SerializeStruct = StructSerializer<'t>;
+    type SerializeStructVariant = TupleSerializer<'t>;
+
+    fn serialize_bool(self, v: bool) -> Result<Variant, Error> {
+        self.finish(v.to_variant())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Variant, Error> {
+        self.integer(v.into(), "n")
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Variant, Error> {
+        self.integer(v.into(), "n")
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Variant, Error> {
+        self.integer(v.into(), "i")
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Variant, Error> {
+        self.integer(v.into(), "x")
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Variant, Error> {
+        self.integer(v.into(), "y")
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Variant, Error> {
+        self.integer(v.into(), "q")
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Variant, Error> {
+        self.integer(v.into(), "u")
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Variant, Error> {
+        self.integer(v.into(), "t")
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Variant, Error> {
+        self.finish(f64::from(v).to_variant())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Variant, Error> {
+        self.finish(v.to_variant())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Variant, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Variant, Error> {
+        let type_ = self.value_type().map(VariantTy::to_str);
+        if type_ != Some("o") && type_ != Some("g") {
+            return self.finish(v.to_variant());
+        }
+
+        let string = CString::new(v).map_err(|_| Error(format!("{:?} contains NUL", v)))?;
+        unsafe {
+            if type_ == Some("o") {
+                if ffi::g_variant_is_object_path(string.as_ptr()) == ffi::GFALSE {
+                    return Err(Error(format!("{:?} is not a valid object path", v)));
+                }
+                Ok(from_glib_none(ffi::g_variant_new_object_path(
+                    string.as_ptr(),
+                )))
+            } else {
+                if ffi::g_variant_is_signature(string.as_ptr()) == ffi::GFALSE {
+                    return Err(Error(format!("{:?} is not a valid signature", v)));
+                }
+                Ok(from_glib_none(ffi::g_variant_new_signature(
+                    string.as_ptr(),
+                )))
+            }
+        }
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Variant, Error> {
+        let variant: Variant = unsafe {
+            from_glib_none(ffi::g_variant_new_fixed_array(
+                b"y\0".as_ptr() as *const _,
+                v.as_ptr() as ffi::gconstpointer,
+                v.len(),
+                1,
+            ))
+        };
+        self.finish(variant)
+    }
+
+    fn serialize_none(self) -> Result<Variant, Error> {
+        match self.value_type() {
+            Some(type_) if type_.is_maybe() => Ok(new_maybe(type_.element(), None)),
+            Some(type_) => Err(Error(format!(
+                "Expected a variant of type {}, got None",
+                type_
+            ))),
+            None => self.finish(new_maybe(VariantTy::new("v").unwrap(), None)),
+        }
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Variant, Error> {
+        match self.value_type() {
+            Some(type_) if type_.is_maybe() => {
+                let element = type_.element();
+                let child = value.serialize(Serializer::new(Some(element)))?;
+                Ok(new_maybe(element, Some(&child)))
+            }
+            Some(type_) => Err(Error(format!(
+                "Expected a variant of type {}, got Some",
+                type_
+            ))),
+            None => {
+                let child = value.serialize(Serializer::new(None))?;
+                self.finish(new_maybe(child.type_(), Some(&child)))
+            }
+        }
+    }
+
+    fn serialize_unit(self) -> Result<Variant, Error> {
+        self.finish(Variant::tuple(&[]))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Variant, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Variant, Error> {
+        if self.value_type().map(VariantTy::to_str) == Some("(sv)") {
+            self.finish(new_tagged(variant, &Variant::tuple(&[])))
+        } else {
+            self.finish(variant.to_variant())
+        }
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Variant, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Variant, Error> {
+        let payload = Variant::tuple(&[to_variant(value)?]);
+        self.finish(new_tagged(variant, &payload))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer<'t>, Error> {
+        let element = match self.value_type() {
+            Some(type_) if type_.is_array() => Some(type_.element()),
+            Some(type_) => {
+                return Err(Error(format!(
+                    "Expected a variant of type {}, got a sequence",
+                    type_
+                )))
+            }
+            None => None,
+        };
+        Ok(SeqSerializer {
+            outer: self,
+            element,
+            children: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<TupleSerializer<'t>, Error> {
+        TupleSerializer::new(self, len, None)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<TupleSerializer<'t>, Error> {
+        TupleSerializer::new(self, len, None)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<TupleSerializer<'t>, Error> {
+        TupleSerializer::new(self, len, Some(variant))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer<'t>, Error> {
+        MapSerializer::new(self, len.unwrap_or(0))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<StructSerializer<'t>, Error> {
+        let is_dict = self.value_type().map_or(false, |type_| {
+            type_.is_array()
+                && type_.element().is_dict_entry()
+                && type_.element().first().map(VariantTy::to_str) == Some("s")
+        });
+        if is_dict {
+            MapSerializer::new(self, len).map(StructSerializer::Dict)
+        } else {
+            TupleSerializer::new(self, len, None).map(StructSerializer::Tuple)
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<TupleSerializer<'t>, Error> {
+        TupleSerializer::new(self, len, Some(variant))
+    }
+}
+
+struct SeqSerializer<'t> {
+    outer: Serializer<'t>,
+    element: Option<&'t VariantTy>,
+    children: Vec<Variant>,
+}
+
+impl<'t> ser::SerializeSeq for SeqSerializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.children
+            .push(value.serialize(Serializer::new(self.element))?);
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<Variant, Error> {
+        let variant = match self.element {
+            Some(element) => new_array(element, &self.children),
+            None => new_array(&common_type(&mut self.children), &self.children),
+        };
+        self.outer.finish(variant)
+    }
+}
+
+struct TupleSerializer<'t> {
+    outer: Serializer<'t>,
+    // The expected type of the next item, if the type of the tuple is known
+    items: Option<VariantTyIterator<'t>>,
+    // The name of the enum variant the tuple is the payload of
+    tag: Option<&'static str>,
+    children: Vec<Variant>,
+}
+
+impl<'t> TupleSerializer<'t> {
+    fn new(outer: Serializer<'t>, len: usize, tag: Option<&'static str>) -> Result<Self, Error> {
+        let items = match outer.value_type() {
+            // The payload of enum variants is boxed in the tagged tuple
+            _ if tag.is_some() => None,
+            Some(type_) if type_.is_tuple() => Some(type_.tuple_types()),
+            Some(type_) => {
+                return Err(Error(format!(
+                    "Expected a variant of type {}, got a tuple",
+                    type_
+                )))
+            }
+            None => None,
+        };
+        Ok(Self {
+            outer,
+            items,
+            tag,
+            children: Vec::with_capacity(len),
+        })
+    }
+
+    fn add<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let child = match self.items.as_mut().map(Iterator::next) {
+            Some(Some(type_)) => value.serialize(Serializer::new(Some(type_)))?,
+            Some(None) => {
+                return Err(Error(format!(
+                    "Too many items for type {}",
+                    self.outer.value_type().unwrap()
+                )))
+            }
+            None => value.serialize(Serializer::new(None))?,
+        };
+        self.children.push(child);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Variant, Error> {
+        if let Some(Some(_)) = self.items.map(|mut items| items.next()) {
+            return Err(Error(format!(
+                "Too few items for type {}",
+                self.outer.value_type().unwrap()
+            )));
+        }
+        let tuple = Variant::tuple(&self.children);
+        match self.tag {
+            Some(tag) => self.outer.finish(new_tagged(tag, &tuple)),
+            None => self.outer.finish(tuple),
+        }
+    }
+}
+
+impl<'t> ser::SerializeTuple for TupleSerializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.add(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl<'t> ser::SerializeTupleStruct for TupleSerializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.add(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl<'t> ser::SerializeTupleVariant for TupleSerializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.add(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl<'t> ser::SerializeStructVariant for TupleSerializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.add(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+struct MapSerializer<'t> {
+    outer: Serializer<'t>,
+    // The expected key and value types, if the type of the dictionary is known
+    entry: Option<(&'t VariantTy, &'t VariantTy)>,
+    keys: Vec<Variant>,
+    values: Vec<Variant>,
+}
+
+impl<'t> MapSerializer<'t> {
+    fn new(outer: Serializer<'t>, len: usize) -> Result<Self, Error> {
+        let entry = match outer.value_type() {
+            Some(type_) if type_.is_array() && type_.element().is_dict_entry() => {
+                let mut entry = type_.element().tuple_types();
+                Some((entry.next().unwrap(), entry.next().unwrap()))
+            }
+            Some(type_) => {
+                return Err(Error(format!(
+                    "Expected a variant of type {}, got a map",
+                    type_
+                )))
+            }
+            None => None,
+        };
+        Ok(Self {
+            outer,
+            entry,
+            keys: Vec::with_capacity(len),
+            values: Vec::with_capacity(len),
+        })
+    }
+}
+
+impl<'t> ser::SerializeMap for MapSerializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = key.serialize(Serializer::new(self.entry.map(|(key, _)| key)))?;
+        if !key.type_().is_basic() {
+            return Err(Error(format!(
+                "Dictionary keys must have a basic type, got {}",
+                key.type_()
+            )));
+        }
+        self.keys.push(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.values
+            .push(value.serialize(Serializer::new(self.entry.map(|(_, value)| value)))?);
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<Variant, Error> {
+        let (key_type, value_type) = match self.entry {
+            Some((key, value)) => (key.to_owned(), value.to_owned()),
+            None => {
+                let key_type = match self.keys.first() {
+                    Some(key) => key.type_().to_owned(),
+                    None => VariantType::new("s").unwrap(),
+                };
+                if self.keys.iter().any(|key| key.type_() != &*key_type) {
+                    return Err(Error("Dictionary keys have different types".into()));
+                }
+                (key_type, common_type(&mut self.values))
+            }
+        };
+
+        let entry_type = VariantType::new(&format!("{{{}{}}}", key_type, value_type)).unwrap();
+        let entries = self
+            .keys
+            .iter()
+            .zip(self.values.iter())
+            .map(|(key, value)| new_dict_entry(key, value))
+            .collect::<Vec<_>>();
+        self.outer.finish(new_array(&entry_type, &entries))
+    }
+}
+
+enum StructSerializer<'t> {
+    Tuple(TupleSerializer<'t>),
+    Dict(MapSerializer<'t>),
+}
+
+impl<'t> ser::SerializeStruct for StructSerializer<'t> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        match self {
+            StructSerializer::Tuple(tuple) => tuple.add(value),
+            StructSerializer::Dict(dict) => {
+                ser::SerializeMap::serialize_key(dict, key)?;
+                ser::SerializeMap::serialize_value(dict, value)
+            }
+        }
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        match self {
+            StructSerializer::Tuple(tuple) => tuple.finish(),
+            StructSerializer::Dict(dict) => ser::SerializeMap::end(dict),
+        }
+    }
+}
+
+struct Deserializer {
+    variant: Variant,
+}
+
+impl Deserializer {
+    fn new(mut variant: Variant) -> Self {
+        // Boxed values are deserialized like the values themselves
+        while variant.type_().is_variant() {
+            variant = variant.get_variant().unwrap();
+        }
+        Self { variant }
+    }
+
+    fn invalid(&self, expected: &str) -> Error {
+        Error(format!(
+            "Expected {}, got a variant of type {}",
+            expected,
+            self.variant.type_()
+        ))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let variant = &self.variant;
+        let type_ = variant.type_();
+        match type_.to_str().as_bytes()[0] {
+            b'b' => visitor.visit_bool(variant.get().unwrap()),
+            b'y' => visitor.visit_u8(variant.get().unwrap()),
+            b'n' => visitor.visit_i16(variant.get().unwrap()),
+            b'q' => visitor.visit_u16(variant.get().unwrap()),
+            b'i' => visitor.visit_i32(variant.get().unwrap()),
+            b'u' => visitor.visit_u32(variant.get().unwrap()),
+            b'x' => visitor.visit_i64(variant.get().unwrap()),
+            b't' => visitor.visit_u64(variant.get().unwrap()),
+            b'd' => visitor.visit_f64(variant.get().unwrap()),
+            b's' | b'o' | b'g' => visitor.visit_str(variant.get_str().unwrap()),
+            b'm' if variant.n_children() == 0 => visitor.visit_none(),
+            b'm' => visitor.visit_some(Deserializer::new(variant.get_child_value(0))),
+            b'a' if type_.element().is_dict_entry() => visitor.visit_map(MapAccess::new(variant)),
+            b'a' | b'(' | b'{' => visitor.visit_seq(SeqAccess::new(variant)),
+            _ => Err(self.invalid("a value supported by serde")),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.variant.type_().is_maybe() {
+            self.deserialize_any(visitor)
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.variant.type_().to_str() == "()" {
+            visitor.visit_unit()
+        } else {
+            Err(self.invalid("a unit"))
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.variant.type_().to_str() == "ay" {
+            visitor.visit_byte_buf(self.variant.get().unwrap())
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.variant.type_().to_str() {
+            "s" => {
+                let tag: StringDeserializer<Error> = self
+                    .variant
+                    .get_str()
+                    .unwrap()
+                    .to_owned()
+                    .into_deserializer();
+                visitor.visit_enum(tag)
+            }
+            "(sv)" => visitor.visit_enum(EnumAccess {
+                tag: self
+                    .variant
+                    .get_child_value(0)
+                    .get_str()
+                    .unwrap()
+                    .to_owned(),
+                payload: Deserializer::new(self.variant.get_child_value(1)),
+            }),
+            _ => Err(self.invalid("an enum")),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string seq tuple tuple_struct map
+        struct identifier ignored_any
+    }
+}
+
+struct SeqAccess<'v> {
+    variant: &'v Variant,
+    index: usize,
+    len: usize,
+}
+
+impl<'v> SeqAccess<'v> {
+    fn new(variant: &'v Variant) -> Self {
+        Self {
+            variant,
+            index: 0,
+            len: variant.n_children(),
+        }
+    }
+}
+
+impl<'de, 'v> de::SeqAccess<'de> for SeqAccess<'v> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        let child = self.variant.get_child_value(self.index);
+        self.index += 1;
+        seed.deserialize(Deserializer::new(child)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+struct MapAccess<'v> {
+    entries: SeqAccess<'v>,
+}
+
+impl<'v> MapAccess<'v> {
+    fn new(variant: &'v Variant) -> Self {
+        Self {
+            entries: SeqAccess::new(variant),
+        }
+    }
+
+    fn entry_item(&self, item: usize) -> Deserializer {
+        let entry = self.entries.variant.get_child_value(self.entries.index);
+        Deserializer::new(entry.get_child_value(item))
+    }
+}
+
+impl<'de, 'v> de::MapAccess<'de> for MapAccess<'v> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if self.entries.index == self.entries.len {
+            return Ok(None);
+        }
+        seed.deserialize(self.entry_item(0)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = seed.deserialize(self.entry_item(1));
+        self.entries.index += 1;
+        value
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        de::SeqAccess::size_hint(&self.entries)
+    }
+}
+
+struct EnumAccess {
+    tag: String,
+    payload: Deserializer,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess {
+    type Error = Error;
+    type Variant = Deserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Deserializer), Error> {
+        let tag: StringDeserializer<Error> = self.tag.into_deserializer();
+        let tag = seed.deserialize(tag)?;
+        Ok((tag, self.payload))
+    }
+}
+
+// The payload of a `(sv)` enum variant
+impl<'de> de::VariantAccess<'de> for Deserializer {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        let type_ = self.variant.type_();
+        if type_.is_tuple() && type_.tuple_types().count() == 1 {
+            seed.deserialize(Deserializer::new(self.variant.get_child_value(0)))
+        } else {
+            seed.deserialize(self)
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Drawing {
+        name: String,
+        shapes: Vec<Shape>,
+        layers: HashMap<String, u32>,
+        parent: Option<String>,
+    }
+
+    fn drawing() -> Drawing {
+        let mut layers = HashMap::new();
+        layers.insert("background".to_string(), 0);
+        Drawing {
+            name: "sketch".into(),
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(1.5),
+                Shape::Rect {
+                    width: 3,
+                    height: 4,
+                },
+            ],
+            layers,
+            parent: None,
+        }
+    }
+
+    #[test]
+    fn inferred() {
+        let variant = to_variant(&drawing()).unwrap();
+        // The unit variant has a different type than the others, so the shapes are boxed
+        assert_eq!(variant.type_().to_str(), "(sava{su}mv)");
+        assert_eq!(from_variant::<Drawing>(&variant).unwrap(), drawing());
+    }
+
+    #[test]
+    fn with_type() {
+        let type_ = VariantTy::new("(sa(sv)a{sq}ms)").unwrap();
+        let variant = to_variant_with_type(&drawing(), type_).unwrap();
+        assert_eq!(variant.type_(), type_);
+        assert_eq!(from_variant::<Drawing>(&variant).unwrap(), drawing());
+
+        let type_ = VariantTy::new("a{sv}").unwrap();
+        let variant = to_variant_with_type(&drawing(), type_).unwrap();
+        assert_eq!(variant.type_(), type_);
+        let dict = crate::VariantDict::new(Some(&variant));
+        assert_eq!(dict.lookup::<String>("name"), Some("sketch".into()));
+        assert_eq!(from_variant::<Drawing>(&variant).unwrap(), drawing());
+
+        assert!(to_variant_with_type(&drawing(), VariantTy::new("(sas)").unwrap()).is_err());
+    }
+
+    #[test]
+    fn numbers() {
+        let variant = to_variant_with_type(&5u8, VariantTy::new("x").unwrap()).unwrap();
+        assert_eq!(variant.get::<i64>(), Some(5));
+        assert_eq!(from_variant::<u8>(&variant), Ok(5));
+        assert!(from_variant::<i8>(&(300i32).to_variant()).is_err());
+        assert!(to_variant_with_type(&300u32, VariantTy::new("y").unwrap()).is_err());
+        assert!(to_variant_with_type(&-1i32, VariantTy::new("t").unwrap()).is_err());
+    }
+
+    #[test]
+    fn strings() {
+        let type_ = VariantTy::new("o").unwrap();
+        let variant = to_variant_with_type("/org/gtk/Demo", type_).unwrap();
+        assert_eq!(variant.type_(), type_);
+        assert_eq!(from_variant::<String>(&variant).unwrap(), "/org/gtk/Demo");
+        assert!(to_variant_with_type("org.gtk.Demo", type_).is_err());
+
+        let variant = to_variant(&'x').unwrap();
+        assert_eq!(variant.get_str(), Some("x"));
+        assert_eq!(from_variant::<char>(&variant), Ok('x'));
+    }
+
+    #[test]
+    fn bytes() {
+        struct Bytes<'a>(&'a [u8]);
+
+        impl<'a> Serialize for Bytes<'a> {
+            fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(self.0)
+            }
+        }
+
+        let variant = to_variant(&Bytes(&[1, 2, 3])).unwrap();
+        assert_eq!(variant.get::<Vec<u8>>(), Some(vec![1, 2, 3]));
+        assert_eq!(from_variant::<Vec<u8>>(&variant), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn boxed() {
+        let variant = to_variant_with_type(&(1u32, "a"), VariantTy::new("(vv)").unwrap()).unwrap();
+        assert_eq!(
+            variant
+                .get_child_value(0)
+                .get_variant()
+                .unwrap()
+                .get::<u32>(),
+            Some(1)
+        );
+        assert_eq!(from_variant::<(u32, String)>(&variant), Ok((1, "a".into())));
+        assert_eq!(
+            from_variant::<Option<u32>>(&Variant::variant(&7u32.to_variant())),
+            Ok(Some(7))
+        );
+    }
+}
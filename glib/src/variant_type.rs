@@ -180,10 +180,118 @@ impl VariantTy {
     pub fn to_str(&self) -> &str {
         &self.inner
     }
+
+    /// Returns `true` if the type doesn't contain indefinite types such as `*`, `?` or `r`.
+    pub fn is_definite(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_definite(self.as_ptr())) }
+    }
+
+    /// Returns `true` if the type is an array, maybe, tuple, dictionary entry or variant type.
+    pub fn is_container(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_container(self.as_ptr())) }
+    }
+
+    /// Returns `true` if the type is a basic type, i.e. a number, string or boolean type, that
+    /// can be used as the key of a dictionary entry.
+    pub fn is_basic(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_basic(self.as_ptr())) }
+    }
+
+    /// Returns `true` if the type is a maybe type, e.g. `ms`.
+    pub fn is_maybe(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_maybe(self.as_ptr())) }
+    }
+
+    /// Returns `true` if the type is an array type, e.g. `as`.
+    pub fn is_array(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_array(self.as_ptr())) }
+    }
+
+    /// Returns `true` if the type is a tuple type, e.g. `(si)`.
+    pub fn is_tuple(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_tuple(self.as_ptr())) }
+    }
+
+    /// Returns `true` if the type is a dictionary entry type, e.g. `{sv}`.
+    pub fn is_dict_entry(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_dict_entry(self.as_ptr())) }
+    }
+
+    /// Returns `true` if the type is the variant type `v`.
+    pub fn is_variant(&self) -> bool {
+        unsafe { from_glib(ffi::g_variant_type_is_variant(self.as_ptr())) }
+    }
+
+    /// Returns the element type of an array or maybe type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is neither an array nor a maybe type.
+    pub fn element(&self) -> &VariantTy {
+        assert!(self.is_array() || self.is_maybe());
+        unsafe { VariantTy::from_ptr(ffi::g_variant_type_element(self.as_ptr())) }
+    }
+
+    /// Returns the type of the first item of a tuple or dictionary entry type, or `None` for
+    /// the empty tuple.
+    ///
+    /// The types of all items are returned by [`tuple_types`](Self::tuple_types).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is neither a tuple nor a dictionary entry type.
+    pub fn first(&self) -> Option<&VariantTy> {
+        assert!(self.is_tuple() || self.is_dict_entry());
+        unsafe {
+            let ptr = ffi::g_variant_type_first(self.as_ptr());
+            if ptr.is_null() {
+                None
+            } else {
+                Some(VariantTy::from_ptr(ptr))
+            }
+        }
+    }
+
+    /// Returns an iterator over the types of the items of a tuple or dictionary entry type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is neither a tuple nor a dictionary entry type.
+    pub fn tuple_types(&self) -> VariantTyIterator {
+        VariantTyIterator { item: self.first() }
+    }
 }
 
 unsafe impl Sync for VariantTy {}
 
+/// Iterator over the item types of a tuple or dictionary entry type, created by
+/// [`VariantTy::tuple_types`].
+#[derive(Debug, Clone, Copy)]
+pub struct VariantTyIterator<'a> {
+    // Only ever the type of an item of the container, which `g_variant_type_next()` requires
+    item: Option<&'a VariantTy>,
+}
+
+impl<'a> Iterator for VariantTyIterator<'a> {
+    type Item = &'a VariantTy;
+
+    #[doc(alias = "g_variant_type_next")]
+    fn next(&mut self) -> Option<&'a VariantTy> {
+        let item = self.item?;
+        self.item = unsafe {
+            let ptr = ffi::g_variant_type_next(item.as_ptr());
+            if ptr.is_null() {
+                None
+            } else {
+                Some(VariantTy::from_ptr(ptr))
+            }
+        };
+        Some(item)
+    }
+}
+
+impl<'a> std::iter::FusedIterator for VariantTyIterator<'a> {}
+
 #[doc(hidden)]
 impl<'a> ToGlibPtr<'a, *const ffi::GVariantType> for VariantTy {
     type Storage = &'a Self;
@@ -368,6 +476,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn inspect() {
+        let ty = VariantTy::new("a{s(iv)}").unwrap();
+        assert!(ty.is_array() && ty.is_definite() && !ty.is_basic());
+        let entry = ty.element();
+        assert!(entry.is_dict_entry());
+        let mut entry_types = entry.tuple_types();
+        let key = entry_types.next().unwrap();
+        assert!(key.is_basic());
+        assert_eq!(key.to_str(), "s");
+        assert_eq!(entry.first(), Some(key));
+        let value = entry_types.next().unwrap();
+        assert!(value.is_tuple());
+        assert!(entry_types.next().is_none());
+        assert!(entry_types.next().is_none());
+
+        let items = value
+            .tuple_types()
+            .map(VariantTy::to_str)
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec!["i", "v"]);
+        assert!(VariantTy::new("()").unwrap().first().is_none());
+        assert_eq!(VariantTy::new("()").unwrap().tuple_types().count(), 0);
+        assert!(!VariantTy::new("m*").unwrap().is_definite());
+    }
+
     #[test]
     fn new_empty() {
         assert!(VariantTy::new("").is_err());
@@ -0,0 +1,219 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Runtime version detection.
+//!
+//! The `v2_xx` features of the bindings select the minimum library version at compile time and
+//! compile out everything newer. The functions in this module instead check the version of the
+//! library the application is actually running against, which allows a single binary to use
+//! newer API conditionally.
+
+use std::error;
+#[cfg(unix)]
+use std::ffi::CStr;
+use std::fmt;
+#[cfg(unix)]
+use std::mem;
+
+/// A `major.minor.micro` library version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub micro: u32,
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32, micro: u32) -> Self {
+        Self {
+            major,
+            minor,
+            micro,
+        }
+    }
+
+    /// Checks if this version is at least `major.minor.micro`.
+    pub fn is_at_least(&self, major: u32, minor: u32, micro: u32) -> bool {
+        *self >= Self::new(major, minor, micro)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.micro)
+    }
+}
+
+/// Error returned when an API is not available in the library version found at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionError {
+    required: Version,
+    found: Version,
+    symbol: Option<String>,
+}
+
+impl VersionError {
+    pub fn new(required: Version, found: Version) -> Self {
+        Self {
+            required,
+            found,
+            symbol: None,
+        }
+    }
+
+    /// The version that is required for the API.
+    pub fn required(&self) -> Version {
+        self.required
+    }
+
+    /// The version found at runtime.
+    pub fn found(&self) -> Version {
+        self.found
+    }
+
+    /// The symbol that was looked up, if any.
+    pub fn symbol(&self) -> Option<&str> {
+        self.symbol.as_deref()
+    }
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.symbol {
+            Some(ref symbol) if self.found >= self.required => write!(
+                f,
+                "symbol '{}' not found although version {} is available",
+                symbol, self.found
+            ),
+            Some(ref symbol) => write!(
+                f,
+                "symbol '{}' requires version {} but only {} is available",
+                symbol, self.required, self.found
+            ),
+            None => write!(
+                f,
+                "version {} required but only {} is available",
+                self.required, self.found
+            ),
+        }
+    }
+}
+
+impl error::Error for VersionError {}
+
+/// Returns the version of GLib the application is running against.
+///
+/// This can be newer than the version selected with the `v2_xx` features.
+pub fn runtime_version() -> Version {
+    unsafe {
+        Version::new(
+            ffi::glib_major_version,
+            ffi::glib_minor_version,
+            ffi::glib_micro_version,
+        )
+    }
+}
+
+/// Checks that the GLib found at runtime is at least `major.minor.micro`.
+pub fn check_runtime_version(major: u32, minor: u32, micro: u32) -> Result<(), VersionError> {
+    let found = runtime_version();
+    let required = Version::new(major, minor, micro);
+    if found >= required {
+        Ok(())
+    } else {
+        Err(VersionError::new(required, found))
+    }
+}
+
+/// Looks up the C function `name` in the libraries loaded into the process, provided that the
+/// `runtime` library version is at least `required`.
+///
+/// This allows calling functions that are newer than the version the bindings were compiled
+/// against. `runtime` is usually the return value of [`runtime_version`] or the equivalent of
+/// another library.
+///
+/// ```ignore
+/// type Func = unsafe extern "C" fn(*mut ffi::GUri) -> *mut c_char;
+///
+/// let to_string: Func = unsafe {
+///     glib::lookup_versioned_fn(
+///         CStr::from_bytes_with_nul(b"g_uri_to_string\0").unwrap(),
+///         glib::Version::new(2, 66, 0),
+///         glib::runtime_version(),
+///     )?
+/// };
+/// ```
+///
+/// # Safety
+///
+/// `F` must be an `extern "C"` function pointer type matching the signature of the C function.
+///
+/// # Panics
+///
+/// If `F` is not pointer-sized.
+#[cfg(unix)]
+pub unsafe fn lookup_versioned_fn<F: Copy>(
+    name: &CStr,
+    required: Version,
+    runtime: Version,
+) -> Result<F, VersionError> {
+    assert_eq!(mem::size_of::<F>(), mem::size_of::<*mut libc::c_void>());
+
+    let error = || VersionError {
+        required,
+        found: runtime,
+        symbol: Some(name.to_string_lossy().into_owned()),
+    };
+
+    if runtime < required {
+        return Err(error());
+    }
+
+    let ptr = libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr());
+    if ptr.is_null() {
+        Err(error())
+    } else {
+        Ok(mem::transmute_copy(&ptr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_version_is_at_least_compiled() {
+        let version = runtime_version();
+        assert!(version.is_at_least(2, 42, 0));
+        assert!(check_runtime_version(2, 42, 0).is_ok());
+
+        let err = check_runtime_version(version.major + 1, 0, 0).unwrap_err();
+        assert_eq!(err.found(), version);
+        assert_eq!(err.required(), Version::new(version.major + 1, 0, 0));
+    }
+
+    #[test]
+    fn version_ordering() {
+        assert!(Version::new(2, 66, 0) > Version::new(2, 64, 3));
+        assert!(Version::new(3, 0, 0) > Version::new(2, 99, 99));
+        assert_eq!(Version::new(2, 66, 1).to_string(), "2.66.1");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn lookup_fn() {
+        type Func = unsafe extern "C" fn() -> std::os::raw::c_int;
+
+        unsafe {
+            let name = CStr::from_bytes_with_nul(b"g_main_depth\0").unwrap();
+            assert!(
+                lookup_versioned_fn::<Func>(name, Version::new(2, 0, 0), runtime_version()).is_ok()
+            );
+
+            let name = CStr::from_bytes_with_nul(b"g_this_does_not_exist\0").unwrap();
+            assert!(
+                lookup_versioned_fn::<Func>(name, Version::new(2, 0, 0), runtime_version())
+                    .is_err()
+            );
+        }
+    }
+}
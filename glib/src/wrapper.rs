@@ -75,6 +75,42 @@
 /// `get_type`: `|| -> glib_ffi::GType` (optional) returns the
 /// `glib_ffi::GType` that corresponds to the foreign struct.
 ///
+/// Comparison, hashing and serialization can be implemented on top of
+/// the foreign functions in an optional `impl traits` block:
+///
+/// ```ignore
+/// wrapper! {
+///     /// Unix mount point
+///     pub struct UnixMountPoint(Boxed<ffi::GUnixMountPoint>);
+///
+///     match fn {
+///         copy => |ptr| ffi::g_unix_mount_point_copy(ptr),
+///         free => |ptr| ffi::g_unix_mount_point_free(ptr),
+///     }
+///
+///     impl traits {
+///         compare => |a, b| ffi::g_unix_mount_point_compare(a, b),
+///         hash    => |ptr|  ffi::g_unix_mount_point_hash(ptr),
+///         serde,
+///     }
+/// }
+/// ```
+///
+/// `compare`: `|*const $foreign, *const $foreign| -> integer` (optional)
+/// returns a negative value, zero or a positive value if the first
+/// value is less than, equal to or greater than the second, and
+/// implements `PartialEq`, `Eq`, `PartialOrd` and `Ord`.
+///
+/// `hash`: `|*const $foreign| -> impl Hash` (optional) returns a hash
+/// of the value and implements `Hash`, which has to be consistent with
+/// `compare`.
+///
+/// `serde` (optional) implements `serde::Serialize` and
+/// `serde::Deserialize` through the `Display` and `FromStr`
+/// implementations of the wrapper. The implementations are only
+/// compiled when glib is built with its `serde` feature, the crate
+/// calling the macro doesn't need to depend on serde.
+///
 /// ### Shared
 ///
 /// Records with reference-counted, shared ownership.
@@ -188,6 +224,33 @@
 /// }
 /// ```
 ///
+/// #### Class methods
+///
+/// Some libraries require calling functions on the class struct from the outside, e.g. for
+/// setting up class-wide state or poking virtual methods. Wrappers with a class struct can
+/// declare such methods in a `class trait` block after the `match fn` block. This creates a trait
+/// with the given name containing all the methods and implements it on `glib::Class<$name>`.
+///
+/// ```ignore
+/// wrapper! {
+///     pub struct Widget(Object<ffi::GtkWidget, ffi::GtkWidgetClass>)
+///         @extends glib::InitiallyUnowned;
+///
+///     match fn {
+///         get_type => || ffi::gtk_widget_get_type(),
+///     }
+///
+///     class trait WidgetClassManualExt {
+///         fn set_css_name(&mut self, name: &str) {
+///             unsafe { ffi::gtk_widget_class_set_css_name(self.as_mut(), name.to_glib_none().0) }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Class structs of any type can additionally store type-specific data with
+/// [`Class::set_type_data`](../object/struct.Class.html#method.set_type_data).
+///
 /// #### Non-derivable classes
 ///
 /// By convention, GObject implements "final" classes, i.e. those who
@@ -244,9 +307,16 @@ macro_rules! wrapper {
             copy => |$copy_arg:ident| $copy_expr:expr,
             free => |$free_arg:ident| $free_expr:expr,
         }
+
+        $(
+            impl traits {
+                $($impls:tt)*
+            }
+        )?
     ) => {
         $crate::glib_boxed_wrapper!([$($attr)*] $name, $ffi_name, @copy $copy_arg $copy_expr,
             @free $free_arg $free_expr);
+        $($crate::glib_boxed_wrapper!(@trait_impls $name, $ffi_name, $($impls)*);)?
     };
 
     (
@@ -258,9 +328,16 @@ macro_rules! wrapper {
             free => |$free_arg:ident| $free_expr:expr,
             get_type => || $get_type_expr:expr,
         }
+
+        $(
+            impl traits {
+                $($impls:tt)*
+            }
+        )?
     ) => {
         $crate::glib_boxed_wrapper!([$($attr)*] $name, $ffi_name, @copy $copy_arg $copy_expr,
             @free $free_arg $free_expr, @get_type $get_type_expr);
+        $($crate::glib_boxed_wrapper!(@trait_impls $name, $ffi_name, $($impls)*);)?
     };
 
     (
@@ -273,9 +350,16 @@ macro_rules! wrapper {
             init => |$init_arg:ident| $init_expr:expr,
             clear => |$clear_arg:ident| $clear_expr:expr,
         }
+
+        $(
+            impl traits {
+                $($impls:tt)*
+            }
+        )?
     ) => {
         $crate::glib_boxed_wrapper!([$($attr)*] $name, $ffi_name, @copy $copy_arg $copy_expr,
             @free $free_arg $free_expr, @init $init_arg $init_expr, @clear $clear_arg $clear_expr);
+        $($crate::glib_boxed_wrapper!(@trait_impls $name, $ffi_name, $($impls)*);)?
     };
 
     (
@@ -289,10 +373,17 @@ macro_rules! wrapper {
             clear => |$clear_arg:ident| $clear_expr:expr,
             get_type => || $get_type_expr:expr,
         }
+
+        $(
+            impl traits {
+                $($impls:tt)*
+            }
+        )?
     ) => {
         $crate::glib_boxed_wrapper!([$($attr)*] $name, $ffi_name, @copy $copy_arg $copy_expr,
             @free $free_arg $free_expr, @init $init_arg $init_expr, @clear $clear_arg $clear_expr,
             @get_type $get_type_expr);
+        $($crate::glib_boxed_wrapper!(@trait_impls $name, $ffi_name, $($impls)*);)?
     };
 
     // Shared
@@ -344,8 +435,15 @@ macro_rules! wrapper {
         match fn {
             get_type => || $get_type_expr:expr,
         }
+
+        $(
+            class trait $class_trait:ident {
+                $($class_methods:tt)*
+            }
+        )?
     ) => {
         $crate::glib_object_wrapper!(@object [$($attr)*] $name, $ffi_name, $ffi_class_name, @get_type $get_type_expr, @extends [], @implements []);
+        $($crate::glib_object_wrapper!(@class_trait $name, $class_trait, $($class_methods)*);)?
     };
 
     // Object, no class struct, parents, no interfaces
@@ -369,9 +467,16 @@ macro_rules! wrapper {
         match fn {
             get_type => || $get_type_expr:expr,
         }
+
+        $(
+            class trait $class_trait:ident {
+                $($class_methods:tt)*
+            }
+        )?
     ) => {
         $crate::glib_object_wrapper!(@object [$($attr)*] $name, $ffi_name, $ffi_class_name,
             @get_type $get_type_expr, @extends [$($extends),+], @implements []);
+        $($crate::glib_object_wrapper!(@class_trait $name, $class_trait, $($class_methods)*);)?
     };
 
     // Object, no class struct, no parents, interfaces
@@ -395,9 +500,16 @@ macro_rules! wrapper {
         match fn {
             get_type => || $get_type_expr:expr,
         }
+
+        $(
+            class trait $class_trait:ident {
+                $($class_methods:tt)*
+            }
+        )?
     ) => {
         $crate::glib_object_wrapper!(@object [$($attr)*] $name, $ffi_name, $ffi_class_name,
             @get_type $get_type_expr, @extends [], @implements [$($implements),+]);
+        $($crate::glib_object_wrapper!(@class_trait $name, $class_trait, $($class_methods)*);)?
     };
 
     // Object, no class struct, parents and interfaces
@@ -421,9 +533,16 @@ macro_rules! wrapper {
         match fn {
             get_type => || $get_type_expr:expr,
         }
+
+        $(
+            class trait $class_trait:ident {
+                $($class_methods:tt)*
+            }
+        )?
     ) => {
         $crate::glib_object_wrapper!(@object [$($attr)*] $name, $ffi_name, $ffi_class_name,
             @get_type $get_type_expr, @extends [$($extends),+], @implements [$($implements),+]);
+        $($crate::glib_object_wrapper!(@class_trait $name, $class_trait, $($class_methods)*);)?
     };
 
     // ObjectSubclass, no parents or interfaces
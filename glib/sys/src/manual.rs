@@ -3,7 +3,7 @@
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
 #[allow(unused_imports)]
-use libc::{c_char, c_int, c_ushort, c_void};
+use libc::{c_char, c_int, c_uint, c_ushort, c_void};
 
 #[cfg(unix)]
 pub use libc::passwd;
@@ -43,3 +43,13 @@ pub struct GPollFD {
     pub events: c_ushort,
     pub revents: c_ushort,
 }
+
+// Exported variables aren't part of the generated bindings
+#[link(name = "glib-2.0")]
+extern "C" {
+    pub static glib_major_version: c_uint;
+    pub static glib_minor_version: c_uint;
+    pub static glib_micro_version: c_uint;
+    pub static glib_interface_age: c_uint;
+    pub static glib_binary_age: c_uint;
+}
@@ -0,0 +1,25 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::EulerOrder;
+#[cfg(any(feature = "v1_10", feature = "dox"))]
+use crate::RayIntersectionKind;
+
+// The conversions of the generated enums, see `glib::enum_conversions!`.
+glib::enum_conversions! {
+    integer:
+    EulerOrder,
+    #[cfg(any(feature = "v1_10", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v1_10")))]
+    RayIntersectionKind,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            glib::__unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
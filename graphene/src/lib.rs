@@ -28,6 +28,7 @@ pub mod prelude;
 pub use crate::auto::*;
 
 mod box_;
+mod enums;
 mod euler;
 mod frustum;
 mod matrix;
@@ -2,13 +2,20 @@
 
 use crate::rt;
 use crate::Application;
+use crate::GtkApplicationExt;
+use crate::Window;
+use futures_core::stream::Stream;
 use gio::ApplicationExt;
 use gio::ApplicationFlags;
+use glib::object::Cast;
 use glib::signal::SignalHandlerId;
 use glib::translate::*;
+use glib::IsA;
 use glib::ObjectExt;
 
+use std::any::Any;
 use std::cell::RefCell;
+use std::pin::Pin;
 use std::rc::Rc;
 
 impl Application {
@@ -48,3 +55,96 @@ impl Application {
         Ok(app)
     }
 }
+
+/// A change of the list of windows of an [`Application`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowListChange {
+    /// The window was added to the application.
+    Added(Window),
+    /// The window was removed from the application.
+    Removed(Window),
+}
+
+const APPLICATION_STATE_KEY: &str = "gtk-rs-application-state";
+
+pub trait GtkApplicationExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns a `Stream` of all additions to and removals from the window list of the
+    /// application.
+    ///
+    /// The signal handlers are disconnected once the stream is dropped, and the stream ends when
+    /// the application is finalized.
+    fn window_list_stream(&self) -> Pin<Box<dyn Stream<Item = WindowListChange> + 'static>>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the active window of the application if it is of type `W`.
+    fn get_active_window_as<W: IsA<Window>>(&self) -> Option<W>;
+
+    // rustdoc-stripper-ignore-next
+    /// Stores `state` in the application, replacing any previously stored state, and returns a
+    /// shared reference to it.
+    ///
+    /// The state can then be retrieved again by every window of the application with
+    /// [`get_state`](Self::get_state):
+    ///
+    /// ```no_run
+    /// use gtk::prelude::*;
+    /// use std::cell::Cell;
+    ///
+    /// struct AppState {
+    ///     counter: Cell<u32>,
+    /// }
+    ///
+    /// # fn build(app: &gtk::Application) {
+    /// app.set_state(AppState { counter: Cell::new(0) });
+    ///
+    /// let window = gtk::ApplicationWindow::new(app);
+    /// let state = window
+    ///     .get_application()
+    ///     .and_then(|app| app.get_state::<AppState>())
+    ///     .expect("No application state");
+    /// state.counter.set(state.counter.get() + 1);
+    /// # }
+    /// ```
+    fn set_state<S: 'static>(&self, state: S) -> Rc<S>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the state stored with [`set_state`](Self::set_state) if it is of type `S`.
+    fn get_state<S: 'static>(&self) -> Option<Rc<S>>;
+}
+
+impl<O: IsA<Application>> GtkApplicationExtManual for O {
+    fn window_list_stream(&self) -> Pin<Box<dyn Stream<Item = WindowListChange> + 'static>> {
+        Box::pin(glib::signal_stream(self.as_ref(), |application, sender| {
+            let added_sender = sender.clone();
+            vec![
+                application.connect_window_added(move |_, window| {
+                    let _ = added_sender.unbounded_send(WindowListChange::Added(window.clone()));
+                }),
+                application.connect_window_removed(move |_, window| {
+                    let _ = sender.unbounded_send(WindowListChange::Removed(window.clone()));
+                }),
+            ]
+        }))
+    }
+
+    fn get_active_window_as<W: IsA<Window>>(&self) -> Option<W> {
+        self.get_active_window()
+            .and_then(|window| window.downcast().ok())
+    }
+
+    fn set_state<S: 'static>(&self, state: S) -> Rc<S> {
+        let state = Rc::new(state);
+        unsafe {
+            self.set_data::<Rc<dyn Any>>(APPLICATION_STATE_KEY, state.clone());
+        }
+        state
+    }
+
+    fn get_state<S: 'static>(&self) -> Option<Rc<S>> {
+        unsafe {
+            self.get_data::<Rc<dyn Any>>(APPLICATION_STATE_KEY)
+                .and_then(|state| state.as_ref().clone().downcast::<S>().ok())
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::EventController;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
 use crate::IMContext;
+use crate::PropagationPhase;
 use crate::Widget;
 use glib::object::Cast;
 use glib::object::IsA;
@@ -13,6 +14,7 @@ use glib::object::ObjectType as ObjectType_;
 use glib::signal::connect_raw;
 use glib::signal::SignalHandlerId;
 use glib::translate::*;
+use glib::ToValue;
 use std::boxed::Box as Box_;
 use std::fmt;
 use std::mem::transmute;
@@ -236,6 +238,40 @@ impl EventControllerKey {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct EventControllerKeyBuilder {
+    propagation_phase: Option<PropagationPhase>,
+    widget: Option<Widget>,
+}
+
+impl EventControllerKeyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> EventControllerKey {
+        let mut properties: Vec<(&str, &dyn ToValue)> = vec![];
+        if let Some(ref propagation_phase) = self.propagation_phase {
+            properties.push(("propagation-phase", propagation_phase));
+        }
+        if let Some(ref widget) = self.widget {
+            properties.push(("widget", widget));
+        }
+        let ret = glib::Object::new::<EventControllerKey>(&properties).expect("object new");
+        ret
+    }
+
+    pub fn propagation_phase(mut self, propagation_phase: PropagationPhase) -> Self {
+        self.propagation_phase = Some(propagation_phase);
+        self
+    }
+
+    pub fn widget<P: IsA<Widget>>(mut self, widget: &P) -> Self {
+        self.widget = Some(widget.clone().upcast());
+        self
+    }
+}
+
 impl fmt::Display for EventControllerKey {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str("EventControllerKey")
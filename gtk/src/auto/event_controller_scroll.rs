@@ -6,6 +6,7 @@ use crate::EventController;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
 use crate::EventControllerScrollFlags;
+use crate::PropagationPhase;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
 use crate::Widget;
@@ -19,6 +20,7 @@ use glib::object::ObjectType as ObjectType_;
 use glib::signal::connect_raw;
 use glib::signal::SignalHandlerId;
 use glib::translate::*;
+use glib::ToValue;
 use std::boxed::Box as Box_;
 use std::fmt;
 use std::mem::transmute;
@@ -201,6 +203,49 @@ impl EventControllerScroll {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct EventControllerScrollBuilder {
+    flags: Option<EventControllerScrollFlags>,
+    propagation_phase: Option<PropagationPhase>,
+    widget: Option<Widget>,
+}
+
+impl EventControllerScrollBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> EventControllerScroll {
+        let mut properties: Vec<(&str, &dyn ToValue)> = vec![];
+        if let Some(ref flags) = self.flags {
+            properties.push(("flags", flags));
+        }
+        if let Some(ref propagation_phase) = self.propagation_phase {
+            properties.push(("propagation-phase", propagation_phase));
+        }
+        if let Some(ref widget) = self.widget {
+            properties.push(("widget", widget));
+        }
+        let ret = glib::Object::new::<EventControllerScroll>(&properties).expect("object new");
+        ret
+    }
+
+    pub fn flags(mut self, flags: EventControllerScrollFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn propagation_phase(mut self, propagation_phase: PropagationPhase) -> Self {
+        self.propagation_phase = Some(propagation_phase);
+        self
+    }
+
+    pub fn widget<P: IsA<Widget>>(mut self, widget: &P) -> Self {
+        self.widget = Some(widget.clone().upcast());
+        self
+    }
+}
+
 impl fmt::Display for EventControllerScroll {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str("EventControllerScroll")
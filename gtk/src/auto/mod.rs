@@ -254,6 +254,9 @@ mod event_controller_key;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
 pub use self::event_controller_key::EventControllerKey;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
+pub use self::event_controller_key::EventControllerKeyBuilder;
 
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
@@ -261,6 +264,9 @@ mod event_controller_motion;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
 pub use self::event_controller_motion::EventControllerMotion;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
+pub use self::event_controller_motion::EventControllerMotionBuilder;
 
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
@@ -268,6 +274,9 @@ mod event_controller_scroll;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
 pub use self::event_controller_scroll::EventControllerScroll;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
+pub use self::event_controller_scroll::EventControllerScrollBuilder;
 
 mod expander;
 pub use self::expander::ExpanderBuilder;
@@ -391,6 +400,9 @@ mod gesture_stylus;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
 pub use self::gesture_stylus::GestureStylus;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
+pub use self::gesture_stylus::GestureStylusBuilder;
 
 mod gesture_swipe;
 pub use self::gesture_swipe::GestureSwipe;
@@ -295,9 +295,6 @@ pub trait TextBufferExt: 'static {
     #[doc(alias = "gtk_text_buffer_place_cursor")]
     fn place_cursor(&self, where_: &TextIter);
 
-    //#[doc(alias = "gtk_text_buffer_register_deserialize_format")]
-    //fn register_deserialize_format<P: Fn(&TextBuffer, &TextBuffer, &TextIter, &Vec<u8>, usize, bool, Option<&glib::Error>) -> bool + 'static>(&self, mime_type: &str, function: P) -> Option<gdk::Atom>;
-
     #[doc(alias = "gtk_text_buffer_register_deserialize_tagset")]
     fn register_deserialize_tagset(&self, tagset_name: Option<&str>) -> gdk::Atom;
 
@@ -1018,10 +1015,6 @@ impl<O: IsA<TextBuffer>> TextBufferExt for O {
         }
     }
 
-    //fn register_deserialize_format<P: Fn(&TextBuffer, &TextBuffer, &TextIter, &Vec<u8>, usize, bool, Option<&glib::Error>) -> bool + 'static>(&self, mime_type: &str, function: P) -> Option<gdk::Atom> {
-    //    unsafe { TODO: call ffi:gtk_text_buffer_register_deserialize_format() }
-    //}
-
     fn register_deserialize_tagset(&self, tagset_name: Option<&str>) -> gdk::Atom {
         unsafe {
             from_glib_none(ffi::gtk_text_buffer_register_deserialize_tagset(
@@ -6,6 +6,9 @@ use glib::translate::*;
 use glib::GString;
 use glib::Object;
 use glib::ObjectExt;
+use glib::StaticType;
+use std::error;
+use std::fmt;
 use std::path::Path;
 
 impl Builder {
@@ -24,6 +27,12 @@ pub trait BuilderExtManual: 'static {
     #[doc(alias = "gtk_builder_get_object")]
     fn get_object<T: IsA<Object>>(&self, name: &str) -> Option<T>;
 
+    // rustdoc-stripper-ignore-next
+    /// Like [`get_object`](Self::get_object), but reports whether the object is missing or of
+    /// the wrong type.
+    #[doc(alias = "gtk_builder_get_object")]
+    fn get_object_checked<T: IsA<Object>>(&self, name: &str) -> Result<T, BuilderObjectError>;
+
     #[doc(alias = "gtk_builder_add_from_file")]
     fn add_from_file<T: AsRef<Path>>(&self, file_path: T) -> Result<(), glib::Error>;
     fn connect_signals<
@@ -45,6 +54,25 @@ impl<O: IsA<Builder>> BuilderExtManual for O {
         }
     }
 
+    fn get_object_checked<T: IsA<Object>>(&self, name: &str) -> Result<T, BuilderObjectError> {
+        let obj = unsafe {
+            Option::<Object>::from_glib_none(ffi::gtk_builder_get_object(
+                self.upcast_ref().to_glib_none().0,
+                name.to_glib_none().0,
+            ))
+        }
+        .ok_or_else(|| BuilderObjectError::Missing {
+            id: String::from(name),
+        })?;
+
+        obj.dynamic_cast::<T>()
+            .map_err(|obj| BuilderObjectError::WrongType {
+                id: String::from(name),
+                actual: obj.get_type(),
+                requested: T::static_type(),
+            })
+    }
+
     fn add_from_file<T: AsRef<Path>>(&self, file_path: T) -> Result<(), glib::Error> {
         unsafe {
             let mut error = ::std::ptr::null_mut();
@@ -110,3 +138,87 @@ impl<O: IsA<Builder>> BuilderExtManual for O {
         }
     }
 }
+
+/// An error returned from [`BuilderExtManual::get_object_checked`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuilderObjectError {
+    /// The builder has no object with this id.
+    Missing { id: String },
+    /// The object with this id is not of the requested type.
+    WrongType {
+        id: String,
+        actual: glib::Type,
+        requested: glib::Type,
+    },
+}
+
+impl fmt::Display for BuilderObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuilderObjectError::Missing { id } => write!(f, "no object with id '{}'", id),
+            BuilderObjectError::WrongType {
+                id,
+                actual,
+                requested,
+            } => write!(
+                f,
+                "object with id '{}' is of type '{}' instead of '{}'",
+                id, actual, requested
+            ),
+        }
+    }
+}
+
+impl error::Error for BuilderObjectError {}
+
+/// Declares a struct of objects that are looked up by id in a [`Builder`].
+///
+/// The id of each object defaults to the name of its field and can be overridden with
+/// `= "id"` after the type. The generated `from_builder` function fails with the error of the
+/// first object that is missing or of the wrong type.
+///
+/// ```no_run
+/// gtk::builder_objects! {
+///     struct Widgets {
+///         window: gtk::Window,
+///         ok_button: gtk::Button = "ok-button",
+///     }
+/// }
+///
+/// # fn build(builder: &gtk::Builder) -> Result<(), gtk::BuilderObjectError> {
+/// let widgets = Widgets::from_builder(builder)?;
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! builder_objects {
+    (@id $field:ident) => {
+        stringify!($field)
+    };
+    (@id $field:ident $id:literal) => {
+        $id
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident : $ty:ty $(= $id:literal)?),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        impl $name {
+            $vis fn from_builder<B: $crate::glib::IsA<$crate::Builder>>(
+                builder: &B,
+            ) -> Result<Self, $crate::BuilderObjectError> {
+                use $crate::prelude::BuilderExtManual as _;
+
+                Ok($name {
+                    $($field: builder.get_object_checked($crate::builder_objects!(@id $field $($id)?))?),*
+                })
+            }
+        }
+    };
+}
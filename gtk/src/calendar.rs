@@ -0,0 +1,67 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::*;
+use crate::Calendar;
+
+pub trait CalendarExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns the start of the selected day in the local time zone, or `None` if no day is
+    /// selected.
+    fn get_date_time(&self) -> Option<glib::DateTime>;
+
+    // rustdoc-stripper-ignore-next
+    /// Shows the month of `date_time` and selects its day.
+    ///
+    /// Like selecting the month and day separately, this emits `month-changed` and
+    /// `day-selected`, the latter possibly several times.
+    fn set_date_time(&self, date_time: &glib::DateTime);
+
+    // rustdoc-stripper-ignore-next
+    /// Marks all `days` of the shown month, keeping the marks of the other days.
+    fn mark_days(&self, days: &[u32]);
+
+    // rustdoc-stripper-ignore-next
+    /// Marks exactly `days` of the shown month, e.g. the days with appointments after the month
+    /// changed.
+    fn set_marked_days(&self, days: &[u32]);
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the marked days of the shown month in ascending order.
+    fn get_marked_days(&self) -> Vec<u32>;
+}
+
+impl<O: IsA<Calendar>> CalendarExtManual for O {
+    fn get_date_time(&self) -> Option<glib::DateTime> {
+        let (year, month, day) = self.get_date();
+        if day == 0 {
+            return None;
+        }
+        // The month of the calendar starts at 0
+        glib::DateTime::new_local(year as i32, month as i32 + 1, day as i32, 0, 0, 0.0).ok()
+    }
+
+    fn set_date_time(&self, date_time: &glib::DateTime) {
+        let (year, month, day) = date_time.get_ymd();
+        // Deselect the day first, the old day might not exist in the new month
+        self.select_day(0);
+        self.select_month(month as u32 - 1, year as u32);
+        self.select_day(day as u32);
+    }
+
+    fn mark_days(&self, days: &[u32]) {
+        for &day in days {
+            self.mark_day(day);
+        }
+    }
+
+    fn set_marked_days(&self, days: &[u32]) {
+        self.clear_marks();
+        self.mark_days(days);
+    }
+
+    fn get_marked_days(&self) -> Vec<u32> {
+        (1..=31)
+            .filter(|&day| self.get_day_is_marked(day))
+            .collect()
+    }
+}
@@ -0,0 +1,396 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! A retained-mode scene graph drawn on a [`DrawingArea`].
+//!
+//! A [`Canvas`] keeps a list of [`CanvasItem`]s, each with its own transformation, stacking
+//! order and visibility. Changing an item only redraws the area it covered before and after the
+//! change, and while drawing, items outside of the area being redrawn are skipped.
+//!
+//! ```no_run
+//! use gtk::canvas::{Canvas, CanvasItem};
+//! use gtk::prelude::*;
+//!
+//! struct Node;
+//!
+//! impl CanvasItem for Node {
+//!     fn bounds(&self) -> (f64, f64, f64, f64) {
+//!         (0.0, 0.0, 100.0, 50.0)
+//!     }
+//!
+//!     fn draw(&self, cr: &cairo::Context) {
+//!         cr.rectangle(0.0, 0.0, 100.0, 50.0);
+//!         cr.fill();
+//!     }
+//! }
+//!
+//! # fn build(window: &gtk::Window) {
+//! let canvas = Canvas::new();
+//! let node = canvas.add(Node);
+//! canvas.set_transform(node, cairo::Matrix::new(1.0, 0.0, 0.0, 1.0, 20.0, 20.0));
+//! window.add(canvas.drawing_area());
+//!
+//! let canvas_clone = canvas.clone();
+//! canvas.drawing_area().add_events(gdk::EventMask::BUTTON_PRESS_MASK);
+//! canvas.drawing_area().connect_button_press_event(move |_, event| {
+//!     let (x, y) = event.get_position();
+//!     if let Some(item) = canvas_clone.item_at(x, y) {
+//!         canvas_clone.set_z_index(item, 1);
+//!     }
+//!     gtk::Inhibit(false)
+//! });
+//! # }
+//! ```
+
+use crate::{DrawingArea, Inhibit, WidgetExt};
+use cairo::Matrix;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+/// An item drawn by a [`Canvas`].
+///
+/// Items are drawn in their own coordinates, which the canvas maps to the coordinates of the
+/// drawing area with the transformation of the item and the view transformation.
+///
+/// The canvas doesn't know when the appearance of an item changes, so items with interior
+/// mutability have to be passed to [`Canvas::invalidate()`] after each change.
+pub trait CanvasItem: 'static {
+    /// Returns the extents `x1`, `y1`, `x2`, `y2` of everything `draw()` paints, in item
+    /// coordinates.
+    fn bounds(&self) -> (f64, f64, f64, f64);
+
+    /// Draws the item, with `cr` transformed to item coordinates.
+    ///
+    /// The canvas must not be modified while drawing.
+    fn draw(&self, cr: &cairo::Context);
+
+    /// Returns whether the point `x`, `y` in item coordinates hits the item.
+    ///
+    /// By default, this is whether the point is inside the bounds.
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let (x1, y1, x2, y2) = self.bounds();
+        x >= x1 && y >= y1 && x < x2 && y < y2
+    }
+}
+
+impl<T: CanvasItem> CanvasItem for Rc<T> {
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        (**self).bounds()
+    }
+
+    fn draw(&self, cr: &cairo::Context) {
+        (**self).draw(cr)
+    }
+
+    fn contains(&self, x: f64, y: f64) -> bool {
+        (**self).contains(x, y)
+    }
+}
+
+/// Identifies an item of a [`Canvas`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ItemId(u64);
+
+struct Entry {
+    id: ItemId,
+    item: Box<dyn CanvasItem>,
+    transform: Matrix,
+    z_index: i32,
+    visible: bool,
+    // The area covered by the item when it was last drawn, in widget coordinates
+    area: Option<gdk::Rectangle>,
+}
+
+impl Entry {
+    fn compute_area(&self, view_transform: &Matrix) -> Option<gdk::Rectangle> {
+        if !self.visible {
+            return None;
+        }
+
+        let (x1, y1, x2, y2) = self.item.bounds();
+        let mut corners = [(x1, y1), (x2, y1), (x1, y2), (x2, y2)];
+        Matrix::multiply(&self.transform, view_transform).transform_points(&mut corners);
+        let (x1, y1, x2, y2) = corners.iter().fold(
+            (
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(x1, y1, x2, y2), &(x, y)| (x1.min(x), y1.min(y), x2.max(x), y2.max(y)),
+        );
+        // Antialiasing can touch the pixels around the extents
+        let area = gdk::Rectangle::from_cairo_extents(x1, y1, x2, y2).inflate(1, 1);
+        Some(area).filter(|area| !area.is_empty())
+    }
+}
+
+struct CanvasInner {
+    drawing_area: DrawingArea,
+    // Sorted by z-index, items added later are above items with the same z-index
+    entries: RefCell<Vec<Entry>>,
+    view_transform: Cell<Matrix>,
+    next_id: Cell<u64>,
+}
+
+impl CanvasInner {
+    fn queue_draw_area(&self, area: Option<gdk::Rectangle>) {
+        if let Some(area) = area {
+            self.drawing_area
+                .queue_draw_area(area.x, area.y, area.width, area.height);
+        }
+    }
+
+    // Redraws the area covered by the entry before and after a change
+    fn update(&self, entry: &mut Entry) {
+        let area = entry.compute_area(&self.view_transform.get());
+        self.queue_draw_area(entry.area);
+        self.queue_draw_area(area);
+        entry.area = area;
+    }
+
+    fn modify<R, F: FnOnce(&mut Entry) -> R>(&self, id: ItemId, f: F) -> Option<R> {
+        let mut entries = self.entries.borrow_mut();
+        let entry = entries.iter_mut().find(|entry| entry.id == id)?;
+        let ret = f(entry);
+        self.update(entry);
+        Some(ret)
+    }
+
+    fn insert(&self, entry: Entry) {
+        let mut entries = self.entries.borrow_mut();
+        let index = entries
+            .iter()
+            .position(|other| other.z_index > entry.z_index)
+            .unwrap_or_else(|| entries.len());
+        entries.insert(index, entry);
+    }
+
+    fn draw(&self, cr: &cairo::Context) {
+        let (x1, y1, x2, y2) = cr.clip_extents();
+        let clip = gdk::Rectangle::from_cairo_extents(x1, y1, x2, y2);
+        let view_transform = self.view_transform.get();
+
+        for entry in self.entries.borrow().iter() {
+            let visible = entry
+                .area
+                .map_or(false, |area| area.intersect(&clip).is_some());
+            if !visible {
+                continue;
+            }
+
+            let _ = cr.save();
+            cr.transform(Matrix::multiply(&entry.transform, &view_transform));
+            entry.item.draw(cr);
+            let _ = cr.restore();
+        }
+    }
+}
+
+/// A scene graph of [`CanvasItem`]s drawn on a [`DrawingArea`].
+///
+/// Cloning the canvas returns another reference to the same scene graph.
+#[derive(Clone)]
+pub struct Canvas(Rc<CanvasInner>);
+
+impl Canvas {
+    /// Creates an empty canvas together with the drawing area showing it.
+    pub fn new() -> Canvas {
+        let inner = Rc::new(CanvasInner {
+            drawing_area: DrawingArea::new(),
+            entries: RefCell::new(Vec::new()),
+            view_transform: Cell::new(Matrix::identity()),
+            next_id: Cell::new(0),
+        });
+
+        // The drawing area is owned by the canvas, so it only keeps a weak reference back
+        let weak: Weak<CanvasInner> = Rc::downgrade(&inner);
+        inner.drawing_area.connect_draw(move |_, cr| {
+            if let Some(inner) = weak.upgrade() {
+                inner.draw(cr);
+            }
+            Inhibit(false)
+        });
+
+        Canvas(inner)
+    }
+
+    /// Returns the drawing area showing the canvas.
+    ///
+    /// Events are delivered to the drawing area, use [`item_at()`](Self::item_at) to find the
+    /// item at the position of an event.
+    pub fn drawing_area(&self) -> &DrawingArea {
+        &self.0.drawing_area
+    }
+
+    /// Adds `item` on top of the items with a z-index of zero, with an identity
+    /// transformation.
+    pub fn add<I: CanvasItem>(&self, item: I) -> ItemId {
+        let id = ItemId(self.0.next_id.get());
+        self.0.next_id.set(id.0 + 1);
+
+        let mut entry = Entry {
+            id,
+            item: Box::new(item),
+            transform: Matrix::identity(),
+            z_index: 0,
+            visible: true,
+            area: None,
+        };
+        self.0.update(&mut entry);
+        self.0.insert(entry);
+        id
+    }
+
+    /// Removes the item `id`, returning whether the canvas contained it.
+    pub fn remove(&self, id: ItemId) -> bool {
+        let mut entries = self.0.entries.borrow_mut();
+        match entries.iter().position(|entry| entry.id == id) {
+            Some(index) => {
+                let entry = entries.remove(index);
+                self.0.queue_draw_area(entry.area);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all items.
+    pub fn clear(&self) {
+        self.0.entries.borrow_mut().clear();
+        self.0.drawing_area.queue_draw();
+    }
+
+    /// Returns the ids of all items, from the bottom to the top.
+    pub fn items(&self) -> Vec<ItemId> {
+        self.0
+            .entries
+            .borrow()
+            .iter()
+            .map(|entry| entry.id)
+            .collect()
+    }
+
+    /// Redraws the item `id` after its appearance or bounds changed.
+    pub fn invalidate(&self, id: ItemId) {
+        self.0.modify(id, |_| ());
+    }
+
+    /// Sets the transformation from the coordinates of the item `id` to canvas coordinates.
+    pub fn set_transform(&self, id: ItemId, transform: Matrix) {
+        self.0.modify(id, |entry| entry.transform = transform);
+    }
+
+    /// Returns the transformation of the item `id`, or `None` if the canvas doesn't contain it.
+    pub fn get_transform(&self, id: ItemId) -> Option<Matrix> {
+        self.0
+            .entries
+            .borrow()
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.transform)
+    }
+
+    /// Sets the stacking order of the item `id`. Items with a higher z-index are drawn above
+    /// items with a lower one.
+    ///
+    /// The item is placed above the other items with the same z-index.
+    pub fn set_z_index(&self, id: ItemId, z_index: i32) {
+        let entry = {
+            let mut entries = self.0.entries.borrow_mut();
+            match entries.iter().position(|entry| entry.id == id) {
+                Some(index) => entries.remove(index),
+                None => return,
+            }
+        };
+
+        let mut entry = Entry { z_index, ..entry };
+        self.0.update(&mut entry);
+        self.0.insert(entry);
+    }
+
+    /// Returns the z-index of the item `id`, or `None` if the canvas doesn't contain it.
+    pub fn get_z_index(&self, id: ItemId) -> Option<i32> {
+        self.0
+            .entries
+            .borrow()
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.z_index)
+    }
+
+    /// Sets whether the item `id` is drawn. Hidden items are ignored by hit testing.
+    pub fn set_visible(&self, id: ItemId, visible: bool) {
+        self.0.modify(id, |entry| entry.visible = visible);
+    }
+
+    /// Returns whether the item `id` is drawn, or `None` if the canvas doesn't contain it.
+    pub fn get_visible(&self, id: ItemId) -> Option<bool> {
+        self.0
+            .entries
+            .borrow()
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.visible)
+    }
+
+    /// Sets the transformation from canvas coordinates to the coordinates of the drawing area,
+    /// e.g. for scrolling and zooming.
+    pub fn set_view_transform(&self, transform: Matrix) {
+        self.0.view_transform.set(transform);
+        for entry in self.0.entries.borrow_mut().iter_mut() {
+            entry.area = entry.compute_area(&transform);
+        }
+        self.0.drawing_area.queue_draw();
+    }
+
+    /// Returns the transformation from canvas coordinates to the coordinates of the drawing
+    /// area.
+    pub fn get_view_transform(&self) -> Matrix {
+        self.0.view_transform.get()
+    }
+
+    /// Returns the topmost visible item at the position `x`, `y` of the drawing area.
+    pub fn item_at(&self, x: f64, y: f64) -> Option<ItemId> {
+        self.items_at(x, y).into_iter().next()
+    }
+
+    /// Returns all visible items at the position `x`, `y` of the drawing area, from the top to
+    /// the bottom.
+    pub fn items_at(&self, x: f64, y: f64) -> Vec<ItemId> {
+        let view_transform = self.0.view_transform.get();
+        self.0
+            .entries
+            .borrow()
+            .iter()
+            .rev()
+            .filter(|entry| entry.visible)
+            .filter(|entry| {
+                // Items which are scaled to nothing can't be hit
+                Matrix::multiply(&entry.transform, &view_transform)
+                    .try_invert()
+                    .map_or(false, |inverse| {
+                        let (x, y) = inverse.transform_point(x, y);
+                        entry.item.contains(x, y)
+                    })
+            })
+            .map(|entry| entry.id)
+            .collect()
+    }
+}
+
+impl Default for Canvas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Canvas")
+            .field("drawing_area", &self.0.drawing_area)
+            .field("items", &self.items())
+            .field("view_transform", &self.0.view_transform.get())
+            .finish()
+    }
+}
@@ -0,0 +1,164 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::*;
+use crate::{Calendar, Label, MenuButton, Popover};
+use glib::SignalHandlerId;
+use std::cell::Cell;
+use std::fmt;
+
+/// A button showing a date, which lets the user pick another date from a [`Calendar`] in a
+/// popover.
+///
+/// The button shows the date formatted for the current locale. Selecting a day updates the
+/// date, double-clicking it also closes the popover.
+///
+/// The state of the date picker is kept by the button, so the `DatePicker` can be dropped
+/// once the button was added to a container.
+///
+/// ```no_run
+/// # fn build(container: &gtk::Box) {
+/// use gtk::prelude::*;
+///
+/// let picker = gtk::DatePicker::new();
+/// picker.set_date_time(&glib::DateTime::new_now_local().unwrap());
+/// picker.connect_date_changed(|_, date| println!("{}", date.format("%F").unwrap()));
+/// container.add(picker.get_button());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DatePicker(MenuButton);
+
+// Stored on the button so that it lives as long as the button
+struct Parts {
+    label: Label,
+    calendar: Calendar,
+    // Set while the date is set by the application, which selects the day of the calendar
+    // several times
+    setting_date: Cell<bool>,
+}
+
+const PARTS_KEY: &str = "gtk-rs-date-picker-parts";
+
+impl DatePicker {
+    /// Creates a new date picker showing today.
+    pub fn new() -> Self {
+        assert_initialized_main_thread!();
+        let label = Label::new(None);
+        let button = MenuButton::new();
+        button.add(&label);
+
+        let calendar = Calendar::new();
+        let popover = Popover::new(Some(&button));
+        popover.add(&calendar);
+        calendar.show();
+        button.set_popover(Some(&popover));
+        button.show_all();
+
+        // The handlers only hold weak references to the button to avoid reference cycles
+        let weak_button = button.downgrade();
+        calendar.connect_day_selected(move |_| {
+            if let Some(button) = weak_button.upgrade() {
+                DatePicker(button).update_label();
+            }
+        });
+        calendar.connect_day_selected_double_click(|calendar| {
+            if let Some(popover) = calendar.get_parent() {
+                popover.hide();
+            }
+        });
+
+        unsafe {
+            button.set_data(
+                PARTS_KEY,
+                Parts {
+                    label,
+                    calendar,
+                    setting_date: Cell::new(false),
+                },
+            );
+        }
+        let picker = DatePicker(button);
+        picker.update_label();
+        picker
+    }
+
+    fn parts(&self) -> &Parts {
+        // The parts are never replaced or removed, and live as long as the button
+        unsafe {
+            self.0
+                .get_data::<Parts>(PARTS_KEY)
+                .expect("date picker parts are missing")
+                .as_ref()
+        }
+    }
+
+    fn update_label(&self) {
+        let text = self
+            .get_date_time()
+            .and_then(|date| date.format_locale_date().ok());
+        self.parts().label.set_text(text.as_deref().unwrap_or(""));
+    }
+
+    /// Returns the button, which has to be added to a container to show the date picker.
+    pub fn get_button(&self) -> &MenuButton {
+        &self.0
+    }
+
+    /// Returns the calendar shown in the popover, e.g. to mark days.
+    pub fn get_calendar(&self) -> &Calendar {
+        &self.parts().calendar
+    }
+
+    /// Returns the start of the picked day in the local time zone.
+    pub fn get_date_time(&self) -> Option<glib::DateTime> {
+        self.parts().calendar.get_date_time()
+    }
+
+    /// Picks the day of `date_time`.
+    ///
+    /// This doesn't call the handlers connected with
+    /// [`connect_date_changed`](Self::connect_date_changed).
+    pub fn set_date_time(&self, date_time: &glib::DateTime) {
+        let parts = self.parts();
+        parts.setting_date.set(true);
+        parts.calendar.set_date_time(date_time);
+        parts.setting_date.set(false);
+    }
+
+    /// Calls `f` with the start of the picked day whenever the user picks another day.
+    ///
+    /// `f` is not called for dates set with [`set_date_time`](Self::set_date_time).
+    pub fn connect_date_changed<F: Fn(&DatePicker, &glib::DateTime) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        let weak_button = self.0.downgrade();
+        self.parts().calendar.connect_day_selected(move |calendar| {
+            let picker = match weak_button.upgrade() {
+                Some(button) => DatePicker(button),
+                None => return,
+            };
+            if picker.parts().setting_date.get() {
+                return;
+            }
+            if let Some(date_time) = calendar.get_date_time() {
+                f(&picker, &date_time);
+            }
+        })
+    }
+}
+
+impl Default for DatePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for DatePicker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DatePicker")
+            .field("button", &self.0)
+            .field("date_time", &self.get_date_time())
+            .finish()
+    }
+}
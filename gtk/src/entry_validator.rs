@@ -0,0 +1,217 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{EditableSignals, Entry, EntryExt, EntryIconPosition, StyleContextExt, WidgetExt};
+use futures_channel::mpsc;
+use futures_core::stream::Stream;
+use glib::object::{IsA, ObjectExt};
+use glib::signal::SignalHandlerId;
+use glib::GString;
+use std::cell::RefCell;
+use std::fmt;
+use std::pin::Pin;
+use std::rc::Rc;
+
+const ERROR_ICON_NAME: &str = "dialog-error-symbolic";
+
+/// Validates the text of an [`Entry`] or [`SpinButton`](crate::SpinButton) whenever it
+/// changes, converting it to a typed value.
+///
+/// While the text is invalid the entry gets the `error` style class and shows an error icon
+/// with the message returned by the validator as tooltip, replacing any other secondary icon
+/// until the text is valid again.
+///
+/// ```no_run
+/// use gtk::prelude::*;
+///
+/// # fn build(entry: &gtk::Entry, button: &gtk::Button) {
+/// let validator = gtk::EntryValidator::new(entry, |text| {
+///     text.parse::<u16>()
+///         .map_err(|_| String::from("Not a valid port number"))
+/// });
+///
+/// button.connect_clicked(move |_| {
+///     if let Some(port) = validator.get_value() {
+///         println!("Connecting to port {}", port);
+///     }
+/// });
+/// # }
+/// ```
+pub struct EntryValidator<T> {
+    entry: Entry,
+    state: Rc<State<T>>,
+}
+
+struct State<T> {
+    validate: Box<dyn Fn(&str) -> Result<T, String> + 'static>,
+    result: RefCell<Option<Result<T, String>>>,
+    // The secondary icon and its tooltip from before the error icon was shown
+    saved_icon: RefCell<Option<(Option<GString>, Option<GString>)>>,
+    senders: RefCell<Vec<mpsc::UnboundedSender<T>>>,
+    handler_id: RefCell<Option<SignalHandlerId>>,
+}
+
+impl<T: Clone + 'static> State<T> {
+    fn validate(&self, entry: &Entry) {
+        let result = (self.validate)(&entry.get_text());
+        match result {
+            Ok(ref value) => {
+                self.clear_error(entry);
+                self.senders
+                    .borrow_mut()
+                    .retain(|sender| sender.unbounded_send(value.clone()).is_ok());
+            }
+            Err(ref message) => self.show_error(entry, message),
+        }
+        *self.result.borrow_mut() = Some(result);
+    }
+
+    fn show_error(&self, entry: &Entry, message: &str) {
+        let mut saved_icon = self.saved_icon.borrow_mut();
+        if saved_icon.is_none() {
+            *saved_icon = Some((
+                entry.get_icon_name(EntryIconPosition::Secondary),
+                entry.get_icon_tooltip_text(EntryIconPosition::Secondary),
+            ));
+            entry
+                .get_style_context()
+                .add_class(*crate::STYLE_CLASS_ERROR);
+            entry.set_icon_from_icon_name(EntryIconPosition::Secondary, Some(ERROR_ICON_NAME));
+        }
+        entry.set_icon_tooltip_text(EntryIconPosition::Secondary, Some(message));
+    }
+
+    fn clear_error(&self, entry: &Entry) {
+        if let Some((icon_name, tooltip)) = self.saved_icon.borrow_mut().take() {
+            entry
+                .get_style_context()
+                .remove_class(*crate::STYLE_CLASS_ERROR);
+            entry.set_icon_from_icon_name(EntryIconPosition::Secondary, icon_name.as_deref());
+            entry.set_icon_tooltip_text(EntryIconPosition::Secondary, tooltip.as_deref());
+        }
+    }
+}
+
+impl<T: Clone + 'static> EntryValidator<T> {
+    /// Attaches `validate` to `entry`, which is called with the text of the entry whenever it
+    /// changes and returns either the value of the text or an error message.
+    ///
+    /// The current text of the entry is validated immediately.
+    pub fn new<E, F>(entry: &E, validate: F) -> Self
+    where
+        E: IsA<Entry>,
+        F: Fn(&str) -> Result<T, String> + 'static,
+    {
+        let entry = entry.as_ref().clone();
+        let state = Rc::new(State {
+            validate: Box::new(validate),
+            result: RefCell::new(None),
+            saved_icon: RefCell::new(None),
+            senders: RefCell::new(Vec::new()),
+            handler_id: RefCell::new(None),
+        });
+
+        let state_clone = state.clone();
+        let handler_id = entry.connect_changed(move |entry| state_clone.validate(entry));
+        *state.handler_id.borrow_mut() = Some(handler_id);
+        state.validate(&entry);
+
+        Self { entry, state }
+    }
+
+    /// Returns the validated entry.
+    pub fn get_entry(&self) -> &Entry {
+        &self.entry
+    }
+
+    /// Returns the value of the current text, or `None` if it is invalid.
+    pub fn get_value(&self) -> Option<T> {
+        match *self.state.result.borrow() {
+            Some(Ok(ref value)) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the error message for the current text, or `None` if it is valid.
+    pub fn get_error(&self) -> Option<String> {
+        match *self.state.result.borrow() {
+            Some(Err(ref message)) => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the current text is valid.
+    pub fn is_valid(&self) -> bool {
+        matches!(*self.state.result.borrow(), Some(Ok(_)))
+    }
+
+    /// Validates the current text again, e.g. because the validator depends on other input.
+    pub fn validate(&self) {
+        self.state.validate(&self.entry);
+    }
+
+    /// Returns a `Stream` of the values of all valid texts of the entry, starting with the
+    /// next change.
+    ///
+    /// The stream ends when the validator is detached.
+    pub fn value_changed_stream(&self) -> Pin<Box<dyn Stream<Item = T> + 'static>> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.state.senders.borrow_mut().push(sender);
+        Box::pin(receiver)
+    }
+
+    /// Stops validating the entry and removes the error indication.
+    ///
+    /// Otherwise the validation continues as long as the entry exists, even if all
+    /// `EntryValidator`s for it are dropped.
+    pub fn detach(&self) {
+        if let Some(handler_id) = self.state.handler_id.borrow_mut().take() {
+            self.entry.disconnect(handler_id);
+        }
+        self.state.clear_error(&self.entry);
+        self.state.senders.borrow_mut().clear();
+    }
+}
+
+impl EntryValidator<String> {
+    /// Attaches a validator to `entry` that accepts texts that are completely matched by the
+    /// Perl-compatible regular expression `pattern`, showing `message` for all other texts.
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn with_regex<E: IsA<Entry>>(
+        entry: &E,
+        pattern: &str,
+        message: &str,
+    ) -> Result<Self, glib::Error> {
+        let regex = glib::Regex::new(
+            &format!("\\A(?:{})\\z", pattern),
+            glib::RegexCompileFlags::empty(),
+            glib::RegexMatchFlags::empty(),
+        )?;
+        let message = message.to_owned();
+        Ok(Self::new(entry, move |text| {
+            if regex.is_match(text, glib::RegexMatchFlags::empty()) {
+                Ok(text.to_owned())
+            } else {
+                Err(message.clone())
+            }
+        }))
+    }
+}
+
+impl<T> Clone for EntryValidator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            entry: self.entry.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for EntryValidator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EntryValidator")
+            .field("entry", &self.entry)
+            .field("result", &self.state.result.borrow())
+            .finish()
+    }
+}
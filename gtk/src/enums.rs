@@ -1,7 +1,33 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::IconSize;
+#[cfg(any(feature = "v3_16", feature = "dox"))]
+use crate::ButtonRole;
+#[cfg(any(feature = "v3_22", feature = "dox"))]
+use crate::PadActionType;
+#[cfg(any(feature = "v3_20", feature = "dox"))]
+use crate::PopoverConstraint;
 use crate::ResponseType;
+#[cfg(any(feature = "v3_20", feature = "dox"))]
+use crate::ShortcutType;
+#[cfg(any(feature = "v3_16", feature = "dox"))]
+use crate::TextExtendSelection;
+use crate::{
+    Align, ArrowType, AssistantPageType, BaselinePosition, BorderStyle, BuilderError,
+    ButtonBoxStyle, ButtonsType, CellRendererAccelMode, CellRendererMode, CornerType,
+    CssProviderError, CssSectionType, DeleteType, DirectionType, DragResult, EntryIconPosition,
+    EventSequenceState, FileChooserAction, FileChooserConfirmation, FileChooserError,
+    IMPreeditStyle, IMStatusStyle, IconSize, IconThemeError, IconViewDropPosition, ImageType,
+    InputPurpose, Justification, LevelBarMode, License, MenuDirectionType, MessageType,
+    MovementStep, NotebookTab, NumberUpLayout, Orientation, PackDirection, PackType,
+    PageOrientation, PageSet, PanDirection, PolicyType, PositionType, PrintDuplex, PrintError,
+    PrintOperationAction, PrintOperationResult, PrintPages, PrintQuality, PrintStatus,
+    PropagationPhase, RecentChooserError, RecentManagerError, RecentSortType, ReliefStyle,
+    ResizeMode, RevealerTransitionType, ScrollStep, ScrollType, ScrollablePolicy, SelectionMode,
+    SensitivityType, ShadowType, SizeGroupMode, SizeRequestMode, SortType, SpinButtonUpdatePolicy,
+    SpinType, StackTransitionType, StateType, TextDirection, TextViewLayer, TextWindowType,
+    ToolbarStyle, TreeViewColumnSizing, TreeViewDropPosition, TreeViewGridLines, Unit,
+    WidgetHelpType, WindowPosition, WindowType, WrapMode,
+};
 use glib::translate::{from_glib, ToGlib};
 
 impl IconSize {
@@ -38,3 +64,120 @@ impl From<i32> for ResponseType {
         unsafe { from_glib(val as ffi::GtkResponseType) }
     }
 }
+
+// The conversions of the generated enums, see `glib::enum_conversions!`.
+glib::enum_conversions! {
+    Align,
+    ArrowType,
+    AssistantPageType,
+    BaselinePosition,
+    BorderStyle,
+    BuilderError,
+    ButtonBoxStyle,
+    #[cfg(any(feature = "v3_16", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_16")))]
+    ButtonRole,
+    ButtonsType,
+    CellRendererAccelMode,
+    CellRendererMode,
+    CornerType,
+    CssProviderError,
+    CssSectionType,
+    DeleteType,
+    DirectionType,
+    DragResult,
+    EntryIconPosition,
+    EventSequenceState,
+    FileChooserAction,
+    FileChooserConfirmation,
+    FileChooserError,
+    IMPreeditStyle,
+    IMStatusStyle,
+    IconThemeError,
+    IconViewDropPosition,
+    ImageType,
+    InputPurpose,
+    Justification,
+    LevelBarMode,
+    License,
+    MenuDirectionType,
+    MessageType,
+    MovementStep,
+    NotebookTab,
+    NumberUpLayout,
+    Orientation,
+    PackDirection,
+    PackType,
+    #[cfg(any(feature = "v3_22", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_22")))]
+    PadActionType,
+    PageOrientation,
+    PageSet,
+    PanDirection,
+    PolicyType,
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    PopoverConstraint,
+    PositionType,
+    PrintDuplex,
+    PrintError,
+    PrintOperationAction,
+    PrintOperationResult,
+    PrintPages,
+    PrintQuality,
+    PrintStatus,
+    PropagationPhase,
+    RecentChooserError,
+    RecentManagerError,
+    RecentSortType,
+    ReliefStyle,
+    ResizeMode,
+    RevealerTransitionType,
+    ScrollStep,
+    ScrollType,
+    ScrollablePolicy,
+    SelectionMode,
+    SensitivityType,
+    ShadowType,
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    ShortcutType,
+    SizeGroupMode,
+    SizeRequestMode,
+    SortType,
+    SpinButtonUpdatePolicy,
+    SpinType,
+    StackTransitionType,
+    StateType,
+    TextDirection,
+    #[cfg(any(feature = "v3_16", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_16")))]
+    TextExtendSelection,
+    TextViewLayer,
+    TextWindowType,
+    ToolbarStyle,
+    TreeViewColumnSizing,
+    TreeViewDropPosition,
+    TreeViewGridLines,
+    Unit,
+    WidgetHelpType,
+    WindowPosition,
+    WindowType,
+    WrapMode,
+}
+
+glib::enum_conversions! {
+    nick:
+    IconSize,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            glib::__unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{EventControllerKey, Inhibit};
+use glib::object::{Cast, IsA};
+use glib::signal::{connect_raw, SignalHandlerId};
+use glib::translate::*;
+use std::boxed::Box as Box_;
+use std::mem::transmute;
+
+pub trait EventControllerKeyExtManual: 'static {
+    /// Like [`connect_key_pressed`](EventControllerKey::connect_key_pressed) but the callback
+    /// gets the key as [`gdk::keys::Key`] and returns whether the key press was handled as
+    /// [`Inhibit`].
+    fn connect_key_pressed_typed<
+        F: Fn(&Self, gdk::keys::Key, u32, gdk::ModifierType) -> Inhibit + 'static,
+    >(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+
+    /// Like [`connect_key_released`](EventControllerKey::connect_key_released) but the
+    /// callback gets the key as [`gdk::keys::Key`].
+    fn connect_key_released_typed<F: Fn(&Self, gdk::keys::Key, u32, gdk::ModifierType) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+}
+
+impl<O: IsA<EventControllerKey>> EventControllerKeyExtManual for O {
+    fn connect_key_pressed_typed<
+        F: Fn(&Self, gdk::keys::Key, u32, gdk::ModifierType) -> Inhibit + 'static,
+    >(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        unsafe extern "C" fn key_pressed_trampoline<
+            P: IsA<EventControllerKey>,
+            F: Fn(&P, gdk::keys::Key, u32, gdk::ModifierType) -> Inhibit + 'static,
+        >(
+            this: *mut ffi::GtkEventControllerKey,
+            keyval: libc::c_uint,
+            keycode: libc::c_uint,
+            state: gdk::ffi::GdkModifierType,
+            f: glib::ffi::gpointer,
+        ) -> glib::ffi::gboolean {
+            let f: &F = &*(f as *const F);
+            f(
+                &EventControllerKey::from_glib_borrow(this).unsafe_cast_ref(),
+                from_glib(keyval),
+                keycode,
+                from_glib(state),
+            )
+            .to_glib()
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(
+                self.as_ptr() as *mut _,
+                b"key-pressed\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(
+                    key_pressed_trampoline::<Self, F> as *const (),
+                )),
+                Box_::into_raw(f),
+            )
+        }
+    }
+
+    fn connect_key_released_typed<
+        F: Fn(&Self, gdk::keys::Key, u32, gdk::ModifierType) + 'static,
+    >(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        unsafe extern "C" fn key_released_trampoline<
+            P: IsA<EventControllerKey>,
+            F: Fn(&P, gdk::keys::Key, u32, gdk::ModifierType) + 'static,
+        >(
+            this: *mut ffi::GtkEventControllerKey,
+            keyval: libc::c_uint,
+            keycode: libc::c_uint,
+            state: gdk::ffi::GdkModifierType,
+            f: glib::ffi::gpointer,
+        ) {
+            let f: &F = &*(f as *const F);
+            f(
+                &EventControllerKey::from_glib_borrow(this).unsafe_cast_ref(),
+                from_glib(keyval),
+                keycode,
+                from_glib(state),
+            )
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(
+                self.as_ptr() as *mut _,
+                b"key-released\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(
+                    key_released_trampoline::<Self, F> as *const (),
+                )),
+                Box_::into_raw(f),
+            )
+        }
+    }
+}
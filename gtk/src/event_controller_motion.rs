@@ -0,0 +1,39 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::EventControllerMotion;
+use glib::object::{Cast, IsA};
+use glib::signal::SignalHandlerId;
+use std::rc::Rc;
+
+pub trait EventControllerMotionExtManual: 'static {
+    /// Connects to the `enter`, `motion` and `leave` signals, passing the position of the
+    /// pointer in the coordinates of the widget while it is inside the widget and `None` once
+    /// it left, e.g. for hover effects.
+    fn connect_pointer_position<F: Fn(&Self, Option<(f64, f64)>) + 'static>(
+        &self,
+        f: F,
+    ) -> [SignalHandlerId; 3];
+}
+
+impl<O: IsA<EventControllerMotion>> EventControllerMotionExtManual for O {
+    fn connect_pointer_position<F: Fn(&Self, Option<(f64, f64)>) + 'static>(
+        &self,
+        f: F,
+    ) -> [SignalHandlerId; 3] {
+        let f = Rc::new(f);
+        let controller = self.as_ref();
+
+        let enter_f = f.clone();
+        let enter = controller.connect_enter(move |controller, x, y| {
+            enter_f(unsafe { controller.unsafe_cast_ref() }, Some((x, y)))
+        });
+        let motion_f = f.clone();
+        let motion = controller.connect_motion(move |controller, x, y| {
+            motion_f(unsafe { controller.unsafe_cast_ref() }, Some((x, y)))
+        });
+        let leave = controller
+            .connect_leave(move |controller| f(unsafe { controller.unsafe_cast_ref() }, None));
+
+        [enter, motion, leave]
+    }
+}
@@ -0,0 +1,39 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::gesture_swipe::main_direction;
+use crate::{DirectionType, EventControllerScroll};
+use gdk::ScrollDirection;
+use glib::object::{Cast, IsA};
+use glib::signal::SignalHandlerId;
+
+pub trait EventControllerScrollExtManual: 'static {
+    /// Connects to the `scroll` signal, passing the direction the deltas mostly point to
+    /// together with the deltas.
+    ///
+    /// The direction is one of `ScrollDirection::Up`, `Down`, `Left` and `Right`, scrolling
+    /// without any delta is ignored. With
+    /// [`EventControllerScrollFlags::DISCRETE`](crate::EventControllerScrollFlags::DISCRETE)
+    /// every emission is a single step, e.g. for switching between items.
+    fn connect_scroll_direction<F: Fn(&Self, ScrollDirection, f64, f64) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+}
+
+impl<O: IsA<EventControllerScroll>> EventControllerScrollExtManual for O {
+    fn connect_scroll_direction<F: Fn(&Self, ScrollDirection, f64, f64) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        self.as_ref().connect_scroll(move |controller, dx, dy| {
+            let direction = match main_direction(dx, dy) {
+                Some(DirectionType::Up) => ScrollDirection::Up,
+                Some(DirectionType::Down) => ScrollDirection::Down,
+                Some(DirectionType::Left) => ScrollDirection::Left,
+                Some(DirectionType::Right) => ScrollDirection::Right,
+                _ => return,
+            };
+            f(unsafe { controller.unsafe_cast_ref() }, direction, dx, dy);
+        })
+    }
+}
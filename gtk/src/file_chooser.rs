@@ -0,0 +1,130 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{FileChooser, FileChooserExt, Image, ImageExt, Widget};
+use gdk_pixbuf::Pixbuf;
+use gio::prelude::*;
+use glib::object::{Cast, IsA, ObjectExt};
+use glib::signal::SignalHandlerId;
+use std::cell::Cell;
+use std::future::Future;
+use std::rc::Rc;
+
+pub trait FileChooserExtManual: 'static {
+    /// Sets `preview_widget` as preview widget and calls `update` with it whenever the file to
+    /// preview changes.
+    ///
+    /// The future returned by `update` is spawned on the default main context and its result
+    /// decides whether the preview widget is shown. Results of previous updates that are still
+    /// pending when the file changes again are ignored. The preview widget is hidden while no
+    /// file is selected.
+    fn set_preview_widget_with<W, F, R>(&self, preview_widget: &W, update: F) -> SignalHandlerId
+    where
+        W: IsA<Widget>,
+        F: Fn(&W, gio::File) -> R + 'static,
+        R: Future<Output = bool> + 'static;
+
+    /// Sets a preview widget showing images scaled down to fit into `size` × `size` pixels.
+    ///
+    /// Files are loaded asynchronously, and the preview is hidden for files that can't be
+    /// loaded as images.
+    fn set_image_preview(&self, size: i32) -> Image;
+
+    /// Selects the filter whose name is stored in the string `key` of `settings`, and stores
+    /// the name of the selected filter there whenever it changes.
+    ///
+    /// The filters have to be added before calling this.
+    fn bind_filter_setting(&self, settings: &gio::Settings, key: &str) -> SignalHandlerId;
+}
+
+impl<O: IsA<FileChooser>> FileChooserExtManual for O {
+    fn set_preview_widget_with<W, F, R>(&self, preview_widget: &W, update: F) -> SignalHandlerId
+    where
+        W: IsA<Widget>,
+        F: Fn(&W, gio::File) -> R + 'static,
+        R: Future<Output = bool> + 'static,
+    {
+        self.set_preview_widget(preview_widget);
+        self.set_preview_widget_active(false);
+
+        let preview_widget = preview_widget.clone();
+        let latest_update = Rc::new(Cell::new(0u64));
+        self.connect_update_preview(move |chooser| {
+            let current_update = latest_update.get().wrapping_add(1);
+            latest_update.set(current_update);
+
+            let file = match chooser.get_preview_file() {
+                Some(file) => file,
+                None => {
+                    chooser.set_preview_widget_active(false);
+                    return;
+                }
+            };
+
+            let future = update(&preview_widget, file);
+            let chooser = chooser.downgrade();
+            let latest_update = latest_update.clone();
+            glib::MainContext::default().spawn_local(async move {
+                let active = future.await;
+                if latest_update.get() != current_update {
+                    return;
+                }
+                if let Some(chooser) = chooser.upgrade() {
+                    chooser.set_preview_widget_active(active);
+                }
+            });
+        })
+    }
+
+    fn set_image_preview(&self, size: i32) -> Image {
+        let image = Image::new();
+        self.set_preview_widget_with(&image, move |image, file| {
+            let image = image.clone();
+            async move {
+                match load_thumbnail(&file, size).await {
+                    Ok(pixbuf) => {
+                        image.set_from_pixbuf(Some(&pixbuf));
+                        true
+                    }
+                    Err(_) => {
+                        image.clear();
+                        false
+                    }
+                }
+            }
+        });
+        image
+    }
+
+    fn bind_filter_setting(&self, settings: &gio::Settings, key: &str) -> SignalHandlerId {
+        let name = settings.get_string(key);
+        if let Some(filter) = self
+            .list_filters()
+            .into_iter()
+            .find(|filter| filter.get_name().as_deref() == Some(name.as_str()))
+        {
+            self.set_filter(&filter);
+        }
+
+        let settings = settings.clone();
+        let key = key.to_owned();
+        self.connect_property_filter_notify(move |chooser| {
+            let name = chooser.get_filter().and_then(|filter| filter.get_name());
+            let name = name.as_deref().unwrap_or("");
+            if let Err(err) = settings.set_string(&key, name) {
+                glib::g_critical!("Gtk", "Failed to store file filter: {}", err);
+            }
+        })
+    }
+}
+
+// Loads `file` as image, scaled down to fit into `size` × `size` pixels
+async fn load_thumbnail(file: &gio::File, size: i32) -> Result<Pixbuf, glib::Error> {
+    let stream = file.read_async_future(glib::PRIORITY_DEFAULT).await?;
+    Pixbuf::from_stream_at_scale_async_future(
+        stream.upcast_ref::<gio::InputStream>(),
+        size,
+        size,
+        true,
+    )
+    .await
+}
@@ -0,0 +1,87 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::FileFilter;
+
+impl FileFilter {
+    /// Creates a filter called `name` that matches files with any of the given `extensions`,
+    /// ignoring case.
+    ///
+    /// The extensions are given without the leading `*.`, e.g. `&["jpg", "jpeg"]`.
+    pub fn from_extensions(name: &str, extensions: &[&str]) -> Self {
+        let filter = Self::new();
+        filter.set_name(Some(name));
+        for extension in extensions {
+            filter.add_pattern(&extension_pattern(extension));
+        }
+        filter
+    }
+
+    /// Creates a filter called `name` that matches files with any of the given MIME types.
+    ///
+    /// MIME types can contain wildcards for the subtype, e.g. `image/*`.
+    pub fn from_mime_types(name: &str, mime_types: &[&str]) -> Self {
+        let filter = Self::new();
+        filter.set_name(Some(name));
+        for mime_type in mime_types {
+            filter.add_mime_type(mime_type);
+        }
+        filter
+    }
+
+    /// Creates a filter called `name` that matches files whose name matches any of the given
+    /// shell-style glob `patterns`.
+    pub fn from_patterns(name: &str, patterns: &[&str]) -> Self {
+        let filter = Self::new();
+        filter.set_name(Some(name));
+        for pattern in patterns {
+            filter.add_pattern(pattern);
+        }
+        filter
+    }
+}
+
+// Glob patterns are case-sensitive, so each letter of the extension is matched by a bracket
+// expression containing both cases. Case mappings to several characters, e.g. `ß` to `SS`,
+// can't be expressed in a bracket expression and are left out.
+fn extension_pattern(extension: &str) -> String {
+    let mut pattern = String::from("*.");
+    for c in extension.trim_start_matches('.').chars() {
+        let mut variants = Vec::new();
+        for mapping in &[c.to_lowercase().to_string(), c.to_uppercase().to_string()] {
+            let mut chars = mapping.chars();
+            if let (Some(variant), None) = (chars.next(), chars.next()) {
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            }
+        }
+        if !variants.contains(&c) {
+            variants.push(c);
+        }
+
+        // Metacharacters are matched literally inside a bracket expression
+        if variants.len() > 1 || matches!(c, '*' | '?' | '[') {
+            pattern.push('[');
+            pattern.extend(variants);
+            pattern.push(']');
+        } else {
+            pattern.push(c);
+        }
+    }
+    pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extension_pattern;
+
+    #[test]
+    fn extension_patterns() {
+        assert_eq!(extension_pattern("jpg"), "*.[jJ][pP][gG]");
+        assert_eq!(extension_pattern(".Tar.gz"), "*.[tT][aA][rR].[gG][zZ]");
+        assert_eq!(extension_pattern("7z"), "*.7[zZ]");
+        assert_eq!(extension_pattern("a*?[b"), "*.[aA][*][?][[][bB]");
+        assert_eq!(extension_pattern("ß"), "*.ß");
+        assert_eq!(extension_pattern("straße"), "*.[sS][tT][rR][aA]ß[eE]");
+    }
+}
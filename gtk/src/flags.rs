@@ -0,0 +1,45 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+use crate::EventControllerScrollFlags;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+use crate::FontChooserLevel;
+#[cfg(any(feature = "v3_20", feature = "dox"))]
+use crate::StyleContextPrintFlags;
+use crate::{
+    AccelFlags, ApplicationInhibitFlags, CalendarDisplayOptions, CellRendererState, DestDefaults,
+    DialogFlags, FileFilterFlags, IconLookupFlags, InputHints, JunctionSides, PlacesOpenFlags,
+    RecentFilterFlags, RegionFlags, StateFlags, TargetFlags, TextSearchFlags,
+    ToolPaletteDragTargets, TreeModelFlags,
+};
+
+// The conversions of the generated flags, see `glib::flags_conversions!`.
+glib::flags_conversions! {
+    AccelFlags,
+    ApplicationInhibitFlags,
+    CalendarDisplayOptions,
+    CellRendererState,
+    DestDefaults,
+    DialogFlags,
+    #[cfg(any(feature = "v3_24", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
+    EventControllerScrollFlags,
+    FileFilterFlags,
+    #[cfg(any(feature = "v3_24", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_24")))]
+    FontChooserLevel,
+    IconLookupFlags,
+    InputHints,
+    JunctionSides,
+    PlacesOpenFlags,
+    RecentFilterFlags,
+    RegionFlags,
+    StateFlags,
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    StyleContextPrintFlags,
+    TargetFlags,
+    TextSearchFlags,
+    ToolPaletteDragTargets,
+    TreeModelFlags,
+}
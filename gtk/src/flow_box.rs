@@ -9,6 +9,19 @@ pub trait FlowBoxExtManual: 'static {
     #[cfg(any(feature = "v3_18", feature = "dox"))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_18")))]
     fn unbind_model(&self);
+
+    // rustdoc-stripper-ignore-next
+    /// Binds `model` like `bind_model`, passing an [`ItemBindings`](crate::ItemBindings) to
+    /// `create_widget_func` to bind properties of the created widgets to the item.
+    ///
+    /// The bindings are connected to the item once the widget is created and disconnected when
+    /// the widget is destroyed, e.g. because the item was removed from `model`.
+    #[cfg(any(feature = "v3_18", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_18")))]
+    fn bind_model_with_bindings<P, F>(&self, model: Option<&P>, create_widget_func: F)
+    where
+        P: IsA<gio::ListModel>,
+        F: Fn(&glib::Object, &crate::ItemBindings) -> crate::Widget + 'static;
 }
 
 impl<O: IsA<FlowBox>> FlowBoxExtManual for O {
@@ -25,4 +38,22 @@ impl<O: IsA<FlowBox>> FlowBoxExtManual for O {
             )
         }
     }
+
+    #[cfg(any(feature = "v3_18", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_18")))]
+    fn bind_model_with_bindings<P, F>(&self, model: Option<&P>, create_widget_func: F)
+    where
+        P: IsA<gio::ListModel>,
+        F: Fn(&glib::Object, &crate::ItemBindings) -> crate::Widget + 'static,
+    {
+        use crate::prelude::*;
+
+        self.as_ref().bind_model(model, move |item| {
+            let bindings = crate::ItemBindings::new();
+            let widget = create_widget_func(item, &bindings);
+            bindings.set_item(Some(item));
+            widget.connect_destroy(move |_| bindings.set_item(None::<&glib::Object>));
+            widget
+        })
+    }
 }
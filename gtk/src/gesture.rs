@@ -0,0 +1,76 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{EventSequenceState, Gesture, GestureExt};
+use glib::object::IsA;
+use glib::translate::*;
+
+pub trait GestureExtManual: 'static {
+    /// Returns the sequences currently handled by the gesture, including pointer events.
+    ///
+    /// Pointer events aren't part of a sequence, which is represented by `None`. Unlike
+    /// [`GestureExt::get_sequences`] this can be used while the gesture handles pointer events.
+    #[doc(alias = "gtk_gesture_get_sequences")]
+    fn get_sequences_with_pointer(&self) -> Vec<Option<gdk::EventSequence>>;
+
+    /// Returns the last point and the state of each sequence currently handled by the gesture,
+    /// e.g. to follow all touches of a multi-touch gesture.
+    ///
+    /// The points are in the coordinates of the widget of the gesture.
+    fn get_sequence_points(&self) -> Vec<SequencePoint>;
+}
+
+impl<O: IsA<Gesture>> GestureExtManual for O {
+    fn get_sequences_with_pointer(&self) -> Vec<Option<gdk::EventSequence>> {
+        unsafe {
+            let list = ffi::gtk_gesture_get_sequences(self.as_ref().to_glib_none().0);
+            let mut sequences = Vec::new();
+            let mut item = list;
+            while !item.is_null() {
+                sequences.push(from_glib_none(
+                    (*item).data as *mut gdk::ffi::GdkEventSequence,
+                ));
+                item = (*item).next;
+            }
+            glib::ffi::g_list_free(list);
+            sequences
+        }
+    }
+
+    fn get_sequence_points(&self) -> Vec<SequencePoint> {
+        self.get_sequences_with_pointer()
+            .into_iter()
+            .filter_map(|sequence| {
+                let (x, y) = self.get_point(sequence.as_ref())?;
+                let state = match sequence {
+                    Some(ref sequence) => self.get_sequence_state(sequence),
+                    None => unsafe {
+                        from_glib(ffi::gtk_gesture_get_sequence_state(
+                            self.as_ref().to_glib_none().0,
+                            std::ptr::null_mut(),
+                        ))
+                    },
+                };
+                Some(SequencePoint {
+                    sequence,
+                    x,
+                    y,
+                    state,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A sequence handled by a [`Gesture`], returned by
+/// [`GestureExtManual::get_sequence_points`].
+#[derive(Debug, Clone)]
+pub struct SequencePoint {
+    /// The sequence, or `None` for pointer events.
+    pub sequence: Option<gdk::EventSequence>,
+    /// The x coordinate of the last event of the sequence.
+    pub x: f64,
+    /// The y coordinate of the last event of the sequence.
+    pub y: f64,
+    /// Whether the gesture claimed or denied the sequence.
+    pub state: EventSequenceState,
+}
@@ -0,0 +1,20 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{GestureDrag, GestureDragExt};
+use glib::object::IsA;
+
+pub trait GestureDragExtManual: 'static {
+    /// Returns the point the drag currently is at, i.e. the start point moved by the offset, in
+    /// the coordinates of the widget of the gesture.
+    ///
+    /// Returns `None` if the gesture is not active.
+    fn get_current_point(&self) -> Option<(f64, f64)>;
+}
+
+impl<O: IsA<GestureDrag>> GestureDragExtManual for O {
+    fn get_current_point(&self) -> Option<(f64, f64)> {
+        let (start_x, start_y) = self.get_start_point()?;
+        let (offset_x, offset_y) = self.get_offset()?;
+        Some((start_x + offset_x, start_y + offset_y))
+    }
+}
@@ -0,0 +1,69 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{DirectionType, GestureSwipe};
+use glib::object::{Cast, IsA};
+use glib::signal::SignalHandlerId;
+
+pub trait GestureSwipeExtManual: 'static {
+    /// Connects to the `swipe` signal, passing the direction the velocity mostly points to
+    /// together with the velocity, e.g. for switching between pages.
+    ///
+    /// The direction is one of `DirectionType::Up`, `Down`, `Left` and `Right`. Swipes without
+    /// any velocity are ignored.
+    fn connect_swipe_direction<F: Fn(&Self, DirectionType, f64, f64) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+}
+
+impl<O: IsA<GestureSwipe>> GestureSwipeExtManual for O {
+    fn connect_swipe_direction<F: Fn(&Self, DirectionType, f64, f64) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        self.as_ref()
+            .connect_swipe(move |gesture, velocity_x, velocity_y| {
+                if let Some(direction) = main_direction(velocity_x, velocity_y) {
+                    f(
+                        unsafe { gesture.unsafe_cast_ref() },
+                        direction,
+                        velocity_x,
+                        velocity_y,
+                    );
+                }
+            })
+    }
+}
+
+// Returns the direction `(x, y)` mostly points to in widget coordinates, or `None` if it is
+// zero or not a number
+pub(crate) fn main_direction(x: f64, y: f64) -> Option<DirectionType> {
+    if x.is_nan() || y.is_nan() || (x == 0.0 && y == 0.0) {
+        None
+    } else if x.abs() >= y.abs() {
+        Some(if x < 0.0 {
+            DirectionType::Left
+        } else {
+            DirectionType::Right
+        })
+    } else if y < 0.0 {
+        Some(DirectionType::Up)
+    } else {
+        Some(DirectionType::Down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn main_directions() {
+        assert_eq!(main_direction(0.0, 0.0), None);
+        assert_eq!(main_direction(f64::NAN, 1.0), None);
+        assert_eq!(main_direction(-3.0, 1.0), Some(DirectionType::Left));
+        assert_eq!(main_direction(3.0, -3.0), Some(DirectionType::Right));
+        assert_eq!(main_direction(1.0, -3.0), Some(DirectionType::Up));
+        assert_eq!(main_direction(0.0, 0.5), Some(DirectionType::Down));
+    }
+}
@@ -0,0 +1,23 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{GestureExt, GestureZoom};
+use glib::object::IsA;
+
+pub trait GestureZoomExtManual: 'static {
+    /// Returns the scale of the current distance between the touches relative to the distance
+    /// when the zoom started.
+    ///
+    /// Unlike [`get_scale_delta`](GestureZoom::get_scale_delta), which returns 1.0 then, this
+    /// returns `None` if no zoom is recognized.
+    fn get_active_scale_delta(&self) -> Option<f64>;
+}
+
+impl<O: IsA<GestureZoom>> GestureZoomExtManual for O {
+    fn get_active_scale_delta(&self) -> Option<f64> {
+        if self.is_recognized() {
+            Some(self.as_ref().get_scale_delta())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{
+    ButtonExt, HeaderBar, HeaderBarExt, IconSize, Image, MenuButton, MenuButtonExt, WidgetExt,
+    Window, WindowExt,
+};
+use glib::object::IsA;
+
+const PRIMARY_MENU_ICON_NAME: &str = "open-menu-symbolic";
+
+impl HeaderBar {
+    /// Creates a header bar showing `title` and `subtitle` and sets it as titlebar of `window`.
+    ///
+    /// The header bar shows the window controls, and as client-side decoration of the window it
+    /// handles moving the window and double-clicks according to the desktop settings.
+    pub fn for_window<W: IsA<Window>>(
+        window: &W,
+        title: Option<&str>,
+        subtitle: Option<&str>,
+    ) -> Self {
+        let header_bar = Self::new();
+        header_bar.set_title(title);
+        header_bar.set_subtitle(subtitle);
+        header_bar.set_show_close_button(true);
+        header_bar.show();
+        window.set_titlebar(Some(&header_bar));
+        header_bar
+    }
+}
+
+pub trait HeaderBarExtManual: 'static {
+    /// Adds a menu button showing `menu` at the end of the header bar, as the primary menu of
+    /// the window.
+    fn add_primary_menu<P: IsA<gio::MenuModel>>(&self, menu: &P) -> MenuButton;
+
+    /// Adds a menu button showing `menu` at the end of the header bar and makes `actions`
+    /// available to the menu items with `prefix`, e.g. `win` for items with the action
+    /// `win.about`.
+    fn add_primary_menu_with_actions<P: IsA<gio::MenuModel>, G: IsA<gio::ActionGroup>>(
+        &self,
+        menu: &P,
+        prefix: &str,
+        actions: &G,
+    ) -> MenuButton;
+}
+
+impl<O: IsA<HeaderBar>> HeaderBarExtManual for O {
+    fn add_primary_menu<P: IsA<gio::MenuModel>>(&self, menu: &P) -> MenuButton {
+        let button = MenuButton::new();
+        button.set_menu_model(Some(menu));
+        button.set_image(Some(&Image::from_icon_name(
+            Some(PRIMARY_MENU_ICON_NAME),
+            IconSize::Button,
+        )));
+        button.show_all();
+        self.pack_end(&button);
+        button
+    }
+
+    fn add_primary_menu_with_actions<P: IsA<gio::MenuModel>, G: IsA<gio::ActionGroup>>(
+        &self,
+        menu: &P,
+        prefix: &str,
+        actions: &G,
+    ) -> MenuButton {
+        let button = self.add_primary_menu(menu);
+        button.insert_action_group(prefix, Some(actions));
+        button
+    }
+}
@@ -0,0 +1,319 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{IMContext, IMContextExt, Inhibit, Widget, WidgetExt};
+use glib::object::{Cast, IsA, ObjectExt, WeakRef};
+use glib::signal::SignalHandlerId;
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+/// The text editing side of an [`InputMethodHandler`], implemented by custom text entry
+/// widgets.
+///
+/// Only `commit()` is required, the other methods allow input methods to show the text being
+/// composed inline, to position their popups next to the cursor and to take the text around the
+/// cursor into account.
+pub trait TextInput: 'static {
+    type Widget: IsA<Widget>;
+
+    /// Inserts the finished `text` at the cursor, replacing the selection, if any.
+    fn commit(&self, widget: &Self::Widget, text: &str);
+
+    /// Shows the text currently being composed at the cursor, or removes it if `preedit` is
+    /// empty.
+    ///
+    /// `cursor_pos` is the position of the cursor inside `preedit` in characters. The
+    /// preedit text is not part of the content of the widget until it is committed.
+    fn preedit_changed(
+        &self,
+        widget: &Self::Widget,
+        preedit: &str,
+        attrs: &pango::AttrList,
+        cursor_pos: i32,
+    ) {
+        let _ = (widget, preedit, attrs, cursor_pos);
+    }
+
+    /// Returns the text around the cursor and the byte index of the cursor in it.
+    ///
+    /// Usually this is the paragraph containing the cursor, without the preedit text.
+    fn surrounding(&self, widget: &Self::Widget) -> Option<(String, i32)> {
+        let _ = widget;
+        None
+    }
+
+    /// Deletes `n_chars` characters starting `offset` characters from the cursor, which may
+    /// be negative, and returns `true` if the text was deleted.
+    fn delete_surrounding(&self, widget: &Self::Widget, offset: i32, n_chars: i32) -> bool {
+        let _ = (widget, offset, n_chars);
+        false
+    }
+
+    /// Returns the area of the cursor in widget coordinates.
+    fn cursor_location(&self, widget: &Self::Widget) -> Option<gdk::Rectangle> {
+        let _ = widget;
+        None
+    }
+}
+
+/// Connects an [`IMContext`] to a widget implementing text input.
+///
+/// This takes care of the details of the interaction between widget and input method that are
+/// easy to get wrong:
+///
+/// * the client window of the input method follows the realization of the widget,
+/// * key presses *and* releases are passed to the input method before the widget handles
+///   them, and are not handled by the widget anymore if the input method consumed them,
+/// * focus changes are forwarded, resetting the input method when the widget loses the focus,
+/// * the cursor location is passed in coordinates of the client window, also for widgets
+///   without their own `gdk::Window`.
+///
+/// As the key handlers are connected to the `key-press-event` and `key-release-event` signals,
+/// they run before the class handlers of the widget and before handlers connected after
+/// creating the `InputMethodHandler`.
+///
+/// The widget has to call [`reset()`](Self::reset) whenever the text or cursor is changed
+/// other than through the input method, and
+/// [`update_cursor_location()`](Self::update_cursor_location) whenever the cursor moves.
+///
+/// Dropping the handler disconnects the input method from the widget.
+///
+/// ```no_run
+/// use gtk::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// struct Editor {
+///     text: Rc<RefCell<String>>,
+/// }
+///
+/// impl gtk::TextInput for Editor {
+///     type Widget = gtk::DrawingArea;
+///
+///     fn commit(&self, widget: &gtk::DrawingArea, text: &str) {
+///         self.text.borrow_mut().push_str(text);
+///         widget.queue_draw();
+///     }
+/// }
+///
+/// struct EditorWidget {
+///     area: gtk::DrawingArea,
+///     // Has to live as long as the widget, dropping it disconnects the input method
+///     input_method: gtk::InputMethodHandler<Editor>,
+/// }
+///
+/// fn build() -> EditorWidget {
+///     let area = gtk::DrawingArea::new();
+///     area.set_can_focus(true);
+///     area.add_events(gdk::EventMask::KEY_PRESS_MASK | gdk::EventMask::KEY_RELEASE_MASK);
+///
+///     let text = Rc::new(RefCell::new(String::new()));
+///     let im_context = gtk::IMMulticontext::new();
+///     let input_method = gtk::InputMethodHandler::new(&area, &im_context, Editor { text });
+///
+///     EditorWidget { area, input_method }
+/// }
+/// ```
+pub struct InputMethodHandler<T: TextInput> {
+    state: Rc<State<T>>,
+    widget_handler_ids: Vec<SignalHandlerId>,
+    im_handler_ids: Vec<SignalHandlerId>,
+}
+
+struct State<T: TextInput> {
+    widget: WeakRef<T::Widget>,
+    im_context: IMContext,
+    input: T,
+    // Set when the input method consumed a key press or the widget got the focus, like in
+    // `GtkEntry`
+    need_reset: Cell<bool>,
+}
+
+impl<T: TextInput> State<T> {
+    fn update_cursor_location(&self, widget: &T::Widget) {
+        let mut area = match self.input.cursor_location(widget) {
+            Some(area) => area,
+            None => return,
+        };
+        let widget = widget.upcast_ref::<Widget>();
+        if !widget.get_has_window() {
+            let allocation = widget.get_allocation();
+            area.x += allocation.x;
+            area.y += allocation.y;
+        }
+        self.im_context.set_cursor_location(&area);
+    }
+
+    fn filter_key(&self, event: &gdk::EventKey) -> Inhibit {
+        if self.im_context.filter_keypress(event) {
+            if event.get_event_type() == gdk::EventType::KeyPress {
+                self.need_reset.set(true);
+            }
+            Inhibit(true)
+        } else {
+            Inhibit(false)
+        }
+    }
+}
+
+impl<T: TextInput> InputMethodHandler<T> {
+    pub fn new<P: IsA<IMContext>>(widget: &T::Widget, im_context: &P, input: T) -> Self {
+        let state = Rc::new(State {
+            widget: widget.downgrade(),
+            im_context: im_context.as_ref().clone(),
+            input,
+            need_reset: Cell::new(false),
+        });
+        let im_context = &state.im_context;
+        let widget = widget.upcast_ref::<Widget>();
+
+        let mut widget_handler_ids = Vec::new();
+        let state_clone = state.clone();
+        widget_handler_ids.push(widget.connect_realize(move |widget| {
+            state_clone
+                .im_context
+                .set_client_window(widget.get_window().as_ref());
+        }));
+        let state_clone = state.clone();
+        widget_handler_ids.push(widget.connect_unrealize(move |_| {
+            state_clone.im_context.set_client_window(None);
+        }));
+        let state_clone = state.clone();
+        widget_handler_ids.push(widget.connect_focus_in_event(move |_, _| {
+            state_clone.need_reset.set(true);
+            state_clone.im_context.focus_in();
+            if let Some(widget) = state_clone.widget.upgrade() {
+                state_clone.update_cursor_location(&widget);
+            }
+            Inhibit(false)
+        }));
+        let state_clone = state.clone();
+        widget_handler_ids.push(widget.connect_focus_out_event(move |_, _| {
+            // Discard the text being composed instead of keeping it for when the focus returns
+            state_clone.need_reset.set(false);
+            state_clone.im_context.focus_out();
+            state_clone.im_context.reset();
+            Inhibit(false)
+        }));
+        let state_clone = state.clone();
+        widget_handler_ids
+            .push(widget.connect_key_press_event(move |_, event| state_clone.filter_key(event)));
+        let state_clone = state.clone();
+        widget_handler_ids
+            .push(widget.connect_key_release_event(move |_, event| state_clone.filter_key(event)));
+
+        let mut im_handler_ids = Vec::new();
+        let state_clone = state.clone();
+        im_handler_ids.push(im_context.connect_commit(move |_, text| {
+            if let Some(widget) = state_clone.widget.upgrade() {
+                state_clone.input.commit(&widget, text);
+                state_clone.update_cursor_location(&widget);
+            }
+        }));
+        let state_clone = state.clone();
+        im_handler_ids.push(im_context.connect_preedit_changed(move |im_context| {
+            if let Some(widget) = state_clone.widget.upgrade() {
+                let (preedit, attrs, cursor_pos) = im_context.get_preedit_string();
+                state_clone
+                    .input
+                    .preedit_changed(&widget, &preedit, &attrs, cursor_pos);
+                state_clone.update_cursor_location(&widget);
+            }
+        }));
+        let state_clone = state.clone();
+        im_handler_ids.push(im_context.connect_retrieve_surrounding(move |im_context| {
+            let surrounding = state_clone
+                .widget
+                .upgrade()
+                .and_then(|widget| state_clone.input.surrounding(&widget));
+            match surrounding {
+                Some((text, cursor_index)) => {
+                    im_context.set_surrounding(&text, cursor_index);
+                    true
+                }
+                None => false,
+            }
+        }));
+        let state_clone = state.clone();
+        im_handler_ids.push(
+            im_context.connect_delete_surrounding(move |_, offset, n_chars| {
+                match state_clone.widget.upgrade() {
+                    Some(widget) => state_clone
+                        .input
+                        .delete_surrounding(&widget, offset, n_chars),
+                    None => false,
+                }
+            }),
+        );
+
+        if widget.get_realized() {
+            im_context.set_client_window(widget.get_window().as_ref());
+        }
+        if widget.has_focus() {
+            im_context.focus_in();
+        }
+
+        Self {
+            state,
+            widget_handler_ids,
+            im_handler_ids,
+        }
+    }
+
+    /// Returns the input method context.
+    pub fn get_im_context(&self) -> &IMContext {
+        &self.state.im_context
+    }
+
+    /// Returns the text input implementation.
+    pub fn get_input(&self) -> &T {
+        &self.state.input
+    }
+
+    /// Resets the input method after the text or the cursor was changed by other means than
+    /// the input method, discarding the text being composed.
+    ///
+    /// The reset is skipped if the input method didn't consume any key presses since the last
+    /// reset, as resetting can be expensive.
+    #[doc(alias = "gtk_im_context_reset")]
+    pub fn reset(&self) {
+        if self.state.need_reset.replace(false) {
+            self.state.im_context.reset();
+        }
+    }
+
+    /// Passes the current cursor location of the widget to the input method.
+    #[doc(alias = "gtk_im_context_set_cursor_location")]
+    pub fn update_cursor_location(&self) {
+        if let Some(widget) = self.state.widget.upgrade() {
+            self.state.update_cursor_location(&widget);
+        }
+    }
+}
+
+impl<T: TextInput> Drop for InputMethodHandler<T> {
+    fn drop(&mut self) {
+        if let Some(widget) = self.state.widget.upgrade() {
+            let widget = widget.upcast::<Widget>();
+            for handler_id in self.widget_handler_ids.drain(..) {
+                widget.disconnect(handler_id);
+            }
+            if widget.has_focus() {
+                self.state.im_context.focus_out();
+            }
+        }
+        for handler_id in self.im_handler_ids.drain(..) {
+            self.state.im_context.disconnect(handler_id);
+        }
+        self.state.im_context.set_client_window(None);
+    }
+}
+
+impl<T: TextInput> fmt::Debug for InputMethodHandler<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InputMethodHandler")
+            .field("widget", &self.state.widget.upgrade())
+            .field("im_context", &self.state.im_context)
+            .finish()
+    }
+}
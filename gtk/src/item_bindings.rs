@@ -0,0 +1,248 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::object::{IsA, ObjectExt, WeakRef};
+use glib::{Object, SignalHandlerId, Value};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+type TransformFunc = dyn Fn(&Value) -> Option<Value>;
+
+/// Keeps properties of the widgets of a list row bound to the properties of the item the row
+/// currently shows.
+///
+/// Bindings are declared once with [`bind`](Self::bind) when the row widget is created, and
+/// [`set_item`](Self::set_item) connects them to an item. Setting another item, e.g. when a row
+/// widget is recycled for another item, or `None` disconnects all `notify` handlers from the
+/// previous item, so neither the item keeps handlers for rows it isn't shown in anymore nor the
+/// handlers keep the row widgets alive. The bound widgets are only referenced weakly.
+///
+/// [`ListBoxExtManual::bind_model_with_bindings`](crate::prelude::ListBoxExtManual::bind_model_with_bindings)
+/// and [`FlowBoxExtManual::bind_model_with_bindings`](crate::prelude::FlowBoxExtManual::bind_model_with_bindings)
+/// take care of setting the item of each row and of clearing it once the row is destroyed.
+#[derive(Clone, Default)]
+pub struct ItemBindings {
+    inner: Rc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    bindings: RefCell<Vec<Rc<Binding>>>,
+    item: RefCell<Option<(Object, Vec<SignalHandlerId>)>>,
+}
+
+struct Binding {
+    item_property: String,
+    target: WeakRef<Object>,
+    target_property: String,
+    transform: Option<Box<TransformFunc>>,
+}
+
+impl Binding {
+    fn update(&self, item: &Object) {
+        let target = match self.target.upgrade() {
+            Some(target) => target,
+            None => return,
+        };
+        let value = match item.get_property(self.item_property.as_str()) {
+            Ok(value) => value,
+            Err(err) => {
+                glib::g_critical!("Gtk", "Failed to bind item property: {}", err);
+                return;
+            }
+        };
+        let value = match self.transform {
+            Some(ref transform) => match transform(&value) {
+                Some(value) => value,
+                None => return,
+            },
+            None => value,
+        };
+        if let Err(err) = target.set_property_from_value(self.target_property.as_str(), &value) {
+            glib::g_critical!("Gtk", "Failed to set bound property: {}", err);
+        }
+    }
+}
+
+impl ItemBindings {
+    /// Creates a new set of bindings without an item.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `target_property` of `target` to `item_property` of the item.
+    pub fn bind<T: IsA<Object>>(&self, item_property: &str, target: &T, target_property: &str) {
+        self.add_binding(item_property, target, target_property, None);
+    }
+
+    /// Binds `target_property` of `target` to `item_property` of the item, converting the
+    /// values with `transform`.
+    ///
+    /// The target property is left unchanged if `transform` returns `None`.
+    pub fn bind_with<T: IsA<Object>, F: Fn(&Value) -> Option<Value> + 'static>(
+        &self,
+        item_property: &str,
+        target: &T,
+        target_property: &str,
+        transform: F,
+    ) {
+        self.add_binding(
+            item_property,
+            target,
+            target_property,
+            Some(Box::new(transform)),
+        );
+    }
+
+    fn add_binding<T: IsA<Object>>(
+        &self,
+        item_property: &str,
+        target: &T,
+        target_property: &str,
+        transform: Option<Box<TransformFunc>>,
+    ) {
+        let binding = Rc::new(Binding {
+            item_property: item_property.to_owned(),
+            target: target.upcast_ref::<Object>().downgrade(),
+            target_property: target_property.to_owned(),
+            transform,
+        });
+
+        if let Some(item) = self.get_item() {
+            binding.update(&item);
+            let handler_id = connect_binding(&item, &binding);
+            if let Some((_, ref mut handler_ids)) = *self.inner.item.borrow_mut() {
+                handler_ids.push(handler_id);
+            }
+        }
+        self.inner.bindings.borrow_mut().push(binding);
+    }
+
+    /// Returns the item the bindings are currently connected to.
+    pub fn get_item(&self) -> Option<Object> {
+        self.inner
+            .item
+            .borrow()
+            .as_ref()
+            .map(|(item, _)| item.clone())
+    }
+
+    /// Connects the bindings to `item`, after disconnecting them from the previous item.
+    ///
+    /// All bound properties are updated from `item` immediately.
+    pub fn set_item<P: IsA<Object>>(&self, item: Option<&P>) {
+        let item = item.map(|item| item.as_ref().clone());
+        if self.get_item() == item {
+            return;
+        }
+
+        if let Some((old_item, handler_ids)) = self.inner.item.borrow_mut().take() {
+            for handler_id in handler_ids {
+                old_item.disconnect(handler_id);
+            }
+        }
+
+        if let Some(item) = item {
+            let bindings = self.inner.bindings.borrow().clone();
+            let handler_ids = bindings
+                .iter()
+                .map(|binding| {
+                    binding.update(&item);
+                    connect_binding(&item, binding)
+                })
+                .collect();
+            *self.inner.item.borrow_mut() = Some((item, handler_ids));
+        }
+    }
+}
+
+fn connect_binding(item: &Object, binding: &Rc<Binding>) -> SignalHandlerId {
+    let name = binding.item_property.clone();
+    let binding = binding.clone();
+    item.connect_notify_local(Some(name.as_str()), move |item, _| binding.update(item))
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if let Some((item, handler_ids)) = self.item.get_mut().take() {
+            for handler_id in handler_ids {
+                item.disconnect(handler_id);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ItemBindings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ItemBindings")
+            .field("item", &self.get_item())
+            .field(
+                "bindings",
+                &self
+                    .inner
+                    .bindings
+                    .borrow()
+                    .iter()
+                    .map(|binding| (&binding.item_property, &binding.target_property))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gio::prelude::*;
+    use glib::ToValue;
+
+    #[test]
+    fn bindings() {
+        let first = gio::SimpleAction::new("first", None);
+        let second = gio::SimpleAction::new("second", None);
+        second.set_enabled(false);
+        let target = gio::SimpleAction::new("target", None);
+        let inverted = gio::SimpleAction::new("inverted", None);
+
+        let bindings = ItemBindings::new();
+        bindings.bind("enabled", &target, "enabled");
+        bindings.set_item(Some(&first));
+        assert_eq!(bindings.get_item(), Some(first.clone().upcast()));
+        assert!(target.get_enabled());
+        // Bindings added later are connected to the current item right away
+        bindings.bind_with("enabled", &inverted, "enabled", |value| {
+            Some((!value.get_some::<bool>().unwrap()).to_value())
+        });
+        assert!(!inverted.get_enabled());
+
+        first.set_enabled(false);
+        assert!(!target.get_enabled());
+        assert!(inverted.get_enabled());
+
+        // The previous item doesn't update the targets anymore
+        bindings.set_item(Some(&second));
+        first.set_enabled(true);
+        assert!(!target.get_enabled());
+        second.set_enabled(true);
+        assert!(target.get_enabled());
+        assert!(!inverted.get_enabled());
+
+        bindings.set_item(None::<&Object>);
+        assert_eq!(bindings.get_item(), None);
+        second.set_enabled(false);
+        assert!(target.get_enabled());
+
+        // The targets are only referenced weakly
+        bindings.set_item(Some(&first));
+        let weak_target = target.downgrade();
+        drop(target);
+        assert!(weak_target.upgrade().is_none());
+        first.set_enabled(false);
+        assert!(inverted.get_enabled());
+
+        // The handlers are disconnected together with the bindings
+        drop(bindings);
+        first.set_enabled(true);
+        assert!(inverted.get_enabled());
+    }
+}
@@ -0,0 +1,46 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::progress_bar::{Indicator, ProgressFuture};
+use crate::LevelBar;
+use futures_core::stream::Stream;
+use glib::object::IsA;
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait LevelBarExtManual: 'static {
+    /// Shows the fractions between `0.0` and `1.0` received from `progress` while `future` is
+    /// running, and fills the level bar once `future` resolved successfully or empties it if
+    /// `future` resolved to an error.
+    ///
+    /// The fractions are mapped to the range between the minimum and maximum value of the
+    /// level bar. The returned future resolves to the output of `future`.
+    fn track_future_with_progress<
+        T,
+        E,
+        F: Future<Output = Result<T, E>> + 'static,
+        S: Stream<Item = f64> + 'static,
+    >(
+        &self,
+        future: F,
+        progress: S,
+    ) -> Pin<Box<dyn Future<Output = Result<T, E>> + 'static>>;
+}
+
+impl<O: IsA<LevelBar>> LevelBarExtManual for O {
+    fn track_future_with_progress<
+        T,
+        E,
+        F: Future<Output = Result<T, E>> + 'static,
+        S: Stream<Item = f64> + 'static,
+    >(
+        &self,
+        future: F,
+        progress: S,
+    ) -> Pin<Box<dyn Future<Output = Result<T, E>> + 'static>> {
+        Box::pin(ProgressFuture::new(
+            Indicator::LevelBar(self.as_ref().clone()),
+            future,
+            Some(Box::pin(progress)),
+        ))
+    }
+}
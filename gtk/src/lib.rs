@@ -197,25 +197,45 @@ mod application_window;
 mod border;
 mod buildable;
 mod builder;
+mod calendar;
 mod cell_renderer_pixbuf;
 mod clipboard;
 mod color_button;
 mod color_chooser;
 mod combo_box;
+mod date_picker;
 mod dialog;
 mod drag_context;
 mod entry;
 mod entry_buffer;
 mod entry_completion;
+mod entry_validator;
 mod enums;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+mod event_controller_key;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+mod event_controller_motion;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+mod event_controller_scroll;
+mod file_chooser;
 mod file_chooser_dialog;
+mod file_filter;
 mod fixed;
+mod flags;
 #[cfg(any(feature = "v3_18", feature = "dox"))]
 mod flow_box;
+mod gesture;
+mod gesture_drag;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 mod gesture_stylus;
+mod gesture_swipe;
+mod gesture_zoom;
+mod header_bar;
 mod im_context_simple;
+mod input_method;
 mod invisible;
+mod item_bindings;
+mod level_bar;
 #[cfg(any(feature = "v3_16", feature = "dox"))]
 mod list_box;
 mod list_store;
@@ -227,7 +247,9 @@ mod pad_action_entry;
 #[cfg(any(feature = "v3_22", feature = "dox"))]
 mod pad_controller;
 mod page_range;
+mod popover;
 mod print_settings;
+mod progress_bar;
 mod radio_button;
 mod radio_menu_item;
 mod radio_tool_button;
@@ -235,19 +257,26 @@ mod recent_chooser_dialog;
 mod recent_data;
 mod requisition;
 mod response_type;
+mod scrolled_window;
 mod selection_data;
 mod signal;
+mod size_group;
 mod style_context;
 mod switch;
 mod target_entry;
 mod target_list;
 mod text_buffer;
 mod text_iter;
+mod toast;
 mod tree_model_filter;
+mod tree_model_sort;
 mod tree_path;
 mod tree_row_reference;
 mod tree_sortable;
 mod tree_store;
+mod tree_view;
+mod typed_adjustment;
+mod typed_dialog;
 mod widget;
 mod window;
 
@@ -256,6 +285,12 @@ pub mod subclass;
 
 pub mod prelude;
 
+pub mod state;
+
+#[cfg(any(feature = "canvas", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "canvas")))]
+pub mod canvas;
+
 pub use crate::auto::functions::*;
 pub use crate::auto::*;
 pub use crate::rt::*;
@@ -265,14 +300,26 @@ pub use gdk::Rectangle as Allocation;
 pub use gdk::Rectangle;
 
 pub use crate::app_chooser::AppChooser;
+pub use crate::application::WindowListChange;
 pub use crate::border::Border;
+pub use crate::builder::BuilderObjectError;
+pub use crate::date_picker::DatePicker;
 pub use crate::entry_buffer::EntryBuffer;
+pub use crate::entry_validator::EntryValidator;
+pub use crate::gesture::SequencePoint;
+pub use crate::input_method::{InputMethodHandler, TextInput};
+pub use crate::item_bindings::ItemBindings;
 pub use crate::page_range::PageRange;
+pub use crate::popover::PopupOptions;
 pub use crate::recent_data::RecentData;
 pub use crate::requisition::Requisition;
 pub use crate::response_type::ResponseType;
+pub use crate::size_group::{FormAlignment, SizeGroupMembership};
 pub use crate::target_entry::TargetEntry;
+pub use crate::toast::{Toast, ToastOverlay};
 pub use crate::tree_sortable::SortColumn;
-pub use crate::widget::TickCallbackId;
+pub use crate::typed_adjustment::{AdjustmentValue, TypedAdjustment};
+pub use crate::typed_dialog::{TypedDialog, TypedDialogBuilder};
+pub use crate::widget::{Ancestors, Descendants, TickCallbackId};
 #[cfg(any(feature = "v3_22", feature = "dox"))]
 pub use pad_action_entry::PadActionEntry;
@@ -0,0 +1,147 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(feature = "v3_20", feature = "dox"))]
+use crate::PopoverConstraint;
+use crate::{Popover, PopoverExt, PositionType, Widget, WidgetExt};
+use glib::object::IsA;
+
+/// How a popover opened with [`PopoverExtManual::popup_at_with`] is placed and dismissed.
+///
+/// The defaults are the ones of a newly created popover, which suit context menus.
+///
+/// ```no_run
+/// use gtk::prelude::*;
+///
+/// # fn run(popover: &gtk::Popover, button: &gtk::Button) {
+/// let options = gtk::PopupOptions::new()
+///     .position(gtk::PositionType::Bottom)
+///     .auto_dismiss(false);
+/// popover.popup_at_with(button, None, &options);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PopupOptions {
+    position: PositionType,
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    constrain_to: PopoverConstraint,
+    auto_dismiss: bool,
+}
+
+impl PopupOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the preferred side of the rectangle the popover appears on.
+    ///
+    /// GTK picks the opposite side if there is not enough space. Defaults to
+    /// `PositionType::Top`.
+    pub fn position(mut self, position: PositionType) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets what the popover is kept inside of. Defaults to `PopoverConstraint::Window`.
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    pub fn constrain_to(mut self, constraint: PopoverConstraint) -> Self {
+        self.constrain_to = constraint;
+        self
+    }
+
+    /// Sets whether the popover is dismissed by clicking outside of it or pressing Escape.
+    ///
+    /// Such popovers are modal and grab the keyboard while they are shown. Defaults to `true`.
+    pub fn auto_dismiss(mut self, auto_dismiss: bool) -> Self {
+        self.auto_dismiss = auto_dismiss;
+        self
+    }
+
+    pub fn get_position(&self) -> PositionType {
+        self.position
+    }
+
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    pub fn get_constrain_to(&self) -> PopoverConstraint {
+        self.constrain_to
+    }
+
+    pub fn get_auto_dismiss(&self) -> bool {
+        self.auto_dismiss
+    }
+
+    fn apply<O: IsA<Popover>>(&self, popover: &O) {
+        popover.set_position(self.position);
+        #[cfg(any(feature = "v3_20", feature = "dox"))]
+        popover.set_constrain_to(self.constrain_to);
+        popover.set_modal(self.auto_dismiss);
+    }
+}
+
+impl Default for PopupOptions {
+    fn default() -> Self {
+        Self {
+            position: PositionType::Top,
+            #[cfg(any(feature = "v3_20", feature = "dox"))]
+            constrain_to: PopoverConstraint::Window,
+            auto_dismiss: true,
+        }
+    }
+}
+
+pub trait PopoverExtManual: 'static {
+    /// Pops up the popover pointing to `rect` of `relative_to`, or to all of `relative_to` if
+    /// `rect` is `None`.
+    ///
+    /// `rect` is in the coordinates of `relative_to`. The position, constraint and modality of
+    /// the popover are kept, use [`popup_at_with`](Self::popup_at_with) to set them as well.
+    fn popup_at<P: IsA<Widget>>(&self, relative_to: &P, rect: Option<&gdk::Rectangle>);
+
+    /// Like [`popup_at`](Self::popup_at), but places and dismisses the popover according to
+    /// `options` first.
+    fn popup_at_with<P: IsA<Widget>>(
+        &self,
+        relative_to: &P,
+        rect: Option<&gdk::Rectangle>,
+        options: &PopupOptions,
+    );
+
+    /// Pops up the popover pointing to the position `x`, `y` of `relative_to`, e.g. the
+    /// position of a click opening a context menu.
+    fn popup_at_point<P: IsA<Widget>>(&self, relative_to: &P, x: f64, y: f64);
+}
+
+impl<O: IsA<Popover>> PopoverExtManual for O {
+    fn popup_at<P: IsA<Widget>>(&self, relative_to: &P, rect: Option<&gdk::Rectangle>) {
+        self.set_relative_to(Some(relative_to));
+        // The popover keeps pointing to a previously set rectangle, so it is reset explicitly
+        let rect = rect.copied().unwrap_or_else(|| {
+            gdk::Rectangle::from_size(
+                relative_to.get_allocated_width(),
+                relative_to.get_allocated_height(),
+            )
+        });
+        self.set_pointing_to(&rect);
+
+        #[cfg(any(feature = "v3_22", feature = "dox"))]
+        self.popup();
+        #[cfg(not(any(feature = "v3_22", feature = "dox")))]
+        self.show();
+    }
+
+    fn popup_at_with<P: IsA<Widget>>(
+        &self,
+        relative_to: &P,
+        rect: Option<&gdk::Rectangle>,
+        options: &PopupOptions,
+    ) {
+        options.apply(self);
+        self.popup_at(relative_to, rect);
+    }
+
+    fn popup_at_point<P: IsA<Widget>>(&self, relative_to: &P, x: f64, y: f64) {
+        let rect = gdk::Rectangle::from_point(x as i32, y as i32);
+        self.popup_at(relative_to, Some(&rect));
+    }
+}
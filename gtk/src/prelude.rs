@@ -19,8 +19,10 @@ pub use crate::auto::traits::*;
 
 pub use crate::accel_group::AccelGroupExtManual;
 pub use crate::app_chooser::AppChooserExt;
+pub use crate::application::GtkApplicationExtManual;
 pub use crate::buildable::BuildableExtManual;
 pub use crate::builder::BuilderExtManual;
+pub use crate::calendar::CalendarExtManual;
 pub use crate::cell_renderer_pixbuf::CellRendererPixbufExtManual;
 pub use crate::color_button::ColorButtonExtManual;
 pub use crate::color_chooser::ColorChooserExtManual;
@@ -29,23 +31,43 @@ pub use crate::dialog::DialogExtManual;
 pub use crate::drag_context::DragContextExtManual;
 pub use crate::entry::EntryExtManual;
 pub use crate::entry_completion::EntryCompletionExtManual;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+pub use crate::event_controller_key::EventControllerKeyExtManual;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+pub use crate::event_controller_motion::EventControllerMotionExtManual;
+#[cfg(any(feature = "v3_24", feature = "dox"))]
+pub use crate::event_controller_scroll::EventControllerScrollExtManual;
+pub use crate::file_chooser::FileChooserExtManual;
 pub use crate::fixed::FixedExtManual;
 #[cfg(any(feature = "v3_18", feature = "dox"))]
 pub use crate::flow_box::FlowBoxExtManual;
+pub use crate::gesture::GestureExtManual;
+pub use crate::gesture_drag::GestureDragExtManual;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 pub use crate::gesture_stylus::GestureStylusExtManual;
+pub use crate::gesture_swipe::GestureSwipeExtManual;
+pub use crate::gesture_zoom::GestureZoomExtManual;
+pub use crate::header_bar::HeaderBarExtManual;
 pub use crate::im_context_simple::IMContextSimpleExtManual;
 pub use crate::invisible::InvisibleExtManual;
+pub use crate::level_bar::LevelBarExtManual;
 #[cfg(any(feature = "v3_16", feature = "dox"))]
 pub use crate::list_box::ListBoxExtManual;
 pub use crate::list_store::GtkListStoreExtManual;
 pub use crate::menu::GtkMenuExtManual;
 pub use crate::notebook::NotebookExtManual;
+pub use crate::popover::PopoverExtManual;
+pub use crate::progress_bar::ProgressBarExtManual;
+pub use crate::scrolled_window::ScrolledWindowExtManual;
+pub use crate::size_group::SizeGroupExtManual;
 pub use crate::style_context::StyleContextExtManual;
 pub use crate::switch::SwitchExtManual;
 pub use crate::text_buffer::TextBufferExtManual;
+pub use crate::tree_model_filter::TreeModelFilterExtManual;
+pub use crate::tree_model_sort::TreeModelSortExtManual;
 pub use crate::tree_sortable::TreeSortableExtManual;
 pub use crate::tree_store::TreeStoreExtManual;
+pub use crate::tree_view::TreeViewExtManual;
 pub use crate::widget::{InitializingWidgetExt, WidgetExtManual};
 pub use crate::window::GtkWindowExtManual;
 
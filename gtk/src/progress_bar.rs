@@ -0,0 +1,200 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{LevelBar, LevelBarExt, ProgressBar, ProgressBarExt};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use glib::object::IsA;
+use glib::SourceId;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+const PULSE_INTERVAL: Duration = Duration::from_millis(100);
+
+pub trait ProgressBarExtManual: 'static {
+    /// Pulses the progress bar while `future` is running and fills it once `future` resolved
+    /// successfully.
+    ///
+    /// If `future` resolves to an error the progress bar is emptied instead. The returned future
+    /// resolves to the output of `future`. Pulsing starts when it is first polled and stops when
+    /// it is dropped.
+    ///
+    /// ```no_run
+    /// # async fn load(progress_bar: &gtk::ProgressBar, file: &gio::File) {
+    /// use gtk::prelude::*;
+    ///
+    /// let contents = progress_bar
+    ///     .track_future(file.load_contents_async_future())
+    ///     .await;
+    /// # }
+    /// ```
+    fn track_future<T, E, F: Future<Output = Result<T, E>> + 'static>(
+        &self,
+        future: F,
+    ) -> Pin<Box<dyn Future<Output = Result<T, E>> + 'static>>;
+
+    /// Like [`track_future`](Self::track_future) but shows the fractions between `0.0` and
+    /// `1.0` received from `progress`.
+    ///
+    /// The progress bar only pulses until the first fraction is received, so operations that
+    /// don't know their progress at the beginning can report it later.
+    fn track_future_with_progress<
+        T,
+        E,
+        F: Future<Output = Result<T, E>> + 'static,
+        S: Stream<Item = f64> + 'static,
+    >(
+        &self,
+        future: F,
+        progress: S,
+    ) -> Pin<Box<dyn Future<Output = Result<T, E>> + 'static>>;
+}
+
+impl<O: IsA<ProgressBar>> ProgressBarExtManual for O {
+    fn track_future<T, E, F: Future<Output = Result<T, E>> + 'static>(
+        &self,
+        future: F,
+    ) -> Pin<Box<dyn Future<Output = Result<T, E>> + 'static>> {
+        Box::pin(ProgressFuture::new(
+            Indicator::ProgressBar(self.as_ref().clone()),
+            future,
+            None,
+        ))
+    }
+
+    fn track_future_with_progress<
+        T,
+        E,
+        F: Future<Output = Result<T, E>> + 'static,
+        S: Stream<Item = f64> + 'static,
+    >(
+        &self,
+        future: F,
+        progress: S,
+    ) -> Pin<Box<dyn Future<Output = Result<T, E>> + 'static>> {
+        Box::pin(ProgressFuture::new(
+            Indicator::ProgressBar(self.as_ref().clone()),
+            future,
+            Some(Box::pin(progress)),
+        ))
+    }
+}
+
+// The widget showing the progress of a `ProgressFuture`
+#[derive(Debug)]
+pub(crate) enum Indicator {
+    ProgressBar(ProgressBar),
+    LevelBar(LevelBar),
+}
+
+impl Indicator {
+    fn set_fraction(&self, fraction: f64) {
+        let fraction = fraction.max(0.0).min(1.0);
+        match self {
+            Indicator::ProgressBar(progress_bar) => progress_bar.set_fraction(fraction),
+            Indicator::LevelBar(level_bar) => {
+                let (min, max) = (level_bar.get_min_value(), level_bar.get_max_value());
+                level_bar.set_value(min + fraction * (max - min));
+            }
+        }
+    }
+
+    // Level bars have no activity mode and just stay empty
+    fn start_pulsing(&self) -> Option<SourceId> {
+        match self {
+            Indicator::ProgressBar(progress_bar) => {
+                let progress_bar = progress_bar.clone();
+                progress_bar.pulse();
+                Some(glib::timeout_add_local(PULSE_INTERVAL, move || {
+                    progress_bar.pulse();
+                    glib::Continue(true)
+                }))
+            }
+            Indicator::LevelBar(_) => None,
+        }
+    }
+}
+
+pub(crate) struct ProgressFuture<F> {
+    indicator: Indicator,
+    future: Pin<Box<F>>,
+    progress: Option<Pin<Box<dyn Stream<Item = f64>>>>,
+    started: bool,
+    pulse: Option<SourceId>,
+}
+
+impl<F> ProgressFuture<F> {
+    pub(crate) fn new(
+        indicator: Indicator,
+        future: F,
+        progress: Option<Pin<Box<dyn Stream<Item = f64>>>>,
+    ) -> Self {
+        Self {
+            indicator,
+            future: Box::pin(future),
+            progress,
+            started: false,
+            pulse: None,
+        }
+    }
+
+    fn stop_pulsing(&mut self) {
+        if let Some(pulse) = self.pulse.take() {
+            glib::source_remove(pulse);
+        }
+    }
+}
+
+impl<T, E, F: Future<Output = Result<T, E>>> Future for ProgressFuture<F> {
+    type Output = Result<T, E>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Result<T, E>> {
+        let this = &mut *self;
+
+        if !this.started {
+            this.started = true;
+            this.indicator.set_fraction(0.0);
+            this.pulse = this.indicator.start_pulsing();
+        }
+
+        let mut fraction = None;
+        let mut progress_done = false;
+        if let Some(ref mut progress) = this.progress {
+            loop {
+                match progress.as_mut().poll_next(ctx) {
+                    Poll::Ready(Some(value)) => fraction = Some(value),
+                    Poll::Ready(None) => {
+                        progress_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+        if progress_done {
+            this.progress = None;
+        }
+        // Only the latest fraction is shown if several were received since the last poll
+        if let Some(fraction) = fraction {
+            this.stop_pulsing();
+            this.indicator.set_fraction(fraction);
+        }
+
+        match this.future.as_mut().poll(ctx) {
+            Poll::Ready(output) => {
+                this.stop_pulsing();
+                // A failed operation didn't complete, even if it reported some progress before
+                this.indicator
+                    .set_fraction(if output.is_ok() { 1.0 } else { 0.0 });
+                Poll::Ready(output)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<F> Drop for ProgressFuture<F> {
+    fn drop(&mut self) {
+        self.stop_pulsing();
+    }
+}
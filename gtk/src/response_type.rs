@@ -4,6 +4,7 @@ use glib::translate::{from_glib, FromGlib, ToGlib, ToGlibPtr, ToGlibPtrMut};
 use glib::value::{FromValue, FromValueOptional, SetValue};
 use glib::{StaticType, Type, Value};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ResponseType {
@@ -94,6 +95,18 @@ impl fmt::Display for ResponseType {
     }
 }
 
+// Application defined responses have no nick and are parsed from their number.
+impl FromStr for ResponseType {
+    type Err = glib::BoolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<u16>() {
+            Ok(value) => Ok(ResponseType::Other(value)),
+            Err(_) => glib::enum_from_nick(s),
+        }
+    }
+}
+
 impl StaticType for ResponseType {
     fn static_type() -> Type {
         unsafe { from_glib(ffi::gtk_response_type_get_type()) }
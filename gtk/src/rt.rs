@@ -139,3 +139,27 @@ pub fn main_quit() {
         }
     }
 }
+
+/// Returns the version of GTK the application is running against.
+///
+/// This can be newer than the version selected with the `v3_xx` features.
+pub fn runtime_version() -> glib::Version {
+    skip_assert_initialized!();
+    glib::Version::new(
+        crate::get_major_version(),
+        crate::get_minor_version(),
+        crate::get_micro_version(),
+    )
+}
+
+/// Checks that the GTK found at runtime is at least `major.minor.micro`.
+pub fn check_runtime_version(major: u32, minor: u32, micro: u32) -> Result<(), glib::VersionError> {
+    skip_assert_initialized!();
+    let found = runtime_version();
+    let required = glib::Version::new(major, minor, micro);
+    if found >= required {
+        Ok(())
+    } else {
+        Err(glib::VersionError::new(required, found))
+    }
+}
@@ -0,0 +1,259 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{
+    Adjustment, AdjustmentExt, BinExt, PositionType, ScrolledWindow, ScrolledWindowExt,
+    SettingsExt, TextIter, TextView, TextViewExt, TextWindowType, TickCallbackId, Widget,
+    WidgetExt, WidgetExtManual,
+};
+use futures_core::stream::Stream;
+use glib::object::IsA;
+use glib::{Continue, ObjectExt};
+use std::cell::Cell;
+use std::pin::Pin;
+use std::time::Duration;
+
+const SMOOTH_SCROLL_KEY: &str = "gtk-rs-smooth-scroll";
+
+pub trait ScrolledWindowExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns a `Stream` of the edges reached by scrolling, as emitted by the `edge-reached`
+    /// signal.
+    ///
+    /// The signal handler is disconnected once the stream is dropped.
+    fn edge_reached_stream(&self) -> Pin<Box<dyn Stream<Item = PositionType> + 'static>>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a `Stream` of the edges the user tried to scroll past, as emitted by the
+    /// `edge-overshot` signal.
+    ///
+    /// The signal handler is disconnected once the stream is dropped.
+    fn edge_overshot_stream(&self) -> Pin<Box<dyn Stream<Item = PositionType> + 'static>>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns `true` if the scrolled window is scrolled to `edge`, within one pixel.
+    ///
+    /// This is useful to only keep scrolling to the bottom on new content if the user did not
+    /// scroll away from it.
+    fn is_at_edge(&self, edge: PositionType) -> bool;
+
+    // rustdoc-stripper-ignore-next
+    /// Scrolls to the horizontal and vertical adjustment values `hvalue` and `vvalue`,
+    /// animated over `duration` with the frame clock.
+    ///
+    /// Values outside the range of the adjustments are clamped on every frame, so scrolling to
+    /// `f64::INFINITY` keeps following the end of content that grows during the animation.
+    /// Scrolling happens immediately without animation if `duration` is zero, the widget is
+    /// not mapped or animations are disabled in the settings. A running animation is replaced
+    /// by the new one.
+    fn smooth_scroll_to(&self, hvalue: Option<f64>, vvalue: Option<f64>, duration: Duration);
+
+    // rustdoc-stripper-ignore-next
+    /// Scrolls to `edge` like [`smooth_scroll_to`](Self::smooth_scroll_to).
+    fn scroll_to_edge(&self, edge: PositionType, duration: Duration);
+
+    // rustdoc-stripper-ignore-next
+    /// Scrolls the least amount necessary to make `widget` visible, like
+    /// [`smooth_scroll_to`](Self::smooth_scroll_to).
+    ///
+    /// Returns `false` if `widget` is not inside the scrolled window or not realized.
+    fn scroll_to_widget<P: IsA<Widget>>(&self, widget: &P, duration: Duration) -> bool;
+
+    // rustdoc-stripper-ignore-next
+    /// Scrolls the least amount necessary to make the location of `iter` in `text_view`
+    /// visible, like [`smooth_scroll_to`](Self::smooth_scroll_to).
+    ///
+    /// Returns `false` if `text_view` is not inside the scrolled window or not realized.
+    fn scroll_to_iter<P: IsA<TextView>>(
+        &self,
+        text_view: &P,
+        iter: &TextIter,
+        duration: Duration,
+    ) -> bool;
+}
+
+impl<O: IsA<ScrolledWindow>> ScrolledWindowExtManual for O {
+    fn edge_reached_stream(&self) -> Pin<Box<dyn Stream<Item = PositionType> + 'static>> {
+        Box::pin(glib::signal_stream(
+            self.as_ref(),
+            |scrolled_window, sender| {
+                vec![scrolled_window.connect_edge_reached(move |_, edge| {
+                    let _ = sender.unbounded_send(edge);
+                })]
+            },
+        ))
+    }
+
+    fn edge_overshot_stream(&self) -> Pin<Box<dyn Stream<Item = PositionType> + 'static>> {
+        Box::pin(glib::signal_stream(
+            self.as_ref(),
+            |scrolled_window, sender| {
+                vec![scrolled_window.connect_edge_overshot(move |_, edge| {
+                    let _ = sender.unbounded_send(edge);
+                })]
+            },
+        ))
+    }
+
+    fn is_at_edge(&self, edge: PositionType) -> bool {
+        let (adjustment, end) = match edge {
+            PositionType::Left => (self.get_hadjustment(), false),
+            PositionType::Right => (self.get_hadjustment(), true),
+            PositionType::Top => (self.get_vadjustment(), false),
+            PositionType::Bottom => (self.get_vadjustment(), true),
+            _ => return false,
+        };
+
+        if end {
+            adjustment.get_value() >= adjustment.get_upper() - adjustment.get_page_size() - 1.0
+        } else {
+            adjustment.get_value() <= adjustment.get_lower() + 1.0
+        }
+    }
+
+    fn smooth_scroll_to(&self, hvalue: Option<f64>, vvalue: Option<f64>, duration: Duration) {
+        let scrolled_window = self.as_ref();
+        unsafe {
+            if let Some(id) = scrolled_window.steal_data::<TickCallbackId>(SMOOTH_SCROLL_KEY) {
+                id.remove();
+            }
+        }
+
+        let animate = duration > Duration::from_secs(0)
+            && scrolled_window.get_mapped()
+            && scrolled_window.get_settings().map_or(true, |settings| {
+                settings.get_property_gtk_enable_animations()
+            });
+        if !animate {
+            if let Some(hvalue) = hvalue {
+                let hadjustment = scrolled_window.get_hadjustment();
+                hadjustment.set_value(clamp_value(&hadjustment, hvalue));
+            }
+            if let Some(vvalue) = vvalue {
+                let vadjustment = scrolled_window.get_vadjustment();
+                vadjustment.set_value(clamp_value(&vadjustment, vvalue));
+            }
+            return;
+        }
+
+        let hstart = scrolled_window.get_hadjustment().get_value();
+        let vstart = scrolled_window.get_vadjustment().get_value();
+        let duration = duration.as_micros() as f64;
+        let start_time = Cell::new(None);
+
+        let id = scrolled_window.add_tick_callback(move |scrolled_window, frame_clock| {
+            let now = frame_clock.get_frame_time();
+            let start = match start_time.get() {
+                Some(start) => start,
+                None => {
+                    start_time.set(Some(now));
+                    now
+                }
+            };
+
+            // Ease out cubic
+            let t = ((now - start) as f64 / duration).min(1.0);
+            let progress = 1.0 - (1.0 - t).powi(3);
+
+            if let Some(hvalue) = hvalue {
+                let hadjustment = scrolled_window.get_hadjustment();
+                let target = clamp_value(&hadjustment, hvalue);
+                hadjustment.set_value(hstart + (target - hstart) * progress);
+            }
+            if let Some(vvalue) = vvalue {
+                let vadjustment = scrolled_window.get_vadjustment();
+                let target = clamp_value(&vadjustment, vvalue);
+                vadjustment.set_value(vstart + (target - vstart) * progress);
+            }
+
+            if t < 1.0 {
+                Continue(true)
+            } else {
+                unsafe {
+                    let _ = scrolled_window.steal_data::<TickCallbackId>(SMOOTH_SCROLL_KEY);
+                }
+                Continue(false)
+            }
+        });
+
+        unsafe {
+            scrolled_window.set_data(SMOOTH_SCROLL_KEY, id);
+        }
+    }
+
+    fn scroll_to_edge(&self, edge: PositionType, duration: Duration) {
+        match edge {
+            PositionType::Left => self.smooth_scroll_to(Some(f64::NEG_INFINITY), None, duration),
+            PositionType::Right => self.smooth_scroll_to(Some(f64::INFINITY), None, duration),
+            PositionType::Top => self.smooth_scroll_to(None, Some(f64::NEG_INFINITY), duration),
+            PositionType::Bottom => self.smooth_scroll_to(None, Some(f64::INFINITY), duration),
+            _ => (),
+        }
+    }
+
+    fn scroll_to_widget<P: IsA<Widget>>(&self, widget: &P, duration: Duration) -> bool {
+        let child = match self.as_ref().get_child() {
+            Some(child) => child,
+            None => return false,
+        };
+        let (x, y) = match widget.translate_coordinates(&child, 0, 0) {
+            Some(pos) => pos,
+            None => return false,
+        };
+
+        let allocation = widget.get_allocation();
+        self.smooth_scroll_to(
+            visible_target(&self.get_hadjustment(), x, allocation.width),
+            visible_target(&self.get_vadjustment(), y, allocation.height),
+            duration,
+        );
+        true
+    }
+
+    fn scroll_to_iter<P: IsA<TextView>>(
+        &self,
+        text_view: &P,
+        iter: &TextIter,
+        duration: Duration,
+    ) -> bool {
+        let child = match self.as_ref().get_child() {
+            Some(child) => child,
+            None => return false,
+        };
+
+        let location = text_view.get_iter_location(iter);
+        let (x, y) =
+            text_view.buffer_to_window_coords(TextWindowType::Widget, location.x, location.y);
+        let (x, y) = match text_view.as_ref().translate_coordinates(&child, x, y) {
+            Some(pos) => pos,
+            None => return false,
+        };
+
+        self.smooth_scroll_to(
+            visible_target(&self.get_hadjustment(), x, location.width),
+            visible_target(&self.get_vadjustment(), y, location.height),
+            duration,
+        );
+        true
+    }
+}
+
+fn clamp_value(adjustment: &Adjustment, value: f64) -> f64 {
+    value
+        .min(adjustment.get_upper() - adjustment.get_page_size())
+        .max(adjustment.get_lower())
+}
+
+// Returns the value of `adjustment` that makes the range starting at `pos` of the visible area
+// visible, or `None` if it is already.
+fn visible_target(adjustment: &Adjustment, pos: i32, size: i32) -> Option<f64> {
+    let (pos, size) = (f64::from(pos), f64::from(size));
+    let page_size = adjustment.get_page_size();
+
+    if pos < 0.0 {
+        Some(adjustment.get_value() + pos)
+    } else if pos + size > page_size {
+        Some(adjustment.get_value() + (pos + size - page_size).min(pos))
+    } else {
+        None
+    }
+}
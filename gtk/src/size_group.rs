@@ -0,0 +1,187 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::builder::BuilderExtManual;
+use crate::{
+    Align, Builder, BuilderObjectError, Label, LabelExt, SizeGroup, SizeGroupExt, SizeGroupMode,
+    Widget, WidgetExt,
+};
+use glib::object::{Cast, IsA, ObjectExt, WeakRef};
+
+pub trait SizeGroupExtManual: 'static {
+    /// Adds `widget` to the size group until the returned guard is dropped.
+    #[doc(alias = "gtk_size_group_add_widget")]
+    fn add_widget_guarded<P: IsA<Widget>>(&self, widget: &P) -> SizeGroupMembership;
+
+    /// Adds all `widgets` to the size group.
+    #[doc(alias = "gtk_size_group_add_widget")]
+    fn add_widgets<P: IsA<Widget>>(&self, widgets: &[&P]);
+
+    /// Adds the widgets with the ids `ids` in `builder` to the size group.
+    ///
+    /// Fails without adding any widget if one of the objects is missing or not a widget.
+    fn add_widgets_from_builder<B: IsA<Builder>>(
+        &self,
+        builder: &B,
+        ids: &[&str],
+    ) -> Result<(), BuilderObjectError>;
+}
+
+impl<O: IsA<SizeGroup>> SizeGroupExtManual for O {
+    fn add_widget_guarded<P: IsA<Widget>>(&self, widget: &P) -> SizeGroupMembership {
+        self.add_widget(widget);
+        SizeGroupMembership {
+            size_group: self.as_ref().clone(),
+            widget: widget.upcast_ref::<Widget>().downgrade(),
+        }
+    }
+
+    fn add_widgets<P: IsA<Widget>>(&self, widgets: &[&P]) {
+        for widget in widgets {
+            self.add_widget(*widget);
+        }
+    }
+
+    fn add_widgets_from_builder<B: IsA<Builder>>(
+        &self,
+        builder: &B,
+        ids: &[&str],
+    ) -> Result<(), BuilderObjectError> {
+        let widgets = ids
+            .iter()
+            .map(|id| builder.get_object_checked::<Widget>(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        for widget in &widgets {
+            self.add_widget(widget);
+        }
+        Ok(())
+    }
+}
+
+/// Guard returned by [`SizeGroupExtManual::add_widget_guarded`] that removes the widget from
+/// the size group when dropped.
+#[derive(Debug)]
+#[must_use = "if unused the widget will immediately be removed from the size group again"]
+pub struct SizeGroupMembership {
+    size_group: SizeGroup,
+    widget: WeakRef<Widget>,
+}
+
+impl SizeGroupMembership {
+    pub fn get_size_group(&self) -> &SizeGroup {
+        &self.size_group
+    }
+
+    /// Returns the widget, unless it was already destroyed.
+    pub fn get_widget(&self) -> Option<Widget> {
+        self.widget.upgrade()
+    }
+
+    /// Keeps the widget in the size group.
+    pub fn keep(mut self) {
+        self.widget = WeakRef::new();
+    }
+}
+
+impl Drop for SizeGroupMembership {
+    fn drop(&mut self) {
+        if let Some(widget) = self.widget.upgrade() {
+            remove_widget_if_added(&self.size_group, &widget);
+        }
+    }
+}
+
+// Removing a widget that is not in the size group emits a critical warning
+fn remove_widget_if_added(size_group: &SizeGroup, widget: &Widget) {
+    if size_group.get_widgets().contains(widget) {
+        size_group.remove_widget(widget);
+    }
+}
+
+/// Aligns the labels and fields of forms that are split across several containers, e.g. one
+/// `Grid` per section of a settings page.
+///
+/// All labels get the width of the widest label and all fields the width of the widest field,
+/// so the columns line up in all containers. Labels are aligned to the end of their column by
+/// default and become the mnemonic labels of their fields.
+///
+/// ```no_run
+/// use gtk::prelude::*;
+///
+/// # fn build(general: &gtk::Grid, network: &gtk::Grid) {
+/// let form = gtk::FormAlignment::new();
+///
+/// let name_label = gtk::Label::with_mnemonic("_Name");
+/// let name_entry = gtk::Entry::new();
+/// general.attach(&name_label, 0, 0, 1, 1);
+/// general.attach(&name_entry, 1, 0, 1, 1);
+/// form.add_row(&name_label, &name_entry);
+///
+/// let proxy_label = gtk::Label::with_mnemonic("HTTP _proxy server");
+/// let proxy_entry = gtk::Entry::new();
+/// network.attach(&proxy_label, 0, 0, 1, 1);
+/// network.attach(&proxy_entry, 1, 0, 1, 1);
+/// form.add_row(&proxy_label, &proxy_entry);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FormAlignment {
+    labels: SizeGroup,
+    fields: SizeGroup,
+    label_align: Align,
+}
+
+impl FormAlignment {
+    pub fn new() -> Self {
+        assert_initialized_main_thread!();
+        Self {
+            labels: SizeGroup::new(SizeGroupMode::Horizontal),
+            fields: SizeGroup::new(SizeGroupMode::Horizontal),
+            label_align: Align::End,
+        }
+    }
+
+    /// Sets the horizontal alignment of the text of labels added afterwards, which defaults to
+    /// `Align::End`.
+    ///
+    /// `Align::Fill` and `Align::Baseline` align the text like `Align::Start`.
+    pub fn set_label_align(&mut self, label_align: Align) {
+        self.label_align = label_align;
+    }
+
+    /// Adds a row consisting of `label` describing `field`.
+    pub fn add_row<L: IsA<Label>, W: IsA<Widget>>(&self, label: &L, field: &W) {
+        // The size group widens the labels, so only aligning the text inside them has an effect
+        label.set_xalign(match self.label_align {
+            Align::Center => 0.5,
+            Align::End => 1.0,
+            _ => 0.0,
+        });
+        label.set_mnemonic_widget(Some(field));
+        self.labels.add_widget(label);
+        self.fields.add_widget(field);
+    }
+
+    /// Removes a row added with [`add_row`](Self::add_row).
+    ///
+    /// Widgets that are not part of the form are ignored.
+    pub fn remove_row<L: IsA<Label>, W: IsA<Widget>>(&self, label: &L, field: &W) {
+        remove_widget_if_added(&self.labels, label.upcast_ref());
+        remove_widget_if_added(&self.fields, field.upcast_ref());
+    }
+
+    /// Returns the size group of the labels.
+    pub fn get_label_group(&self) -> &SizeGroup {
+        &self.labels
+    }
+
+    /// Returns the size group of the fields.
+    pub fn get_field_group(&self) -> &SizeGroup {
+        &self.fields
+    }
+}
+
+impl Default for FormAlignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
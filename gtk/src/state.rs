@@ -0,0 +1,219 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Saving and restoring the state of widgets across runs of an application.
+//!
+//! Each widget is identified by an id and its state is written to a [`StateStorage`], i.e.
+//! [`gio::Settings`] or a [`glib::KeyFile`], when it is unmapped. The state is restored when
+//! the widget is persisted, or for widgets that need their allocation, when they are mapped.
+//!
+//! A key file has to be saved after the state was written, e.g. from an `unmap` handler of the
+//! window connected after persisting it, which runs once the window and its children were
+//! unmapped. `delete-event` is emitted while the widgets are still mapped and is too early.
+//!
+//! ```no_run
+//! use gtk::prelude::*;
+//!
+//! # fn build(window: &gtk::ApplicationWindow, paned: &gtk::Paned) {
+//! let state = glib::KeyFile::new();
+//! let _ = state.load_from_file("state.ini", glib::KeyFileFlags::NONE);
+//!
+//! gtk::state::persist_window(window, "main-window", &state);
+//! gtk::state::persist_paned(paned, "sidebar", &state);
+//!
+//! window.connect_unmap(move |_| {
+//!     let _ = state.save_to_file("state.ini");
+//! });
+//! # }
+//! ```
+
+use crate::tree_view;
+use crate::{Paned, PanedExt, TreeView, TreeViewExt, Widget, WidgetExt, Window, WindowExt};
+use gio::prelude::*;
+use glib::object::IsA;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Storage for the state of widgets.
+///
+/// Values are stored for the id of a widget under the names `width`, `height`, `maximized`,
+/// `position` and `column-<n>-width`.
+pub trait StateStorage: Clone + 'static {
+    /// Returns the integer stored for `id` under `name`, if any.
+    fn load_int(&self, id: &str, name: &str) -> Option<i32>;
+
+    /// Stores `value` for `id` under `name`.
+    fn save_int(&self, id: &str, name: &str, value: i32);
+
+    /// Returns the boolean stored for `id` under `name`, if any.
+    fn load_bool(&self, id: &str, name: &str) -> Option<bool>;
+
+    /// Stores `value` for `id` under `name`.
+    fn save_bool(&self, id: &str, name: &str, value: bool);
+}
+
+/// Stores each value under the key `<id>-<name>`, e.g. `main-window-width`.
+///
+/// The keys have to be declared in the schema of the settings, values whose key is missing are
+/// neither restored nor saved.
+impl StateStorage for gio::Settings {
+    fn load_int(&self, id: &str, name: &str) -> Option<i32> {
+        let key = settings_key(self, id, name)?;
+        Some(self.get_int(&key))
+    }
+
+    fn save_int(&self, id: &str, name: &str, value: i32) {
+        if let Some(key) = settings_key(self, id, name) {
+            if let Err(err) = self.set_int(&key, value) {
+                glib::g_critical!("Gtk", "Failed to save widget state: {}", err);
+            }
+        }
+    }
+
+    fn load_bool(&self, id: &str, name: &str) -> Option<bool> {
+        let key = settings_key(self, id, name)?;
+        Some(self.get_boolean(&key))
+    }
+
+    fn save_bool(&self, id: &str, name: &str, value: bool) {
+        if let Some(key) = settings_key(self, id, name) {
+            if let Err(err) = self.set_boolean(&key, value) {
+                glib::g_critical!("Gtk", "Failed to save widget state: {}", err);
+            }
+        }
+    }
+}
+
+fn settings_key(settings: &gio::Settings, id: &str, name: &str) -> Option<String> {
+    let key = format!("{}-{}", id, name);
+    settings
+        .get_property_settings_schema()
+        .filter(|schema| schema.has_key(&key))
+        .map(|_| key)
+}
+
+/// Stores each value in the group `<id>` under the key `<name>`.
+///
+/// Saving the key file is left to the application.
+impl StateStorage for glib::KeyFile {
+    fn load_int(&self, id: &str, name: &str) -> Option<i32> {
+        self.get_integer(id, name).ok()
+    }
+
+    fn save_int(&self, id: &str, name: &str, value: i32) {
+        self.set_integer(id, name, value);
+    }
+
+    fn load_bool(&self, id: &str, name: &str) -> Option<bool> {
+        self.get_boolean(id, name).ok()
+    }
+
+    fn save_bool(&self, id: &str, name: &str, value: bool) {
+        self.set_boolean(id, name, value);
+    }
+}
+
+/// Restores the size and maximized state of `window` and saves them whenever it is unmapped.
+///
+/// This has to be called before the window is shown.
+pub fn persist_window<W: IsA<Window>, S: StateStorage>(window: &W, id: &str, storage: &S) {
+    let window = window.as_ref();
+    if let (Some(width), Some(height)) = (
+        storage.load_int(id, "width"),
+        storage.load_int(id, "height"),
+    ) {
+        window.set_default_size(width, height);
+    }
+    if storage.load_bool(id, "maximized") == Some(true) {
+        window.maximize();
+    }
+
+    // The size of a maximized window isn't interesting, so the last size it had before is saved
+    let size = Rc::new(Cell::new(window.get_default_size()));
+    let size_clone = size.clone();
+    window.connect_size_allocate(move |window, _| {
+        if !window.is_maximized() {
+            size_clone.set(window.get_size());
+        }
+    });
+
+    let id = id.to_owned();
+    let storage = storage.clone();
+    window.connect_unmap(move |window| {
+        let (width, height) = size.get();
+        if width > 0 && height > 0 {
+            storage.save_int(&id, "width", width);
+            storage.save_int(&id, "height", height);
+        }
+        storage.save_bool(&id, "maximized", window.is_maximized());
+    });
+}
+
+/// Restores the position of the divider of `paned` when it is mapped and saves it whenever it
+/// is unmapped.
+pub fn persist_paned<P: IsA<Paned>, S: StateStorage>(paned: &P, id: &str, storage: &S) {
+    let paned = paned.as_ref();
+    persist_on_map(
+        paned,
+        id,
+        storage,
+        |paned: &Paned, id, storage: &S| {
+            if let Some(position) = storage.load_int(id, "position") {
+                paned.set_position(position);
+            }
+        },
+        |paned: &Paned, id, storage: &S| storage.save_int(id, "position", paned.get_position()),
+    );
+}
+
+/// Restores the widths of the columns of `tree_view` when it is mapped and saves them whenever
+/// it is unmapped.
+///
+/// Columns are identified by their index, so the columns have to be added before the tree view
+/// is mapped, and always in the same order. Like
+/// [`TreeViewExtManual::restore_columns`](crate::prelude::TreeViewExtManual::restore_columns),
+/// which also restores the order and visibility of named columns, columns with a saved width get
+/// fixed sizing.
+pub fn persist_columns<T: IsA<TreeView>, S: StateStorage>(tree_view: &T, id: &str, storage: &S) {
+    let tree_view = tree_view.as_ref();
+    persist_on_map(
+        tree_view,
+        id,
+        storage,
+        |tree_view: &TreeView, id, storage: &S| {
+            for (index, column) in tree_view.get_columns().iter().enumerate() {
+                let name = format!("column-{}-width", index);
+                if let Some(width) = storage.load_int(id, &name) {
+                    tree_view::restore_column_width(column, width);
+                }
+            }
+        },
+        |tree_view: &TreeView, id, storage: &S| {
+            for (index, column) in tree_view.get_columns().iter().enumerate() {
+                let name = format!("column-{}-width", index);
+                storage.save_int(id, &name, tree_view::column_width(column));
+            }
+        },
+    );
+}
+
+// Restores the state the first time `widget` is mapped and saves it whenever it is unmapped
+fn persist_on_map<W, S, R, V>(widget: &W, id: &str, storage: &S, restore: R, save: V)
+where
+    W: IsA<Widget>,
+    S: StateStorage,
+    R: Fn(&W, &str, &S) + 'static,
+    V: Fn(&W, &str, &S) + 'static,
+{
+    let restored = Cell::new(false);
+    let id_clone = id.to_owned();
+    let storage_clone = storage.clone();
+    widget.connect_map(move |widget| {
+        if !restored.replace(true) {
+            restore(widget, &id_clone, &storage_clone);
+        }
+    });
+
+    let id = id.to_owned();
+    let storage = storage.clone();
+    widget.connect_unmap(move |widget| save(widget, &id, &storage));
+}
@@ -68,7 +68,8 @@ pub mod prelude {
     pub use super::stack::StackImpl;
     pub use super::tree_view::TreeViewImpl;
     pub use super::widget::{
-        CompositeTemplate, TemplateChild, WidgetClassSubclassExt, WidgetImpl, WidgetImplExt,
+        CompositeTemplate, CompositeTemplateCallbacks, TemplateChild, WidgetClassSubclassExt,
+        WidgetImpl, WidgetImplExt,
     };
     pub use super::window::{WindowImpl, WindowImplExt};
 }
@@ -1780,3 +1780,117 @@ where
 pub trait CompositeTemplate: WidgetImpl {
     fn bind_template(klass: &mut Self::Class);
 }
+
+// rustdoc-stripper-ignore-next
+/// A named callback that can be referenced as signal handler from a composite template.
+///
+/// The values passed to the callback are the arguments of the signal, starting with the
+/// emitting object.
+pub type TemplateCallback<T> = (&'static str, fn(&T, &[glib::Value]) -> Option<glib::Value>);
+
+// rustdoc-stripper-ignore-next
+/// Signal handlers of a composite template, usually implemented with the
+/// `#[template_callbacks]` attribute macro.
+pub trait CompositeTemplateCallbacks: WidgetImpl {
+    const CALLBACKS: &'static [TemplateCallback<Self>];
+
+    // rustdoc-stripper-ignore-next
+    /// Makes the `CALLBACKS` available as handlers to the signals declared in the template.
+    ///
+    /// This has to be called in `class_init`, after the template was set.
+    fn bind_template_callbacks(klass: &mut Self::Class) {
+        unsafe {
+            let type_class = klass as *mut _ as *mut glib::gobject_ffi::GTypeClass;
+            let widget_class =
+                glib::gobject_ffi::g_type_check_class_cast(type_class, ffi::gtk_widget_get_type())
+                    as *mut ffi::GtkWidgetClass;
+            ffi::gtk_widget_class_set_connect_func(
+                widget_class,
+                Some(template_connect_func::<Self>),
+                std::ptr::null_mut(),
+                None,
+            );
+        }
+    }
+}
+
+unsafe extern "C" fn template_connect_func<T: CompositeTemplateCallbacks>(
+    builder: *mut ffi::GtkBuilder,
+    object: *mut glib::gobject_ffi::GObject,
+    signal_name: *const libc::c_char,
+    handler_name: *const libc::c_char,
+    connect_object: *mut glib::gobject_ffi::GObject,
+    flags: glib::gobject_ffi::GConnectFlags,
+    _user_data: glib::ffi::gpointer,
+) {
+    // Panicking would unwind into GTK, so errors are only reported
+    let signal_name: Borrowed<glib::GString> = from_glib_borrow(signal_name);
+    let handler_name: Borrowed<glib::GString> = from_glib_borrow(handler_name);
+    if !connect_object.is_null() {
+        glib::g_critical!(
+            "Gtk",
+            "Connect object of template callback '{}' is not supported",
+            handler_name.as_str()
+        );
+        return;
+    }
+    if flags & glib::gobject_ffi::G_CONNECT_SWAPPED != 0 {
+        glib::g_critical!(
+            "Gtk",
+            "Swapped template callback '{}' is not supported",
+            handler_name.as_str()
+        );
+        return;
+    }
+
+    let callback = match T::CALLBACKS
+        .iter()
+        .find(|(name, _)| *name == handler_name.as_str())
+    {
+        Some(&(_, callback)) => callback,
+        None => {
+            glib::g_critical!(
+                "Gtk",
+                "Template callback '{}' not found",
+                handler_name.as_str()
+            );
+            return;
+        }
+    };
+
+    // The template extends the instance, which the builder exposes under the name of its type.
+    let this = match Option::<Object>::from_glib_none(ffi::gtk_builder_get_object(
+        builder,
+        glib::gobject_ffi::g_type_name(T::get_type().to_glib()),
+    ))
+    .and_then(|this| this.downcast::<T::Type>().ok())
+    {
+        Some(this) => this.downgrade(),
+        None => {
+            glib::g_critical!(
+                "Gtk",
+                "Failed to retrieve the template instance for callback '{}'",
+                handler_name.as_str()
+            );
+            return;
+        }
+    };
+
+    let object: Borrowed<Object> = from_glib_borrow(object);
+    if let Err(err) = object.connect_local(
+        signal_name.as_str(),
+        flags & glib::gobject_ffi::G_CONNECT_AFTER != 0,
+        move |values| {
+            let this = this.upgrade()?;
+            callback(T::from_instance(&this), values)
+        },
+    ) {
+        glib::g_critical!(
+            "Gtk",
+            "Failed to connect template callback '{}' to signal '{}': {}",
+            handler_name.as_str(),
+            signal_name.as_str(),
+            err
+        );
+    }
+}
@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::TextBuffer;
+use crate::TextBufferExt;
 use crate::TextChildAnchor;
 use crate::TextIter;
 use crate::TextTag;
@@ -9,8 +10,8 @@ use glib::signal::{connect_raw, SignalHandlerId};
 use glib::translate::*;
 use libc::{c_char, c_int};
 use std::boxed::Box as Box_;
-use std::mem::transmute;
-use std::{slice, str};
+use std::mem::{self, transmute};
+use std::{ptr, slice, str};
 
 pub trait TextBufferExtManual: 'static {
     fn connect_apply_tag<F: Fn(&Self, &TextTag, &mut TextIter, &mut TextIter) + 'static>(
@@ -42,6 +43,51 @@ pub trait TextBufferExtManual: 'static {
         &self,
         f: F,
     ) -> SignalHandlerId;
+    /// Registers a custom serialization format for the contents of text buffers.
+    ///
+    /// `function` is called with the buffer that registered the format, the buffer whose
+    /// contents are serialized and the range to serialize. The returned atom identifies the
+    /// format in [`serialize`](crate::TextBufferExt::serialize) and is offered as a
+    /// target when copying text to the clipboard.
+    #[doc(alias = "gtk_text_buffer_register_serialize_format")]
+    fn register_serialize_format<
+        F: Fn(&TextBuffer, &TextBuffer, &TextIter, &TextIter) -> Vec<u8> + 'static,
+    >(
+        &self,
+        mime_type: &str,
+        function: F,
+    ) -> gdk::Atom;
+
+    /// Registers a custom deserialization format for the contents of text buffers.
+    ///
+    /// `function` is called with the buffer that registered the format, the buffer to insert
+    /// into, the insertion point, the data and whether the format may create new tags. The
+    /// insertion point has to be moved to the end of the inserted text. The returned atom
+    /// identifies the format in [`deserialize`](crate::TextBufferExt::deserialize)
+    /// and is accepted when pasting text from the clipboard.
+    #[doc(alias = "gtk_text_buffer_register_deserialize_format")]
+    fn register_deserialize_format<
+        F: Fn(&TextBuffer, &TextBuffer, &mut TextIter, &[u8], bool) -> Result<(), glib::Error>
+            + 'static,
+    >(
+        &self,
+        mime_type: &str,
+        function: F,
+    ) -> gdk::Atom;
+
+    /// Serializes the text and tags between `start` and `end` in GTK's internal rich text
+    /// format.
+    ///
+    /// The result can be loaded again with
+    /// [`deserialize_rich_text`](Self::deserialize_rich_text), also into another buffer.
+    fn serialize_rich_text(&self, start: &TextIter, end: &TextIter) -> Vec<u8>;
+
+    /// Inserts text and tags serialized with [`serialize_rich_text`](Self::serialize_rich_text)
+    /// at `iter`.
+    ///
+    /// Tags that don't exist in the tag table of the buffer yet are created. `iter` is moved to
+    /// the end of the inserted text.
+    fn deserialize_rich_text(&self, iter: &mut TextIter, data: &[u8]) -> Result<(), glib::Error>;
 }
 
 impl<O: IsA<TextBuffer>> TextBufferExtManual for O {
@@ -286,4 +332,135 @@ impl<O: IsA<TextBuffer>> TextBufferExtManual for O {
             )
         }
     }
+
+    fn register_serialize_format<
+        F: Fn(&TextBuffer, &TextBuffer, &TextIter, &TextIter) -> Vec<u8> + 'static,
+    >(
+        &self,
+        mime_type: &str,
+        function: F,
+    ) -> gdk::Atom {
+        unsafe extern "C" fn serialize_trampoline<
+            F: Fn(&TextBuffer, &TextBuffer, &TextIter, &TextIter) -> Vec<u8> + 'static,
+        >(
+            register_buffer: *mut ffi::GtkTextBuffer,
+            content_buffer: *mut ffi::GtkTextBuffer,
+            start: *const ffi::GtkTextIter,
+            end: *const ffi::GtkTextIter,
+            length: *mut libc::size_t,
+            f: glib::ffi::gpointer,
+        ) -> *mut u8 {
+            let f: &F = &*(f as *const F);
+            let data = f(
+                &from_glib_borrow(register_buffer),
+                &from_glib_borrow(content_buffer),
+                &from_glib_borrow(start),
+                &from_glib_borrow(end),
+            );
+
+            *length = data.len();
+            if data.is_empty() {
+                return ptr::null_mut();
+            }
+            let ret = glib::ffi::g_malloc(data.len()) as *mut u8;
+            ptr::copy_nonoverlapping(data.as_ptr(), ret, data.len());
+            ret
+        }
+        unsafe extern "C" fn destroy_closure<
+            F: Fn(&TextBuffer, &TextBuffer, &TextIter, &TextIter) -> Vec<u8> + 'static,
+        >(
+            ptr: glib::ffi::gpointer,
+        ) {
+            Box_::<F>::from_raw(ptr as *mut _);
+        }
+        unsafe {
+            from_glib_none(ffi::gtk_text_buffer_register_serialize_format(
+                self.as_ref().to_glib_none().0,
+                mime_type.to_glib_none().0,
+                Some(serialize_trampoline::<F>),
+                Box_::into_raw(Box_::new(function)) as *mut _,
+                Some(destroy_closure::<F>),
+            ))
+        }
+    }
+
+    fn register_deserialize_format<
+        F: Fn(&TextBuffer, &TextBuffer, &mut TextIter, &[u8], bool) -> Result<(), glib::Error>
+            + 'static,
+    >(
+        &self,
+        mime_type: &str,
+        function: F,
+    ) -> gdk::Atom {
+        unsafe extern "C" fn deserialize_trampoline<
+            F: Fn(&TextBuffer, &TextBuffer, &mut TextIter, &[u8], bool) -> Result<(), glib::Error>
+                + 'static,
+        >(
+            register_buffer: *mut ffi::GtkTextBuffer,
+            content_buffer: *mut ffi::GtkTextBuffer,
+            iter: *mut ffi::GtkTextIter,
+            data: *const u8,
+            length: libc::size_t,
+            create_tags: glib::ffi::gboolean,
+            f: glib::ffi::gpointer,
+            error: *mut *mut glib::ffi::GError,
+        ) -> glib::ffi::gboolean {
+            let f: &F = &*(f as *const F);
+            let mut iter_copy = from_glib_none(iter);
+            let data = if length == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(data, length)
+            };
+
+            let res = f(
+                &from_glib_borrow(register_buffer),
+                &from_glib_borrow(content_buffer),
+                &mut iter_copy,
+                data,
+                from_glib(create_tags),
+            );
+
+            *iter = *iter_copy.to_glib_none().0;
+
+            match res {
+                Ok(()) => glib::ffi::GTRUE,
+                Err(e) => {
+                    if !error.is_null() {
+                        let mut e = mem::ManuallyDrop::new(e);
+                        *error = e.to_glib_none_mut().0;
+                    }
+                    glib::ffi::GFALSE
+                }
+            }
+        }
+        unsafe extern "C" fn destroy_closure<
+            F: Fn(&TextBuffer, &TextBuffer, &mut TextIter, &[u8], bool) -> Result<(), glib::Error>
+                + 'static,
+        >(
+            ptr: glib::ffi::gpointer,
+        ) {
+            Box_::<F>::from_raw(ptr as *mut _);
+        }
+        unsafe {
+            from_glib_none(ffi::gtk_text_buffer_register_deserialize_format(
+                self.as_ref().to_glib_none().0,
+                mime_type.to_glib_none().0,
+                Some(deserialize_trampoline::<F>),
+                Box_::into_raw(Box_::new(function)) as *mut _,
+                Some(destroy_closure::<F>),
+            ))
+        }
+    }
+
+    fn serialize_rich_text(&self, start: &TextIter, end: &TextIter) -> Vec<u8> {
+        let format = self.register_serialize_tagset(None);
+        self.serialize(self, &format, start, end)
+    }
+
+    fn deserialize_rich_text(&self, iter: &mut TextIter, data: &[u8]) -> Result<(), glib::Error> {
+        let format = self.register_deserialize_tagset(None);
+        self.deserialize_set_can_create_tags(&format, true);
+        self.deserialize(self, &format, iter, data)
+    }
 }
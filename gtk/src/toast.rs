@@ -0,0 +1,274 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{
+    Align, BinExt, ContainerExt, InfoBar, InfoBarExt, Label, LabelExt, MessageType, Overlay,
+    OverlayExt, ResponseType, Revealer, RevealerExt, RevealerTransitionType, WidgetExt, Window,
+};
+use glib::object::{Cast, IsA};
+use glib::ObjectExt;
+use glib::SourceId;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+const TOAST_OVERLAY_KEY: &str = "gtk-rs-toast-overlay";
+
+/// A transient in-app notification shown by a [`ToastOverlay`].
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// # fn notify(window: &gtk::Window) {
+/// let overlay = gtk::ToastOverlay::for_window(window);
+/// overlay.add_toast(
+///     gtk::Toast::new("File deleted")
+///         .action("Undo", || println!("Undo"))
+///         .timeout(Some(Duration::from_secs(10))),
+/// );
+/// # }
+/// ```
+pub struct Toast {
+    message: String,
+    message_type: MessageType,
+    action: Option<(String, Box<dyn Fn() + 'static>)>,
+    timeout: Option<Duration>,
+}
+
+impl Toast {
+    /// Creates a new toast showing `message`, which is dismissed after five seconds.
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.to_owned(),
+            message_type: MessageType::Other,
+            action: None,
+            timeout: Some(Duration::from_secs(5)),
+        }
+    }
+
+    /// Adds a button labelled `label` which calls `f` and dismisses the toast when clicked.
+    pub fn action<F: Fn() + 'static>(mut self, label: &str, f: F) -> Self {
+        self.action = Some((label.to_owned(), Box::new(f)));
+        self
+    }
+
+    /// Sets the time after which the toast is dismissed, or `None` to only dismiss it when
+    /// the user closes it.
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the message type of the underlying [`InfoBar`].
+    pub fn message_type(mut self, message_type: MessageType) -> Self {
+        self.message_type = message_type;
+        self
+    }
+}
+
+impl fmt::Debug for Toast {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Toast")
+            .field("message", &self.message)
+            .field("message_type", &self.message_type)
+            .field("action", &self.action.as_ref().map(|(label, _)| label))
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// Shows [`Toast`]s on top of a widget, one at a time.
+///
+/// Each toast is an [`InfoBar`] that slides in from the bottom in a [`Revealer`] added to an
+/// [`Overlay`]. Toasts added while another one is shown are queued and shown once the previous
+/// one has been dismissed and slid out.
+#[derive(Clone)]
+pub struct ToastOverlay(Rc<ToastOverlayInner>);
+
+struct ToastOverlayInner {
+    overlay: Overlay,
+    queue: RefCell<VecDeque<Toast>>,
+    current: RefCell<Option<(Revealer, Option<SourceId>)>>,
+}
+
+impl ToastOverlay {
+    /// Creates a new toast overlay.
+    ///
+    /// The widget the toasts are shown on has to be added to [`get_overlay`](Self::get_overlay).
+    pub fn new() -> Self {
+        assert_initialized_main_thread!();
+        let overlay = Overlay::new();
+        overlay.show();
+
+        ToastOverlay(Rc::new(ToastOverlayInner {
+            overlay,
+            queue: RefCell::new(VecDeque::new()),
+            current: RefCell::new(None),
+        }))
+    }
+
+    /// Returns the toast overlay of `window`.
+    ///
+    /// On the first call the current child of `window` is moved into a new toast overlay that
+    /// then becomes the child of `window`, later calls return the same overlay.
+    pub fn for_window<W: IsA<Window>>(window: &W) -> Self {
+        unsafe {
+            if let Some(overlay) = window.get_data::<ToastOverlay>(TOAST_OVERLAY_KEY) {
+                return overlay.clone();
+            }
+        }
+
+        let toast_overlay = Self::new();
+        let window = window.upcast_ref::<Window>();
+        if let Some(child) = window.get_child() {
+            window.remove(&child);
+            toast_overlay.get_overlay().add(&child);
+        }
+        window.add(toast_overlay.get_overlay());
+
+        unsafe {
+            window.set_data(TOAST_OVERLAY_KEY, toast_overlay.clone());
+        }
+        toast_overlay
+    }
+
+    /// Returns the overlay the toasts are shown in.
+    pub fn get_overlay(&self) -> &Overlay {
+        &self.0.overlay
+    }
+
+    /// Shows `toast`, or queues it if another toast is currently shown.
+    pub fn add_toast(&self, toast: Toast) {
+        self.0.queue.borrow_mut().push_back(toast);
+        self.0.show_next();
+    }
+
+    /// Dismisses the currently shown toast.
+    pub fn dismiss(&self) {
+        self.0.dismiss();
+    }
+
+    /// Dismisses the currently shown toast and drops all queued ones.
+    pub fn clear(&self) {
+        self.0.queue.borrow_mut().clear();
+        self.0.dismiss();
+    }
+}
+
+impl Default for ToastOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ToastOverlay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ToastOverlay")
+            .field("overlay", &self.0.overlay)
+            .field("queued", &self.0.queue.borrow().len())
+            .finish()
+    }
+}
+
+impl ToastOverlayInner {
+    fn show_next(self: &Rc<Self>) {
+        if self.current.borrow().is_some() {
+            return;
+        }
+        let toast = match self.queue.borrow_mut().pop_front() {
+            Some(toast) => toast,
+            None => return,
+        };
+
+        let info_bar = InfoBar::new();
+        info_bar.set_message_type(toast.message_type);
+        info_bar.set_show_close_button(true);
+
+        let label = Label::new(Some(&toast.message));
+        label.set_line_wrap(true);
+        info_bar.get_content_area().add(&label);
+
+        let action = toast.action.map(|(label, f)| {
+            info_bar.add_button(&label, ResponseType::Accept);
+            f
+        });
+
+        let revealer = Revealer::new();
+        revealer.set_transition_type(RevealerTransitionType::SlideUp);
+        revealer.set_halign(Align::Center);
+        revealer.set_valign(Align::End);
+        revealer.add(&info_bar);
+        revealer.show_all();
+        self.overlay.add_overlay(&revealer);
+
+        let this = Rc::downgrade(self);
+        info_bar.connect_response(move |_, response| {
+            if response == ResponseType::Accept {
+                if let Some(ref action) = action {
+                    action();
+                }
+            }
+            if let Some(this) = this.upgrade() {
+                this.dismiss();
+            }
+        });
+
+        let this = Rc::downgrade(self);
+        revealer.connect_property_child_revealed_notify(move |revealer| {
+            if revealer.get_reveal_child() || revealer.get_child_revealed() {
+                return;
+            }
+            if let Some(this) = this.upgrade() {
+                this.remove(revealer);
+            }
+        });
+
+        let timeout = toast.timeout.map(|timeout| {
+            let this = Rc::downgrade(self);
+            glib::timeout_add_local_once(timeout, move || {
+                if let Some(this) = this.upgrade() {
+                    // The source is already removed once it was dispatched
+                    if let Some((_, ref mut timeout)) = *this.current.borrow_mut() {
+                        timeout.take();
+                    }
+                    this.dismiss();
+                }
+            })
+        });
+
+        *self.current.borrow_mut() = Some((revealer.clone(), timeout));
+        revealer.set_reveal_child(true);
+    }
+
+    fn dismiss(self: &Rc<Self>) {
+        let revealer = match *self.current.borrow_mut() {
+            Some((ref revealer, ref mut timeout)) => {
+                if let Some(timeout) = timeout.take() {
+                    glib::source_remove(timeout);
+                }
+                revealer.clone()
+            }
+            None => return,
+        };
+        // `child-revealed` is only notified once the toast slid out if it was fully shown,
+        // e.g. not if the revealer is still animating or not mapped at all
+        let was_revealed = revealer.get_child_revealed();
+        revealer.set_reveal_child(false);
+        if !was_revealed {
+            self.remove(&revealer);
+        }
+    }
+
+    // Removes the toast of `revealer` if it is the current one and shows the next one
+    fn remove(self: &Rc<Self>, revealer: &Revealer) {
+        let is_current =
+            matches!(*self.current.borrow(), Some((ref current, _)) if current == revealer);
+        if !is_current {
+            return;
+        }
+        *self.current.borrow_mut() = None;
+        self.overlay.remove(revealer);
+        self.show_next();
+    }
+}
@@ -1,9 +1,12 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use glib::object::{Cast, IsA};
+use glib::object::{Cast, IsA, ObjectExt, ObjectType};
+use glib::signal::SignalHandlerId;
 use glib::translate::*;
 
-use crate::{TreeModel, TreeModelFilter, TreePath};
+use crate::{TreeIter, TreeModel, TreeModelExt, TreeModelFilter, TreeModelFilterExt, TreePath};
+#[cfg(any(feature = "v3_16", feature = "dox"))]
+use gio::prelude::*;
 
 impl TreeModelFilter {
     #[doc(alias = "gtk_tree_model_filter_new")]
@@ -18,3 +21,128 @@ impl TreeModelFilter {
         }
     }
 }
+
+pub trait TreeModelFilterExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Sets `func` like `set_visible_func`, only keeping a weak reference to `object`, which is
+    /// passed to it.
+    ///
+    /// This avoids reference cycles when the visible function needs e.g. the search entry
+    /// the rows are filtered by. All rows are visible once `object` is destroyed.
+    ///
+    /// Like `set_visible_func`, this can only be called once for each filter model, and not
+    /// after `set_visible_column`.
+    fn set_visible_func_weak<T, F>(&self, object: &T, func: F)
+    where
+        T: ObjectType,
+        F: Fn(&T, &TreeModel, &TreeIter) -> bool + 'static;
+
+    // rustdoc-stripper-ignore-next
+    /// Re-evaluates the visible function whenever the property `property_name` of `object`
+    /// changes, e.g. the `text` of the search entry the rows are filtered by.
+    ///
+    /// The returned handler is connected to `object` and only keeps a weak reference to the
+    /// filter model.
+    fn refilter_on_notify<P: IsA<glib::Object>>(
+        &self,
+        object: &P,
+        property_name: &str,
+    ) -> SignalHandlerId;
+
+    // rustdoc-stripper-ignore-next
+    /// Shows the rows whose object in `column` is matched by `filter`, filtering the rows again
+    /// whenever the filter emits `changed`.
+    ///
+    /// This allows sharing one filter, e.g. for a search, between this model and
+    /// `gio::FilterListModel`s. Rows without an object in `column` are hidden.
+    ///
+    /// The returned handler is connected to `filter` and only keeps a weak reference to the
+    /// filter model.
+    ///
+    /// This sets the visible function of the filter model, so like `set_visible_func` it can
+    /// only be called once for each filter model, and not after `set_visible_column`. Later
+    /// calls are ignored by GTK with a critical warning. To filter by another filter, use one
+    /// that can be changed, e.g. a `gio::CustomFilter` whose function is replaced.
+    #[cfg(any(feature = "v3_16", feature = "dox"))]
+    fn set_visible_filter<P: IsA<gio::Filter>>(&self, filter: &P, column: i32) -> SignalHandlerId;
+
+    // rustdoc-stripper-ignore-next
+    /// Converts `child_path` to the path of the same row in the filter model.
+    ///
+    /// Fails if the row is not visible, or if `child_path` does not point to a row of the
+    /// child model.
+    fn child_path_to_path(&self, child_path: &TreePath) -> Result<TreePath, glib::BoolError>;
+
+    // rustdoc-stripper-ignore-next
+    /// Converts `filter_path` to the path of the same row in the child model.
+    ///
+    /// Fails if `filter_path` does not point to a row of the filter model.
+    fn path_to_child_path(&self, filter_path: &TreePath) -> Result<TreePath, glib::BoolError>;
+
+    // rustdoc-stripper-ignore-next
+    /// Converts `child_iter` to an iter pointing to the same row in the filter model.
+    ///
+    /// Fails if the row is not visible.
+    fn child_iter_to_iter(&self, child_iter: &TreeIter) -> Result<TreeIter, glib::BoolError>;
+}
+
+impl<O: IsA<TreeModelFilter>> TreeModelFilterExtManual for O {
+    fn set_visible_func_weak<T, F>(&self, object: &T, func: F)
+    where
+        T: ObjectType,
+        F: Fn(&T, &TreeModel, &TreeIter) -> bool + 'static,
+    {
+        let object = object.downgrade();
+        self.set_visible_func(move |model, iter| match object.upgrade() {
+            Some(object) => func(&object, model, iter),
+            None => true,
+        });
+    }
+
+    fn refilter_on_notify<P: IsA<glib::Object>>(
+        &self,
+        object: &P,
+        property_name: &str,
+    ) -> SignalHandlerId {
+        let filter = self.as_ref().downgrade();
+        object.connect_notify_local(Some(property_name), move |_, _| {
+            if let Some(filter) = filter.upgrade() {
+                filter.refilter();
+            }
+        })
+    }
+
+    #[cfg(any(feature = "v3_16", feature = "dox"))]
+    fn set_visible_filter<P: IsA<gio::Filter>>(&self, filter: &P, column: i32) -> SignalHandlerId {
+        let filter = filter.as_ref().clone();
+        self.set_visible_func({
+            let filter = filter.clone();
+            move |model, iter| match model.get_value(iter, column).get::<glib::Object>() {
+                Ok(Some(item)) => filter.matches(&item),
+                _ => false,
+            }
+        });
+
+        let model = self.as_ref().downgrade();
+        filter.connect_changed(move |_| {
+            if let Some(model) = model.upgrade() {
+                model.refilter();
+            }
+        })
+    }
+
+    fn child_path_to_path(&self, child_path: &TreePath) -> Result<TreePath, glib::BoolError> {
+        self.convert_child_path_to_path(child_path)
+            .ok_or_else(|| glib::bool_error!("Row is not visible in the filter model"))
+    }
+
+    fn path_to_child_path(&self, filter_path: &TreePath) -> Result<TreePath, glib::BoolError> {
+        self.convert_path_to_child_path(filter_path)
+            .ok_or_else(|| glib::bool_error!("Path does not point to a row of the filter model"))
+    }
+
+    fn child_iter_to_iter(&self, child_iter: &TreeIter) -> Result<TreeIter, glib::BoolError> {
+        self.convert_child_iter_to_iter(child_iter)
+            .ok_or_else(|| glib::bool_error!("Row is not visible in the filter model"))
+    }
+}
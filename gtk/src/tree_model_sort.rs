@@ -0,0 +1,55 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::object::IsA;
+
+use crate::{TreeIter, TreeModelSort, TreeModelSortExt, TreePath};
+
+pub trait TreeModelSortExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Converts `child_path` to the path of the same row in the sort model.
+    ///
+    /// Fails if `child_path` does not point to a row of the child model.
+    fn child_path_to_path(&self, child_path: &TreePath) -> Result<TreePath, glib::BoolError>;
+
+    // rustdoc-stripper-ignore-next
+    /// Converts `sorted_path` to the path of the same row in the child model.
+    ///
+    /// Fails if `sorted_path` does not point to a row of the sort model.
+    fn path_to_child_path(&self, sorted_path: &TreePath) -> Result<TreePath, glib::BoolError>;
+
+    // rustdoc-stripper-ignore-next
+    /// Converts `child_iter` to an iter pointing to the same row in the sort model.
+    fn child_iter_to_iter(&self, child_iter: &TreeIter) -> Result<TreeIter, glib::BoolError>;
+
+    // rustdoc-stripper-ignore-next
+    /// Converts `sorted_iter` to an iter pointing to the same row in the child model.
+    ///
+    /// Fails if `sorted_iter` is not a valid iter of the sort model, e.g. because the model
+    /// changed since it was obtained.
+    fn iter_to_child_iter(&self, sorted_iter: &TreeIter) -> Result<TreeIter, glib::BoolError>;
+}
+
+impl<O: IsA<TreeModelSort>> TreeModelSortExtManual for O {
+    fn child_path_to_path(&self, child_path: &TreePath) -> Result<TreePath, glib::BoolError> {
+        self.convert_child_path_to_path(child_path)
+            .ok_or_else(|| glib::bool_error!("Path does not point to a row of the child model"))
+    }
+
+    fn path_to_child_path(&self, sorted_path: &TreePath) -> Result<TreePath, glib::BoolError> {
+        self.convert_path_to_child_path(sorted_path)
+            .ok_or_else(|| glib::bool_error!("Path does not point to a row of the sort model"))
+    }
+
+    fn child_iter_to_iter(&self, child_iter: &TreeIter) -> Result<TreeIter, glib::BoolError> {
+        self.convert_child_iter_to_iter(child_iter)
+            .ok_or_else(|| glib::bool_error!("Iter does not point to a row of the child model"))
+    }
+
+    fn iter_to_child_iter(&self, sorted_iter: &TreeIter) -> Result<TreeIter, glib::BoolError> {
+        if self.iter_is_valid(sorted_iter) {
+            Ok(self.convert_iter_to_child_iter(sorted_iter))
+        } else {
+            Err(glib::bool_error!("Iter is not valid for the sort model"))
+        }
+    }
+}
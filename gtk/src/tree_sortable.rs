@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::SortType;
-use glib::object::IsA;
+use glib::object::{IsA, ObjectExt, ObjectType};
 use glib::translate::*;
 use std::cmp::Ordering;
 use std::fmt;
@@ -71,6 +71,17 @@ pub trait TreeSortableExtManual: 'static {
     fn get_sort_column_id(&self) -> Option<(SortColumn, SortType)>;
     fn set_sort_column_id(&self, sort_column_id: SortColumn, order: SortType);
     fn set_unsorted(&self);
+
+    // rustdoc-stripper-ignore-next
+    /// Sets `sort_func` like [`set_sort_func`](Self::set_sort_func), only keeping a weak
+    /// reference to `object`, which is passed to it.
+    ///
+    /// This avoids reference cycles when the sort function needs e.g. the widget showing the
+    /// model. All rows compare equal once `object` is destroyed.
+    fn set_sort_func_weak<T, F>(&self, sort_column_id: SortColumn, object: &T, sort_func: F)
+    where
+        T: ObjectType,
+        F: Fn(&T, &TreeModel, &TreeIter, &TreeIter) -> Ordering + 'static;
 }
 
 fn into_raw<F, T>(func: F) -> gpointer
@@ -193,4 +204,16 @@ impl<O: IsA<TreeSortable>> TreeSortableExtManual for O {
             )
         }
     }
+
+    fn set_sort_func_weak<T, F>(&self, sort_column_id: SortColumn, object: &T, sort_func: F)
+    where
+        T: ObjectType,
+        F: Fn(&T, &TreeModel, &TreeIter, &TreeIter) -> Ordering + 'static,
+    {
+        let object = object.downgrade();
+        self.set_sort_func(sort_column_id, move |model, a, b| match object.upgrade() {
+            Some(object) => sort_func(&object, model, a, b),
+            None => Ordering::Equal,
+        });
+    }
 }
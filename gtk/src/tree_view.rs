@@ -0,0 +1,165 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::value::FromValueOptional;
+use std::cmp::Ordering;
+
+use crate::prelude::*;
+use crate::{
+    SortColumn, TreeIter, TreeModel, TreeSortable, TreeView, TreeViewColumn, TreeViewColumnSizing,
+};
+
+pub trait TreeViewExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Sorts the rows with `sort_func` when the header of `column` is clicked.
+    ///
+    /// `sort_func` is set as the sort function of `sort_column_id` on the model of the tree view,
+    /// which has to implement `TreeSortable`, e.g. a `TreeModelSort` or a `ListStore`. The header
+    /// of `column` toggles between ascending and descending order and shows the sort indicator
+    /// while the model is sorted by `sort_column_id`.
+    ///
+    /// Fails if the tree view has no model or the model does not implement `TreeSortable`.
+    fn set_column_sort_func<P, F>(
+        &self,
+        column: &P,
+        sort_column_id: u32,
+        sort_func: F,
+    ) -> Result<(), glib::BoolError>
+    where
+        P: IsA<TreeViewColumn>,
+        F: Fn(&TreeModel, &TreeIter, &TreeIter) -> Ordering + 'static;
+
+    // rustdoc-stripper-ignore-next
+    /// Sorts the rows by the values of type `T` in `model_column` when the header of `column` is
+    /// clicked, like [`set_column_sort_func`](Self::set_column_sort_func).
+    ///
+    /// Rows without a value of type `T` are sorted before all other rows.
+    fn set_column_sort_by<T, P>(
+        &self,
+        column: &P,
+        sort_column_id: u32,
+        model_column: i32,
+    ) -> Result<(), glib::BoolError>
+    where
+        T: for<'a> FromValueOptional<'a> + Ord + 'static,
+        P: IsA<TreeViewColumn>;
+
+    // rustdoc-stripper-ignore-next
+    /// Saves the order, visibility and width of the columns to `key` of `settings`, which has to
+    /// have the type `a(sbi)`.
+    ///
+    /// Columns are identified by their buildable name, i.e. their id in a UI file or the name set
+    /// with `BuildableExtManual::set_buildable_name`. Columns without a name are not saved.
+    fn save_columns(&self, settings: &gio::Settings, key: &str) -> Result<(), glib::BoolError>;
+
+    // rustdoc-stripper-ignore-next
+    /// Restores the order, visibility and width of the columns saved with
+    /// [`save_columns`](Self::save_columns).
+    ///
+    /// Columns with a saved width get fixed sizing, as their fixed width is not used otherwise.
+    /// Saved columns that no longer exist are ignored, and columns that were not saved are kept
+    /// after the restored ones.
+    fn restore_columns(&self, settings: &gio::Settings, key: &str) -> Result<(), glib::BoolError>;
+}
+
+impl<O: IsA<TreeView>> TreeViewExtManual for O {
+    fn set_column_sort_func<P, F>(
+        &self,
+        column: &P,
+        sort_column_id: u32,
+        sort_func: F,
+    ) -> Result<(), glib::BoolError>
+    where
+        P: IsA<TreeViewColumn>,
+        F: Fn(&TreeModel, &TreeIter, &TreeIter) -> Ordering + 'static,
+    {
+        let sortable = self
+            .get_model()
+            .and_then(|model| model.dynamic_cast::<TreeSortable>().ok())
+            .ok_or_else(|| glib::bool_error!("Tree view has no sortable model"))?;
+        assert!(
+            sort_column_id <= i32::max_value() as u32,
+            "sort column id is too big"
+        );
+
+        sortable.set_sort_func(SortColumn::Index(sort_column_id), sort_func);
+        // Makes the header clickable and shows the sort indicator
+        column.as_ref().set_sort_column_id(sort_column_id as i32);
+        Ok(())
+    }
+
+    fn set_column_sort_by<T, P>(
+        &self,
+        column: &P,
+        sort_column_id: u32,
+        model_column: i32,
+    ) -> Result<(), glib::BoolError>
+    where
+        T: for<'a> FromValueOptional<'a> + Ord + 'static,
+        P: IsA<TreeViewColumn>,
+    {
+        self.set_column_sort_func(column, sort_column_id, move |model, a, b| {
+            let a = model.get_value(a, model_column);
+            let b = model.get_value(b, model_column);
+            a.get::<T>()
+                .unwrap_or(None)
+                .cmp(&b.get::<T>().unwrap_or(None))
+        })
+    }
+
+    fn save_columns(&self, settings: &gio::Settings, key: &str) -> Result<(), glib::BoolError> {
+        let columns = self
+            .get_columns()
+            .into_iter()
+            .filter_map(|column| {
+                let name = column.get_buildable_name()?;
+                Some((name, column.get_visible(), column_width(&column)))
+            })
+            .collect::<Vec<_>>();
+
+        settings.set_value(key, &columns.to_variant())
+    }
+
+    fn restore_columns(&self, settings: &gio::Settings, key: &str) -> Result<(), glib::BoolError> {
+        let value = settings.get_value(key);
+        let saved = value
+            .iter_as::<(String, bool, i32)>()
+            .ok_or_else(|| glib::bool_error!("Key {} does not have the type a(sbi)", key))?;
+
+        let columns = self.get_columns();
+        let mut previous: Option<TreeViewColumn> = None;
+        for (name, visible, width) in saved {
+            let column = match columns
+                .iter()
+                .find(|column| column.get_buildable_name().as_deref() == Some(name.as_str()))
+            {
+                Some(column) => column,
+                None => continue,
+            };
+
+            self.move_column_after(column, previous.as_ref());
+            column.set_visible(visible);
+            restore_column_width(column, width);
+            previous = Some(column.clone());
+        }
+
+        Ok(())
+    }
+}
+
+// The width to save for `column`, also used by `state::persist_columns`. Hidden columns have no
+// allocated width, so their fixed width is saved instead
+pub(crate) fn column_width(column: &TreeViewColumn) -> i32 {
+    if column.get_visible() {
+        column.get_width()
+    } else {
+        column.get_fixed_width()
+    }
+}
+
+// The fixed width is only used as the width of columns with fixed sizing
+pub(crate) fn restore_column_width(column: &TreeViewColumn, width: i32) {
+    if width > 0 {
+        column.set_sizing(TreeViewColumnSizing::Fixed);
+        column.set_fixed_width(width);
+    }
+}
@@ -0,0 +1,583 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::*;
+use crate::{Adjustment, Inhibit, Scale, SpinButton};
+use glib::{ParamSpec, SignalHandlerId, Type, Value};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// A numeric type that can be the value of a [`TypedAdjustment`].
+///
+/// Implemented for all integer types, `f32`, `f64` and `Duration`.
+pub trait AdjustmentValue: Copy + PartialOrd + 'static {
+    /// The smallest value of the type in the unit of the adjustment.
+    const MIN: f64;
+    /// The biggest value of the type in the unit of the adjustment.
+    const MAX: f64;
+    /// The default step increment.
+    const STEP: f64;
+    /// The default number of decimal digits that are shown.
+    const DIGITS: u32;
+
+    /// Converts the value to the unit of the adjustment.
+    fn to_adjustment_value(self) -> f64;
+
+    /// Converts a value in the unit of the adjustment, rounding and clamping it to the type.
+    fn from_adjustment_value(value: f64) -> Self;
+
+    /// Returns the factor to convert the value of a numeric property of type `type_` to the unit
+    /// of the adjustment.
+    fn property_scale(_type_: Type) -> f64 {
+        1.0
+    }
+
+    /// Formats the value as shown by spin buttons and scales without a format function.
+    ///
+    /// Unlike the default output of GTK this does not depend on the locale, so that it can
+    /// always be parsed again by [`parse`](Self::parse).
+    fn format(self) -> String {
+        format!("{:.*}", Self::DIGITS as usize, self.to_adjustment_value())
+    }
+
+    /// Parses text entered into spin buttons without a parse function.
+    fn parse(text: &str) -> Option<Self> {
+        let value = text.trim().parse::<f64>().ok()?;
+        if value.is_finite() {
+            Some(Self::from_adjustment_value(value))
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! integer_adjustment_value {
+    ($($type_:ty),*) => {
+        $(
+            impl AdjustmentValue for $type_ {
+                const MIN: f64 = <$type_>::min_value() as f64;
+                const MAX: f64 = <$type_>::max_value() as f64;
+                const STEP: f64 = 1.0;
+                const DIGITS: u32 = 0;
+
+                fn to_adjustment_value(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_adjustment_value(value: f64) -> Self {
+                    // Casts saturate
+                    value.round() as $type_
+                }
+            }
+        )*
+    };
+}
+
+integer_adjustment_value!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+// The full range of floating point values is limited to the integers that `f64` represents
+// exactly, as the huge differences of `f64::MIN` and `f64::MAX` overflow to infinity in the
+// calculations of `Adjustment`, e.g. of the page size and of the position of scale sliders
+const FLOAT_LIMIT: f64 = 9_007_199_254_740_992.0;
+
+/// The full range is limited to ±2^53.
+impl AdjustmentValue for f32 {
+    const MIN: f64 = -FLOAT_LIMIT;
+    const MAX: f64 = FLOAT_LIMIT;
+    const STEP: f64 = 0.1;
+    const DIGITS: u32 = 2;
+
+    fn to_adjustment_value(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_adjustment_value(value: f64) -> Self {
+        value as f32
+    }
+}
+
+/// The full range is limited to ±2^53.
+impl AdjustmentValue for f64 {
+    const MIN: f64 = -FLOAT_LIMIT;
+    const MAX: f64 = FLOAT_LIMIT;
+    const STEP: f64 = 0.1;
+    const DIGITS: u32 = 2;
+
+    fn to_adjustment_value(self) -> f64 {
+        self
+    }
+
+    fn from_adjustment_value(value: f64) -> Self {
+        value
+    }
+}
+
+/// Durations are adjusted in seconds. Integer properties are interpreted as microseconds like
+/// `GTimeSpan`, floating point properties as seconds.
+impl AdjustmentValue for Duration {
+    const MIN: f64 = 0.0;
+    // `Duration::from_secs_f64()` panics on overflow, so stay far below `u64::MAX` seconds.
+    const MAX: f64 = std::u32::MAX as f64;
+    const STEP: f64 = 1.0;
+    const DIGITS: u32 = 1;
+
+    fn to_adjustment_value(self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    fn from_adjustment_value(value: f64) -> Self {
+        // Not the inherent `Duration::MAX`
+        let max = <Self as AdjustmentValue>::MAX;
+        if value > max {
+            Duration::from_secs_f64(max)
+        } else if value > 0.0 {
+            Duration::from_secs_f64(value)
+        } else {
+            Duration::from_secs(0)
+        }
+    }
+
+    fn property_scale(type_: Type) -> f64 {
+        if type_ == Type::F32 || type_ == Type::F64 {
+            1.0
+        } else {
+            1e-6
+        }
+    }
+}
+
+type FormatFunc<T> = dyn Fn(T) -> String;
+type ParseFunc<T> = dyn Fn(&str) -> Option<T>;
+
+/// An [`Adjustment`] holding values of type `T`, e.g. an integer or a `Duration`.
+///
+/// The range, step increment and number of shown digits default to what makes sense for `T`,
+/// and values shown by and entered into spin buttons and scales are converted with the format
+/// and parse functions instead of the locale dependent conversion of GTK.
+///
+/// ```no_run
+/// use gtk::TypedAdjustment;
+///
+/// fn parse_size(text: &str) -> Option<u64> {
+///     let text = text.trim();
+///     let (number, factor) = match text.find(|c: char| c.is_alphabetic()) {
+///         Some(pos) => match text[pos..].trim() {
+///             "B" | "bytes" => (&text[..pos], 1),
+///             "KiB" => (&text[..pos], 1 << 10),
+///             "MiB" => (&text[..pos], 1 << 20),
+///             "GiB" => (&text[..pos], 1 << 30),
+///             _ => return None,
+///         },
+///         None => (text, 1),
+///     };
+///     let number = number.trim().parse::<f64>().ok()?;
+///     Some((number * factor as f64) as u64)
+/// }
+///
+/// # fn example(spin_button: &gtk::SpinButton, settings: &glib::Object) {
+/// // The range is restricted further to the one of the property below
+/// let cache_size = TypedAdjustment::<u64>::new(0, 16 << 30);
+/// cache_size.set_step(1 << 20);
+/// cache_size.set_format(|size| {
+///     glib::format_size_full(size, glib::FormatSizeFlags::IEC_UNITS).into()
+/// });
+/// cache_size.set_parse(parse_size);
+/// cache_size.attach_spin_button(spin_button);
+/// cache_size
+///     .bind_property(settings, "cache-size")
+///     .expect("Failed to bind cache size");
+/// # }
+/// ```
+pub struct TypedAdjustment<T>(Rc<Inner<T>>);
+
+struct Inner<T> {
+    adjustment: Adjustment,
+    format: RefCell<Option<Rc<FormatFunc<T>>>>,
+    parse: RefCell<Option<Rc<ParseFunc<T>>>>,
+}
+
+impl<T> Clone for TypedAdjustment<T> {
+    fn clone(&self) -> Self {
+        TypedAdjustment(self.0.clone())
+    }
+}
+
+impl<T: AdjustmentValue> TypedAdjustment<T> {
+    /// Creates an adjustment for the values from `lower` to `upper`, starting at `lower`.
+    pub fn new(lower: T, upper: T) -> Self {
+        assert_initialized_main_thread!();
+        let lower = lower.to_adjustment_value();
+        let adjustment = Adjustment::new(
+            lower,
+            lower,
+            upper.to_adjustment_value(),
+            T::STEP,
+            T::STEP * 10.0,
+            0.0,
+        );
+        TypedAdjustment(Rc::new(Inner {
+            adjustment,
+            format: RefCell::new(None),
+            parse: RefCell::new(None),
+        }))
+    }
+
+    /// Creates an adjustment for all values of `T`.
+    pub fn with_full_range() -> Self {
+        Self::new(
+            T::from_adjustment_value(T::MIN),
+            T::from_adjustment_value(T::MAX),
+        )
+    }
+
+    /// Returns the underlying adjustment.
+    pub fn get_adjustment(&self) -> &Adjustment {
+        &self.0.adjustment
+    }
+
+    pub fn get_value(&self) -> T {
+        T::from_adjustment_value(self.0.adjustment.get_value())
+    }
+
+    /// Sets the value, clamped to the range of the adjustment.
+    pub fn set_value(&self, value: T) {
+        self.0.adjustment.set_value(value.to_adjustment_value());
+    }
+
+    pub fn get_lower(&self) -> T {
+        T::from_adjustment_value(self.0.adjustment.get_lower())
+    }
+
+    pub fn get_upper(&self) -> T {
+        T::from_adjustment_value(self.0.adjustment.get_upper())
+    }
+
+    /// Sets the range of the values, clamping the current value to it.
+    pub fn set_range(&self, lower: T, upper: T) {
+        let adjustment = &self.0.adjustment;
+        let lower = lower.to_adjustment_value();
+        let upper = upper.to_adjustment_value();
+        let value = adjustment.get_value();
+        adjustment.configure(
+            if value < lower {
+                lower
+            } else if value > upper {
+                upper
+            } else {
+                value
+            },
+            lower,
+            upper,
+            adjustment.get_step_increment(),
+            adjustment.get_page_increment(),
+            0.0,
+        );
+    }
+
+    /// Sets the step increment to `step` and the page increment to ten steps.
+    pub fn set_step(&self, step: T) {
+        let step = step.to_adjustment_value();
+        self.0.adjustment.set_step_increment(step);
+        self.0.adjustment.set_page_increment(step * 10.0);
+    }
+
+    /// Sets the function formatting the values shown by attached spin buttons and scales.
+    ///
+    /// Defaults to [`AdjustmentValue::format`].
+    pub fn set_format<F: Fn(T) -> String + 'static>(&self, format: F) {
+        self.0.format.replace(Some(Rc::new(format)));
+    }
+
+    /// Sets the function parsing the text entered into attached spin buttons, which returns
+    /// `None` if the text is invalid.
+    ///
+    /// Defaults to [`AdjustmentValue::parse`].
+    pub fn set_parse<F: Fn(&str) -> Option<T> + 'static>(&self, parse: F) {
+        self.0.parse.replace(Some(Rc::new(parse)));
+    }
+
+    fn format(&self, value: f64) -> String {
+        let value = T::from_adjustment_value(value);
+        let format = self.0.format.borrow().clone();
+        match format {
+            Some(format) => format(value),
+            None => value.format(),
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<T> {
+        let parse = self.0.parse.borrow().clone();
+        match parse {
+            Some(parse) => parse(text),
+            None => T::parse(text),
+        }
+    }
+
+    /// Uses the adjustment for `spin_button` and its format and parse functions for the text of
+    /// `spin_button`.
+    pub fn attach_spin_button<P: IsA<SpinButton>>(&self, spin_button: &P) {
+        let spin_button = spin_button.as_ref();
+        spin_button.set_adjustment(&self.0.adjustment);
+        spin_button.set_digits(T::DIGITS);
+        // Numeric mode would reject units in the text
+        spin_button.set_numeric(false);
+
+        let this = self.clone();
+        spin_button.connect_output(move |spin_button| {
+            let text = this.format(spin_button.get_value());
+            if spin_button.get_text() != text {
+                spin_button.set_text(&text);
+            }
+            Inhibit(true)
+        });
+        let this = self.clone();
+        spin_button.connect_input(move |spin_button| {
+            Some(
+                this.parse(&spin_button.get_text())
+                    .map(T::to_adjustment_value)
+                    .ok_or(()),
+            )
+        });
+        spin_button.update();
+    }
+
+    /// Uses the adjustment for `scale` and its format function for the value shown by `scale`.
+    pub fn attach_scale<P: IsA<Scale>>(&self, scale: &P) {
+        let scale = scale.as_ref();
+        scale.set_adjustment(&self.0.adjustment);
+        scale.set_digits(T::DIGITS as i32);
+        scale.set_round_digits(T::DIGITS as i32);
+
+        let this = self.clone();
+        scale.connect_format_value(move |_, value| this.format(value));
+    }
+
+    /// Binds the value bidirectionally to the numeric `property` of `object`, restricting the
+    /// range of the adjustment to the range of the property.
+    ///
+    /// The value of the adjustment is set to the value of the property.
+    pub fn bind_property<O: IsA<glib::Object>>(
+        &self,
+        object: &O,
+        property: &str,
+    ) -> Result<glib::Binding, glib::BoolError> {
+        let pspec = object.find_property(property).ok_or_else(|| {
+            glib::bool_error!("Type {} has no property {}", object.get_type(), property)
+        })?;
+        let type_ = pspec.get_value_type();
+        let (min, max) = property_range(&pspec)
+            .ok_or_else(|| glib::bool_error!("Property {} is not numeric", property))?;
+        let scale = T::property_scale(type_);
+
+        let adjustment = &self.0.adjustment;
+        let lower = adjustment.get_lower().max(min * scale);
+        let upper = adjustment.get_upper().min(max * scale);
+        self.set_range(
+            T::from_adjustment_value(lower),
+            T::from_adjustment_value(upper),
+        );
+
+        adjustment
+            .bind_property("value", object, property)
+            .transform_to(move |_, value| {
+                let value = T::from_adjustment_value(value.get_some::<f64>().ok()?);
+                property_value(value.to_adjustment_value() / scale, type_)
+            })
+            .transform_from(move |_, value| {
+                let value = value.transform::<f64>()?.get_some::<f64>().ok()?;
+                Some(
+                    T::from_adjustment_value(value * scale)
+                        .to_adjustment_value()
+                        .to_value(),
+                )
+            })
+            .flags(glib::BindingFlags::BIDIRECTIONAL | glib::BindingFlags::SYNC_CREATE)
+            .build()
+            .ok_or_else(|| glib::bool_error!("Failed to bind property {}", property))
+    }
+
+    pub fn connect_value_changed<F: Fn(T) + 'static>(&self, f: F) -> SignalHandlerId {
+        self.0.adjustment.connect_value_changed(move |adjustment| {
+            f(T::from_adjustment_value(adjustment.get_value()))
+        })
+    }
+}
+
+impl<T: AdjustmentValue> Default for TypedAdjustment<T> {
+    fn default() -> Self {
+        Self::with_full_range()
+    }
+}
+
+impl<T: AdjustmentValue + fmt::Debug> fmt::Debug for TypedAdjustment<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedAdjustment")
+            .field("value", &self.get_value())
+            .field("lower", &self.get_lower())
+            .field("upper", &self.get_upper())
+            .finish()
+    }
+}
+
+fn property_range(pspec: &ParamSpec) -> Option<(f64, f64)> {
+    macro_rules! range {
+        ($($pspec_type:ident),*) => {
+            $(
+                if let Some(pspec) = pspec.downcast_ref::<glib::$pspec_type>() {
+                    return Some((pspec.get_minimum() as f64, pspec.get_maximum() as f64));
+                }
+            )*
+        };
+    }
+
+    range!(
+        ParamSpecChar,
+        ParamSpecUChar,
+        ParamSpecInt,
+        ParamSpecUInt,
+        ParamSpecLong,
+        ParamSpecULong,
+        ParamSpecInt64,
+        ParamSpecUInt64,
+        ParamSpecFloat,
+        ParamSpecDouble
+    );
+    None
+}
+
+fn property_value(value: f64, type_: Type) -> Option<Value> {
+    if type_ == Type::F64 {
+        return Some(value.to_value());
+    } else if type_ == Type::F32 {
+        return Some((value as f32).to_value());
+    }
+
+    // Casts saturate
+    let value = value.round();
+    let value = if type_ == Type::I8 {
+        (value as i8).to_value()
+    } else if type_ == Type::U8 {
+        (value as u8).to_value()
+    } else if type_ == Type::I32 {
+        (value as i32).to_value()
+    } else if type_ == Type::U32 {
+        (value as u32).to_value()
+    } else if type_ == Type::I_LONG {
+        glib::ILong(value as libc::c_long).to_value()
+    } else if type_ == Type::U_LONG {
+        glib::ULong(value as libc::c_ulong).to_value()
+    } else if type_ == Type::I64 {
+        (value as i64).to_value()
+    } else if type_ == Type::U64 {
+        (value as u64).to_value()
+    } else {
+        return None;
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glib::ParamFlags;
+
+    #[test]
+    fn integer_values_saturate() {
+        assert_eq!(u8::from_adjustment_value(-3.0), 0);
+        assert_eq!(u8::from_adjustment_value(255.6), 255);
+        assert_eq!(i8::from_adjustment_value(-1000.0), -128);
+        assert_eq!(i32::from_adjustment_value(1.5), 2);
+        assert_eq!(u64::from_adjustment_value(f64::NAN), 0);
+        assert_eq!(
+            u64::from_adjustment_value(<u64 as AdjustmentValue>::MAX),
+            u64::max_value()
+        );
+
+        assert_eq!(u8::parse(" 12 "), Some(12));
+        assert_eq!(u8::parse("inf"), None);
+        assert_eq!(u8::parse("twelve"), None);
+        assert_eq!(42i32.format(), "42");
+    }
+
+    #[test]
+    fn float_values_have_a_finite_range() {
+        assert!((<f64 as AdjustmentValue>::MAX - <f64 as AdjustmentValue>::MIN).is_finite());
+        assert!((<f32 as AdjustmentValue>::MAX - <f32 as AdjustmentValue>::MIN).is_finite());
+        assert_eq!(0.25f64.format(), "0.25");
+        assert_eq!(f64::parse("1.5"), Some(1.5));
+    }
+
+    #[test]
+    fn durations_are_clamped() {
+        assert_eq!(
+            Duration::from_adjustment_value(1.5),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(
+            Duration::from_adjustment_value(-1.0),
+            Duration::from_secs(0)
+        );
+        assert_eq!(
+            Duration::from_adjustment_value(f64::NAN),
+            Duration::from_secs(0)
+        );
+        assert_eq!(
+            Duration::from_adjustment_value(f64::INFINITY),
+            Duration::from_secs(u64::from(std::u32::MAX))
+        );
+        assert_eq!(Duration::from_millis(2500).format(), "2.5");
+
+        assert_eq!(Duration::property_scale(Type::F64), 1.0);
+        assert_eq!(Duration::property_scale(Type::I64), 1e-6);
+    }
+
+    #[test]
+    fn property_ranges() {
+        let pspec = ParamSpec::char("char", "Char", "Char", -5, 10, 0, ParamFlags::READWRITE);
+        assert_eq!(property_range(&pspec), Some((-5.0, 10.0)));
+        let pspec = ParamSpec::double(
+            "double",
+            "Double",
+            "Double",
+            0.5,
+            1.5,
+            1.0,
+            ParamFlags::READWRITE,
+        );
+        assert_eq!(property_range(&pspec), Some((0.5, 1.5)));
+        let pspec = ParamSpec::uint64(
+            "uint64",
+            "UInt64",
+            "UInt64",
+            1,
+            100,
+            1,
+            ParamFlags::READWRITE,
+        );
+        assert_eq!(property_range(&pspec), Some((1.0, 100.0)));
+        let pspec = ParamSpec::boolean(
+            "boolean",
+            "Boolean",
+            "Boolean",
+            false,
+            ParamFlags::READWRITE,
+        );
+        assert_eq!(property_range(&pspec), None);
+    }
+
+    #[test]
+    fn property_values() {
+        let value = property_value(1.25, Type::F64).unwrap();
+        assert_eq!(value.get_some::<f64>(), Ok(1.25));
+        let value = property_value(1.25, Type::F32).unwrap();
+        assert_eq!(value.get_some::<f32>(), Ok(1.25));
+        let value = property_value(2.5, Type::I32).unwrap();
+        assert_eq!(value.get_some::<i32>(), Ok(3));
+        let value = property_value(300.0, Type::U8).unwrap();
+        assert_eq!(value.get_some::<u8>(), Ok(255));
+        let value = property_value(-1.0, Type::U64).unwrap();
+        assert_eq!(value.get_some::<u64>(), Ok(0));
+        assert!(property_value(1.0, Type::BOOL).is_none());
+    }
+}
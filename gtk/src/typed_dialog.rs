@@ -0,0 +1,295 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Dialog, DialogExt, DialogExtManual, DialogFlags, ResponseType, Widget, Window};
+use glib::object::{Cast, IsA};
+use glib::signal::SignalHandlerId;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// A builder for a [`TypedDialog`], whose buttons respond with values of type `R` instead of
+/// [`ResponseType`]s.
+///
+/// ```no_run
+/// use gtk::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum Choice {
+///     Discard,
+///     Cancel,
+///     Save,
+/// }
+///
+/// # async fn ask(window: &gtk::Window) {
+/// let dialog = gtk::TypedDialogBuilder::new()
+///     .title("Save changes?")
+///     .transient_for(window)
+///     .modal(true)
+///     .button("Discard", Choice::Discard)
+///     .button("Cancel", Choice::Cancel)
+///     .default_button("Save", Choice::Save)
+///     .close_response(Choice::Cancel)
+///     .build();
+///
+/// let choice = dialog.run_future().await;
+/// dialog.get_dialog().close();
+/// match choice {
+///     Some(Choice::Save) => println!("Saving"),
+///     Some(Choice::Discard) => println!("Discarding"),
+///     Some(Choice::Cancel) | None => (),
+/// }
+/// # }
+/// ```
+pub struct TypedDialogBuilder<R> {
+    title: Option<String>,
+    transient_for: Option<Window>,
+    flags: DialogFlags,
+    buttons: Vec<(String, R)>,
+    default: Option<usize>,
+    close_response: Option<R>,
+}
+
+impl<R: Clone + 'static> TypedDialogBuilder<R> {
+    /// Creates a new builder for a dialog without buttons.
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            transient_for: None,
+            flags: DialogFlags::empty(),
+            buttons: Vec::new(),
+            default: None,
+            close_response: None,
+        }
+    }
+
+    /// Sets the title of the dialog.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    /// Sets the window the dialog is transient for.
+    pub fn transient_for<P: IsA<Window>>(mut self, parent: &P) -> Self {
+        self.transient_for = Some(parent.clone().upcast());
+        self
+    }
+
+    /// Sets whether the dialog is modal.
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.flags.set(DialogFlags::MODAL, modal);
+        self
+    }
+
+    /// Sets whether the dialog is destroyed together with the window it is transient for.
+    pub fn destroy_with_parent(mut self, destroy_with_parent: bool) -> Self {
+        self.flags
+            .set(DialogFlags::DESTROY_WITH_PARENT, destroy_with_parent);
+        self
+    }
+
+    /// Sets whether the buttons are shown in a header bar instead of the action area.
+    pub fn use_header_bar(mut self, use_header_bar: bool) -> Self {
+        self.flags.set(DialogFlags::USE_HEADER_BAR, use_header_bar);
+        self
+    }
+
+    /// Adds a button labelled `label` that responds with `response`.
+    pub fn button(mut self, label: &str, response: R) -> Self {
+        self.buttons.push((label.to_owned(), response));
+        self
+    }
+
+    /// Adds a button like [`button`](Self::button) and makes it the default response.
+    pub fn default_button(mut self, label: &str, response: R) -> Self {
+        self.default = Some(self.buttons.len());
+        self.button(label, response)
+    }
+
+    /// Sets the response used when the dialog is closed by the window manager or by pressing
+    /// Escape.
+    ///
+    /// Without it closing the dialog results in no response value.
+    pub fn close_response(mut self, response: R) -> Self {
+        self.close_response = Some(response);
+        self
+    }
+
+    /// Creates the dialog.
+    pub fn build(self) -> TypedDialog<R> {
+        assert_initialized_main_thread!();
+        let dialog = Dialog::with_buttons(
+            self.title.as_deref(),
+            self.transient_for.as_ref(),
+            self.flags,
+            &[],
+        );
+
+        for (index, (label, _)) in self.buttons.iter().enumerate() {
+            dialog.add_button(label, ResponseType::Other(index as u16));
+        }
+        if let Some(index) = self.default {
+            dialog.set_default_response(ResponseType::Other(index as u16));
+        }
+
+        TypedDialog {
+            dialog,
+            responses: Rc::new(Responses {
+                buttons: self.buttons.into_iter().map(|(_, r)| r).collect(),
+                close_response: self.close_response,
+            }),
+        }
+    }
+}
+
+impl<R: Clone + 'static> Default for TypedDialogBuilder<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for TypedDialogBuilder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedDialogBuilder")
+            .field("title", &self.title)
+            .field("flags", &self.flags)
+            .field("buttons", &self.buttons)
+            .field("close_response", &self.close_response)
+            .finish()
+    }
+}
+
+/// A [`Dialog`] whose buttons respond with values of type `R`, created by a
+/// [`TypedDialogBuilder`].
+///
+/// Each button is assigned an internal `ResponseType::Other` response, which is translated back
+/// to the value given to the builder, so the response handlers can't confuse the responses of
+/// different buttons.
+pub struct TypedDialog<R> {
+    dialog: Dialog,
+    responses: Rc<Responses<R>>,
+}
+
+struct Responses<R> {
+    buttons: Vec<R>,
+    close_response: Option<R>,
+}
+
+impl<R: Clone> Responses<R> {
+    fn translate(&self, response: ResponseType) -> Option<R> {
+        match response {
+            ResponseType::Other(index) => self.buttons.get(usize::from(index)).cloned(),
+            ResponseType::DeleteEvent => self.close_response.clone(),
+            _ => None,
+        }
+    }
+}
+
+impl<R: Clone + 'static> TypedDialog<R> {
+    /// Returns the underlying dialog, e.g. to add content to it.
+    pub fn get_dialog(&self) -> &Dialog {
+        &self.dialog
+    }
+
+    /// Connects `f` to the responses of the dialog.
+    ///
+    /// `f` is called with `None` for responses that don't belong to a button, e.g. closing the
+    /// dialog without a [`close_response`](TypedDialogBuilder::close_response).
+    pub fn connect_response<F: Fn(&Dialog, Option<R>) + 'static>(&self, f: F) -> SignalHandlerId {
+        let responses = self.responses.clone();
+        self.dialog
+            .connect_response(move |dialog, response| f(dialog, responses.translate(response)))
+    }
+
+    /// Shows the dialog and returns a `Future` that resolves to the response on the first
+    /// response, like [`DialogExtManual::run_future`].
+    pub fn run_future<'a>(&'a self) -> Pin<Box<dyn Future<Output = Option<R>> + 'a>> {
+        Box::pin(async move {
+            let response = self.dialog.run_future().await;
+            self.responses.translate(response)
+        })
+    }
+}
+
+impl<R: Clone + PartialEq + 'static> TypedDialog<R> {
+    /// Emits the response of the button responding with `response`.
+    ///
+    /// Returns `false` if the dialog has no such button.
+    pub fn response(&self, response: &R) -> bool {
+        match self.response_type(response) {
+            Some(response_type) => {
+                self.dialog.response(response_type);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets whether the button responding with `response` is sensitive.
+    pub fn set_response_sensitive(&self, response: &R, sensitive: bool) {
+        if let Some(response_type) = self.response_type(response) {
+            self.dialog.set_response_sensitive(response_type, sensitive);
+        }
+    }
+
+    /// Returns the button responding with `response`.
+    pub fn get_widget_for_response(&self, response: &R) -> Option<Widget> {
+        self.response_type(response)
+            .and_then(|response_type| self.dialog.get_widget_for_response(response_type))
+    }
+
+    fn response_type(&self, response: &R) -> Option<ResponseType> {
+        self.responses
+            .buttons
+            .iter()
+            .position(|r| r == response)
+            .map(|index| ResponseType::Other(index as u16))
+    }
+}
+
+impl<R> Clone for TypedDialog<R> {
+    fn clone(&self) -> Self {
+        Self {
+            dialog: self.dialog.clone(),
+            responses: self.responses.clone(),
+        }
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for TypedDialog<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedDialog")
+            .field("dialog", &self.dialog)
+            .field("buttons", &self.responses.buttons)
+            .field("close_response", &self.responses.close_response)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translate_responses() {
+        let responses = Responses {
+            buttons: vec!["discard", "save"],
+            close_response: Some("cancel"),
+        };
+        assert_eq!(responses.translate(ResponseType::Other(0)), Some("discard"));
+        assert_eq!(responses.translate(ResponseType::Other(1)), Some("save"));
+        assert_eq!(responses.translate(ResponseType::Other(2)), None);
+        assert_eq!(
+            responses.translate(ResponseType::DeleteEvent),
+            Some("cancel")
+        );
+        // Only the internal responses of the buttons are translated
+        assert_eq!(responses.translate(ResponseType::Accept), None);
+
+        let responses = Responses {
+            buttons: vec!["save"],
+            close_response: None,
+        };
+        assert_eq!(responses.translate(ResponseType::DeleteEvent), None);
+    }
+}
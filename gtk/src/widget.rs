@@ -10,7 +10,10 @@ use glib::ObjectExt;
 use std::mem::transmute;
 use std::ptr;
 
-use crate::{DestDefaults, Rectangle, TargetEntry, Widget, WidgetExt};
+use crate::{
+    BuildableExtManual, Container, ContainerExt, DestDefaults, Rectangle, TargetEntry, Widget,
+    WidgetExt,
+};
 
 pub struct TickCallbackId {
     id: u32,
@@ -79,6 +82,60 @@ pub trait WidgetExtManual: 'static {
 
     #[doc(alias = "gtk_widget_hide_on_delete")]
     fn hide_on_delete(&self) -> Inhibit;
+
+    // rustdoc-stripper-ignore-next
+    /// Creates an image surface of `width` × `height` units suitable for drawing content that is
+    /// later shown in this widget.
+    ///
+    /// The surface gets the scale factor of the widget as device scale, so that it is rendered
+    /// sharp on HiDPI monitors. Returns `None` if the widget is not realized.
+    #[doc(alias = "gdk_window_create_similar_image_surface")]
+    #[doc(alias = "create_similar_for_widget")]
+    fn create_similar_image_surface(
+        &self,
+        format: cairo::Format,
+        width: i32,
+        height: i32,
+    ) -> Option<cairo::Surface>;
+
+    // rustdoc-stripper-ignore-next
+    /// Renders the widget and its children offscreen to a new `Pixbuf`.
+    ///
+    /// The widget is drawn with `gtk_widget_draw()` at its current allocation, including its
+    /// CSS background and borders, onto an image surface with a device scale of `scale`. The
+    /// pixbuf is `scale` times as large as the allocation in each direction, which is useful
+    /// for drag icons and previews on HiDPI screens.
+    ///
+    /// Returns `None` if the widget is not drawable, i.e. not both visible and mapped. Widgets
+    /// that were never shown still report a 1x1 allocation, so the allocation can't tell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is not positive.
+    #[cfg(any(feature = "v3_16", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_16")))]
+    fn render_to_pixbuf(&self, scale: i32) -> Option<gdk_pixbuf::Pixbuf>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns an iterator over all descendants of this widget in depth-first order, not
+    /// including the widget itself.
+    ///
+    /// Internal children like the content area of a `Dialog` are included, as with
+    /// `gtk_container_forall()`.
+    fn descendants(&self) -> Descendants;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns an iterator over the parent of this widget, its parent and so on up to the
+    /// toplevel.
+    fn ancestors(&self) -> Ancestors;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the first descendant whose widget name or `Builder` ID is `name`.
+    fn find_descendant_by_name(&self, name: &str) -> Option<Widget>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the first descendant of type `T`.
+    fn find_descendant_by_type<T: IsA<Widget>>(&self) -> Option<T>;
 }
 
 impl<O: IsA<Widget>> WidgetExtManual for O {
@@ -270,6 +327,118 @@ impl<O: IsA<Widget>> WidgetExtManual for O {
             )))
         }
     }
+
+    fn create_similar_image_surface(
+        &self,
+        format: cairo::Format,
+        width: i32,
+        height: i32,
+    ) -> Option<cairo::Surface> {
+        let widget = self.as_ref();
+        widget.get_window()?.create_similar_image_surface(
+            format,
+            width,
+            height,
+            widget.get_scale_factor(),
+        )
+    }
+
+    #[cfg(any(feature = "v3_16", feature = "dox"))]
+    fn render_to_pixbuf(&self, scale: i32) -> Option<gdk_pixbuf::Pixbuf> {
+        assert!(scale > 0, "Scale must be positive");
+        let widget = self.as_ref();
+        if !widget.is_drawable() {
+            return None;
+        }
+        let (width, height) = (widget.get_allocated_width(), widget.get_allocated_height());
+
+        let surface =
+            cairo::ImageSurface::create(cairo::Format::ARgb32, width * scale, height * scale)
+                .ok()?;
+        surface.set_device_scale(f64::from(scale), f64::from(scale));
+        {
+            let cr = cairo::Context::new(&surface);
+            widget.draw(&cr);
+        }
+
+        gdk::pixbuf_from_surface(
+            &surface,
+            &Rectangle {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            },
+        )
+    }
+
+    fn descendants(&self) -> Descendants {
+        let mut stack = Vec::new();
+        push_children(&mut stack, self.as_ref());
+        Descendants { stack }
+    }
+
+    fn ancestors(&self) -> Ancestors {
+        Ancestors {
+            next: self.get_parent(),
+        }
+    }
+
+    fn find_descendant_by_name(&self, name: &str) -> Option<Widget> {
+        self.descendants().find(|widget| {
+            widget.get_widget_name() == name || widget.get_buildable_name().as_deref() == Some(name)
+        })
+    }
+
+    fn find_descendant_by_type<T: IsA<Widget>>(&self) -> Option<T> {
+        self.descendants()
+            .find_map(|widget| widget.downcast::<T>().ok())
+    }
+}
+
+fn push_children(stack: &mut Vec<Widget>, widget: &Widget) {
+    if let Some(container) = widget.downcast_ref::<Container>() {
+        let len = stack.len();
+        container.forall(|child| stack.push(child.clone()));
+        // Children are popped from the end, so reverse them to visit them in order
+        stack[len..].reverse();
+    }
+}
+
+/// An iterator over the descendants of a widget.
+///
+/// Created with [`WidgetExtManual::descendants`].
+#[derive(Debug)]
+pub struct Descendants {
+    stack: Vec<Widget>,
+}
+
+impl Iterator for Descendants {
+    type Item = Widget;
+
+    fn next(&mut self) -> Option<Widget> {
+        let widget = self.stack.pop()?;
+        push_children(&mut self.stack, &widget);
+        Some(widget)
+    }
+}
+
+/// An iterator over the ancestors of a widget.
+///
+/// Created with [`WidgetExtManual::ancestors`].
+#[derive(Debug)]
+pub struct Ancestors {
+    next: Option<Widget>,
+}
+
+impl Iterator for Ancestors {
+    type Item = Widget;
+
+    fn next(&mut self) -> Option<Widget> {
+        let widget = self.next.take()?;
+        self.next = widget.get_parent();
+        Some(widget)
+    }
 }
 
 pub trait InitializingWidgetExt {
@@ -0,0 +1,141 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![cfg(feature = "canvas")]
+
+use cairo::Matrix;
+use gtk::canvas::{Canvas, CanvasItem};
+use gtk::prelude::*;
+use gtk::OffscreenWindow;
+use std::cell::Cell;
+use std::rc::Rc;
+
+// A 10×10 square counting how often it is drawn
+#[derive(Default)]
+struct Square {
+    draws: Cell<u32>,
+}
+
+impl CanvasItem for Square {
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        (0.0, 0.0, 10.0, 10.0)
+    }
+
+    fn draw(&self, cr: &cairo::Context) {
+        self.draws.set(self.draws.get() + 1);
+        cr.rectangle(0.0, 0.0, 10.0, 10.0);
+        cr.fill();
+    }
+}
+
+fn translate(x: f64, y: f64) -> Matrix {
+    Matrix::new(1.0, 0.0, 0.0, 1.0, x, y)
+}
+
+fn stacking() {
+    let canvas = Canvas::new();
+    let first = canvas.add(Square::default());
+    let second = canvas.add(Square::default());
+    let third = canvas.add(Square::default());
+    assert_eq!(canvas.items(), [first, second, third]);
+
+    canvas.set_z_index(first, 1);
+    assert_eq!(canvas.items(), [second, third, first]);
+    assert_eq!(canvas.get_z_index(first), Some(1));
+    // Items with the same z-index are stacked in the order they got it
+    canvas.set_z_index(second, 1);
+    assert_eq!(canvas.items(), [third, first, second]);
+    canvas.set_z_index(third, -1);
+    assert_eq!(canvas.items(), [third, first, second]);
+
+    assert!(canvas.remove(first));
+    assert!(!canvas.remove(first));
+    assert_eq!(canvas.items(), [third, second]);
+    assert_eq!(canvas.get_z_index(first), None);
+    assert_eq!(canvas.get_transform(first), None);
+    assert_eq!(canvas.get_visible(first), None);
+
+    canvas.clear();
+    assert!(canvas.items().is_empty());
+}
+
+fn hit_testing() {
+    let canvas = Canvas::new();
+    let bottom = canvas.add(Square::default());
+    let top = canvas.add(Square::default());
+    canvas.set_transform(top, translate(5.0, 5.0));
+    assert_eq!(canvas.get_transform(top), Some(translate(5.0, 5.0)));
+
+    assert_eq!(canvas.item_at(2.0, 2.0), Some(bottom));
+    assert_eq!(canvas.item_at(7.0, 7.0), Some(top));
+    assert_eq!(canvas.items_at(7.0, 7.0), [top, bottom]);
+    assert_eq!(canvas.item_at(12.0, 12.0), Some(top));
+    assert_eq!(canvas.item_at(20.0, 20.0), None);
+
+    canvas.set_visible(top, false);
+    assert_eq!(canvas.get_visible(top), Some(false));
+    assert_eq!(canvas.item_at(7.0, 7.0), Some(bottom));
+    assert_eq!(canvas.item_at(12.0, 12.0), None);
+    canvas.set_visible(top, true);
+
+    // Items which are scaled to nothing can't be hit
+    canvas.set_transform(bottom, Matrix::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+    assert_eq!(canvas.item_at(2.0, 2.0), None);
+    canvas.set_transform(bottom, Matrix::identity());
+
+    let zoom = Matrix::new(2.0, 0.0, 0.0, 2.0, 0.0, 0.0);
+    canvas.set_view_transform(zoom);
+    assert_eq!(canvas.get_view_transform(), zoom);
+    assert_eq!(canvas.item_at(15.0, 15.0), Some(top));
+    assert_eq!(canvas.item_at(25.0, 25.0), Some(top));
+    assert_eq!(canvas.item_at(35.0, 35.0), None);
+}
+
+fn drawing() {
+    let canvas = Canvas::new();
+    let shown = Rc::new(Square::default());
+    let hidden = Rc::new(Square::default());
+    let outside = Rc::new(Square::default());
+    canvas.add(shown.clone());
+    let hidden_id = canvas.add(hidden.clone());
+    canvas.set_visible(hidden_id, false);
+    let outside_id = canvas.add(outside.clone());
+    canvas.set_transform(outside_id, translate(50.0, 50.0));
+
+    // Only mapped widgets are drawn
+    let window = OffscreenWindow::new();
+    canvas.drawing_area().set_size_request(100, 100);
+    window.add(canvas.drawing_area());
+    window.show_all();
+    while gtk::events_pending() {
+        gtk::main_iteration();
+    }
+    let (shown_draws, outside_draws) = (shown.draws.get(), outside.draws.get());
+
+    let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, 100, 100).unwrap();
+    let cr = cairo::Context::new(&surface);
+    cr.rectangle(0.0, 0.0, 20.0, 20.0);
+    cr.clip();
+    canvas.drawing_area().draw(&cr);
+    assert_eq!(shown.draws.get(), shown_draws + 1);
+    assert_eq!(hidden.draws.get(), 0);
+    // Items outside of the redrawn area are skipped
+    assert_eq!(outside.draws.get(), outside_draws);
+
+    unsafe {
+        window.destroy();
+    }
+}
+
+#[test]
+fn canvas() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the canvas tests");
+        return;
+    }
+
+    stacking();
+    hit_testing();
+    drawing();
+}
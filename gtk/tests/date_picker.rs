@@ -0,0 +1,80 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{Calendar, DatePicker};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn date(year: i32, month: i32, day: i32) -> glib::DateTime {
+    glib::DateTime::new_local(year, month, day, 0, 0, 0.0).unwrap()
+}
+
+fn calendar() {
+    let calendar = Calendar::new();
+    calendar.select_day(0);
+    assert_eq!(calendar.get_date_time(), None);
+
+    calendar.set_date_time(&date(2021, 1, 31));
+    assert_eq!(calendar.get_date_time().unwrap().get_ymd(), (2021, 1, 31));
+    // The 31st doesn't exist in February
+    calendar.set_date_time(&date(2021, 2, 14));
+    assert_eq!(calendar.get_date_time().unwrap().get_ymd(), (2021, 2, 14));
+    assert_eq!(calendar.get_date(), (2021, 1, 14));
+
+    calendar.mark_days(&[3, 1]);
+    calendar.mark_days(&[2]);
+    assert_eq!(calendar.get_marked_days(), [1, 2, 3]);
+    calendar.set_marked_days(&[28]);
+    assert_eq!(calendar.get_marked_days(), [28]);
+}
+
+fn date_picker() {
+    let picker = DatePicker::new();
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let changes_clone = changes.clone();
+    picker.connect_date_changed(move |_, date| changes_clone.borrow_mut().push(date.get_ymd()));
+
+    picker.set_date_time(&date(2020, 2, 29));
+    assert_eq!(picker.get_date_time().unwrap().get_ymd(), (2020, 2, 29));
+    assert!(changes.borrow().is_empty());
+    assert_eq!(
+        picker
+            .get_button()
+            .get_child()
+            .unwrap()
+            .downcast::<gtk::Label>()
+            .unwrap()
+            .get_text(),
+        date(2020, 2, 29).format_locale_date().unwrap()
+    );
+
+    // Like the user picking a day
+    picker.get_calendar().select_day(3);
+    assert_eq!(*changes.borrow(), [(2020, 2, 3)]);
+
+    // The state lives as long as the button
+    let button = picker.get_button().clone();
+    drop(picker);
+    let label = button
+        .get_child()
+        .unwrap()
+        .downcast::<gtk::Label>()
+        .unwrap();
+    assert_eq!(
+        label.get_text(),
+        date(2020, 2, 3).format_locale_date().unwrap()
+    );
+}
+
+#[test]
+fn date_picker_and_calendar() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the calendar tests");
+        return;
+    }
+
+    calendar();
+    date_picker();
+}
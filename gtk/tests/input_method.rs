@@ -0,0 +1,92 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{DrawingArea, IMContextSimple, InputMethodHandler, TextInput};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Recorder {
+    events: Rc<RefCell<Vec<String>>>,
+}
+
+impl TextInput for Recorder {
+    type Widget = DrawingArea;
+
+    fn commit(&self, _widget: &DrawingArea, text: &str) {
+        self.events.borrow_mut().push(format!("commit {}", text));
+    }
+
+    fn preedit_changed(
+        &self,
+        _widget: &DrawingArea,
+        preedit: &str,
+        _attrs: &pango::AttrList,
+        cursor_pos: i32,
+    ) {
+        self.events
+            .borrow_mut()
+            .push(format!("preedit '{}' {}", preedit, cursor_pos));
+    }
+
+    fn surrounding(&self, _widget: &DrawingArea) -> Option<(String, i32)> {
+        Some((String::from("hello"), 2))
+    }
+
+    fn delete_surrounding(&self, _widget: &DrawingArea, offset: i32, n_chars: i32) -> bool {
+        self.events
+            .borrow_mut()
+            .push(format!("delete {} {}", offset, n_chars));
+        true
+    }
+}
+
+#[test]
+fn input_method_handler() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the input method tests");
+        return;
+    }
+
+    let area = DrawingArea::new();
+    let im_context = IMContextSimple::new();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let handler = InputMethodHandler::new(
+        &area,
+        &im_context,
+        Recorder {
+            events: events.clone(),
+        },
+    );
+    assert_eq!(handler.get_im_context(), im_context.upcast_ref());
+
+    im_context.emit_by_name("commit", &[&"ä"]).unwrap();
+    im_context.emit_by_name("preedit-changed", &[]).unwrap();
+    let retrieved = im_context
+        .emit_by_name("retrieve-surrounding", &[])
+        .unwrap()
+        .unwrap();
+    assert!(retrieved.get_some::<bool>().unwrap());
+    let (text, cursor_index) = im_context.get_surrounding().unwrap();
+    assert_eq!((text.as_str(), cursor_index), ("hello", 2));
+    let deleted = im_context
+        .emit_by_name("delete-surrounding", &[&-1, &2])
+        .unwrap()
+        .unwrap();
+    assert!(deleted.get_some::<bool>().unwrap());
+    assert_eq!(
+        *events.borrow(),
+        ["commit ä", "preedit '' 0", "delete -1 2"]
+    );
+
+    // Dropping the handler disconnects the input method
+    drop(handler);
+    im_context.emit_by_name("commit", &[&"b"]).unwrap();
+    let retrieved = im_context
+        .emit_by_name("retrieve-surrounding", &[])
+        .unwrap()
+        .unwrap();
+    assert!(!retrieved.get_some::<bool>().unwrap());
+    assert_eq!(events.borrow().len(), 3);
+}
@@ -0,0 +1,54 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{Button, Popover, PopupOptions, PositionType, Window, WindowType};
+
+#[test]
+fn popup_at_with() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the popover tests");
+        return;
+    }
+
+    let options = PopupOptions::default();
+    assert_eq!(options.get_position(), PositionType::Top);
+    assert!(options.get_auto_dismiss());
+
+    let window = Window::new(WindowType::Toplevel);
+    let button = Button::new();
+    window.add(&button);
+    window.show_all();
+
+    let popover = Popover::new::<Button>(None);
+    assert_eq!(popover.get_position(), options.get_position());
+    assert_eq!(popover.get_modal(), options.get_auto_dismiss());
+
+    let options = PopupOptions::new()
+        .position(PositionType::Right)
+        .auto_dismiss(false);
+    let rect = gtk::Rectangle::from_point(3, 4);
+    popover.popup_at_with(&button, Some(&rect), &options);
+    assert_eq!(popover.get_relative_to(), Some(button.clone().upcast()));
+    assert_eq!(popover.get_pointing_to(), Some(rect));
+    assert_eq!(popover.get_position(), PositionType::Right);
+    assert!(!popover.get_modal());
+    assert!(popover.get_visible());
+
+    // `popup_at` keeps the placement
+    popover.hide();
+    popover.popup_at(&button, None);
+    assert_eq!(popover.get_position(), PositionType::Right);
+    assert_eq!(
+        popover.get_pointing_to(),
+        Some(gtk::Rectangle::from_size(
+            button.get_allocated_width(),
+            button.get_allocated_height()
+        ))
+    );
+
+    unsafe {
+        window.destroy();
+    }
+}
@@ -0,0 +1,93 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use futures_channel::{mpsc, oneshot};
+use gtk::prelude::*;
+use gtk::{LevelBar, ProgressBar};
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+
+fn iterate() {
+    let context = glib::MainContext::default();
+    while context.iteration(false) {}
+}
+
+// Spawns `future` on the default main context and returns where its output is stored
+fn spawn<T: 'static>(future: impl Future<Output = T> + 'static) -> Rc<RefCell<Option<T>>> {
+    let output = Rc::new(RefCell::new(None));
+    let output_clone = output.clone();
+    glib::MainContext::default().spawn_local(async move {
+        *output_clone.borrow_mut() = Some(future.await);
+    });
+    iterate();
+    output
+}
+
+fn progress_bar() {
+    let progress_bar = ProgressBar::new();
+    let (sender, receiver) = mpsc::unbounded();
+    let (done_sender, done) = oneshot::channel::<Result<i32, ()>>();
+    let output = spawn(
+        progress_bar.track_future_with_progress(async move { done.await.unwrap() }, receiver),
+    );
+    assert_eq!(progress_bar.get_fraction(), 0.0);
+
+    sender.unbounded_send(0.25).unwrap();
+    sender.unbounded_send(0.5).unwrap();
+    iterate();
+    // Only the latest fraction is shown
+    assert_eq!(progress_bar.get_fraction(), 0.5);
+    sender.unbounded_send(2.0).unwrap();
+    iterate();
+    assert_eq!(progress_bar.get_fraction(), 1.0);
+
+    done_sender.send(Ok(42)).unwrap();
+    iterate();
+    assert_eq!(*output.borrow(), Some(Ok(42)));
+    assert_eq!(progress_bar.get_fraction(), 1.0);
+
+    // Failed operations empty the progress bar
+    let (done_sender, done) = oneshot::channel::<Result<i32, ()>>();
+    let output = spawn(progress_bar.track_future(async move { done.await.unwrap() }));
+    assert_eq!(progress_bar.get_fraction(), 0.0);
+    done_sender.send(Err(())).unwrap();
+    iterate();
+    assert_eq!(*output.borrow(), Some(Err(())));
+    assert_eq!(progress_bar.get_fraction(), 0.0);
+}
+
+fn level_bar() {
+    let level_bar = LevelBar::new_for_interval(10.0, 20.0);
+    let (sender, receiver) = mpsc::unbounded();
+    let (done_sender, done) = oneshot::channel::<Result<(), ()>>();
+    let output =
+        spawn(level_bar.track_future_with_progress(async move { done.await.unwrap() }, receiver));
+    assert_eq!(level_bar.get_value(), 10.0);
+
+    sender.unbounded_send(0.5).unwrap();
+    iterate();
+    assert_eq!(level_bar.get_value(), 15.0);
+    sender.unbounded_send(-1.0).unwrap();
+    iterate();
+    assert_eq!(level_bar.get_value(), 10.0);
+    sender.unbounded_send(0.75).unwrap();
+    iterate();
+
+    done_sender.send(Err(())).unwrap();
+    iterate();
+    assert_eq!(*output.borrow(), Some(Err(())));
+    assert_eq!(level_bar.get_value(), 10.0);
+}
+
+#[test]
+fn track_future() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the progress tests");
+        return;
+    }
+
+    progress_bar();
+    level_bar();
+}
@@ -0,0 +1,99 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{Align, Builder, Button, Entry, FormAlignment, Label, SizeGroup, SizeGroupMode, Widget};
+
+fn contains<P: IsA<Widget>>(size_group: &SizeGroup, widget: &P) -> bool {
+    size_group.get_widgets().contains(widget.upcast_ref())
+}
+
+fn memberships() {
+    let size_group = SizeGroup::new(SizeGroupMode::Horizontal);
+    let first = Button::new();
+    let second = Button::new();
+
+    let membership = size_group.add_widget_guarded(&first);
+    assert_eq!(membership.get_size_group(), &size_group);
+    assert_eq!(membership.get_widget(), Some(first.clone().upcast()));
+    assert!(contains(&size_group, &first));
+    drop(membership);
+    assert!(!contains(&size_group, &first));
+
+    size_group.add_widget_guarded(&second).keep();
+    assert!(contains(&size_group, &second));
+
+    // Guards of widgets that were removed in the meantime don't remove them again
+    let membership = size_group.add_widget_guarded(&first);
+    size_group.remove_widget(&first);
+    drop(membership);
+
+    size_group.add_widgets(&[&first, &second]);
+    assert_eq!(size_group.get_widgets().len(), 2);
+}
+
+fn from_builder() {
+    let builder = Builder::from_string(
+        r#"<interface>
+  <object class="GtkButton" id="button"/>
+  <object class="GtkEntry" id="entry"/>
+  <object class="GtkAdjustment" id="adjustment"/>
+</interface>"#,
+    );
+    let size_group = SizeGroup::new(SizeGroupMode::Vertical);
+    assert!(size_group
+        .add_widgets_from_builder(&builder, &["button", "missing"])
+        .is_err());
+    assert!(size_group
+        .add_widgets_from_builder(&builder, &["button", "adjustment"])
+        .is_err());
+    assert!(size_group.get_widgets().is_empty());
+
+    size_group
+        .add_widgets_from_builder(&builder, &["button", "entry"])
+        .unwrap();
+    assert_eq!(size_group.get_widgets().len(), 2);
+}
+
+fn form_alignment() {
+    let mut form = FormAlignment::new();
+    let name_label = Label::with_mnemonic("_Name");
+    let name_entry = Entry::new();
+    form.add_row(&name_label, &name_entry);
+    assert_eq!(name_label.get_xalign(), 1.0);
+    assert_eq!(
+        name_label.get_mnemonic_widget(),
+        Some(name_entry.clone().upcast())
+    );
+
+    form.set_label_align(Align::Fill);
+    let proxy_label = Label::with_mnemonic("HTTP _proxy server");
+    let proxy_entry = Entry::new();
+    form.add_row(&proxy_label, &proxy_entry);
+    assert_eq!(proxy_label.get_xalign(), 0.0);
+
+    assert!(contains(form.get_label_group(), &name_label));
+    assert!(contains(form.get_label_group(), &proxy_label));
+    assert!(contains(form.get_field_group(), &name_entry));
+    assert!(contains(form.get_field_group(), &proxy_entry));
+
+    form.remove_row(&name_label, &name_entry);
+    assert!(!contains(form.get_label_group(), &name_label));
+    assert!(!contains(form.get_field_group(), &name_entry));
+    // Rows that are not part of the form are ignored
+    form.remove_row(&name_label, &name_entry);
+    assert_eq!(form.get_label_group().get_widgets().len(), 1);
+}
+
+#[test]
+fn size_groups() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the size group tests");
+        return;
+    }
+
+    memberships();
+    from_builder();
+    form_alignment();
+}
@@ -0,0 +1,143 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{
+    Entry, ListStore, SortColumn, SortType, ToggleButton, TreeModelFilter, TreeModelSort, TreePath,
+};
+
+fn values<M: IsA<gtk::TreeModel>>(model: &M) -> Vec<i32> {
+    let mut values = Vec::new();
+    if let Some(iter) = model.get_iter_first() {
+        loop {
+            values.push(model.get_value(&iter, 0).get_some::<i32>().unwrap());
+            if !model.iter_next(&iter) {
+                break;
+            }
+        }
+    }
+    values
+}
+
+fn store() -> ListStore {
+    let store = ListStore::new(&[glib::Type::I32]);
+    for value in 1..=6 {
+        store.insert_with_values(None, &[(0, &value)]);
+    }
+    store
+}
+
+fn filter() {
+    let store = store();
+    let filter = TreeModelFilter::new(&store, None);
+    // Shows the multiples of the number in the entry
+    let entry = Entry::new();
+    filter.set_visible_func_weak(&entry, |entry, model, iter| {
+        let value = model.get_value(iter, 0).get_some::<i32>().unwrap();
+        match entry.get_text().parse::<i32>() {
+            Ok(divisor) => value % divisor == 0,
+            Err(_) => true,
+        }
+    });
+    filter.refilter_on_notify(&entry, "text");
+    assert_eq!(values(&filter), [1, 2, 3, 4, 5, 6]);
+
+    entry.set_text("2");
+    assert_eq!(values(&filter), [2, 4, 6]);
+
+    assert!(filter
+        .child_path_to_path(&TreePath::from_indicesv(&[0]))
+        .is_err());
+    assert_eq!(
+        filter
+            .child_path_to_path(&TreePath::from_indicesv(&[3]))
+            .unwrap()
+            .get_indices(),
+        [1]
+    );
+    assert_eq!(
+        filter
+            .path_to_child_path(&TreePath::from_indicesv(&[2]))
+            .unwrap()
+            .get_indices(),
+        [5]
+    );
+    assert!(filter
+        .path_to_child_path(&TreePath::from_indicesv(&[3]))
+        .is_err());
+    let hidden = store.get_iter_first().unwrap();
+    assert!(filter.child_iter_to_iter(&hidden).is_err());
+    store.iter_next(&hidden);
+    let visible = filter.child_iter_to_iter(&hidden).unwrap();
+    assert_eq!(filter.get_value(&visible, 0).get_some::<i32>().unwrap(), 2);
+
+    // All rows are visible once the entry is gone
+    drop(entry);
+    filter.refilter();
+    assert_eq!(values(&filter), [1, 2, 3, 4, 5, 6]);
+}
+
+fn sort() {
+    let store = store();
+    let sort = TreeModelSort::new(&store);
+    // Sorts in reverse while the button is active
+    let reverse = ToggleButton::new();
+    sort.set_sort_func_weak(SortColumn::Index(0), &reverse, |reverse, model, a, b| {
+        let a = model.get_value(a, 0).get_some::<i32>().unwrap();
+        let b = model.get_value(b, 0).get_some::<i32>().unwrap();
+        if reverse.get_active() {
+            b.cmp(&a)
+        } else {
+            a.cmp(&b)
+        }
+    });
+    reverse.set_active(true);
+    sort.set_sort_column_id(SortColumn::Index(0), SortType::Ascending);
+    assert_eq!(values(&sort), [6, 5, 4, 3, 2, 1]);
+
+    assert_eq!(
+        sort.child_path_to_path(&TreePath::from_indicesv(&[0]))
+            .unwrap()
+            .get_indices(),
+        [5]
+    );
+    assert_eq!(
+        sort.path_to_child_path(&TreePath::from_indicesv(&[1]))
+            .unwrap()
+            .get_indices(),
+        [4]
+    );
+    assert!(sort
+        .path_to_child_path(&TreePath::from_indicesv(&[6]))
+        .is_err());
+    let first = sort.get_iter_first().unwrap();
+    let child = sort.iter_to_child_iter(&first).unwrap();
+    assert_eq!(store.get_value(&child, 0).get_some::<i32>().unwrap(), 6);
+    let child_first = store.get_iter_first().unwrap();
+    let sorted = sort.child_iter_to_iter(&child_first).unwrap();
+    assert_eq!(sort.get_value(&sorted, 0).get_some::<i32>().unwrap(), 1);
+
+    // Iters of other models are rejected
+    assert!(sort.iter_to_child_iter(&child_first).is_err());
+
+    reverse.set_active(false);
+    sort.set_sort_column_id(SortColumn::Index(0), SortType::Descending);
+    assert_eq!(values(&sort), [6, 5, 4, 3, 2, 1]);
+
+    // Rows compare equal once the button is gone
+    drop(reverse);
+    sort.set_sort_column_id(SortColumn::Index(0), SortType::Ascending);
+    assert_eq!(values(&sort).len(), 6);
+}
+
+#[test]
+fn tree_model_filter_and_sort() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the tree model tests");
+        return;
+    }
+
+    filter();
+    sort();
+}
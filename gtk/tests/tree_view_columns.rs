@@ -0,0 +1,180 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{ListStore, TreeView, TreeViewColumn, TreeViewColumnSizing, Window, WindowType};
+use std::path::Path;
+use std::process::Command;
+
+fn values(store: &ListStore) -> Vec<i32> {
+    let mut values = Vec::new();
+    if let Some(iter) = store.get_iter_first() {
+        loop {
+            values.push(store.get_value(&iter, 0).get_some::<i32>().unwrap());
+            if !store.iter_next(&iter) {
+                break;
+            }
+        }
+    }
+    values
+}
+
+fn with_columns(names: &[&str]) -> TreeView {
+    let tree_view = TreeView::new();
+    for name in names {
+        let column = TreeViewColumn::new();
+        column.set_buildable_name(name);
+        tree_view.append_column(&column);
+    }
+    tree_view
+}
+
+fn sort_helpers() {
+    assert!(TreeView::new()
+        .set_column_sort_by::<i32, _>(&TreeViewColumn::new(), 0, 0)
+        .is_err());
+
+    let store = ListStore::new(&[glib::Type::I32]);
+    for value in &[2, 3, 1] {
+        store.insert_with_values(None, &[(0, value)]);
+    }
+    let tree_view = TreeView::with_model(&store);
+    let column = TreeViewColumn::new();
+    tree_view.append_column(&column);
+
+    tree_view
+        .set_column_sort_by::<i32, _>(&column, 0, 0)
+        .unwrap();
+    assert_eq!(column.get_sort_column_id(), 0);
+    assert!(column.get_clickable());
+
+    column.clicked();
+    assert_eq!(values(&store), [1, 2, 3]);
+    column.clicked();
+    assert_eq!(values(&store), [3, 2, 1]);
+}
+
+fn persist_columns() {
+    let state = glib::KeyFile::new();
+    state.set_integer("columns", "column-0-width", 120);
+
+    let window = Window::new(WindowType::Toplevel);
+    let tree_view = with_columns(&["first", "second"]);
+    let hidden = tree_view.get_column(1).unwrap();
+    hidden.set_fixed_width(50);
+    hidden.set_visible(false);
+    gtk::state::persist_columns(&tree_view, "columns", &state);
+    window.add(&tree_view);
+
+    window.show_all();
+    let column = tree_view.get_column(0).unwrap();
+    assert_eq!(column.get_sizing(), TreeViewColumnSizing::Fixed);
+    assert_eq!(column.get_fixed_width(), 120);
+    while gtk::events_pending() {
+        gtk::main_iteration();
+    }
+
+    let width = column.get_width();
+    window.hide();
+    assert_eq!(
+        state.get_integer("columns", "column-0-width").unwrap(),
+        width
+    );
+    // Hidden columns have no allocation and keep their fixed width
+    assert_eq!(state.get_integer("columns", "column-1-width").unwrap(), 50);
+
+    unsafe {
+        window.destroy();
+    }
+}
+
+// The settings need a compiled schema, which is skipped without `glib-compile-schemas`
+fn settings(dir: &Path) -> Option<gio::Settings> {
+    std::fs::create_dir_all(dir).unwrap();
+    std::fs::write(
+        dir.join("org.gtk-rs.test.gschema.xml"),
+        r#"<schemalist>
+  <schema id="org.gtk-rs.test" path="/org/gtk-rs/test/">
+    <key name="columns" type="a(sbi)"><default>[]</default></key>
+  </schema>
+</schemalist>
+"#,
+    )
+    .unwrap();
+    match Command::new("glib-compile-schemas").arg(dir).status() {
+        Ok(status) if status.success() => (),
+        _ => {
+            eprintln!("glib-compile-schemas is not available, skipping save_columns");
+            return None;
+        }
+    }
+
+    let source = gio::SettingsSchemaSource::from_directory(dir, None, false).unwrap();
+    let schema = source.lookup("org.gtk-rs.test", false).unwrap();
+    let backend = gio::memory_settings_backend_new();
+    Some(gio::Settings::new_full(&schema, Some(&backend), None))
+}
+
+fn save_columns() {
+    let dir = std::env::temp_dir().join(format!("gtk-rs-tree-view-{}", std::process::id()));
+    let settings = settings(&dir);
+    let _ = std::fs::remove_dir_all(&dir);
+    let settings = match settings {
+        Some(settings) => settings,
+        None => return,
+    };
+
+    let tree_view = with_columns(&["first", "second", "third"]);
+    let second = tree_view.get_column(1).unwrap();
+    second.set_fixed_width(80);
+    second.set_visible(false);
+    tree_view.save_columns(&settings, "columns").unwrap();
+
+    let saved = settings.get_value("columns");
+    let saved = saved
+        .iter_as::<(String, bool, i32)>()
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(saved[1], (String::from("second"), false, 80));
+
+    settings
+        .set_value(
+            "columns",
+            &vec![
+                (String::from("third"), true, 0),
+                (String::from("missing"), true, 10),
+                (String::from("second"), true, 90),
+            ]
+            .to_variant(),
+        )
+        .unwrap();
+    let tree_view = with_columns(&["first", "second", "third"]);
+    tree_view.restore_columns(&settings, "columns").unwrap();
+
+    let names = tree_view
+        .get_columns()
+        .iter()
+        .map(|column| column.get_buildable_name().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["third", "second", "first"]);
+    let second = tree_view.get_column(1).unwrap();
+    assert!(second.get_visible());
+    assert_eq!(second.get_sizing(), TreeViewColumnSizing::Fixed);
+    assert_eq!(second.get_fixed_width(), 90);
+    // Columns without a saved width keep their sizing
+    let third = tree_view.get_column(0).unwrap();
+    assert_eq!(third.get_sizing(), TreeViewColumnSizing::GrowOnly);
+}
+
+#[test]
+fn tree_view_columns() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the tree view column tests");
+        return;
+    }
+
+    sort_helpers();
+    persist_columns();
+    save_columns();
+}
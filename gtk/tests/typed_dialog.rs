@@ -0,0 +1,78 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{ResponseType, TypedDialogBuilder};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Choice {
+    Discard,
+    Cancel,
+    Save,
+}
+
+#[test]
+fn typed_dialog() {
+    // GTK can only be used from the thread it was initialized on, so everything is tested from
+    // a single test.
+    if gtk::init().is_err() {
+        eprintln!("No display available, skipping the typed dialog tests");
+        return;
+    }
+
+    let dialog = TypedDialogBuilder::new()
+        .title("Save changes?")
+        .button("Discard", Choice::Discard)
+        .default_button("Save", Choice::Save)
+        .close_response(Choice::Cancel)
+        .build();
+    assert_eq!(dialog.get_dialog().get_title().unwrap(), "Save changes?");
+
+    let save = dialog
+        .get_widget_for_response(&Choice::Save)
+        .unwrap()
+        .downcast::<gtk::Button>()
+        .unwrap();
+    assert_eq!(save.get_label().unwrap(), "Save");
+    assert!(dialog.get_widget_for_response(&Choice::Cancel).is_none());
+    dialog.set_response_sensitive(&Choice::Save, false);
+    assert!(!save.get_sensitive());
+    dialog.set_response_sensitive(&Choice::Save, true);
+
+    let responses = Rc::new(RefCell::new(Vec::new()));
+    let responses_clone = responses.clone();
+    dialog.connect_response(move |_, response| responses_clone.borrow_mut().push(response));
+
+    assert!(dialog.response(&Choice::Discard));
+    assert!(!dialog.response(&Choice::Cancel));
+    save.clicked();
+    dialog.get_dialog().response(ResponseType::DeleteEvent);
+    dialog.get_dialog().response(ResponseType::Ok);
+    assert_eq!(
+        *responses.borrow(),
+        [
+            Some(Choice::Discard),
+            Some(Choice::Save),
+            Some(Choice::Cancel),
+            None
+        ]
+    );
+
+    let context = glib::MainContext::default();
+    let result = Rc::new(RefCell::new(None));
+    let result_clone = result.clone();
+    let dialog_clone = dialog.clone();
+    context.spawn_local(async move {
+        *result_clone.borrow_mut() = Some(dialog_clone.run_future().await);
+    });
+    while context.iteration(false) {}
+    assert!(dialog.get_dialog().get_visible());
+    assert!(dialog.response(&Choice::Save));
+    while context.iteration(false) {}
+    assert_eq!(*result.borrow(), Some(Some(Choice::Save)));
+
+    unsafe {
+        dialog.get_dialog().destroy();
+    }
+}
@@ -252,3 +252,40 @@ pub fn parse_fields(fields: &Fields) -> Result<Vec<AttributedField>, Error> {
 
     Ok(attributed_fields)
 }
+
+// parse the optional name of a #[template_callback(name = "value")] attribute
+pub fn parse_template_callback_name(attr: &Attribute) -> Result<Option<String>, Error> {
+    let list = match attr.parse_meta()? {
+        Meta::Path(_) => return Ok(None),
+        Meta::List(list) => list,
+        meta => {
+            return Err(Error::new(
+                meta.span(),
+                "invalid attribute argument type, expected `name = value` list or nothing",
+            ))
+        }
+    };
+
+    let mut name = None;
+    for meta in &list.nested {
+        let name_value = match meta {
+            NestedMeta::Meta(Meta::NameValue(n)) if n.path.is_ident("name") => n,
+            _ => {
+                return Err(Error::new(
+                    meta.span(),
+                    "invalid type - expected a name-value pair like name = \"callback\"",
+                ))
+            }
+        };
+        if name.is_some() {
+            return Err(Error::new(
+                meta.span(),
+                "two instances of the same attribute \
+                argument, each argument must be specified only once",
+            ));
+        }
+        name = Some(parse_field_attr_value_str(name_value)?);
+    }
+
+    Ok(name)
+}
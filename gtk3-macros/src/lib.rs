@@ -2,11 +2,12 @@
 
 mod attribute_parser;
 mod composite_template_derive;
+mod template_callbacks_attribute;
 mod util;
 
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, ItemImpl};
 
 /// Derive macro for using a composite template in a widget.
 ///
@@ -61,3 +62,52 @@ pub fn composite_template_derive(input: TokenStream) -> TokenStream {
     let gen = composite_template_derive::impl_composite_template(&input);
     gen.into()
 }
+
+/// Attribute macro for declaring the signal handlers of a composite template.
+///
+/// All methods of the `impl` block that are marked with `#[template_callback]` can be used
+/// as handlers of the signals declared in the template. The name of a handler defaults to the
+/// name of the method and can be changed with `#[template_callback(name = "...")]`.
+///
+/// The arguments of the signal, starting with the emitting object, are converted to the
+/// types of the method arguments. Arguments that are not needed can be omitted from the end.
+///
+/// # Example
+///
+/// ```compile_fail
+/// #[derive(Debug, Default, CompositeTemplate)]
+/// #[template(file = "composite_template.ui")]
+/// struct MyWidget {
+///     #[template_child]
+///     pub label: TemplateChild<gtk::Label>,
+/// }
+///
+/// #[gtk::template_callbacks]
+/// impl MyWidget {
+///     #[template_callback]
+///     fn button_clicked(&self, button: &gtk::Button) {
+///         self.label.set_text(&button.get_label().unwrap());
+///     }
+///
+///     #[template_callback(name = "entry_changed")]
+///     fn on_entry_changed(&self) {
+///         self.label.set_text("");
+///     }
+/// }
+/// ```
+///
+/// The callbacks then need to be bound in `class_init`, after the template:
+///
+/// ```compile_fail
+/// fn class_init(klass: &mut Self::Class) {
+///     Self::bind_template(klass);
+///     Self::bind_template_callbacks(klass);
+/// }
+/// ```
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn template_callbacks(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemImpl);
+    let gen = template_callbacks_attribute::impl_template_callbacks(input);
+    gen.into()
+}
@@ -0,0 +1,127 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{FnArg, ImplItem, ItemImpl, ReturnType, Type};
+
+use crate::attribute_parser::*;
+use crate::util::*;
+
+// Generates the code extracting the argument `idx` of the callback `name` from the signal values,
+// and the expression passing it on to the method.
+fn gen_callback_arg(name: &str, idx: usize, ty: &Type) -> (TokenStream, TokenStream) {
+    let crate_ident = crate_ident_new();
+    let ident = format_ident!("arg{}", idx);
+
+    let (ty, reference) = match ty {
+        Type::Reference(r) => match &*r.elem {
+            Type::Path(p) if p.path.is_ident("str") => (quote!(String), true),
+            elem => (quote!(#elem), true),
+        },
+        ty => (quote!(#ty), false),
+    };
+
+    let extract = quote! {
+        let #ident = values
+            .get(#idx)
+            .unwrap_or_else(|| panic!("Missing argument {} of template callback '{}'", #idx, #name))
+            .get::<#ty>()
+            .unwrap_or_else(|err: #crate_ident::glib::value::GetError| {
+                panic!("Wrong type for argument {} of template callback '{}': {}", #idx, #name, err)
+            })
+            .unwrap_or_else(|| panic!("Argument {} of template callback '{}' is None", #idx, #name));
+    };
+
+    let pass = if reference {
+        quote!(&#ident)
+    } else {
+        quote!(#ident)
+    };
+
+    (extract, pass)
+}
+
+pub fn impl_template_callbacks(mut input: ItemImpl) -> TokenStream {
+    let crate_ident = crate_ident_new();
+    let mut callbacks = Vec::new();
+
+    for item in input.items.iter_mut() {
+        let method = match item {
+            ImplItem::Method(method) => method,
+            _ => continue,
+        };
+        let attr = match method
+            .attrs
+            .iter()
+            .position(|attr| attr.path.is_ident("template_callback"))
+        {
+            Some(pos) => method.attrs.remove(pos),
+            None => continue,
+        };
+
+        let name = match parse_template_callback_name(&attr) {
+            Ok(name) => name.unwrap_or_else(|| method.sig.ident.to_string()),
+            Err(err) => abort!(err.span(), err.to_string()),
+        };
+
+        let mut inputs = method.sig.inputs.iter();
+        match inputs.next() {
+            Some(FnArg::Receiver(receiver))
+                if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+            _ => abort!(
+                method.sig.span(),
+                "template callbacks must take `&self` as first argument"
+            ),
+        }
+
+        let (extract, pass): (Vec<_>, Vec<_>) = inputs
+            .enumerate()
+            .map(|(idx, arg)| match arg {
+                FnArg::Typed(arg) => gen_callback_arg(&name, idx, &arg.ty),
+                FnArg::Receiver(receiver) => abort!(receiver.span(), "unexpected receiver"),
+            })
+            .unzip();
+
+        // Callbacks without arguments don't look at the signal values
+        let values = if pass.is_empty() {
+            format_ident!("_values")
+        } else {
+            format_ident!("values")
+        };
+
+        let method_ident = &method.sig.ident;
+        let call = quote! { imp.#method_ident(#(#pass),*) };
+        let ret = match method.sig.output {
+            ReturnType::Default => quote! {
+                #call;
+                None
+            },
+            ReturnType::Type(..) => quote! {
+                let ret = #call;
+                Some(#crate_ident::glib::value::ToValue::to_value(&ret))
+            },
+        };
+
+        callbacks.push(quote! {
+            (#name, |imp: &Self, #values: &[#crate_ident::glib::Value]| {
+                #(#extract)*
+                #ret
+            })
+        });
+    }
+
+    let self_ty = &input.self_ty;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        #input
+
+        impl #impl_generics #crate_ident::subclass::widget::CompositeTemplateCallbacks for #self_ty #where_clause {
+            const CALLBACKS: &'static [#crate_ident::subclass::widget::TemplateCallback<Self>] = &[
+                #(#callbacks),*
+            ];
+        }
+    }
+}
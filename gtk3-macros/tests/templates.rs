@@ -0,0 +1,110 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+
+mod imp {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(string = r#"
+    <interface>
+      <template class="TemplateCallbacksWidget" parent="GtkBox">
+        <child>
+          <object class="GtkButton" id="button">
+            <property name="label">Click</property>
+            <signal name="clicked" handler="button_clicked"/>
+            <signal name="mnemonic-activate" handler="button_mnemonic_activate"/>
+          </object>
+        </child>
+        <child>
+          <object class="GtkButton" id="reset_button">
+            <signal name="clicked" handler="reset"/>
+          </object>
+        </child>
+      </template>
+    </interface>
+    "#)]
+    pub struct TemplateCallbacksWidget {
+        #[template_child]
+        pub button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub reset_button: TemplateChild<gtk::Button>,
+        pub clicks: Cell<u32>,
+        pub last_label: RefCell<Option<String>>,
+        pub group_cycling: Cell<Option<bool>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for TemplateCallbacksWidget {
+        const NAME: &'static str = "TemplateCallbacksWidget";
+        type Type = super::TemplateCallbacksWidget;
+        type ParentType = gtk::Box;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+            Self::bind_template_callbacks(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    #[gtk::template_callbacks]
+    impl TemplateCallbacksWidget {
+        #[template_callback]
+        fn button_clicked(&self, button: &gtk::Button) {
+            self.clicks.set(self.clicks.get() + 1);
+            *self.last_label.borrow_mut() = button.get_label().map(String::from);
+        }
+
+        #[template_callback]
+        fn button_mnemonic_activate(&self, _button: &gtk::Button, group_cycling: bool) -> bool {
+            self.group_cycling.set(Some(group_cycling));
+            true
+        }
+
+        #[template_callback(name = "reset")]
+        fn on_reset_clicked(&self) {
+            self.clicks.set(0);
+            *self.last_label.borrow_mut() = None;
+        }
+    }
+
+    impl ObjectImpl for TemplateCallbacksWidget {}
+    impl WidgetImpl for TemplateCallbacksWidget {}
+    impl ContainerImpl for TemplateCallbacksWidget {}
+    impl BoxImpl for TemplateCallbacksWidget {}
+}
+
+glib::wrapper! {
+    pub struct TemplateCallbacksWidget(ObjectSubclass<imp::TemplateCallbacksWidget>)
+        @extends gtk::Widget, gtk::Container, gtk::Box;
+}
+
+#[test]
+fn template_callbacks() {
+    // GTK can't be initialized without a display.
+    if gtk::init().is_err() {
+        return;
+    }
+
+    let widget = glib::Object::new::<TemplateCallbacksWidget>(&[]).unwrap();
+    let imp = imp::TemplateCallbacksWidget::from_instance(&widget);
+
+    imp.button.clicked();
+    imp.button.clicked();
+    assert_eq!(imp.clicks.get(), 2);
+    assert_eq!(imp.last_label.borrow().as_deref(), Some("Click"));
+
+    assert!(imp.button.mnemonic_activate(true));
+    assert_eq!(imp.group_cycling.get(), Some(true));
+
+    imp.reset_button.clicked();
+    assert_eq!(imp.clicks.get(), 0);
+    assert_eq!(*imp.last_label.borrow(), None);
+}
@@ -0,0 +1,43 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(feature = "v1_46", feature = "dox"))]
+use crate::Overline;
+use crate::{
+    Alignment, AttrType, BidiType, CoverageLevel, Direction, EllipsizeMode, Gravity, GravityHint,
+    RenderPart, Script, Stretch, Style, TabAlign, Underline, Variant, Weight, WrapMode,
+};
+
+// The conversions of the generated enums, see `glib::enum_conversions!`.
+glib::enum_conversions! {
+    Alignment,
+    AttrType,
+    BidiType,
+    CoverageLevel,
+    Direction,
+    EllipsizeMode,
+    Gravity,
+    GravityHint,
+    #[cfg(any(feature = "v1_46", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v1_46")))]
+    Overline,
+    RenderPart,
+    Script,
+    Stretch,
+    Style,
+    TabAlign,
+    Underline,
+    Variant,
+    Weight,
+    WrapMode,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enum_conversions_list_all_enums() {
+        assert_eq!(
+            glib::__unlisted_enums(include_str!("auto/enums.rs"), include_str!("enums.rs")),
+            Vec::<&str>::new()
+        );
+    }
+}
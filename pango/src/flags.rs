@@ -0,0 +1,18 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::FontMask;
+#[cfg(any(feature = "v1_44", feature = "dox"))]
+use crate::ShapeFlags;
+#[cfg(any(feature = "v1_44", feature = "dox"))]
+use crate::ShowFlags;
+
+// The conversions of the generated flags, see `glib::flags_conversions!`.
+glib::flags_conversions! {
+    FontMask,
+    #[cfg(any(feature = "v1_44", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v1_44")))]
+    ShapeFlags,
+    #[cfg(any(feature = "v1_44", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v1_44")))]
+    ShowFlags,
+}
@@ -0,0 +1,15 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{FontDescription, Pt, Px};
+
+impl FontDescription {
+    /// Sets the size of the font in points.
+    pub fn set_size_pt(&mut self, size: Pt) {
+        self.set_size(size.to_pango_units());
+    }
+
+    /// Sets the size of the font in device units.
+    pub fn set_absolute_size_px(&mut self, size: Px) {
+        self.set_absolute_size(size.0 * f64::from(crate::SCALE));
+    }
+}
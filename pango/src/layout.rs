@@ -0,0 +1,16 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Layout, Px};
+
+impl Layout {
+    /// Sets the width at which lines are wrapped or ellipsized, or unsets it with `None`.
+    pub fn set_width_px(&self, width: Option<Px>) {
+        self.set_width(width.map_or(-1, Px::to_pango_units));
+    }
+
+    /// Returns the logical width and height of the layout.
+    pub fn get_size_px(&self) -> (Px, Px) {
+        let (width, height) = self.get_size();
+        (Px::from_pango_units(width), Px::from_pango_units(height))
+    }
+}
@@ -59,3 +59,11 @@ pub use glyph::{GlyphGeometry, GlyphInfo};
 
 mod coverage;
 pub use crate::coverage::*;
+
+mod enums;
+mod flags;
+
+mod font_description;
+mod layout;
+pub mod units;
+pub use crate::units::{units_to_pixels, Pt, Px};
@@ -0,0 +1,145 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Lengths in device units and points.
+//!
+//! Pango stores lengths as integers in Pango units, which are [`SCALE`](crate::SCALE) times
+//! smaller than device units or points. [`Px`] and [`Pt`] convert from and to them, so that the
+//! scale doesn't have to be applied by hand.
+
+use std::ops;
+
+/// A length in device units, usually pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Px(pub f64);
+
+/// A length in points, as used for font sizes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Pt(pub f64);
+
+macro_rules! define_unit {
+    ($name:ident) => {
+        impl $name {
+            /// Converts a length in Pango units.
+            pub fn from_pango_units(units: i32) -> $name {
+                $name(crate::units_to_double(units))
+            }
+
+            /// Converts the length to Pango units, rounding to the nearest unit.
+            pub fn to_pango_units(self) -> i32 {
+                crate::units_from_double(self.0)
+            }
+        }
+
+        impl ops::Add for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl ops::Sub for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl ops::Mul<f64> for $name {
+            type Output = $name;
+
+            fn mul(self, factor: f64) -> $name {
+                $name(self.0 * factor)
+            }
+        }
+
+        impl ops::Div<f64> for $name {
+            type Output = $name;
+
+            fn div(self, divisor: f64) -> $name {
+                $name(self.0 / divisor)
+            }
+        }
+
+        impl ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+    };
+}
+
+define_unit!(Px);
+define_unit!(Pt);
+
+/// Converts a length in Pango units to whole device units, rounding to the nearest one.
+///
+/// Same as the `PANGO_PIXELS()` macro, halfway lengths are rounded up.
+pub fn units_to_pixels(units: i32) -> i32 {
+    (units + crate::SCALE / 2).div_euclid(crate::SCALE)
+}
+
+impl Px {
+    /// Rounds the length to whole device units like [`units_to_pixels`].
+    pub fn round(self) -> i32 {
+        units_to_pixels(self.to_pango_units())
+    }
+
+    /// Converts the length to points at a resolution of `dpi` device units per inch.
+    pub fn to_pt(self, dpi: f64) -> Pt {
+        Pt(self.0 * 72.0 / dpi)
+    }
+}
+
+impl Pt {
+    /// Converts the length to device units at a resolution of `dpi` device units per inch.
+    pub fn to_px(self, dpi: f64) -> Px {
+        Px(self.0 * dpi / 72.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pango_units() {
+        assert_eq!(Px::from_pango_units(3 * crate::SCALE / 2), Px(1.5));
+        assert_eq!(Px(1.5).to_pango_units(), 3 * crate::SCALE / 2);
+        assert_eq!(Pt(-2.0).to_pango_units(), -2 * crate::SCALE);
+        assert_eq!(Pt::from_pango_units(crate::SCALE), Pt(1.0));
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(units_to_pixels(0), 0);
+        assert_eq!(units_to_pixels(crate::SCALE / 2 - 1), 0);
+        assert_eq!(units_to_pixels(crate::SCALE / 2), 1);
+        assert_eq!(units_to_pixels(-crate::SCALE / 2), 0);
+        assert_eq!(units_to_pixels(-crate::SCALE / 2 - 1), -1);
+
+        assert_eq!(Px(2.5).round(), 3);
+        assert_eq!(Px(-2.5).round(), -2);
+        assert_eq!(Px(-2.6).round(), -3);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Pt(12.0).to_px(96.0), Px(16.0));
+        assert_eq!(Px(16.0).to_pt(96.0), Pt(12.0));
+        assert_eq!(Pt(72.0).to_px(72.0), Px(72.0));
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Px(1.0) + Px(2.5), Px(3.5));
+        assert_eq!(Px(1.0) - Px(2.5), Px(-1.5));
+        assert_eq!(Pt(3.0) * 2.0, Pt(6.0));
+        assert_eq!(Pt(3.0) / 2.0, Pt(1.5));
+        assert_eq!(-Px(1.0), Px(-1.0));
+        assert!(Px(1.0) < Px(2.0));
+    }
+}
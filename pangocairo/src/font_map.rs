@@ -1,18 +1,95 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::FontMap;
-use glib::object::IsA;
+use glib::object::{Cast, IsA};
 use glib::translate::*;
+use std::fmt;
+
+/// A font backend that pango-cairo can render text with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FontBackend {
+    /// fontconfig and FreeType, the default on all platforms but Windows and macOS.
+    Fontconfig,
+    /// The Win32 GDI font backend, only available on Windows.
+    Win32,
+    /// The CoreText font backend, only available on macOS.
+    CoreText,
+}
+
+impl FontBackend {
+    /// All font backends, including ones not available on the current platform.
+    pub const ALL: &'static [FontBackend] = &[
+        FontBackend::Fontconfig,
+        FontBackend::Win32,
+        FontBackend::CoreText,
+    ];
+
+    /// Returns the cairo font type used by font maps of this backend.
+    pub fn font_type(self) -> cairo::FontType {
+        match self {
+            FontBackend::Fontconfig => cairo::FontType::FontTypeFt,
+            FontBackend::Win32 => cairo::FontType::FontTypeWin32,
+            FontBackend::CoreText => cairo::FontType::FontTypeQuartz,
+        }
+    }
+
+    /// Returns the backend using the cairo font type `font_type`, if any.
+    pub fn from_font_type(font_type: cairo::FontType) -> Option<FontBackend> {
+        match font_type {
+            cairo::FontType::FontTypeFt => Some(FontBackend::Fontconfig),
+            cairo::FontType::FontTypeWin32 => Some(FontBackend::Win32),
+            cairo::FontType::FontTypeQuartz => Some(FontBackend::CoreText),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the backend as understood by the `PANGOCAIRO_BACKEND` environment
+    /// variable.
+    pub fn name(self) -> &'static str {
+        match self {
+            FontBackend::Fontconfig => "fc",
+            FontBackend::Win32 => "win32",
+            FontBackend::CoreText => "coretext",
+        }
+    }
+
+    /// Returns whether pango was built with support for this backend.
+    pub fn is_available(self) -> bool {
+        FontMap::new_for_backend(self).is_some()
+    }
+
+    /// Returns all backends pango was built with support for.
+    pub fn available() -> Vec<FontBackend> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|backend| backend.is_available())
+            .collect()
+    }
+}
+
+impl fmt::Display for FontBackend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
 
 pub trait FontMapExtManual {
     #[doc(alias = "pango_cairo_font_map_get_font_type")]
     fn get_font_type(&self) -> cairo::FontType;
+
+    /// Returns the font backend of the font map.
+    fn get_backend(&self) -> Option<FontBackend>;
 }
 
 impl<O: IsA<FontMap>> FontMapExtManual for O {
     fn get_font_type(&self) -> cairo::FontType {
         unsafe { ffi::pango_cairo_font_map_get_font_type(self.as_ref().to_glib_none().0).into() }
     }
+
+    fn get_backend(&self) -> Option<FontBackend> {
+        FontBackend::from_font_type(self.get_font_type())
+    }
 }
 
 impl FontMap {
@@ -21,6 +98,14 @@ impl FontMap {
         unsafe { from_glib_full(ffi::pango_cairo_font_map_new_for_font_type(fonttype.into())) }
     }
 
+    /// Creates a new font map using `backend`.
+    ///
+    /// Returns `None` if pango was built without support for `backend`.
+    #[doc(alias = "pango_cairo_font_map_new_for_font_type")]
+    pub fn new_for_backend(backend: FontBackend) -> Option<pango::FontMap> {
+        Self::new_for_font_type(backend.font_type())
+    }
+
     #[allow(clippy::new_ret_no_self)]
     #[doc(alias = "pango_cairo_font_map_new")]
     pub fn new() -> Option<pango::FontMap> {
@@ -33,4 +118,16 @@ impl FontMap {
             ffi::pango_cairo_font_map_set_default(font_map.as_ref().to_glib_none().0);
         }
     }
+
+    /// Makes a new font map using `backend` the default font map of the current thread.
+    ///
+    /// This is useful for tests that need deterministic font rendering independent of the
+    /// `PANGOCAIRO_BACKEND` environment variable.
+    pub fn set_default_backend(backend: FontBackend) -> Result<(), glib::BoolError> {
+        let font_map = Self::new_for_backend(backend)
+            .and_then(|font_map| font_map.dynamic_cast::<FontMap>().ok())
+            .ok_or_else(|| glib::bool_error!("Font backend '{}' is not available", backend))?;
+        Self::set_default(Some(font_map));
+        Ok(())
+    }
 }
@@ -17,3 +17,4 @@ pub use crate::auto::*;
 pub mod prelude;
 
 mod font_map;
+pub use crate::font_map::FontBackend;